@@ -2,6 +2,8 @@
 
 use anyhow::Result as AnyhowResult;
 use clap::{Parser, Subcommand};
+use commons::timestamp_from_string;
+use std::path::PathBuf;
 
 /// Supported server commands.
 #[derive(Debug, Subcommand)]
@@ -24,7 +26,7 @@ pub(crate) enum Commands {
         password: String,
     },
 
-    /// Authenticate an existing user (returns a JWT token).
+    /// Authenticate an existing user (saves the access and refresh tokens).
     Login {
         /// Username.
         #[arg(short, long)]
@@ -35,6 +37,25 @@ pub(crate) enum Commands {
         password: String,
     },
 
+    /// Log out: revoke the session on the server and forget the saved tokens.
+    Logout,
+
+    /// Change or reset the account password.
+    #[command(subcommand)]
+    Password(PasswordCommands),
+
+    /// Confirm the account email address.
+    #[command(subcommand)]
+    Email(EmailCommands),
+
+    /// Show or edit your profile (token required).
+    #[command(subcommand)]
+    Profile(ProfileCommands),
+
+    /// Export your data or delete your account (token required).
+    #[command(subcommand)]
+    Account(AccountCommands),
+
     /// Create a new post (token required).
     Create {
         /// Post title. Maximum length of 100 characters.
@@ -43,13 +64,90 @@ pub(crate) enum Commands {
         /// Post content. Please follow ethical guidelines and show respect for readers.
         #[arg(short, long)]
         content: String,
+
+        /// Post tag. Can be repeated: `--tag rust --tag web`. Optional.
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+
+        /// Save the post as a draft visible only to you.
+        #[arg(long, conflicts_with = "publish_at")]
+        draft: bool,
+
+        /// Schedule publication, e.g. `2026-10-20T09:00:00+03:00` or
+        /// `2026-10-20 09:00` (local time). Must be in the future.
+        #[arg(long, value_parser=validate_datetime)]
+        publish_at: Option<i64>,
     },
 
-    /// Retrieve a specific post.
-    Get {
+    /// Publish a draft or scheduled post (token required).
+    Publish {
         /// Post ID.
         #[arg(short, long, value_parser=validate_post_id)]
         post_id: i64,
+
+        /// Schedule publication instead of publishing right away. Same format
+        /// as `create --publish-at`.
+        #[arg(long, value_parser=validate_datetime)]
+        at: Option<i64>,
+    },
+
+    /// List your drafts and scheduled posts (token required).
+    Drafts {
+        /// Number of records to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Number of records to skip. Optional.
+        #[arg(short, long)]
+        offset: Option<u32>,
+    },
+
+    /// List your posts in the trash, most recently deleted first (token
+    /// required).
+    Trash {
+        /// Number of records to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Number of records to skip. Optional.
+        #[arg(short, long)]
+        offset: Option<u32>,
+    },
+
+    /// Show an author's public profile followed by their published posts,
+    /// newest first.
+    Author {
+        /// Author's username.
+        #[arg(short, long)]
+        username: String,
+
+        /// Number of posts to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Number of posts to skip. Optional.
+        #[arg(short, long)]
+        offset: Option<u32>,
+    },
+
+    /// Retrieve a specific post.
+    Get {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id, required_unless_present = "slug")]
+        post_id: Option<i64>,
+
+        /// Post slug (human-readable address) instead of the ID. Former
+        /// slugs of the post are accepted as well.
+        #[arg(short, long, conflicts_with = "post_id")]
+        slug: Option<String>,
+
+        /// Render the Markdown content for the terminal instead of printing
+        /// the source text.
+        #[arg(long)]
+        render: bool,
     },
 
     /// Update an existing post (token required).
@@ -66,9 +164,19 @@ pub(crate) enum Commands {
         /// Optional.
         #[arg(short, long)]
         content: Option<String>,
+
+        /// New post tag. Can be repeated; replaces all existing tags. Optional.
+        #[arg(long = "tag", conflicts_with = "clear_tags")]
+        tags: Vec<String>,
+
+        /// Remove all tags from the post.
+        #[arg(long)]
+        clear_tags: bool,
     },
 
-    /// Delete a post (token required).
+    /// Move a post to the trash (token required). It can be restored with
+    /// `restore` until the trash is purged. Moderators can delete any post;
+    /// the author then cannot publish it again.
     Delete {
         /// Post ID.
         #[arg(short, long, value_parser=validate_post_id)]
@@ -85,6 +193,497 @@ pub(crate) enum Commands {
         /// Number of records to skip. Optional.
         #[arg(short, long)]
         offset: Option<u32>,
+
+        /// Show only posts with this tag. Optional.
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Continue after this cursor (printed at the end of the previous
+        /// page). Cannot be combined with --offset.
+        #[arg(long, conflicts_with = "offset")]
+        after: Option<String>,
+    },
+
+    /// Your personal feed: posts by the authors you follow, with the same
+    /// pagination as `list` (token required).
+    Feed {
+        /// Number of records to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Number of records to skip. Optional.
+        #[arg(short, long)]
+        offset: Option<u32>,
+
+        /// Show only posts with this tag. Optional.
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Continue after this cursor (printed at the end of the previous
+        /// page). Cannot be combined with --offset.
+        #[arg(long, conflicts_with = "offset")]
+        after: Option<String>,
+    },
+
+    /// Full-text search over post titles and content.
+    Search {
+        /// Search query. Supports "quoted phrases", `or` and `-word` to exclude.
+        #[arg(short, long)]
+        query: String,
+
+        /// Number of records to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Number of records to skip. Optional.
+        #[arg(short, long)]
+        offset: Option<u32>,
+    },
+
+    /// List tags with post counts.
+    Tags,
+
+    /// Show the revision history of your post (token required).
+    History {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        post_id: i64,
+
+        /// Show the full text of this revision instead of the list. Optional.
+        #[arg(short, long, value_parser=validate_revision)]
+        revision: Option<i32>,
+
+        /// Number of records to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long, conflicts_with = "revision")]
+        limit: Option<u32>,
+
+        /// Number of records to skip. Optional.
+        #[arg(short, long, conflicts_with = "revision")]
+        offset: Option<u32>,
+    },
+
+    /// Show a line diff between two revisions of your post (token required).
+    Diff {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        post_id: i64,
+
+        /// Older revision number.
+        #[arg(short, long, value_parser=validate_revision)]
+        from: i32,
+
+        /// Newer revision number.
+        #[arg(short, long, value_parser=validate_revision)]
+        to: i32,
+    },
+
+    /// Restore your post from the trash, or to a revision when --revision is
+    /// given (token required). A restored revision is saved as a new revision.
+    Restore {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        post_id: i64,
+
+        /// Revision number to restore. Optional.
+        #[arg(short, long, value_parser=validate_revision)]
+        revision: Option<i32>,
+    },
+
+    /// React to a post, or take your reaction back with --remove (token
+    /// required).
+    React {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        post_id: i64,
+
+        /// Reaction kind.
+        #[arg(short, long, value_parser = ["like", "love", "laugh", "wow", "sad"])]
+        kind: String,
+
+        /// Remove the reaction instead of adding it.
+        #[arg(short, long)]
+        remove: bool,
+    },
+
+    /// Follow an author, or unfollow them with --remove (token required).
+    Follow {
+        /// Author's username.
+        #[arg(short, long)]
+        username: String,
+
+        /// Unfollow the author instead of following.
+        #[arg(short, long)]
+        remove: bool,
+    },
+
+    /// List a user's followers, most recent first, or with --following the
+    /// authors they follow.
+    Followers {
+        /// Username.
+        #[arg(short, long)]
+        username: String,
+
+        /// List the authors the user follows instead of their followers.
+        #[arg(short, long)]
+        following: bool,
+
+        /// Number of records to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Number of records to skip. Optional.
+        #[arg(short, long)]
+        offset: Option<u32>,
+    },
+
+    /// Upload an image (PNG, JPEG, GIF, WebP) or a PDF document to embed in
+    /// posts (token required).
+    Upload {
+        /// Path to the file.
+        #[arg(short, long)]
+        file: PathBuf,
+    },
+
+    /// Hide a post from readers, or return it with --remove (moderator role
+    /// required). A hidden post stays visible to its author only.
+    Hide {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        post_id: i64,
+
+        /// Return the hidden post to readers instead of hiding it.
+        #[arg(short, long)]
+        remove: bool,
+    },
+
+    /// Manage comments on posts.
+    #[command(subcommand)]
+    Comment(CommentCommands),
+
+    /// Manage user accounts (admin role required).
+    #[command(subcommand)]
+    Admin(AdminCommands),
+
+    /// Manage moderation rules and review held posts (moderator role
+    /// required).
+    #[command(subcommand)]
+    Moderation(ModerationCommands),
+}
+
+/// Supported email verification commands.
+#[derive(Debug, Subcommand)]
+pub(crate) enum EmailCommands {
+    /// Confirm the email address using the token from the verification email.
+    Verify {
+        /// Verification token from the email.
+        #[arg(short, long)]
+        token: String,
+    },
+
+    /// Send the verification email again (token required).
+    Resend,
+}
+
+/// Supported profile commands.
+#[derive(Debug, Subcommand)]
+pub(crate) enum ProfileCommands {
+    /// Show your account and profile.
+    Show,
+
+    /// Edit your profile. Only the given fields change; an empty value clears
+    /// the display name, bio or avatar.
+    Edit {
+        /// Display name shown instead of the username. Maximum length of 64
+        /// characters.
+        #[arg(short, long)]
+        display_name: Option<String>,
+
+        /// A short bio for the public profile. Maximum length of 1000
+        /// characters.
+        #[arg(short, long)]
+        bio: Option<String>,
+
+        /// Avatar address: an http(s):// link or the URL of an uploaded
+        /// image (/uploads/...).
+        #[arg(short, long)]
+        avatar_url: Option<String>,
+
+        /// New email address. It has to be confirmed again via the link
+        /// sent to it.
+        #[arg(short, long)]
+        email: Option<String>,
+    },
+}
+
+/// Supported account commands.
+#[derive(Debug, Subcommand)]
+pub(crate) enum AccountCommands {
+    /// Export the profile and all posts, including drafts and trashed ones,
+    /// as JSON.
+    Export {
+        /// Write the export to this file instead of printing it. Optional.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Delete the account. The saved session is removed as well.
+    Delete {
+        /// Current password for confirmation.
+        #[arg(short, long)]
+        password: String,
+
+        /// What to do with your posts and comments: delete them or keep them
+        /// under the "deleted-user" account.
+        #[arg(long, value_parser = ["delete", "anonymize"])]
+        posts: String,
+    },
+}
+
+/// Supported password commands.
+#[derive(Debug, Subcommand)]
+pub(crate) enum PasswordCommands {
+    /// Change the password (token required). All sessions of the user are
+    /// revoked; the tokens of a new session are saved.
+    Change {
+        /// Current password.
+        #[arg(short, long)]
+        old_password: String,
+
+        /// New password. Same requirements as on registration.
+        #[arg(short, long)]
+        new_password: String,
+    },
+
+    /// Request a password reset email for a forgotten password.
+    Forgot {
+        /// Email address of the account.
+        #[arg(short, long)]
+        email: String,
+    },
+
+    /// Set a new password using the token from the reset email.
+    Reset {
+        /// Password reset token.
+        #[arg(short, long)]
+        token: String,
+
+        /// New password. Same requirements as on registration.
+        #[arg(short, long)]
+        new_password: String,
+    },
+}
+
+/// Supported comment commands.
+#[derive(Debug, Subcommand)]
+pub(crate) enum CommentCommands {
+    /// Add a comment to a post (token required).
+    Add {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        post_id: i64,
+
+        /// Comment text. Maximum length of 2000 characters.
+        #[arg(short, long)]
+        content: String,
+    },
+
+    /// List comments on a post with pagination support.
+    List {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        post_id: i64,
+
+        /// Number of records to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Number of records to skip. Optional.
+        #[arg(short, long)]
+        offset: Option<u32>,
+    },
+
+    /// Delete a comment (token required). Available to the comment author,
+    /// the post author and moderators.
+    Delete {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        post_id: i64,
+
+        /// Comment ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        comment_id: i64,
+    },
+}
+
+/// Supported account management commands.
+#[derive(Debug, Subcommand)]
+pub(crate) enum AdminCommands {
+    /// List user accounts, oldest first.
+    Users {
+        /// Number of records to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Number of records to skip. Optional.
+        #[arg(short, long)]
+        offset: Option<u32>,
+    },
+
+    /// Change a user's role. It takes effect when the user's session is
+    /// renewed.
+    SetRole {
+        /// Username.
+        #[arg(short, long)]
+        username: String,
+
+        /// New role.
+        #[arg(short, long, value_parser = ["user", "moderator", "admin"])]
+        role: String,
+    },
+
+    /// Disable an account: the user cannot log in and their sessions are
+    /// revoked.
+    Disable {
+        /// Username.
+        #[arg(short, long)]
+        username: String,
+    },
+
+    /// Enable a disabled account.
+    Enable {
+        /// Username.
+        #[arg(short, long)]
+        username: String,
+    },
+
+    /// Lift a login lockout caused by repeated failed login attempts.
+    Unlock {
+        /// Username.
+        #[arg(short, long)]
+        username: String,
+    },
+
+    /// Show the audit log, newest first. Filters are optional and combined.
+    Audit {
+        /// Username of the actor.
+        #[arg(long)]
+        actor: Option<String>,
+
+        /// Action.
+        #[arg(long, value_parser = [
+            "user_registered", "login_succeeded", "login_failed",
+            "post_created", "post_updated", "post_deleted",
+            "post_published", "post_hidden", "post_unhidden",
+            "post_approved", "post_rejected", "post_restored",
+            "post_revision_restored",
+        ])]
+        action: Option<String>,
+
+        /// Target of the action: `user:<id>` or `post:<id>`.
+        #[arg(long)]
+        target: Option<String>,
+
+        /// Start of the period (inclusive). Same format as
+        /// `create --publish-at`.
+        #[arg(long, value_parser=validate_datetime)]
+        since: Option<i64>,
+
+        /// End of the period (exclusive). Same format as
+        /// `create --publish-at`.
+        #[arg(long, value_parser=validate_datetime)]
+        until: Option<i64>,
+
+        /// Number of records to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Number of records to skip. Optional.
+        #[arg(short, long)]
+        offset: Option<u32>,
+    },
+}
+
+/// Supported moderation commands.
+#[derive(Debug, Subcommand)]
+pub(crate) enum ModerationCommands {
+    /// List moderation rules.
+    Rules,
+
+    /// Add a moderation rule.
+    AddRule {
+        /// Word, phrase or regular expression. Maximum length of 200
+        /// characters.
+        #[arg(short, long)]
+        pattern: String,
+
+        /// Pattern kind: a single word, a phrase of several words or a
+        /// regular expression. Words and phrases are case-insensitive.
+        #[arg(short, long, value_parser = ["word", "phrase", "regex"], default_value = "word")]
+        kind: String,
+
+        /// What to do on a match: reject the text or hold the post for
+        /// review. Comments are rejected on any match.
+        #[arg(short, long, value_parser = ["reject", "hold"], default_value = "reject")]
+        action: String,
+    },
+
+    /// Replace the pattern, kind and action of a moderation rule.
+    UpdateRule {
+        /// Rule ID.
+        #[arg(short, long, value_parser=validate_rule_id)]
+        rule_id: i64,
+
+        /// Word, phrase or regular expression.
+        #[arg(short, long)]
+        pattern: String,
+
+        /// Pattern kind.
+        #[arg(short, long, value_parser = ["word", "phrase", "regex"], default_value = "word")]
+        kind: String,
+
+        /// What to do on a match.
+        #[arg(short, long, value_parser = ["reject", "hold"], default_value = "reject")]
+        action: String,
+    },
+
+    /// Delete a moderation rule.
+    DeleteRule {
+        /// Rule ID.
+        #[arg(short, long, value_parser=validate_rule_id)]
+        rule_id: i64,
+    },
+
+    /// List posts held for review, oldest first.
+    Queue {
+        /// Number of records to return. If not provided, the default value
+        /// is used.
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Number of records to skip. Optional.
+        #[arg(short, long)]
+        offset: Option<u32>,
+    },
+
+    /// Approve a held post: it is published now or at its scheduled time.
+    Approve {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        post_id: i64,
+    },
+
+    /// Reject a held post: it is hidden from readers.
+    Reject {
+        /// Post ID.
+        #[arg(short, long, value_parser=validate_post_id)]
+        post_id: i64,
     },
 }
 
@@ -101,6 +700,29 @@ fn validate_post_id(post_id: &str) -> Result<i64, String> {
     Ok(id)
 }
 
+/// Валидировать id правила модерации: целое число от 1.
+fn validate_rule_id(rule_id: &str) -> Result<i64, String> {
+    match rule_id.parse::<i64>() {
+        Ok(n) if n >= 1 => Ok(n),
+        _ => Err(format!("Rule ID must be a positive integer: {rule_id}")),
+    }
+}
+
+/// Валидировать номер ревизии публикации: целое число от 1.
+fn validate_revision(revision: &str) -> Result<i32, String> {
+    match revision.parse::<i32>() {
+        Ok(n) if n >= 1 => Ok(n),
+        _ => Err(format!("Revision must be a positive integer: {revision}")),
+    }
+}
+
+/// Валидировать дату и время публикации, вернуть unix-время (секунды).
+fn validate_datetime(value: &str) -> Result<i64, String> {
+    timestamp_from_string(value).ok_or_else(|| {
+        format!("Expected date and time like `2026-10-20T09:00:00+03:00` or `2026-10-20 09:00`: {value}")
+    })
+}
+
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = None)]
 pub(crate) struct CliArgs {
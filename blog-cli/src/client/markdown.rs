@@ -0,0 +1,266 @@
+//! Отрисовка содержания публикаций (CommonMark) для терминала.
+//!
+//! Разметка заменяется отступами, маркерами списков и подчёркиваниями
+//! заголовков. Если вывод идёт в терминал, дополнительно применяются
+//! ANSI-стили: полужирный, курсив, подчёркивание и цвет кода. Встроенный
+//! HTML не выводится.
+
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+const BOLD_ON: &str = "\x1b[1m";
+const BOLD_OFF: &str = "\x1b[22m";
+const ITALIC_ON: &str = "\x1b[3m";
+const ITALIC_OFF: &str = "\x1b[23m";
+const UNDERLINE_ON: &str = "\x1b[4m";
+const UNDERLINE_OFF: &str = "\x1b[24m";
+const CODE_ON: &str = "\x1b[36m";
+const CODE_OFF: &str = "\x1b[39m";
+
+/// Ширина горизонтальной черты (символов).
+const RULE_WIDTH: usize = 40;
+
+/// Отрисовать текст в формате CommonMark для вывода в консоль.
+///
+/// ## Args
+///
+/// - `markdown` — исходный текст публикации
+/// - `styled` — применять ли ANSI-стили
+pub(super) fn render_terminal(markdown: &str, styled: bool) -> String {
+    let mut writer = TerminalWriter::new(styled);
+
+    for event in Parser::new_ext(markdown, Options::empty()) {
+        writer.event(event);
+    }
+
+    writer.out.trim_end().to_string()
+}
+
+/// Состояние отрисовки.
+struct TerminalWriter {
+    /// Результат.
+    out: String,
+    /// Применять ли ANSI-стили.
+    styled: bool,
+    /// Префиксы строк вложенных блоков (цитаты, элементы списков, код).
+    prefixes: Vec<String>,
+    /// Маркер элемента списка, ожидающий первой строки.
+    pending_marker: Option<String>,
+    /// Следующий вывод начинается с новой строки.
+    line_start: bool,
+    /// Последняя строка — пустой разделитель блоков.
+    blank: bool,
+    /// Открытые списки: номер следующего элемента для нумерованных.
+    lists: Vec<Option<u64>>,
+    /// Открытые ссылки и изображения: адрес и начало текста в `out`.
+    links: Vec<(String, usize)>,
+    /// Начало текста текущего заголовка в `out`.
+    heading_start: usize,
+}
+
+impl TerminalWriter {
+    fn new(styled: bool) -> Self {
+        Self {
+            out: String::new(),
+            styled,
+            prefixes: Vec::new(),
+            pending_marker: None,
+            line_start: true,
+            blank: false,
+            lists: Vec::new(),
+            links: Vec::new(),
+            heading_start: 0,
+        }
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text),
+            Event::Code(code) => {
+                if self.styled {
+                    self.style(CODE_ON);
+                    self.text(&code);
+                    self.style(CODE_OFF);
+                } else {
+                    self.text(&format!("`{code}`"));
+                }
+            }
+            Event::SoftBreak => self.text(" "),
+            Event::HardBreak => self.newline(),
+            Event::Rule => {
+                self.start_block();
+                self.text(&"─".repeat(RULE_WIDTH));
+                self.newline();
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.start_block(),
+            Tag::Heading { .. } => {
+                self.start_block();
+                self.style(BOLD_ON);
+                self.heading_start = self.out.len();
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                self.prefixes.push("│ ".to_string());
+            }
+            Tag::CodeBlock(_) => {
+                self.start_block();
+                self.prefixes.push("    ".to_string());
+            }
+            Tag::List(start) => {
+                if self.lists.is_empty() {
+                    self.start_block();
+                } else {
+                    self.end_line();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.end_line();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}. ", *n - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.prefixes.push(" ".repeat(marker.chars().count()));
+                self.pending_marker = Some(marker);
+            }
+            Tag::Emphasis => self.style(ITALIC_ON),
+            Tag::Strong => self.style(BOLD_ON),
+            Tag::Link { dest_url, .. } => {
+                self.style(UNDERLINE_ON);
+                self.links.push((dest_url.to_string(), self.out.len()));
+            }
+            Tag::Image { dest_url, .. } => {
+                self.text("[изображение: ");
+                self.links.push((dest_url.to_string(), self.out.len()));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.end_line(),
+            TagEnd::Heading(level) => {
+                let width = self.out[self.heading_start..].chars().count();
+                self.style(BOLD_OFF);
+                self.newline();
+                if !self.styled {
+                    let underline = if level == HeadingLevel::H1 { "=" } else { "-" };
+                    self.text(&underline.repeat(width));
+                    self.newline();
+                }
+            }
+            TagEnd::BlockQuote(_) | TagEnd::CodeBlock => {
+                self.prefixes.pop();
+                self.end_line();
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+                self.end_line();
+            }
+            TagEnd::Item => {
+                if self.pending_marker.is_some() {
+                    self.write_prefix();
+                }
+                self.prefixes.pop();
+                self.end_line();
+            }
+            TagEnd::Emphasis => self.style(ITALIC_OFF),
+            TagEnd::Strong => self.style(BOLD_OFF),
+            TagEnd::Link => {
+                let link = self.links.pop();
+                let autolink = link
+                    .as_ref()
+                    .is_some_and(|(url, start)| url.is_empty() || self.out[*start..] == *url);
+                self.style(UNDERLINE_OFF);
+                if let Some((url, _)) = link
+                    && !autolink
+                {
+                    self.text(&format!(" ({url})"));
+                }
+            }
+            TagEnd::Image => {
+                if let Some((url, _)) = self.links.pop() {
+                    self.text(&format!("] ({url})"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Вывести текст; переводы строк получают префиксы вложенных блоков.
+    fn text(&mut self, text: &str) {
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.newline();
+            }
+            if !line.is_empty() {
+                self.write_prefix();
+                self.out.push_str(line);
+            }
+        }
+    }
+
+    /// Применить ANSI-стиль, если он разрешён.
+    fn style(&mut self, code: &str) {
+        if self.styled {
+            self.write_prefix();
+            self.out.push_str(code);
+        }
+    }
+
+    /// Вывести префиксы в начале строки (и маркер элемента списка).
+    fn write_prefix(&mut self) {
+        if !self.line_start {
+            return;
+        }
+
+        let marker = self.pending_marker.take();
+        let own = usize::from(marker.is_some());
+        for prefix in &self.prefixes[..self.prefixes.len() - own] {
+            self.out.push_str(prefix);
+        }
+        if let Some(marker) = marker {
+            self.out.push_str(&marker);
+        }
+
+        self.line_start = false;
+        self.blank = false;
+    }
+
+    fn newline(&mut self) {
+        self.out.push('\n');
+        self.line_start = true;
+    }
+
+    /// Завершить текущую строку, если она начата.
+    fn end_line(&mut self) {
+        if !self.line_start {
+            self.newline();
+        }
+    }
+
+    /// Отделить новый блок пустой строкой. Первый блок элемента списка
+    /// выводится сразу после маркера.
+    fn start_block(&mut self) {
+        self.end_line();
+        if self.out.is_empty() || self.blank || self.pending_marker.is_some() {
+            return;
+        }
+
+        let prefix: String = self.prefixes.concat();
+        self.out.push_str(prefix.trim_end());
+        self.out.push('\n');
+        self.blank = true;
+    }
+}
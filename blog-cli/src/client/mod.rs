@@ -1,4 +1,5 @@
 //! Инфраструктура осуществления запросов к серверам.
 
+mod markdown;
 mod tools;
 pub(crate) mod request;
\ No newline at end of file
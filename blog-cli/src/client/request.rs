@@ -1,12 +1,21 @@
 //! Выполнение команды пользователя на взаимодействие с серверами.
 
 use crate::{
-    cli::Commands,
-    client::tools::{print_one_post, print_success, print_token_not_set, read_token, save_token},
+    cli::{
+        AccountCommands, AdminCommands, Commands, CommentCommands, EmailCommands,
+        ModerationCommands, PasswordCommands, ProfileCommands,
+    },
+    client::tools::{
+        print_attachment, print_audit_event, print_diff, print_follow_user, print_moderation_rule,
+        print_one_comment, print_one_post, print_one_post_rendered, print_one_revision,
+        print_reactions, print_search_hit, print_success, print_token_not_set, print_user,
+        print_user_account, print_user_profile, read_tokens, remove_tokens, save_tokens,
+    },
     config::Settings,
 };
-use anyhow::Result as AnyhowResult;
-use blog_client::BlogClient;
+use anyhow::{Context, Result as AnyhowResult};
+use blog_client::{AuditQuery, BlogClient, PublishMode};
+use tokio::fs;
 
 /// Исполнитель пользовательских заданий.
 ///
@@ -25,43 +34,286 @@ pub(crate) async fn execute_request(
     command: &Commands,
     config: &Settings,
 ) -> AnyhowResult<()> {
-    let token_from_file = read_token(&config.app_state.token_full_path).await;
+    let token_file = &config.app_state.token_full_path;
+    let saved = read_tokens(token_file).await;
+    if let Some(refresh_token) = &saved.refresh_token {
+        server.set_refresh_token(refresh_token.clone().into());
+    }
+
+    let result = run_command(server, command, config, saved.token).await;
+
+    // Клиент мог получить новые токены (вход или автоматическое продление
+    // сессии) даже при неудачной команде: прежний токен обновления уже
+    // недействителен, поэтому новые сохраняются в любом случае.
+    if let (Some(token), Some(refresh_token)) = (server.token(), server.refresh_token())
+        && saved.refresh_token.as_deref() != Some(refresh_token.as_str())
+    {
+        save_tokens(token_file, token.as_str(), refresh_token.as_str()).await?;
+    }
+
+    result
+}
 
+/// Выполнить команду пользователя с токеном доступа из файла.
+async fn run_command(
+    server: &mut BlogClient,
+    command: &Commands,
+    config: &Settings,
+    token_from_file: Option<String>,
+) -> AnyhowResult<()> {
     match command {
         Commands::Register {
             username,
             email,
             password,
         } => {
-            let auth = server.register(username, email, password).await?;
-            save_token(&config.app_state.token_full_path, auth.token.as_str()).await?;
+            server.register(username, email, password).await?;
             println!("Пользователь зарегистрирован: {}, {}", username, email);
+            println!("На адрес {} отправлено письмо для подтверждения", email);
         }
 
         Commands::Login { username, password } => {
-            let auth = server.login(username, password).await?;
-            save_token(&config.app_state.token_full_path, auth.token.as_str()).await?;
+            server.login(username, password).await?;
             println!("Пользователь прошёл авторизацию: {}", username);
         }
 
-        Commands::Get { post_id } => {
-            let post = server.get_post(*post_id).await?;
+        Commands::Logout => {
+            server.logout().await?;
+            remove_tokens(&config.app_state.token_full_path).await?;
+            print_success();
+            println!("Сессия завершена");
+        }
+
+        Commands::Password(PasswordCommands::Change {
+            old_password,
+            new_password,
+        }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                server.change_password(old_password, new_password).await?;
+                print_success();
+                println!("Пароль изменён, прочие сессии завершены");
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Password(PasswordCommands::Forgot { email }) => {
+            server.forgot_password(email).await?;
+            print_success();
+            println!(
+                "Если адрес {} зарегистрирован, на него отправлено письмо для сброса пароля",
+                email
+            );
+        }
+
+        Commands::Password(PasswordCommands::Reset {
+            token,
+            new_password,
+        }) => {
+            server.reset_password(token, new_password).await?;
+            print_success();
+            println!("Пароль изменён, выполните вход с новым паролем");
+        }
+
+        Commands::Email(EmailCommands::Verify { token }) => {
+            let user = server.verify_email(token).await?;
+            print_success();
+            println!("Адрес {} подтверждён", user.email);
+        }
+
+        Commands::Email(EmailCommands::Resend) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                server.resend_verification().await?;
+                print_success();
+                println!("Письмо для подтверждения адреса отправлено повторно");
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Profile(ProfileCommands::Show) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let user = server.me().await?;
+                print_success();
+                print_user(&user);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Profile(ProfileCommands::Edit {
+            display_name,
+            bio,
+            avatar_url,
+            email,
+        }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let user = server
+                    .update_profile(
+                        display_name.as_deref(),
+                        bio.as_deref(),
+                        avatar_url.as_deref(),
+                        email.as_deref(),
+                    )
+                    .await?;
+                print_success();
+                print_user(&user);
+                if email.is_some() && !user.email_verified {
+                    println!("На новый адрес отправлено письмо для его подтверждения");
+                }
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Account(AccountCommands::Export { output }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let export = server.export_my_data().await?;
+                let json = serde_json::to_string_pretty(&export)
+                    .context("Не удалось сформировать выгрузку")?;
+
+                match output {
+                    Some(path) => {
+                        fs::write(path, json).await.with_context(|| {
+                            format!("Не удалось записать файл {}", path.display())
+                        })?;
+                        print_success();
+                        println!(
+                            "Выгружено публикаций: {}, файл {}",
+                            export.posts.len(),
+                            path.display()
+                        );
+                    }
+                    None => println!("{json}"),
+                }
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Account(AccountCommands::Delete { password, posts }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                server.delete_my_account(password, posts).await?;
+                remove_tokens(&config.app_state.token_full_path).await?;
+                print_success();
+                println!("Учётная запись удалена");
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Get {
+            post_id,
+            slug,
+            render,
+        } => {
+            // Токен необязателен: с ним автору доступны его черновики.
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+            }
+            let post = match (post_id, slug) {
+                (Some(post_id), _) => server.get_post(*post_id).await?,
+                (None, Some(slug)) => server.get_post_by_slug(slug).await?,
+                (None, None) => unreachable!("clap требует post_id или slug"),
+            };
             print_success();
-            print_one_post(&post);
+            if *render {
+                print_one_post_rendered(&post);
+            } else {
+                print_one_post(&post);
+            }
         }
 
-        Commands::List { limit, offset } => {
-            let posts = server.list_posts(*limit, *offset).await?;
+        Commands::List {
+            limit,
+            offset,
+            tag,
+            after,
+        } => {
+            // Токен необязателен: с ним публикации содержат реакции
+            // пользователя.
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+            }
+            let posts = server
+                .list_posts(*limit, *offset, tag.as_deref(), after.as_deref())
+                .await?;
             print_success();
+            if let Some(total) = posts.total {
+                println!("Всего публикаций: {}\n", total);
+            }
             posts.posts.into_iter().for_each(|post| {
                 print_one_post(&post);
             });
+            if let Some(cursor) = posts.next_cursor {
+                println!("Следующая страница: --after {}", cursor);
+            }
         }
 
-        Commands::Create { title, content } => {
+        Commands::Feed {
+            limit,
+            offset,
+            tag,
+            after,
+        } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let posts = server
+                    .feed(*limit, *offset, tag.as_deref(), after.as_deref())
+                    .await?;
+                print_success();
+                if let Some(total) = posts.total {
+                    println!("Всего публикаций в ленте: {}\n", total);
+                }
+                posts.posts.iter().for_each(print_one_post);
+                if let Some(cursor) = posts.next_cursor {
+                    println!("Следующая страница: --after {}", cursor);
+                }
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Search {
+            query,
+            limit,
+            offset,
+        } => {
+            let results = server.search_posts(query, *limit, *offset).await?;
+            print_success();
+            println!("Найдено публикаций: {}\n", results.total);
+            results.hits.iter().for_each(print_search_hit);
+        }
+
+        Commands::Tags => {
+            let tags = server.list_tags().await?;
+            print_success();
+            tags.tags.into_iter().for_each(|tag| {
+                println!("#{} ({})", tag.name, tag.posts_count);
+            });
+        }
+
+        Commands::Create {
+            title,
+            content,
+            tags,
+            draft,
+            publish_at,
+        } => {
             if let Some(token) = token_from_file {
                 server.set_token(token.into());
-                let post = server.create_post(title, content).await?;
+                let mode = match (draft, publish_at) {
+                    (true, _) => PublishMode::Draft,
+                    (false, Some(ts)) => PublishMode::At(*ts),
+                    (false, None) => PublishMode::Now,
+                };
+                let post = server.create_post(title, content, tags, mode).await?;
                 print_success();
                 print_one_post(&post);
             } else {
@@ -73,11 +325,16 @@ pub(crate) async fn execute_request(
             post_id,
             title,
             content,
+            tags,
+            clear_tags,
         } => {
+            // Теги заменяются, только если пользователь явно их задал.
+            let tags = (*clear_tags || !tags.is_empty()).then_some(tags.as_slice());
+
             if let Some(token) = token_from_file {
                 server.set_token(token.into());
                 let post = server
-                    .update_post(*post_id, title.as_deref(), content.as_deref())
+                    .update_post(*post_id, title.as_deref(), content.as_deref(), tags)
                     .await?;
                 print_success();
                 print_one_post(&post);
@@ -91,7 +348,422 @@ pub(crate) async fn execute_request(
                 server.set_token(token.into());
                 server.delete_post(*post_id).await?;
                 print_success();
-                println!("Пост # {} удалён", post_id);
+                println!("Пост # {} перемещён в корзину", post_id);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Publish { post_id, at } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let post = server.publish_post(*post_id, *at).await?;
+                print_success();
+                print_one_post(&post);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Drafts { limit, offset } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let drafts = server.list_drafts(*limit, *offset).await?;
+                print_success();
+                if let Some(total) = drafts.total {
+                    println!("Всего черновиков и отложенных: {}\n", total);
+                }
+                drafts.posts.iter().for_each(print_one_post);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Trash { limit, offset } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let trash = server.list_trash(*limit, *offset).await?;
+                print_success();
+                if let Some(total) = trash.total {
+                    println!("Всего в корзине: {}\n", total);
+                }
+                trash.posts.iter().for_each(print_one_post);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Author {
+            username,
+            limit,
+            offset,
+        } => {
+            let profile = server.get_user_profile(username).await?;
+            let posts = server.list_user_posts(username, *limit, *offset).await?;
+            print_success();
+            print_user_profile(&profile);
+            posts.posts.iter().for_each(print_one_post);
+        }
+
+        Commands::History {
+            post_id,
+            revision,
+            limit,
+            offset,
+        } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                print_success();
+                if let Some(revision) = revision {
+                    let revision = server.get_revision(*post_id, *revision).await?;
+                    print_one_revision(&revision, true);
+                } else {
+                    let history = server.list_revisions(*post_id, *limit, *offset).await?;
+                    println!("Всего ревизий: {}\n", history.total);
+                    history
+                        .revisions
+                        .iter()
+                        .for_each(|revision| print_one_revision(revision, false));
+                }
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Diff { post_id, from, to } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let diff = server.diff_revisions(*post_id, *from, *to).await?;
+                print_success();
+                print_diff(&diff);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Restore { post_id, revision } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let post = if let Some(revision) = revision {
+                    let post = server.restore_revision(*post_id, *revision).await?;
+                    print_success();
+                    println!("Пост # {} восстановлен из ревизии {}\n", post_id, revision);
+                    post
+                } else {
+                    let post = server.restore_post(*post_id).await?;
+                    print_success();
+                    println!("Пост # {} восстановлен из корзины\n", post_id);
+                    post
+                };
+                print_one_post(&post);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::React {
+            post_id,
+            kind,
+            remove,
+        } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let reactions = if *remove {
+                    server.unreact(*post_id, kind).await?
+                } else {
+                    server.react(*post_id, kind).await?
+                };
+                print_success();
+                print_reactions(&reactions);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Follow { username, remove } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                print_success();
+                if *remove {
+                    server.unfollow(username).await?;
+                    println!("Вы отписались от @{}", username);
+                } else {
+                    server.follow(username).await?;
+                    println!("Вы подписались на @{}", username);
+                }
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Followers {
+            username,
+            following,
+            limit,
+            offset,
+        } => {
+            let (title, follows) = if *following {
+                let follows = server.list_following(username, *limit, *offset).await?;
+                ("Подписок", follows)
+            } else {
+                let follows = server.list_followers(username, *limit, *offset).await?;
+                ("Подписчиков", follows)
+            };
+            print_success();
+            println!("{} у @{}: {}\n", title, username, follows.total);
+            follows.users.iter().for_each(print_follow_user);
+        }
+
+        Commands::Upload { file } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let data = fs::read(file)
+                    .await
+                    .with_context(|| format!("Не удалось прочитать файл {}", file.display()))?;
+                let file_name = file
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+
+                let attachment = server.upload(&file_name, data).await?;
+                print_success();
+                print_attachment(&attachment);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Hide { post_id, remove } => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let post = if *remove {
+                    let post = server.unhide_post(*post_id).await?;
+                    print_success();
+                    println!("Пост # {} снова доступен читателям\n", post_id);
+                    post
+                } else {
+                    let post = server.hide_post(*post_id).await?;
+                    print_success();
+                    println!("Пост # {} скрыт от читателей\n", post_id);
+                    post
+                };
+                print_one_post(&post);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Comment(CommentCommands::Add { post_id, content }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let comment = server.add_comment(*post_id, content).await?;
+                print_success();
+                print_one_comment(&comment);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Comment(CommentCommands::List {
+            post_id,
+            limit,
+            offset,
+        }) => {
+            let comments = server.list_comments(*post_id, *limit, *offset).await?;
+            print_success();
+            comments.comments.into_iter().for_each(|comment| {
+                print_one_comment(&comment);
+            });
+        }
+
+        Commands::Comment(CommentCommands::Delete {
+            post_id,
+            comment_id,
+        }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                server.delete_comment(*post_id, *comment_id).await?;
+                print_success();
+                println!("Комментарий # {} удалён", comment_id);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Admin(AdminCommands::Users { limit, offset }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let users = server.list_users(*limit, *offset).await?;
+                print_success();
+                println!("Всего учётных записей: {}\n", users.total);
+                users.users.iter().for_each(print_user_account);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Admin(AdminCommands::SetRole { username, role }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let account = server.set_user_role(username, role).await?;
+                print_success();
+                print_user_account(&account);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Admin(AdminCommands::Disable { username }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let account = server.set_user_disabled(username, true).await?;
+                print_success();
+                print_user_account(&account);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Admin(AdminCommands::Enable { username }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let account = server.set_user_disabled(username, false).await?;
+                print_success();
+                print_user_account(&account);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Admin(AdminCommands::Unlock { username }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let account = server.unlock_user(username).await?;
+                print_success();
+                print_user_account(&account);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Admin(AdminCommands::Audit {
+            actor,
+            action,
+            target,
+            since,
+            until,
+            limit,
+            offset,
+        }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let query = AuditQuery {
+                    actor: actor.clone(),
+                    action: action.clone(),
+                    target: target.clone(),
+                    since: *since,
+                    until: *until,
+                };
+                let events = server.list_audit_events(&query, *limit, *offset).await?;
+                print_success();
+                println!("Всего записей: {}\n", events.total);
+                events.events.iter().for_each(print_audit_event);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Moderation(ModerationCommands::Rules) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let rules = server.list_moderation_rules().await?;
+                print_success();
+                println!("Всего правил: {}\n", rules.rules.len());
+                rules.rules.iter().for_each(print_moderation_rule);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Moderation(ModerationCommands::AddRule {
+            pattern,
+            kind,
+            action,
+        }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let rule = server
+                    .create_moderation_rule(pattern, kind, action)
+                    .await?;
+                print_success();
+                print_moderation_rule(&rule);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Moderation(ModerationCommands::UpdateRule {
+            rule_id,
+            pattern,
+            kind,
+            action,
+        }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let rule = server
+                    .update_moderation_rule(*rule_id, pattern, kind, action)
+                    .await?;
+                print_success();
+                print_moderation_rule(&rule);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Moderation(ModerationCommands::DeleteRule { rule_id }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                server.delete_moderation_rule(*rule_id).await?;
+                print_success();
+                println!("Правило # {} удалено", rule_id);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Moderation(ModerationCommands::Queue { limit, offset }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let queue = server.list_moderation_queue(*limit, *offset).await?;
+                print_success();
+                if let Some(total) = queue.total {
+                    println!("Всего на проверке: {}\n", total);
+                }
+                queue.posts.iter().for_each(print_one_post);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Moderation(ModerationCommands::Approve { post_id }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let post = server.approve_post(*post_id).await?;
+                print_success();
+                println!("Пост # {} одобрен\n", post_id);
+                print_one_post(&post);
+            } else {
+                print_token_not_set();
+            }
+        }
+
+        Commands::Moderation(ModerationCommands::Reject { post_id }) => {
+            if let Some(token) = token_from_file {
+                server.set_token(token.into());
+                let post = server.reject_post(*post_id).await?;
+                print_success();
+                println!("Пост # {} отклонён и скрыт от читателей\n", post_id);
+                print_one_post(&post);
             } else {
                 print_token_not_set();
             }
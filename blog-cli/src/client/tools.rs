@@ -1,28 +1,51 @@
 //! Поддерживающие инструменты для клиентского модуля.
 use anyhow::{Context, Result as AnyhowResult};
-use blog_client::Post;
-use std::path::Path;
+use blog_client::{
+    Attachment, AuditEvent, Comment, DiffLine, DiffRevisionsResponse, FollowUser, ModerationRule,
+    Post, PostRevision, PostSearchHit, ReactionCount, ReactionsResponse, User, UserAccount,
+    UserProfile,
+};
+use std::{
+    io::{ErrorKind, IsTerminal},
+    path::Path,
+};
 use tokio::{
-    fs::{read_to_string, OpenOptions},
+    fs::{read_to_string, remove_file, OpenOptions},
     io::AsyncWriteExt,
 };
 
 use commons::string_from_timestamp;
 
-/// Загрузить токен из файла.
-pub(super) async fn read_token(token_file: &Path) -> Option<String> {
-    let content = read_to_string(token_file).await.ok()?;
-    let content = content.trim().to_string();
+use crate::client::markdown::render_terminal;
 
-    if content.is_empty() {
-        None
-    } else {
-        Some(content)
+/// Токены сессии, сохранённые в файле.
+#[derive(Debug, Default)]
+pub(super) struct SavedTokens {
+    /// Токен доступа.
+    pub(super) token: Option<String>,
+    /// Токен обновления.
+    pub(super) refresh_token: Option<String>,
+}
+
+/// Загрузить токены из файла: в первой строке токен доступа, во второй —
+/// токен обновления.
+pub(super) async fn read_tokens(token_file: &Path) -> SavedTokens {
+    let Ok(content) = read_to_string(token_file).await else {
+        return SavedTokens::default();
+    };
+    let mut lines = content
+        .lines()
+        .map(str::trim)
+        .map(|line| (!line.is_empty()).then(|| line.to_string()));
+
+    SavedTokens {
+        token: lines.next().flatten(),
+        refresh_token: lines.next().flatten(),
     }
 }
 
-/// Сохранить токен в файл.
-pub(super) async fn save_token(file: &Path, token: &str) -> AnyhowResult<()> {
+/// Сохранить токены в файл.
+pub(super) async fn save_tokens(file: &Path, token: &str, refresh_token: &str) -> AnyhowResult<()> {
     let mut f = OpenOptions::new()
         .create(true)
         .write(true)
@@ -31,16 +54,39 @@ pub(super) async fn save_token(file: &Path, token: &str) -> AnyhowResult<()> {
         .await
         .with_context(|| "Токен не удалось сохранить в файл")?;
 
-    f.write_all(token.as_bytes()).await?;
+    f.write_all(format!("{token}\n{refresh_token}\n").as_bytes())
+        .await?;
     Ok(())
 }
 
+/// Удалить файл с токенами.
+pub(super) async fn remove_tokens(file: &Path) -> AnyhowResult<()> {
+    match remove_file(file).await {
+        Err(err) if err.kind() != ErrorKind::NotFound => {
+            Err(err).with_context(|| "Файл с токенами не удалось удалить")
+        }
+        _ => Ok(()),
+    }
+}
+
 pub(super) fn print_success() {
     println!("УСПЕШНО\n");
 }
 
 /// Вывести в консоль сообщение с одной публикацией.
 pub(super) fn print_one_post(post: &Post) {
+    print_post_with_content(post, &post.content);
+}
+
+/// Вывести в консоль публикацию, содержание которой отрисовано для
+/// терминала.
+pub(super) fn print_one_post_rendered(post: &Post) {
+    let content = render_terminal(&post.content, std::io::stdout().is_terminal());
+    print_post_with_content(post, &content);
+}
+
+/// Вывести в консоль публикацию с заданным представлением содержания.
+fn print_post_with_content(post: &Post, content: &str) {
     let created_at =
         string_from_timestamp(post.created_at, true).unwrap_or_else(|| "неизвестно".to_string());
 
@@ -50,16 +96,313 @@ pub(super) fn print_one_post(post: &Post) {
         .map(|upd| format!(" (обновлено: {upd})"))
         .unwrap_or_default();
 
+    let status = match post.status.as_str() {
+        _ if post.deleted_at.is_some() => {
+            let deleted_at = post
+                .deleted_at
+                .and_then(|ts| string_from_timestamp(ts, true))
+                .unwrap_or_else(|| "неизвестно".to_string());
+            format!(" [в корзине с {deleted_at}]")
+        }
+        "draft" => " [черновик]".to_string(),
+        "pending" => " [на проверке у модератора]".to_string(),
+        "scheduled" => {
+            let publish_at = post
+                .publish_at
+                .and_then(|ts| string_from_timestamp(ts, true))
+                .unwrap_or_else(|| "неизвестно".to_string());
+            format!(" [публикация: {publish_at}]")
+        }
+        _ => String::new(),
+    };
+
+    let tags = if post.tags.is_empty() {
+        String::new()
+    } else {
+        let tags: Vec<String> = post.tags.iter().map(|t| format!("#{t}")).collect();
+        format!("\n{}", tags.join(" "))
+    };
+
+    let slug = if post.slug.is_empty() {
+        String::new()
+    } else {
+        format!(" /{}", post.slug)
+    };
+
+    let author = if post.author_username.is_empty() {
+        String::new()
+    } else {
+        format!(" @{}", post.author_username)
+    };
+
+    let reactions = if post.reactions.is_empty() {
+        String::new()
+    } else {
+        format!("\n{}", format_reactions(&post.reactions, &post.my_reactions))
+    };
+
     let message = format!(
-        "[id {}] {}{}\n\
+        "[id {}]{}{} {}{}{}\n\
          {}\n\
-         {}",
-        post.id, created_at, updated_at, post.title, post.content
+         {}{}{}",
+        post.id,
+        slug,
+        author,
+        created_at,
+        updated_at,
+        status,
+        post.title,
+        content,
+        tags,
+        reactions
     );
 
     println!("{message}\n");
 }
 
+/// Вывести в консоль сводку реакций на публикацию.
+pub(super) fn print_reactions(reactions: &ReactionsResponse) {
+    if reactions.reactions.is_empty() {
+        println!("Пост # {}: реакций нет", reactions.post_id);
+    } else {
+        println!(
+            "Пост # {}: {}",
+            reactions.post_id,
+            format_reactions(&reactions.reactions, &reactions.my_reactions)
+        );
+    }
+}
+
+/// Представить реакции строкой вида `like 3 (вы) · wow 1`: реакции
+/// пользователя отмечаются `(вы)`.
+fn format_reactions(reactions: &[ReactionCount], my_reactions: &[String]) -> String {
+    reactions
+        .iter()
+        .map(|r| {
+            if my_reactions.contains(&r.kind) {
+                format!("{} {} (вы)", r.kind, r.count)
+            } else {
+                format!("{} {}", r.kind, r.count)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
+/// Вывести в консоль публичный профиль автора.
+pub(super) fn print_user_profile(profile: &UserProfile) {
+    let joined_at =
+        string_from_timestamp(profile.joined_at, true).unwrap_or_else(|| "неизвестно".to_string());
+
+    if !profile.display_name.is_empty() {
+        println!("{}", profile.display_name);
+    }
+    println!(
+        "@{} (с {}, публикаций: {})",
+        profile.username, joined_at, profile.posts_count
+    );
+    if !profile.avatar_url.is_empty() {
+        println!("Аватар: {}", profile.avatar_url);
+    }
+    if !profile.bio.is_empty() {
+        println!("{}", profile.bio);
+    }
+    println!();
+}
+
+/// Вывести в консоль профиль текущего пользователя.
+pub(super) fn print_user(user: &User) {
+    let email_status = if user.email_verified {
+        "подтверждён"
+    } else {
+        "не подтверждён"
+    };
+
+    println!(
+        "# {} @{} <{}> ({}) роль: {}",
+        user.id, user.username, user.email, email_status, user.role
+    );
+    if !user.display_name.is_empty() {
+        println!("Имя: {}", user.display_name);
+    }
+    if !user.avatar_url.is_empty() {
+        println!("Аватар: {}", user.avatar_url);
+    }
+    if !user.bio.is_empty() {
+        println!("{}", user.bio);
+    }
+}
+
+/// Вывести в консоль учётную запись пользователя для администратора.
+pub(super) fn print_user_account(account: &UserAccount) {
+    let created_at =
+        string_from_timestamp(account.created_at, true).unwrap_or_else(|| "неизвестно".to_string());
+    let mut status = account
+        .disabled_at
+        .map(|at| {
+            let disabled_at =
+                string_from_timestamp(at, true).unwrap_or_else(|| "неизвестно".to_string());
+            format!(", заблокирован с {disabled_at}")
+        })
+        .unwrap_or_default();
+    if let Some(until) = account.locked_until {
+        let locked_until =
+            string_from_timestamp(until, true).unwrap_or_else(|| "неизвестно".to_string());
+        status.push_str(&format!(", вход заблокирован до {locked_until}"));
+    }
+
+    println!(
+        "# {} @{} <{}> роль: {} (с {}{})",
+        account.id, account.username, account.email, account.role, created_at, status
+    );
+}
+
+/// Вывести в консоль запись журнала аудита: время, участника, действие,
+/// объект и сведения о запросе, а затем состояние объекта до и после.
+pub(super) fn print_audit_event(event: &AuditEvent) {
+    let created_at =
+        string_from_timestamp(event.created_at, true).unwrap_or_else(|| "неизвестно".to_string());
+    let target = event
+        .target
+        .as_deref()
+        .map(|target| format!(" {target}"))
+        .unwrap_or_default();
+
+    println!(
+        "# {} [{}] @{} {}{}",
+        event.id, created_at, event.actor, event.action, target
+    );
+
+    let request = [
+        event.ip.as_deref().map(|ip| format!("ip: {ip}")),
+        event
+            .request_id
+            .as_deref()
+            .map(|id| format!("запрос: {id}")),
+        event
+            .user_agent
+            .as_deref()
+            .map(|ua| format!("клиент: {ua}")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    if !request.is_empty() {
+        println!("  {}", request.join(", "));
+    }
+    if let Some(before) = &event.before {
+        println!("  до: {before}");
+    }
+    if let Some(after) = &event.after {
+        println!("  после: {after}");
+    }
+}
+
+/// Вывести в консоль правило модерации.
+pub(super) fn print_moderation_rule(rule: &ModerationRule) {
+    let action = match rule.action.as_str() {
+        "hold" => "на проверку",
+        "reject" => "отклонить",
+        other => other,
+    };
+
+    println!("# {} [{}] {}: {}", rule.id, rule.kind, action, rule.pattern);
+}
+
+/// Вывести в консоль пользователя из перечня подписчиков или подписок.
+pub(super) fn print_follow_user(user: &FollowUser) {
+    let followed_at =
+        string_from_timestamp(user.followed_at, true).unwrap_or_else(|| "неизвестно".to_string());
+
+    println!("@{} (с {})", user.username, followed_at);
+}
+
+/// Вывести в консоль сведения о загруженном вложении и Markdown-разметку
+/// для вставки в публикацию.
+pub(super) fn print_attachment(attachment: &Attachment) {
+    println!("id: {}", attachment.id);
+    println!("Файл: {}", attachment.file_name);
+    println!("Тип: {}, {} байт", attachment.content_type, attachment.size);
+    if let (Some(width), Some(height)) = (attachment.width, attachment.height) {
+        println!("Размеры: {width}×{height}");
+    }
+    println!("Адрес: {}", attachment.url);
+
+    let markup = if attachment.content_type.starts_with("image/") {
+        format!("![{}]({})", attachment.file_name, attachment.url)
+    } else {
+        format!("[{}]({})", attachment.file_name, attachment.url)
+    };
+    println!("\nMarkdown: {markup}");
+}
+
+/// Вывести в консоль результат поиска: заголовок публикации и фрагмент
+/// текста, где совпадения выделены `*`.
+pub(super) fn print_search_hit(hit: &PostSearchHit) {
+    let Some(post) = &hit.post else {
+        return;
+    };
+
+    let snippet = hit.snippet.replace("<mark>", "*").replace("</mark>", "*");
+
+    println!("[id {}] {}\n{}\n", post.id, post.title, snippet);
+}
+
+/// Вывести в консоль ревизию публикации: заголовок, а при `full` — также
+/// содержание и теги.
+pub(super) fn print_one_revision(revision: &PostRevision, full: bool) {
+    let created_at = string_from_timestamp(revision.created_at, true)
+        .unwrap_or_else(|| "неизвестно".to_string());
+
+    println!(
+        "[ревизия {}] {}\n{}",
+        revision.revision, created_at, revision.title
+    );
+
+    if full {
+        println!("{}", revision.content);
+        if !revision.tags.is_empty() {
+            let tags: Vec<String> = revision.tags.iter().map(|t| format!("#{t}")).collect();
+            println!("{}", tags.join(" "));
+        }
+    }
+
+    println!();
+}
+
+/// Вывести в консоль построчное сравнение ревизий: добавленные строки
+/// помечаются `+`, удалённые — `-`.
+pub(super) fn print_diff(diff: &DiffRevisionsResponse) {
+    /// Вывести строки одного поля.
+    fn print_lines(lines: &[DiffLine]) {
+        for line in lines {
+            let mark = match line.op.as_str() {
+                "insert" => '+',
+                "delete" => '-',
+                _ => ' ',
+            };
+            println!("{mark} {}", line.text);
+        }
+    }
+
+    println!("Ревизии {} → {}\n", diff.from, diff.to);
+    println!("Заголовок:");
+    print_lines(&diff.title);
+    println!("\nСодержание:");
+    print_lines(&diff.content);
+}
+
+/// Вывести в консоль сообщение с одним комментарием.
+pub(super) fn print_one_comment(comment: &Comment) {
+    let created_at =
+        string_from_timestamp(comment.created_at, true).unwrap_or_else(|| "неизвестно".to_string());
+
+    println!(
+        "[id {}] {} (автор: {})\n{}\n",
+        comment.id, created_at, comment.author_id, comment.content
+    );
+}
+
 pub(super) fn print_token_not_set() {
     println!("Не выполнено. Отсутствует токен авторизации")
 }
@@ -4,15 +4,33 @@ use crate::{
     BlogClientError,
     clients::{
         models::{
-            PostCreateCmd, PostId, PostResponseWrap, PostUpdateCmd, UserAuthCmd, UserRegisterCmd,
+            AccountDeleteCmd, CommentCreateCmd, EmailVerifyCmd, ModerationRuleCmd,
+            PasswordChangeCmd, PasswordForgotCmd, PasswordResetCmd, PostCreateCmd, PostId,
+            PostPublishCmd, PostResponseWrap, PostUpdateCmd, ProfileUpdateCmd, RefreshCmd,
+            UserAuthCmd, UserRegisterCmd, UserRoleCmd,
         },
         traits::ClientTransportExt,
     },
-    models::{AuthResponse, Token},
+    models::{AccountExport, AuditQuery, AuthResponse, Token},
 };
 use proto_crate::proto_blog::{
-    CreatePostRequest, DeletePostRequest, GetPostRequest, ListPostsRequest, ListPostsResponse,
-    LoginRequest, Post, RegisterRequest, UpdatePostRequest, blog_service_client::BlogServiceClient,
+    AddCommentRequest, Attachment, ChangePasswordRequest, Comment, CreateModerationRuleRequest,
+    CreatePostRequest, DeleteCommentRequest, DeleteModerationRuleRequest, DeleteMyAccountRequest,
+    DeletePostRequest, DiffRevisionsRequest, DiffRevisionsResponse, ExportMyDataRequest,
+    FollowRequest, GetMeRequest, GetPostBySlugRequest, GetPostRequest, GetRevisionRequest,
+    GetUserProfileRequest, HidePostRequest, ListAuditEventsRequest, ListAuditEventsResponse,
+    ListCommentsRequest, ListCommentsResponse, ListDraftsRequest, ListFollowsRequest,
+    ListFollowsResponse, ListModerationQueueRequest, ListModerationRulesRequest,
+    ListModerationRulesResponse, ListPostsRequest, ListPostsResponse, ListRevisionsRequest,
+    ListRevisionsResponse, ListTagsRequest, ListTagsResponse, ListTrashRequest,
+    ListUserPostsRequest, ListUsersRequest, ListUsersResponse, LoginRequest, LogoutRequest,
+    ModerationRule, ModerationRuleResponse, Post, PostRevision, PublishPostRequest, ReactRequest,
+    ReactionsResponse, RefreshRequest, RegisterRequest, RequestPasswordResetRequest,
+    ResendVerificationRequest, ResetPasswordRequest, RestorePostRequest, RestoreRevisionRequest,
+    SearchPostsRequest, SearchPostsResponse, SetUserDisabledRequest, SetUserRoleRequest,
+    UnlockUserRequest, UpdatePostRequest, UpdateProfileRequest, UploadAttachmentRequest, User,
+    UserAccount, UserAccountResponse, UserProfile, VerifyEmailRequest,
+    blog_service_client::BlogServiceClient, export_my_data_response::Item,
 };
 use tonic::{
     Request, Status, async_trait,
@@ -20,12 +38,17 @@ use tonic::{
     transport::{Channel, Endpoint, Uri},
 };
 
+/// Размер части файла в одном сообщении потока загрузки вложения (байты).
+const UPLOAD_CHUNK_BYTES: usize = 64 * 1024;
+
 /// Перечисление для адаптера регистрации и аутентификации.
 enum AuthCmd {
     /// Регистрация пользователя.
     Register(RegisterRequest),
     /// Авторизация пользователя.
     Login(LoginRequest),
+    /// Обновление сессии.
+    Refresh(RefreshRequest),
 }
 
 /// Клиент для взаимодействия с gRPC-сервером.
@@ -56,6 +79,7 @@ impl GrpcClient {
         let auth_user = match cmd {
             AuthCmd::Register(req) => client.register(Request::new(req)).await?.into_inner(),
             AuthCmd::Login(req) => client.login(Request::new(req)).await?.into_inner(),
+            AuthCmd::Refresh(req) => client.refresh(Request::new(req)).await?.into_inner(),
         };
 
         Ok(auth_user.into())
@@ -95,6 +119,149 @@ impl ClientTransportExt for GrpcClient {
         self.authenticate(AuthCmd::Login(cmd.into())).await
     }
 
+    async fn refresh_session(&self, cmd: RefreshCmd) -> Result<AuthResponse, Self::Error> {
+        self.authenticate(AuthCmd::Refresh(cmd.into())).await
+    }
+
+    async fn logout(&self, cmd: RefreshCmd) -> Result<(), Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        client
+            .logout(Request::new(LogoutRequest::from(cmd)))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn change_password(
+        &self,
+        cmd: PasswordChangeCmd,
+        token: &Token,
+    ) -> Result<AuthResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ChangePasswordRequest::from(cmd));
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.change_password(request).await?.into_inner().into())
+    }
+
+    async fn forgot_password(&self, cmd: PasswordForgotCmd) -> Result<(), Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        client
+            .request_password_reset(Request::new(RequestPasswordResetRequest::from(cmd)))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn reset_password(&self, cmd: PasswordResetCmd) -> Result<(), Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        client
+            .reset_password(Request::new(ResetPasswordRequest::from(cmd)))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn verify_email(&self, cmd: EmailVerifyCmd) -> Result<User, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        client
+            .verify_email(Request::new(VerifyEmailRequest::from(cmd)))
+            .await?
+            .into_inner()
+            .user
+            .ok_or(BlogClientError::GrpcError(Status::data_loss(
+                "Данные о пользователе отсутствуют (не переданы сервером)",
+            )))
+    }
+
+    async fn resend_verification(&self, token: &Token) -> Result<(), Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ResendVerificationRequest {});
+        self.add_token_to_req(&mut request, token)?;
+        client.resend_verification(request).await?;
+
+        Ok(())
+    }
+
+    async fn get_me(&self, token: &Token) -> Result<User, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(GetMeRequest {});
+        self.add_token_to_req(&mut request, token)?;
+
+        client
+            .get_me(request)
+            .await?
+            .into_inner()
+            .user
+            .ok_or(BlogClientError::GrpcError(Status::data_loss(
+                "Данные о пользователе отсутствуют (не переданы сервером)",
+            )))
+    }
+
+    async fn update_profile(
+        &self,
+        cmd: ProfileUpdateCmd,
+        token: &Token,
+    ) -> Result<User, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(UpdateProfileRequest::from(cmd));
+        self.add_token_to_req(&mut request, token)?;
+
+        client
+            .update_profile(request)
+            .await?
+            .into_inner()
+            .user
+            .ok_or(BlogClientError::GrpcError(Status::data_loss(
+                "Данные о пользователе отсутствуют (не переданы сервером)",
+            )))
+    }
+
+    async fn export_my_data(&self, token: &Token) -> Result<AccountExport, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ExportMyDataRequest {});
+        self.add_token_to_req(&mut request, token)?;
+        let mut stream = client.export_my_data(request).await?.into_inner();
+
+        let mut account = None;
+        let mut posts = Vec::new();
+        while let Some(message) = stream.message().await? {
+            match message.item {
+                Some(Item::Account(data)) => account = Some(data),
+                Some(Item::Post(post)) => posts.push(post),
+                None => {}
+            }
+        }
+        let account = account.ok_or(BlogClientError::GrpcError(Status::data_loss(
+            "Данные учётной записи отсутствуют (не переданы сервером)",
+        )))?;
+
+        Ok(AccountExport { account, posts })
+    }
+
+    async fn delete_my_account(
+        &self,
+        cmd: AccountDeleteCmd,
+        token: &Token,
+    ) -> Result<(), Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(DeleteMyAccountRequest::from(cmd));
+        self.add_token_to_req(&mut request, token)?;
+        client.delete_my_account(request).await?;
+
+        Ok(())
+    }
+
     async fn create_post(&self, cmd: PostCreateCmd, token: &Token) -> Result<Post, Self::Error> {
         let mut client = self.get_service_client().await;
 
@@ -106,15 +273,36 @@ impl ClientTransportExt for GrpcClient {
         post.get_post_or_loss()
     }
 
-    async fn get_post(&self, post_id: PostId) -> Result<Post, Self::Error> {
+    async fn get_post(&self, post_id: PostId, token: Option<&Token>) -> Result<Post, Self::Error> {
         let mut client = self.get_service_client().await;
 
-        let post_req = Request::new(GetPostRequest { id: post_id.into() });
+        let mut post_req = Request::new(GetPostRequest { id: post_id.into() });
+        if let Some(token) = token {
+            self.add_token_to_req(&mut post_req, token)?;
+        }
         let post: PostResponseWrap = client.get_post(post_req).await?.into_inner().into();
 
         post.get_post_or_loss()
     }
 
+    async fn get_post_by_slug(
+        &self,
+        slug: &str,
+        token: Option<&Token>,
+    ) -> Result<Post, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(GetPostBySlugRequest {
+            slug: slug.to_string(),
+        });
+        if let Some(token) = token {
+            self.add_token_to_req(&mut request, token)?;
+        }
+        let post: PostResponseWrap = client.get_post_by_slug(request).await?.into_inner().into();
+
+        post.get_post_or_loss()
+    }
+
     async fn update_post(&self, cmd: PostUpdateCmd, token: &Token) -> Result<Post, Self::Error> {
         let mut client = self.get_service_client().await;
 
@@ -138,13 +326,123 @@ impl ClientTransportExt for GrpcClient {
         Ok(())
     }
 
-    async fn list_posts(&self, limit: u32, offset: u32) -> Result<ListPostsResponse, Self::Error> {
-        fn u32_to_i32(n: u32, name: &str) -> Result<i32, BlogClientError> {
-            i32::try_from(n).map_err(|_| {
-                BlogClientError::invalid_req(format!("слишком большое значение '{}': {}", name, n))
-            })
-        }
+    async fn publish_post(&self, cmd: PostPublishCmd, token: &Token) -> Result<Post, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(PublishPostRequest::from(cmd));
+        self.add_token_to_req(&mut request, token)?;
+
+        let post: PostResponseWrap = client.publish_post(request).await?.into_inner().into();
+
+        post.get_post_or_loss()
+    }
+
+    async fn list_drafts(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ListDraftsRequest {
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.list_drafts(request).await?.into_inner())
+    }
+
+    async fn list_trash(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ListTrashRequest {
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.list_trash(request).await?.into_inner())
+    }
+
+    async fn get_user_profile(&self, username: &str) -> Result<UserProfile, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let request = Request::new(GetUserProfileRequest {
+            username: username.to_string(),
+        });
+
+        client
+            .get_user_profile(request)
+            .await?
+            .into_inner()
+            .profile
+            .ok_or(BlogClientError::GrpcError(Status::data_loss(
+                "Данные о профиле отсутствуют (не переданы сервером)",
+            )))
+    }
+
+    async fn list_user_posts(
+        &self,
+        username: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListPostsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let request = Request::new(ListUserPostsRequest {
+            username: username.to_string(),
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        });
 
+        Ok(client.list_user_posts(request).await?.into_inner())
+    }
+
+    async fn restore_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(RestorePostRequest { id: post_id.into() });
+        self.add_token_to_req(&mut request, token)?;
+
+        let post: PostResponseWrap = client.restore_post(request).await?.into_inner().into();
+        post.get_post_or_loss()
+    }
+
+    async fn hide_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(HidePostRequest { id: post_id.into() });
+        self.add_token_to_req(&mut request, token)?;
+
+        let post: PostResponseWrap = client.hide_post(request).await?.into_inner().into();
+        post.get_post_or_loss()
+    }
+
+    async fn unhide_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(HidePostRequest { id: post_id.into() });
+        self.add_token_to_req(&mut request, token)?;
+
+        let post: PostResponseWrap = client.unhide_post(request).await?.into_inner().into();
+        post.get_post_or_loss()
+    }
+
+    async fn list_posts(
+        &self,
+        limit: u32,
+        offset: u32,
+        tag: Option<&str>,
+        after: Option<&str>,
+        token: Option<&Token>,
+    ) -> Result<ListPostsResponse, Self::Error> {
         let limit_i32 = u32_to_i32(limit, "limit")?;
         let offset_i32: i32 = u32_to_i32(offset, "offset")?;
 
@@ -152,11 +450,537 @@ impl ClientTransportExt for GrpcClient {
         let posts_req = ListPostsRequest {
             limit: limit_i32,
             offset: offset_i32,
+            tag: tag.map(String::from),
+            after: after.map(String::from),
         };
-        let request = Request::new(posts_req);
+        let mut request = Request::new(posts_req);
+        if let Some(token) = token {
+            self.add_token_to_req(&mut request, token)?;
+        }
 
         Ok(client.list_posts(request).await?.into_inner())
     }
+
+    async fn list_tags(&self) -> Result<ListTagsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        Ok(client
+            .list_tags(Request::new(ListTagsRequest {}))
+            .await?
+            .into_inner())
+    }
+
+    async fn search_posts(
+        &self,
+        query: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<SearchPostsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+        let search_req = SearchPostsRequest {
+            query: query.to_string(),
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        };
+
+        Ok(client
+            .search_posts(Request::new(search_req))
+            .await?
+            .into_inner())
+    }
+
+    async fn add_comment(
+        &self,
+        cmd: CommentCreateCmd,
+        token: &Token,
+    ) -> Result<Comment, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(AddCommentRequest::from(cmd));
+        self.add_token_to_req(&mut request, token)?;
+
+        client
+            .add_comment(request)
+            .await?
+            .into_inner()
+            .comment
+            .ok_or(BlogClientError::GrpcError(Status::data_loss(
+                "Данные о комментарии отсутствуют (не переданы сервером)",
+            )))
+    }
+
+    async fn list_comments(
+        &self,
+        post_id: PostId,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListCommentsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+        let comments_req = ListCommentsRequest {
+            post_id: post_id.into(),
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        };
+
+        Ok(client
+            .list_comments(Request::new(comments_req))
+            .await?
+            .into_inner())
+    }
+
+    async fn delete_comment(
+        &self,
+        post_id: PostId,
+        comment_id: i64,
+        token: &Token,
+    ) -> Result<(), Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let comment_req = DeleteCommentRequest {
+            post_id: post_id.into(),
+            id: comment_id,
+        };
+        let mut request = Request::new(comment_req);
+        self.add_token_to_req(&mut request, token)?;
+
+        client.delete_comment(request).await?;
+
+        Ok(())
+    }
+
+    async fn list_revisions(
+        &self,
+        post_id: PostId,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListRevisionsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ListRevisionsRequest {
+            post_id: post_id.into(),
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.list_revisions(request).await?.into_inner())
+    }
+
+    async fn get_revision(
+        &self,
+        post_id: PostId,
+        revision: i32,
+        token: &Token,
+    ) -> Result<PostRevision, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(GetRevisionRequest {
+            post_id: post_id.into(),
+            revision,
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        client
+            .get_revision(request)
+            .await?
+            .into_inner()
+            .revision
+            .ok_or(BlogClientError::GrpcError(Status::data_loss(
+                "Данные о ревизии отсутствуют (не переданы сервером)",
+            )))
+    }
+
+    async fn diff_revisions(
+        &self,
+        post_id: PostId,
+        from: i32,
+        to: i32,
+        token: &Token,
+    ) -> Result<DiffRevisionsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(DiffRevisionsRequest {
+            post_id: post_id.into(),
+            from,
+            to,
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.diff_revisions(request).await?.into_inner())
+    }
+
+    async fn restore_revision(
+        &self,
+        post_id: PostId,
+        revision: i32,
+        token: &Token,
+    ) -> Result<Post, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(RestoreRevisionRequest {
+            post_id: post_id.into(),
+            revision,
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        let post: PostResponseWrap = client.restore_revision(request).await?.into_inner().into();
+        post.get_post_or_loss()
+    }
+
+    async fn react(
+        &self,
+        post_id: PostId,
+        kind: &str,
+        token: &Token,
+    ) -> Result<ReactionsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ReactRequest {
+            post_id: post_id.into(),
+            kind: kind.to_string(),
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.react(request).await?.into_inner())
+    }
+
+    async fn unreact(
+        &self,
+        post_id: PostId,
+        kind: &str,
+        token: &Token,
+    ) -> Result<ReactionsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ReactRequest {
+            post_id: post_id.into(),
+            kind: kind.to_string(),
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.unreact(request).await?.into_inner())
+    }
+
+    async fn follow(&self, username: &str, token: &Token) -> Result<(), Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(FollowRequest {
+            username: username.to_string(),
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        client.follow(request).await?;
+
+        Ok(())
+    }
+
+    async fn unfollow(&self, username: &str, token: &Token) -> Result<(), Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(FollowRequest {
+            username: username.to_string(),
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        client.unfollow(request).await?;
+
+        Ok(())
+    }
+
+    async fn list_followers(
+        &self,
+        username: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListFollowsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let request = Request::new(ListFollowsRequest {
+            username: username.to_string(),
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        });
+
+        Ok(client.list_followers(request).await?.into_inner())
+    }
+
+    async fn list_following(
+        &self,
+        username: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListFollowsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let request = Request::new(ListFollowsRequest {
+            username: username.to_string(),
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        });
+
+        Ok(client.list_following(request).await?.into_inner())
+    }
+
+    async fn feed(
+        &self,
+        limit: u32,
+        offset: u32,
+        tag: Option<&str>,
+        after: Option<&str>,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ListPostsRequest {
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+            tag: tag.map(String::from),
+            after: after.map(String::from),
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.feed(request).await?.into_inner())
+    }
+
+    async fn upload(
+        &self,
+        file_name: &str,
+        data: &[u8],
+        token: &Token,
+    ) -> Result<Attachment, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        // Имя файла передаётся в первом сообщении; пустой файл отправляется
+        // одним сообщением, чтобы сервер вернул понятную ошибку.
+        let parts: Vec<UploadAttachmentRequest> = data
+            .chunks(UPLOAD_CHUNK_BYTES)
+            .enumerate()
+            .map(|(i, chunk)| UploadAttachmentRequest {
+                file_name: if i == 0 {
+                    file_name.to_string()
+                } else {
+                    String::new()
+                },
+                chunk: chunk.to_vec(),
+            })
+            .collect();
+        let parts = if parts.is_empty() {
+            vec![UploadAttachmentRequest {
+                file_name: file_name.to_string(),
+                chunk: Vec::new(),
+            }]
+        } else {
+            parts
+        };
+
+        let mut request = Request::new(tokio_stream::iter(parts));
+        self.add_token_to_req(&mut request, token)?;
+
+        client
+            .upload_attachment(request)
+            .await?
+            .into_inner()
+            .attachment
+            .ok_or(BlogClientError::GrpcError(Status::data_loss(
+                "Данные о вложении отсутствуют (не переданы сервером)",
+            )))
+    }
+
+    async fn list_users(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListUsersResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ListUsersRequest {
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.list_users(request).await?.into_inner())
+    }
+
+    async fn set_user_role(
+        &self,
+        cmd: UserRoleCmd,
+        token: &Token,
+    ) -> Result<UserAccount, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(SetUserRoleRequest::from(cmd));
+        self.add_token_to_req(&mut request, token)?;
+
+        account_or_loss(client.set_user_role(request).await?.into_inner())
+    }
+
+    async fn set_user_disabled(
+        &self,
+        username: &str,
+        disabled: bool,
+        token: &Token,
+    ) -> Result<UserAccount, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(SetUserDisabledRequest {
+            username: username.to_string(),
+            disabled,
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        account_or_loss(client.set_user_disabled(request).await?.into_inner())
+    }
+
+    async fn unlock_user(&self, username: &str, token: &Token) -> Result<UserAccount, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(UnlockUserRequest {
+            username: username.to_string(),
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        account_or_loss(client.unlock_user(request).await?.into_inner())
+    }
+
+    async fn list_audit_events(
+        &self,
+        query: &AuditQuery,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListAuditEventsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ListAuditEventsRequest {
+            actor: query.actor.clone(),
+            action: query.action.clone(),
+            target: query.target.clone(),
+            since: query.since,
+            until: query.until,
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.list_audit_events(request).await?.into_inner())
+    }
+
+    async fn list_moderation_rules(
+        &self,
+        token: &Token,
+    ) -> Result<ListModerationRulesResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ListModerationRulesRequest {});
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.list_moderation_rules(request).await?.into_inner())
+    }
+
+    async fn create_moderation_rule(
+        &self,
+        cmd: ModerationRuleCmd,
+        token: &Token,
+    ) -> Result<ModerationRule, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(CreateModerationRuleRequest::from(cmd));
+        self.add_token_to_req(&mut request, token)?;
+
+        rule_or_loss(client.create_moderation_rule(request).await?.into_inner())
+    }
+
+    async fn update_moderation_rule(
+        &self,
+        rule_id: i64,
+        cmd: ModerationRuleCmd,
+        token: &Token,
+    ) -> Result<ModerationRule, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(cmd.into_update(rule_id));
+        self.add_token_to_req(&mut request, token)?;
+
+        rule_or_loss(client.update_moderation_rule(request).await?.into_inner())
+    }
+
+    async fn delete_moderation_rule(
+        &self,
+        rule_id: i64,
+        token: &Token,
+    ) -> Result<(), Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(DeleteModerationRuleRequest { id: rule_id });
+        self.add_token_to_req(&mut request, token)?;
+
+        client.delete_moderation_rule(request).await?;
+
+        Ok(())
+    }
+
+    async fn list_moderation_queue(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(ListModerationQueueRequest {
+            limit: u32_to_i32(limit, "limit")?,
+            offset: u32_to_i32(offset, "offset")?,
+        });
+        self.add_token_to_req(&mut request, token)?;
+
+        Ok(client.list_moderation_queue(request).await?.into_inner())
+    }
+
+    async fn approve_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(HidePostRequest { id: post_id.into() });
+        self.add_token_to_req(&mut request, token)?;
+
+        let post: PostResponseWrap = client.approve_post(request).await?.into_inner().into();
+        post.get_post_or_loss()
+    }
+
+    async fn reject_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error> {
+        let mut client = self.get_service_client().await;
+
+        let mut request = Request::new(HidePostRequest { id: post_id.into() });
+        self.add_token_to_req(&mut request, token)?;
+
+        let post: PostResponseWrap = client.reject_post(request).await?.into_inner().into();
+        post.get_post_or_loss()
+    }
+}
+
+/// Извлечь учётную запись из ответа сервера.
+fn account_or_loss(resp: UserAccountResponse) -> Result<UserAccount, BlogClientError> {
+    resp.account
+        .ok_or(BlogClientError::GrpcError(Status::data_loss(
+            "Данные об учётной записи отсутствуют (не переданы сервером)",
+        )))
+}
+
+/// Извлечь правило модерации из ответа сервера.
+fn rule_or_loss(resp: ModerationRuleResponse) -> Result<ModerationRule, BlogClientError> {
+    resp.rule
+        .ok_or(BlogClientError::GrpcError(Status::data_loss(
+            "Данные о правиле модерации отсутствуют (не переданы сервером)",
+        )))
+}
+
+/// Преобразовать `u32` в `i32`, поддерживаемый gRPC-сервером, с проверкой.
+fn u32_to_i32(n: u32, name: &str) -> Result<i32, BlogClientError> {
+    i32::try_from(n).map_err(|_| {
+        BlogClientError::invalid_req(format!("слишком большое значение '{}': {}", name, n))
+    })
 }
 
 impl PostResponseWrap {
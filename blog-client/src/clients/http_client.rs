@@ -4,15 +4,24 @@ use crate::{
     BlogClientError,
     clients::{
         models::{
-            PostCreateCmd, PostId, PostUpdateCmd, PostUpdateCmdHttp, UserAuthCmd, UserRegisterCmd,
+            AccountDeleteCmd, CommentCreateCmd, CommentCreateCmdHttp, EmailVerifyCmd,
+            ModerationRuleCmd, PasswordChangeCmd, PasswordForgotCmd, PasswordResetCmd,
+            PostCreateCmd, PostId, PostPublishCmd, PostPublishCmdHttp, PostUpdateCmd,
+            PostUpdateCmdHttp, ProfileUpdateCmd, RefreshCmd, UserAuthCmd, UserRegisterCmd,
+            UserRoleCmd,
         },
-        tools::compile_url,
+        tools::{compile_url, multipart_body},
         traits::ClientTransportExt,
     },
-    models::{AuthResponse, Token},
+    models::{AccountExport, AuditQuery, AuthResponse, Token},
 };
-use proto_crate::proto_blog::{ListPostsResponse, Post};
-use reqwest::{Client, Method, Response, Url};
+use proto_crate::proto_blog::{
+    Attachment, Comment, DiffRevisionsResponse, ListAuditEventsResponse, ListCommentsResponse,
+    ListFollowsResponse, ListModerationRulesResponse, ListPostsResponse, ListRevisionsResponse,
+    ListTagsResponse, ListUsersResponse, ModerationRule, Post, PostRevision, ReactionsResponse,
+    SearchPostsResponse, User, UserAccount, UserProfile,
+};
+use reqwest::{Client, Method, Response, Url, header::CONTENT_TYPE};
 use serde::Serialize;
 use std::{sync::Arc, time::Duration};
 use tonic::async_trait;
@@ -21,8 +30,90 @@ use tonic::async_trait;
 const API_AUTH_REGISTER: &str = "api/auth/register";
 /// Эндпоинт для авторизации пользователя.
 const API_AUTH_LOGIN: &str = "api/auth/login";
+/// Эндпоинт для обновления сессии.
+const API_AUTH_REFRESH: &str = "api/auth/refresh";
+/// Эндпоинт для завершения сессии.
+const API_AUTH_LOGOUT: &str = "api/auth/logout";
+/// Эндпоинт для смены пароля.
+const API_AUTH_PASSWORD: &str = "api/auth/password";
+/// Запрос на сброс пароля (вложен в смену пароля: `api/auth/password/forgot`).
+const API_PASSWORD_FORGOT: &str = "forgot";
+/// Новый пароль по токену (вложен в смену пароля: `api/auth/password/reset`).
+const API_PASSWORD_RESET: &str = "reset";
+/// Эндпоинт для подтверждения адреса электронной почты.
+const API_AUTH_EMAIL_VERIFY: &str = "api/auth/email/verify";
+/// Эндпоинт для повторной отправки письма подтверждения.
+const API_AUTH_EMAIL_RESEND: &str = "api/auth/email/resend";
+/// Учётная запись текущего пользователя.
+const API_ME: &str = "api/me";
+/// Выгрузка персональных данных (вложена в учётную запись: `api/me/export`).
+const API_EXPORT: &str = "export";
 /// Эндпоинт для взаимодействия с публикациями.
 const API_POSTS: &str = "api/posts";
+/// Ресурс комментариев (вложен в публикацию: `api/posts/{id}/comments`).
+const API_COMMENTS: &str = "comments";
+/// Перечень тегов.
+const API_TAGS: &str = "api/tags";
+/// Публикация по адресу (вложена в публикации: `api/posts/by-slug/{slug}`).
+const API_BY_SLUG: &str = "by-slug";
+/// Поиск публикаций (вложен в публикации: `api/posts/search`).
+const API_SEARCH: &str = "search";
+/// Публикация поста (вложена в публикацию: `api/posts/{id}/publish`).
+const API_PUBLISH: &str = "publish";
+/// Публичные профили авторов (`api/users/{username}`).
+const API_USERS: &str = "api/users";
+/// Публикации автора (вложены в профиль: `api/users/{username}/posts`).
+const API_USER_POSTS: &str = "posts";
+/// Подписка на автора (вложена в профиль: `api/users/{username}/follow`).
+const API_FOLLOW: &str = "follow";
+/// Подписчики пользователя (`api/users/{username}/followers`).
+const API_FOLLOWERS: &str = "followers";
+/// Подписки пользователя (`api/users/{username}/following`).
+const API_FOLLOWING: &str = "following";
+/// Персональная лента текущего пользователя.
+const API_FEED: &str = "api/feed";
+/// Черновики и отложенные публикации текущего пользователя.
+const API_DRAFTS: &str = "api/drafts";
+/// Публикации текущего пользователя в корзине.
+const API_TRASH: &str = "api/trash";
+/// Реакции на публикацию (вложены в публикацию:
+/// `api/posts/{id}/reactions/{kind}`).
+const API_REACTIONS: &str = "reactions";
+/// Ревизии публикации (вложены в публикацию: `api/posts/{id}/revisions`).
+const API_REVISIONS: &str = "revisions";
+/// Сравнение ревизий (вложено в публикацию: `api/posts/{id}/diff`).
+const API_DIFF: &str = "diff";
+/// Загрузка вложений.
+const API_UPLOADS: &str = "api/uploads";
+/// Восстановление из корзины (`api/posts/{id}/restore`) или ревизии
+/// (`api/posts/{id}/revisions/{revision}/restore`).
+const API_RESTORE: &str = "restore";
+/// Скрытие публикации модератором (`api/posts/{id}/hide`).
+const API_HIDE: &str = "hide";
+/// Возврат скрытой публикации (`api/posts/{id}/unhide`).
+const API_UNHIDE: &str = "unhide";
+/// Учётные записи пользователей для администратора.
+const API_ADMIN_USERS: &str = "api/admin/users";
+/// Роль пользователя (`api/admin/users/{username}/role`).
+const API_ROLE: &str = "role";
+/// Блокировка учётной записи (`api/admin/users/{username}/disable`).
+const API_DISABLE: &str = "disable";
+/// Разблокировка учётной записи (`api/admin/users/{username}/enable`).
+const API_ENABLE: &str = "enable";
+/// Снятие блокировки входа (`api/admin/users/{username}/unlock`).
+const API_UNLOCK: &str = "unlock";
+/// Журнал аудита для администратора.
+const API_ADMIN_AUDIT: &str = "api/admin/audit";
+/// Правила модерации.
+const API_MODERATION_RULES: &str = "api/moderation/rules";
+/// Очередь модерации.
+const API_MODERATION_QUEUE: &str = "api/moderation/queue";
+/// Публикации в очереди модерации (`api/moderation/posts/{id}/approve`).
+const API_MODERATION_POSTS: &str = "api/moderation/posts";
+/// Одобрение публикации (`api/moderation/posts/{id}/approve`).
+const API_APPROVE: &str = "approve";
+/// Отклонение публикации (`api/moderation/posts/{id}/reject`).
+const API_REJECT: &str = "reject";
 
 /// Настройки для [`Client`].
 struct ClientSettings {
@@ -104,9 +195,32 @@ impl HttpClient {
 
         let res = req_builder.send().await?;
 
-        res.error_for_status_ref()?;
+        BlogClientError::check_response(&res)?;
         Ok(res)
     }
+
+    /// Перечень подписчиков (`resource` = [`API_FOLLOWERS`]) или подписок
+    /// (`resource` = [`API_FOLLOWING`]) пользователя.
+    async fn list_follows(
+        &self,
+        username: &str,
+        resource: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListFollowsResponse, BlogClientError> {
+        let mut url = compile_url(&self.server_url, &[API_USERS, username, resource])?;
+
+        url.query_pairs_mut()
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, None)
+            .await?;
+        let follows: ListFollowsResponse = res.json().await?;
+
+        Ok(follows)
+    }
 }
 
 #[async_trait]
@@ -135,6 +249,129 @@ impl ClientTransportExt for HttpClient {
         Ok(auth_response)
     }
 
+    async fn refresh_session(&self, cmd: RefreshCmd) -> Result<AuthResponse, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_AUTH_REFRESH])?;
+
+        let res = self
+            .send_request(Method::POST, url, Some(&cmd), None)
+            .await?;
+        let auth_response: AuthResponse = res.json().await?;
+
+        Ok(auth_response)
+    }
+
+    async fn logout(&self, cmd: RefreshCmd) -> Result<(), Self::Error> {
+        let url = compile_url(&self.server_url, &[API_AUTH_LOGOUT])?;
+
+        self.send_request(Method::POST, url, Some(&cmd), None)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn change_password(
+        &self,
+        cmd: PasswordChangeCmd,
+        token: &Token,
+    ) -> Result<AuthResponse, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_AUTH_PASSWORD])?;
+
+        let res = self
+            .send_request(Method::POST, url, Some(&cmd), Some(token))
+            .await?;
+        let auth_response: AuthResponse = res.json().await?;
+
+        Ok(auth_response)
+    }
+
+    async fn forgot_password(&self, cmd: PasswordForgotCmd) -> Result<(), Self::Error> {
+        let url = compile_url(&self.server_url, &[API_AUTH_PASSWORD, API_PASSWORD_FORGOT])?;
+
+        self.send_request(Method::POST, url, Some(&cmd), None)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn reset_password(&self, cmd: PasswordResetCmd) -> Result<(), Self::Error> {
+        let url = compile_url(&self.server_url, &[API_AUTH_PASSWORD, API_PASSWORD_RESET])?;
+
+        self.send_request(Method::POST, url, Some(&cmd), None)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn verify_email(&self, cmd: EmailVerifyCmd) -> Result<User, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_AUTH_EMAIL_VERIFY])?;
+
+        let res = self
+            .send_request(Method::POST, url, Some(&cmd), None)
+            .await?;
+        let user: User = res.json().await?;
+
+        Ok(user)
+    }
+
+    async fn resend_verification(&self, token: &Token) -> Result<(), Self::Error> {
+        let url = compile_url(&self.server_url, &[API_AUTH_EMAIL_RESEND])?;
+
+        self.send_request::<()>(Method::POST, url, None, Some(token))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_me(&self, token: &Token) -> Result<User, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_ME])?;
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let user: User = res.json().await?;
+
+        Ok(user)
+    }
+
+    async fn update_profile(
+        &self,
+        cmd: ProfileUpdateCmd,
+        token: &Token,
+    ) -> Result<User, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_ME])?;
+
+        let res = self
+            .send_request(Method::PATCH, url, Some(&cmd), Some(token))
+            .await?;
+        let user: User = res.json().await?;
+
+        Ok(user)
+    }
+
+    async fn export_my_data(&self, token: &Token) -> Result<AccountExport, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_ME, API_EXPORT])?;
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let export: AccountExport = res.json().await?;
+
+        Ok(export)
+    }
+
+    async fn delete_my_account(
+        &self,
+        cmd: AccountDeleteCmd,
+        token: &Token,
+    ) -> Result<(), Self::Error> {
+        let url = compile_url(&self.server_url, &[API_ME])?;
+
+        self.send_request(Method::DELETE, url, Some(&cmd), Some(token))
+            .await?;
+
+        Ok(())
+    }
+
     async fn create_post(&self, cmd: PostCreateCmd, token: &Token) -> Result<Post, Self::Error> {
         let url = compile_url(&self.server_url, &[API_POSTS])?;
 
@@ -146,11 +383,28 @@ impl ClientTransportExt for HttpClient {
         Ok(post)
     }
 
-    async fn get_post(&self, post_id: PostId) -> Result<Post, Self::Error> {
+    async fn get_post(&self, post_id: PostId, token: Option<&Token>) -> Result<Post, Self::Error> {
         let url = compile_url(&self.server_url, &[API_POSTS, &post_id.to_string()])?;
 
         let res = self
-            .send_request::<()>(Method::GET, url, None, None)
+            .send_request::<()>(Method::GET, url, None, token)
+            .await?;
+        let post: Post = res.json().await?;
+
+        Ok(post)
+    }
+
+    /// Перенаправление с прежнего адреса на текущий выполняется
+    /// HTTP-клиентом автоматически.
+    async fn get_post_by_slug(
+        &self,
+        slug: &str,
+        token: Option<&Token>,
+    ) -> Result<Post, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_POSTS, API_BY_SLUG, slug])?;
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, token)
             .await?;
         let post: Post = res.json().await?;
 
@@ -180,19 +434,691 @@ impl ClientTransportExt for HttpClient {
         Ok(())
     }
 
-    async fn list_posts(&self, limit: u32, offset: u32) -> Result<ListPostsResponse, Self::Error> {
+    async fn publish_post(&self, cmd: PostPublishCmd, token: &Token) -> Result<Post, Self::Error> {
+        let post_id = cmd.post_id.to_string();
+        let url = compile_url(&self.server_url, &[API_POSTS, &post_id, API_PUBLISH])?;
+
+        let body: PostPublishCmdHttp = cmd.into();
+        let res = self
+            .send_request(Method::POST, url, Some(&body), Some(token))
+            .await?;
+        let post: Post = res.json().await?;
+
+        Ok(post)
+    }
+
+    async fn list_drafts(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error> {
+        let mut url = compile_url(&self.server_url, &[API_DRAFTS])?;
+
+        url.query_pairs_mut()
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let drafts: ListPostsResponse = res.json().await?;
+
+        Ok(drafts)
+    }
+
+    async fn list_trash(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error> {
+        let mut url = compile_url(&self.server_url, &[API_TRASH])?;
+
+        url.query_pairs_mut()
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let trash: ListPostsResponse = res.json().await?;
+
+        Ok(trash)
+    }
+
+    async fn get_user_profile(&self, username: &str) -> Result<UserProfile, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_USERS, username])?;
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, None)
+            .await?;
+        let profile: UserProfile = res.json().await?;
+
+        Ok(profile)
+    }
+
+    async fn list_user_posts(
+        &self,
+        username: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListPostsResponse, Self::Error> {
+        let mut url = compile_url(&self.server_url, &[API_USERS, username, API_USER_POSTS])?;
+
+        url.query_pairs_mut()
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, None)
+            .await?;
+        let posts: ListPostsResponse = res.json().await?;
+
+        Ok(posts)
+    }
+
+    async fn restore_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[API_POSTS, &post_id.to_string(), API_RESTORE],
+        )?;
+
+        let res = self
+            .send_request::<()>(Method::POST, url, None, Some(token))
+            .await?;
+        let post: Post = res.json().await?;
+
+        Ok(post)
+    }
+
+    async fn hide_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[API_POSTS, &post_id.to_string(), API_HIDE],
+        )?;
+
+        let res = self
+            .send_request::<()>(Method::POST, url, None, Some(token))
+            .await?;
+        let post: Post = res.json().await?;
+
+        Ok(post)
+    }
+
+    async fn unhide_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[API_POSTS, &post_id.to_string(), API_UNHIDE],
+        )?;
+
+        let res = self
+            .send_request::<()>(Method::POST, url, None, Some(token))
+            .await?;
+        let post: Post = res.json().await?;
+
+        Ok(post)
+    }
+
+    async fn list_posts(
+        &self,
+        limit: u32,
+        offset: u32,
+        tag: Option<&str>,
+        after: Option<&str>,
+        token: Option<&Token>,
+    ) -> Result<ListPostsResponse, Self::Error> {
         let mut url = compile_url(&self.server_url, &[API_POSTS])?;
 
         url.query_pairs_mut()
             .append_pair("limit", &limit.to_string());
         url.query_pairs_mut()
             .append_pair("offset", &offset.to_string());
+        if let Some(tag) = tag {
+            url.query_pairs_mut().append_pair("tag", tag);
+        }
+        if let Some(after) = after {
+            url.query_pairs_mut().append_pair("after", after);
+        }
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, token)
+            .await?;
+        let posts: ListPostsResponse = res.json().await?;
+
+        Ok(posts)
+    }
+
+    async fn list_tags(&self) -> Result<ListTagsResponse, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_TAGS])?;
 
         let res = self
             .send_request::<()>(Method::GET, url, None, None)
             .await?;
+        let tags: ListTagsResponse = res.json().await?;
+
+        Ok(tags)
+    }
+
+    async fn search_posts(
+        &self,
+        query: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<SearchPostsResponse, Self::Error> {
+        let mut url = compile_url(&self.server_url, &[API_POSTS, API_SEARCH])?;
+
+        url.query_pairs_mut()
+            .append_pair("q", query)
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, None)
+            .await?;
+        let results: SearchPostsResponse = res.json().await?;
+
+        Ok(results)
+    }
+
+    async fn add_comment(
+        &self,
+        cmd: CommentCreateCmd,
+        token: &Token,
+    ) -> Result<Comment, Self::Error> {
+        let post_id = cmd.post_id.to_string();
+        let url = compile_url(&self.server_url, &[API_POSTS, &post_id, API_COMMENTS])?;
+
+        let comment_cmd_http: CommentCreateCmdHttp = cmd.into();
+
+        let res = self
+            .send_request(Method::POST, url, Some(&comment_cmd_http), Some(token))
+            .await?;
+        let comment: Comment = res.json().await?;
+
+        Ok(comment)
+    }
+
+    async fn list_comments(
+        &self,
+        post_id: PostId,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListCommentsResponse, Self::Error> {
+        let mut url = compile_url(
+            &self.server_url,
+            &[API_POSTS, &post_id.to_string(), API_COMMENTS],
+        )?;
+
+        url.query_pairs_mut()
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, None)
+            .await?;
+        let comments: ListCommentsResponse = res.json().await?;
+
+        Ok(comments)
+    }
+
+    async fn delete_comment(
+        &self,
+        post_id: PostId,
+        comment_id: i64,
+        token: &Token,
+    ) -> Result<(), Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[
+                API_POSTS,
+                &post_id.to_string(),
+                API_COMMENTS,
+                &comment_id.to_string(),
+            ],
+        )?;
+
+        self.send_request::<()>(Method::DELETE, url, None, Some(token))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn list_revisions(
+        &self,
+        post_id: PostId,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListRevisionsResponse, Self::Error> {
+        let mut url = compile_url(
+            &self.server_url,
+            &[API_POSTS, &post_id.to_string(), API_REVISIONS],
+        )?;
+
+        url.query_pairs_mut()
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let revisions: ListRevisionsResponse = res.json().await?;
+
+        Ok(revisions)
+    }
+
+    async fn get_revision(
+        &self,
+        post_id: PostId,
+        revision: i32,
+        token: &Token,
+    ) -> Result<PostRevision, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[
+                API_POSTS,
+                &post_id.to_string(),
+                API_REVISIONS,
+                &revision.to_string(),
+            ],
+        )?;
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let revision: PostRevision = res.json().await?;
+
+        Ok(revision)
+    }
+
+    async fn diff_revisions(
+        &self,
+        post_id: PostId,
+        from: i32,
+        to: i32,
+        token: &Token,
+    ) -> Result<DiffRevisionsResponse, Self::Error> {
+        let mut url = compile_url(
+            &self.server_url,
+            &[API_POSTS, &post_id.to_string(), API_DIFF],
+        )?;
+
+        url.query_pairs_mut()
+            .append_pair("from", &from.to_string())
+            .append_pair("to", &to.to_string());
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let diff: DiffRevisionsResponse = res.json().await?;
+
+        Ok(diff)
+    }
+
+    async fn restore_revision(
+        &self,
+        post_id: PostId,
+        revision: i32,
+        token: &Token,
+    ) -> Result<Post, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[
+                API_POSTS,
+                &post_id.to_string(),
+                API_REVISIONS,
+                &revision.to_string(),
+                API_RESTORE,
+            ],
+        )?;
+
+        let res = self
+            .send_request::<()>(Method::POST, url, None, Some(token))
+            .await?;
+        let post: Post = res.json().await?;
+
+        Ok(post)
+    }
+
+    async fn react(
+        &self,
+        post_id: PostId,
+        kind: &str,
+        token: &Token,
+    ) -> Result<ReactionsResponse, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[API_POSTS, &post_id.to_string(), API_REACTIONS, kind],
+        )?;
+
+        let res = self
+            .send_request::<()>(Method::PUT, url, None, Some(token))
+            .await?;
+        let reactions: ReactionsResponse = res.json().await?;
+
+        Ok(reactions)
+    }
+
+    async fn unreact(
+        &self,
+        post_id: PostId,
+        kind: &str,
+        token: &Token,
+    ) -> Result<ReactionsResponse, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[API_POSTS, &post_id.to_string(), API_REACTIONS, kind],
+        )?;
+
+        let res = self
+            .send_request::<()>(Method::DELETE, url, None, Some(token))
+            .await?;
+        let reactions: ReactionsResponse = res.json().await?;
+
+        Ok(reactions)
+    }
+
+    async fn follow(&self, username: &str, token: &Token) -> Result<(), Self::Error> {
+        let url = compile_url(&self.server_url, &[API_USERS, username, API_FOLLOW])?;
+
+        self.send_request::<()>(Method::PUT, url, None, Some(token))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn unfollow(&self, username: &str, token: &Token) -> Result<(), Self::Error> {
+        let url = compile_url(&self.server_url, &[API_USERS, username, API_FOLLOW])?;
+
+        self.send_request::<()>(Method::DELETE, url, None, Some(token))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn list_followers(
+        &self,
+        username: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListFollowsResponse, Self::Error> {
+        self.list_follows(username, API_FOLLOWERS, limit, offset)
+            .await
+    }
+
+    async fn list_following(
+        &self,
+        username: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListFollowsResponse, Self::Error> {
+        self.list_follows(username, API_FOLLOWING, limit, offset)
+            .await
+    }
+
+    async fn feed(
+        &self,
+        limit: u32,
+        offset: u32,
+        tag: Option<&str>,
+        after: Option<&str>,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error> {
+        let mut url = compile_url(&self.server_url, &[API_FEED])?;
+
+        url.query_pairs_mut()
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+        if let Some(tag) = tag {
+            url.query_pairs_mut().append_pair("tag", tag);
+        }
+        if let Some(after) = after {
+            url.query_pairs_mut().append_pair("after", after);
+        }
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
         let posts: ListPostsResponse = res.json().await?;
 
         Ok(posts)
     }
+
+    async fn upload(
+        &self,
+        file_name: &str,
+        data: &[u8],
+        token: &Token,
+    ) -> Result<Attachment, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_UPLOADS])?;
+        let (content_type, body) = multipart_body("file", file_name, data);
+
+        let res = self
+            .client
+            .post(url)
+            .bearer_auth(token.as_str())
+            .header(CONTENT_TYPE, content_type)
+            .body(body)
+            .send()
+            .await?;
+        BlogClientError::check_response(&res)?;
+        let attachment: Attachment = res.json().await?;
+
+        Ok(attachment)
+    }
+
+    async fn list_users(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListUsersResponse, Self::Error> {
+        let mut url = compile_url(&self.server_url, &[API_ADMIN_USERS])?;
+
+        url.query_pairs_mut()
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let users: ListUsersResponse = res.json().await?;
+
+        Ok(users)
+    }
+
+    async fn set_user_role(
+        &self,
+        cmd: UserRoleCmd,
+        token: &Token,
+    ) -> Result<UserAccount, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[API_ADMIN_USERS, &cmd.username, API_ROLE],
+        )?;
+
+        let res = self
+            .send_request(Method::PUT, url, Some(&cmd), Some(token))
+            .await?;
+        let account: UserAccount = res.json().await?;
+
+        Ok(account)
+    }
+
+    async fn set_user_disabled(
+        &self,
+        username: &str,
+        disabled: bool,
+        token: &Token,
+    ) -> Result<UserAccount, Self::Error> {
+        let action = if disabled { API_DISABLE } else { API_ENABLE };
+        let url = compile_url(&self.server_url, &[API_ADMIN_USERS, username, action])?;
+
+        let res = self
+            .send_request::<()>(Method::POST, url, None, Some(token))
+            .await?;
+        let account: UserAccount = res.json().await?;
+
+        Ok(account)
+    }
+
+    async fn unlock_user(&self, username: &str, token: &Token) -> Result<UserAccount, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_ADMIN_USERS, username, API_UNLOCK])?;
+
+        let res = self
+            .send_request::<()>(Method::POST, url, None, Some(token))
+            .await?;
+        let account: UserAccount = res.json().await?;
+
+        Ok(account)
+    }
+
+    async fn list_audit_events(
+        &self,
+        query: &AuditQuery,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListAuditEventsResponse, Self::Error> {
+        let mut url = compile_url(&self.server_url, &[API_ADMIN_AUDIT])?;
+
+        {
+            let mut pairs = url.query_pairs_mut();
+            pairs
+                .append_pair("limit", &limit.to_string())
+                .append_pair("offset", &offset.to_string());
+            let filters = [
+                ("actor", query.actor.clone()),
+                ("action", query.action.clone()),
+                ("target", query.target.clone()),
+                ("since", query.since.map(|ts| ts.to_string())),
+                ("until", query.until.map(|ts| ts.to_string())),
+            ];
+            for (name, value) in filters {
+                if let Some(value) = value {
+                    pairs.append_pair(name, &value);
+                }
+            }
+        }
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let events: ListAuditEventsResponse = res.json().await?;
+
+        Ok(events)
+    }
+
+    async fn list_moderation_rules(
+        &self,
+        token: &Token,
+    ) -> Result<ListModerationRulesResponse, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_MODERATION_RULES])?;
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let rules: ListModerationRulesResponse = res.json().await?;
+
+        Ok(rules)
+    }
+
+    async fn create_moderation_rule(
+        &self,
+        cmd: ModerationRuleCmd,
+        token: &Token,
+    ) -> Result<ModerationRule, Self::Error> {
+        let url = compile_url(&self.server_url, &[API_MODERATION_RULES])?;
+
+        let res = self
+            .send_request(Method::POST, url, Some(&cmd), Some(token))
+            .await?;
+        let rule: ModerationRule = res.json().await?;
+
+        Ok(rule)
+    }
+
+    async fn update_moderation_rule(
+        &self,
+        rule_id: i64,
+        cmd: ModerationRuleCmd,
+        token: &Token,
+    ) -> Result<ModerationRule, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[API_MODERATION_RULES, &rule_id.to_string()],
+        )?;
+
+        let res = self
+            .send_request(Method::PUT, url, Some(&cmd), Some(token))
+            .await?;
+        let rule: ModerationRule = res.json().await?;
+
+        Ok(rule)
+    }
+
+    async fn delete_moderation_rule(
+        &self,
+        rule_id: i64,
+        token: &Token,
+    ) -> Result<(), Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[API_MODERATION_RULES, &rule_id.to_string()],
+        )?;
+
+        self.send_request::<()>(Method::DELETE, url, None, Some(token))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn list_moderation_queue(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error> {
+        let mut url = compile_url(&self.server_url, &[API_MODERATION_QUEUE])?;
+
+        url.query_pairs_mut()
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+
+        let res = self
+            .send_request::<()>(Method::GET, url, None, Some(token))
+            .await?;
+        let posts: ListPostsResponse = res.json().await?;
+
+        Ok(posts)
+    }
+
+    async fn approve_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[API_MODERATION_POSTS, &post_id.to_string(), API_APPROVE],
+        )?;
+
+        let res = self
+            .send_request::<()>(Method::POST, url, None, Some(token))
+            .await?;
+        let post: Post = res.json().await?;
+
+        Ok(post)
+    }
+
+    async fn reject_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error> {
+        let url = compile_url(
+            &self.server_url,
+            &[API_MODERATION_POSTS, &post_id.to_string(), API_REJECT],
+        )?;
+
+        let res = self
+            .send_request::<()>(Method::POST, url, None, Some(token))
+            .await?;
+        let post: Post = res.json().await?;
+
+        Ok(post)
+    }
 }
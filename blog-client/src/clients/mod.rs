@@ -3,5 +3,5 @@
 pub(crate) mod grpc_client;
 pub(crate) mod http_client;
 pub(crate) mod models;
-pub(crate) mod traits;
 mod tools;
+pub(crate) mod traits;
@@ -1,7 +1,12 @@
 //! Локальные модели и команды клиентского транспорта.
 
+use crate::models::{PublishMode, Token};
 use proto_crate::proto_blog::{
-    CreatePostRequest, LoginRequest, PostResponse, RegisterRequest, UpdatePostRequest,
+    AddCommentRequest, ChangePasswordRequest, CreateModerationRuleRequest, CreatePostRequest,
+    DeleteMyAccountRequest, LoginRequest, LogoutRequest, PostResponse, PublishPostRequest,
+    RefreshRequest, RegisterRequest, RequestPasswordResetRequest, ResetPasswordRequest,
+    SetUserRoleRequest, TagList, UpdateModerationRuleRequest, UpdatePostRequest,
+    UpdateProfileRequest, VerifyEmailRequest,
 };
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -89,6 +94,281 @@ impl UserAuthCmd {
     }
 }
 
+/// Команда на обновление или завершение сессии.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct RefreshCmd {
+    /// Токен обновления сессии.
+    pub(crate) refresh_token: String,
+}
+
+impl From<RefreshCmd> for RefreshRequest {
+    fn from(cmd: RefreshCmd) -> Self {
+        Self {
+            refresh_token: cmd.refresh_token,
+        }
+    }
+}
+
+impl From<RefreshCmd> for LogoutRequest {
+    fn from(cmd: RefreshCmd) -> Self {
+        Self {
+            refresh_token: cmd.refresh_token,
+        }
+    }
+}
+
+impl RefreshCmd {
+    /// Создать команду с токеном обновления `refresh_token`.
+    pub(crate) fn new(refresh_token: &Token) -> Self {
+        Self {
+            refresh_token: refresh_token.as_str().to_string(),
+        }
+    }
+}
+
+/// Команда на смену пароля.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PasswordChangeCmd {
+    /// Текущий пароль.
+    pub(crate) old_password: String,
+    /// Новый пароль.
+    pub(crate) new_password: String,
+}
+
+impl From<PasswordChangeCmd> for ChangePasswordRequest {
+    fn from(cmd: PasswordChangeCmd) -> Self {
+        Self {
+            old_password: cmd.old_password,
+            new_password: cmd.new_password,
+        }
+    }
+}
+
+impl PasswordChangeCmd {
+    /// Создать команду на смену пароля `old_password` на `new_password`.
+    pub(crate) fn new(old_password: &str, new_password: &str) -> Self {
+        Self {
+            old_password: old_password.to_string(),
+            new_password: new_password.to_string(),
+        }
+    }
+}
+
+/// Команда на отправку письма для сброса пароля.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PasswordForgotCmd {
+    /// Адрес электронной почты учётной записи.
+    pub(crate) email: String,
+}
+
+impl From<PasswordForgotCmd> for RequestPasswordResetRequest {
+    fn from(cmd: PasswordForgotCmd) -> Self {
+        Self { email: cmd.email }
+    }
+}
+
+impl PasswordForgotCmd {
+    /// Создать команду на сброс пароля учётной записи с адресом `email`.
+    pub(crate) fn new(email: &str) -> Self {
+        Self {
+            email: email.to_string(),
+        }
+    }
+}
+
+/// Команда на установку нового пароля по токену сброса.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PasswordResetCmd {
+    /// Токен сброса пароля из письма.
+    pub(crate) token: String,
+    /// Новый пароль.
+    pub(crate) new_password: String,
+}
+
+impl From<PasswordResetCmd> for ResetPasswordRequest {
+    fn from(cmd: PasswordResetCmd) -> Self {
+        Self {
+            token: cmd.token,
+            new_password: cmd.new_password,
+        }
+    }
+}
+
+impl PasswordResetCmd {
+    /// Создать команду на установку пароля `new_password` по токену `token`.
+    pub(crate) fn new(token: &str, new_password: &str) -> Self {
+        Self {
+            token: token.to_string(),
+            new_password: new_password.to_string(),
+        }
+    }
+}
+
+/// Команда на подтверждение адреса электронной почты.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct EmailVerifyCmd {
+    /// Токен подтверждения из письма.
+    pub(crate) token: String,
+}
+
+impl From<EmailVerifyCmd> for VerifyEmailRequest {
+    fn from(cmd: EmailVerifyCmd) -> Self {
+        Self { token: cmd.token }
+    }
+}
+
+impl EmailVerifyCmd {
+    /// Создать команду на подтверждение адреса по токену `token`.
+    pub(crate) fn new(token: &str) -> Self {
+        Self {
+            token: token.to_string(),
+        }
+    }
+}
+
+/// Команда на изменение профиля текущего пользователя.
+///
+/// Незаданные поля не меняются, пустая строка очищает поле.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ProfileUpdateCmd {
+    /// Отображаемое имя.
+    pub(crate) display_name: Option<String>,
+    /// Информация о себе.
+    pub(crate) bio: Option<String>,
+    /// Адрес аватара.
+    pub(crate) avatar_url: Option<String>,
+    /// Новый адрес электронной почты.
+    pub(crate) email: Option<String>,
+}
+
+impl From<ProfileUpdateCmd> for UpdateProfileRequest {
+    fn from(cmd: ProfileUpdateCmd) -> Self {
+        Self {
+            display_name: cmd.display_name,
+            bio: cmd.bio,
+            avatar_url: cmd.avatar_url,
+            email: cmd.email,
+        }
+    }
+}
+
+impl ProfileUpdateCmd {
+    /// Создать команду на изменение профиля.
+    pub(crate) fn new(
+        display_name: Option<&str>,
+        bio: Option<&str>,
+        avatar_url: Option<&str>,
+        email: Option<&str>,
+    ) -> Self {
+        Self {
+            display_name: display_name.map(String::from),
+            bio: bio.map(String::from),
+            avatar_url: avatar_url.map(String::from),
+            email: email.map(String::from),
+        }
+    }
+}
+
+/// Команда на удаление учётной записи текущего пользователя.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct AccountDeleteCmd {
+    /// Текущий пароль для подтверждения.
+    pub(crate) password: String,
+    /// Что сделать с публикациями: `delete` или `anonymize`.
+    pub(crate) posts: String,
+}
+
+impl From<AccountDeleteCmd> for DeleteMyAccountRequest {
+    fn from(cmd: AccountDeleteCmd) -> Self {
+        Self {
+            password: cmd.password,
+            posts: cmd.posts,
+        }
+    }
+}
+
+impl AccountDeleteCmd {
+    /// Создать команду на удаление учётной записи с паролем `password`
+    /// и способом обработки публикаций `posts`.
+    pub(crate) fn new(password: &str, posts: &str) -> Self {
+        Self {
+            password: password.to_string(),
+            posts: posts.to_string(),
+        }
+    }
+}
+
+/// Команда на смену роли пользователя.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct UserRoleCmd {
+    /// Имя пользователя (в теле HTTP-запроса не передаётся).
+    #[serde(skip)]
+    pub(crate) username: String,
+    /// Роль: `user`, `moderator` или `admin`.
+    pub(crate) role: String,
+}
+
+impl From<UserRoleCmd> for SetUserRoleRequest {
+    fn from(cmd: UserRoleCmd) -> Self {
+        Self {
+            username: cmd.username,
+            role: cmd.role,
+        }
+    }
+}
+
+impl UserRoleCmd {
+    /// Создать команду назначения пользователю `username` роли `role`.
+    pub(crate) fn new(username: &str, role: &str) -> Self {
+        Self {
+            username: username.to_string(),
+            role: role.to_string(),
+        }
+    }
+}
+
+/// Команда создания или изменения правила модерации.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ModerationRuleCmd {
+    /// Шаблон: слово, фраза или регулярное выражение.
+    pub(crate) pattern: String,
+    /// Вид шаблона: `word`, `phrase` или `regex`.
+    pub(crate) kind: String,
+    /// Действие при совпадении: `reject` или `hold`.
+    pub(crate) action: String,
+}
+
+impl From<ModerationRuleCmd> for CreateModerationRuleRequest {
+    fn from(cmd: ModerationRuleCmd) -> Self {
+        Self {
+            pattern: cmd.pattern,
+            kind: cmd.kind,
+            action: cmd.action,
+        }
+    }
+}
+
+impl ModerationRuleCmd {
+    /// Создать команду с правилом вида `kind` и действием `action`.
+    pub(crate) fn new(pattern: &str, kind: &str, action: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            kind: kind.to_string(),
+            action: action.to_string(),
+        }
+    }
+
+    /// Запрос изменения правила `rule_id` для gRPC-сервера.
+    pub(crate) fn into_update(self, rule_id: i64) -> UpdateModerationRuleRequest {
+        UpdateModerationRuleRequest {
+            id: rule_id,
+            pattern: self.pattern,
+            kind: self.kind,
+            action: self.action,
+        }
+    }
+}
+
 /// Команда создания публикации.
 #[derive(Serialize, Deserialize)]
 pub(crate) struct PostCreateCmd {
@@ -96,6 +376,12 @@ pub(crate) struct PostCreateCmd {
     pub(crate) title: String,
     /// Содержание публикации.
     pub(crate) content: String,
+    /// Теги публикации.
+    pub(crate) tags: Vec<String>,
+    /// Статус публикации (если не указан, сервер публикует пост сразу).
+    pub(crate) status: Option<String>,
+    /// Время отложенной публикации (unix-время, секунды).
+    pub(crate) publish_at: Option<i64>,
 }
 
 impl From<PostCreateCmd> for CreatePostRequest {
@@ -103,16 +389,73 @@ impl From<PostCreateCmd> for CreatePostRequest {
         Self {
             title: post_cmd.title,
             content: post_cmd.content,
+            tags: post_cmd.tags,
+            status: post_cmd.status,
+            publish_at: post_cmd.publish_at,
         }
     }
 }
 
 impl PostCreateCmd {
     /// Создание команды для размещения публикации.
-    pub(crate) fn new(title: &str, content: &str) -> Self {
+    pub(crate) fn new(title: &str, content: &str, tags: &[String], mode: PublishMode) -> Self {
+        let (status, publish_at) = match mode {
+            PublishMode::Now => (None, None),
+            PublishMode::Draft => (Some("draft".to_string()), None),
+            PublishMode::At(ts) => (Some("scheduled".to_string()), Some(ts)),
+        };
+
         Self {
             title: title.to_string(),
             content: content.to_string(),
+            tags: tags.to_vec(),
+            status,
+            publish_at,
+        }
+    }
+}
+
+/// Команда публикации черновика или отложенной записи.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PostPublishCmd {
+    /// Id публикации на сервере.
+    pub(crate) post_id: PostId,
+    /// Время отложенной публикации (unix-время, секунды). Если не указано,
+    /// пост публикуется сразу.
+    pub(crate) publish_at: Option<i64>,
+}
+
+impl From<PostPublishCmd> for PublishPostRequest {
+    fn from(cmd: PostPublishCmd) -> Self {
+        Self {
+            id: cmd.post_id.into(),
+            publish_at: cmd.publish_at,
+        }
+    }
+}
+
+impl PostPublishCmd {
+    /// Создание команды для публикации поста.
+    pub(crate) fn new(post_id: i64, publish_at: Option<i64>) -> Self {
+        Self {
+            post_id: PostId(post_id),
+            publish_at,
+        }
+    }
+}
+
+/// Специализированная команда публикации поста для HTTP-сервера.
+/// Отсутствует post_id в теле структуры.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PostPublishCmdHttp {
+    /// Время отложенной публикации (опционально).
+    pub(crate) publish_at: Option<i64>,
+}
+
+impl From<PostPublishCmd> for PostPublishCmdHttp {
+    fn from(p: PostPublishCmd) -> Self {
+        Self {
+            publish_at: p.publish_at,
         }
     }
 }
@@ -126,6 +469,8 @@ pub(crate) struct PostUpdateCmd {
     pub(crate) title: Option<String>,
     /// Новое содержание публикации (опционально).
     pub(crate) content: Option<String>,
+    /// Новый набор тегов (опционально), заменяет прежний.
+    pub(crate) tags: Option<Vec<String>>,
 }
 
 impl From<PostUpdateCmd> for UpdatePostRequest {
@@ -134,21 +479,29 @@ impl From<PostUpdateCmd> for UpdatePostRequest {
             id: upd_cmd.post_id.into(),
             title: upd_cmd.title,
             content: upd_cmd.content,
+            tags: upd_cmd.tags.map(|tags| TagList { tags }),
         }
     }
 }
 
 impl PostUpdateCmd {
     /// Создание команды для изменения публикации.
-    pub(crate) fn new(post_id: i64, title: Option<&str>, content: Option<&str>) -> Self {
+    pub(crate) fn new(
+        post_id: i64,
+        title: Option<&str>,
+        content: Option<&str>,
+        tags: Option<&[String]>,
+    ) -> Self {
         let post_id = PostId(post_id);
         let title = title.map(String::from);
         let content = content.map(String::from);
+        let tags = tags.map(<[String]>::to_vec);
 
         Self {
             post_id,
             title,
             content,
+            tags,
         }
     }
 }
@@ -161,6 +514,8 @@ pub(crate) struct PostUpdateCmdHttp {
     pub(crate) title: Option<String>,
     /// Новое содержание публикации (опционально).
     pub(crate) content: Option<String>,
+    /// Новый набор тегов (опционально).
+    pub(crate) tags: Option<Vec<String>>,
 }
 
 impl From<PostUpdateCmd> for PostUpdateCmdHttp {
@@ -168,10 +523,53 @@ impl From<PostUpdateCmd> for PostUpdateCmdHttp {
         Self {
             title: p.title,
             content: p.content,
+            tags: p.tags,
+        }
+    }
+}
+
+/// Команда добавления комментария к публикации.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CommentCreateCmd {
+    /// Id публикации на сервере.
+    pub(crate) post_id: PostId,
+    /// Текст комментария.
+    pub(crate) content: String,
+}
+
+impl From<CommentCreateCmd> for AddCommentRequest {
+    fn from(cmd: CommentCreateCmd) -> Self {
+        Self {
+            post_id: cmd.post_id.into(),
+            content: cmd.content,
+        }
+    }
+}
+
+impl CommentCreateCmd {
+    /// Создание команды для добавления комментария.
+    pub(crate) fn new(post_id: i64, content: &str) -> Self {
+        Self {
+            post_id: PostId(post_id),
+            content: content.to_string(),
         }
     }
 }
 
+/// Специализированная команда добавления комментария для HTTP-сервера.
+/// Отсутствует post_id в теле структуры.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CommentCreateCmdHttp {
+    /// Текст комментария.
+    pub(crate) content: String,
+}
+
+impl From<CommentCreateCmd> for CommentCreateCmdHttp {
+    fn from(c: CommentCreateCmd) -> Self {
+        Self { content: c.content }
+    }
+}
+
 /// Обёртка для [`PostResponse`].
 ///
 /// При взаимодействии с gRPC-сервером, экземпляр [`Post`] возвращается внутри
@@ -2,6 +2,7 @@
 
 use crate::BlogClientError;
 use reqwest::Url;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Собрать ссылку из компонентов пути. Обёртка для метода [`Url`].
 ///
@@ -14,6 +15,41 @@ pub(crate) fn compile_url(base: &Url, endpoint: &[&str]) -> Result<Url, BlogClie
         .map_err(|err| BlogClientError::client_error(err.to_string()))
 }
 
+/// Сформировать тело `multipart/form-data` с единственным файлом в поле
+/// `field`.
+///
+/// ## Returns
+///
+/// Значение заголовка `Content-Type` (с границей частей) и тело запроса.
+pub(crate) fn multipart_body(field: &str, file_name: &str, data: &[u8]) -> (String, Vec<u8>) {
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    // Граница не должна встречаться в содержимом файла.
+    let boundary = (0u128..)
+        .map(|n| format!("blog-client-{:x}", seed.wrapping_add(n)))
+        .find(|b| !data.windows(b.len()).any(|w| w == b.as_bytes()))
+        .unwrap_or_default();
+
+    let file_name: String = file_name
+        .chars()
+        .filter(|c| !c.is_control())
+        .map(|c| if c == '"' { '\'' } else { c })
+        .collect();
+
+    let mut body = format!(
+        "--{boundary}\r\n\
+         Content-Disposition: form-data; name=\"{field}\"; filename=\"{file_name}\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(data);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+    (format!("multipart/form-data; boundary={boundary}"), body)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -28,4 +64,17 @@ mod tests {
         let res = compile_url(&valid_base_url(), &endpoint);
         assert!(res.is_ok());
     }
+
+    #[test]
+    fn multipart_body_wraps_file() {
+        let (content_type, body) = multipart_body("file", "a\"b.png", b"data");
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+
+        let body = String::from_utf8(body).unwrap();
+        assert!(body.starts_with(&format!("--{boundary}\r\n")));
+        assert!(body.contains("name=\"file\"; filename=\"a'b.png\""));
+        assert!(body.ends_with(&format!("\r\n\r\ndata\r\n--{boundary}--\r\n")));
+    }
 }
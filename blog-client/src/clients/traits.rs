@@ -1,10 +1,19 @@
 //! Трейты для обеспечения работы клиентов.
 
 use crate::{
-    clients::models::{PostCreateCmd, PostId, PostUpdateCmd, UserAuthCmd, UserRegisterCmd},
-    models::{AuthResponse, Token},
+    clients::models::{
+        AccountDeleteCmd, CommentCreateCmd, EmailVerifyCmd, ModerationRuleCmd, PasswordChangeCmd,
+        PasswordForgotCmd, PasswordResetCmd, PostCreateCmd, PostId, PostPublishCmd, PostUpdateCmd,
+        ProfileUpdateCmd, RefreshCmd, UserAuthCmd, UserRegisterCmd, UserRoleCmd,
+    },
+    models::{AccountExport, AuditQuery, AuthResponse, Token},
+};
+use proto_crate::proto_blog::{
+    Attachment, Comment, DiffRevisionsResponse, ListAuditEventsResponse, ListCommentsResponse,
+    ListFollowsResponse, ListModerationRulesResponse, ListPostsResponse, ListRevisionsResponse,
+    ListTagsResponse, ListUsersResponse, ModerationRule, Post, PostRevision, ReactionsResponse,
+    SearchPostsResponse, User, UserAccount, UserProfile,
 };
-use proto_crate::proto_blog::{ListPostsResponse, Post};
 use tonic::async_trait;
 
 /// Трейт для унифицирования транспортных асинхронных методов клиентов.
@@ -18,29 +27,352 @@ pub(crate) trait ClientTransportExt {
     /// Авторизация пользователя.
     async fn login_user(&self, cmd: UserAuthCmd) -> Result<AuthResponse, Self::Error>;
 
+    /// Обновление сессии: новая пара токенов взамен токена обновления.
+    async fn refresh_session(&self, cmd: RefreshCmd) -> Result<AuthResponse, Self::Error>;
+
+    /// Завершение сессии с отзывом её токенов.
+    async fn logout(&self, cmd: RefreshCmd) -> Result<(), Self::Error>;
+
+    /// Смена пароля: сессии пользователя завершаются, ответ содержит токены
+    /// новой сессии.
+    async fn change_password(
+        &self,
+        cmd: PasswordChangeCmd,
+        token: &Token,
+    ) -> Result<AuthResponse, Self::Error>;
+
+    /// Запрос письма со ссылкой для сброса забытого пароля.
+    async fn forgot_password(&self, cmd: PasswordForgotCmd) -> Result<(), Self::Error>;
+
+    /// Установка нового пароля по токену сброса.
+    async fn reset_password(&self, cmd: PasswordResetCmd) -> Result<(), Self::Error>;
+
+    /// Подтверждение адреса электронной почты по токену из письма.
+    async fn verify_email(&self, cmd: EmailVerifyCmd) -> Result<User, Self::Error>;
+
+    /// Повторная отправка письма для подтверждения адреса.
+    async fn resend_verification(&self, token: &Token) -> Result<(), Self::Error>;
+
+    /// Данные текущего пользователя.
+    async fn get_me(&self, token: &Token) -> Result<User, Self::Error>;
+
+    /// Изменение профиля текущего пользователя.
+    async fn update_profile(
+        &self,
+        cmd: ProfileUpdateCmd,
+        token: &Token,
+    ) -> Result<User, Self::Error>;
+
+    /// Выгрузка персональных данных текущего пользователя.
+    async fn export_my_data(&self, token: &Token) -> Result<AccountExport, Self::Error>;
+
+    /// Удаление учётной записи текущего пользователя.
+    async fn delete_my_account(
+        &self,
+        cmd: AccountDeleteCmd,
+        token: &Token,
+    ) -> Result<(), Self::Error>;
+
     /// Создание публикации.
     async fn create_post(&self, cmd: PostCreateCmd, token: &Token) -> Result<Post, Self::Error>;
 
     /// Чтение публикации.
-    async fn get_post(&self, post_id: PostId) -> Result<Post, Self::Error>;
+    ///
+    /// Токен необязателен: он позволяет автору читать свои черновики
+    /// и отложенные публикации.
+    async fn get_post(&self, post_id: PostId, token: Option<&Token>) -> Result<Post, Self::Error>;
+
+    /// Чтение публикации по её текущему или прежнему адресу (slug).
+    ///
+    /// Возвращённая публикация содержит текущий адрес.
+    async fn get_post_by_slug(
+        &self,
+        slug: &str,
+        token: Option<&Token>,
+    ) -> Result<Post, Self::Error>;
 
     /// Обновление публикации.
     async fn update_post(&self, cmd: PostUpdateCmd, token: &Token) -> Result<Post, Self::Error>;
 
-    /// Удаление публикации.
+    /// Удаление публикации (перемещение в корзину).
     async fn delete_post(&self, post_id: PostId, token: &Token) -> Result<(), Self::Error>;
 
+    /// Публикация черновика или отложенной записи.
+    async fn publish_post(&self, cmd: PostPublishCmd, token: &Token) -> Result<Post, Self::Error>;
+
+    /// Черновики и отложенные публикации текущего пользователя.
+    async fn list_drafts(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error>;
+
+    /// Публикации текущего пользователя в корзине.
+    async fn list_trash(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error>;
+
+    /// Восстановление публикации из корзины.
+    async fn restore_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error>;
+
+    /// Скрытие публикации модератором.
+    async fn hide_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error>;
+
+    /// Возврат скрытой публикации читателям.
+    async fn unhide_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error>;
+
+    /// Публичный профиль автора.
+    async fn get_user_profile(&self, username: &str) -> Result<UserProfile, Self::Error>;
+
+    /// Опубликованные записи автора, от новых к старым.
+    async fn list_user_posts(
+        &self,
+        username: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListPostsResponse, Self::Error>;
+
     /// Просмотр публикаций с пагинацией.
     ///
     /// ## Args
     ///
     /// - `limit` — количество возвращаемых записей
     /// - `offset` — количество записей для пропуска
+    /// - `tag` — фильтр по тегу (опционально)
+    /// - `after` — курсор следующей страницы из предыдущего ответа
+    ///   (опционально)
+    /// - `token` — токен пользователя (опционально), чтобы получить его
+    ///   реакции на публикации
     ///
     /// Сервер может устанавливать ограничения по значениям.
     async fn list_posts(
         &self,
         limit: u32,
         offset: u32,
+        tag: Option<&str>,
+        after: Option<&str>,
+        token: Option<&Token>,
     ) -> Result<ListPostsResponse, Self::Error>;
+
+    /// Перечень тегов с количеством публикаций.
+    async fn list_tags(&self) -> Result<ListTagsResponse, Self::Error>;
+
+    /// Полнотекстовый поиск публикаций.
+    ///
+    /// ## Args
+    ///
+    /// - `query` — поисковый запрос
+    /// - `limit` — количество возвращаемых записей
+    /// - `offset` — количество записей для пропуска
+    async fn search_posts(
+        &self,
+        query: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<SearchPostsResponse, Self::Error>;
+
+    /// Добавление комментария к публикации.
+    async fn add_comment(
+        &self,
+        cmd: CommentCreateCmd,
+        token: &Token,
+    ) -> Result<Comment, Self::Error>;
+
+    /// Просмотр комментариев к публикации с пагинацией.
+    ///
+    /// ## Args
+    ///
+    /// - `post_id` — id публикации
+    /// - `limit` — количество возвращаемых записей
+    /// - `offset` — количество записей для пропуска
+    async fn list_comments(
+        &self,
+        post_id: PostId,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListCommentsResponse, Self::Error>;
+
+    /// Удаление комментария.
+    async fn delete_comment(
+        &self,
+        post_id: PostId,
+        comment_id: i64,
+        token: &Token,
+    ) -> Result<(), Self::Error>;
+
+    /// История изменений публикации, от новых ревизий к старым.
+    ///
+    /// ## Args
+    ///
+    /// - `post_id` — id публикации
+    /// - `limit` — количество возвращаемых записей
+    /// - `offset` — количество записей для пропуска
+    async fn list_revisions(
+        &self,
+        post_id: PostId,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListRevisionsResponse, Self::Error>;
+
+    /// Получение отдельной ревизии публикации.
+    async fn get_revision(
+        &self,
+        post_id: PostId,
+        revision: i32,
+        token: &Token,
+    ) -> Result<PostRevision, Self::Error>;
+
+    /// Построчное сравнение ревизий `from` и `to` публикации.
+    async fn diff_revisions(
+        &self,
+        post_id: PostId,
+        from: i32,
+        to: i32,
+        token: &Token,
+    ) -> Result<DiffRevisionsResponse, Self::Error>;
+
+    /// Восстановление публикации из ревизии.
+    async fn restore_revision(
+        &self,
+        post_id: PostId,
+        revision: i32,
+        token: &Token,
+    ) -> Result<Post, Self::Error>;
+
+    /// Реакция вида `kind` на публикацию.
+    async fn react(
+        &self,
+        post_id: PostId,
+        kind: &str,
+        token: &Token,
+    ) -> Result<ReactionsResponse, Self::Error>;
+
+    /// Снятие реакции вида `kind` с публикации.
+    async fn unreact(
+        &self,
+        post_id: PostId,
+        kind: &str,
+        token: &Token,
+    ) -> Result<ReactionsResponse, Self::Error>;
+
+    /// Подписка на автора `username`.
+    async fn follow(&self, username: &str, token: &Token) -> Result<(), Self::Error>;
+
+    /// Отписка от автора `username`.
+    async fn unfollow(&self, username: &str, token: &Token) -> Result<(), Self::Error>;
+
+    /// Подписчики пользователя, от недавно подписавшихся.
+    async fn list_followers(
+        &self,
+        username: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListFollowsResponse, Self::Error>;
+
+    /// Авторы, на которых подписан пользователь.
+    async fn list_following(
+        &self,
+        username: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<ListFollowsResponse, Self::Error>;
+
+    /// Персональная лента: публикации авторов, на которых подписан
+    /// текущий пользователь. Параметры те же, что у
+    /// [`ClientTransportExt::list_posts`].
+    async fn feed(
+        &self,
+        limit: u32,
+        offset: u32,
+        tag: Option<&str>,
+        after: Option<&str>,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error>;
+
+    /// Загрузка вложения (изображения или файла) с именем `file_name`.
+    async fn upload(
+        &self,
+        file_name: &str,
+        data: &[u8],
+        token: &Token,
+    ) -> Result<Attachment, Self::Error>;
+
+    /// Учётные записи пользователей (для администратора).
+    async fn list_users(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListUsersResponse, Self::Error>;
+
+    /// Смена роли пользователя.
+    async fn set_user_role(
+        &self,
+        cmd: UserRoleCmd,
+        token: &Token,
+    ) -> Result<UserAccount, Self::Error>;
+
+    /// Блокировка (`disabled = true`) или разблокировка учётной записи.
+    async fn set_user_disabled(
+        &self,
+        username: &str,
+        disabled: bool,
+        token: &Token,
+    ) -> Result<UserAccount, Self::Error>;
+
+    /// Снятие блокировки входа после неудачных попыток.
+    async fn unlock_user(&self, username: &str, token: &Token) -> Result<UserAccount, Self::Error>;
+
+    /// Записи журнала аудита (для администратора).
+    async fn list_audit_events(
+        &self,
+        query: &AuditQuery,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListAuditEventsResponse, Self::Error>;
+
+    /// Правила модерации (для модератора).
+    async fn list_moderation_rules(
+        &self,
+        token: &Token,
+    ) -> Result<ListModerationRulesResponse, Self::Error>;
+
+    /// Добавление правила модерации.
+    async fn create_moderation_rule(
+        &self,
+        cmd: ModerationRuleCmd,
+        token: &Token,
+    ) -> Result<ModerationRule, Self::Error>;
+
+    /// Изменение правила модерации.
+    async fn update_moderation_rule(
+        &self,
+        rule_id: i64,
+        cmd: ModerationRuleCmd,
+        token: &Token,
+    ) -> Result<ModerationRule, Self::Error>;
+
+    /// Удаление правила модерации.
+    async fn delete_moderation_rule(&self, rule_id: i64, token: &Token)
+    -> Result<(), Self::Error>;
+
+    /// Публикации, ожидающие проверки модератором.
+    async fn list_moderation_queue(
+        &self,
+        limit: u32,
+        offset: u32,
+        token: &Token,
+    ) -> Result<ListPostsResponse, Self::Error>;
+
+    /// Одобрение публикации из очереди модерации.
+    async fn approve_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error>;
+
+    /// Отклонение публикации из очереди модерации.
+    async fn reject_post(&self, post_id: PostId, token: &Token) -> Result<Post, Self::Error>;
 }
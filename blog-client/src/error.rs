@@ -2,16 +2,19 @@
 //!
 //! Ошибки домена/клиента (семантические, контекстные) — относятся к смыслу
 //! запроса/ответа и состоянию клиента:
-//! `NotFound`, `Unauthorized`, `InvalidRequest`, `InvalidUrl`, `TokenNotSet`,
-//! `ClientError`.
+//! `NotFound`, `Unauthorized`, `RateLimited`, `AccountLocked`, `InvalidRequest`,
+//! `InvalidUrl`, `TokenNotSet`, `ClientError`.
 //!
 //! Транспортные ошибки (сетевые/протокольные) — ошибки на уровне HTTP/gRPC и
 //! транспорта: `ReqwestError`, `TonicError`, `GrpcError(Status)`
 
-use reqwest::{Error as ReqwestError, StatusCode};
+use reqwest::{Error as ReqwestError, Response, StatusCode, header::RETRY_AFTER};
 use thiserror::Error;
 use tonic::{Code, Status, transport::Error as TonicError};
 
+/// Имя gRPC-метаданных с временем до повторного запроса (в секундах).
+const GRPC_RETRY_AFTER: &str = "retry-after";
+
 /// Ошибки клиента.
 #[derive(Error, Debug)]
 pub enum BlogClientError {
@@ -23,6 +26,21 @@ pub enum BlogClientError {
     #[error("Ошибка авторизации")]
     Unauthorized,
 
+    /// Превышен лимит частоты запросов; `retry_after` — через сколько секунд
+    /// запрос можно повторить, если сервер это сообщил.
+    #[error("Слишком много запросов{}", retry_hint(.retry_after))]
+    RateLimited {
+        /// Время до повторного запроса в секундах.
+        retry_after: Option<u64>,
+    },
+
+    /// Вход временно заблокирован после серии неудачных попыток.
+    #[error("Вход временно заблокирован{}", retry_hint(.retry_after))]
+    AccountLocked {
+        /// Время до снятия блокировки в секундах.
+        retry_after: Option<u64>,
+    },
+
     /// Некорректный запрос.
     #[error("Некорректный запрос: {0}")]
     InvalidRequest(String),
@@ -67,6 +85,39 @@ impl BlogClientError {
     pub(crate) fn client_error(msg_err: impl Into<String>) -> Self {
         Self::ClientError(msg_err.into())
     }
+
+    /// Проверить статус HTTP-ответа.
+    ///
+    /// В отличие от [`Response::error_for_status_ref`] сохраняет заголовок
+    /// `Retry-After` для ответов `429 Too Many Requests` и `423 Locked`.
+    pub(crate) fn check_response(res: &Response) -> Result<(), Self> {
+        let retry_after = || {
+            res.headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+        };
+
+        match res.status() {
+            StatusCode::TOO_MANY_REQUESTS => Err(Self::RateLimited {
+                retry_after: retry_after(),
+            }),
+            StatusCode::LOCKED => Err(Self::AccountLocked {
+                retry_after: retry_after(),
+            }),
+            _ => {
+                res.error_for_status_ref()?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Подсказка о времени ожидания для сообщения [`BlogClientError::RateLimited`].
+fn retry_hint(retry_after: &Option<u64>) -> String {
+    retry_after
+        .map(|secs| format!(", повторите через {secs} с"))
+        .unwrap_or_default()
 }
 
 impl From<ReqwestError> for BlogClientError {
@@ -77,6 +128,8 @@ impl From<ReqwestError> for BlogClientError {
             match status {
                 StatusCode::UNAUTHORIZED => BlogClientError::Unauthorized,
                 StatusCode::NOT_FOUND => BlogClientError::NotFound,
+                StatusCode::TOO_MANY_REQUESTS => BlogClientError::RateLimited { retry_after: None },
+                StatusCode::LOCKED => BlogClientError::AccountLocked { retry_after: None },
                 _ => BlogClientError::invalid_req(status.to_string()),
             }
         } else {
@@ -87,9 +140,25 @@ impl From<ReqwestError> for BlogClientError {
 
 impl From<Status> for BlogClientError {
     fn from(status: Status) -> Self {
+        // `RESOURCE_EXHAUSTED` означает и превышение размера вложения,
+        // а `FAILED_PRECONDITION` — и другие нарушения условий запроса:
+        // лимит запросов и блокировку входа сервер отмечает метаданными
+        // `retry-after`.
+        let retry_after = status
+            .metadata()
+            .get(GRPC_RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok());
+
         match status.code() {
             Code::NotFound => BlogClientError::NotFound,
             Code::Unauthenticated => BlogClientError::Unauthorized,
+            Code::ResourceExhausted if retry_after.is_some() => {
+                BlogClientError::RateLimited { retry_after }
+            }
+            Code::FailedPrecondition if retry_after.is_some() => {
+                BlogClientError::AccountLocked { retry_after }
+            }
             Code::AlreadyExists | Code::InvalidArgument => {
                 BlogClientError::InvalidRequest(status.to_string())
             }
@@ -20,7 +20,7 @@
 //!     let client = BlogClient::new(transport).await.unwrap();
 //!
 //!     // Получим список постов (API http-сервиса GET "/api/posts").
-//!     let post = client.list_posts(10, None).await.unwrap();
+//!     let post = client.list_posts(Some(10), None, None, None).await.unwrap();
 //! // }
 //!
 //! ## Структуры
@@ -41,20 +41,49 @@ mod config;
 pub mod error;
 pub mod models;
 
-pub use crate::models::{AuthResponse, Token};
+pub use crate::models::{AccountExport, AuditQuery, AuthResponse, PublishMode, Token};
 pub use error::BlogClientError;
-pub use proto_crate::proto_blog::{ListPostsResponse, Post};
+pub use proto_crate::proto_blog::{
+    AccountData, Attachment, AuditEvent, Comment, DiffLine, DiffRevisionsResponse, FollowUser,
+    ListAuditEventsResponse, ListCommentsResponse, ListFollowsResponse,
+    ListModerationRulesResponse, ListPostsResponse, ListRevisionsResponse, ListTagsResponse,
+    ListUsersResponse, ModerationRule, Post, PostRevision, PostSearchHit, ReactionCount,
+    ReactionsResponse, SearchPostsResponse, TagStat, User, UserAccount, UserProfile,
+};
 
 use crate::clients::{
     grpc_client::GrpcClient,
     http_client::HttpClient,
-    models::{PostCreateCmd, PostId, PostUpdateCmd, UserAuthCmd, UserRegisterCmd},
+    models::{
+        AccountDeleteCmd, CommentCreateCmd, EmailVerifyCmd, ModerationRuleCmd, PasswordChangeCmd,
+        PasswordForgotCmd, PasswordResetCmd, PostCreateCmd, PostId, PostPublishCmd, PostUpdateCmd,
+        ProfileUpdateCmd, RefreshCmd, UserAuthCmd, UserRegisterCmd, UserRoleCmd,
+    },
     traits::ClientTransportExt,
 };
 
 use reqwest::Url;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 use tonic::transport::Uri;
 
+/// Выполнить запрос, требующий авторизации.
+///
+/// Запрос `$call` формируется с токеном доступа `$token`. Если сервер
+/// отклонил токен (например, истёк срок его действия), сессия обновляется
+/// токеном обновления, и запрос повторяется один раз.
+macro_rules! with_token {
+    ($client:ident, |$token:ident| $call:expr) => {{
+        let $token = $client.get_token()?;
+        let result = $call.await;
+        if matches!(result, Err(BlogClientError::Unauthorized)) && $client.try_refresh().await? {
+            let $token = $client.get_token()?;
+            $call.await
+        } else {
+            result
+        }
+    }};
+}
+
 /// Доступный транспорт для запросов к API.
 ///
 /// Для выбора нужного заказа рекомендуется использовать предоставленные
@@ -117,8 +146,18 @@ pub struct BlogClient {
     http_client: Option<HttpClient>,
     /// Используемый клиент для gRPC-запросов.
     grpc_client: Option<GrpcClient>,
-    /// Токен для взаимодействия с API.
+    /// Токены сессии для взаимодействия с API. Обновляются при
+    /// автоматическом продлении сессии, поэтому доступны через `&self`.
+    session: Mutex<Session>,
+}
+
+/// Токены сессии пользователя.
+#[derive(Debug, Default)]
+struct Session {
+    /// Токен доступа.
     token: Option<Token>,
+    /// Токен обновления сессии.
+    refresh_token: Option<Token>,
 }
 
 impl BlogClient {
@@ -136,7 +175,7 @@ impl BlogClient {
     /// let transport = Transport::http(server_url).unwrap();
     ///
     /// let mut client = BlogClient::new(transport).await.unwrap();
-    /// let result = client.list_posts(10, 0).await.unwrap();
+    /// let result = client.list_posts(Some(10), Some(0), None, None).await.unwrap();
     /// ```
     ///
     /// ## Ошибки
@@ -162,19 +201,69 @@ impl BlogClient {
             transport,
             http_client,
             grpc_client,
-            token: None,
+            session: Mutex::default(),
         })
     }
 
     /// Добавить JWT-токен клиенту.
     pub fn set_token(&mut self, token: Token) {
-        self.token = Some(token);
+        self.session_mut().token = Some(token);
+    }
+
+    /// Добавить клиенту токен обновления сессии. С ним клиент продлевает
+    /// сессию, когда сервер отклоняет истёкший токен доступа.
+    pub fn set_refresh_token(&mut self, refresh_token: Token) {
+        self.session_mut().refresh_token = Some(refresh_token);
+    }
+
+    /// Текущий токен доступа. После автоматического продления сессии
+    /// отличается от установленного ранее.
+    pub fn token(&self) -> Option<Token> {
+        self.session().token.clone()
+    }
+
+    /// Текущий токен обновления сессии.
+    pub fn refresh_token(&self) -> Option<Token> {
+        self.session().refresh_token.clone()
     }
 
     /// Получить JWT-токен. Если токен отсутствует, вернётся ошибка
     /// [`BlogClientError::TokenNotSet`].
-    fn get_token(&self) -> Result<&Token, BlogClientError> {
-        self.token.as_ref().ok_or(BlogClientError::TokenNotSet)
+    fn get_token(&self) -> Result<Token, BlogClientError> {
+        self.token().ok_or(BlogClientError::TokenNotSet)
+    }
+
+    /// Доступ к токенам сессии.
+    fn session(&self) -> MutexGuard<'_, Session> {
+        self.session.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Изменяемый доступ к токенам сессии.
+    fn session_mut(&mut self) -> &mut Session {
+        self.session
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Сохранить токены из ответа сервера.
+    fn save_session(&self, auth_response: &AuthResponse) {
+        let mut session = self.session();
+        session.token = Some(auth_response.token.clone());
+        session.refresh_token = Some(auth_response.refresh_token.clone());
+    }
+
+    /// Продлить сессию после отказа сервера в доступе.
+    ///
+    /// ## Returns
+    ///
+    /// `true`, если получены новые токены и запрос стоит повторить;
+    /// `false`, если токена обновления нет или сервер его отклонил.
+    async fn try_refresh(&self) -> Result<bool, BlogClientError> {
+        match self.refresh().await {
+            Ok(_) => Ok(true),
+            Err(BlogClientError::TokenNotSet | BlogClientError::Unauthorized) => Ok(false),
+            Err(err) => Err(err),
+        }
     }
 
     /// Предоставить установленный транспорт (например, HTTP или gRPC) для
@@ -202,7 +291,7 @@ impl BlogClient {
         let register_cmd = UserRegisterCmd::new(username, email, password);
         let auth_response = self.transport().register_user(register_cmd).await?;
 
-        self.set_token(auth_response.token.clone());
+        self.save_session(&auth_response);
         Ok(auth_response)
     }
 
@@ -215,49 +304,307 @@ impl BlogClient {
         let auth_cmd = UserAuthCmd::new(username, password);
         let auth_response = self.transport().login_user(auth_cmd).await?;
 
-        self.set_token(auth_response.token.clone());
+        self.save_session(&auth_response);
         Ok(auth_response)
     }
 
-    /// Создание публикации.
+    /// Продление сессии: новая пара токенов взамен токена обновления.
+    ///
+    /// Обычно вызывать не требуется: клиент продлевает сессию сам, когда
+    /// сервер отклоняет истёкший токен доступа. Каждый токен обновления
+    /// действует один раз; повторное его использование сервер считает
+    /// кражей и завершает сессию.
+    pub async fn refresh(&self) -> Result<AuthResponse, BlogClientError> {
+        let refresh_token = self.refresh_token().ok_or(BlogClientError::TokenNotSet)?;
+
+        let refresh_cmd = RefreshCmd::new(&refresh_token);
+        let auth_response = self.transport().refresh_session(refresh_cmd).await?;
+
+        self.save_session(&auth_response);
+        Ok(auth_response)
+    }
+
+    /// Выход: сервер отзывает токены сессии, клиент их забывает.
+    pub async fn logout(&mut self) -> Result<(), BlogClientError> {
+        if let Some(refresh_token) = self.refresh_token() {
+            let logout_cmd = RefreshCmd::new(&refresh_token);
+            self.transport().logout(logout_cmd).await?;
+        }
+
+        *self.session_mut() = Session::default();
+        Ok(())
+    }
+
+    /// Смена пароля.
+    ///
+    /// Требуется предварительная авторизация. Сервер завершает все сессии
+    /// пользователя, клиент сохраняет токены новой сессии из ответа.
+    pub async fn change_password(
+        &self,
+        old_password: &str,
+        new_password: &str,
+    ) -> Result<AuthResponse, BlogClientError> {
+        let auth_response = with_token!(self, |token| {
+            let cmd = PasswordChangeCmd::new(old_password, new_password);
+            self.transport().change_password(cmd, &token)
+        })?;
+
+        self.save_session(&auth_response);
+        Ok(auth_response)
+    }
+
+    /// Запрос письма с токеном для сброса забытого пароля.
+    ///
+    /// Сервер отвечает одинаково независимо от того, зарегистрирован ли
+    /// адрес `email`.
+    pub async fn forgot_password(&self, email: &str) -> Result<(), BlogClientError> {
+        let cmd = PasswordForgotCmd::new(email);
+        self.transport().forgot_password(cmd).await
+    }
+
+    /// Установка нового пароля по токену сброса из письма.
+    ///
+    /// Токен действует один раз; после сброса все сессии пользователя
+    /// завершаются, и требуется новая авторизация.
+    pub async fn reset_password(
+        &self,
+        token: &str,
+        new_password: &str,
+    ) -> Result<(), BlogClientError> {
+        let cmd = PasswordResetCmd::new(token, new_password);
+        self.transport().reset_password(cmd).await
+    }
+
+    /// Подтверждение адреса электронной почты по токену из письма.
+    ///
+    /// Авторизация не требуется: токен сам указывает на учётную запись.
+    pub async fn verify_email(&self, token: &str) -> Result<User, BlogClientError> {
+        let cmd = EmailVerifyCmd::new(token);
+        self.transport().verify_email(cmd).await
+    }
+
+    /// Повторная отправка письма для подтверждения адреса.
+    ///
+    /// Требуется предварительная авторизация.
+    pub async fn resend_verification(&self) -> Result<(), BlogClientError> {
+        with_token!(self, |token| self.transport().resend_verification(&token))
+    }
+
+    /// Данные текущего пользователя.
+    ///
+    /// Требуется предварительная авторизация.
+    pub async fn me(&self) -> Result<User, BlogClientError> {
+        with_token!(self, |token| self.transport().get_me(&token))
+    }
+
+    /// Изменение профиля текущего пользователя.
+    ///
+    /// Требуется предварительная авторизация. Незаданные (`None`) поля не
+    /// меняются, пустая строка очищает отображаемое имя, информацию о себе
+    /// или аватар. Новый адрес электронной почты требует повторного
+    /// подтверждения: на него отправляется письмо.
+    pub async fn update_profile(
+        &self,
+        display_name: Option<&str>,
+        bio: Option<&str>,
+        avatar_url: Option<&str>,
+        email: Option<&str>,
+    ) -> Result<User, BlogClientError> {
+        with_token!(self, |token| {
+            let cmd = ProfileUpdateCmd::new(display_name, bio, avatar_url, email);
+            self.transport().update_profile(cmd, &token)
+        })
+    }
+
+    /// Выгрузка персональных данных: данные учётной записи и все
+    /// публикации пользователя, включая черновики и записи в корзине.
     ///
     /// Требуется предварительная авторизация.
-    pub async fn create_post(&self, title: &str, content: &str) -> Result<Post, BlogClientError> {
-        let token = self.get_token()?;
+    pub async fn export_my_data(&self) -> Result<AccountExport, BlogClientError> {
+        with_token!(self, |token| self.transport().export_my_data(&token))
+    }
+
+    /// Удаление учётной записи текущего пользователя.
+    ///
+    /// Требуется предварительная авторизация и текущий пароль `password`.
+    /// Режим `posts` определяет судьбу публикаций и комментариев: `delete`
+    /// удаляет их, `anonymize` передаёт служебной учётной записи. После
+    /// удаления сессия клиента сбрасывается.
+    pub async fn delete_my_account(
+        &mut self,
+        password: &str,
+        posts: &str,
+    ) -> Result<(), BlogClientError> {
+        with_token!(self, |token| {
+            let cmd = AccountDeleteCmd::new(password, posts);
+            self.transport().delete_my_account(cmd, &token)
+        })?;
 
-        let create_post_cmd = PostCreateCmd::new(title, content);
-        self.transport().create_post(create_post_cmd, token).await
+        *self.session_mut() = Session::default();
+        Ok(())
+    }
+
+    /// Создание публикации.
+    ///
+    /// Требуется предварительная авторизация. Теги можно не указывать
+    /// (пустой срез). Режим `mode` определяет, публикуется ли пост сразу,
+    /// сохраняется черновиком или откладывается до указанного времени.
+    pub async fn create_post(
+        &self,
+        title: &str,
+        content: &str,
+        tags: &[String],
+        mode: PublishMode,
+    ) -> Result<Post, BlogClientError> {
+        with_token!(self, |token| {
+            let create_post_cmd = PostCreateCmd::new(title, content, tags, mode);
+            self.transport().create_post(create_post_cmd, &token)
+        })
     }
 
     /// Получение публикации.
+    ///
+    /// Если клиент авторизован, автору доступны также его черновики
+    /// и отложенные публикации.
     pub async fn get_post(&self, post_id: i64) -> Result<Post, BlogClientError> {
         let post_id: PostId = post_id.into();
-        self.transport().get_post(post_id).await
+        self.transport()
+            .get_post(post_id, self.token().as_ref())
+            .await
+    }
+
+    /// Получение публикации по её человекочитаемому адресу (slug).
+    ///
+    /// Прежние адреса публикации также действительны; возвращённая
+    /// публикация содержит текущий адрес.
+    pub async fn get_post_by_slug(&self, slug: &str) -> Result<Post, BlogClientError> {
+        self.transport()
+            .get_post_by_slug(slug, self.token().as_ref())
+            .await
+    }
+
+    /// Публикация черновика или отложенной записи.
+    ///
+    /// Требуется предварительная авторизация. Если `publish_at` (unix-время,
+    /// секунды) не указан, пост публикуется сразу, иначе публикация
+    /// откладывается до этого времени.
+    pub async fn publish_post(
+        &self,
+        post_id: i64,
+        publish_at: Option<i64>,
+    ) -> Result<Post, BlogClientError> {
+        with_token!(self, |token| {
+            let publish_cmd = PostPublishCmd::new(post_id, publish_at);
+            self.transport().publish_post(publish_cmd, &token)
+        })
+    }
+
+    /// Черновики и отложенные публикации текущего пользователя.
+    ///
+    /// Требуется предварительная авторизация. Параметры `limit` и `offset`
+    /// по умолчанию равны 10 и 0.
+    pub async fn list_drafts(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<ListPostsResponse, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport()
+                .list_drafts(limit.unwrap_or(10), offset.unwrap_or(0), &token)
+        })
     }
 
     /// Обновление публикации.
     ///
-    /// Требуется предварительная авторизация.
+    /// Требуется предварительная авторизация. Если переданы `tags`, они
+    /// полностью заменяют прежний набор тегов.
     pub async fn update_post(
         &self,
         post_id: i64,
         title: Option<&str>,
         content: Option<&str>,
+        tags: Option<&[String]>,
     ) -> Result<Post, BlogClientError> {
-        let token = self.get_token()?;
-
-        let update_post_cmd = PostUpdateCmd::new(post_id, title, content);
-        self.transport().update_post(update_post_cmd, token).await
+        with_token!(self, |token| {
+            let update_post_cmd = PostUpdateCmd::new(post_id, title, content, tags);
+            self.transport().update_post(update_post_cmd, &token)
+        })
     }
 
     /// Удаление публикации.
     ///
-    /// Требуется предварительная авторизация.
+    /// Требуется предварительная авторизация. Публикация перемещается
+    /// в корзину и может быть восстановлена до окончательной очистки.
     pub async fn delete_post(&self, post_id: i64) -> Result<(), BlogClientError> {
-        let token = self.get_token()?;
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().delete_post(post_id, &token)
+        })
+    }
 
-        let post_id: PostId = post_id.into();
-        self.transport().delete_post(post_id, token).await
+    /// Публичный профиль автора.
+    pub async fn get_user_profile(&self, username: &str) -> Result<UserProfile, BlogClientError> {
+        self.transport().get_user_profile(username).await
+    }
+
+    /// Опубликованные записи автора, от новых к старым.
+    ///
+    /// Параметры `limit` и `offset` по умолчанию равны 10 и 0.
+    pub async fn list_user_posts(
+        &self,
+        username: &str,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<ListPostsResponse, BlogClientError> {
+        self.transport()
+            .list_user_posts(username, limit.unwrap_or(10), offset.unwrap_or(0))
+            .await
+    }
+
+    /// Публикации текущего пользователя в корзине.
+    ///
+    /// Требуется предварительная авторизация. Параметры `limit` и `offset`
+    /// по умолчанию равны 10 и 0.
+    pub async fn list_trash(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<ListPostsResponse, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport()
+                .list_trash(limit.unwrap_or(10), offset.unwrap_or(0), &token)
+        })
+    }
+
+    /// Восстановление публикации из корзины.
+    ///
+    /// Требуется предварительная авторизация.
+    pub async fn restore_post(&self, post_id: i64) -> Result<Post, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().restore_post(post_id, &token)
+        })
+    }
+
+    /// Скрытие публикации: она пропадает из лент и поиска и остаётся
+    /// видна только автору, который не может опубликовать её повторно.
+    ///
+    /// Требуется роль модератора или администратора.
+    pub async fn hide_post(&self, post_id: i64) -> Result<Post, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().hide_post(post_id, &token)
+        })
+    }
+
+    /// Возврат скрытой публикации читателям.
+    ///
+    /// Требуется роль модератора или администратора.
+    pub async fn unhide_post(&self, post_id: i64) -> Result<Post, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().unhide_post(post_id, &token)
+        })
     }
 
     /// Просмотр публикаций с пагинацией.
@@ -268,18 +615,471 @@ impl BlogClient {
     ///   значение равно 10.
     /// - `offset` — количество записей для пропуска (опционально), по
     ///   умолчанию значение равно 0.
+    /// - `tag` — выгрузить только публикации с этим тегом (опционально).
+    /// - `after` — курсор `next_cursor` из предыдущего ответа (опционально).
+    ///   Позволяет листать ленту без `offset`: глубокие страницы выгружаются
+    ///   так же быстро, как первая. Несовместим с ненулевым `offset`.
+    ///
+    /// Общее количество публикаций `total` сервер возвращает только для
+    /// первой страницы (без курсора). Если клиент авторизован, публикации
+    /// содержат реакции пользователя (`my_reactions`).
     ///
     /// Сервер может устанавливать ограничения по значениям.
     pub async fn list_posts(
         &self,
         limit: Option<u32>,
         offset: Option<u32>,
+        tag: Option<&str>,
+        after: Option<&str>,
     ) -> Result<ListPostsResponse, BlogClientError> {
         self.transport()
-            .list_posts(limit.unwrap_or(10), offset.unwrap_or(0))
+            .list_posts(
+                limit.unwrap_or(10),
+                offset.unwrap_or(0),
+                tag,
+                after,
+                self.token().as_ref(),
+            )
+            .await
+    }
+
+    /// Полнотекстовый поиск публикаций, от наиболее релевантных.
+    ///
+    /// ## Args
+    ///
+    /// - `query` — поисковый запрос; поддерживаются кавычки для точных фраз,
+    ///   `or` и `-` для исключения слов.
+    /// - `limit` — количество возвращаемых записей (опционально), по умолчанию
+    ///   значение равно 10.
+    /// - `offset` — количество записей для пропуска (опционально), по
+    ///   умолчанию значение равно 0.
+    ///
+    /// Во фрагменте `snippet` совпадения обрамлены `<mark>` и `</mark>`.
+    pub async fn search_posts(
+        &self,
+        query: &str,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<SearchPostsResponse, BlogClientError> {
+        self.transport()
+            .search_posts(query, limit.unwrap_or(10), offset.unwrap_or(0))
+            .await
+    }
+
+    /// Перечень тегов с количеством публикаций, от самых популярных.
+    pub async fn list_tags(&self) -> Result<ListTagsResponse, BlogClientError> {
+        self.transport().list_tags().await
+    }
+
+    /// Добавление комментария к публикации.
+    ///
+    /// Требуется предварительная авторизация.
+    pub async fn add_comment(
+        &self,
+        post_id: i64,
+        content: &str,
+    ) -> Result<Comment, BlogClientError> {
+        with_token!(self, |token| {
+            let comment_cmd = CommentCreateCmd::new(post_id, content);
+            self.transport().add_comment(comment_cmd, &token)
+        })
+    }
+
+    /// Просмотр комментариев к публикации с пагинацией.
+    ///
+    /// ## Args
+    ///
+    /// - `post_id` — id публикации
+    /// - `limit` — количество возвращаемых записей (опционально), по умолчанию
+    ///   значение равно 50.
+    /// - `offset` — количество записей для пропуска (опционально), по
+    ///   умолчанию значение равно 0.
+    pub async fn list_comments(
+        &self,
+        post_id: i64,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<ListCommentsResponse, BlogClientError> {
+        let post_id: PostId = post_id.into();
+        self.transport()
+            .list_comments(post_id, limit.unwrap_or(50), offset.unwrap_or(0))
+            .await
+    }
+
+    /// Удаление комментария.
+    ///
+    /// Требуется предварительная авторизация. Удалить комментарий может его
+    /// автор или автор публикации.
+    pub async fn delete_comment(
+        &self,
+        post_id: i64,
+        comment_id: i64,
+    ) -> Result<(), BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().delete_comment(post_id, comment_id, &token)
+        })
+    }
+
+    /// История изменений публикации, от новых ревизий к старым.
+    ///
+    /// Требуется предварительная авторизация, история доступна автору
+    /// публикации.
+    ///
+    /// ## Args
+    ///
+    /// - `post_id` — id публикации
+    /// - `limit` — количество возвращаемых записей (опционально), по умолчанию
+    ///   значение равно 10.
+    /// - `offset` — количество записей для пропуска (опционально), по
+    ///   умолчанию значение равно 0.
+    pub async fn list_revisions(
+        &self,
+        post_id: i64,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<ListRevisionsResponse, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().list_revisions(
+                post_id,
+                limit.unwrap_or(10),
+                offset.unwrap_or(0),
+                &token,
+            )
+        })
+    }
+
+    /// Получение отдельной ревизии публикации.
+    ///
+    /// Требуется предварительная авторизация.
+    pub async fn get_revision(
+        &self,
+        post_id: i64,
+        revision: i32,
+    ) -> Result<PostRevision, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().get_revision(post_id, revision, &token)
+        })
+    }
+
+    /// Построчное сравнение ревизий `from` и `to` публикации.
+    ///
+    /// Требуется предварительная авторизация. Каждая строка результата
+    /// помечена операцией `equal`, `insert` или `delete`.
+    pub async fn diff_revisions(
+        &self,
+        post_id: i64,
+        from: i32,
+        to: i32,
+    ) -> Result<DiffRevisionsResponse, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().diff_revisions(post_id, from, to, &token)
+        })
+    }
+
+    /// Восстановление публикации из ревизии.
+    ///
+    /// Требуется предварительная авторизация. Восстановленная версия
+    /// сохраняется новой ревизией.
+    pub async fn restore_revision(
+        &self,
+        post_id: i64,
+        revision: i32,
+    ) -> Result<Post, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().restore_revision(post_id, revision, &token)
+        })
+    }
+
+    /// Реакция на публикацию.
+    ///
+    /// Требуется предварительная авторизация. Повторная реакция того же
+    /// вида ничего не меняет.
+    ///
+    /// ## Args
+    ///
+    /// - `post_id` — id публикации
+    /// - `kind` — вид реакции: `like`, `love`, `laugh`, `wow` или `sad`
+    pub async fn react(
+        &self,
+        post_id: i64,
+        kind: &str,
+    ) -> Result<ReactionsResponse, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().react(post_id, kind, &token)
+        })
+    }
+
+    /// Снятие реакции с публикации.
+    ///
+    /// Требуется предварительная авторизация.
+    pub async fn unreact(
+        &self,
+        post_id: i64,
+        kind: &str,
+    ) -> Result<ReactionsResponse, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().unreact(post_id, kind, &token)
+        })
+    }
+
+    /// Подписка на автора.
+    ///
+    /// Требуется предварительная авторизация. Повторная подписка ничего
+    /// не меняет.
+    pub async fn follow(&self, username: &str) -> Result<(), BlogClientError> {
+        with_token!(self, |token| self.transport().follow(username, &token))
+    }
+
+    /// Отписка от автора.
+    ///
+    /// Требуется предварительная авторизация.
+    pub async fn unfollow(&self, username: &str) -> Result<(), BlogClientError> {
+        with_token!(self, |token| self.transport().unfollow(username, &token))
+    }
+
+    /// Подписчики пользователя, от недавно подписавшихся.
+    ///
+    /// Параметры `limit` и `offset` по умолчанию равны 10 и 0.
+    pub async fn list_followers(
+        &self,
+        username: &str,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<ListFollowsResponse, BlogClientError> {
+        self.transport()
+            .list_followers(username, limit.unwrap_or(10), offset.unwrap_or(0))
+            .await
+    }
+
+    /// Авторы, на которых подписан пользователь, от недавних подписок.
+    ///
+    /// Параметры `limit` и `offset` по умолчанию равны 10 и 0.
+    pub async fn list_following(
+        &self,
+        username: &str,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<ListFollowsResponse, BlogClientError> {
+        self.transport()
+            .list_following(username, limit.unwrap_or(10), offset.unwrap_or(0))
             .await
     }
 
+    /// Персональная лента: публикации авторов, на которых подписан
+    /// текущий пользователь, от новых к старым.
+    ///
+    /// Требуется предварительная авторизация. Параметры и пагинация те же,
+    /// что у [`BlogClient::list_posts`].
+    pub async fn feed(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        tag: Option<&str>,
+        after: Option<&str>,
+    ) -> Result<ListPostsResponse, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport()
+                .feed(limit.unwrap_or(10), offset.unwrap_or(0), tag, after, &token)
+        })
+    }
+
+    /// Загрузка вложения: изображения (PNG, JPEG, GIF, WebP) или документа
+    /// PDF.
+    ///
+    /// Требуется предварительная авторизация. Тип файла определяется
+    /// сервером по содержимому; в ответе — постоянный адрес файла
+    /// относительно адреса HTTP-сервера.
+    ///
+    /// ## Args
+    ///
+    /// - `file_name` — исходное имя файла
+    /// - `data` — содержимое файла
+    pub async fn upload(
+        &self,
+        file_name: &str,
+        data: Vec<u8>,
+    ) -> Result<Attachment, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport().upload(file_name, &data, &token)
+        })
+    }
+
+    /// Учётные записи пользователей, от ранее зарегистрированных.
+    ///
+    /// Требуется роль администратора. Параметры `limit` и `offset`
+    /// по умолчанию равны 10 и 0.
+    pub async fn list_users(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<ListUsersResponse, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport()
+                .list_users(limit.unwrap_or(10), offset.unwrap_or(0), &token)
+        })
+    }
+
+    /// Смена роли пользователя: `user`, `moderator` или `admin`.
+    ///
+    /// Требуется роль администратора. Новая роль действует после
+    /// обновления сессии пользователя.
+    pub async fn set_user_role(
+        &self,
+        username: &str,
+        role: &str,
+    ) -> Result<UserAccount, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport()
+                .set_user_role(UserRoleCmd::new(username, role), &token)
+        })
+    }
+
+    /// Блокировка (`disabled = true`) или разблокировка учётной записи.
+    /// Сессии заблокированного пользователя завершаются.
+    ///
+    /// Требуется роль администратора.
+    pub async fn set_user_disabled(
+        &self,
+        username: &str,
+        disabled: bool,
+    ) -> Result<UserAccount, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport()
+                .set_user_disabled(username, disabled, &token)
+        })
+    }
+
+    /// Снятие блокировки входа, наступившей после серии неудачных попыток.
+    ///
+    /// Требуется роль администратора.
+    pub async fn unlock_user(&self, username: &str) -> Result<UserAccount, BlogClientError> {
+        with_token!(self, |token| self.transport().unlock_user(username, &token))
+    }
+
+    /// Записи журнала аудита, подходящие под фильтры `query`, от новых
+    /// к старым.
+    ///
+    /// Требуется роль администратора. Параметры `limit` и `offset`
+    /// по умолчанию равны 10 и 0.
+    pub async fn list_audit_events(
+        &self,
+        query: &AuditQuery,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<ListAuditEventsResponse, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport().list_audit_events(
+                query,
+                limit.unwrap_or(10),
+                offset.unwrap_or(0),
+                &token,
+            )
+        })
+    }
+
+    /// Правила модерации: слова, фразы и регулярные выражения, по которым
+    /// проверяются публикации и комментарии.
+    ///
+    /// Требуется роль модератора или администратора.
+    pub async fn list_moderation_rules(
+        &self,
+    ) -> Result<ListModerationRulesResponse, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport().list_moderation_rules(&token)
+        })
+    }
+
+    /// Добавление правила модерации. Вид `kind`: `word`, `phrase` или
+    /// `regex`; действие `action`: `reject` (отклонить текст) или `hold`
+    /// (отправить публикацию на проверку).
+    ///
+    /// Требуется роль модератора или администратора.
+    pub async fn create_moderation_rule(
+        &self,
+        pattern: &str,
+        kind: &str,
+        action: &str,
+    ) -> Result<ModerationRule, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport()
+                .create_moderation_rule(ModerationRuleCmd::new(pattern, kind, action), &token)
+        })
+    }
+
+    /// Изменение правила модерации. Параметры — как у
+    /// [`BlogClient::create_moderation_rule`].
+    ///
+    /// Требуется роль модератора или администратора.
+    pub async fn update_moderation_rule(
+        &self,
+        rule_id: i64,
+        pattern: &str,
+        kind: &str,
+        action: &str,
+    ) -> Result<ModerationRule, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport().update_moderation_rule(
+                rule_id,
+                ModerationRuleCmd::new(pattern, kind, action),
+                &token,
+            )
+        })
+    }
+
+    /// Удаление правила модерации.
+    ///
+    /// Требуется роль модератора или администратора.
+    pub async fn delete_moderation_rule(&self, rule_id: i64) -> Result<(), BlogClientError> {
+        with_token!(self, |token| {
+            self.transport().delete_moderation_rule(rule_id, &token)
+        })
+    }
+
+    /// Очередь модерации: публикации, ожидающие проверки, от давно
+    /// ожидающих.
+    ///
+    /// Требуется роль модератора или администратора. Параметры `limit`
+    /// и `offset` по умолчанию равны 10 и 0.
+    pub async fn list_moderation_queue(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<ListPostsResponse, BlogClientError> {
+        with_token!(self, |token| {
+            self.transport()
+                .list_moderation_queue(limit.unwrap_or(10), offset.unwrap_or(0), &token)
+        })
+    }
+
+    /// Одобрение публикации из очереди модерации: она публикуется сразу
+    /// или в запланированное автором время.
+    ///
+    /// Требуется роль модератора или администратора.
+    pub async fn approve_post(&self, post_id: i64) -> Result<Post, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().approve_post(post_id, &token)
+        })
+    }
+
+    /// Отклонение публикации из очереди модерации: она скрывается
+    /// от читателей.
+    ///
+    /// Требуется роль модератора или администратора.
+    pub async fn reject_post(&self, post_id: i64) -> Result<Post, BlogClientError> {
+        with_token!(self, |token| {
+            let post_id: PostId = post_id.into();
+            self.transport().reject_post(post_id, &token)
+        })
+    }
+
     /// Возвращает `true`, если сервер работает в режиме `http`.
     pub fn is_http(&self) -> bool {
         self.http_client.is_some()
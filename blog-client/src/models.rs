@@ -1,7 +1,7 @@
 //! Клиентские типы и модели для обработки информации.
 
 use crate::config::DISPLAY_TOKEN_CHARS;
-use proto_crate::proto_blog::{AuthResponse as ProtoAuthResponse, User};
+use proto_crate::proto_blog::{AccountData, AuthResponse as ProtoAuthResponse, Post, User};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display};
 
@@ -45,13 +45,15 @@ impl Token {
     }
 }
 
-/// Успешный ответ при регистрации и авторизации.
+/// Успешный ответ при регистрации, авторизации и обновлении сессии.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthResponse {
     /// Пользователь.
     pub user: Option<User>,
-    /// JWT-токен пользователя.
+    /// Короткоживущий JWT-токен доступа.
     pub token: Token,
+    /// Токен обновления сессии. После использования заменяется новым.
+    pub refresh_token: Token,
 }
 
 impl From<ProtoAuthResponse> for AuthResponse {
@@ -59,6 +61,43 @@ impl From<ProtoAuthResponse> for AuthResponse {
         Self {
             user: resp.user,
             token: Token(resp.token),
+            refresh_token: Token(resp.refresh_token),
         }
     }
 }
+
+/// Режим публикации при создании поста.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PublishMode {
+    /// Опубликовать сразу.
+    #[default]
+    Now,
+    /// Сохранить как черновик (виден только автору).
+    Draft,
+    /// Отложить публикацию до указанного unix-времени (секунды).
+    At(i64),
+}
+
+/// Выгрузка персональных данных пользователя.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountExport {
+    /// Данные учётной записи.
+    pub account: AccountData,
+    /// Все публикации пользователя, включая черновики и записи в корзине.
+    pub posts: Vec<Post>,
+}
+
+/// Фильтры выборки из журнала аудита. Незаданные фильтры не применяются.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditQuery {
+    /// Имя участника.
+    pub actor: Option<String>,
+    /// Действие, например `login_failed` или `post_deleted`.
+    pub action: Option<String>,
+    /// Объект действия: `user:{id}` или `post:{id}`.
+    pub target: Option<String>,
+    /// Начало периода (unix-время, секунды), включительно.
+    pub since: Option<i64>,
+    /// Конец периода (unix-время, секунды), не включительно.
+    pub until: Option<i64>,
+}
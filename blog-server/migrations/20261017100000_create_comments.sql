@@ -0,0 +1,25 @@
+-- Добавление таблицы comments.
+CREATE TABLE IF NOT EXISTS comments (
+    id BIGSERIAL PRIMARY KEY,
+    post_id BIGINT NOT NULL,
+    author_id BIGINT NOT NULL,
+    content TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+    );
+
+-- Внешние ключи: комментарии удаляются вместе с публикацией или автором.
+ALTER TABLE comments
+    ADD CONSTRAINT fk_comments_post_id
+        FOREIGN KEY (post_id)
+            REFERENCES posts(id)
+            ON DELETE CASCADE;
+
+ALTER TABLE comments
+    ADD CONSTRAINT fk_comments_author_id
+        FOREIGN KEY (author_id)
+            REFERENCES users(id)
+            ON DELETE CASCADE;
+
+-- Индексы
+CREATE INDEX IF NOT EXISTS idx_comments_post_id_created_at ON comments(post_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_comments_author_id ON comments(author_id);
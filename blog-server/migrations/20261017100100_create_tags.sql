@@ -0,0 +1,30 @@
+-- Добавление таблицы tags и связующей таблицы post_tags.
+CREATE TABLE IF NOT EXISTS tags (
+    id BIGSERIAL PRIMARY KEY,
+    name VARCHAR(32) NOT NULL
+    );
+
+ALTER TABLE tags
+    ADD CONSTRAINT tags_name_key UNIQUE (name);
+
+CREATE TABLE IF NOT EXISTS post_tags (
+    post_id BIGINT NOT NULL,
+    tag_id BIGINT NOT NULL,
+    PRIMARY KEY (post_id, tag_id)
+    );
+
+-- Внешние ключи: связи удаляются вместе с публикацией или тегом.
+ALTER TABLE post_tags
+    ADD CONSTRAINT fk_post_tags_post_id
+        FOREIGN KEY (post_id)
+            REFERENCES posts(id)
+            ON DELETE CASCADE;
+
+ALTER TABLE post_tags
+    ADD CONSTRAINT fk_post_tags_tag_id
+        FOREIGN KEY (tag_id)
+            REFERENCES tags(id)
+            ON DELETE CASCADE;
+
+-- Индексы
+CREATE INDEX IF NOT EXISTS idx_post_tags_tag_id ON post_tags(tag_id);
@@ -0,0 +1,13 @@
+-- Полнотекстовый поиск по публикациям: заголовок весомее содержания,
+-- используются русская и английская конфигурации.
+ALTER TABLE posts
+    ADD COLUMN IF NOT EXISTS search_vector TSVECTOR
+        GENERATED ALWAYS AS (
+            setweight(to_tsvector('russian', coalesce(title, '')), 'A') ||
+            setweight(to_tsvector('english', coalesce(title, '')), 'A') ||
+            setweight(to_tsvector('russian', coalesce(content, '')), 'B') ||
+            setweight(to_tsvector('english', coalesce(content, '')), 'B')
+        ) STORED;
+
+-- Индексы
+CREATE INDEX IF NOT EXISTS idx_posts_search_vector ON posts USING GIN (search_vector);
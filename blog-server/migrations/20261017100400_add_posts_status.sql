@@ -0,0 +1,25 @@
+-- Жизненный цикл публикации: черновик, опубликована, отложенная.
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'post_status') THEN
+        CREATE TYPE post_status AS ENUM ('draft', 'published', 'scheduled');
+    END IF;
+END
+$$;
+
+-- `publish_at` — фактическое время публикации для опубликованных постов
+-- и запланированное для отложенных; у черновиков отсутствует.
+ALTER TABLE posts
+    ADD COLUMN IF NOT EXISTS status post_status NOT NULL DEFAULT 'published',
+    ADD COLUMN IF NOT EXISTS publish_at TIMESTAMP WITH TIME ZONE;
+
+UPDATE posts SET publish_at = created_at WHERE status = 'published' AND publish_at IS NULL;
+
+-- Лента строится только по опубликованным постам, в порядке публикации.
+DROP INDEX IF EXISTS idx_posts_created_at_id;
+CREATE INDEX IF NOT EXISTS idx_posts_published_feed
+    ON posts (publish_at DESC, id DESC) WHERE status = 'published';
+CREATE INDEX IF NOT EXISTS idx_posts_scheduled
+    ON posts (publish_at) WHERE status = 'scheduled';
+CREATE INDEX IF NOT EXISTS idx_posts_author_unpublished
+    ON posts (author_id, created_at DESC) WHERE status <> 'published';
@@ -0,0 +1,29 @@
+-- История изменений публикаций: снимок заголовка, содержания и тегов после
+-- каждого сохранения. Ревизия 1 — исходная версия публикации.
+CREATE TABLE IF NOT EXISTS post_revisions (
+    id BIGSERIAL PRIMARY KEY,
+    post_id BIGINT NOT NULL,
+    revision INTEGER NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    content TEXT NOT NULL,
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_post_revisions_post_id_revision UNIQUE (post_id, revision)
+    );
+
+-- Внешний ключ: история удаляется вместе с публикацией.
+ALTER TABLE post_revisions
+    ADD CONSTRAINT fk_post_revisions_post_id
+        FOREIGN KEY (post_id)
+            REFERENCES posts(id)
+            ON DELETE CASCADE;
+
+-- Текущее состояние существующих публикаций становится их первой ревизией.
+INSERT INTO post_revisions (post_id, revision, title, content, tags, created_at)
+SELECT p.id, 1, p.title, p.content,
+       ARRAY(
+           SELECT t.name::TEXT FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+           WHERE pt.post_id = p.id ORDER BY t.name
+       ),
+       COALESCE(p.updated_at, p.created_at)
+FROM posts p;
@@ -0,0 +1,17 @@
+-- Мягкое удаление публикаций: запись попадает в корзину автора и удаляется
+-- окончательно по истечении срока хранения.
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMP WITH TIME ZONE;
+
+-- Лента строится только по неудалённым опубликованным записям.
+DROP INDEX IF EXISTS idx_posts_published_feed;
+CREATE INDEX IF NOT EXISTS idx_posts_published_feed
+    ON posts (publish_at DESC, id DESC)
+    WHERE status = 'published' AND deleted_at IS NULL;
+
+-- Корзина автора и очистка по сроку хранения.
+CREATE INDEX IF NOT EXISTS idx_posts_deleted_at
+    ON posts (deleted_at)
+    WHERE deleted_at IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_posts_author_deleted
+    ON posts (author_id, deleted_at DESC)
+    WHERE deleted_at IS NOT NULL;
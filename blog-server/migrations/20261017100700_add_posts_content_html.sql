@@ -0,0 +1,7 @@
+-- Содержание публикаций трактуется как CommonMark; сервер хранит
+-- отрисованный безопасный HTML рядом с исходным текстом.
+--
+-- Для записей, созданных до миграции, поле остаётся пустым: сервер
+-- однократно заполняет его при запуске (BlogService::fill_content_html),
+-- отрисовывая HTML из содержания.
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS content_html TEXT;
@@ -0,0 +1,23 @@
+-- Человекочитаемые адреса публикаций. Текущий адрес хранится в posts.slug,
+-- а все когда-либо выданные публикации адреса — в post_slugs: прежние
+-- адреса продолжают вести на публикацию (с перенаправлением на текущий).
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS slug VARCHAR(80);
+
+-- Записи, созданные до миграции, получают адрес по id; адрес по заголовку
+-- назначается при следующем обновлении, прежний остаётся перенаправлением.
+UPDATE posts SET slug = 'post-' || id WHERE slug IS NULL;
+
+ALTER TABLE posts ALTER COLUMN slug SET NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_posts_slug ON posts (slug);
+
+CREATE TABLE IF NOT EXISTS post_slugs (
+    slug VARCHAR(80) PRIMARY KEY,
+    post_id BIGINT NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_post_slugs_post_id ON post_slugs (post_id);
+
+INSERT INTO post_slugs (slug, post_id)
+SELECT slug, id FROM posts
+ON CONFLICT (slug) DO NOTHING;
@@ -0,0 +1,7 @@
+-- Публичный профиль автора: краткая информация о себе.
+ALTER TABLE users ADD COLUMN IF NOT EXISTS bio TEXT NOT NULL DEFAULT '';
+
+-- Лента публикаций отдельного автора.
+CREATE INDEX IF NOT EXISTS idx_posts_author_published
+    ON posts (author_id, publish_at DESC, id DESC)
+    WHERE status = 'published' AND deleted_at IS NULL;
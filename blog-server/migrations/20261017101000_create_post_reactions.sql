@@ -0,0 +1,21 @@
+-- Реакции пользователей на публикации.
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'reaction_kind') THEN
+        CREATE TYPE reaction_kind AS ENUM ('like', 'love', 'laugh', 'wow', 'sad');
+    END IF;
+END
+$$;
+
+-- Пользователь ставит каждый вид реакции на публикацию не более одного раза.
+CREATE TABLE IF NOT EXISTS post_reactions (
+    post_id BIGINT NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    user_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    kind reaction_kind NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (post_id, user_id, kind)
+    );
+
+-- Реакции пользователя на страницу публикаций.
+CREATE INDEX IF NOT EXISTS idx_post_reactions_user_id
+    ON post_reactions (user_id, post_id);
@@ -0,0 +1,12 @@
+-- Подписки пользователей на авторов.
+CREATE TABLE IF NOT EXISTS follows (
+    follower_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    followee_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (follower_id, followee_id),
+    CHECK (follower_id <> followee_id)
+    );
+
+-- Подписчики автора.
+CREATE INDEX IF NOT EXISTS idx_follows_followee_id
+    ON follows (followee_id, created_at DESC);
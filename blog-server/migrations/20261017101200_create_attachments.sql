@@ -0,0 +1,17 @@
+-- Вложения публикаций: изображения и файлы, загруженные пользователями.
+-- Содержимое файлов хранится вне базы данных, по ключу `storage_key`.
+CREATE TABLE IF NOT EXISTS attachments (
+    id BIGSERIAL PRIMARY KEY,
+    owner_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    storage_key VARCHAR(64) NOT NULL UNIQUE,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    size_bytes BIGINT NOT NULL CHECK (size_bytes > 0),
+    width INTEGER CHECK (width > 0),
+    height INTEGER CHECK (height > 0),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+    );
+
+-- Вложения пользователя.
+CREATE INDEX IF NOT EXISTS idx_attachments_owner_id
+    ON attachments (owner_id, created_at DESC);
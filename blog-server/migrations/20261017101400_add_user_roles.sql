@@ -0,0 +1,17 @@
+-- Роли пользователей: модераторы и администраторы.
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'user_role') THEN
+        CREATE TYPE user_role AS ENUM ('user', 'moderator', 'admin');
+    END IF;
+END
+$$;
+
+-- `disabled_at` — время блокировки учётной записи администратором.
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS role user_role NOT NULL DEFAULT 'user',
+    ADD COLUMN IF NOT EXISTS disabled_at TIMESTAMP WITH TIME ZONE;
+
+-- Публикация, скрытая модератором: видна только автору и не может быть
+-- опубликована им повторно.
+ALTER TYPE post_status ADD VALUE IF NOT EXISTS 'hidden';
@@ -0,0 +1,40 @@
+-- Правила модерации текста: запрещённые слова, фразы и регулярные выражения.
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'moderation_rule_kind') THEN
+        CREATE TYPE moderation_rule_kind AS ENUM ('word', 'phrase', 'regex');
+    END IF;
+    IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'moderation_action') THEN
+        CREATE TYPE moderation_action AS ENUM ('reject', 'hold');
+    END IF;
+END
+$$;
+
+-- `action`: `reject` — текст отклоняется, `hold` — публикация ожидает
+-- проверки модератором.
+CREATE TABLE IF NOT EXISTS moderation_rules (
+    id BIGSERIAL PRIMARY KEY,
+    pattern VARCHAR(200) NOT NULL,
+    kind moderation_rule_kind NOT NULL,
+    action moderation_action NOT NULL,
+    created_by BIGINT REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE,
+    CONSTRAINT uq_moderation_rules_pattern UNIQUE (kind, pattern)
+    );
+
+-- Прежний встроенный список запрещённых выражений.
+INSERT INTO moderation_rules (pattern, kind, action)
+VALUES ('дурак', 'word', 'reject'),
+       ('глупец', 'word', 'reject'),
+       ('фронтенд', 'word', 'reject'),
+       ('legacy', 'word', 'reject'),
+       ('рефакторинг', 'word', 'reject'),
+       ('техдолг', 'word', 'reject'),
+       ('политик', 'word', 'reject'),
+       ('кому нужен ваш Rust', 'phrase', 'reject'),
+       ('я не тестировал, но должно работать', 'phrase', 'reject')
+ON CONFLICT DO NOTHING;
+
+-- Публикация, ожидающая проверки модератором: видна только автору.
+ALTER TYPE post_status ADD VALUE IF NOT EXISTS 'pending';
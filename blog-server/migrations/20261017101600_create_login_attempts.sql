@@ -0,0 +1,23 @@
+-- Состояние защиты от подбора пароля: число неудачных попыток входа подряд,
+-- время последней из них и время, до которого вход временно заблокирован.
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS failed_logins INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS last_failed_login_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN IF NOT EXISTS locked_until TIMESTAMP WITH TIME ZONE;
+
+-- Журнал попыток входа. `user_id` отсутствует, если пользователь
+-- с указанным именем не найден.
+CREATE TABLE IF NOT EXISTS login_attempts (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT REFERENCES users(id) ON DELETE CASCADE,
+    username VARCHAR(32) NOT NULL,
+    ip VARCHAR(45),
+    succeeded BOOLEAN NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+    );
+
+CREATE INDEX IF NOT EXISTS idx_login_attempts_ip_created_at
+    ON login_attempts (ip, created_at) WHERE NOT succeeded;
+
+CREATE INDEX IF NOT EXISTS idx_login_attempts_created_at
+    ON login_attempts (created_at);
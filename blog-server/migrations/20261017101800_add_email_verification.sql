@@ -0,0 +1,4 @@
+-- Время подтверждения адреса электронной почты. У неподтверждённых адресов
+-- отсутствует; при смене адреса сбрасывается.
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS email_verified_at TIMESTAMP WITH TIME ZONE;
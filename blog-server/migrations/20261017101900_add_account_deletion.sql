@@ -0,0 +1,45 @@
+-- Служебная учётная запись «удалённый пользователь»: ей передаются
+-- публикации, комментарии и вложения пользователей, удаливших учётную
+-- запись с обезличиванием. Имя не проходит проверку при регистрации,
+-- пароль не является хешем, а запись заблокирована, поэтому войти под ней
+-- нельзя.
+--
+-- Сервер находит эту запись по флагу is_deleted_placeholder, который нельзя
+-- получить при регистрации, а не по имени. Если имя deleted-user уже занято
+-- обычной учётной записью, миграция завершается ошибкой: иначе публикации
+-- удалённых пользователей достались бы ей. Конфликт разрешается вручную.
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS is_deleted_placeholder BOOLEAN NOT NULL DEFAULT FALSE;
+
+INSERT INTO users (username, email, password_hash, disabled_at, is_deleted_placeholder)
+VALUES ('deleted-user', 'deleted-user@localhost', '!', NOW(), TRUE)
+ON CONFLICT (username) DO NOTHING;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM users WHERE is_deleted_placeholder) THEN
+        RAISE EXCEPTION 'имя deleted-user занято обычной учётной записью';
+    END IF;
+END
+$$;
+
+CREATE UNIQUE INDEX IF NOT EXISTS users_deleted_placeholder_key
+    ON users (is_deleted_placeholder)
+    WHERE is_deleted_placeholder;
+
+-- Публикации и комментарии больше не удаляются вместе с автором неявно:
+-- при удалении учётной записи сервер удаляет или обезличивает их сам,
+-- по выбору пользователя.
+ALTER TABLE posts
+    DROP CONSTRAINT IF EXISTS fk_posts_author_id,
+    ADD CONSTRAINT fk_posts_author_id
+        FOREIGN KEY (author_id)
+            REFERENCES users(id)
+            ON DELETE RESTRICT;
+
+ALTER TABLE comments
+    DROP CONSTRAINT IF EXISTS fk_comments_author_id,
+    ADD CONSTRAINT fk_comments_author_id
+        FOREIGN KEY (author_id)
+            REFERENCES users(id)
+            ON DELETE RESTRICT;
@@ -0,0 +1,5 @@
+-- Редактируемый профиль пользователя: отображаемое имя и адрес аватара.
+-- Пустые значения означают, что поле не заполнено.
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS display_name TEXT NOT NULL DEFAULT '',
+    ADD COLUMN IF NOT EXISTS avatar_url TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,38 @@
+-- Журнал аудита: значимые для безопасности и изменяющие данные действия.
+-- `actor_id` не ссылается на `users`: записи сохраняются и после удаления
+-- учётной записи участника. `before` и `after` — краткое состояние объекта
+-- до и после действия.
+CREATE TABLE IF NOT EXISTS audit_events (
+    id BIGSERIAL PRIMARY KEY,
+    actor_id BIGINT,
+    actor VARCHAR(32) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    target VARCHAR(64),
+    ip VARCHAR(45),
+    user_agent VARCHAR(256),
+    request_id VARCHAR(64),
+    before JSONB,
+    after JSONB,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+    );
+
+CREATE INDEX IF NOT EXISTS idx_audit_events_created_at
+    ON audit_events (created_at DESC, id DESC);
+
+CREATE INDEX IF NOT EXISTS idx_audit_events_actor
+    ON audit_events (actor, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_audit_events_target
+    ON audit_events (target, created_at DESC);
+
+-- Записи журнала только добавляются: изменение и удаление запрещены.
+CREATE OR REPLACE FUNCTION audit_events_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_events is append-only';
+END
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_audit_events_append_only ON audit_events;
+CREATE TRIGGER trg_audit_events_append_only
+    BEFORE UPDATE OR DELETE ON audit_events
+    FOR EACH ROW EXECUTE FUNCTION audit_events_append_only();
@@ -0,0 +1,306 @@
+//! Бизнес-логика удаления учётной записи и выгрузки персональных данных.
+
+use crate::{
+    application::token_service::TokenService,
+    data::{post_repo::PostRepository, token_repo::TokenRepository, user_repo::UserRepository},
+    domain::{
+        account::{AccountExport, DeleteAccount},
+        types::DataId,
+        user::{AuthenticatedUser, User},
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+    infrastructure::storage::FileStorage,
+    settings::EXPORT_POSTS_PAGE_SIZE,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::{error, info, instrument, warn};
+
+/// Сервисы для удаления учётной записи её владельцем и выгрузки его
+/// персональных данных.
+pub(crate) struct AccountService<
+    U = dyn UserRepository,
+    P = dyn PostRepository,
+    T = dyn TokenRepository,
+    S = dyn FileStorage,
+> where
+    U: UserRepository + ?Sized + 'static,
+    P: PostRepository + ?Sized + 'static,
+    T: TokenRepository + ?Sized + 'static,
+    S: FileStorage + ?Sized + 'static,
+{
+    /// Репозиторий пользователей.
+    users: Arc<U>,
+    /// Репозиторий публикаций.
+    posts: Arc<P>,
+    /// Сервис сессий.
+    token_service: Arc<TokenService<T>>,
+    /// Хранилище файлов вложений.
+    storage: Arc<S>,
+}
+
+impl<U, P, T, S> AccountService<U, P, T, S>
+where
+    U: UserRepository + ?Sized + 'static,
+    P: PostRepository + ?Sized + 'static,
+    T: TokenRepository + ?Sized + 'static,
+    S: FileStorage + ?Sized + 'static,
+{
+    /// Создать сервис [`AccountService`].
+    pub(crate) fn new(
+        users: Arc<U>,
+        posts: Arc<P>,
+        token_service: Arc<TokenService<T>>,
+        storage: Arc<S>,
+    ) -> Self {
+        Self {
+            users,
+            posts,
+            token_service,
+            storage,
+        }
+    }
+
+    /// Выгрузить данные учётной записи и все публикации пользователя.
+    ///
+    /// Публикации передаются потоком: фоновая задача читает их страницами
+    /// по [`EXPORT_POSTS_PAGE_SIZE`] по мере того, как получатель забирает
+    /// предыдущие, и останавливается, если получатель закрыл поток.
+    #[instrument(skip_all, level = "debug", fields(username = %user.username))]
+    pub(crate) async fn export(
+        &self,
+        user: &AuthenticatedUser,
+    ) -> Result<AccountExport, DomainError> {
+        let account = self.get_account(user).await?;
+
+        let (tx, rx) = mpsc::channel(EXPORT_POSTS_PAGE_SIZE as usize);
+        let posts = Arc::clone(&self.posts);
+        let author_id = user.id.clone();
+        let username = user.username.clone();
+        tokio::spawn(async move {
+            let mut after: Option<DataId> = None;
+            let mut exported = 0;
+            loop {
+                let page = match posts
+                    .list_by_author_after(&author_id, after.as_ref(), EXPORT_POSTS_PAGE_SIZE)
+                    .await
+                {
+                    Ok(page) => page,
+                    Err(err) => {
+                        error!(
+                            error = %err,
+                            username = %username,
+                            "Не удалось прочитать публикации для выгрузки"
+                        );
+                        let _ = tx.send(Err(DomainError::server_err(err.to_string()))).await;
+                        return;
+                    }
+                };
+                let last_page = page.len() < EXPORT_POSTS_PAGE_SIZE as usize;
+                after = page.last().and_then(|post| post.id.clone());
+                for post in page {
+                    if tx.send(Ok(post)).await.is_err() {
+                        warn!(username = %username, "Выгрузка прервана получателем");
+                        return;
+                    }
+                    exported += 1;
+                }
+                if last_page {
+                    break;
+                }
+            }
+
+            info!(
+                event = "account_exported",
+                username = %username,
+                posts = exported,
+                "Выгружены персональные данные"
+            );
+        });
+
+        Ok(AccountExport {
+            account: account.into(),
+            posts: ReceiverStream::new(rx),
+            exported_at: Utc::now(),
+        })
+    }
+
+    /// Удалить учётную запись после подтверждения паролем.
+    ///
+    /// Все сессии пользователя завершаются. Публикации, комментарии и
+    /// вложения удаляются или передаются служебной учётной записи
+    /// согласно `cmd.posts`.
+    #[instrument(skip_all, level = "debug", fields(username = %user.username))]
+    pub(crate) async fn delete_account(
+        &self,
+        user: &AuthenticatedUser,
+        cmd: &DeleteAccount,
+    ) -> Result<(), DomainError> {
+        let account = self.get_account(user).await?;
+
+        let verified = cmd
+            .password
+            .verify_hash(&account.password_hash)
+            .map_err(|err| {
+                DomainError::invalid_credentials(format!("ошибка хеширования: {err}"))
+            })?;
+        if !verified {
+            warn!(
+                event = "account_deletion_rejected",
+                username = %user.username,
+                "Неверный пароль при удалении учётной записи"
+            );
+            return Err(DomainError::invalid_password("не совпадает с текущим"));
+        }
+
+        // Сведения о сессиях удаляются вместе с учётной записью, поэтому
+        // токены доступа запоминаются заранее, а отзываются только после
+        // успешного удаления.
+        let sessions = self.token_service.user_access_tokens(&user.id).await?;
+
+        let removed_files = self
+            .users
+            .delete_account(&user.id, cmd.posts)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+
+        if let Err(err) = self.token_service.revoke_access(sessions).await {
+            error!(
+                error = %err,
+                username = %user.username,
+                "Не удалось отозвать токены доступа удалённой учётной записи"
+            );
+        }
+
+        for key in &removed_files {
+            if let Err(err) = self.storage.delete(key).await {
+                error!(
+                    error = %err,
+                    key = %key,
+                    "Не удалось удалить файл вложения удалённой учётной записи"
+                );
+            }
+        }
+
+        info!(
+            event = "account_deleted",
+            username = %user.username,
+            mode = %cmd.posts,
+            "Учётная запись удалена"
+        );
+
+        Ok(())
+    }
+
+    /// Учётная запись авторизованного пользователя.
+    async fn get_account(&self, user: &AuthenticatedUser) -> Result<User, DomainError> {
+        self.users
+            .get_by_id(&user.id)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::fixtures, Repositories},
+        domain::{account::DeletionMode, post::PostStatus, role::Role, types::UserPassword},
+        infrastructure::{jwt::JwtService, storage::MemoryStorage},
+    };
+    use sqlx::Error as SqlxError;
+    use tokio_stream::StreamExt;
+
+    const PASSWORD: &str = "Correct_Horse_42";
+
+    fn service(repos: &Repositories, jwt_service: &JwtService) -> AccountService {
+        let token_service = TokenService::new(repos.tokens.clone(), jwt_service.clone());
+        AccountService::new(
+            repos.users.clone(),
+            repos.posts.clone(),
+            Arc::new(token_service),
+            Arc::new(MemoryStorage::default()),
+        )
+    }
+
+    /// Пользователь с паролем [`PASSWORD`] и токеном доступа его сессии.
+    async fn signed_in(
+        repos: &Repositories,
+        jwt_service: &JwtService,
+    ) -> (AuthenticatedUser, String) {
+        let user = fixtures::user(repos, "alice", Role::default()).await;
+        let hash = UserPassword::try_from(PASSWORD.to_string())
+            .unwrap()
+            .hash()
+            .unwrap();
+        repos.users.set_password(&user.id, &hash).await.unwrap();
+        let tokens = TokenService::new(repos.tokens.clone(), jwt_service.clone())
+            .issue(&user.id, &user.username, user.role)
+            .await
+            .unwrap();
+
+        (user, tokens.access_token)
+    }
+
+    fn deletion(password: &str) -> DeleteAccount {
+        DeleteAccount {
+            password: UserPassword::try_from(password.to_string()).unwrap(),
+            posts: DeletionMode::Delete,
+        }
+    }
+
+    #[actix_web::test]
+    async fn sessions_are_revoked_only_after_deletion() {
+        let repos = Repositories::memory();
+        let jwt_service = JwtService::from_secret("account-service-test-secret");
+        let service = service(&repos, &jwt_service);
+        let (user, access_token) = signed_in(&repos, &jwt_service).await;
+
+        let rejected = service
+            .delete_account(&user, &deletion("Wrong_Password_42"))
+            .await;
+        assert!(matches!(rejected, Err(DomainError::InvalidPassword(_))));
+        assert!(jwt_service.verify_token(&access_token).is_ok());
+
+        service
+            .delete_account(&user, &deletion(PASSWORD))
+            .await
+            .unwrap();
+        assert!(jwt_service.verify_token(&access_token).is_err());
+        assert!(matches!(
+            repos.users.get_by_id(&user.id).await,
+            Err(SqlxError::RowNotFound)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn export_reads_posts_page_by_page() {
+        let repos = Repositories::memory();
+        let jwt_service = JwtService::from_secret("account-service-test-secret");
+        let service = service(&repos, &jwt_service);
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let total = EXPORT_POSTS_PAGE_SIZE as usize + 5;
+        for _ in 0..total {
+            fixtures::post(&repos, &author.id, PostStatus::Draft).await;
+        }
+
+        let export = service.export(&author).await.unwrap();
+
+        let ids: Vec<i64> = export
+            .posts
+            .map(|post| post.unwrap().id.unwrap().0)
+            .collect()
+            .await;
+        assert_eq!(ids.len(), total);
+        assert!(ids.is_sorted());
+    }
+}
@@ -0,0 +1,254 @@
+//! Бизнес-логика администрирования учётных записей.
+
+use crate::{
+    application::token_service::TokenService,
+    data::{token_repo::TokenRepository, user_repo::UserRepository},
+    domain::{
+        role::{Permission, Role},
+        types::Username,
+        user::{AuthenticatedUser, ListUsers, UserAccount},
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+};
+use std::sync::Arc;
+use tracing::{error, info, instrument, warn};
+
+/// Сервисы для управления пользователями: роли и блокировка учётных записей.
+///
+/// Все действия, кроме назначения администраторов при запуске, требуют
+/// права [`Permission::ManageUsers`].
+pub(crate) struct AdminService<U = dyn UserRepository, T = dyn TokenRepository>
+where
+    U: UserRepository + ?Sized + 'static,
+    T: TokenRepository + ?Sized + 'static,
+{
+    /// Репозиторий пользователей.
+    repo: Arc<U>,
+    /// Сервис сессий: при блокировке сессии пользователя отзываются.
+    token_service: Arc<TokenService<T>>,
+}
+
+impl<U, T> AdminService<U, T>
+where
+    U: UserRepository + ?Sized + 'static,
+    T: TokenRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`AdminService`] с репозиторием пользователей и
+    /// сервисом сессий.
+    pub(crate) fn new(repo: Arc<U>, token_service: Arc<TokenService<T>>) -> Self {
+        Self {
+            repo,
+            token_service,
+        }
+    }
+
+    /// Предоставить учётные записи, от ранее зарегистрированных.
+    #[instrument(skip(self, admin), level = "debug", fields(admin = %admin.username))]
+    pub(crate) async fn list_users(
+        &self,
+        admin: &AuthenticatedUser,
+        limit: i32,
+        offset: i32,
+    ) -> Result<ListUsers, DomainError> {
+        admin.require(Permission::ManageUsers)?;
+
+        let (users, total) = self.repo.list(limit, offset).await.map_err(|err| {
+            error!(
+                error=%err,
+                "Не удалось получить из БД список пользователей"
+            );
+            DomainError::server_err(err.to_string())
+        })?;
+
+        Ok(ListUsers {
+            users: users.into_iter().map(UserAccount::from).collect(),
+            total,
+            limit,
+            offset,
+        })
+    }
+
+    /// Назначить пользователю роль.
+    ///
+    /// Если новая роль даёт меньше прав, все сессии пользователя отзываются
+    /// сразу, чтобы прежние права не действовали до истечения токена
+    /// доступа. Повышенная роль попадает в токен доступа при следующем
+    /// обновлении сессии. Изменить собственную роль нельзя: так
+    /// администратор не лишит себя прав по ошибке.
+    #[instrument(skip(self, admin), level = "debug", fields(admin = %admin.username))]
+    pub(crate) async fn set_role(
+        &self,
+        admin: &AuthenticatedUser,
+        username: &Username,
+        role: Role,
+    ) -> Result<UserAccount, DomainError> {
+        admin.require(Permission::ManageUsers)?;
+        let username = not_self(admin, username)?;
+
+        let previous = self
+            .repo
+            .get_by_username(&username)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+        let user = self
+            .repo
+            .set_role(&username, role)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+
+        if let Some(user_id) = user.id.as_ref().filter(|_| !role.includes(previous.role)) {
+            self.token_service.revoke_user(user_id).await?;
+        }
+
+        info!(username = %username, role = %role, "Изменена роль пользователя");
+
+        Ok(user.into())
+    }
+
+    /// Заблокировать или разблокировать учётную запись.
+    ///
+    /// Заблокированный пользователь не может войти, а все его сессии
+    /// отзываются сразу. Заблокировать себя нельзя.
+    #[instrument(skip(self, admin), level = "debug", fields(admin = %admin.username))]
+    pub(crate) async fn set_disabled(
+        &self,
+        admin: &AuthenticatedUser,
+        username: &Username,
+        disabled: bool,
+    ) -> Result<UserAccount, DomainError> {
+        admin.require(Permission::ManageUsers)?;
+        let username = not_self(admin, username)?;
+
+        let user = self
+            .repo
+            .set_disabled(&username, disabled)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+
+        if let Some(user_id) = user.id.as_ref().filter(|_| disabled) {
+            self.token_service.revoke_user(user_id).await?;
+        }
+
+        info!(username = %username, disabled, "Изменён статус учётной записи");
+
+        Ok(user.into())
+    }
+
+    /// Снять блокировку входа, наступившую после неудачных попыток, и
+    /// сбросить счётчик неудачных попыток.
+    #[instrument(skip(self, admin), level = "debug", fields(admin = %admin.username))]
+    pub(crate) async fn unlock(
+        &self,
+        admin: &AuthenticatedUser,
+        username: &Username,
+    ) -> Result<UserAccount, DomainError> {
+        admin.require(Permission::ManageUsers)?;
+        let username = username.to_lowercase();
+
+        let user = self
+            .repo
+            .reset_login_failures(&username)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+
+        warn!(
+            event = "account_unlocked",
+            admin = %admin.username,
+            username = %username,
+            "Администратор снял блокировку входа"
+        );
+
+        Ok(user.into())
+    }
+
+    /// Назначить администраторами пользователей из конфигурации сервера.
+    ///
+    /// Так появляется первый администратор. Ещё не зарегистрированные
+    /// пользователи пропускаются.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn grant_admins(&self, usernames: &[Username]) -> Result<(), DomainError> {
+        for username in usernames {
+            match self
+                .repo
+                .set_role(&username.to_lowercase(), Role::Admin)
+                .await
+            {
+                Ok(_) => info!(username = %username, "Пользователь назначен администратором"),
+                Err(sqlx::Error::RowNotFound) => {
+                    warn!(username = %username, "Администратор из конфигурации не зарегистрирован")
+                }
+                Err(err) => return Err(DomainError::server_err(err.to_string())),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Проверить, что действие направлено не на самого администратора.
+///
+/// ## Returns
+///
+/// Имя пользователя в нижнем регистре, как оно хранится в базе данных.
+fn not_self(admin: &AuthenticatedUser, username: &Username) -> Result<Username, DomainError> {
+    let username = username.to_lowercase();
+    if username == admin.username {
+        return Err(DomainError::api_error(
+            "нельзя изменить собственную учётную запись",
+        ));
+    }
+
+    Ok(username)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::fixtures, Repositories},
+        infrastructure::jwt::JwtService,
+    };
+
+    #[actix_web::test]
+    async fn role_downgrade_revokes_sessions() {
+        let repos = Repositories::memory();
+        let jwt_service = JwtService::from_secret("admin-service-test-secret");
+        let token_service = Arc::new(TokenService::new(repos.tokens.clone(), jwt_service.clone()));
+        let service = AdminService::new(repos.users.clone(), Arc::clone(&token_service));
+        let admin = fixtures::user(&repos, "root", Role::Admin).await;
+        let user = fixtures::user(&repos, "bob", Role::default()).await;
+        let access_token = token_service
+            .issue(&user.id, &user.username, user.role)
+            .await
+            .unwrap()
+            .access_token;
+
+        let denied = service.set_role(&user, &admin.username, Role::User).await;
+        assert!(matches!(denied, Err(DomainError::Forbidden)));
+
+        service
+            .set_role(&admin, &user.username, Role::Moderator)
+            .await
+            .unwrap();
+        assert!(jwt_service.verify_token(&access_token).is_ok());
+
+        let account = service
+            .set_role(&admin, &user.username, Role::User)
+            .await
+            .unwrap();
+        assert_eq!(account.role, Role::User);
+        assert!(jwt_service.verify_token(&access_token).is_err());
+    }
+}
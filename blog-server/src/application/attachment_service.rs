@@ -0,0 +1,126 @@
+//! Бизнес-логика вложений публикаций.
+
+use crate::{
+    data::attachment_repo::AttachmentRepository,
+    domain::{
+        attachment::{Attachment, NewAttachment},
+        types::{DataId, StorageKey},
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+    infrastructure::storage::FileStorage,
+};
+use std::{io, sync::Arc};
+use tracing::{error, info, instrument, warn};
+
+/// Сервисы для загрузки и выдачи вложений.
+///
+/// Содержимое файлов сохраняется в хранилище `S`, сведения о них (владелец,
+/// тип, размеры) — в репозитории `A`.
+pub(crate) struct AttachmentService<A = dyn AttachmentRepository, S = dyn FileStorage>
+where
+    A: AttachmentRepository + ?Sized + 'static,
+    S: FileStorage + ?Sized + 'static,
+{
+    /// Репозиторий вложений.
+    repo: Arc<A>,
+    /// Хранилище файлов.
+    storage: Arc<S>,
+    /// Максимальный размер вложения в байтах.
+    max_bytes: usize,
+}
+
+impl<A, S> AttachmentService<A, S>
+where
+    A: AttachmentRepository + ?Sized + 'static,
+    S: FileStorage + ?Sized + 'static,
+{
+    /// Создать сервис [`AttachmentService`] с репозиторием вложений,
+    /// хранилищем файлов и ограничением размера вложения.
+    pub(crate) fn new(repo: Arc<A>, storage: Arc<S>, max_bytes: usize) -> Self {
+        Self {
+            repo,
+            storage,
+            max_bytes,
+        }
+    }
+
+    /// Максимальный размер вложения в байтах.
+    pub(crate) fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Проверить и сохранить файл, загруженный пользователем `owner_id`.
+    ///
+    /// Если сведения о файле не удалось записать в базу данных, файл
+    /// удаляется из хранилища.
+    #[instrument(skip(self, data), fields(size = data.len()), level = "debug")]
+    pub(crate) async fn upload(
+        &self,
+        owner_id: &DataId,
+        file_name: &str,
+        data: &[u8],
+    ) -> Result<Attachment, DomainError> {
+        let new_attachment =
+            NewAttachment::from_upload(owner_id.clone(), file_name, data, self.max_bytes)?;
+        let key = &new_attachment.storage_key;
+
+        self.storage.save(key, data).await.map_err(|err| {
+            error!(
+                error=%err,
+                storage_key=%key,
+                "Не удалось сохранить файл вложения"
+            );
+            DomainError::server_err(err.to_string())
+        })?;
+
+        let attachment = match self.repo.create(&new_attachment).await {
+            Ok(attachment) => attachment,
+            Err(err) => {
+                error!(
+                    error=%err,
+                    storage_key=%key,
+                    "Не удалось записать вложение в БД"
+                );
+                if let Err(err) = self.storage.delete(key).await {
+                    warn!(error=%err, storage_key=%key, "Не удалось удалить файл вложения");
+                }
+                return Err(DomainError::server_err(err.to_string()));
+            }
+        };
+
+        info!(
+            attachment_id = %attachment.id,
+            owner_id = %owner_id,
+            content_type = %attachment.content_type,
+            "Загружено вложение");
+
+        Ok(attachment)
+    }
+
+    /// Получить вложение и содержимое его файла по ключу.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn open(
+        &self,
+        key: &StorageKey,
+    ) -> Result<(Attachment, Vec<u8>), DomainError> {
+        let attachment = self.repo.get_by_key(key).await.map_repo_err(RepoErrorMap {
+            not_found: DomainError::AttachmentNotFound,
+            unique_violations: None,
+        })?;
+
+        let data = self.storage.load(key).await.map_err(|err| {
+            if err.kind() == io::ErrorKind::NotFound {
+                warn!(storage_key=%key, "Файл вложения отсутствует в хранилище");
+                return DomainError::AttachmentNotFound;
+            }
+            error!(
+                error=%err,
+                storage_key=%key,
+                "Не удалось прочитать файл вложения"
+            );
+            DomainError::server_err(err.to_string())
+        })?;
+
+        Ok((attachment, data))
+    }
+}
@@ -0,0 +1,75 @@
+//! Бизнес-логика журнала аудита.
+
+use crate::{
+    data::audit_repo::AuditRepository,
+    domain::{
+        audit::{AuditFilter, ListAuditEvents, NewAuditEvent},
+        role::Permission,
+        user::AuthenticatedUser,
+    },
+    errors::DomainError,
+};
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+/// Сервисы журнала аудита: запись действий и выборка для администраторов.
+pub(crate) struct AuditService<L: AuditRepository + ?Sized + 'static = dyn AuditRepository> {
+    /// Репозиторий журнала аудита.
+    repo: Arc<L>,
+}
+
+impl<L> AuditService<L>
+where
+    L: AuditRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`AuditService`].
+    pub(crate) fn new(repo: Arc<L>) -> Self {
+        Self { repo }
+    }
+
+    /// Добавить запись в журнал.
+    ///
+    /// Действие к этому моменту уже выполнено, поэтому ошибка записи не
+    /// возвращается вызывающему, а только попадает в лог.
+    #[instrument(skip_all, level = "debug", fields(action = %event.action, actor = %event.actor))]
+    pub(crate) async fn record(&self, event: NewAuditEvent) {
+        if let Err(err) = self.repo.create(&event).await {
+            error!(
+                error = %err,
+                action = %event.action,
+                actor = %event.actor,
+                target = ?event.target,
+                "Не удалось записать действие в журнал аудита"
+            );
+        }
+    }
+
+    /// Предоставить записи журнала по фильтрам, от новых к старым.
+    ///
+    /// Требуется право [`Permission::ViewAuditLog`].
+    #[instrument(skip(self, admin), level = "debug", fields(admin = %admin.username))]
+    pub(crate) async fn list(
+        &self,
+        admin: &AuthenticatedUser,
+        mut filter: AuditFilter,
+    ) -> Result<ListAuditEvents, DomainError> {
+        admin.require(Permission::ViewAuditLog)?;
+        filter.validate()?;
+        filter.actor = filter.actor.map(|actor| actor.to_lowercase());
+
+        let (events, total) = self.repo.list(&filter).await.map_err(|err| {
+            error!(
+                error=%err,
+                "Не удалось получить из БД записи журнала аудита"
+            );
+            DomainError::server_err(err.to_string())
+        })?;
+
+        Ok(ListAuditEvents {
+            events,
+            total,
+            limit: filter.limit,
+            offset: filter.offset,
+        })
+    }
+}
@@ -2,37 +2,82 @@
 
 use crate::domain::types::Username;
 use crate::{
-    data::user_repo::UserRepository,
-    domain::user::{CreateUser, User},
+    application::audit_service::AuditService,
+    data::{
+        audit_repo::AuditRepository, login_attempt_repo::LoginAttemptRepository,
+        user_repo::UserRepository,
+    },
+    domain::{
+        audit::{user_summary, AuditAction, AuditTarget, NewAuditEvent, RequestContext},
+        login::{LoginPolicy, NewLoginAttempt},
+        types::DataId,
+        user::{CreateUser, LoginUser, User},
+    },
     errors::{DomainError, RepoErrorMap, SqlxResultExt},
+    settings::{LOGIN_ATTEMPTS_RETENTION, LOGIN_IP_WINDOW},
 };
-use std::sync::Arc;
-use tracing::{info, instrument};
+use chrono::Utc;
+use serde_json::json;
+use std::{net::IpAddr, sync::Arc};
+use tracing::{error, info, instrument, warn};
 
 /// Сервис аутентификации.
-pub(crate) struct AuthService<R: UserRepository + 'static> {
+pub(crate) struct AuthService<
+    R = dyn UserRepository,
+    A = dyn LoginAttemptRepository,
+    L = dyn AuditRepository,
+> where
+    R: UserRepository + ?Sized + 'static,
+    A: LoginAttemptRepository + ?Sized + 'static,
+    L: AuditRepository + ?Sized + 'static,
+{
     /// Репозиторий пользователей.
     repo: Arc<R>,
+    /// Журнал попыток входа.
+    attempts: Arc<A>,
+    /// Правила блокировки входа после неудачных попыток.
+    policy: LoginPolicy,
+    /// Журнал аудита: регистрация и попытки входа.
+    audit: Arc<AuditService<L>>,
 }
 
-impl<R> AuthService<R>
+impl<R, A, L> AuthService<R, A, L>
 where
-    R: UserRepository + 'static,
+    R: UserRepository + ?Sized + 'static,
+    A: LoginAttemptRepository + ?Sized + 'static,
+    L: AuditRepository + ?Sized + 'static,
 {
-    /// Создать сервис [`AuthService`] с репозиторием пользователей.
-    pub(crate) fn new(repo: Arc<R>) -> Self {
-        Self { repo }
+    /// Создать сервис [`AuthService`] с репозиторием пользователей,
+    /// журналом попыток входа, правилами блокировки входа и журналом
+    /// аудита.
+    pub(crate) fn new(
+        repo: Arc<R>,
+        attempts: Arc<A>,
+        policy: LoginPolicy,
+        audit: Arc<AuditService<L>>,
+    ) -> Self {
+        Self {
+            repo,
+            attempts,
+            policy,
+            audit,
+        }
     }
 
     /// Создать нового пользователя на основе [`CreateUser`].
     ///
     /// За счёт использования новых типов (например, [`types::Username`])
-    /// данные валидированы на этапе создания.
+    /// данные валидированы на этапе создания. Регистрация записывается в
+    /// журнал аудита со сведениями о запросе `ctx`.
     #[instrument(
-        skip(self, create_user),
+        skip(self, create_user, ctx),
         level = "debug",
         fields(username = %create_user.username, email=%create_user.email))]
-    pub(crate) async fn create_user(&self, create_user: &CreateUser) -> Result<User, DomainError> {
+    pub(crate) async fn create_user(
+        &self,
+        create_user: &CreateUser,
+        ctx: &RequestContext,
+    ) -> Result<User, DomainError> {
         let password_hash = create_user
             .password
             .hash()
@@ -52,6 +97,18 @@ where
 
         info!("Создан новый пользователь: {}", user.username);
 
+        let mut event = NewAuditEvent::new(
+            AuditAction::UserRegistered,
+            user.id.clone(),
+            user.username.to_string(),
+            ctx,
+        )
+        .with_changes(None, Some(user_summary(&user)));
+        if let Some(id) = &user.id {
+            event = event.with_target(AuditTarget::User(id.clone()));
+        }
+        self.audit.record(event).await;
+
         Ok(user)
     }
 
@@ -67,4 +124,209 @@ where
                 unique_violations: None,
             })
     }
+
+    /// Авторизовать пользователя по имени и паролю. Сведения о запросе
+    /// `ctx` (IP-адрес клиента и др.) используются для защиты от подбора
+    /// пароля и записываются в журнал аудита вместе с исходом попытки.
+    ///
+    /// Каждая попытка записывается в журнал. Неудачи учитываются по
+    /// учётной записи и по IP-адресу: между попытками выдерживается
+    /// растущая задержка, а после серии неудач вход временно блокируется
+    /// (см. [`LoginPolicy`]).
+    ///
+    /// ## Errors
+    ///
+    /// - [`DomainError::RateLimited`] — слишком частые попытки;
+    /// - [`DomainError::AccountLocked`] — вход временно заблокирован;
+    /// - [`DomainError::InvalidPassword`] — неверный пароль;
+    /// - [`DomainError::AccountDisabled`] — учётная запись заблокирована
+    ///   администратором.
+    #[instrument(skip(self, login_user, ctx), level = "debug", fields(username = %login_user.username))]
+    pub(crate) async fn login(
+        &self,
+        login_user: &LoginUser,
+        ctx: &RequestContext,
+    ) -> Result<User, DomainError> {
+        let result = self.authenticate(login_user, ctx.ip).await;
+
+        let event = match &result {
+            Ok(user) => {
+                let event = NewAuditEvent::new(
+                    AuditAction::LoginSucceeded,
+                    user.id.clone(),
+                    user.username.to_string(),
+                    ctx,
+                );
+                match &user.id {
+                    Some(id) => event.with_target(AuditTarget::User(id.clone())),
+                    None => event,
+                }
+            }
+            Err(err) => NewAuditEvent::new(
+                AuditAction::LoginFailed,
+                None,
+                login_user.username.to_lowercase().to_string(),
+                ctx,
+            )
+            .with_changes(None, Some(json!({ "reason": err.to_string() }))),
+        };
+        self.audit.record(event).await;
+
+        result
+    }
+
+    /// Проверить попытку входа с IP-адреса `ip` (см. [`Self::login`]).
+    async fn authenticate(
+        &self,
+        login_user: &LoginUser,
+        ip: Option<IpAddr>,
+    ) -> Result<User, DomainError> {
+        let now = Utc::now();
+        let username = login_user.username.to_lowercase();
+
+        if let Some(ip) = ip {
+            let (failures, oldest) = self
+                .attempts
+                .count_ip_failures(&ip, now - LOGIN_IP_WINDOW)
+                .await
+                .map_err(|err| DomainError::server_err(err.to_string()))?;
+            self.policy
+                .check_ip(failures, oldest, now)
+                .inspect_err(|_| {
+                    warn!(
+                        event = "login_ip_limited",
+                        ip = %ip,
+                        failures,
+                        "Слишком много неудачных попыток входа с IP-адреса"
+                    )
+                })?;
+        }
+
+        let user = match self.get_user(&username).await {
+            Ok(user) => user,
+            Err(err @ DomainError::UserNotFound) => {
+                self.record_attempt(None, &username, ip, false).await?;
+                return Err(err);
+            }
+            Err(err) => return Err(err),
+        };
+        let user_id = user
+            .id
+            .clone()
+            .ok_or_else(|| DomainError::server_err("Пользователь не имеет ID"))?;
+
+        if let Err(err) = self.policy.check(&user, now) {
+            warn!(
+                event = "login_rejected",
+                username = %username,
+                ip = ?ip,
+                error = %err,
+                "Попытка входа до истечения задержки или блокировки"
+            );
+            self.record_attempt(Some(user_id), &username, ip, false).await?;
+            return Err(err);
+        }
+
+        if !verify_password(login_user, &user)? {
+            let user = self
+                .repo
+                .record_login_failure(
+                    &user_id,
+                    now,
+                    self.policy.max_failures,
+                    self.policy.locked_until(now),
+                )
+                .await
+                .map_err(|err| DomainError::server_err(err.to_string()))?;
+            self.record_attempt(Some(user_id), &username, ip, false).await?;
+
+            if user.locked_until.is_some_and(|until| until > now) {
+                warn!(
+                    event = "account_locked",
+                    username = %username,
+                    ip = ?ip,
+                    locked_until = ?user.locked_until,
+                    "Вход заблокирован после неудачных попыток"
+                );
+                return Err(DomainError::AccountLocked {
+                    retry_after: self.policy.lockout.num_seconds().unsigned_abs(),
+                });
+            }
+
+            warn!(
+                event = "login_failed",
+                username = %username,
+                ip = ?ip,
+                failed_logins = user.failed_logins,
+                "Неудачная попытка входа"
+            );
+            return Err(DomainError::invalid_password(""));
+        }
+
+        if user.is_disabled() {
+            self.record_attempt(Some(user_id), &username, ip, false).await?;
+            return Err(DomainError::AccountDisabled);
+        }
+
+        let user = if user.failed_logins > 0 || user.locked_until.is_some() {
+            self.repo
+                .reset_login_failures(&username)
+                .await
+                .map_err(|err| DomainError::server_err(err.to_string()))?
+        } else {
+            user
+        };
+        self.record_attempt(Some(user_id), &username, ip, true).await?;
+
+        Ok(user)
+    }
+
+    /// Удалить из журнала попытки входа старше
+    /// [`LOGIN_ATTEMPTS_RETENTION`].
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn purge_login_attempts(&self) -> Result<u64, DomainError> {
+        let purged = self
+            .attempts
+            .purge(Utc::now() - LOGIN_ATTEMPTS_RETENTION)
+            .await
+            .map_err(|err| {
+                error!(error = %err, "Не удалось очистить журнал попыток входа");
+                DomainError::server_err(err.to_string())
+            })?;
+
+        if purged > 0 {
+            info!(purged, "Журнал попыток входа очищен");
+        }
+
+        Ok(purged)
+    }
+
+    /// Записать попытку входа в журнал.
+    async fn record_attempt(
+        &self,
+        user_id: Option<DataId>,
+        username: &Username,
+        ip: Option<IpAddr>,
+        succeeded: bool,
+    ) -> Result<(), DomainError> {
+        let attempt = NewLoginAttempt {
+            user_id,
+            username: username.clone(),
+            ip,
+            succeeded,
+        };
+
+        self.attempts.create(&attempt).await.map_err(|err| {
+            error!(error = %err, "Не удалось записать попытку входа");
+            DomainError::server_err(err.to_string())
+        })
+    }
+}
+
+/// Проверить пароль авторизуемого пользователя.
+fn verify_password(login_user: &LoginUser, user: &User) -> Result<bool, DomainError> {
+    login_user
+        .password
+        .verify_hash(&user.password_hash)
+        .map_err(|err| DomainError::invalid_credentials(format!("ошибка хеширования: {err}")))
 }
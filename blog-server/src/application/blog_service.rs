@@ -1,33 +1,79 @@
 //! Бизнес-логика блога.
 
 use crate::{
-    data::post_repo::PostRepository,
+    application::audit_service::AuditService,
+    data::{audit_repo::AuditRepository, post_repo::PostRepository, user_repo::UserRepository},
     domain::{
-        post::{CreatePost, EditPostCommand, ListPosts, Post},
-        types::DataId,
+        audit::{post_summary, AuditAction, AuditTarget, NewAuditEvent, RequestContext},
+        moderation::ModerationFilter,
+        post::{
+            CreatePost, EditPostCommand, ListPosts, ListPostsParams, ListTags, Post, PostStatus,
+            SearchPosts,
+        },
+        role::Permission,
+        types::{DataId, SearchQuery, Slug, Tag},
+        user::AuthenticatedUser,
+        verification::EmailPolicy,
     },
     errors::{DomainError, RepoErrorMap, SqlxResultExt},
+    settings::{CONTENT_HTML_FILL_BATCH, POST_TAGS_MAX},
 };
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+use sqlx::Error as SqlxError;
 use std::sync::Arc;
 use tracing::{error, info, instrument};
 
 /// Сервисы для взаимодействия с записями блога.
-pub(crate) struct BlogService<R: PostRepository + 'static> {
+pub(crate) struct BlogService<
+    R = dyn PostRepository,
+    U = dyn UserRepository,
+    L = dyn AuditRepository,
+> where
+    R: PostRepository + ?Sized + 'static,
+    U: UserRepository + ?Sized + 'static,
+    L: AuditRepository + ?Sized + 'static,
+{
     repo: Arc<R>,
+    /// Репозиторий пользователей: проверка адреса автора.
+    users: Arc<U>,
+    /// Правила модерации публикаций.
+    filter: ModerationFilter,
+    /// Требования к подтверждению адреса электронной почты авторов.
+    email_policy: EmailPolicy,
+    /// Журнал аудита: создание, изменение и удаление публикаций.
+    audit: Arc<AuditService<L>>,
 }
 
-impl<R> BlogService<R>
+impl<R, U, L> BlogService<R, U, L>
 where
-    R: PostRepository + 'static,
+    R: PostRepository + ?Sized + 'static,
+    U: UserRepository + ?Sized + 'static,
+    L: AuditRepository + ?Sized + 'static,
 {
-    /// Создать сервис [`AuthService`] с репозиторием пользователей.
-    pub(crate) fn new(repo: Arc<R>) -> Self {
-        Self { repo }
+    /// Создать сервис [`BlogService`] с репозиторием публикаций, правилами
+    /// модерации, требованиями к адресу электронной почты авторов и
+    /// журналом аудита.
+    pub(crate) fn new(
+        repo: Arc<R>,
+        users: Arc<U>,
+        filter: ModerationFilter,
+        email_policy: EmailPolicy,
+        audit: Arc<AuditService<L>>,
+    ) -> Self {
+        Self {
+            repo,
+            users,
+            filter,
+            email_policy,
+            audit,
+        }
     }
 
-    /// Получить публикацию по id.
+    /// Получить публикацию по id, независимо от её статуса.
     ///
-    /// Метод открытый, проверки на авторство не требуется.
+    /// Проверки на авторство не проводится: метод предназначен для внутренних
+    /// сценариев, где доступ проверяется отдельно.
     #[instrument(skip(self), level = "debug")]
     pub(crate) async fn get_post(&self, post_id: &DataId) -> Result<Post, DomainError> {
         let post = self.repo.get(post_id).await.map_repo_err(RepoErrorMap {
@@ -38,17 +84,70 @@ where
         Ok(post)
     }
 
+    /// Получить публикацию по id для просмотра.
+    ///
+    /// Опубликованные записи доступны всем; черновики и отложенные — только
+    /// автору (`viewer`), для остальных они не существуют.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn get_visible_post(
+        &self,
+        post_id: &DataId,
+        viewer: Option<&DataId>,
+    ) -> Result<Post, DomainError> {
+        load_visible_post(self.repo.as_ref(), post_id, viewer).await
+    }
+
+    /// Получить публикацию для просмотра по её адресу.
+    ///
+    /// Подходит как текущий, так и прежний адрес публикации; вызывающий
+    /// сравнивает запрошенный адрес с [`Post::slug`], чтобы перенаправить
+    /// на текущий. Видимость — как у [`Self::get_visible_post`].
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn get_visible_post_by_slug(
+        &self,
+        slug: &Slug,
+        viewer: Option<&DataId>,
+    ) -> Result<Post, DomainError> {
+        let post = self
+            .repo
+            .get_by_slug(slug)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::PostNotFound,
+                unique_violations: None,
+            })?;
+
+        visible_to(post, viewer)
+    }
+
     /// Создание публикации (поста).
     ///
     /// Данные принимаются в обёртке новых типов (например, [`CreatePost`],
     /// которые обеспечивают базовую валидацию и гарантируют типы.
-    #[instrument(skip(self, new_post), level = "debug", fields(title=%new_post.title, author=%author))]
+    ///
+    /// Если этого требуют настройки, адрес электронной почты автора должен
+    /// быть подтверждён. Создание записывается в журнал аудита со
+    /// сведениями о запросе `ctx`.
+    #[instrument(skip(self, new_post, user, ctx), level = "debug", fields(title=%new_post.title, author=%user.id))]
     pub(crate) async fn create_post(
         &self,
         new_post: &CreatePost,
-        author: &DataId,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
     ) -> Result<Post, DomainError> {
-        let post = Post::new_by_create(new_post.clone(), author.clone());
+        let author = &user.id;
+        if self.email_policy.required_to_post {
+            let user = self.users.get_by_id(author).await.map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+            self.email_policy.check_can_post(&user)?;
+        }
+
+        let mut post = Post::new_by_create(new_post.clone(), author.clone())?;
+        check_tags_count(&post)?;
+        self.filter.moderate_post(&mut post)?;
+
         let post = self.repo.create(&post).await.map_err(|err| {
             error!(
                 error=%err,
@@ -63,47 +162,232 @@ where
             title = %post.title,
             post_id = ?post.id,
             author_id = %post.author_id,
+            status = %post.status,
             "Создана новая запись в блоге");
 
+        let after = post_summary(&post);
+        self.record(
+            AuditAction::PostCreated,
+            user,
+            ctx,
+            post.id.as_ref(),
+            None,
+            Some(after),
+        )
+        .await;
+
         Ok(post)
     }
 
     /// Предоставить список опубликованных постов всех авторов.
+    ///
+    /// Если передан `params.tag`, выгружаются только публикации с этим тегом,
+    /// если `params.author_id` — только публикации этого автора.
+    /// Если передан `params.after`, выгрузка продолжается с позиции курсора;
+    /// общее количество публикаций в этом случае не считается.
     #[instrument(skip(self), level = "debug")]
     pub(crate) async fn list_posts(
         &self,
+        mut params: ListPostsParams,
+    ) -> Result<ListPosts, DomainError> {
+        if params.after.is_some() && params.offset > 0 {
+            return Err(DomainError::invalid_cursor(
+                "курсор нельзя использовать вместе с offset",
+            ));
+        }
+
+        params.tag = params.tag.as_ref().map(Tag::to_lowercase);
+        let log_err = |err: SqlxError| {
+            error!(
+                error=%err,
+                "Не удалось получить из БД список постов"
+            );
+            DomainError::server_err(err.to_string())
+        };
+
+        let (posts, next_cursor) = self.repo.list(&params).await.map_err(log_err)?;
+        let total = match params.after {
+            Some(_) => None,
+            None => Some(self.repo.count(&params).await.map_err(log_err)?),
+        };
+
+        Ok(ListPosts::new(
+            posts,
+            total,
+            params.limit,
+            params.offset,
+            next_cursor,
+        ))
+    }
+
+    /// Предоставить персональную ленту: опубликованные записи авторов,
+    /// на которых подписан пользователь.
+    ///
+    /// Пагинация и фильтры те же, что у [`BlogService::list_posts`].
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn feed(
+        &self,
+        user_id: &DataId,
+        mut params: ListPostsParams,
+    ) -> Result<ListPosts, DomainError> {
+        params.followed_by = Some(user_id.clone());
+        self.list_posts(params).await
+    }
+
+    /// Предоставить черновики и отложенные публикации автора.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn list_drafts(
+        &self,
+        author_id: &DataId,
         limit: i32,
         offset: i32,
     ) -> Result<ListPosts, DomainError> {
-        let (posts, total) = self.repo.list(limit, offset).await.map_err(|err| {
+        let (posts, total) = self
+            .repo
+            .list_unpublished(author_id, limit, offset)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    author_id=%author_id,
+                    "Не удалось получить из БД черновики автора"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        Ok(ListPosts::new(posts, Some(total), limit, offset, None))
+    }
+
+    /// Опубликовать черновик или отложенную запись сразу, либо
+    /// запланировать публикацию на `publish_at`.
+    ///
+    /// Проводится проверка, что автор публикации совпадает с авторизованным
+    /// пользователем. Смена статуса записывается в журнал аудита.
+    #[instrument(skip(self, user, ctx), level = "debug", fields(user_id = %user.id))]
+    pub(crate) async fn publish_post(
+        &self,
+        post_id: &DataId,
+        user: &AuthenticatedUser,
+        publish_at: Option<DateTime<Utc>>,
+        ctx: &RequestContext,
+    ) -> Result<Post, DomainError> {
+        let mut post = self.get_post(post_id).await?;
+
+        if !post.is_author(&user.id) {
+            return Err(DomainError::Forbidden);
+        }
+        let before = post_summary(&post);
+
+        let was_published = post.is_published();
+        post.publish(publish_at, Utc::now())?;
+        if was_published {
+            return Ok(post);
+        }
+        self.filter.moderate_post(&mut post)?;
+
+        self.save_publication(&post).await?;
+
+        info!(
+            post_id = %post_id,
+            status = %post.status,
+            "Изменён статус публикации"
+        );
+
+        self.record_transition(AuditAction::PostPublished, user, ctx, &post, before)
+            .await;
+
+        Ok(post)
+    }
+
+    /// Опубликовать отложенные записи, время публикации которых наступило.
+    ///
+    /// ## Returns
+    ///
+    /// Количество опубликованных записей.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn publish_scheduled_posts(&self) -> Result<u64, DomainError> {
+        let published = self.repo.publish_due().await.map_err(|err| {
             error!(
                 error=%err,
-                "Не удалось получить из БД список постов"
+                "Не удалось опубликовать отложенные записи"
+            );
+            DomainError::server_err(err.to_string())
+        })?;
+
+        if published > 0 {
+            info!(published, "Опубликованы отложенные записи");
+        }
+
+        Ok(published)
+    }
+
+    /// Полнотекстовый поиск публикаций, от наиболее релевантных.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn search_posts(
+        &self,
+        query: &SearchQuery,
+        limit: i32,
+        offset: i32,
+    ) -> Result<SearchPosts, DomainError> {
+        let (hits, total) = self
+            .repo
+            .search(query, limit, offset)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    query=%query,
+                    "Не удалось выполнить поиск публикаций"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        Ok(SearchPosts {
+            hits,
+            total,
+            limit,
+            offset,
+        })
+    }
+
+    /// Предоставить перечень используемых тегов с количеством публикаций.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn list_tags(&self) -> Result<ListTags, DomainError> {
+        let tags = self.repo.list_tags().await.map_err(|err| {
+            error!(
+                error=%err,
+                "Не удалось получить из БД список тегов"
             );
             DomainError::server_err(err.to_string())
         })?;
 
-        Ok(ListPosts::new(posts, total, limit, offset))
+        Ok(ListTags { tags })
     }
 
     /// Отредактировать существующую публикацию.
     ///
     /// Проводится проверка, что автор публикации совпадает с авторизованным
-    /// пользователем.
-    #[instrument(skip(self, edit_command), level = "debug", fields(post_id=%edit_command.post_id, user_id=%user_id))]
+    /// пользователем. Изменение записывается в журнал аудита.
+    #[instrument(skip(self, edit_command, user, ctx), level = "debug", fields(post_id=%edit_command.post_id, user_id=%user.id))]
     pub(crate) async fn update_post(
         &self,
         edit_command: &EditPostCommand,
-        user_id: &DataId,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
     ) -> Result<Post, DomainError> {
+        let user_id = &user.id;
         let mut post = self.get_post(&edit_command.post_id).await?;
 
         if !post.is_author(user_id) {
             return Err(DomainError::Forbidden);
         }
+        let before = post_summary(&post);
 
         post.update(&edit_command.edit_post);
-        self.repo.update(&post).await.map_err(|err| {
+        check_tags_count(&post)?;
+        self.filter.moderate_post(&mut post)?;
+
+        let post = self.repo.update(&post).await.map_err(|err| {
             error!(
                 error=%err,
                 post_id=%edit_command.post_id,
@@ -113,36 +397,659 @@ where
             DomainError::server_err(err.to_string())
         })?;
 
+        let after = post_summary(&post);
+        self.record(
+            AuditAction::PostUpdated,
+            user,
+            ctx,
+            post.id.as_ref(),
+            Some(before),
+            Some(after),
+        )
+        .await;
+
         Ok(post)
     }
 
-    /// Удалить публикацию.
+    /// Удалить публикацию: запись перемещается в корзину автора.
     ///
-    /// Обязательно проводится проверка, что пользователь является автором.
-    #[instrument(skip(self), level = "debug")]
+    /// Удалить публикацию может её автор или модератор. Запись, удалённая
+    /// модератором, дополнительно скрывается: восстановив её из корзины,
+    /// автор не вернёт её читателям. Удаление записывается в журнал аудита.
+    #[instrument(skip(self, user, ctx), level = "debug", fields(user_id = %user.id))]
     pub(crate) async fn delete_post(
         &self,
         post_id: &DataId,
-        user_id: &DataId,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
     ) -> Result<(), DomainError> {
-        let author_id = self
+        let mut post = self.get_post(post_id).await?;
+        let before = post_summary(&post);
+
+        if !post.is_author(&user.id) {
+            user.require(Permission::ModerateContent)?;
+            if post.hide().is_ok() {
+                self.save_publication(&post).await?;
+            }
+        }
+
+        self.repo.delete(post_id).await.map_repo_err(RepoErrorMap {
+            not_found: DomainError::PostNotFound,
+            unique_violations: None,
+        })?;
+
+        info!(post_id = %post_id, "Публикация перемещена в корзину");
+
+        self.record(
+            AuditAction::PostDeleted,
+            user,
+            ctx,
+            Some(post_id),
+            Some(before),
+            None,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// Скрыть публикацию от читателей (доступно модераторам).
+    ///
+    /// Скрытая запись остаётся доступной автору, но опубликовать её снова
+    /// он не может. Скрытие записывается в журнал аудита.
+    #[instrument(skip(self, user, ctx), level = "debug", fields(user_id = %user.id))]
+    pub(crate) async fn hide_post(
+        &self,
+        post_id: &DataId,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
+    ) -> Result<Post, DomainError> {
+        user.require(Permission::ModerateContent)?;
+
+        let mut post = self.get_post(post_id).await?;
+        let before = post_summary(&post);
+        post.hide()?;
+        self.save_publication(&post).await?;
+
+        info!(post_id = %post_id, moderator = %user.username, "Публикация скрыта");
+
+        self.record_transition(AuditAction::PostHidden, user, ctx, &post, before)
+            .await;
+
+        Ok(post)
+    }
+
+    /// Вернуть скрытую публикацию читателям (доступно модераторам).
+    #[instrument(skip(self, user, ctx), level = "debug", fields(user_id = %user.id))]
+    pub(crate) async fn unhide_post(
+        &self,
+        post_id: &DataId,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
+    ) -> Result<Post, DomainError> {
+        user.require(Permission::ModerateContent)?;
+
+        let mut post = self.get_post(post_id).await?;
+        let before = post_summary(&post);
+        post.unhide(Utc::now())?;
+        self.save_publication(&post).await?;
+
+        info!(post_id = %post_id, moderator = %user.username, "Публикация снова видна читателям");
+
+        self.record_transition(AuditAction::PostUnhidden, user, ctx, &post, before)
+            .await;
+
+        Ok(post)
+    }
+
+    /// Предоставить очередь модерации: публикации всех авторов, ожидающие
+    /// проверки, от давно ожидающих (доступно модераторам).
+    #[instrument(skip(self, user), level = "debug", fields(user_id = %user.id))]
+    pub(crate) async fn list_moderation_queue(
+        &self,
+        user: &AuthenticatedUser,
+        limit: i32,
+        offset: i32,
+    ) -> Result<ListPosts, DomainError> {
+        user.require(Permission::ModerateContent)?;
+
+        let (posts, total) = self
             .repo
-            .get_author_id(post_id)
+            .list_pending(limit, offset)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    "Не удалось получить из БД очередь модерации"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        Ok(ListPosts::new(posts, Some(total), limit, offset, None))
+    }
+
+    /// Одобрить публикацию из очереди модерации (доступно модераторам).
+    ///
+    /// Запись публикуется сразу или в запланированное автором время.
+    #[instrument(skip(self, user, ctx), level = "debug", fields(user_id = %user.id))]
+    pub(crate) async fn approve_post(
+        &self,
+        post_id: &DataId,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
+    ) -> Result<Post, DomainError> {
+        user.require(Permission::ModerateContent)?;
+
+        let mut post = self.get_post(post_id).await?;
+        let before = post_summary(&post);
+        post.approve(Utc::now())?;
+        self.save_publication(&post).await?;
+
+        info!(
+            post_id = %post_id,
+            status = %post.status,
+            moderator = %user.username,
+            "Публикация одобрена модератором"
+        );
+
+        self.record_transition(AuditAction::PostApproved, user, ctx, &post, before)
+            .await;
+
+        Ok(post)
+    }
+
+    /// Отклонить публикацию из очереди модерации (доступно модераторам).
+    ///
+    /// Отклонённая запись скрывается, как при [`Self::hide_post`].
+    #[instrument(skip(self, user, ctx), level = "debug", fields(user_id = %user.id))]
+    pub(crate) async fn reject_post(
+        &self,
+        post_id: &DataId,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
+    ) -> Result<Post, DomainError> {
+        user.require(Permission::ModerateContent)?;
+
+        let mut post = self.get_post(post_id).await?;
+        if post.status != PostStatus::Pending {
+            return Err(DomainError::invalid_publication(
+                "публикация не ожидает проверки",
+            ));
+        }
+        let before = post_summary(&post);
+        post.hide()?;
+        self.save_publication(&post).await?;
+
+        info!(post_id = %post_id, moderator = %user.username, "Публикация отклонена модератором");
+
+        self.record_transition(AuditAction::PostRejected, user, ctx, &post, before)
+            .await;
+
+        Ok(post)
+    }
+
+    /// Предоставить публикации автора в корзине, от недавно удалённых.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn list_trash(
+        &self,
+        author_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<ListPosts, DomainError> {
+        let (posts, total) = self
+            .repo
+            .list_deleted(author_id, limit, offset)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    author_id=%author_id,
+                    "Не удалось получить из БД корзину автора"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        Ok(ListPosts::new(posts, Some(total), limit, offset, None))
+    }
+
+    /// Вернуть публикацию из корзины.
+    ///
+    /// Восстановить публикацию может только её автор. Восстановление
+    /// записывается в журнал аудита.
+    #[instrument(skip(self, user, ctx), level = "debug", fields(user_id = %user.id))]
+    pub(crate) async fn restore_post(
+        &self,
+        post_id: &DataId,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
+    ) -> Result<Post, DomainError> {
+        let mut post = self
+            .repo
+            .get_deleted(post_id)
             .await
             .map_repo_err(RepoErrorMap {
                 not_found: DomainError::PostNotFound,
                 unique_violations: None,
             })?;
 
-        if !author_id.eq(user_id) {
+        if !post.is_author(&user.id) {
             return Err(DomainError::Forbidden);
         }
+        let before = post_summary(&post);
 
-        self.repo.delete(post_id).await.map_repo_err(RepoErrorMap {
+        self.repo.restore(post_id).await.map_repo_err(RepoErrorMap {
             not_found: DomainError::PostNotFound,
             unique_violations: None,
         })?;
+        post.deleted_at = None;
 
-        Ok(())
+        info!(post_id = %post_id, "Публикация восстановлена из корзины");
+
+        self.record_transition(AuditAction::PostRestored, user, ctx, &post, before)
+            .await;
+
+        Ok(post)
+    }
+
+    /// Окончательно удалить публикации, пролежавшие в корзине дольше
+    /// `retention`.
+    ///
+    /// ## Returns
+    ///
+    /// Количество удалённых записей.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn purge_trash(&self, retention: Duration) -> Result<u64, DomainError> {
+        let purged = self
+            .repo
+            .purge_deleted(Utc::now() - retention)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    "Не удалось очистить корзину публикаций"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        if purged > 0 {
+            info!(purged, "Корзина очищена от устаревших публикаций");
+        }
+
+        Ok(purged)
+    }
+
+    /// Однократно дополнить отрисованным HTML публикации, созданные до
+    /// появления поля `content_html`, чтобы не отрисовывать их при каждом
+    /// чтении.
+    ///
+    /// ## Returns
+    ///
+    /// Количество дополненных записей.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn fill_content_html(&self) -> Result<u64, DomainError> {
+        let mut filled = 0;
+        loop {
+            let batch = self
+                .repo
+                .fill_content_html(CONTENT_HTML_FILL_BATCH)
+                .await
+                .map_err(|err| {
+                    error!(error = %err, "Не удалось заполнить HTML публикаций");
+                    DomainError::server_err(err.to_string())
+                })?;
+            if batch == 0 {
+                break;
+            }
+            filled += batch;
+        }
+
+        if filled > 0 {
+            info!(filled, "HTML публикаций заполнен");
+        }
+
+        Ok(filled)
+    }
+
+    /// Записать действие пользователя `user` с публикацией `post_id` в
+    /// журнал аудита.
+    async fn record(
+        &self,
+        action: AuditAction,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
+        post_id: Option<&DataId>,
+        before: Option<Value>,
+        after: Option<Value>,
+    ) {
+        let mut event =
+            NewAuditEvent::new(action, Some(user.id.clone()), user.username.to_string(), ctx)
+                .with_changes(before, after);
+        if let Some(post_id) = post_id {
+            event = event.with_target(AuditTarget::Post(post_id.clone()));
+        }
+        self.audit.record(event).await;
+    }
+
+    /// Записать в журнал аудита смену состояния публикации `post`, бывшей
+    /// до действия в состоянии `before`.
+    async fn record_transition(
+        &self,
+        action: AuditAction,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
+        post: &Post,
+        before: Value,
+    ) {
+        let after = post_summary(post);
+        self.record(
+            action,
+            user,
+            ctx,
+            post.id.as_ref(),
+            Some(before),
+            Some(after),
+        )
+        .await;
+    }
+
+    /// Сохранить статус и время публикации записи.
+    async fn save_publication(&self, post: &Post) -> Result<(), DomainError> {
+        let post_id = post.id.as_ref().ok_or(DomainError::PostNotFound)?;
+
+        self.repo
+            .set_publication(post_id, post.status, post.publish_at)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::PostNotFound,
+                unique_violations: None,
+            })
+    }
+}
+
+/// Проверить, что количество тегов публикации не превышает допустимое.
+fn check_tags_count(post: &Post) -> Result<(), DomainError> {
+    if post.tags.len() > POST_TAGS_MAX {
+        return Err(DomainError::invalid_tag(format!(
+            "у публикации может быть не более {} тегов",
+            POST_TAGS_MAX
+        )));
+    }
+
+    Ok(())
+}
+
+/// Получить публикацию из репозитория `repo` для просмотра пользователем
+/// `viewer`. Единое правило видимости для всех сервисов: запись, которая
+/// недоступна пользователю, для него не существует.
+pub(crate) async fn load_visible_post<R>(
+    repo: &R,
+    post_id: &DataId,
+    viewer: Option<&DataId>,
+) -> Result<Post, DomainError>
+where
+    R: PostRepository + ?Sized,
+{
+    let post = repo.get(post_id).await.map_repo_err(RepoErrorMap {
+        not_found: DomainError::PostNotFound,
+        unique_violations: None,
+    })?;
+
+    visible_to(post, viewer)
+}
+
+/// Вернуть публикацию, если она доступна пользователю `viewer`
+/// (см. [`Post::is_visible_to`]), иначе — [`DomainError::PostNotFound`].
+fn visible_to(post: Post, viewer: Option<&DataId>) -> Result<Post, DomainError> {
+    if !post.is_visible_to(viewer) {
+        return Err(DomainError::PostNotFound);
+    }
+
+    Ok(post)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::fixtures, Repositories},
+        domain::{audit::AuditFilter, role::Role},
+    };
+
+    /// Действия из журнала аудита, от старых к новым.
+    async fn audited(repos: &Repositories) -> Vec<(AuditAction, Value, Value)> {
+        let filter = AuditFilter {
+            limit: 100,
+            ..Default::default()
+        };
+        let (events, _) = repos.audit.list(&filter).await.unwrap();
+        events
+            .into_iter()
+            .rev()
+            .map(|event| {
+                (
+                    event.action,
+                    event.before.unwrap_or_default(),
+                    event.after.unwrap_or_default(),
+                )
+            })
+            .collect()
+    }
+
+    #[actix_web::test]
+    async fn state_changes_are_audited() {
+        let repos = Repositories::memory();
+        let service = fixtures::blog_service(&repos);
+        let ctx = RequestContext::default();
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let moderator = fixtures::user(&repos, "mod", Role::Moderator).await;
+        let post_id = fixtures::post(&repos, &author.id, PostStatus::Draft)
+            .await
+            .id
+            .unwrap();
+
+        service
+            .publish_post(&post_id, &author, None, &ctx)
+            .await
+            .unwrap();
+        service.hide_post(&post_id, &moderator, &ctx).await.unwrap();
+        service
+            .unhide_post(&post_id, &moderator, &ctx)
+            .await
+            .unwrap();
+        service.delete_post(&post_id, &author, &ctx).await.unwrap();
+        service.restore_post(&post_id, &author, &ctx).await.unwrap();
+
+        let events = audited(&repos).await;
+        let actions: Vec<_> = events.iter().map(|(action, _, _)| *action).collect();
+        assert_eq!(
+            actions,
+            [
+                AuditAction::PostPublished,
+                AuditAction::PostHidden,
+                AuditAction::PostUnhidden,
+                AuditAction::PostDeleted,
+                AuditAction::PostRestored,
+            ]
+        );
+        let (_, before, after) = &events[0];
+        assert_eq!(before["status"], "draft");
+        assert_eq!(after["status"], "published");
+        let (_, before, after) = &events[1];
+        assert_eq!(before["status"], "published");
+        assert_eq!(after["status"], "hidden");
+        let (_, before, after) = &events[4];
+        assert_eq!(before["deleted"], true);
+        assert_eq!(after["deleted"], false);
+    }
+
+    #[actix_web::test]
+    async fn moderation_queue_decisions_are_audited() {
+        let repos = Repositories::memory();
+        let service = fixtures::blog_service(&repos);
+        let ctx = RequestContext::default();
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let moderator = fixtures::user(&repos, "mod", Role::Moderator).await;
+        let approved = fixtures::post(&repos, &author.id, PostStatus::Pending).await;
+        let rejected = fixtures::post(&repos, &author.id, PostStatus::Pending).await;
+
+        service
+            .approve_post(approved.id.as_ref().unwrap(), &moderator, &ctx)
+            .await
+            .unwrap();
+        service
+            .reject_post(rejected.id.as_ref().unwrap(), &moderator, &ctx)
+            .await
+            .unwrap();
+
+        let events = audited(&repos).await;
+        assert_eq!(events.len(), 2);
+        let (action, before, after) = &events[0];
+        assert_eq!(*action, AuditAction::PostApproved);
+        assert_eq!(before["status"], "pending");
+        assert_eq!(after["status"], "published");
+        let (action, _, after) = &events[1];
+        assert_eq!(*action, AuditAction::PostRejected);
+        assert_eq!(after["status"], "hidden");
+    }
+
+    #[actix_web::test]
+    async fn moderation_requires_moderator() {
+        let repos = Repositories::memory();
+        let service = fixtures::blog_service(&repos);
+        let ctx = RequestContext::default();
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let reader = fixtures::user(&repos, "bob", Role::default()).await;
+        let published = fixtures::post(&repos, &author.id, PostStatus::Published).await;
+        let pending = fixtures::post(&repos, &author.id, PostStatus::Pending).await;
+        let published_id = published.id.unwrap();
+        let pending_id = pending.id.unwrap();
+
+        let hidden = service.hide_post(&published_id, &reader, &ctx).await;
+        assert!(matches!(hidden, Err(DomainError::Forbidden)));
+        let approved = service.approve_post(&pending_id, &reader, &ctx).await;
+        assert!(matches!(approved, Err(DomainError::Forbidden)));
+        let rejected = service.reject_post(&pending_id, &author, &ctx).await;
+        assert!(matches!(rejected, Err(DomainError::Forbidden)));
+        let deleted = service.delete_post(&published_id, &reader, &ctx).await;
+        assert!(matches!(deleted, Err(DomainError::Forbidden)));
+
+        let post = service.get_post(&published_id).await.unwrap();
+        assert_eq!(post.status, PostStatus::Published);
+        assert!(audited(&repos).await.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn feed_lists_followed_authors_only() {
+        let repos = Repositories::memory();
+        let service = fixtures::blog_service(&repos);
+        let reader = fixtures::user(&repos, "alice", Role::default()).await;
+        let followed = fixtures::user(&repos, "bob", Role::default()).await;
+        let other = fixtures::user(&repos, "carol", Role::default()).await;
+        let expected = fixtures::post(&repos, &followed.id, PostStatus::Published).await;
+        fixtures::post(&repos, &followed.id, PostStatus::Draft).await;
+        fixtures::post(&repos, &other.id, PostStatus::Published).await;
+        let params = ListPostsParams {
+            limit: 10,
+            ..Default::default()
+        };
+
+        let empty = service.feed(&reader.id, params.clone()).await.unwrap();
+        assert_eq!(empty.total, Some(0));
+
+        repos
+            .follows
+            .follow(&reader.id, &followed.id)
+            .await
+            .unwrap();
+        let feed = service.feed(&reader.id, params).await.unwrap();
+        assert_eq!(feed.total, Some(1));
+        assert_eq!(feed.posts[0].id, expected.id);
+    }
+
+    #[actix_web::test]
+    async fn trash_is_listed_and_restored_by_author_only() {
+        let repos = Repositories::memory();
+        let service = fixtures::blog_service(&repos);
+        let ctx = RequestContext::default();
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let reader = fixtures::user(&repos, "bob", Role::default()).await;
+        let post_id = fixtures::post(&repos, &author.id, PostStatus::Published)
+            .await
+            .id
+            .unwrap();
+        let params = ListPostsParams {
+            limit: 10,
+            ..Default::default()
+        };
+
+        service.delete_post(&post_id, &author, &ctx).await.unwrap();
+        let deleted = service.get_post(&post_id).await;
+        assert!(matches!(deleted, Err(DomainError::PostNotFound)));
+        let listed = service.list_posts(params.clone()).await.unwrap();
+        assert_eq!(listed.total, Some(0));
+        let trash = service.list_trash(&author.id, 10, 0).await.unwrap();
+        assert_eq!(trash.total, Some(1));
+        assert!(trash.posts[0].deleted_at.is_some());
+        let foreign = service.list_trash(&reader.id, 10, 0).await.unwrap();
+        assert_eq!(foreign.total, Some(0));
+
+        let denied = service.restore_post(&post_id, &reader, &ctx).await;
+        assert!(matches!(denied, Err(DomainError::Forbidden)));
+        let restored = service.restore_post(&post_id, &author, &ctx).await.unwrap();
+        assert!(restored.deleted_at.is_none());
+        assert_eq!(restored.status, PostStatus::Published);
+        let listed = service.list_posts(params).await.unwrap();
+        assert_eq!(listed.total, Some(1));
+        let trash = service.list_trash(&author.id, 10, 0).await.unwrap();
+        assert_eq!(trash.total, Some(0));
+    }
+
+    #[actix_web::test]
+    async fn post_deleted_by_moderator_stays_hidden_after_restore() {
+        let repos = Repositories::memory();
+        let service = fixtures::blog_service(&repos);
+        let ctx = RequestContext::default();
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let moderator = fixtures::user(&repos, "mod", Role::Moderator).await;
+        let post_id = fixtures::post(&repos, &author.id, PostStatus::Published)
+            .await
+            .id
+            .unwrap();
+
+        service
+            .delete_post(&post_id, &moderator, &ctx)
+            .await
+            .unwrap();
+        let denied = service.restore_post(&post_id, &moderator, &ctx).await;
+        assert!(matches!(denied, Err(DomainError::Forbidden)));
+        let restored = service.restore_post(&post_id, &author, &ctx).await.unwrap();
+        assert_eq!(restored.status, PostStatus::Hidden);
+    }
+
+    #[actix_web::test]
+    async fn purge_trash_removes_only_expired_posts() {
+        let repos = Repositories::memory();
+        let service = fixtures::blog_service(&repos);
+        let ctx = RequestContext::default();
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let kept_id = fixtures::post(&repos, &author.id, PostStatus::Published)
+            .await
+            .id
+            .unwrap();
+        let trashed_id = fixtures::post(&repos, &author.id, PostStatus::Published)
+            .await
+            .id
+            .unwrap();
+        service
+            .delete_post(&trashed_id, &author, &ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(service.purge_trash(Duration::days(30)).await.unwrap(), 0);
+        assert_eq!(service.purge_trash(Duration::zero()).await.unwrap(), 1);
+
+        let purged = service.restore_post(&trashed_id, &author, &ctx).await;
+        assert!(matches!(purged, Err(DomainError::PostNotFound)));
+        assert!(service.get_post(&kept_id).await.is_ok());
     }
 }
@@ -0,0 +1,221 @@
+//! Бизнес-логика комментариев к публикациям.
+
+use crate::{
+    application::blog_service::load_visible_post,
+    data::{comment_repo::CommentRepository, post_repo::PostRepository},
+    domain::{
+        comment::{Comment, CreateComment, ListComments},
+        moderation::ModerationFilter,
+        post::Post,
+        role::Permission,
+        types::DataId,
+        user::AuthenticatedUser,
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+/// Сервисы для взаимодействия с комментариями к публикациям.
+///
+/// Для проверки существования и авторства публикаций использует репозиторий
+/// постов.
+pub(crate) struct CommentService<C = dyn CommentRepository, P = dyn PostRepository>
+where
+    C: CommentRepository + ?Sized + 'static,
+    P: PostRepository + ?Sized + 'static,
+{
+    /// Репозиторий комментариев.
+    repo: Arc<C>,
+    /// Репозиторий публикаций.
+    post_repo: Arc<P>,
+    /// Правила модерации комментариев.
+    filter: ModerationFilter,
+}
+
+impl<C, P> CommentService<C, P>
+where
+    C: CommentRepository + ?Sized + 'static,
+    P: PostRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`CommentService`] с репозиториями комментариев
+    /// и публикаций и правилами модерации.
+    pub(crate) fn new(repo: Arc<C>, post_repo: Arc<P>, filter: ModerationFilter) -> Self {
+        Self {
+            repo,
+            post_repo,
+            filter,
+        }
+    }
+
+    /// Получить публикацию, проверив, что она существует и доступна
+    /// пользователю. Черновики и скрытые записи для посторонних выглядят
+    /// так же, как несуществующие.
+    async fn get_visible_post(
+        &self,
+        post_id: &DataId,
+        viewer: Option<&DataId>,
+    ) -> Result<Post, DomainError> {
+        load_visible_post(self.post_repo.as_ref(), post_id, viewer).await
+    }
+
+    /// Добавить комментарий к публикации.
+    #[instrument(skip(self, new_comment), level = "debug", fields(post_id=%post_id, author=%author))]
+    pub(crate) async fn add_comment(
+        &self,
+        post_id: &DataId,
+        new_comment: &CreateComment,
+        author: &DataId,
+    ) -> Result<Comment, DomainError> {
+        self.filter.check_comment(&new_comment.content)?;
+        self.get_visible_post(post_id, Some(author)).await?;
+
+        let comment = Comment::new_by_create(new_comment.clone(), post_id.clone(), author.clone());
+        let comment = self
+            .repo
+            .create(&comment)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::PostNotFound,
+                unique_violations: Some(vec![("fk_comments_post_id", DomainError::PostNotFound)]),
+            })
+            .inspect_err(|err| {
+                error!(
+                    error=%err,
+                    post_id=%post_id,
+                    author=%author,
+                    "Ошибка создания комментария в базе данных"
+                )
+            })?;
+
+        info!(
+            comment_id = ?comment.id,
+            post_id = %comment.post_id,
+            author_id = %comment.author_id,
+            "Добавлен комментарий к публикации");
+
+        Ok(comment)
+    }
+
+    /// Предоставить список комментариев к публикации, доступной
+    /// пользователю `viewer`.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn list_comments(
+        &self,
+        post_id: &DataId,
+        viewer: Option<&DataId>,
+        limit: i32,
+        offset: i32,
+    ) -> Result<ListComments, DomainError> {
+        self.get_visible_post(post_id, viewer).await?;
+
+        let (comments, total) = self
+            .repo
+            .list_by_post(post_id, limit, offset)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    post_id=%post_id,
+                    "Не удалось получить из БД список комментариев"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        Ok(ListComments::new(comments, total, limit, offset))
+    }
+
+    /// Удалить комментарий.
+    ///
+    /// Удаление доступно автору комментария, автору публикации, к которой
+    /// комментарий относится, и модераторам.
+    #[instrument(skip(self, user), level = "debug", fields(user_id = %user.id))]
+    pub(crate) async fn delete_comment(
+        &self,
+        post_id: &DataId,
+        comment_id: &DataId,
+        user: &AuthenticatedUser,
+    ) -> Result<(), DomainError> {
+        let user_id = &user.id;
+        let comment = self.repo.get(comment_id).await.map_repo_err(RepoErrorMap {
+            not_found: DomainError::CommentNotFound,
+            unique_violations: None,
+        })?;
+
+        if !comment.post_id.eq(post_id) {
+            return Err(DomainError::CommentNotFound);
+        }
+
+        if !comment.is_author(user_id) && !user.can(Permission::ModerateContent) {
+            let post = self.get_visible_post(post_id, Some(user_id)).await?;
+            if !post.is_author(user_id) {
+                return Err(DomainError::Forbidden);
+            }
+        }
+
+        self.repo
+            .delete(comment_id)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::CommentNotFound,
+                unique_violations: None,
+            })?;
+
+        info!(
+            comment_id = %comment_id,
+            post_id = %post_id,
+            user_id = %user_id,
+            "Комментарий удалён");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::fixtures, Repositories},
+        domain::{post::PostStatus, role::Role, types::CommentContent},
+    };
+
+    fn service(repos: &Repositories) -> CommentService {
+        CommentService::new(
+            repos.comments.clone(),
+            repos.posts.clone(),
+            ModerationFilter::default(),
+        )
+    }
+
+    #[actix_web::test]
+    async fn draft_comments_are_hidden_from_other_users() {
+        let repos = Repositories::memory();
+        let service = service(&repos);
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let reader = fixtures::user(&repos, "bob", Role::default()).await;
+        let draft = fixtures::post(&repos, &author.id, PostStatus::Draft).await;
+        let draft_id = draft.id.unwrap();
+        let comment = CreateComment {
+            content: CommentContent::try_from("Отличная запись".to_string()).unwrap(),
+        };
+
+        let added = service.add_comment(&draft_id, &comment, &reader.id).await;
+        assert!(matches!(added, Err(DomainError::PostNotFound)));
+        let listed = service
+            .list_comments(&draft_id, Some(&reader.id), 10, 0)
+            .await;
+        assert!(matches!(listed, Err(DomainError::PostNotFound)));
+        let anonymous = service.list_comments(&draft_id, None, 10, 0).await;
+        assert!(matches!(anonymous, Err(DomainError::PostNotFound)));
+
+        service
+            .add_comment(&draft_id, &comment, &author.id)
+            .await
+            .unwrap();
+        let own = service
+            .list_comments(&draft_id, Some(&author.id), 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(own.total, 1);
+    }
+}
@@ -0,0 +1,217 @@
+//! Бизнес-логика подписок на авторов.
+
+use crate::{
+    data::{follow_repo::FollowRepository, user_repo::UserRepository},
+    domain::{
+        follow::ListFollows,
+        types::{DataId, Username},
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+/// Сервисы для взаимодействия с подписками на авторов.
+///
+/// Перечни подписчиков и подписок публичны; подписаться и отписаться
+/// может только аутентифицированный пользователь.
+pub(crate) struct FollowService<F = dyn FollowRepository, U = dyn UserRepository>
+where
+    F: FollowRepository + ?Sized + 'static,
+    U: UserRepository + ?Sized + 'static,
+{
+    /// Репозиторий подписок.
+    repo: Arc<F>,
+    /// Репозиторий пользователей.
+    user_repo: Arc<U>,
+}
+
+impl<F, U> FollowService<F, U>
+where
+    F: FollowRepository + ?Sized + 'static,
+    U: UserRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`FollowService`] с репозиториями подписок
+    /// и пользователей.
+    pub(crate) fn new(repo: Arc<F>, user_repo: Arc<U>) -> Self {
+        Self { repo, user_repo }
+    }
+
+    /// Получить id пользователя по его имени.
+    async fn user_id(&self, username: &Username) -> Result<DataId, DomainError> {
+        let user = self
+            .user_repo
+            .get_by_username(&username.to_lowercase())
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+
+        user.id.ok_or(DomainError::UserNotFound)
+    }
+
+    /// Подписать пользователя на автора `username`.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn follow(
+        &self,
+        follower_id: &DataId,
+        username: &Username,
+    ) -> Result<(), DomainError> {
+        let followee_id = self.user_id(username).await?;
+        if followee_id.eq(follower_id) {
+            return Err(DomainError::invalid_follow(
+                "нельзя подписаться на самого себя",
+            ));
+        }
+
+        self.repo
+            .follow(follower_id, &followee_id)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    follower_id=%follower_id,
+                    followee_id=%followee_id,
+                    "Ошибка сохранения подписки"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        info!(
+            follower_id = %follower_id,
+            followee_id = %followee_id,
+            "Оформлена подписка на автора");
+
+        Ok(())
+    }
+
+    /// Отписать пользователя от автора `username`.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn unfollow(
+        &self,
+        follower_id: &DataId,
+        username: &Username,
+    ) -> Result<(), DomainError> {
+        let followee_id = self.user_id(username).await?;
+
+        self.repo
+            .unfollow(follower_id, &followee_id)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    follower_id=%follower_id,
+                    followee_id=%followee_id,
+                    "Ошибка удаления подписки"
+                );
+                DomainError::server_err(err.to_string())
+            })
+    }
+
+    /// Предоставить подписчиков пользователя, от недавно подписавшихся.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn list_followers(
+        &self,
+        username: &Username,
+        limit: i32,
+        offset: i32,
+    ) -> Result<ListFollows, DomainError> {
+        let user_id = self.user_id(username).await?;
+
+        let (users, total) = self
+            .repo
+            .list_followers(&user_id, limit, offset)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    user_id=%user_id,
+                    "Не удалось получить из БД подписчиков пользователя"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        Ok(ListFollows::new(users, total, limit, offset))
+    }
+
+    /// Предоставить авторов, на которых подписан пользователь.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn list_following(
+        &self,
+        username: &Username,
+        limit: i32,
+        offset: i32,
+    ) -> Result<ListFollows, DomainError> {
+        let user_id = self.user_id(username).await?;
+
+        let (users, total) = self
+            .repo
+            .list_following(&user_id, limit, offset)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    user_id=%user_id,
+                    "Не удалось получить из БД подписки пользователя"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        Ok(ListFollows::new(users, total, limit, offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::fixtures, Repositories},
+        domain::role::Role,
+    };
+
+    #[actix_web::test]
+    async fn follow_and_unfollow_update_both_lists() {
+        let repos = Repositories::memory();
+        let service = fixtures::follow_service(&repos);
+        let reader = fixtures::user(&repos, "alice", Role::default()).await;
+        let author = fixtures::user(&repos, "bob", Role::default()).await;
+
+        service.follow(&reader.id, &author.username).await.unwrap();
+        let followers = service
+            .list_followers(&author.username, 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(followers.total, 1);
+        assert_eq!(followers.users[0].username, reader.username);
+        let following = service
+            .list_following(&reader.username, 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(following.total, 1);
+        assert_eq!(following.users[0].username, author.username);
+
+        service
+            .unfollow(&reader.id, &author.username)
+            .await
+            .unwrap();
+        let followers = service
+            .list_followers(&author.username, 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(followers.total, 0);
+    }
+
+    #[actix_web::test]
+    async fn follow_rejects_self_and_unknown_users() {
+        let repos = Repositories::memory();
+        let service = fixtures::follow_service(&repos);
+        let reader = fixtures::user(&repos, "alice", Role::default()).await;
+
+        let own = service.follow(&reader.id, &reader.username).await;
+        assert!(matches!(own, Err(DomainError::InvalidFollow(_))));
+        let nobody = Username::try_from("nobody".to_string()).unwrap();
+        let missing = service.follow(&reader.id, &nobody).await;
+        assert!(matches!(missing, Err(DomainError::UserNotFound)));
+    }
+}
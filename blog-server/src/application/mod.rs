@@ -1,35 +1,177 @@
 //! Элементы приложения.
 
 use crate::{
-    application::{auth_service::AuthService, blog_service::BlogService},
-    data::{post_repo::PostRepo, user_repo::UserRepo},
+    application::{
+        account_service::AccountService, admin_service::AdminService,
+        attachment_service::AttachmentService, audit_service::AuditService,
+        auth_service::AuthService,
+        blog_service::BlogService, comment_service::CommentService, follow_service::FollowService,
+        moderation_service::ModerationService, password_service::PasswordService,
+        profile_service::ProfileService, reaction_service::ReactionService,
+        revision_service::RevisionService, token_service::TokenService,
+        user_service::UserService, verification_service::VerificationService,
+    },
+    data::Repositories,
+    domain::{login::LoginPolicy, moderation::ModerationFilter, verification::EmailPolicy},
+    infrastructure::{config::BlogConfig, rate_limit::RateLimiter, storage::FileStorage},
 };
-use sqlx::PgPool;
 use std::sync::Arc;
 
+pub(crate) mod account_service;
+pub(crate) mod admin_service;
+pub(crate) mod attachment_service;
+pub(crate) mod audit_service;
 pub(crate) mod auth_service;
 pub(crate) mod blog_service;
+pub(crate) mod comment_service;
+pub(crate) mod follow_service;
+pub(crate) mod moderation_service;
+pub(crate) mod password_service;
+pub(crate) mod profile_service;
+pub(crate) mod reaction_service;
+pub(crate) mod revision_service;
+pub(crate) mod token_service;
+pub(crate) mod user_service;
+pub(crate) mod verification_service;
 
 /// Структура сервисов обработки данных.
 #[derive(Clone)]
 pub(crate) struct AppServices {
     /// Взаимодействие с пользовательскими сессиями.
-    pub(crate) auth_service: Arc<AuthService<UserRepo>>,
+    pub(crate) auth_service: Arc<AuthService>,
+    /// Взаимодействие с сессиями: токены доступа и обновления.
+    pub(crate) token_service: Arc<TokenService>,
+    /// Смена и сброс пароля.
+    pub(crate) password_service: Arc<PasswordService>,
+    /// Подтверждение адреса электронной почты.
+    pub(crate) verification_service: Arc<VerificationService>,
+    /// Просмотр и изменение профиля текущим пользователем.
+    pub(crate) profile_service: Arc<ProfileService>,
+    /// Удаление учётной записи владельцем и выгрузка его данных.
+    pub(crate) account_service: Arc<AccountService>,
+    /// Управление учётными записями: роли и блокировка.
+    pub(crate) admin_service: Arc<AdminService>,
+    /// Журнал аудита: просмотр администраторами.
+    pub(crate) audit_service: Arc<AuditService>,
+    /// Управление правилами модерации.
+    pub(crate) moderation_service: Arc<ModerationService>,
     /// Взаимодействие с публикацией постов.
-    pub(crate) blog_service: Arc<BlogService<PostRepo>>,
+    pub(crate) blog_service: Arc<BlogService>,
+    /// Взаимодействие с комментариями к публикациям.
+    pub(crate) comment_service: Arc<CommentService>,
+    /// Взаимодействие с историей изменений публикаций.
+    pub(crate) revision_service: Arc<RevisionService>,
+    /// Взаимодействие с публичными профилями авторов.
+    pub(crate) user_service: Arc<UserService>,
+    /// Взаимодействие с реакциями на публикации.
+    pub(crate) reaction_service: Arc<ReactionService>,
+    /// Взаимодействие с подписками на авторов.
+    pub(crate) follow_service: Arc<FollowService>,
+    /// Взаимодействие с вложениями публикаций.
+    pub(crate) attachment_service: Arc<AttachmentService>,
+    /// Ограничение частоты запросов, общее для HTTP- и gRPC-серверов.
+    pub(crate) rate_limiter: Arc<RateLimiter>,
 }
 
 impl AppServices {
-    pub(crate) fn new(pool: &PgPool) -> Self {
-        let user_repo = Arc::new(UserRepo::new(pool));
-        let blog_repo = Arc::new(PostRepo::new(pool));
+    /// Создать сервисы над репозиториями `repos` и хранилищем файлов
+    /// вложений `storage`.
+    pub(crate) fn new(
+        repos: &Repositories,
+        storage: Arc<dyn FileStorage>,
+        cfg: &BlogConfig,
+    ) -> Self {
+        let Repositories {
+            users: user_repo,
+            posts: blog_repo,
+            comments: comment_repo,
+            revisions: revision_repo,
+            reactions: reaction_repo,
+            follows: follow_repo,
+            attachments: attachment_repo,
+            tokens: token_repo,
+            moderation: moderation_repo,
+            login_attempts: login_attempt_repo,
+            password_resets: password_reset_repo,
+            audit: audit_repo,
+        } = repos.clone();
 
-        let auth_service = AuthService::new(user_repo);
-        let blog_service = BlogService::new(blog_repo);
+        let audit_service = Arc::new(AuditService::new(audit_repo));
+        let login_policy = LoginPolicy::new(cfg.login.max_failures, cfg.login.lockout);
+        let auth_service = AuthService::new(
+            Arc::clone(&user_repo),
+            login_attempt_repo,
+            login_policy,
+            Arc::clone(&audit_service),
+        );
+        let token_service = Arc::new(TokenService::new(
+            token_repo,
+            cfg.security.jwt_service.clone(),
+        ));
+        let password_service = PasswordService::new(
+            Arc::clone(&user_repo),
+            password_reset_repo,
+            Arc::clone(&token_service),
+            Arc::clone(&cfg.mail.mailer),
+            cfg.feed.site_url.clone(),
+        );
+        let verification_service = Arc::new(VerificationService::new(
+            Arc::clone(&user_repo),
+            cfg.security.jwt_service.clone(),
+            Arc::clone(&cfg.mail.mailer),
+            cfg.feed.site_url.clone(),
+        ));
+        let profile_service =
+            ProfileService::new(Arc::clone(&user_repo), Arc::clone(&verification_service));
+        let account_service = AccountService::new(
+            Arc::clone(&user_repo),
+            Arc::clone(&blog_repo),
+            Arc::clone(&token_service),
+            Arc::clone(&storage),
+        );
+        let admin_service = AdminService::new(Arc::clone(&user_repo), Arc::clone(&token_service));
+        let filter = ModerationFilter::default();
+        let moderation_service = ModerationService::new(moderation_repo, filter.clone());
+        let email_policy = EmailPolicy::new(cfg.verification.required_to_post);
+        let blog_service = BlogService::new(
+            Arc::clone(&blog_repo),
+            Arc::clone(&user_repo),
+            filter.clone(),
+            email_policy,
+            Arc::clone(&audit_service),
+        );
+        let comment_service =
+            CommentService::new(comment_repo, Arc::clone(&blog_repo), filter.clone());
+        let revision_service = RevisionService::new(
+            revision_repo,
+            Arc::clone(&blog_repo),
+            filter,
+            Arc::clone(&audit_service),
+        );
+        let user_service = UserService::new(Arc::clone(&user_repo), Arc::clone(&blog_repo));
+        let reaction_service = ReactionService::new(reaction_repo, blog_repo);
+        let follow_service = FollowService::new(follow_repo, user_repo);
+        let attachment_service =
+            AttachmentService::new(attachment_repo, storage, cfg.storage.max_upload_bytes);
 
         Self {
             auth_service: Arc::new(auth_service),
+            token_service,
+            password_service: Arc::new(password_service),
+            verification_service,
+            profile_service: Arc::new(profile_service),
+            account_service: Arc::new(account_service),
+            admin_service: Arc::new(admin_service),
+            audit_service,
+            moderation_service: Arc::new(moderation_service),
             blog_service: Arc::new(blog_service),
+            comment_service: Arc::new(comment_service),
+            revision_service: Arc::new(revision_service),
+            user_service: Arc::new(user_service),
+            reaction_service: Arc::new(reaction_service),
+            follow_service: Arc::new(follow_service),
+            attachment_service: Arc::new(attachment_service),
+            rate_limiter: Arc::new(RateLimiter::new(&cfg.rate_limit)),
         }
     }
 }
@@ -0,0 +1,163 @@
+//! Бизнес-логика правил модерации.
+
+use crate::{
+    data::moderation_repo::ModerationRepository,
+    domain::{
+        moderation::{
+            ListModerationRules, ModerationFilter, ModerationMatcher, ModerationRule,
+            SaveModerationRule,
+        },
+        role::Permission,
+        types::DataId,
+        user::AuthenticatedUser,
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+/// Сервисы для управления правилами модерации.
+///
+/// Правила хранятся в базе данных; после каждого изменения они заново
+/// компилируются в общий для сервисов [`ModerationFilter`]. Все действия
+/// требуют права [`Permission::ModerateContent`].
+pub(crate) struct ModerationService<M = dyn ModerationRepository>
+where
+    M: ModerationRepository + ?Sized + 'static,
+{
+    /// Репозиторий правил модерации.
+    repo: Arc<M>,
+    /// Действующие правила, по которым проверяются тексты.
+    filter: ModerationFilter,
+}
+
+impl<M> ModerationService<M>
+where
+    M: ModerationRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`ModerationService`] с репозиторием правил и
+    /// фильтром, который он обновляет.
+    pub(crate) fn new(repo: Arc<M>, filter: ModerationFilter) -> Self {
+        Self { repo, filter }
+    }
+
+    /// Загрузить правила из базы данных и заменить ими действующие.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn reload(&self) -> Result<(), DomainError> {
+        let rules = self.fetch_rules().await?;
+        self.filter.replace(ModerationMatcher::new(&rules));
+
+        info!(rules = rules.len(), "Загружены правила модерации");
+
+        Ok(())
+    }
+
+    /// Предоставить все правила модерации.
+    #[instrument(skip(self, user), level = "debug", fields(user = %user.username))]
+    pub(crate) async fn list_rules(
+        &self,
+        user: &AuthenticatedUser,
+    ) -> Result<ListModerationRules, DomainError> {
+        user.require(Permission::ModerateContent)?;
+
+        Ok(ListModerationRules {
+            rules: self.fetch_rules().await?,
+        })
+    }
+
+    /// Добавить правило модерации.
+    #[instrument(skip(self, user, rule), level = "debug", fields(user = %user.username))]
+    pub(crate) async fn create_rule(
+        &self,
+        user: &AuthenticatedUser,
+        rule: SaveModerationRule,
+    ) -> Result<ModerationRule, DomainError> {
+        user.require(Permission::ModerateContent)?;
+        let rule = rule.validated()?;
+
+        let rule = self
+            .repo
+            .create(&rule, &user.id)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::ModerationRuleNotFound,
+                unique_violations: Some(vec![(
+                    "uq_moderation_rules_pattern",
+                    DomainError::ModerationRuleAlreadyExists,
+                )]),
+            })?;
+        self.reload().await?;
+
+        info!(
+            rule_id = %rule.id,
+            kind = %rule.kind,
+            action = %rule.action,
+            moderator = %user.username,
+            "Добавлено правило модерации");
+
+        Ok(rule)
+    }
+
+    /// Изменить правило модерации.
+    #[instrument(skip(self, user, rule), level = "debug", fields(user = %user.username))]
+    pub(crate) async fn update_rule(
+        &self,
+        user: &AuthenticatedUser,
+        rule_id: &DataId,
+        rule: SaveModerationRule,
+    ) -> Result<ModerationRule, DomainError> {
+        user.require(Permission::ModerateContent)?;
+        let rule = rule.validated()?;
+
+        let rule = self
+            .repo
+            .update(rule_id, &rule)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::ModerationRuleNotFound,
+                unique_violations: Some(vec![(
+                    "uq_moderation_rules_pattern",
+                    DomainError::ModerationRuleAlreadyExists,
+                )]),
+            })?;
+        self.reload().await?;
+
+        info!(rule_id = %rule_id, moderator = %user.username, "Изменено правило модерации");
+
+        Ok(rule)
+    }
+
+    /// Удалить правило модерации.
+    #[instrument(skip(self, user), level = "debug", fields(user = %user.username))]
+    pub(crate) async fn delete_rule(
+        &self,
+        user: &AuthenticatedUser,
+        rule_id: &DataId,
+    ) -> Result<(), DomainError> {
+        user.require(Permission::ModerateContent)?;
+
+        self.repo
+            .delete(rule_id)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::ModerationRuleNotFound,
+                unique_violations: None,
+            })?;
+        self.reload().await?;
+
+        info!(rule_id = %rule_id, moderator = %user.username, "Удалено правило модерации");
+
+        Ok(())
+    }
+
+    /// Получить правила из базы данных.
+    async fn fetch_rules(&self) -> Result<Vec<ModerationRule>, DomainError> {
+        self.repo.list().await.map_err(|err| {
+            error!(
+                error=%err,
+                "Не удалось получить из БД правила модерации"
+            );
+            DomainError::server_err(err.to_string())
+        })
+    }
+}
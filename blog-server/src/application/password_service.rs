@@ -0,0 +1,233 @@
+//! Бизнес-логика смены и сброса пароля.
+
+use crate::{
+    application::token_service::TokenService,
+    data::{
+        password_reset_repo::PasswordResetRepository, token_repo::TokenRepository,
+        user_repo::UserRepository,
+    },
+    domain::{
+        password::{reset_link, reset_mail_body, ChangePassword, NewPasswordResetToken},
+        token::{generate_secret, hash_secret},
+        types::{DataId, Email, UserPassword},
+        user::{AuthenticatedUser, User},
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+    infrastructure::mailer::{Mail, Mailer},
+    settings::PASSWORD_RESET_TOKEN_LIFETIME,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use tracing::{error, info, instrument, warn};
+
+/// Сервисы для смены пароля и сброса забытого пароля по электронной почте.
+///
+/// После смены или сброса пароля все сессии пользователя отзываются.
+pub(crate) struct PasswordService<
+    U = dyn UserRepository,
+    P = dyn PasswordResetRepository,
+    T = dyn TokenRepository,
+> where
+    U: UserRepository + ?Sized + 'static,
+    P: PasswordResetRepository + ?Sized + 'static,
+    T: TokenRepository + ?Sized + 'static,
+{
+    /// Репозиторий пользователей.
+    users: Arc<U>,
+    /// Репозиторий токенов сброса пароля.
+    resets: Arc<P>,
+    /// Сервис сессий.
+    token_service: Arc<TokenService<T>>,
+    /// Отправка писем со ссылкой на сброс пароля.
+    mailer: Arc<dyn Mailer>,
+    /// Адрес веб-клиента, от которого строится ссылка на сброс пароля.
+    site_url: String,
+}
+
+impl<U, P, T> PasswordService<U, P, T>
+where
+    U: UserRepository + ?Sized + 'static,
+    P: PasswordResetRepository + ?Sized + 'static,
+    T: TokenRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`PasswordService`].
+    pub(crate) fn new(
+        users: Arc<U>,
+        resets: Arc<P>,
+        token_service: Arc<TokenService<T>>,
+        mailer: Arc<dyn Mailer>,
+        site_url: String,
+    ) -> Self {
+        Self {
+            users,
+            resets,
+            token_service,
+            mailer,
+            site_url,
+        }
+    }
+
+    /// Сменить пароль авторизованного пользователя.
+    ///
+    /// Все сессии пользователя, включая текущую, завершаются; новую сессию
+    /// открывает вызывающий.
+    #[instrument(skip(self, user, cmd), level = "debug", fields(username = %user.username))]
+    pub(crate) async fn change_password(
+        &self,
+        user: &AuthenticatedUser,
+        cmd: &ChangePassword,
+    ) -> Result<User, DomainError> {
+        let account = self
+            .users
+            .get_by_id(&user.id)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+
+        let verified = cmd
+            .old_password
+            .verify_hash(&account.password_hash)
+            .map_err(|err| DomainError::invalid_credentials(format!("ошибка хеширования: {err}")))?;
+        if !verified {
+            warn!(
+                event = "password_change_rejected",
+                username = %user.username,
+                "Неверный текущий пароль при смене пароля"
+            );
+            return Err(DomainError::invalid_password("не совпадает с текущим"));
+        }
+        if cmd.old_password == cmd.new_password {
+            return Err(DomainError::invalid_password(
+                "новый пароль совпадает с текущим",
+            ));
+        }
+
+        let account = self.set_password(&user.id, &cmd.new_password).await?;
+
+        info!(
+            event = "password_changed",
+            username = %user.username,
+            "Пароль изменён"
+        );
+
+        Ok(account)
+    }
+
+    /// Отправить на адрес `email` письмо с токеном сброса пароля.
+    ///
+    /// Чтобы не раскрывать, зарегистрирован ли адрес, для неизвестного
+    /// адреса и при ошибке отправки письма ответ такой же, как при успехе.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn request_reset(&self, email: &Email) -> Result<(), DomainError> {
+        let email = email.to_lowercase();
+        let user = match self.users.get_by_email(&email).await {
+            Ok(user) => user,
+            Err(sqlx::Error::RowNotFound) => {
+                info!(email = %email, "Сброс пароля для незарегистрированного адреса");
+                return Ok(());
+            }
+            Err(err) => return Err(DomainError::server_err(err.to_string())),
+        };
+        if user.is_disabled() {
+            warn!(
+                event = "password_reset_rejected",
+                username = %user.username,
+                "Сброс пароля для заблокированной учётной записи"
+            );
+            return Ok(());
+        }
+        let user_id = user
+            .id
+            .clone()
+            .ok_or_else(|| DomainError::server_err("Пользователь не имеет ID"))?;
+
+        let token = generate_secret();
+        let expires_at = Utc::now() + PASSWORD_RESET_TOKEN_LIFETIME;
+        self.resets
+            .create(&NewPasswordResetToken {
+                user_id,
+                token_hash: hash_secret(&token),
+                expires_at,
+            })
+            .await
+            .map_err(|err| DomainError::server_err(err.to_string()))?;
+
+        let link = reset_link(&self.site_url, &token);
+        let mail = Mail {
+            to: user.email.clone(),
+            subject: "Сброс пароля".to_string(),
+            body: reset_mail_body(&user.username, &link, &token, expires_at),
+        };
+        match self.mailer.send(&mail).await {
+            Ok(()) => info!(
+                event = "password_reset_requested",
+                username = %user.username,
+                "Отправлено письмо для сброса пароля"
+            ),
+            Err(err) => error!(
+                error = %err,
+                username = %user.username,
+                "Не удалось отправить письмо для сброса пароля"
+            ),
+        }
+
+        Ok(())
+    }
+
+    /// Установить новый пароль по токену сброса из письма.
+    ///
+    /// Токен действует один раз. Вместе с паролем снимается блокировка
+    /// входа после неудачных попыток.
+    #[instrument(skip_all, level = "debug")]
+    pub(crate) async fn reset_password(
+        &self,
+        token: &str,
+        new_password: &UserPassword,
+    ) -> Result<(), DomainError> {
+        let user_id = self
+            .resets
+            .consume(&hash_secret(token), Utc::now())
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::invalid_token(
+                    "токен сброса пароля недействителен или истёк",
+                ),
+                unique_violations: None,
+            })?;
+
+        let user = self.set_password(&user_id, new_password).await?;
+
+        info!(
+            event = "password_reset",
+            username = %user.username,
+            "Пароль сброшен по токену из письма"
+        );
+
+        Ok(())
+    }
+
+    /// Сохранить новый пароль и завершить все сессии пользователя.
+    async fn set_password(
+        &self,
+        user_id: &DataId,
+        password: &UserPassword,
+    ) -> Result<User, DomainError> {
+        let password_hash = password
+            .hash()
+            .map_err(|err| DomainError::server_err(err.to_string()))?;
+
+        let user = self
+            .users
+            .set_password(user_id, &password_hash)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+        self.token_service.revoke_user(user_id).await?;
+
+        Ok(user)
+    }
+}
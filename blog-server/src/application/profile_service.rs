@@ -0,0 +1,96 @@
+//! Бизнес-логика профиля текущего пользователя.
+
+use crate::{
+    application::verification_service::VerificationService,
+    data::user_repo::UserRepository,
+    domain::user::{AuthenticatedUser, UpdateProfile, User},
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+/// Сервисы просмотра и изменения профиля текущим пользователем.
+pub(crate) struct ProfileService<U: UserRepository + ?Sized + 'static = dyn UserRepository> {
+    /// Репозиторий пользователей.
+    users: Arc<U>,
+    /// Подтверждение нового адреса электронной почты.
+    verification_service: Arc<VerificationService<U>>,
+}
+
+impl<U> ProfileService<U>
+where
+    U: UserRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`ProfileService`].
+    pub(crate) fn new(users: Arc<U>, verification_service: Arc<VerificationService<U>>) -> Self {
+        Self {
+            users,
+            verification_service,
+        }
+    }
+
+    /// Данные авторизованного пользователя.
+    #[instrument(skip_all, level = "debug", fields(username = %user.username))]
+    pub(crate) async fn me(&self, user: &AuthenticatedUser) -> Result<User, DomainError> {
+        self.users
+            .get_by_id(&user.id)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })
+    }
+
+    /// Изменить профиль авторизованного пользователя.
+    ///
+    /// На новый адрес электронной почты отправляется письмо для его
+    /// подтверждения; ошибка отправки изменение не отменяет.
+    ///
+    /// ## Errors
+    ///
+    /// [`DomainError::EmailAlreadyExists`], если адрес занят другим
+    /// пользователем.
+    #[instrument(skip_all, level = "debug", fields(username = %user.username))]
+    pub(crate) async fn update(
+        &self,
+        user: &AuthenticatedUser,
+        mut update: UpdateProfile,
+    ) -> Result<User, DomainError> {
+        if update.is_empty() {
+            return Err(DomainError::invalid_profile(
+                "не задано ни одного изменения",
+            ));
+        }
+        update.email = update.email.map(|email| email.to_lowercase());
+
+        let updated = self
+            .users
+            .update_profile(&user.id, &update)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: Some(vec![("users_email_key", DomainError::EmailAlreadyExists)]),
+            })?;
+
+        info!(
+            event = "profile_updated",
+            username = %user.username,
+            email_changed = update.email.is_some() && !updated.is_email_verified(),
+            "Профиль пользователя изменён"
+        );
+
+        // `send_verification` не отправляет письмо для подтверждённого
+        // адреса, в том числе если адрес не изменился.
+        if update.email.is_some()
+            && let Err(err) = self.verification_service.send_verification(&updated).await
+        {
+            error!(
+                error = %err,
+                username = %updated.username,
+                "Ошибка отправки письма для подтверждения нового адреса"
+            );
+        }
+
+        Ok(updated)
+    }
+}
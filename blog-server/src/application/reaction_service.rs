@@ -0,0 +1,171 @@
+//! Бизнес-логика реакций на публикации.
+
+use crate::{
+    application::blog_service::load_visible_post,
+    data::{post_repo::PostRepository, reaction_repo::ReactionRepository},
+    domain::{
+        post::Post,
+        reaction::{PostReactions, ReactionKind},
+        types::DataId,
+    },
+    errors::DomainError,
+};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+/// Сервисы для взаимодействия с реакциями на публикации.
+///
+/// Реакции ставятся только на публикации, доступные пользователю.
+/// Счётчики реакций выгружаются репозиторием публикаций вместе с постом,
+/// а реакции текущего пользователя дополняются этим сервисом.
+pub(crate) struct ReactionService<X = dyn ReactionRepository, P = dyn PostRepository>
+where
+    X: ReactionRepository + ?Sized + 'static,
+    P: PostRepository + ?Sized + 'static,
+{
+    /// Репозиторий реакций.
+    repo: Arc<X>,
+    /// Репозиторий публикаций.
+    post_repo: Arc<P>,
+}
+
+impl<X, P> ReactionService<X, P>
+where
+    X: ReactionRepository + ?Sized + 'static,
+    P: PostRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`ReactionService`] с репозиториями реакций
+    /// и публикаций.
+    pub(crate) fn new(repo: Arc<X>, post_repo: Arc<P>) -> Self {
+        Self { repo, post_repo }
+    }
+
+    /// Проверить, что публикация существует и доступна пользователю.
+    async fn check_visible(&self, post_id: &DataId, user_id: &DataId) -> Result<(), DomainError> {
+        load_visible_post(self.post_repo.as_ref(), post_id, Some(user_id)).await?;
+
+        Ok(())
+    }
+
+    /// Сводка реакций на публикацию с реакциями пользователя.
+    async fn summary(
+        &self,
+        post_id: &DataId,
+        user_id: &DataId,
+    ) -> Result<PostReactions, DomainError> {
+        let map_err = |err: sqlx::Error| {
+            error!(
+                error=%err,
+                post_id=%post_id,
+                "Не удалось получить из БД реакции на публикацию"
+            );
+            DomainError::server_err(err.to_string())
+        };
+
+        let reactions = self.repo.counts(post_id).await.map_err(map_err)?;
+        let my_reactions = self
+            .repo
+            .list_by_user(std::slice::from_ref(post_id), user_id)
+            .await
+            .map_err(map_err)?
+            .into_iter()
+            .map(|(_, kind)| kind)
+            .collect();
+
+        Ok(PostReactions::new(post_id.clone(), reactions, my_reactions))
+    }
+
+    /// Поставить реакцию на публикацию.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn react(
+        &self,
+        post_id: &DataId,
+        user_id: &DataId,
+        kind: ReactionKind,
+    ) -> Result<PostReactions, DomainError> {
+        self.check_visible(post_id, user_id).await?;
+
+        self.repo.add(post_id, user_id, kind).await.map_err(|err| {
+            error!(
+                error=%err,
+                post_id=%post_id,
+                kind=%kind,
+                "Ошибка сохранения реакции на публикацию"
+            );
+            DomainError::server_err(err.to_string())
+        })?;
+
+        info!(post_id = %post_id, user_id = %user_id, kind = %kind, "Реакция поставлена");
+
+        self.summary(post_id, user_id).await
+    }
+
+    /// Снять реакцию с публикации.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn unreact(
+        &self,
+        post_id: &DataId,
+        user_id: &DataId,
+        kind: ReactionKind,
+    ) -> Result<PostReactions, DomainError> {
+        self.check_visible(post_id, user_id).await?;
+
+        self.repo
+            .remove(post_id, user_id, kind)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    post_id=%post_id,
+                    kind=%kind,
+                    "Ошибка удаления реакции на публикацию"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        info!(post_id = %post_id, user_id = %user_id, kind = %kind, "Реакция снята");
+
+        self.summary(post_id, user_id).await
+    }
+
+    /// Дополнить публикации реакциями текущего пользователя. Для анонимных
+    /// запросов публикации не меняются.
+    #[instrument(skip(self, posts), level = "debug")]
+    pub(crate) async fn apply_viewer(
+        &self,
+        posts: &mut [Post],
+        viewer: Option<&DataId>,
+    ) -> Result<(), DomainError> {
+        let Some(user_id) = viewer else {
+            return Ok(());
+        };
+
+        let post_ids: Vec<DataId> = posts.iter().filter_map(|post| post.id.clone()).collect();
+        if post_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mine = self
+            .repo
+            .list_by_user(&post_ids, user_id)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    user_id=%user_id,
+                    "Не удалось получить из БД реакции пользователя"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        for post in posts.iter_mut() {
+            post.my_reactions = mine
+                .iter()
+                .filter(|(post_id, _)| post.id.as_ref() == Some(post_id))
+                .map(|(_, kind)| *kind)
+                .collect();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,296 @@
+//! Бизнес-логика истории изменений публикаций.
+
+use crate::{
+    application::audit_service::AuditService,
+    data::{
+        audit_repo::AuditRepository, post_repo::PostRepository, revision_repo::RevisionRepository,
+    },
+    domain::{
+        audit::{post_summary, AuditAction, AuditTarget, NewAuditEvent, RequestContext},
+        moderation::ModerationFilter,
+        post::{EditPost, Post},
+        revision::{ListRevisions, PostRevision, RevisionDiff},
+        types::DataId,
+        user::AuthenticatedUser,
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+/// Сервисы для взаимодействия с ревизиями публикаций.
+///
+/// История публикации доступна только её автору. Ревизии записываются
+/// репозиторием публикаций при создании и каждом обновлении.
+pub(crate) struct RevisionService<
+    V = dyn RevisionRepository,
+    P = dyn PostRepository,
+    L = dyn AuditRepository,
+> where
+    V: RevisionRepository + ?Sized + 'static,
+    P: PostRepository + ?Sized + 'static,
+    L: AuditRepository + ?Sized + 'static,
+{
+    /// Репозиторий ревизий.
+    repo: Arc<V>,
+    /// Репозиторий публикаций.
+    post_repo: Arc<P>,
+    /// Правила модерации: восстановленная версия проверяется, как новая.
+    filter: ModerationFilter,
+    /// Журнал аудита: восстановление публикаций из ревизий.
+    audit: Arc<AuditService<L>>,
+}
+
+impl<V, P, L> RevisionService<V, P, L>
+where
+    V: RevisionRepository + ?Sized + 'static,
+    P: PostRepository + ?Sized + 'static,
+    L: AuditRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`RevisionService`] с репозиториями ревизий
+    /// и публикаций, правилами модерации и журналом аудита.
+    pub(crate) fn new(
+        repo: Arc<V>,
+        post_repo: Arc<P>,
+        filter: ModerationFilter,
+        audit: Arc<AuditService<L>>,
+    ) -> Self {
+        Self {
+            repo,
+            post_repo,
+            filter,
+            audit,
+        }
+    }
+
+    /// Проверить, что публикация существует и пользователь — её автор.
+    async fn check_author(&self, post_id: &DataId, user_id: &DataId) -> Result<(), DomainError> {
+        let author_id = self
+            .post_repo
+            .get_author_id(post_id)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::PostNotFound,
+                unique_violations: None,
+            })?;
+
+        if !author_id.eq(user_id) {
+            return Err(DomainError::Forbidden);
+        }
+
+        Ok(())
+    }
+
+    /// Получить ревизию без проверки доступа.
+    async fn fetch_revision(
+        &self,
+        post_id: &DataId,
+        revision: i32,
+    ) -> Result<PostRevision, DomainError> {
+        self.repo
+            .get(post_id, revision)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::RevisionNotFound,
+                unique_violations: None,
+            })
+    }
+
+    /// Предоставить список ревизий публикации, от новых к старым.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn list_revisions(
+        &self,
+        post_id: &DataId,
+        user_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<ListRevisions, DomainError> {
+        self.check_author(post_id, user_id).await?;
+
+        let (revisions, total) = self
+            .repo
+            .list_by_post(post_id, limit, offset)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    post_id=%post_id,
+                    "Не удалось получить из БД список ревизий"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        Ok(ListRevisions::new(revisions, total, limit, offset))
+    }
+
+    /// Получить отдельную ревизию публикации.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn get_revision(
+        &self,
+        post_id: &DataId,
+        revision: i32,
+        user_id: &DataId,
+    ) -> Result<PostRevision, DomainError> {
+        self.check_author(post_id, user_id).await?;
+        self.fetch_revision(post_id, revision).await
+    }
+
+    /// Построчно сравнить ревизии `from` и `to` публикации.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn diff_revisions(
+        &self,
+        post_id: &DataId,
+        from: i32,
+        to: i32,
+        user_id: &DataId,
+    ) -> Result<RevisionDiff, DomainError> {
+        self.check_author(post_id, user_id).await?;
+
+        let from = self.fetch_revision(post_id, from).await?;
+        let to = self.fetch_revision(post_id, to).await?;
+
+        Ok(RevisionDiff::new(&from, &to))
+    }
+
+    /// Восстановить публикацию до состояния ревизии.
+    ///
+    /// Восстанавливаются заголовок, содержание и теги; восстановленная
+    /// версия сохраняется новой ревизией, прежняя история не меняется.
+    /// Восстановление записывается в журнал аудита.
+    #[instrument(skip(self, user, ctx), level = "debug", fields(user_id = %user.id))]
+    pub(crate) async fn restore_revision(
+        &self,
+        post_id: &DataId,
+        revision: i32,
+        user: &AuthenticatedUser,
+        ctx: &RequestContext,
+    ) -> Result<Post, DomainError> {
+        let user_id = &user.id;
+        self.check_author(post_id, user_id).await?;
+
+        let restored = self.fetch_revision(post_id, revision).await?;
+        let mut post = self.post_repo.get(post_id).await.map_repo_err(RepoErrorMap {
+            not_found: DomainError::PostNotFound,
+            unique_violations: None,
+        })?;
+        let before = post_summary(&post);
+
+        post.update(&EditPost {
+            title: Some(restored.title),
+            content: Some(restored.content),
+            tags: Some(restored.tags),
+        });
+        self.filter.moderate_post(&mut post)?;
+
+        let post = self.post_repo.update(&post).await.map_err(|err| {
+            error!(
+                error=%err,
+                post_id=%post_id,
+                revision,
+                "Ошибка восстановления ревизии публикации"
+            );
+            DomainError::server_err(err.to_string())
+        })?;
+
+        info!(
+            post_id = %post_id,
+            revision,
+            user_id = %user_id,
+            "Публикация восстановлена из ревизии");
+
+        let event = NewAuditEvent::new(
+            AuditAction::PostRevisionRestored,
+            Some(user_id.clone()),
+            user.username.to_string(),
+            ctx,
+        )
+        .with_target(AuditTarget::Post(post_id.clone()))
+        .with_changes(Some(before), Some(post_summary(&post)));
+        self.audit.record(event).await;
+
+        Ok(post)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::fixtures, Repositories},
+        domain::{
+            audit::AuditFilter,
+            post::PostStatus,
+            role::Role,
+            types::{PostTitle, Tag},
+        },
+    };
+
+    #[actix_web::test]
+    async fn restore_revision_is_audited() {
+        let repos = Repositories::memory();
+        let service = fixtures::revision_service(&repos);
+        let ctx = RequestContext::default();
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let reader = fixtures::user(&repos, "bob", Role::default()).await;
+        let mut post = fixtures::post(&repos, &author.id, PostStatus::Published).await;
+        let post_id = post.id.clone().unwrap();
+        post.update(&EditPost {
+            title: Some(PostTitle::try_from("Новый заголовок".to_string()).unwrap()),
+            content: None,
+            tags: None,
+        });
+        repos.posts.update(&post).await.unwrap();
+
+        let denied = service.restore_revision(&post_id, 1, &reader, &ctx).await;
+        assert!(matches!(denied, Err(DomainError::Forbidden)));
+        let restored = service
+            .restore_revision(&post_id, 1, &author, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(restored.title.as_ref(), "Hello world");
+
+        let filter = AuditFilter {
+            limit: 10,
+            ..Default::default()
+        };
+        let (events, total) = repos.audit.list(&filter).await.unwrap();
+        assert_eq!(total, 1);
+        let event = &events[0];
+        assert_eq!(event.action, AuditAction::PostRevisionRestored);
+        assert_eq!(
+            event.target.as_deref(),
+            Some(format!("post:{post_id}").as_str())
+        );
+        assert_eq!(event.before.as_ref().unwrap()["title"], "Новый заголовок");
+        assert_eq!(event.after.as_ref().unwrap()["title"], "Hello world");
+    }
+
+    #[actix_web::test]
+    async fn unchanged_post_does_not_add_revision() {
+        let repos = Repositories::memory();
+        let service = fixtures::revision_service(&repos);
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let mut post = fixtures::post(&repos, &author.id, PostStatus::Draft).await;
+        let post_id = post.id.clone().unwrap();
+
+        post.status = PostStatus::Published;
+        repos.posts.update(&post).await.unwrap();
+        let listed = service
+            .list_revisions(&post_id, &author.id, 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(listed.total, 1);
+
+        post.update(&EditPost {
+            title: None,
+            content: None,
+            tags: Some(vec![Tag::try_from("rust".to_string()).unwrap()]),
+        });
+        repos.posts.update(&post).await.unwrap();
+        let listed = service
+            .list_revisions(&post_id, &author.id, 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(listed.total, 2);
+    }
+}
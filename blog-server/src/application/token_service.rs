@@ -0,0 +1,241 @@
+//! Сервис сессий: выдача, обновление и отзыв токенов.
+
+use crate::{
+    data::token_repo::TokenRepository,
+    domain::{
+        role::Role,
+        token::{generate_secret, hash_secret, random_id, AccessTokenId, NewRefreshToken, TokenPair},
+        types::{DataId, Username},
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+    infrastructure::jwt::JwtService,
+    settings::REFRESH_TOKEN_LIFETIME,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// Сервис сессий пользователей.
+pub(crate) struct TokenService<R: TokenRepository + ?Sized + 'static = dyn TokenRepository> {
+    /// Репозиторий токенов.
+    repo: Arc<R>,
+    /// Выдача и проверка токенов доступа; хранит список отозванных.
+    jwt_service: JwtService,
+}
+
+impl<R> TokenService<R>
+where
+    R: TokenRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`TokenService`] с репозиторием токенов.
+    pub(crate) fn new(repo: Arc<R>, jwt_service: JwtService) -> Self {
+        Self { repo, jwt_service }
+    }
+
+    /// Загрузить из базы данных список отозванных токенов доступа, чтобы
+    /// отзыв действовал и после перезапуска сервера.
+    pub(crate) async fn load_revoked(&self) -> Result<(), DomainError> {
+        let revoked = self
+            .repo
+            .list_revoked_access()
+            .await
+            .map_err(|err| DomainError::server_err(err.to_string()))?;
+        self.jwt_service.revoke(revoked);
+
+        Ok(())
+    }
+
+    /// Начать новую сессию пользователя: выдать токен доступа и токен
+    /// обновления.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn issue(
+        &self,
+        user_id: &DataId,
+        username: &Username,
+        role: Role,
+    ) -> Result<TokenPair, DomainError> {
+        let (pair, token) = self.new_tokens(user_id, username, role, random_id())?;
+        self.repo
+            .create(&token)
+            .await
+            .map_err(|err| DomainError::server_err(err.to_string()))?;
+
+        Ok(pair)
+    }
+
+    /// Обновить сессию: заменить токен обновления новым и выдать новый
+    /// токен доступа. Прежний токен доступа сессии отзывается.
+    ///
+    /// Повторное предъявление уже заменённого токена означает, что он
+    /// попал в чужие руки: сессия отзывается целиком. Новый токен доступа
+    /// содержит текущую роль пользователя.
+    ///
+    /// ## Returns
+    ///
+    /// Имя владельца сессии и новая пара токенов.
+    #[instrument(skip_all, level = "debug")]
+    pub(crate) async fn refresh(
+        &self,
+        refresh_token: &str,
+    ) -> Result<(Username, TokenPair), DomainError> {
+        let current = self
+            .repo
+            .get_by_hash(&hash_secret(refresh_token))
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::invalid_token("неизвестный токен обновления"),
+                unique_violations: None,
+            })?;
+
+        if current.revoked_at.is_some() {
+            return Err(self.reject_reuse(&current.family_id, &current.username).await?);
+        }
+        if current.is_expired(Utc::now()) {
+            return Err(DomainError::invalid_token("срок действия токена обновления истёк"));
+        }
+        if current.account_disabled {
+            self.revoke_family(&current.family_id).await?;
+            return Err(DomainError::AccountDisabled);
+        }
+
+        let (pair, token) = self.new_tokens(
+            &current.user_id,
+            &current.username,
+            current.role,
+            current.family_id.clone(),
+        )?;
+        let rotated = self
+            .repo
+            .rotate(&current.id, &token)
+            .await
+            .map_err(|err| DomainError::server_err(err.to_string()))?;
+        if !rotated {
+            return Err(self.reject_reuse(&current.family_id, &current.username).await?);
+        }
+
+        self.revoke_access(vec![current.access]).await?;
+
+        Ok((current.username, pair))
+    }
+
+    /// Завершить сессию, к которой относится токен обновления: отзываются
+    /// все её токены обновления и токены доступа. Неизвестный токен
+    /// ошибкой не считается.
+    #[instrument(skip_all, level = "debug")]
+    pub(crate) async fn logout(&self, refresh_token: &str) -> Result<(), DomainError> {
+        let current = match self
+            .repo
+            .get_by_hash(&hash_secret(refresh_token))
+            .await
+        {
+            Ok(token) => token,
+            Err(sqlx::Error::RowNotFound) => return Ok(()),
+            Err(err) => return Err(DomainError::server_err(err.to_string())),
+        };
+
+        self.revoke_family(&current.family_id).await?;
+        info!("Пользователь {} завершил сессию", current.username);
+
+        Ok(())
+    }
+
+    /// Завершить все сессии пользователя, например при блокировке учётной
+    /// записи.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn revoke_user(&self, user_id: &DataId) -> Result<(), DomainError> {
+        let access = self
+            .repo
+            .revoke_user(user_id)
+            .await
+            .map_err(|err| DomainError::server_err(err.to_string()))?;
+
+        self.revoke_access(access).await
+    }
+
+    /// Отозвать сессию, в которой повторно использован токен обновления.
+    ///
+    /// ## Returns
+    ///
+    /// Ошибку для ответа клиенту.
+    async fn reject_reuse(
+        &self,
+        family_id: &str,
+        username: &Username,
+    ) -> Result<DomainError, DomainError> {
+        warn!(
+            username = %username,
+            "Повторное использование токена обновления, сессия отозвана"
+        );
+        self.revoke_family(family_id).await?;
+
+        Ok(DomainError::invalid_token(
+            "токен обновления уже использован, войдите заново",
+        ))
+    }
+
+    /// Отозвать все токены сессии `family_id`.
+    async fn revoke_family(&self, family_id: &str) -> Result<(), DomainError> {
+        let access = self
+            .repo
+            .revoke_family(family_id)
+            .await
+            .map_err(|err| DomainError::server_err(err.to_string()))?;
+
+        self.revoke_access(access).await
+    }
+
+    /// Ещё не истёкшие токены доступа пользователя. Нужны, чтобы отозвать
+    /// их после удаления учётной записи, вместе с которой удаляются и
+    /// сведения о сессиях.
+    pub(crate) async fn user_access_tokens(
+        &self,
+        user_id: &DataId,
+    ) -> Result<Vec<AccessTokenId>, DomainError> {
+        self.repo
+            .list_user_access(user_id)
+            .await
+            .map_err(|err| DomainError::server_err(err.to_string()))
+    }
+
+    /// Отозвать токены доступа: записать в базу данных и в список
+    /// отозванных, по которому проверяются запросы.
+    pub(crate) async fn revoke_access(
+        &self,
+        tokens: Vec<AccessTokenId>,
+    ) -> Result<(), DomainError> {
+        self.repo
+            .revoke_access(&tokens)
+            .await
+            .map_err(|err| DomainError::server_err(err.to_string()))?;
+        self.jwt_service.revoke(tokens);
+
+        Ok(())
+    }
+
+    /// Сформировать пару токенов сессии `family_id` и запись токена
+    /// обновления для базы данных.
+    fn new_tokens(
+        &self,
+        user_id: &DataId,
+        username: &Username,
+        role: Role,
+        family_id: String,
+    ) -> Result<(TokenPair, NewRefreshToken), DomainError> {
+        let access = self.jwt_service.generate_token(user_id, username, role)?;
+        let refresh_token = generate_secret();
+
+        let token = NewRefreshToken {
+            user_id: user_id.clone(),
+            token_hash: hash_secret(&refresh_token),
+            family_id,
+            access: access.id,
+            expires_at: Utc::now() + REFRESH_TOKEN_LIFETIME,
+        };
+        let pair = TokenPair {
+            access_token: access.token,
+            refresh_token,
+        };
+
+        Ok((pair, token))
+    }
+}
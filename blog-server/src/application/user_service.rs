@@ -0,0 +1,144 @@
+//! Бизнес-логика публичных профилей пользователей.
+
+use crate::{
+    data::{post_repo::PostRepository, user_repo::UserRepository},
+    domain::{
+        post::ListPosts,
+        types::{DataId, Username},
+        user::UserProfile,
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+};
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+/// Сервисы для взаимодействия с профилями авторов.
+///
+/// Для выгрузки публикаций автора использует репозиторий постов.
+pub(crate) struct UserService<U = dyn UserRepository, P = dyn PostRepository>
+where
+    U: UserRepository + ?Sized + 'static,
+    P: PostRepository + ?Sized + 'static,
+{
+    /// Репозиторий пользователей.
+    repo: Arc<U>,
+    /// Репозиторий публикаций.
+    post_repo: Arc<P>,
+}
+
+impl<U, P> UserService<U, P>
+where
+    U: UserRepository + ?Sized + 'static,
+    P: PostRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`UserService`] с репозиториями пользователей
+    /// и публикаций.
+    pub(crate) fn new(repo: Arc<U>, post_repo: Arc<P>) -> Self {
+        Self { repo, post_repo }
+    }
+
+    /// Предоставить публичный профиль пользователя.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn get_profile(&self, username: &Username) -> Result<UserProfile, DomainError> {
+        self.repo
+            .get_profile(&username.to_lowercase())
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })
+    }
+
+    /// Получить id автора по его имени.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn get_author_id(&self, username: &Username) -> Result<DataId, DomainError> {
+        let author = self
+            .repo
+            .get_by_username(&username.to_lowercase())
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+
+        author.id.ok_or(DomainError::UserNotFound)
+    }
+
+    /// Предоставить опубликованные записи автора, от новых к старым.
+    #[instrument(skip(self), level = "debug")]
+    pub(crate) async fn list_author_posts(
+        &self,
+        username: &Username,
+        limit: i32,
+        offset: i32,
+    ) -> Result<ListPosts, DomainError> {
+        let author_id = self.get_author_id(username).await?;
+
+        let (posts, total) = self
+            .post_repo
+            .list_by_author(&author_id, limit, offset)
+            .await
+            .map_err(|err| {
+                error!(
+                    error=%err,
+                    author_id=%author_id,
+                    "Не удалось получить из БД публикации автора"
+                );
+                DomainError::server_err(err.to_string())
+            })?;
+
+        Ok(ListPosts::new(posts, Some(total), limit, offset, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::fixtures, Repositories},
+        domain::{post::PostStatus, role::Role},
+    };
+
+    fn username(name: &str) -> Username {
+        Username::try_from(name.to_string()).unwrap()
+    }
+
+    #[actix_web::test]
+    async fn profile_counts_published_posts() {
+        let repos = Repositories::memory();
+        let service = fixtures::user_service(&repos);
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        fixtures::post(&repos, &author.id, PostStatus::Published).await;
+        fixtures::post(&repos, &author.id, PostStatus::Published).await;
+        fixtures::post(&repos, &author.id, PostStatus::Draft).await;
+
+        let profile = service.get_profile(&username("Alice")).await.unwrap();
+        assert_eq!(profile.username, author.username);
+        assert_eq!(profile.posts_count, 2);
+
+        let missing = service.get_profile(&username("nobody")).await;
+        assert!(matches!(missing, Err(DomainError::UserNotFound)));
+    }
+
+    #[actix_web::test]
+    async fn author_posts_are_published_posts_of_that_author() {
+        let repos = Repositories::memory();
+        let service = fixtures::user_service(&repos);
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let other = fixtures::user(&repos, "bob", Role::default()).await;
+        let published = fixtures::post(&repos, &author.id, PostStatus::Published).await;
+        fixtures::post(&repos, &author.id, PostStatus::Draft).await;
+        fixtures::post(&repos, &other.id, PostStatus::Published).await;
+
+        let listed = service
+            .list_author_posts(&username("alice"), 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(listed.total, Some(1));
+        assert_eq!(listed.posts[0].id, published.id);
+        assert_eq!(listed.posts[0].author_username, Some(author.username));
+
+        let missing = service.list_author_posts(&username("nobody"), 10, 0).await;
+        assert!(matches!(missing, Err(DomainError::UserNotFound)));
+    }
+}
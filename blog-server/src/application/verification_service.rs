@@ -0,0 +1,146 @@
+//! Бизнес-логика подтверждения адреса электронной почты.
+
+use crate::{
+    data::user_repo::UserRepository,
+    domain::{
+        user::{AuthenticatedUser, User},
+        verification::{verification_link, verification_mail_body},
+    },
+    errors::{DomainError, RepoErrorMap, SqlxResultExt},
+    infrastructure::{
+        jwt::JwtService,
+        mailer::{Mail, Mailer},
+    },
+};
+use chrono::Utc;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// Сервисы подтверждения адреса электронной почты.
+///
+/// Письмо содержит подписанный токен с id пользователя и адресом, поэтому
+/// токены не хранятся в базе данных. Токен действует, пока не истёк и пока
+/// адрес пользователя не изменился.
+pub(crate) struct VerificationService<U: UserRepository + ?Sized + 'static = dyn UserRepository> {
+    /// Репозиторий пользователей.
+    users: Arc<U>,
+    /// Подпись и проверка токенов подтверждения.
+    jwt_service: JwtService,
+    /// Отправка писем со ссылкой на подтверждение адреса.
+    mailer: Arc<dyn Mailer>,
+    /// Адрес веб-клиента, от которого строится ссылка на подтверждение.
+    site_url: String,
+}
+
+impl<U> VerificationService<U>
+where
+    U: UserRepository + ?Sized + 'static,
+{
+    /// Создать сервис [`VerificationService`].
+    pub(crate) fn new(
+        users: Arc<U>,
+        jwt_service: JwtService,
+        mailer: Arc<dyn Mailer>,
+        site_url: String,
+    ) -> Self {
+        Self {
+            users,
+            jwt_service,
+            mailer,
+            site_url,
+        }
+    }
+
+    /// Отправить пользователю `user` письмо со ссылкой на подтверждение
+    /// адреса. Для подтверждённого адреса письмо не отправляется.
+    #[instrument(skip_all, level = "debug", fields(username = %user.username))]
+    pub(crate) async fn send_verification(&self, user: &User) -> Result<(), DomainError> {
+        if user.is_email_verified() {
+            return Ok(());
+        }
+        let user_id = user
+            .id
+            .as_ref()
+            .ok_or_else(|| DomainError::server_err("Пользователь не имеет ID"))?;
+
+        let (token, expires_at) = self
+            .jwt_service
+            .generate_email_token(user_id, &user.email)
+            .map_err(|err| DomainError::server_err(err.to_string()))?;
+
+        let link = verification_link(&self.site_url, &token);
+        let mail = Mail {
+            to: user.email.clone(),
+            subject: "Подтверждение адреса электронной почты".to_string(),
+            body: verification_mail_body(&user.username, &link, &token, expires_at),
+        };
+        self.mailer
+            .send(&mail)
+            .await
+            .map_err(|err| DomainError::server_err(err.to_string()))?;
+
+        info!(
+            event = "email_verification_sent",
+            username = %user.username,
+            "Отправлено письмо для подтверждения адреса"
+        );
+
+        Ok(())
+    }
+
+    /// Повторно отправить письмо для подтверждения адреса авторизованному
+    /// пользователю.
+    ///
+    /// ## Errors
+    ///
+    /// [`DomainError::EmailAlreadyVerified`], если адрес уже подтверждён.
+    #[instrument(skip_all, level = "debug", fields(username = %user.username))]
+    pub(crate) async fn resend(&self, user: &AuthenticatedUser) -> Result<(), DomainError> {
+        let account = self
+            .users
+            .get_by_id(&user.id)
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::UserNotFound,
+                unique_violations: None,
+            })?;
+        if account.is_email_verified() {
+            return Err(DomainError::EmailAlreadyVerified);
+        }
+
+        self.send_verification(&account).await
+    }
+
+    /// Подтвердить адрес по токену из письма. Повторное подтверждение
+    /// ошибкой не считается.
+    #[instrument(skip_all, level = "debug")]
+    pub(crate) async fn verify(&self, token: &str) -> Result<User, DomainError> {
+        let claims = self.jwt_service.verify_email_token(token).map_err(|err| {
+            warn!(
+                event = "email_verification_rejected",
+                error = %err,
+                "Недействительный токен подтверждения адреса"
+            );
+            DomainError::invalid_token("токен подтверждения адреса недействителен или истёк")
+        })?;
+
+        let user = self
+            .users
+            .mark_email_verified(&claims.sub, &claims.email, Utc::now())
+            .await
+            .map_repo_err(RepoErrorMap {
+                not_found: DomainError::invalid_token(
+                    "адрес электронной почты изменился после отправки письма",
+                ),
+                unique_violations: None,
+            })?;
+
+        info!(
+            event = "email_verified",
+            username = %user.username,
+            "Адрес электронной почты подтверждён"
+        );
+
+        Ok(user)
+    }
+}
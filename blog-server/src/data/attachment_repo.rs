@@ -0,0 +1,86 @@
+//! Репозиторий вложений.
+
+use crate::{
+    domain::{
+        attachment::{Attachment, NewAttachment},
+        types::StorageKey,
+    },
+    repo_pg_pool,
+};
+use sqlx::{postgres::PgRow, Error as SqlxError, PgPool, Row};
+use tonic::async_trait;
+
+#[async_trait]
+pub(crate) trait AttachmentRepository: Send + Sync {
+    /// Записать сведения о сохранённом файле вложения.
+    async fn create(&self, attachment: &NewAttachment) -> Result<Attachment, SqlxError>;
+
+    /// Получить вложение по ключу файла в хранилище.
+    async fn get_by_key(&self, key: &StorageKey) -> Result<Attachment, SqlxError>;
+}
+
+repo_pg_pool!(
+    #[derive(Clone)]
+    /// Структура взаимодействия с базой данной для работы с `attachments`.
+    pub(crate) struct AttachmentRepo;
+);
+
+#[async_trait]
+impl AttachmentRepository for AttachmentRepo {
+    async fn create(&self, attachment: &NewAttachment) -> Result<Attachment, SqlxError> {
+        let (width, height) = attachment.dimensions.unzip();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO attachments
+                (owner_id, storage_key, file_name, content_type, size_bytes, width, height)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, owner_id, storage_key, file_name, content_type, size_bytes,
+                      width, height, created_at
+            "#,
+        )
+        .bind(&attachment.owner_id)
+        .bind(&attachment.storage_key)
+        .bind(&attachment.file_name)
+        .bind(attachment.media_type.content_type())
+        .bind(attachment.size)
+        .bind(width)
+        .bind(height)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_attachment_by_row(&result))
+    }
+
+    async fn get_by_key(&self, key: &StorageKey) -> Result<Attachment, SqlxError> {
+        let result = sqlx::query(
+            r#"
+            SELECT id, owner_id, storage_key, file_name, content_type, size_bytes,
+                   width, height, created_at
+            FROM attachments
+            WHERE storage_key = $1
+            "#,
+        )
+        .bind(key)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_attachment_by_row(&result))
+    }
+}
+
+/// Поддерживающая функция: создаёт [`Attachment`] на основе записи из базы
+/// данных, обёрнутой в [`PgRow`].
+fn make_attachment_by_row(record: &PgRow) -> Attachment {
+    Attachment {
+        id: record.get("id"),
+        owner_id: record.get("owner_id"),
+        storage_key: record.get("storage_key"),
+        file_name: record.get("file_name"),
+        content_type: record.get("content_type"),
+        size: record.get("size_bytes"),
+        width: record.get("width"),
+        height: record.get("height"),
+        created_at: record.get("created_at"),
+    }
+}
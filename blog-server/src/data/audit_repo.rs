@@ -0,0 +1,131 @@
+//! Репозиторий журнала аудита.
+
+use crate::{
+    domain::audit::{AuditEvent, AuditFilter, NewAuditEvent},
+    repo_pg_pool,
+};
+use sqlx::{postgres::PgRow, Error as SqlxError, PgPool, Row};
+use tonic::async_trait;
+
+#[async_trait]
+pub(crate) trait AuditRepository: Send + Sync {
+    /// Добавить запись в журнал.
+    async fn create(&self, event: &NewAuditEvent) -> Result<(), SqlxError>;
+
+    /// Записи журнала, подходящие под фильтры `filter`, от новых к старым,
+    /// и их общее количество.
+    async fn list(&self, filter: &AuditFilter) -> Result<(Vec<AuditEvent>, i64), SqlxError>;
+}
+
+repo_pg_pool!(
+    #[derive(Clone)]
+    /// Структура взаимодействия с базой данной для работы с `audit_events`.
+    pub(crate) struct AuditRepo;
+);
+
+#[async_trait]
+impl AuditRepository for AuditRepo {
+    async fn create(&self, event: &NewAuditEvent) -> Result<(), SqlxError> {
+        sqlx::query(
+            r#"
+            INSERT INTO audit_events
+                (actor_id, actor, action, target, ip, user_agent, request_id, before, after)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(&event.actor_id)
+        .bind(&event.actor)
+        .bind(event.action.to_string())
+        .bind(event.target.as_ref().map(ToString::to_string))
+        .bind(event.context.ip.map(|ip| ip.to_string()))
+        .bind(&event.context.user_agent)
+        .bind(&event.context.request_id)
+        .bind(&event.before)
+        .bind(&event.after)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list(&self, filter: &AuditFilter) -> Result<(Vec<AuditEvent>, i64), SqlxError> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, actor_id, actor, action, target, ip, user_agent, request_id, before,
+                   after, created_at, COUNT(*) OVER () AS total
+            FROM audit_events
+            WHERE ($1::VARCHAR IS NULL OR actor = $1)
+              AND ($2::VARCHAR IS NULL OR action = $2)
+              AND ($3::VARCHAR IS NULL OR target = $3)
+              AND ($4::TIMESTAMPTZ IS NULL OR created_at >= $4)
+              AND ($5::TIMESTAMPTZ IS NULL OR created_at < $5)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $6 OFFSET $7
+            "#,
+        )
+        .bind(&filter.actor)
+        .bind(filter.action.map(|action| action.to_string()))
+        .bind(&filter.target)
+        .bind(filter.since)
+        .bind(filter.until)
+        .bind(filter.limit)
+        .bind(filter.offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total = match records.first() {
+            Some(record) => record.get("total"),
+            None => count(&self.pool, filter).await?,
+        };
+        let events = records
+            .iter()
+            .map(make_event_by_row)
+            .collect::<Result<_, _>>()?;
+
+        Ok((events, total))
+    }
+}
+
+/// Количество записей, подходящих под фильтры, если страница пуста
+/// (например, сдвиг больше числа записей).
+async fn count(pool: &PgPool, filter: &AuditFilter) -> Result<i64, SqlxError> {
+    sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*)
+        FROM audit_events
+        WHERE ($1::VARCHAR IS NULL OR actor = $1)
+          AND ($2::VARCHAR IS NULL OR action = $2)
+          AND ($3::VARCHAR IS NULL OR target = $3)
+          AND ($4::TIMESTAMPTZ IS NULL OR created_at >= $4)
+          AND ($5::TIMESTAMPTZ IS NULL OR created_at < $5)
+        "#,
+    )
+    .bind(&filter.actor)
+    .bind(filter.action.map(|action| action.to_string()))
+    .bind(&filter.target)
+    .bind(filter.since)
+    .bind(filter.until)
+    .fetch_one(pool)
+    .await
+}
+
+/// Создать [`AuditEvent`] из строки результата запроса.
+fn make_event_by_row(record: &PgRow) -> Result<AuditEvent, SqlxError> {
+    let action: String = record.get("action");
+
+    Ok(AuditEvent {
+        id: record.get("id"),
+        actor_id: record.get("actor_id"),
+        actor: record.get("actor"),
+        action: action
+            .parse()
+            .map_err(|err| SqlxError::Decode(Box::new(err)))?,
+        target: record.get("target"),
+        ip: record.get("ip"),
+        user_agent: record.get("user_agent"),
+        request_id: record.get("request_id"),
+        before: record.get("before"),
+        after: record.get("after"),
+        created_at: record.get("created_at"),
+    })
+}
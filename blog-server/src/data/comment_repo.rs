@@ -0,0 +1,149 @@
+//! Репозиторий комментариев.
+
+use crate::{
+    domain::{comment::Comment, types::DataId},
+    repo_pg_pool,
+};
+use sqlx::{postgres::PgRow, Error as SqlxError, PgPool, Row};
+use tonic::async_trait;
+
+#[async_trait]
+pub(crate) trait CommentRepository: Send + Sync {
+    /// Создать комментарий.
+    async fn create(&self, comment: &Comment) -> Result<Comment, SqlxError>;
+
+    /// Получить комментарий по его id.
+    async fn get(&self, comment_id: &DataId) -> Result<Comment, SqlxError>;
+
+    /// Предоставить список комментариев к публикации.
+    ///
+    /// ## Args
+    ///
+    /// - `post_id` — id публикации
+    /// - `limit` — количество записей, должно быть больше 1
+    /// - `offset` — отступ от первой записи в извлечённом списке
+    ///
+    /// ## Returns
+    ///
+    /// Перечень комментариев, с учётом заказа, и общее число комментариев
+    /// к публикации.
+    async fn list_by_post(
+        &self,
+        post_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Comment>, i64), SqlxError>;
+
+    /// Удаление комментария.
+    async fn delete(&self, comment_id: &DataId) -> Result<(), SqlxError>;
+}
+
+repo_pg_pool!(
+    #[derive(Clone)]
+    /// Структура взаимодействия с базой данной для работы с `comments`.
+    pub(crate) struct CommentRepo;
+);
+
+#[async_trait]
+impl CommentRepository for CommentRepo {
+    async fn create(&self, comment: &Comment) -> Result<Comment, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            INSERT INTO comments (post_id, author_id, content, created_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(&comment.post_id)
+        .bind(&comment.author_id)
+        .bind(&comment.content)
+        .bind(comment.created_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_comment_by_row(&record))
+    }
+
+    async fn get(&self, comment_id: &DataId) -> Result<Comment, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, post_id, author_id, content, created_at FROM comments WHERE id = $1
+            "#,
+        )
+        .bind(comment_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_comment_by_row(&record))
+    }
+
+    /// Предоставить список комментариев к публикации.
+    ///
+    /// ## Сортировка
+    ///
+    /// Комментарии выгружаются в хронологическом порядке, от самого раннего,
+    /// чтобы обсуждение читалось сверху вниз.
+    async fn list_by_post(
+        &self,
+        post_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Comment>, i64), SqlxError> {
+        let results = sqlx::query(
+            r#"
+            SELECT id, post_id, author_id, content, created_at
+            FROM comments
+            WHERE post_id = $1
+            ORDER BY created_at, id
+            LIMIT $2
+            OFFSET $3
+            "#,
+        )
+        .bind(post_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let comments = results
+            .into_iter()
+            .map(|row| make_comment_by_row(&row))
+            .collect();
+
+        let total_count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM comments WHERE post_id = $1")
+                .bind(post_id)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok((comments, total_count))
+    }
+
+    async fn delete(&self, comment_id: &DataId) -> Result<(), SqlxError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM comments WHERE id = $1
+            "#,
+        )
+        .bind(comment_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(SqlxError::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+/// Создать [`Comment`] на основе выгрузки строки из базы данных.
+fn make_comment_by_row(record: &PgRow) -> Comment {
+    Comment::new(
+        record.get("id"),
+        record.get("post_id"),
+        record.get("author_id"),
+        record.get("content"),
+        record.get("created_at"),
+    )
+}
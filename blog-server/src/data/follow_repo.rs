@@ -0,0 +1,159 @@
+//! Репозиторий подписок на авторов.
+
+use crate::{
+    domain::{follow::FollowUser, types::DataId},
+    repo_pg_pool,
+};
+use sqlx::{postgres::PgRow, Error as SqlxError, PgPool, Row};
+use tonic::async_trait;
+
+#[async_trait]
+pub(crate) trait FollowRepository: Send + Sync {
+    /// Подписать пользователя на автора. Повторная подписка ничего
+    /// не меняет.
+    async fn follow(&self, follower_id: &DataId, followee_id: &DataId) -> Result<(), SqlxError>;
+
+    /// Отписать пользователя от автора. Отсутствие подписки ошибкой
+    /// не считается.
+    async fn unfollow(&self, follower_id: &DataId, followee_id: &DataId) -> Result<(), SqlxError>;
+
+    /// Подписчики пользователя, от недавно подписавшихся.
+    ///
+    /// ## Returns
+    ///
+    /// Перечень подписчиков и их общее количество.
+    async fn list_followers(
+        &self,
+        user_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<FollowUser>, i64), SqlxError>;
+
+    /// Авторы, на которых подписан пользователь, от недавних подписок.
+    ///
+    /// ## Returns
+    ///
+    /// Перечень авторов и их общее количество.
+    async fn list_following(
+        &self,
+        user_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<FollowUser>, i64), SqlxError>;
+}
+
+repo_pg_pool!(
+    #[derive(Clone)]
+    /// Структура взаимодействия с базой данной для работы с `follows`.
+    pub(crate) struct FollowRepo;
+);
+
+#[async_trait]
+impl FollowRepository for FollowRepo {
+    async fn follow(&self, follower_id: &DataId, followee_id: &DataId) -> Result<(), SqlxError> {
+        sqlx::query(
+            r#"
+            INSERT INTO follows (follower_id, followee_id)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(follower_id)
+        .bind(followee_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn unfollow(&self, follower_id: &DataId, followee_id: &DataId) -> Result<(), SqlxError> {
+        sqlx::query(
+            r#"
+            DELETE FROM follows WHERE follower_id = $1 AND followee_id = $2
+            "#,
+        )
+        .bind(follower_id)
+        .bind(followee_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_followers(
+        &self,
+        user_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<FollowUser>, i64), SqlxError> {
+        let results = sqlx::query(
+            r#"
+            SELECT u.username, f.created_at
+            FROM follows f JOIN users u ON u.id = f.follower_id
+            WHERE f.followee_id = $1
+            ORDER BY f.created_at DESC, u.username
+            LIMIT $2
+            OFFSET $3
+            "#,
+        )
+        .bind(user_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total_count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM follows WHERE followee_id = $1")
+                .bind(user_id)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok((
+            results.iter().map(make_follow_user_by_row).collect(),
+            total_count,
+        ))
+    }
+
+    async fn list_following(
+        &self,
+        user_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<FollowUser>, i64), SqlxError> {
+        let results = sqlx::query(
+            r#"
+            SELECT u.username, f.created_at
+            FROM follows f JOIN users u ON u.id = f.followee_id
+            WHERE f.follower_id = $1
+            ORDER BY f.created_at DESC, u.username
+            LIMIT $2
+            OFFSET $3
+            "#,
+        )
+        .bind(user_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total_count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM follows WHERE follower_id = $1")
+                .bind(user_id)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok((
+            results.iter().map(make_follow_user_by_row).collect(),
+            total_count,
+        ))
+    }
+}
+
+/// Поддерживающая функция: создаёт [`FollowUser`] на основе записи из базы
+/// данных, обёрнутой в [`PgRow`].
+fn make_follow_user_by_row(record: &PgRow) -> FollowUser {
+    FollowUser {
+        username: record.get("username"),
+        followed_at: record.get("created_at"),
+    }
+}
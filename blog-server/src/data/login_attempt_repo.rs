@@ -0,0 +1,83 @@
+//! Репозиторий журнала попыток входа.
+
+use crate::{domain::login::NewLoginAttempt, repo_pg_pool};
+use chrono::{DateTime, Utc};
+use sqlx::{Error as SqlxError, PgPool, Row};
+use std::net::IpAddr;
+use tonic::async_trait;
+
+#[async_trait]
+pub(crate) trait LoginAttemptRepository: Send + Sync {
+    /// Записать попытку входа.
+    async fn create(&self, attempt: &NewLoginAttempt) -> Result<(), SqlxError>;
+
+    /// Неудачные попытки входа с IP-адреса `ip` начиная с `since`:
+    /// количество и время самой ранней из них.
+    async fn count_ip_failures(
+        &self,
+        ip: &IpAddr,
+        since: DateTime<Utc>,
+    ) -> Result<(i64, Option<DateTime<Utc>>), SqlxError>;
+
+    /// Удалить записи журнала, сделанные раньше `before`.
+    ///
+    /// ## Returns
+    ///
+    /// Количество удалённых записей.
+    async fn purge(&self, before: DateTime<Utc>) -> Result<u64, SqlxError>;
+}
+
+repo_pg_pool!(
+    #[derive(Clone)]
+    /// Структура взаимодействия с базой данной для работы с `login_attempts`.
+    pub(crate) struct LoginAttemptRepo;
+);
+
+#[async_trait]
+impl LoginAttemptRepository for LoginAttemptRepo {
+    async fn create(&self, attempt: &NewLoginAttempt) -> Result<(), SqlxError> {
+        sqlx::query(
+            r#"
+            INSERT INTO login_attempts (user_id, username, ip, succeeded)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(&attempt.user_id)
+        .bind(&attempt.username)
+        .bind(attempt.ip.map(|ip| ip.to_string()))
+        .bind(attempt.succeeded)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn count_ip_failures(
+        &self,
+        ip: &IpAddr,
+        since: DateTime<Utc>,
+    ) -> Result<(i64, Option<DateTime<Utc>>), SqlxError> {
+        let record = sqlx::query(
+            r#"
+            SELECT COUNT(*) AS failures, MIN(created_at) AS oldest
+            FROM login_attempts
+            WHERE ip = $1 AND NOT succeeded AND created_at >= $2
+            "#,
+        )
+        .bind(ip.to_string())
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok((record.get("failures"), record.get("oldest")))
+    }
+
+    async fn purge(&self, before: DateTime<Utc>) -> Result<u64, SqlxError> {
+        let result = sqlx::query("DELETE FROM login_attempts WHERE created_at < $1")
+            .bind(before)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
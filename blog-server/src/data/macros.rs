@@ -19,3 +19,26 @@ macro_rules! repo_pg_pool {
         }
     };
 }
+
+/// Создаёт структуру для слоя данных, хранящего записи в памяти процесса
+/// ([`MemoryStore`](crate::data::memory::MemoryStore)).
+#[macro_export]
+macro_rules! repo_memory {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident;
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            store: Arc<MemoryStore>,
+        }
+
+        impl $name {
+            pub(crate) fn new(store: &Arc<MemoryStore>) -> Self {
+                Self {
+                    store: Arc::clone(store),
+                }
+            }
+        }
+    };
+}
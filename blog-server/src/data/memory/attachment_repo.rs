@@ -0,0 +1,67 @@
+//! Репозиторий вложений в памяти.
+
+use crate::{
+    data::{
+        attachment_repo::AttachmentRepository,
+        memory::{foreign_key_violation, unique_violation, MemoryStore},
+    },
+    domain::{
+        attachment::{Attachment, NewAttachment},
+        types::StorageKey,
+    },
+    repo_memory,
+};
+use chrono::Utc;
+use sqlx::Error as SqlxError;
+use std::sync::Arc;
+use tonic::async_trait;
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение сведений о вложениях (`attachments`) в памяти.
+    pub(crate) struct MemoryAttachmentRepo;
+);
+
+#[async_trait]
+impl AttachmentRepository for MemoryAttachmentRepo {
+    async fn create(&self, attachment: &NewAttachment) -> Result<Attachment, SqlxError> {
+        let mut tables = self.store.tables();
+        if tables.users.get(&attachment.owner_id).is_none() {
+            return Err(foreign_key_violation("attachments_owner_id_fkey"));
+        }
+        if tables
+            .attachments
+            .values()
+            .any(|a| a.storage_key == attachment.storage_key)
+        {
+            return Err(unique_violation("attachments_storage_key_key"));
+        }
+
+        let (width, height) = attachment.dimensions.unzip();
+        let id = tables.attachments.next_id();
+        let created = Attachment {
+            id: id.clone(),
+            owner_id: attachment.owner_id.clone(),
+            storage_key: attachment.storage_key.clone(),
+            file_name: attachment.file_name.clone(),
+            content_type: attachment.media_type.content_type().to_string(),
+            size: attachment.size,
+            width,
+            height,
+            created_at: Utc::now(),
+        };
+        tables.attachments.insert(&id, created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_by_key(&self, key: &StorageKey) -> Result<Attachment, SqlxError> {
+        let tables = self.store.tables();
+        tables
+            .attachments
+            .values()
+            .find(|a| &a.storage_key == key)
+            .cloned()
+            .ok_or(SqlxError::RowNotFound)
+    }
+}
@@ -0,0 +1,74 @@
+//! Репозиторий журнала аудита в памяти.
+
+use crate::{
+    data::{
+        audit_repo::AuditRepository,
+        memory::{page, MemoryStore},
+    },
+    domain::audit::{AuditEvent, AuditFilter, NewAuditEvent},
+    repo_memory,
+};
+use chrono::Utc;
+use sqlx::Error as SqlxError;
+use std::{cmp::Reverse, sync::Arc};
+use tonic::async_trait;
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение журнала аудита (`audit_events`) в памяти.
+    pub(crate) struct MemoryAuditRepo;
+);
+
+#[async_trait]
+impl AuditRepository for MemoryAuditRepo {
+    async fn create(&self, event: &NewAuditEvent) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        let id = tables.audit_events.next_id();
+        let event = AuditEvent {
+            id: id.clone(),
+            actor_id: event.actor_id.clone(),
+            actor: event.actor.clone(),
+            action: event.action,
+            target: event.target.as_ref().map(ToString::to_string),
+            ip: event.context.ip.map(|ip| ip.to_string()),
+            user_agent: event.context.user_agent.clone(),
+            request_id: event.context.request_id.clone(),
+            before: event.before.clone(),
+            after: event.after.clone(),
+            created_at: Utc::now(),
+        };
+        tables.audit_events.insert(&id, event);
+
+        Ok(())
+    }
+
+    async fn list(&self, filter: &AuditFilter) -> Result<(Vec<AuditEvent>, i64), SqlxError> {
+        let tables = self.store.tables();
+        let mut events: Vec<&AuditEvent> = tables
+            .audit_events
+            .values()
+            .filter(|event| {
+                filter
+                    .actor
+                    .as_ref()
+                    .is_none_or(|actor| &event.actor == actor)
+            })
+            .filter(|event| filter.action.is_none_or(|action| event.action == action))
+            .filter(|event| {
+                filter
+                    .target
+                    .as_ref()
+                    .is_none_or(|target| event.target.as_ref() == Some(target))
+            })
+            .filter(|event| filter.since.is_none_or(|since| event.created_at >= since))
+            .filter(|event| filter.until.is_none_or(|until| event.created_at < until))
+            .collect();
+        events.sort_by_key(|event| Reverse((event.created_at, event.id.0)));
+        let total = events.len() as i64;
+
+        Ok((
+            page(events.into_iter().cloned(), filter.limit, filter.offset),
+            total,
+        ))
+    }
+}
@@ -0,0 +1,82 @@
+//! Репозиторий комментариев в памяти.
+
+use crate::{
+    data::{
+        comment_repo::CommentRepository,
+        memory::{foreign_key_violation, page, MemoryStore},
+    },
+    domain::{comment::Comment, types::DataId},
+    repo_memory,
+};
+use sqlx::Error as SqlxError;
+use std::sync::Arc;
+use tonic::async_trait;
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение комментариев (`comments`) в памяти.
+    pub(crate) struct MemoryCommentRepo;
+);
+
+#[async_trait]
+impl CommentRepository for MemoryCommentRepo {
+    async fn create(&self, comment: &Comment) -> Result<Comment, SqlxError> {
+        let mut tables = self.store.tables();
+        if tables.posts.get(&comment.post_id).is_none() {
+            return Err(foreign_key_violation("fk_comments_post_id"));
+        }
+        if tables.users.get(&comment.author_id).is_none() {
+            return Err(foreign_key_violation("fk_comments_author_id"));
+        }
+
+        let id = tables.comments.next_id();
+        let created = Comment::new(
+            Some(id.clone()),
+            comment.post_id.clone(),
+            comment.author_id.clone(),
+            comment.content.clone(),
+            Some(comment.created_at),
+        );
+        tables.comments.insert(&id, created.clone());
+
+        Ok(created)
+    }
+
+    async fn get(&self, comment_id: &DataId) -> Result<Comment, SqlxError> {
+        let tables = self.store.tables();
+        tables
+            .comments
+            .get(comment_id)
+            .cloned()
+            .ok_or(SqlxError::RowNotFound)
+    }
+
+    async fn list_by_post(
+        &self,
+        post_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Comment>, i64), SqlxError> {
+        let tables = self.store.tables();
+        let mut comments: Vec<&Comment> = tables
+            .comments
+            .values()
+            .filter(|c| &c.post_id == post_id)
+            .collect();
+        // Строки таблицы уже упорядочены по id.
+        comments.sort_by_key(|c| c.created_at);
+        let total = comments.len() as i64;
+
+        Ok((page(comments.into_iter().cloned(), limit, offset), total))
+    }
+
+    async fn delete(&self, comment_id: &DataId) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        tables
+            .comments
+            .rows
+            .remove(&comment_id.0)
+            .map(|_| ())
+            .ok_or(SqlxError::RowNotFound)
+    }
+}
@@ -0,0 +1,98 @@
+//! Заготовки данных для тестов сервисов на хранилище в памяти.
+
+use crate::{
+    application::{
+        audit_service::AuditService, blog_service::BlogService, follow_service::FollowService,
+        reaction_service::ReactionService, revision_service::RevisionService,
+        user_service::UserService,
+    },
+    data::Repositories,
+    domain::{
+        moderation::ModerationFilter,
+        post::{Post, PostStatus},
+        role::Role,
+        types::{DataId, Email, PostContent, PostTitle, Username},
+        user::{AuthenticatedUser, User},
+        verification::EmailPolicy,
+    },
+};
+use std::sync::Arc;
+
+/// Зарегистрировать пользователя `username` и вернуть его как
+/// аутентифицированного с ролью `role`.
+pub(crate) async fn user(repos: &Repositories, username: &str, role: Role) -> AuthenticatedUser {
+    let user = repos
+        .users
+        .create(&User::new(
+            None,
+            Username::try_from(username.to_string()).unwrap(),
+            Email::try_from(format!("{username}@example.com")).unwrap(),
+            "hash",
+            None,
+        ))
+        .await
+        .unwrap();
+
+    AuthenticatedUser {
+        id: user.id.unwrap(),
+        username: user.username,
+        role,
+    }
+}
+
+/// Создать публикацию автора `author_id` в статусе `status`.
+pub(crate) async fn post(repos: &Repositories, author_id: &DataId, status: PostStatus) -> Post {
+    let post = Post::new(
+        None,
+        PostTitle::try_from("Hello world".to_string()).unwrap(),
+        PostContent::try_from("Первая публикация".to_string()).unwrap(),
+        None,
+        author_id.clone(),
+        None,
+        None,
+    );
+    let publish_at = post.publish_at.filter(|_| status == PostStatus::Published);
+
+    repos
+        .posts
+        .create(&post.with_publication(status, publish_at))
+        .await
+        .unwrap()
+}
+
+/// Сервис публикаций над репозиториями `repos`: без правил модерации и без
+/// требования подтверждённого адреса электронной почты.
+pub(crate) fn blog_service(repos: &Repositories) -> BlogService {
+    BlogService::new(
+        repos.posts.clone(),
+        repos.users.clone(),
+        ModerationFilter::default(),
+        EmailPolicy::new(false),
+        Arc::new(AuditService::new(repos.audit.clone())),
+    )
+}
+
+/// Сервис профилей авторов над репозиториями `repos`.
+pub(crate) fn user_service(repos: &Repositories) -> UserService {
+    UserService::new(repos.users.clone(), repos.posts.clone())
+}
+
+/// Сервис подписок над репозиториями `repos`.
+pub(crate) fn follow_service(repos: &Repositories) -> FollowService {
+    FollowService::new(repos.follows.clone(), repos.users.clone())
+}
+
+/// Сервис реакций над репозиториями `repos`.
+pub(crate) fn reaction_service(repos: &Repositories) -> ReactionService {
+    ReactionService::new(repos.reactions.clone(), repos.posts.clone())
+}
+
+/// Сервис ревизий над репозиториями `repos`, без правил модерации.
+pub(crate) fn revision_service(repos: &Repositories) -> RevisionService {
+    RevisionService::new(
+        repos.revisions.clone(),
+        repos.posts.clone(),
+        ModerationFilter::default(),
+        Arc::new(AuditService::new(repos.audit.clone())),
+    )
+}
@@ -0,0 +1,115 @@
+//! Репозиторий подписок на авторов в памяти.
+
+use crate::{
+    data::{
+        follow_repo::FollowRepository,
+        memory::{check_violation, foreign_key_violation, page, MemoryStore, Tables},
+    },
+    domain::{follow::FollowUser, types::DataId},
+    repo_memory,
+};
+use chrono::{DateTime, Utc};
+use sqlx::Error as SqlxError;
+use std::sync::Arc;
+use tonic::async_trait;
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение подписок (`follows`) в памяти.
+    pub(crate) struct MemoryFollowRepo;
+);
+
+#[async_trait]
+impl FollowRepository for MemoryFollowRepo {
+    async fn follow(&self, follower_id: &DataId, followee_id: &DataId) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        if follower_id == followee_id {
+            return Err(check_violation("follows_check"));
+        }
+        if tables.users.get(follower_id).is_none() {
+            return Err(foreign_key_violation("follows_follower_id_fkey"));
+        }
+        if tables.users.get(followee_id).is_none() {
+            return Err(foreign_key_violation("follows_followee_id_fkey"));
+        }
+
+        let exists = tables.follows.iter().any(|(follower, followee, _)| {
+            *follower == follower_id.0 && *followee == followee_id.0
+        });
+        if !exists {
+            tables
+                .follows
+                .push((follower_id.0, followee_id.0, Utc::now()));
+        }
+
+        Ok(())
+    }
+
+    async fn unfollow(&self, follower_id: &DataId, followee_id: &DataId) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        tables.follows.retain(|(follower, followee, _)| {
+            *follower != follower_id.0 || *followee != followee_id.0
+        });
+
+        Ok(())
+    }
+
+    async fn list_followers(
+        &self,
+        user_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<FollowUser>, i64), SqlxError> {
+        let tables = self.store.tables();
+        let followers = tables
+            .follows
+            .iter()
+            .filter(|(_, followee, _)| *followee == user_id.0)
+            .map(|(follower, _, followed_at)| (*follower, *followed_at));
+
+        Ok(follow_users(&tables, followers, limit, offset))
+    }
+
+    async fn list_following(
+        &self,
+        user_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<FollowUser>, i64), SqlxError> {
+        let tables = self.store.tables();
+        let following = tables
+            .follows
+            .iter()
+            .filter(|(follower, _, _)| *follower == user_id.0)
+            .map(|(_, followee, followed_at)| (*followee, *followed_at));
+
+        Ok(follow_users(&tables, following, limit, offset))
+    }
+}
+
+/// Страница пользователей из пар «id пользователя, время подписки»
+/// в порядке `created_at DESC, username` и их общее количество.
+fn follow_users(
+    tables: &Tables,
+    follows: impl Iterator<Item = (i64, DateTime<Utc>)>,
+    limit: i32,
+    offset: i32,
+) -> (Vec<FollowUser>, i64) {
+    let mut users: Vec<FollowUser> = follows
+        .filter_map(|(user_id, followed_at)| {
+            let user = tables.users.get(&DataId(user_id))?;
+            Some(FollowUser {
+                username: user.username.clone(),
+                followed_at,
+            })
+        })
+        .collect();
+    users.sort_by(|a, b| {
+        b.followed_at
+            .cmp(&a.followed_at)
+            .then_with(|| a.username.as_ref().cmp(b.username.as_ref()))
+    });
+    let total = users.len() as i64;
+
+    (page(users, limit, offset), total)
+}
@@ -0,0 +1,84 @@
+//! Репозиторий журнала попыток входа в памяти.
+
+use crate::{
+    data::{
+        login_attempt_repo::LoginAttemptRepository,
+        memory::{foreign_key_violation, MemoryStore},
+    },
+    domain::{login::NewLoginAttempt, types::DataId},
+    repo_memory,
+};
+use chrono::{DateTime, Utc};
+use sqlx::Error as SqlxError;
+use std::{net::IpAddr, sync::Arc};
+use tonic::async_trait;
+
+/// Строка таблицы `login_attempts`.
+#[derive(Clone, Debug)]
+pub(super) struct LoginAttemptRow {
+    /// Id пользователя, если имя пользователя найдено.
+    pub(super) user_id: Option<DataId>,
+    /// IP-адрес клиента.
+    ip: Option<String>,
+    /// Успешна ли попытка.
+    succeeded: bool,
+    /// Время попытки.
+    created_at: DateTime<Utc>,
+}
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение журнала попыток входа (`login_attempts`) в памяти.
+    pub(crate) struct MemoryLoginAttemptRepo;
+);
+
+#[async_trait]
+impl LoginAttemptRepository for MemoryLoginAttemptRepo {
+    async fn create(&self, attempt: &NewLoginAttempt) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        if let Some(user_id) = &attempt.user_id
+            && tables.users.get(user_id).is_none()
+        {
+            return Err(foreign_key_violation("login_attempts_user_id_fkey"));
+        }
+
+        let id = tables.login_attempts.next_id();
+        let row = LoginAttemptRow {
+            user_id: attempt.user_id.clone(),
+            ip: attempt.ip.map(|ip| ip.to_string()),
+            succeeded: attempt.succeeded,
+            created_at: Utc::now(),
+        };
+        tables.login_attempts.insert(&id, row);
+
+        Ok(())
+    }
+
+    async fn count_ip_failures(
+        &self,
+        ip: &IpAddr,
+        since: DateTime<Utc>,
+    ) -> Result<(i64, Option<DateTime<Utc>>), SqlxError> {
+        let tables = self.store.tables();
+        let ip = ip.to_string();
+        let failures: Vec<DateTime<Utc>> = tables
+            .login_attempts
+            .values()
+            .filter(|row| row.ip.as_ref() == Some(&ip) && !row.succeeded && row.created_at >= since)
+            .map(|row| row.created_at)
+            .collect();
+
+        Ok((failures.len() as i64, failures.into_iter().min()))
+    }
+
+    async fn purge(&self, before: DateTime<Utc>) -> Result<u64, SqlxError> {
+        let mut tables = self.store.tables();
+        let count = tables.login_attempts.rows.len();
+        tables
+            .login_attempts
+            .rows
+            .retain(|_, row| row.created_at >= before);
+
+        Ok((count - tables.login_attempts.rows.len()) as u64)
+    }
+}
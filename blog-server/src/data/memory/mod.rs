@@ -0,0 +1,394 @@
+//! Хранение данных в памяти процесса.
+//!
+//! Репозитории модуля реализуют те же трейты, что и репозитории PostgreSQL,
+//! и воспроизводят ограничения схемы из миграций: уникальность, внешние
+//! ключи и каскадное удаление. Нарушение ограничения возвращается как
+//! [`SqlxError::Database`] с тем же именем ограничения, отсутствие записи —
+//! как [`SqlxError::RowNotFound`], поэтому сервисы обрабатывают ошибки
+//! одинаково для обоих хранилищ.
+//!
+//! Данные теряются при остановке сервера: хранилище предназначено для
+//! демонстраций и тестов.
+
+use crate::{
+    data::memory::{
+        login_attempt_repo::LoginAttemptRow, password_reset_repo::PasswordResetRow,
+        token_repo::RefreshTokenRow,
+    },
+    domain::{
+        attachment::Attachment,
+        audit::AuditEvent,
+        comment::Comment,
+        moderation::{ModerationRule, RuleAction, RuleKind},
+        post::Post,
+        reaction::{ReactionCount, ReactionKind},
+        revision::PostRevision,
+        role::Role,
+        types::{DataId, Email, Username},
+        user::User,
+    },
+    settings::{DELETED_USER_EMAIL, DELETED_USER_USERNAME},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{
+    error::{DatabaseError, ErrorKind},
+    Error as SqlxError,
+};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    error::Error as StdError,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+use thiserror::Error;
+
+pub(crate) mod attachment_repo;
+pub(crate) mod audit_repo;
+pub(crate) mod comment_repo;
+#[cfg(test)]
+pub(crate) mod fixtures;
+pub(crate) mod follow_repo;
+pub(crate) mod login_attempt_repo;
+pub(crate) mod moderation_repo;
+pub(crate) mod password_reset_repo;
+pub(crate) mod post_repo;
+pub(crate) mod reaction_repo;
+pub(crate) mod revision_repo;
+pub(crate) mod token_repo;
+pub(crate) mod user_repo;
+
+/// Правила модерации, добавляемые миграцией `create_moderation_rules`.
+const SEED_MODERATION_RULES: [(&str, RuleKind); 9] = [
+    ("дурак", RuleKind::Word),
+    ("глупец", RuleKind::Word),
+    ("фронтенд", RuleKind::Word),
+    ("legacy", RuleKind::Word),
+    ("рефакторинг", RuleKind::Word),
+    ("техдолг", RuleKind::Word),
+    ("политик", RuleKind::Word),
+    ("кому нужен ваш Rust", RuleKind::Phrase),
+    ("я не тестировал, но должно работать", RuleKind::Phrase),
+];
+
+/// Общее хранилище таблиц в памяти. Репозитории разделяют один экземпляр,
+/// как репозитории PostgreSQL — один пул соединений.
+#[derive(Debug)]
+pub(crate) struct MemoryStore {
+    /// Таблицы под общей блокировкой: каждая операция репозитория
+    /// выполняется целиком, как транзакция.
+    tables: Mutex<Tables>,
+}
+
+impl MemoryStore {
+    /// Создать хранилище с начальными данными миграций: служебной учётной
+    /// записью [`DELETED_USER_USERNAME`] и правилами модерации.
+    pub(crate) fn new() -> Self {
+        let mut tables = Tables::default();
+        let now = Utc::now();
+
+        let id = tables.users.next_id();
+        let deleted_user = User::new(
+            Some(id.clone()),
+            Username::reserved(DELETED_USER_USERNAME),
+            Email::reserved(DELETED_USER_EMAIL),
+            "!",
+            Some(now),
+        )
+        .with_access(Role::default(), Some(now));
+        tables.users.insert(&id, deleted_user);
+        tables.deleted_user = Some(id);
+
+        for (pattern, kind) in SEED_MODERATION_RULES {
+            let id = tables.moderation_rules.next_id();
+            let rule = ModerationRule {
+                id: id.clone(),
+                pattern: pattern.to_string(),
+                kind,
+                action: RuleAction::Reject,
+                created_at: now,
+                updated_at: None,
+            };
+            tables.moderation_rules.insert(&id, rule);
+        }
+
+        Self {
+            tables: Mutex::new(tables),
+        }
+    }
+
+    /// Захватить таблицы. Паника другого потока не портит данные: каждая
+    /// операция проверяет ограничения до изменения таблиц.
+    pub(super) fn tables(&self) -> MutexGuard<'_, Tables> {
+        self.tables.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Таблица с автоинкрементным id (аналог `BIGSERIAL`).
+#[derive(Debug)]
+pub(super) struct Table<T> {
+    /// Строки по id.
+    pub(super) rows: BTreeMap<i64, T>,
+    /// Последний выданный id. Id удалённых строк повторно не выдаются.
+    last_id: i64,
+}
+
+impl<T> Default for Table<T> {
+    fn default() -> Self {
+        Self {
+            rows: BTreeMap::new(),
+            last_id: 0,
+        }
+    }
+}
+
+impl<T> Table<T> {
+    /// Выдать очередной id.
+    pub(super) fn next_id(&mut self) -> DataId {
+        self.last_id += 1;
+        DataId(self.last_id)
+    }
+
+    /// Строка по id.
+    pub(super) fn get(&self, id: &DataId) -> Option<&T> {
+        self.rows.get(&id.0)
+    }
+
+    /// Изменяемая строка по id.
+    pub(super) fn get_mut(&mut self, id: &DataId) -> Option<&mut T> {
+        self.rows.get_mut(&id.0)
+    }
+
+    /// Сохранить строку с id `id`.
+    pub(super) fn insert(&mut self, id: &DataId, row: T) {
+        self.rows.insert(id.0, row);
+    }
+
+    /// Строки таблицы в порядке id.
+    pub(super) fn values(&self) -> impl Iterator<Item = &T> {
+        self.rows.values()
+    }
+}
+
+/// Таблицы базы данных блога.
+#[derive(Debug, Default)]
+pub(super) struct Tables {
+    /// `users`.
+    users: Table<User>,
+    /// Id служебной учётной записи [`DELETED_USER_USERNAME`] (флаг
+    /// `users.is_deleted_placeholder`).
+    deleted_user: Option<DataId>,
+    /// `posts`. Имя автора и реакции заполняются при чтении.
+    posts: Table<Post>,
+    /// `post_slugs`: все выданные адреса и id их публикаций.
+    post_slugs: HashMap<String, i64>,
+    /// `post_revisions`.
+    revisions: Vec<PostRevision>,
+    /// `comments`.
+    comments: Table<Comment>,
+    /// `post_reactions`: id публикации, id пользователя и вид реакции.
+    reactions: BTreeSet<(i64, i64, ReactionKind)>,
+    /// `follows`: id подписчика, id автора и время подписки.
+    follows: Vec<(i64, i64, DateTime<Utc>)>,
+    /// `attachments`.
+    attachments: Table<Attachment>,
+    /// `refresh_tokens`.
+    refresh_tokens: Table<RefreshTokenRow>,
+    /// `revoked_tokens`: jti отозванного токена доступа и время его
+    /// истечения.
+    revoked_tokens: HashMap<String, DateTime<Utc>>,
+    /// `login_attempts`.
+    login_attempts: Table<LoginAttemptRow>,
+    /// `moderation_rules`.
+    moderation_rules: Table<ModerationRule>,
+    /// `password_reset_tokens`.
+    password_reset_tokens: Table<PasswordResetRow>,
+    /// `audit_events`.
+    audit_events: Table<AuditEvent>,
+}
+
+impl Tables {
+    /// Id пользователя с именем `username`.
+    pub(super) fn user_id_by_name(&self, username: &str) -> Option<DataId> {
+        self.users
+            .values()
+            .find(|user| user.username.as_ref() == username)
+            .and_then(|user| user.id.clone())
+    }
+
+    /// Публикация в том виде, в каком её возвращает чтение из базы данных:
+    /// с именем автора, отсортированными тегами и счётчиками реакций.
+    pub(super) fn post_view(&self, post: &Post) -> Post {
+        let post_id = post.id.as_ref().map_or(0, |id| id.0);
+        let mut counts: BTreeMap<ReactionKind, i64> = BTreeMap::new();
+        for (_, _, kind) in self.reactions.iter().filter(|r| r.0 == post_id) {
+            *counts.entry(*kind).or_default() += 1;
+        }
+        let (kinds, counts) = counts.into_iter().unzip();
+
+        let mut view = post
+            .clone()
+            .with_reactions(ReactionCount::zip(kinds, counts));
+        view.author_username = self
+            .users
+            .get(&post.author_id)
+            .map(|user| user.username.clone());
+        view.tags.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+        view
+    }
+
+    /// Удалить публикацию вместе с зависимыми строками (`ON DELETE CASCADE`):
+    /// комментариями, адресами, ревизиями и реакциями.
+    pub(super) fn delete_post(&mut self, post_id: i64) {
+        self.posts.rows.remove(&post_id);
+        self.comments.rows.retain(|_, c| c.post_id.0 != post_id);
+        self.post_slugs.retain(|_, id| *id != post_id);
+        self.revisions.retain(|r| r.post_id.0 != post_id);
+        self.reactions.retain(|r| r.0 != post_id);
+    }
+
+    /// Удалить пользователя вместе с зависимыми строками.
+    ///
+    /// ## Errors
+    ///
+    /// [`SqlxError::RowNotFound`], если пользователя нет; нарушение
+    /// `fk_posts_author_id` или `fk_comments_author_id`
+    /// (`ON DELETE RESTRICT`), если у него остались публикации или
+    /// комментарии.
+    pub(super) fn delete_user(&mut self, user_id: &DataId) -> Result<(), SqlxError> {
+        if self.users.get(user_id).is_none() {
+            return Err(SqlxError::RowNotFound);
+        }
+        if self.posts.values().any(|p| &p.author_id == user_id) {
+            return Err(foreign_key_violation("fk_posts_author_id"));
+        }
+        if self.comments.values().any(|c| &c.author_id == user_id) {
+            return Err(foreign_key_violation("fk_comments_author_id"));
+        }
+
+        let id = user_id.0;
+        self.users.rows.remove(&id);
+        self.reactions.retain(|r| r.1 != id);
+        self.follows.retain(|f| f.0 != id && f.1 != id);
+        self.attachments.rows.retain(|_, a| a.owner_id.0 != id);
+        self.refresh_tokens.rows.retain(|_, t| t.user_id.0 != id);
+        self.login_attempts
+            .rows
+            .retain(|_, a| a.user_id.as_ref().is_none_or(|user_id| user_id.0 != id));
+        self.password_reset_tokens
+            .rows
+            .retain(|_, t| t.user_id.0 != id);
+        Ok(())
+    }
+}
+
+/// Вид нарушенного ограничения.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ViolationKind {
+    /// `UNIQUE` или первичный ключ.
+    Unique,
+    /// Внешний ключ.
+    ForeignKey,
+    /// `CHECK`.
+    Check,
+}
+
+/// Нарушение ограничения схемы. Повторяет ошибку PostgreSQL: имя
+/// ограничения, вид и код SQLSTATE.
+#[derive(Debug, Error)]
+#[error("{message}")]
+struct ConstraintViolation {
+    /// Вид ограничения.
+    kind: ViolationKind,
+    /// Имя ограничения из миграций.
+    constraint: &'static str,
+    /// Текст ошибки.
+    message: String,
+}
+
+impl DatabaseError for ConstraintViolation {
+    fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn code(&self) -> Option<Cow<'_, str>> {
+        let code = match self.kind {
+            ViolationKind::Unique => "23505",
+            ViolationKind::ForeignKey => "23503",
+            ViolationKind::Check => "23514",
+        };
+        Some(Cow::Borrowed(code))
+    }
+
+    fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn StdError + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn StdError + Send + Sync + 'static> {
+        self
+    }
+
+    fn constraint(&self) -> Option<&str> {
+        Some(self.constraint)
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self.kind {
+            ViolationKind::Unique => ErrorKind::UniqueViolation,
+            ViolationKind::ForeignKey => ErrorKind::ForeignKeyViolation,
+            ViolationKind::Check => ErrorKind::CheckViolation,
+        }
+    }
+}
+
+/// Ошибка нарушения ограничения уникальности `constraint`.
+pub(super) fn unique_violation(constraint: &'static str) -> SqlxError {
+    violation(
+        ViolationKind::Unique,
+        constraint,
+        format!("повторяющееся значение ключа нарушает ограничение уникальности \"{constraint}\""),
+    )
+}
+
+/// Ошибка нарушения внешнего ключа `constraint`.
+pub(super) fn foreign_key_violation(constraint: &'static str) -> SqlxError {
+    violation(
+        ViolationKind::ForeignKey,
+        constraint,
+        format!("операция нарушает ограничение внешнего ключа \"{constraint}\""),
+    )
+}
+
+/// Ошибка нарушения ограничения-проверки `constraint`.
+pub(super) fn check_violation(constraint: &'static str) -> SqlxError {
+    violation(
+        ViolationKind::Check,
+        constraint,
+        format!("строка нарушает ограничение-проверку \"{constraint}\""),
+    )
+}
+
+fn violation(kind: ViolationKind, constraint: &'static str, message: String) -> SqlxError {
+    SqlxError::Database(Box::new(ConstraintViolation {
+        kind,
+        constraint,
+        message,
+    }))
+}
+
+/// Страница строк с учётом `LIMIT` и `OFFSET`.
+pub(super) fn page<T>(rows: impl IntoIterator<Item = T>, limit: i32, offset: i32) -> Vec<T> {
+    rows.into_iter()
+        .skip(usize::try_from(offset).unwrap_or_default())
+        .take(usize::try_from(limit).unwrap_or_default())
+        .collect()
+}
@@ -0,0 +1,106 @@
+//! Репозиторий правил модерации в памяти.
+
+use crate::{
+    data::{
+        memory::{foreign_key_violation, unique_violation, MemoryStore, Tables},
+        moderation_repo::ModerationRepository,
+    },
+    domain::{
+        moderation::{ModerationRule, SaveModerationRule},
+        types::DataId,
+    },
+    repo_memory,
+};
+use chrono::Utc;
+use sqlx::Error as SqlxError;
+use std::sync::Arc;
+use tonic::async_trait;
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение правил модерации (`moderation_rules`) в памяти.
+    pub(crate) struct MemoryModerationRepo;
+);
+
+#[async_trait]
+impl ModerationRepository for MemoryModerationRepo {
+    async fn list(&self) -> Result<Vec<ModerationRule>, SqlxError> {
+        let tables = self.store.tables();
+
+        Ok(tables.moderation_rules.values().cloned().collect())
+    }
+
+    async fn create(
+        &self,
+        rule: &SaveModerationRule,
+        created_by: &DataId,
+    ) -> Result<ModerationRule, SqlxError> {
+        let mut tables = self.store.tables();
+        if tables.users.get(created_by).is_none() {
+            return Err(foreign_key_violation("moderation_rules_created_by_fkey"));
+        }
+        check_unique(&tables, rule, None)?;
+
+        let id = tables.moderation_rules.next_id();
+        let created = ModerationRule {
+            id: id.clone(),
+            pattern: rule.pattern.clone(),
+            kind: rule.kind,
+            action: rule.action,
+            created_at: Utc::now(),
+            updated_at: None,
+        };
+        tables.moderation_rules.insert(&id, created.clone());
+
+        Ok(created)
+    }
+
+    async fn update(
+        &self,
+        rule_id: &DataId,
+        rule: &SaveModerationRule,
+    ) -> Result<ModerationRule, SqlxError> {
+        let mut tables = self.store.tables();
+        if tables.moderation_rules.get(rule_id).is_none() {
+            return Err(SqlxError::RowNotFound);
+        }
+        check_unique(&tables, rule, Some(rule_id))?;
+
+        let stored = tables
+            .moderation_rules
+            .get_mut(rule_id)
+            .ok_or(SqlxError::RowNotFound)?;
+        stored.pattern = rule.pattern.clone();
+        stored.kind = rule.kind;
+        stored.action = rule.action;
+        stored.updated_at = Some(Utc::now());
+
+        Ok(stored.clone())
+    }
+
+    async fn delete(&self, rule_id: &DataId) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        tables
+            .moderation_rules
+            .rows
+            .remove(&rule_id.0)
+            .map(|_| ())
+            .ok_or(SqlxError::RowNotFound)
+    }
+}
+
+/// Поддерживающая функция: проверяет уникальность пары (вид, шаблон)
+/// среди правил, кроме `rule_id`.
+fn check_unique(
+    tables: &Tables,
+    rule: &SaveModerationRule,
+    rule_id: Option<&DataId>,
+) -> Result<(), SqlxError> {
+    let duplicate = tables.moderation_rules.values().any(|stored| {
+        stored.kind == rule.kind && stored.pattern == rule.pattern && Some(&stored.id) != rule_id
+    });
+    match duplicate {
+        true => Err(unique_violation("uq_moderation_rules_pattern")),
+        false => Ok(()),
+    }
+}
@@ -0,0 +1,83 @@
+//! Репозиторий токенов сброса пароля в памяти.
+
+use crate::{
+    data::{
+        memory::{foreign_key_violation, unique_violation, MemoryStore},
+        password_reset_repo::PasswordResetRepository,
+    },
+    domain::{password::NewPasswordResetToken, types::DataId},
+    repo_memory,
+};
+use chrono::{DateTime, Utc};
+use sqlx::Error as SqlxError;
+use std::sync::Arc;
+use tonic::async_trait;
+
+/// Строка таблицы `password_reset_tokens`.
+#[derive(Clone, Debug)]
+pub(super) struct PasswordResetRow {
+    /// Id владельца учётной записи.
+    pub(super) user_id: DataId,
+    /// Хеш секрета токена.
+    token_hash: String,
+    /// Время истечения.
+    expires_at: DateTime<Utc>,
+    /// Время использования.
+    used_at: Option<DateTime<Utc>>,
+}
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение токенов сброса пароля (`password_reset_tokens`) в памяти.
+    pub(crate) struct MemoryPasswordResetRepo;
+);
+
+#[async_trait]
+impl PasswordResetRepository for MemoryPasswordResetRepo {
+    async fn create(&self, token: &NewPasswordResetToken) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        if tables.users.get(&token.user_id).is_none() {
+            return Err(foreign_key_violation("password_reset_tokens_user_id_fkey"));
+        }
+        if tables
+            .password_reset_tokens
+            .values()
+            .any(|row| row.token_hash == token.token_hash)
+        {
+            return Err(unique_violation("password_reset_tokens_token_hash_key"));
+        }
+
+        let now = Utc::now();
+        for row in tables.password_reset_tokens.rows.values_mut() {
+            if row.user_id == token.user_id && row.used_at.is_none() {
+                row.used_at = Some(now);
+            }
+        }
+
+        let id = tables.password_reset_tokens.next_id();
+        let row = PasswordResetRow {
+            user_id: token.user_id.clone(),
+            token_hash: token.token_hash.clone(),
+            expires_at: token.expires_at,
+            used_at: None,
+        };
+        tables.password_reset_tokens.insert(&id, row);
+
+        Ok(())
+    }
+
+    async fn consume(&self, token_hash: &str, now: DateTime<Utc>) -> Result<DataId, SqlxError> {
+        let mut tables = self.store.tables();
+        let row = tables
+            .password_reset_tokens
+            .rows
+            .values_mut()
+            .find(|row| {
+                row.token_hash == token_hash && row.used_at.is_none() && row.expires_at > now
+            })
+            .ok_or(SqlxError::RowNotFound)?;
+        row.used_at = Some(now);
+
+        Ok(row.user_id.clone())
+    }
+}
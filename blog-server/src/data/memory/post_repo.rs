@@ -0,0 +1,677 @@
+//! Репозиторий постов в памяти.
+
+use crate::{
+    data::{
+        memory::{foreign_key_violation, page, revision_repo::save_revision, MemoryStore, Tables},
+        post_repo::PostRepository,
+    },
+    domain::{
+        post::{ListPostsParams, Post, PostSearchHit, PostStatus, TagStat},
+        types::{DataId, PostCursor, SearchQuery, Slug},
+    },
+    repo_memory,
+};
+use chrono::{DateTime, Utc};
+use sqlx::Error as SqlxError;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BTreeMap,
+    sync::Arc,
+};
+use tonic::async_trait;
+
+/// Количество слов во фрагменте с совпадениями.
+const SNIPPET_MAX_WORDS: usize = 35;
+
+/// Количество слов перед первым совпадением во фрагменте.
+const SNIPPET_CONTEXT_WORDS: usize = 5;
+
+/// Вес совпадения в заголовке.
+const TITLE_MATCH_RANK: f32 = 1.0;
+
+/// Вес каждого совпадения в содержании.
+const CONTENT_MATCH_RANK: f32 = 0.1;
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение публикаций (`posts`, `post_slugs`, `post_tags`) в памяти.
+    pub(crate) struct MemoryPostRepo;
+);
+
+#[async_trait]
+impl PostRepository for MemoryPostRepo {
+    async fn get(&self, post_id: &DataId) -> Result<Post, SqlxError> {
+        let tables = self.store.tables();
+        let post = active_post(&tables, post_id)?;
+
+        Ok(tables.post_view(post))
+    }
+
+    async fn get_by_slug(&self, slug: &Slug) -> Result<Post, SqlxError> {
+        let tables = self.store.tables();
+        let post_id = tables
+            .post_slugs
+            .get(slug.as_ref())
+            .ok_or(SqlxError::RowNotFound)?;
+        let post = active_post(&tables, &DataId(*post_id))?;
+
+        Ok(tables.post_view(post))
+    }
+
+    async fn create(&self, post: &Post) -> Result<Post, SqlxError> {
+        let mut tables = self.store.tables();
+        if tables.users.get(&post.author_id).is_none() {
+            return Err(foreign_key_violation("fk_posts_author_id"));
+        }
+
+        let slug = allocate_slug(&tables, &Slug::from_title(&post.title), None);
+        let post_id = tables.posts.next_id();
+        let created = Post::new(
+            Some(post_id.clone()),
+            post.title.clone(),
+            post.content.clone(),
+            Some(post.content_html.clone()),
+            post.author_id.clone(),
+            Some(post.created_at),
+            None,
+        )
+        .with_slug(Some(slug.clone()))
+        .with_tags(post.tags.clone())
+        .with_publication(post.status, post.publish_at);
+
+        tables.posts.insert(&post_id, created.clone());
+        save_slug(&mut tables, &post_id, &slug);
+        save_revision(&mut tables, &post_id, post);
+
+        Ok(tables.post_view(&created))
+    }
+
+    async fn list(
+        &self,
+        params: &ListPostsParams,
+    ) -> Result<(Vec<Post>, Option<PostCursor>), SqlxError> {
+        let tables = self.store.tables();
+        let mut found: Vec<&Post> = tables
+            .posts
+            .values()
+            .filter(|post| is_listed(&tables, post, params))
+            .filter(|post| match &params.after {
+                Some(cursor) => {
+                    (post.publish_at, post_key(post)) < (Some(cursor.publish_at), cursor.id.0)
+                }
+                None => true,
+            })
+            .collect();
+        found.sort_by(by_publication_desc);
+
+        // Одна лишняя запись показывает, есть ли следующая страница.
+        let mut posts: Vec<Post> = page(found, params.limit.saturating_add(1), params.offset)
+            .into_iter()
+            .map(|post| tables.post_view(post))
+            .collect();
+
+        let next_cursor = if posts.len() > params.limit as usize {
+            posts.truncate(params.limit as usize);
+            posts
+                .last()
+                .and_then(|post| Some(PostCursor::new(post.publish_at?, post.id.clone()?)))
+        } else {
+            None
+        };
+
+        Ok((posts, next_cursor))
+    }
+
+    async fn count(&self, params: &ListPostsParams) -> Result<i64, SqlxError> {
+        let tables = self.store.tables();
+        let count = tables
+            .posts
+            .values()
+            .filter(|post| is_listed(&tables, post, params))
+            .count();
+
+        Ok(count as i64)
+    }
+
+    async fn list_by_author(
+        &self,
+        author_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Post>, i64), SqlxError> {
+        let tables = self.store.tables();
+
+        Ok(select(
+            &tables,
+            |post| &post.author_id == author_id && is_published(post),
+            by_publication_desc,
+            limit,
+            offset,
+        ))
+    }
+
+    async fn list_unpublished(
+        &self,
+        author_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Post>, i64), SqlxError> {
+        let tables = self.store.tables();
+
+        Ok(select(
+            &tables,
+            |post| {
+                &post.author_id == author_id
+                    && post.status != PostStatus::Published
+                    && post.deleted_at.is_none()
+            },
+            |a, b| (b.created_at, post_key(b)).cmp(&(a.created_at, post_key(a))),
+            limit,
+            offset,
+        ))
+    }
+
+    async fn list_pending(&self, limit: i32, offset: i32) -> Result<(Vec<Post>, i64), SqlxError> {
+        let tables = self.store.tables();
+
+        Ok(select(
+            &tables,
+            |post| post.status == PostStatus::Pending && post.deleted_at.is_none(),
+            |a, b| {
+                let a_key = (a.updated_at.unwrap_or(a.created_at), post_key(a));
+                a_key.cmp(&(b.updated_at.unwrap_or(b.created_at), post_key(b)))
+            },
+            limit,
+            offset,
+        ))
+    }
+
+    async fn list_by_author_after(
+        &self,
+        author_id: &DataId,
+        after: Option<&DataId>,
+        limit: i32,
+    ) -> Result<Vec<Post>, SqlxError> {
+        let tables = self.store.tables();
+        let after = after.map_or(0, |id| id.0);
+
+        Ok(tables
+            .posts
+            .rows
+            .range(after + 1..)
+            .map(|(_, post)| post)
+            .filter(|post| &post.author_id == author_id)
+            .take(limit.max(0) as usize)
+            .map(|post| tables.post_view(post))
+            .collect())
+    }
+
+    async fn set_publication(
+        &self,
+        post_id: &DataId,
+        status: PostStatus,
+        publish_at: Option<DateTime<Utc>>,
+    ) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        let post = active_post_mut(&mut tables, post_id)?;
+        post.status = status;
+        post.publish_at = publish_at;
+
+        Ok(())
+    }
+
+    async fn publish_due(&self) -> Result<u64, SqlxError> {
+        let mut tables = self.store.tables();
+        let now = Utc::now();
+        let mut published = 0;
+        for post in tables.posts.rows.values_mut() {
+            if post.status == PostStatus::Scheduled
+                && post.publish_at.is_some_and(|publish_at| publish_at <= now)
+                && post.deleted_at.is_none()
+            {
+                post.status = PostStatus::Published;
+                published += 1;
+            }
+        }
+
+        Ok(published)
+    }
+
+    async fn update(&self, post: &Post) -> Result<Post, SqlxError> {
+        let post_id = post.id.clone().ok_or(SqlxError::RowNotFound)?;
+        let mut tables = self.store.tables();
+        active_post(&tables, &post_id)?;
+
+        let base = Slug::from_title(&post.title);
+        let slug = match &post.slug {
+            Some(slug) if slug.is_derived_from(&base) => slug.clone(),
+            _ => allocate_slug(&tables, &base, Some(&post_id)),
+        };
+
+        let stored = active_post_mut(&mut tables, &post_id)?;
+        stored.title = post.title.clone();
+        stored.content = post.content.clone();
+        stored.content_html = post.content_html.clone();
+        stored.updated_at = post.updated_at;
+        stored.slug = Some(slug.clone());
+        stored.status = post.status;
+        stored.publish_at = post.publish_at;
+        let updated = stored.clone().with_tags(post.tags.clone());
+        tables.posts.insert(&post_id, updated.clone());
+
+        save_slug(&mut tables, &post_id, &slug);
+        save_revision(&mut tables, &post_id, post);
+
+        Ok(tables.post_view(&updated))
+    }
+
+    async fn delete(&self, post_id: &DataId) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        active_post_mut(&mut tables, post_id)?.deleted_at = Some(Utc::now());
+
+        Ok(())
+    }
+
+    async fn get_deleted(&self, post_id: &DataId) -> Result<Post, SqlxError> {
+        let tables = self.store.tables();
+        let post = tables
+            .posts
+            .get(post_id)
+            .filter(|post| post.deleted_at.is_some())
+            .ok_or(SqlxError::RowNotFound)?;
+
+        Ok(tables.post_view(post))
+    }
+
+    async fn list_deleted(
+        &self,
+        author_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Post>, i64), SqlxError> {
+        let tables = self.store.tables();
+
+        Ok(select(
+            &tables,
+            |post| &post.author_id == author_id && post.deleted_at.is_some(),
+            |a, b| (b.deleted_at, post_key(b)).cmp(&(a.deleted_at, post_key(a))),
+            limit,
+            offset,
+        ))
+    }
+
+    async fn restore(&self, post_id: &DataId) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        let post = tables
+            .posts
+            .get_mut(post_id)
+            .filter(|post| post.deleted_at.is_some())
+            .ok_or(SqlxError::RowNotFound)?;
+        post.deleted_at = None;
+
+        Ok(())
+    }
+
+    async fn purge_deleted(&self, before: DateTime<Utc>) -> Result<u64, SqlxError> {
+        let mut tables = self.store.tables();
+        let expired: Vec<i64> = tables
+            .posts
+            .rows
+            .iter()
+            .filter(|(_, post)| {
+                post.deleted_at
+                    .is_some_and(|deleted_at| deleted_at < before)
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        for post_id in &expired {
+            tables.delete_post(*post_id);
+        }
+
+        Ok(expired.len() as u64)
+    }
+
+    async fn fill_content_html(&self, _limit: i32) -> Result<u64, SqlxError> {
+        // HTML отрисовывается при создании записи и хранится всегда.
+        Ok(0)
+    }
+
+    async fn get_author_id(&self, post_id: &DataId) -> Result<DataId, SqlxError> {
+        let tables = self.store.tables();
+
+        Ok(active_post(&tables, post_id)?.author_id.clone())
+    }
+
+    async fn list_tags(&self) -> Result<Vec<TagStat>, SqlxError> {
+        let tables = self.store.tables();
+        let mut counts = BTreeMap::new();
+        for tag in tables
+            .posts
+            .values()
+            .filter(|post| is_published(post))
+            .flat_map(|post| &post.tags)
+        {
+            counts
+                .entry(tag.as_ref())
+                .or_insert_with(|| TagStat {
+                    name: tag.clone(),
+                    posts_count: 0,
+                })
+                .posts_count += 1;
+        }
+
+        // Порядок BTreeMap уже по имени; сортировка устойчива.
+        let mut tags: Vec<TagStat> = counts.into_values().collect();
+        tags.sort_by_key(|tag| Reverse(tag.posts_count));
+
+        Ok(tags)
+    }
+
+    /// Поиск по публикациям без словарей PostgreSQL: слова запроса ищутся
+    /// в заголовке и содержании без учёта регистра. Синтаксис запроса тот же
+    /// (кавычки, `or` и `-`), совпадения в заголовке весомее совпадений
+    /// в содержании.
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<PostSearchHit>, i64), SqlxError> {
+        let tables = self.store.tables();
+        let terms = SearchTerms::parse(query.as_ref());
+
+        let mut found: Vec<(&Post, f32)> = tables
+            .posts
+            .values()
+            .filter(|post| is_published(post))
+            .filter_map(|post| Some((post, terms.rank(post)?)))
+            .collect();
+        found.sort_by(|(a, a_rank), (b, b_rank)| {
+            b_rank
+                .total_cmp(a_rank)
+                .then_with(|| b.publish_at.cmp(&a.publish_at))
+        });
+
+        let total = found.len() as i64;
+        let hits = page(found, limit, offset)
+            .into_iter()
+            .map(|(post, rank)| PostSearchHit {
+                post: tables.post_view(post),
+                rank,
+                snippet: terms.snippet(post.content.as_ref()),
+            })
+            .collect();
+
+        Ok((hits, total))
+    }
+}
+
+/// Разобранный поисковый запрос в синтаксисе `websearch_to_tsquery`.
+#[derive(Debug)]
+struct SearchTerms {
+    /// Группы альтернатив (`a or b`): совпасть должна каждая группа.
+    required: Vec<Vec<String>>,
+    /// Исключённые слова и фразы (`-слово`).
+    excluded: Vec<String>,
+}
+
+impl SearchTerms {
+    /// Разобрать запрос: слова, фразы в кавычках, `or` и `-`.
+    fn parse(query: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut current = String::new();
+        let mut quoted = false;
+        for c in query.to_lowercase().chars() {
+            match c {
+                '"' => {
+                    quoted = !quoted;
+                    tokens.push(std::mem::take(&mut current));
+                }
+                c if c.is_whitespace() && !quoted => tokens.push(std::mem::take(&mut current)),
+                c => current.push(c),
+            }
+        }
+        tokens.push(current);
+
+        let mut terms = Self {
+            required: Vec::new(),
+            excluded: Vec::new(),
+        };
+        let mut alternative = false;
+        for token in tokens {
+            let (negated, token) = match token.strip_prefix('-') {
+                Some(rest) => (true, rest),
+                None => (false, token.as_str()),
+            };
+            let term = token.trim_matches(|c: char| !c.is_alphanumeric());
+            if term.is_empty() {
+                continue;
+            }
+            if term == "or" && !negated {
+                alternative = !terms.required.is_empty();
+                continue;
+            }
+
+            let term = term.to_string();
+            match (negated, alternative, terms.required.last_mut()) {
+                (true, ..) => terms.excluded.push(term),
+                (false, true, Some(group)) => group.push(term),
+                _ => terms.required.push(vec![term]),
+            }
+            alternative = false;
+        }
+        terms
+    }
+
+    /// Релевантность публикации или `None`, если она не подходит под запрос.
+    fn rank(&self, post: &Post) -> Option<f32> {
+        let title = post.title.as_ref().to_lowercase();
+        let content = post.content.as_ref().to_lowercase();
+        let contains = |term: &String| title.contains(term) || content.contains(term);
+
+        if self.required.is_empty()
+            || self.excluded.iter().any(contains)
+            || !self.required.iter().all(|group| group.iter().any(contains))
+        {
+            return None;
+        }
+
+        let rank = self
+            .required
+            .iter()
+            .flatten()
+            .map(|term| {
+                let in_title = if title.contains(term) {
+                    TITLE_MATCH_RANK
+                } else {
+                    0.0
+                };
+                in_title + CONTENT_MATCH_RANK * content.matches(term.as_str()).count() as f32
+            })
+            .sum();
+        Some(rank)
+    }
+
+    /// Фрагмент содержания вокруг первого совпадения; совпавшие слова
+    /// обрамлены `<mark>` и `</mark>`.
+    fn snippet(&self, content: &str) -> String {
+        let words: Vec<&str> = content.split_whitespace().collect();
+        let is_match = |word: &str| {
+            let word = word.to_lowercase();
+            self.required
+                .iter()
+                .flatten()
+                .flat_map(|term| term.split_whitespace())
+                .any(|term| word.contains(term))
+        };
+
+        let first = words.iter().position(|word| is_match(word)).unwrap_or(0);
+        words
+            .iter()
+            .skip(first.saturating_sub(SNIPPET_CONTEXT_WORDS))
+            .take(SNIPPET_MAX_WORDS)
+            .map(|word| match is_match(word) {
+                true => format!("<mark>{word}</mark>"),
+                false => word.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Неудалённая публикация по id.
+fn active_post<'a>(tables: &'a Tables, post_id: &DataId) -> Result<&'a Post, SqlxError> {
+    tables
+        .posts
+        .get(post_id)
+        .filter(|post| post.deleted_at.is_none())
+        .ok_or(SqlxError::RowNotFound)
+}
+
+/// Изменяемая неудалённая публикация по id.
+fn active_post_mut<'a>(
+    tables: &'a mut Tables,
+    post_id: &DataId,
+) -> Result<&'a mut Post, SqlxError> {
+    tables
+        .posts
+        .get_mut(post_id)
+        .filter(|post| post.deleted_at.is_none())
+        .ok_or(SqlxError::RowNotFound)
+}
+
+/// Опубликована и не удалена.
+fn is_published(post: &Post) -> bool {
+    post.status == PostStatus::Published && post.deleted_at.is_none()
+}
+
+/// Подходит ли опубликованная запись под фильтры ленты `params` (тег,
+/// подписки, автор); пагинация не учитывается.
+fn is_listed(tables: &Tables, post: &Post, params: &ListPostsParams) -> bool {
+    is_published(post)
+        && params
+            .tag
+            .as_ref()
+            .is_none_or(|tag| post.tags.contains(tag))
+        && params.followed_by.as_ref().is_none_or(|follower| {
+            tables.follows.iter().any(|(follower_id, followee_id, _)| {
+                *follower_id == follower.0 && *followee_id == post.author_id.0
+            })
+        })
+        && params
+            .author_id
+            .as_ref()
+            .is_none_or(|author_id| &post.author_id == author_id)
+}
+
+/// Id публикации для сортировки.
+fn post_key(post: &Post) -> i64 {
+    post.id.as_ref().map_or(0, |id| id.0)
+}
+
+/// Порядок `publish_at DESC, id DESC`.
+fn by_publication_desc(a: &&Post, b: &&Post) -> Ordering {
+    (b.publish_at, post_key(b)).cmp(&(a.publish_at, post_key(a)))
+}
+
+/// Публикации, подходящие под `filter`, в порядке `order`: страница
+/// и общее количество.
+fn select(
+    tables: &Tables,
+    filter: impl Fn(&Post) -> bool,
+    order: impl FnMut(&&Post, &&Post) -> Ordering,
+    limit: i32,
+    offset: i32,
+) -> (Vec<Post>, i64) {
+    let mut posts: Vec<&Post> = tables.posts.values().filter(|post| filter(post)).collect();
+    posts.sort_by(order);
+    let total = posts.len() as i64;
+
+    let posts = page(posts, limit, offset)
+        .into_iter()
+        .map(|post| tables.post_view(post))
+        .collect();
+    (posts, total)
+}
+
+/// Подобрать свободный адрес на основе `base`: сам `base` или первый
+/// свободный из `base-2`, `base-3`, ... Адрес, ранее принадлежавший той же
+/// публикации (`post_id`), считается свободным.
+fn allocate_slug(tables: &Tables, base: &Slug, post_id: Option<&DataId>) -> Slug {
+    let mut candidate = base.clone();
+    let mut n = 1;
+    loop {
+        match tables.post_slugs.get(candidate.as_ref()) {
+            None => return candidate,
+            Some(owner) if post_id.is_some_and(|id| id.0 == *owner) => return candidate,
+            Some(_) => {
+                n += 1;
+                candidate = base.with_suffix(n);
+            }
+        }
+    }
+}
+
+/// Закрепить адрес за публикацией. Ранее выданные адреса сохраняются.
+fn save_slug(tables: &mut Tables, post_id: &DataId, slug: &Slug) {
+    tables
+        .post_slugs
+        .entry(slug.to_string())
+        .or_insert(post_id.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::user_repo::MemoryUserRepo, user_repo::UserRepository},
+        domain::{
+            types::{Email, PostContent, PostTitle, Username},
+            user::User,
+        },
+    };
+
+    fn new_post(author_id: &DataId) -> Post {
+        Post::new(
+            None,
+            PostTitle::try_from("Hello world".to_string()).unwrap(),
+            PostContent::try_from("Первая публикация".to_string()).unwrap(),
+            None,
+            author_id.clone(),
+            None,
+            None,
+        )
+    }
+
+    #[actix_web::test]
+    async fn create_allocates_unique_slugs_and_checks_author() {
+        let store = Arc::new(MemoryStore::new());
+        let repo = MemoryPostRepo::new(&store);
+        let author = MemoryUserRepo::new(&store)
+            .create(&User::new(
+                None,
+                Username::try_from("alice".to_string()).unwrap(),
+                Email::try_from("alice@example.com".to_string()).unwrap(),
+                "hash",
+                None,
+            ))
+            .await
+            .unwrap();
+        let author_id = author.id.unwrap();
+
+        let first = repo.create(&new_post(&author_id)).await.unwrap();
+        let second = repo.create(&new_post(&author_id)).await.unwrap();
+        assert_eq!(first.slug.unwrap().as_ref(), "hello-world");
+        assert_eq!(second.slug.clone().unwrap().as_ref(), "hello-world-2");
+        assert_eq!(
+            repo.get_by_slug(&second.slug.unwrap()).await.unwrap().id,
+            second.id
+        );
+
+        let orphan = repo.create(&new_post(&DataId(999))).await.unwrap_err();
+        assert_eq!(
+            orphan.as_database_error().and_then(|err| err.constraint()),
+            Some("fk_posts_author_id")
+        );
+    }
+}
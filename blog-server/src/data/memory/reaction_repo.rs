@@ -0,0 +1,86 @@
+//! Репозиторий реакций на публикации в памяти.
+
+use crate::{
+    data::{
+        memory::{foreign_key_violation, MemoryStore},
+        reaction_repo::ReactionRepository,
+    },
+    domain::{
+        reaction::{ReactionCount, ReactionKind},
+        types::DataId,
+    },
+    repo_memory,
+};
+use sqlx::Error as SqlxError;
+use std::{collections::BTreeMap, sync::Arc};
+use tonic::async_trait;
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение реакций (`post_reactions`) в памяти.
+    pub(crate) struct MemoryReactionRepo;
+);
+
+#[async_trait]
+impl ReactionRepository for MemoryReactionRepo {
+    async fn add(
+        &self,
+        post_id: &DataId,
+        user_id: &DataId,
+        kind: ReactionKind,
+    ) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        if tables.posts.get(post_id).is_none() {
+            return Err(foreign_key_violation("post_reactions_post_id_fkey"));
+        }
+        if tables.users.get(user_id).is_none() {
+            return Err(foreign_key_violation("post_reactions_user_id_fkey"));
+        }
+        tables.reactions.insert((post_id.0, user_id.0, kind));
+
+        Ok(())
+    }
+
+    async fn remove(
+        &self,
+        post_id: &DataId,
+        user_id: &DataId,
+        kind: ReactionKind,
+    ) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        tables.reactions.remove(&(post_id.0, user_id.0, kind));
+
+        Ok(())
+    }
+
+    async fn counts(&self, post_id: &DataId) -> Result<Vec<ReactionCount>, SqlxError> {
+        let tables = self.store.tables();
+        let mut counts: BTreeMap<ReactionKind, i64> = BTreeMap::new();
+        for (_, _, kind) in tables.reactions.iter().filter(|r| r.0 == post_id.0) {
+            *counts.entry(*kind).or_default() += 1;
+        }
+
+        Ok(counts
+            .into_iter()
+            .map(|(kind, count)| ReactionCount { kind, count })
+            .collect())
+    }
+
+    async fn list_by_user(
+        &self,
+        post_ids: &[DataId],
+        user_id: &DataId,
+    ) -> Result<Vec<(DataId, ReactionKind)>, SqlxError> {
+        let tables = self.store.tables();
+
+        // Порядок множества — (post_id, user_id, kind), как `ORDER BY post_id, kind`.
+        Ok(tables
+            .reactions
+            .iter()
+            .filter(|(post_id, reactor_id, _)| {
+                *reactor_id == user_id.0 && post_ids.iter().any(|id| id.0 == *post_id)
+            })
+            .map(|(post_id, _, kind)| (DataId(*post_id), *kind))
+            .collect())
+    }
+}
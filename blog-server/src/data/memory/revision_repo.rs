@@ -0,0 +1,76 @@
+//! Репозиторий ревизий публикаций в памяти.
+
+use crate::{
+    data::{
+        memory::{page, MemoryStore, Tables},
+        revision_repo::RevisionRepository,
+    },
+    domain::{post::Post, revision::PostRevision, types::DataId},
+    repo_memory,
+};
+use sqlx::Error as SqlxError;
+use std::{cmp::Reverse, sync::Arc};
+use tonic::async_trait;
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение ревизий публикаций (`post_revisions`) в памяти.
+    pub(crate) struct MemoryRevisionRepo;
+);
+
+#[async_trait]
+impl RevisionRepository for MemoryRevisionRepo {
+    async fn get(&self, post_id: &DataId, revision: i32) -> Result<PostRevision, SqlxError> {
+        let tables = self.store.tables();
+        tables
+            .revisions
+            .iter()
+            .find(|r| &r.post_id == post_id && r.revision == revision)
+            .cloned()
+            .ok_or(SqlxError::RowNotFound)
+    }
+
+    async fn list_by_post(
+        &self,
+        post_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<PostRevision>, i64), SqlxError> {
+        let tables = self.store.tables();
+        let mut revisions: Vec<&PostRevision> = tables
+            .revisions
+            .iter()
+            .filter(|r| &r.post_id == post_id)
+            .collect();
+        revisions.sort_by_key(|r| Reverse(r.revision));
+        let total = revisions.len() as i64;
+
+        Ok((page(revisions.into_iter().cloned(), limit, offset), total))
+    }
+}
+
+/// Сохранить текущее состояние публикации следующей ревизией. Вызывается
+/// после записи публикации под той же блокировкой таблиц. Если заголовок,
+/// содержание и теги совпадают с последней ревизией, новая не создаётся.
+pub(super) fn save_revision(tables: &mut Tables, post_id: &DataId, post: &Post) {
+    let last = tables
+        .revisions
+        .iter()
+        .filter(|r| &r.post_id == post_id)
+        .max_by_key(|r| r.revision);
+    if last
+        .is_some_and(|r| r.title == post.title && r.content == post.content && r.tags == post.tags)
+    {
+        return;
+    }
+    let revision = last.map_or(0, |r| r.revision) + 1;
+
+    tables.revisions.push(PostRevision::new(
+        post_id.clone(),
+        revision,
+        post.title.clone(),
+        post.content.clone(),
+        post.tags.clone(),
+        post.updated_at.unwrap_or(post.created_at),
+    ));
+}
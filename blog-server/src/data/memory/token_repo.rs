@@ -0,0 +1,191 @@
+//! Репозиторий токенов сессий в памяти.
+
+use crate::{
+    data::{
+        memory::{foreign_key_violation, unique_violation, MemoryStore, Tables},
+        token_repo::TokenRepository,
+    },
+    domain::{
+        token::{AccessTokenId, NewRefreshToken, RefreshToken},
+        types::DataId,
+    },
+    repo_memory,
+};
+use chrono::{DateTime, Utc};
+use sqlx::Error as SqlxError;
+use std::sync::Arc;
+use tonic::async_trait;
+
+/// Строка таблицы `refresh_tokens`.
+#[derive(Clone, Debug)]
+pub(super) struct RefreshTokenRow {
+    /// Id владельца.
+    pub(super) user_id: DataId,
+    /// Хеш секрета токена.
+    token_hash: String,
+    /// Id сессии.
+    family_id: String,
+    /// Токен доступа, выданный вместе с токеном обновления.
+    access: AccessTokenId,
+    /// Время истечения.
+    expires_at: DateTime<Utc>,
+    /// Время отзыва.
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение токенов (`refresh_tokens`, `revoked_tokens`) в памяти.
+    pub(crate) struct MemoryTokenRepo;
+);
+
+#[async_trait]
+impl TokenRepository for MemoryTokenRepo {
+    async fn create(&self, token: &NewRefreshToken) -> Result<(), SqlxError> {
+        insert_refresh_token(&mut self.store.tables(), token)
+    }
+
+    async fn get_by_hash(&self, token_hash: &str) -> Result<RefreshToken, SqlxError> {
+        let tables = self.store.tables();
+        let (id, row) = tables
+            .refresh_tokens
+            .rows
+            .iter()
+            .find(|(_, row)| row.token_hash == token_hash)
+            .ok_or(SqlxError::RowNotFound)?;
+        let user = tables
+            .users
+            .get(&row.user_id)
+            .ok_or(SqlxError::RowNotFound)?;
+
+        Ok(RefreshToken {
+            id: DataId(*id),
+            user_id: row.user_id.clone(),
+            username: user.username.clone(),
+            role: user.role,
+            account_disabled: user.disabled_at.is_some(),
+            family_id: row.family_id.clone(),
+            access: row.access.clone(),
+            expires_at: row.expires_at,
+            revoked_at: row.revoked_at,
+        })
+    }
+
+    async fn rotate(&self, old_id: &DataId, token: &NewRefreshToken) -> Result<bool, SqlxError> {
+        let mut tables = self.store.tables();
+        match tables.refresh_tokens.get(old_id) {
+            Some(row) if row.revoked_at.is_none() => {}
+            _ => return Ok(false),
+        }
+
+        // Как и в транзакции PostgreSQL, старый токен остаётся действующим,
+        // если новый записать не удалось.
+        insert_refresh_token(&mut tables, token)?;
+        if let Some(row) = tables.refresh_tokens.get_mut(old_id) {
+            row.revoked_at = Some(Utc::now());
+        }
+
+        Ok(true)
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> Result<Vec<AccessTokenId>, SqlxError> {
+        Ok(revoke_where(&mut self.store.tables(), |row| {
+            row.family_id == family_id
+        }))
+    }
+
+    async fn revoke_user(&self, user_id: &DataId) -> Result<Vec<AccessTokenId>, SqlxError> {
+        Ok(revoke_where(&mut self.store.tables(), |row| {
+            &row.user_id == user_id
+        }))
+    }
+
+    async fn list_user_access(&self, user_id: &DataId) -> Result<Vec<AccessTokenId>, SqlxError> {
+        let tables = self.store.tables();
+        let now = Utc::now();
+
+        Ok(tables
+            .refresh_tokens
+            .values()
+            .filter(|row| &row.user_id == user_id && row.access.expires_at > now)
+            .map(|row| row.access.clone())
+            .collect())
+    }
+
+    async fn revoke_access(&self, tokens: &[AccessTokenId]) -> Result<(), SqlxError> {
+        let mut tables = self.store.tables();
+        for token in tokens {
+            tables
+                .revoked_tokens
+                .entry(token.jti.clone())
+                .or_insert(token.expires_at);
+        }
+
+        Ok(())
+    }
+
+    async fn list_revoked_access(&self) -> Result<Vec<AccessTokenId>, SqlxError> {
+        let mut tables = self.store.tables();
+        let now = Utc::now();
+        tables
+            .revoked_tokens
+            .retain(|_, expires_at| *expires_at > now);
+
+        Ok(tables
+            .revoked_tokens
+            .iter()
+            .map(|(jti, expires_at)| AccessTokenId {
+                jti: jti.clone(),
+                expires_at: *expires_at,
+            })
+            .collect())
+    }
+}
+
+/// Поддерживающая функция: записывает токен обновления, проверяя
+/// ограничения таблицы.
+fn insert_refresh_token(tables: &mut Tables, token: &NewRefreshToken) -> Result<(), SqlxError> {
+    if tables.users.get(&token.user_id).is_none() {
+        return Err(foreign_key_violation("refresh_tokens_user_id_fkey"));
+    }
+    if tables
+        .refresh_tokens
+        .values()
+        .any(|row| row.token_hash == token.token_hash)
+    {
+        return Err(unique_violation("refresh_tokens_token_hash_key"));
+    }
+
+    let id = tables.refresh_tokens.next_id();
+    let row = RefreshTokenRow {
+        user_id: token.user_id.clone(),
+        token_hash: token.token_hash.clone(),
+        family_id: token.family_id.clone(),
+        access: token.access.clone(),
+        expires_at: token.expires_at,
+        revoked_at: None,
+    };
+    tables.refresh_tokens.insert(&id, row);
+
+    Ok(())
+}
+
+/// Поддерживающая функция: отзывает токены обновления, подходящие под
+/// `predicate`, и возвращает ещё не истёкшие токены доступа из них.
+fn revoke_where(
+    tables: &mut Tables,
+    predicate: impl Fn(&RefreshTokenRow) -> bool,
+) -> Vec<AccessTokenId> {
+    let now = Utc::now();
+    tables
+        .refresh_tokens
+        .rows
+        .values_mut()
+        .filter(|row| predicate(row))
+        .map(|row| {
+            row.revoked_at = row.revoked_at.or(Some(now));
+            row.access.clone()
+        })
+        .filter(|token| token.expires_at > now)
+        .collect()
+}
@@ -0,0 +1,391 @@
+//! Репозиторий пользователей в памяти.
+
+use crate::{
+    data::{
+        memory::{unique_violation, MemoryStore, Tables},
+        user_repo::UserRepository,
+    },
+    domain::{
+        account::DeletionMode,
+        post::PostStatus,
+        role::Role,
+        types::{DataId, Email, StorageKey, Username},
+        user::{UpdateProfile, User, UserProfile},
+    },
+    repo_memory,
+};
+use chrono::{DateTime, Utc};
+use sqlx::Error as SqlxError;
+use std::sync::Arc;
+use tonic::async_trait;
+
+repo_memory!(
+    #[derive(Clone, Debug)]
+    /// Хранение пользователей (`users`) в памяти.
+    pub(crate) struct MemoryUserRepo;
+);
+
+#[async_trait]
+impl UserRepository for MemoryUserRepo {
+    async fn create(&self, user: &User) -> Result<User, SqlxError> {
+        let mut tables = self.store.tables();
+        if tables.user_id_by_name(user.username.as_ref()).is_some() {
+            return Err(unique_violation("users_username_key"));
+        }
+        if tables.users.values().any(|u| u.email == user.email) {
+            return Err(unique_violation("users_email_key"));
+        }
+
+        let id = tables.users.next_id();
+        let created = User::new(
+            Some(id.clone()),
+            user.username.clone(),
+            user.email.clone(),
+            &user.password_hash,
+            Some(user.created_at),
+        );
+        tables.users.insert(&id, created.clone());
+
+        Ok(created)
+    }
+
+    async fn get_by_id(&self, user_id: &DataId) -> Result<User, SqlxError> {
+        let tables = self.store.tables();
+        tables
+            .users
+            .get(user_id)
+            .cloned()
+            .ok_or(SqlxError::RowNotFound)
+    }
+
+    async fn get_by_username(&self, username: &Username) -> Result<User, SqlxError> {
+        let tables = self.store.tables();
+        find_user(&tables, |user| &user.username == username).cloned()
+    }
+
+    async fn get_by_email(&self, email: &Email) -> Result<User, SqlxError> {
+        let tables = self.store.tables();
+        find_user(&tables, |user| &user.email == email).cloned()
+    }
+
+    async fn get_profile(&self, username: &Username) -> Result<UserProfile, SqlxError> {
+        let tables = self.store.tables();
+        let user = find_user(&tables, |user| &user.username == username)?;
+        let posts_count = tables
+            .posts
+            .values()
+            .filter(|post| Some(&post.author_id) == user.id.as_ref())
+            .filter(|post| post.status == PostStatus::Published && post.deleted_at.is_none())
+            .count();
+
+        Ok(UserProfile {
+            username: user.username.clone(),
+            display_name: user.display_name.clone(),
+            bio: user.bio.clone(),
+            avatar_url: user.avatar_url.clone(),
+            joined_at: user.created_at,
+            posts_count: posts_count as i64,
+        })
+    }
+
+    async fn list(&self, limit: i32, offset: i32) -> Result<(Vec<User>, i64), SqlxError> {
+        let tables = self.store.tables();
+        let users = super::page(tables.users.values().cloned(), limit, offset);
+
+        Ok((users, tables.users.rows.len() as i64))
+    }
+
+    async fn set_role(&self, username: &Username, role: Role) -> Result<User, SqlxError> {
+        self.update_by_name(username, |user| user.role = role)
+    }
+
+    async fn set_disabled(&self, username: &Username, disabled: bool) -> Result<User, SqlxError> {
+        let now = Utc::now();
+        self.update_by_name(username, |user| {
+            user.disabled_at = match disabled {
+                true => Some(user.disabled_at.unwrap_or(now)),
+                false => None,
+            };
+        })
+    }
+
+    async fn record_login_failure(
+        &self,
+        user_id: &DataId,
+        now: DateTime<Utc>,
+        max_failures: i32,
+        locked_until: DateTime<Utc>,
+    ) -> Result<User, SqlxError> {
+        self.update_by_id(user_id, |user| {
+            if user.failed_logins + 1 >= max_failures {
+                user.failed_logins = 0;
+                user.last_failed_login_at = None;
+                user.locked_until = Some(locked_until);
+            } else {
+                user.failed_logins += 1;
+                user.last_failed_login_at = Some(now);
+            }
+        })
+    }
+
+    async fn reset_login_failures(&self, username: &Username) -> Result<User, SqlxError> {
+        self.update_by_name(username, reset_login_state)
+    }
+
+    async fn set_password(&self, user_id: &DataId, password_hash: &str) -> Result<User, SqlxError> {
+        self.update_by_id(user_id, |user| {
+            user.password_hash = password_hash.to_string();
+            reset_login_state(user);
+        })
+    }
+
+    async fn mark_email_verified(
+        &self,
+        user_id: &DataId,
+        email: &Email,
+        now: DateTime<Utc>,
+    ) -> Result<User, SqlxError> {
+        let mut tables = self.store.tables();
+        let user = tables
+            .users
+            .get_mut(user_id)
+            .filter(|user| &user.email == email)
+            .ok_or(SqlxError::RowNotFound)?;
+        user.email_verified_at = user.email_verified_at.or(Some(now));
+
+        Ok(user.clone())
+    }
+
+    async fn update_profile(
+        &self,
+        user_id: &DataId,
+        update: &UpdateProfile,
+    ) -> Result<User, SqlxError> {
+        let mut tables = self.store.tables();
+        if tables.users.get(user_id).is_none() {
+            return Err(SqlxError::RowNotFound);
+        }
+        if let Some(email) = &update.email
+            && tables
+                .users
+                .values()
+                .any(|user| &user.email == email && user.id.as_ref() != Some(user_id))
+        {
+            return Err(unique_violation("users_email_key"));
+        }
+
+        let user = tables
+            .users
+            .get_mut(user_id)
+            .ok_or(SqlxError::RowNotFound)?;
+        if let Some(display_name) = &update.display_name {
+            user.display_name = display_name.as_ref().trim().to_string();
+        }
+        if let Some(bio) = &update.bio {
+            user.bio = bio.as_ref().trim().to_string();
+        }
+        if let Some(avatar_url) = &update.avatar_url {
+            user.avatar_url = avatar_url.to_string();
+        }
+        if let Some(email) = &update.email
+            && email != &user.email
+        {
+            user.email = email.clone();
+            user.email_verified_at = None;
+        }
+
+        Ok(user.clone())
+    }
+
+    async fn delete_account(
+        &self,
+        user_id: &DataId,
+        mode: DeletionMode,
+    ) -> Result<Vec<StorageKey>, SqlxError> {
+        let mut tables = self.store.tables();
+        if tables.users.get(user_id).is_none() {
+            return Err(SqlxError::RowNotFound);
+        }
+
+        let removed_files = match mode {
+            DeletionMode::Anonymize => {
+                let heir_id = tables.deleted_user.clone().ok_or(SqlxError::RowNotFound)?;
+                for post in tables.posts.rows.values_mut() {
+                    if &post.author_id == user_id {
+                        post.author_id = heir_id.clone();
+                    }
+                }
+                for comment in tables.comments.rows.values_mut() {
+                    if &comment.author_id == user_id {
+                        comment.author_id = heir_id.clone();
+                    }
+                }
+                for attachment in tables.attachments.rows.values_mut() {
+                    if &attachment.owner_id == user_id {
+                        attachment.owner_id = heir_id.clone();
+                    }
+                }
+                Vec::new()
+            }
+            DeletionMode::Delete => {
+                tables.comments.rows.retain(|_, c| &c.author_id != user_id);
+                let post_ids: Vec<i64> = tables
+                    .posts
+                    .rows
+                    .iter()
+                    .filter(|(_, post)| &post.author_id == user_id)
+                    .map(|(id, _)| *id)
+                    .collect();
+                for post_id in post_ids {
+                    tables.delete_post(post_id);
+                }
+                tables
+                    .attachments
+                    .values()
+                    .filter(|attachment| &attachment.owner_id == user_id)
+                    .map(|attachment| attachment.storage_key.clone())
+                    .collect()
+            }
+        };
+
+        tables.delete_user(user_id)?;
+
+        Ok(removed_files)
+    }
+}
+
+impl MemoryUserRepo {
+    /// Изменить пользователя `user_id` функцией `change`.
+    fn update_by_id(
+        &self,
+        user_id: &DataId,
+        change: impl FnOnce(&mut User),
+    ) -> Result<User, SqlxError> {
+        let mut tables = self.store.tables();
+        let user = tables
+            .users
+            .get_mut(user_id)
+            .ok_or(SqlxError::RowNotFound)?;
+        change(user);
+
+        Ok(user.clone())
+    }
+
+    /// Изменить пользователя с именем `username` функцией `change`.
+    fn update_by_name(
+        &self,
+        username: &Username,
+        change: impl FnOnce(&mut User),
+    ) -> Result<User, SqlxError> {
+        let mut tables = self.store.tables();
+        let user_id = tables
+            .user_id_by_name(username.as_ref())
+            .ok_or(SqlxError::RowNotFound)?;
+        let user = tables
+            .users
+            .get_mut(&user_id)
+            .ok_or(SqlxError::RowNotFound)?;
+        change(user);
+
+        Ok(user.clone())
+    }
+}
+
+/// Пользователь, удовлетворяющий условию `predicate`.
+fn find_user(tables: &Tables, predicate: impl Fn(&User) -> bool) -> Result<&User, SqlxError> {
+    tables
+        .users
+        .values()
+        .find(|user| predicate(user))
+        .ok_or(SqlxError::RowNotFound)
+}
+
+/// Сбросить счётчик неудачных попыток входа и снять блокировку входа.
+fn reset_login_state(user: &mut User) {
+    user.failed_logins = 0;
+    user.last_failed_login_at = None;
+    user.locked_until = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::fixtures, Repositories},
+        errors::{DomainError, RepoErrorMap, SqlxResultExt},
+        settings::DELETED_USER_USERNAME,
+    };
+
+    fn new_user(username: &str, email: &str) -> User {
+        User::new(
+            None,
+            Username::try_from(username.to_string()).unwrap(),
+            Email::try_from(email.to_string()).unwrap(),
+            "hash",
+            None,
+        )
+    }
+
+    fn registration_errors() -> RepoErrorMap {
+        RepoErrorMap {
+            not_found: DomainError::UserNotFound,
+            unique_violations: Some(vec![
+                ("users_username_key", DomainError::UserAlreadyExists),
+                ("users_email_key", DomainError::EmailAlreadyExists),
+            ]),
+        }
+    }
+
+    #[actix_web::test]
+    async fn create_reports_unique_violations() {
+        let repo = MemoryUserRepo::new(&Arc::new(MemoryStore::new()));
+        repo.create(&new_user("alice", "alice@example.com"))
+            .await
+            .unwrap();
+
+        let same_name = repo.create(&new_user("alice", "other@example.com")).await;
+        assert!(matches!(
+            same_name.map_repo_err(registration_errors()),
+            Err(DomainError::UserAlreadyExists)
+        ));
+
+        let same_email = repo.create(&new_user("bob", "alice@example.com")).await;
+        assert!(matches!(
+            same_email.map_repo_err(registration_errors()),
+            Err(DomainError::EmailAlreadyExists)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn missing_user_is_row_not_found() {
+        let repo = MemoryUserRepo::new(&Arc::new(MemoryStore::new()));
+        let username = Username::try_from("nobody".to_string()).unwrap();
+
+        assert!(matches!(
+            repo.get_by_username(&username).await,
+            Err(SqlxError::RowNotFound)
+        ));
+        assert!(matches!(
+            repo.set_role(&username, Role::Admin).await,
+            Err(SqlxError::RowNotFound)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn anonymized_posts_go_to_deleted_user() {
+        let repos = Repositories::memory();
+        let author = fixtures::user(&repos, "alice", Role::default()).await;
+        let post = fixtures::post(&repos, &author.id, PostStatus::Published).await;
+
+        repos
+            .users
+            .delete_account(&author.id, DeletionMode::Anonymize)
+            .await
+            .unwrap();
+
+        let post = repos.posts.get(post.id.as_ref().unwrap()).await.unwrap();
+        let heir = repos.users.get_by_id(&post.author_id).await.unwrap();
+        assert_eq!(heir.username.as_ref(), DELETED_USER_USERNAME);
+        assert!(heir.is_disabled());
+    }
+}
@@ -1,4 +1,111 @@
 //! Репозитории обработки данных.
 mod macros;
+pub(crate) mod attachment_repo;
+pub(crate) mod audit_repo;
+pub(crate) mod comment_repo;
+pub(crate) mod follow_repo;
+pub(crate) mod login_attempt_repo;
+pub(crate) mod memory;
+pub(crate) mod moderation_repo;
+pub(crate) mod password_reset_repo;
 pub(crate) mod post_repo;
+pub(crate) mod reaction_repo;
+pub(crate) mod revision_repo;
+pub(crate) mod token_repo;
 pub(crate) mod user_repo;
+
+use crate::data::{
+    attachment_repo::{AttachmentRepo, AttachmentRepository},
+    audit_repo::{AuditRepo, AuditRepository},
+    comment_repo::{CommentRepo, CommentRepository},
+    follow_repo::{FollowRepo, FollowRepository},
+    login_attempt_repo::{LoginAttemptRepo, LoginAttemptRepository},
+    memory::{
+        attachment_repo::MemoryAttachmentRepo, audit_repo::MemoryAuditRepo,
+        comment_repo::MemoryCommentRepo, follow_repo::MemoryFollowRepo,
+        login_attempt_repo::MemoryLoginAttemptRepo, moderation_repo::MemoryModerationRepo,
+        password_reset_repo::MemoryPasswordResetRepo, post_repo::MemoryPostRepo,
+        reaction_repo::MemoryReactionRepo, revision_repo::MemoryRevisionRepo,
+        token_repo::MemoryTokenRepo, user_repo::MemoryUserRepo, MemoryStore,
+    },
+    moderation_repo::{ModerationRepo, ModerationRepository},
+    password_reset_repo::{PasswordResetRepo, PasswordResetRepository},
+    post_repo::{PostRepo, PostRepository},
+    reaction_repo::{ReactionRepo, ReactionRepository},
+    revision_repo::{RevisionRepo, RevisionRepository},
+    token_repo::{TokenRepo, TokenRepository},
+    user_repo::{UserRepo, UserRepository},
+};
+use sqlx::PgPool;
+use std::sync::Arc;
+
+/// Набор репозиториев одного хранилища. Сервисы получают репозитории через
+/// трейты и не зависят от того, где хранятся данные.
+#[derive(Clone)]
+pub(crate) struct Repositories {
+    /// Пользователи.
+    pub(crate) users: Arc<dyn UserRepository>,
+    /// Публикации.
+    pub(crate) posts: Arc<dyn PostRepository>,
+    /// Комментарии.
+    pub(crate) comments: Arc<dyn CommentRepository>,
+    /// Ревизии публикаций.
+    pub(crate) revisions: Arc<dyn RevisionRepository>,
+    /// Реакции на публикации.
+    pub(crate) reactions: Arc<dyn ReactionRepository>,
+    /// Подписки на авторов.
+    pub(crate) follows: Arc<dyn FollowRepository>,
+    /// Вложения.
+    pub(crate) attachments: Arc<dyn AttachmentRepository>,
+    /// Токены сессий.
+    pub(crate) tokens: Arc<dyn TokenRepository>,
+    /// Правила модерации.
+    pub(crate) moderation: Arc<dyn ModerationRepository>,
+    /// Журнал попыток входа.
+    pub(crate) login_attempts: Arc<dyn LoginAttemptRepository>,
+    /// Токены сброса пароля.
+    pub(crate) password_resets: Arc<dyn PasswordResetRepository>,
+    /// Журнал аудита.
+    pub(crate) audit: Arc<dyn AuditRepository>,
+}
+
+impl Repositories {
+    /// Репозитории базы данных PostgreSQL.
+    pub(crate) fn postgres(pool: &PgPool) -> Self {
+        Self {
+            users: Arc::new(UserRepo::new(pool)),
+            posts: Arc::new(PostRepo::new(pool)),
+            comments: Arc::new(CommentRepo::new(pool)),
+            revisions: Arc::new(RevisionRepo::new(pool)),
+            reactions: Arc::new(ReactionRepo::new(pool)),
+            follows: Arc::new(FollowRepo::new(pool)),
+            attachments: Arc::new(AttachmentRepo::new(pool)),
+            tokens: Arc::new(TokenRepo::new(pool)),
+            moderation: Arc::new(ModerationRepo::new(pool)),
+            login_attempts: Arc::new(LoginAttemptRepo::new(pool)),
+            password_resets: Arc::new(PasswordResetRepo::new(pool)),
+            audit: Arc::new(AuditRepo::new(pool)),
+        }
+    }
+
+    /// Репозитории в памяти процесса над общим новым хранилищем
+    /// [`MemoryStore`]. Данные теряются при остановке сервера.
+    pub(crate) fn memory() -> Self {
+        let store = Arc::new(MemoryStore::new());
+
+        Self {
+            users: Arc::new(MemoryUserRepo::new(&store)),
+            posts: Arc::new(MemoryPostRepo::new(&store)),
+            comments: Arc::new(MemoryCommentRepo::new(&store)),
+            revisions: Arc::new(MemoryRevisionRepo::new(&store)),
+            reactions: Arc::new(MemoryReactionRepo::new(&store)),
+            follows: Arc::new(MemoryFollowRepo::new(&store)),
+            attachments: Arc::new(MemoryAttachmentRepo::new(&store)),
+            tokens: Arc::new(MemoryTokenRepo::new(&store)),
+            moderation: Arc::new(MemoryModerationRepo::new(&store)),
+            login_attempts: Arc::new(MemoryLoginAttemptRepo::new(&store)),
+            password_resets: Arc::new(MemoryPasswordResetRepo::new(&store)),
+            audit: Arc::new(MemoryAuditRepo::new(&store)),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Репозиторий правил модерации.
+
+use crate::{
+    domain::{
+        moderation::{ModerationRule, SaveModerationRule},
+        types::DataId,
+    },
+    repo_pg_pool,
+};
+use sqlx::{postgres::PgRow, Error as SqlxError, PgPool, Row};
+use tonic::async_trait;
+
+#[async_trait]
+pub(crate) trait ModerationRepository: Send + Sync {
+    /// Все правила модерации, от ранее созданных.
+    async fn list(&self) -> Result<Vec<ModerationRule>, SqlxError>;
+
+    /// Создать правило модерации.
+    async fn create(
+        &self,
+        rule: &SaveModerationRule,
+        created_by: &DataId,
+    ) -> Result<ModerationRule, SqlxError>;
+
+    /// Заменить шаблон, вид и действие существующего правила.
+    async fn update(
+        &self,
+        rule_id: &DataId,
+        rule: &SaveModerationRule,
+    ) -> Result<ModerationRule, SqlxError>;
+
+    /// Удалить правило модерации.
+    async fn delete(&self, rule_id: &DataId) -> Result<(), SqlxError>;
+}
+
+repo_pg_pool!(
+    #[derive(Clone)]
+    /// Структура взаимодействия с базой данной для работы с `moderation_rules`.
+    pub(crate) struct ModerationRepo;
+);
+
+#[async_trait]
+impl ModerationRepository for ModerationRepo {
+    async fn list(&self) -> Result<Vec<ModerationRule>, SqlxError> {
+        let results = sqlx::query(
+            r#"
+            SELECT id, pattern, kind, action, created_at, updated_at
+            FROM moderation_rules
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results.iter().map(make_rule_by_row).collect())
+    }
+
+    async fn create(
+        &self,
+        rule: &SaveModerationRule,
+        created_by: &DataId,
+    ) -> Result<ModerationRule, SqlxError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO moderation_rules (pattern, kind, action, created_by)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, pattern, kind, action, created_at, updated_at
+            "#,
+        )
+        .bind(&rule.pattern)
+        .bind(rule.kind)
+        .bind(rule.action)
+        .bind(created_by)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_rule_by_row(&result))
+    }
+
+    async fn update(
+        &self,
+        rule_id: &DataId,
+        rule: &SaveModerationRule,
+    ) -> Result<ModerationRule, SqlxError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE moderation_rules
+            SET pattern = $1, kind = $2, action = $3, updated_at = NOW()
+            WHERE id = $4
+            RETURNING id, pattern, kind, action, created_at, updated_at
+            "#,
+        )
+        .bind(&rule.pattern)
+        .bind(rule.kind)
+        .bind(rule.action)
+        .bind(rule_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_rule_by_row(&result))
+    }
+
+    async fn delete(&self, rule_id: &DataId) -> Result<(), SqlxError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM moderation_rules WHERE id = $1
+            "#,
+        )
+        .bind(rule_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(SqlxError::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+/// Поддерживающая функция: создаёт [`ModerationRule`] на основе записи из
+/// базы данных, обёрнутой в [`PgRow`].
+fn make_rule_by_row(record: &PgRow) -> ModerationRule {
+    ModerationRule {
+        id: record.get("id"),
+        pattern: record.get("pattern"),
+        kind: record.get("kind"),
+        action: record.get("action"),
+        created_at: record.get("created_at"),
+        updated_at: record.get("updated_at"),
+    }
+}
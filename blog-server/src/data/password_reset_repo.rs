@@ -0,0 +1,77 @@
+//! Репозиторий токенов сброса пароля.
+
+use crate::{
+    domain::{password::NewPasswordResetToken, types::DataId},
+    repo_pg_pool,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{Error as SqlxError, PgPool};
+use tonic::async_trait;
+
+#[async_trait]
+pub(crate) trait PasswordResetRepository: Send + Sync {
+    /// Записать новый токен сброса пароля. Прежние неиспользованные токены
+    /// пользователя становятся недействительными.
+    async fn create(&self, token: &NewPasswordResetToken) -> Result<(), SqlxError>;
+
+    /// Использовать токен с хешем `token_hash`: отметить его использованным,
+    /// если он не использован и не истёк к моменту `now`.
+    ///
+    /// ## Returns
+    ///
+    /// Id владельца токена или [`SqlxError::RowNotFound`], если токен
+    /// недействителен.
+    async fn consume(&self, token_hash: &str, now: DateTime<Utc>) -> Result<DataId, SqlxError>;
+}
+
+repo_pg_pool!(
+    #[derive(Clone)]
+    /// Структура взаимодействия с базой данной для работы
+    /// с `password_reset_tokens`.
+    pub(crate) struct PasswordResetRepo;
+);
+
+#[async_trait]
+impl PasswordResetRepository for PasswordResetRepo {
+    async fn create(&self, token: &NewPasswordResetToken) -> Result<(), SqlxError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE password_reset_tokens SET used_at = NOW()
+            WHERE user_id = $1 AND used_at IS NULL
+            "#,
+        )
+        .bind(&token.user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO password_reset_tokens (user_id, token_hash, expires_at)
+            VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(&token.user_id)
+        .bind(&token.token_hash)
+        .bind(token.expires_at)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await
+    }
+
+    async fn consume(&self, token_hash: &str, now: DateTime<Utc>) -> Result<DataId, SqlxError> {
+        sqlx::query_scalar(
+            r#"
+            UPDATE password_reset_tokens SET used_at = $2
+            WHERE token_hash = $1 AND used_at IS NULL AND expires_at > $2
+            RETURNING user_id
+            "#,
+        )
+        .bind(token_hash)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await
+    }
+}
@@ -1,10 +1,17 @@
 //! Репозиторий постов.
 
 use crate::{
-    domain::{post::Post, types::DataId},
+    domain::{
+        markdown::render_html,
+        post::{ListPostsParams, Post, PostSearchHit, PostStatus, TagStat},
+        reaction::ReactionCount,
+        types::{DataId, PostCursor, SearchQuery, Slug, Tag},
+    },
+    data::revision_repo::save_revision,
     repo_pg_pool,
 };
-use sqlx::{postgres::PgRow, Error as SqlxError, PgPool, Row};
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgRow, Error as SqlxError, PgPool, Postgres, Row, Transaction};
 use tonic::async_trait;
 
 #[async_trait]
@@ -12,32 +19,186 @@ pub(crate) trait PostRepository: Send + Sync {
     /// Получить публикацию по её id.
     async fn get(&self, post_id: &DataId) -> Result<Post, SqlxError>;
 
-    /// Создать публикацию (пост).
+    /// Получить неудалённую публикацию по любому из её адресов — текущему
+    /// или прежнему.
+    async fn get_by_slug(&self, slug: &Slug) -> Result<Post, SqlxError>;
+
+    /// Создать публикацию (пост). Исходная версия сохраняется первой
+    /// ревизией, адрес формируется по заголовку.
     async fn create(&self, post: &Post) -> Result<Post, SqlxError>;
 
-    /// Предоставить список публикаций, сделанных всеми авторами за всё время.
+    /// Предоставить список опубликованных записей всех авторов, от новых
+    /// к старым (по времени публикации).
     ///
     /// ## Args
     ///
-    /// - `limit` — количество записей, должно быть больше 1
-    /// - `offset` — отступ от первой записи в извлечённом списке
-    /// 
+    /// - `params.limit` — количество записей, должно быть больше 1
+    /// - `params.offset` — отступ от первой записи в извлечённом списке
+    /// - `params.tag` — фильтр по тегу (опционально)
+    /// - `params.after` — курсор, после которого продолжается выгрузка
+    ///   (опционально)
+    ///
+    /// ## Returns
+    ///
+    /// Перечень публикаций и курсор следующей страницы, если она есть.
+    async fn list(
+        &self,
+        params: &ListPostsParams,
+    ) -> Result<(Vec<Post>, Option<PostCursor>), SqlxError>;
+
+    /// Общее количество опубликованных записей с учётом фильтров `params`
+    /// (тег, автор, подписки); пагинация не учитывается.
+    async fn count(&self, params: &ListPostsParams) -> Result<i64, SqlxError>;
+
+    /// Опубликованные записи автора, от новых к старым (по времени
+    /// публикации).
+    ///
+    /// ## Returns
+    ///
+    /// Перечень публикаций и их общее количество у автора.
+    async fn list_by_author(
+        &self,
+        author_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Post>, i64), SqlxError>;
+
+    /// Черновики и отложенные публикации автора, от новых к старым.
+    ///
+    /// ## Returns
+    ///
+    /// Перечень публикаций и их общее количество у автора.
+    async fn list_unpublished(
+        &self,
+        author_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Post>, i64), SqlxError>;
+
+    /// Публикации всех авторов, ожидающие проверки модератором, от давно
+    /// ожидающих.
+    ///
     /// ## Returns
-    /// 
-    /// Перечень публикаций, с учётом заказа, и общее число публикаций в базе.
-    async fn list(&self, limit: i32, offset: i32) -> Result<(Vec<Post>, i64), SqlxError>;
+    ///
+    /// Перечень публикаций и их общее количество в очереди.
+    async fn list_pending(&self, limit: i32, offset: i32) -> Result<(Vec<Post>, i64), SqlxError>;
+
+    /// Страница публикаций автора в любом статусе, включая корзину, от
+    /// старых к новым: не более `limit` записей с id больше `after`.
+    async fn list_by_author_after(
+        &self,
+        author_id: &DataId,
+        after: Option<&DataId>,
+        limit: i32,
+    ) -> Result<Vec<Post>, SqlxError>;
 
-    /// Обновление существующей публикации (поста).
-    async fn update(&self, post: &Post) -> Result<(), SqlxError>;
+    /// Сохранить статус и время публикации записи.
+    async fn set_publication(
+        &self,
+        post_id: &DataId,
+        status: PostStatus,
+        publish_at: Option<DateTime<Utc>>,
+    ) -> Result<(), SqlxError>;
 
-    /// Удаление публикации (поста).
+    /// Перевести в опубликованные все отложенные записи, время публикации
+    /// которых наступило.
+    ///
+    /// ## Returns
+    ///
+    /// Количество опубликованных записей.
+    async fn publish_due(&self) -> Result<u64, SqlxError>;
+
+    /// Обновление существующей публикации (поста), включая её статус
+    /// и время публикации. Новая версия сохраняется очередной ревизией.
+    ///
+    /// Если текущий адрес не соответствует новому заголовку, публикация
+    /// получает новый адрес; прежний остаётся за ней для перенаправления.
+    async fn update(&self, post: &Post) -> Result<Post, SqlxError>;
+
+    /// Удаление публикации (поста): запись перемещается в корзину и
+    /// перестаёт выдаваться остальными методами репозитория.
     async fn delete(&self, post_id: &DataId) -> Result<(), SqlxError>;
 
+    /// Получить публикацию из корзины по её id.
+    async fn get_deleted(&self, post_id: &DataId) -> Result<Post, SqlxError>;
+
+    /// Публикации автора в корзине, от недавно удалённых.
+    ///
+    /// ## Returns
+    ///
+    /// Перечень публикаций и их общее количество в корзине автора.
+    async fn list_deleted(
+        &self,
+        author_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Post>, i64), SqlxError>;
+
+    /// Вернуть публикацию из корзины.
+    async fn restore(&self, post_id: &DataId) -> Result<(), SqlxError>;
+
+    /// Окончательно удалить публикации, перемещённые в корзину раньше
+    /// `before`.
+    ///
+    /// ## Returns
+    ///
+    /// Количество удалённых записей.
+    async fn purge_deleted(&self, before: DateTime<Utc>) -> Result<u64, SqlxError>;
+
+    /// Отрисовать и сохранить HTML для не более чем `limit` публикаций,
+    /// созданных до появления поля `content_html`.
+    ///
+    /// ## Returns
+    ///
+    /// Количество дополненных записей; ноль — заполнять больше нечего.
+    async fn fill_content_html(&self, limit: i32) -> Result<u64, SqlxError>;
+
     /// Возвращает ID автора публикации, если пост с предоставленным id
     /// существует.
     async fn get_author_id(&self, post_id: &DataId) -> Result<DataId, SqlxError>;
+
+    /// Перечень используемых тегов с количеством публикаций у каждого.
+    async fn list_tags(&self) -> Result<Vec<TagStat>, SqlxError>;
+
+    /// Полнотекстовый поиск по заголовкам и содержанию публикаций.
+    ///
+    /// ## Returns
+    ///
+    /// Найденные публикации, от наиболее релевантных, и общее количество
+    /// совпадений.
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<PostSearchHit>, i64), SqlxError>;
 }
 
+/// Общий список колонок публикации для запросов к `posts`: поля самой
+/// записи, имя автора и сводка реакций. Теги выбираются отдельно —
+/// см. [`POST_TAGS`], при создании и правке они подставляются из параметров.
+const POST_COLUMNS: &str = r#"
+    posts.id, posts.title, posts.slug, posts.content, posts.content_html, posts.author_id,
+    posts.created_at, posts.updated_at, posts.status, posts.publish_at, posts.deleted_at,
+    (SELECT username FROM users WHERE users.id = posts.author_id) AS author_username,
+    ARRAY(
+        SELECT r.kind FROM post_reactions r WHERE r.post_id = posts.id
+        GROUP BY r.kind ORDER BY r.kind
+    ) AS reaction_kinds,
+    ARRAY(
+        SELECT COUNT(*) FROM post_reactions r WHERE r.post_id = posts.id
+        GROUP BY r.kind ORDER BY r.kind
+    ) AS reaction_counts
+"#;
+
+/// Теги публикации, упорядоченные по имени.
+const POST_TAGS: &str = r#"
+    ARRAY(
+        SELECT t.name::TEXT FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+        WHERE pt.post_id = posts.id ORDER BY t.name
+    ) AS tags
+"#;
+
 repo_pg_pool!(
     #[derive(Clone)]
     pub(crate) struct PostRepo;
@@ -46,11 +207,12 @@ repo_pg_pool!(
 #[async_trait]
 impl PostRepository for PostRepo {
     async fn get(&self, post_id: &DataId) -> Result<Post, SqlxError> {
-        let record = sqlx::query(
+        let record = sqlx::query(&format!(
             r#"
-            SELECT id, title, content, author_id, created_at, updated_at FROM posts WHERE id = $1
-            "#,
-        )
+            SELECT {POST_COLUMNS}, {POST_TAGS}
+            FROM posts WHERE id = $1 AND deleted_at IS NULL
+            "#
+        ))
         .bind(post_id)
         .fetch_one(&self.pool)
         .await?;
@@ -58,21 +220,53 @@ impl PostRepository for PostRepo {
         Ok(make_post_by_row(&record))
     }
 
+    async fn get_by_slug(&self, slug: &Slug) -> Result<Post, SqlxError> {
+        let record = sqlx::query(&format!(
+            r#"
+            SELECT {POST_COLUMNS}, {POST_TAGS}
+            FROM post_slugs ps
+            JOIN posts ON posts.id = ps.post_id
+            WHERE ps.slug = $1 AND deleted_at IS NULL
+            "#
+        ))
+        .bind(slug)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_post_by_row(&record))
+    }
+
     async fn create(&self, post: &Post) -> Result<Post, SqlxError> {
-        let record = sqlx::query(
+        let mut tx = self.pool.begin().await?;
+        let slug = allocate_slug(&mut tx, &Slug::from_title(&post.title), None).await?;
+
+        let record = sqlx::query(&format!(
             r#"
-            INSERT INTO posts (title, content, author_id, created_at)
-            VALUES ($1, $2, $3, $4)
-            RETURNING *
-            "#,
-        )
+            INSERT INTO posts
+                (title, content, author_id, created_at, status, publish_at, content_html, slug)
+            VALUES ($1, $2, $3, $4, $6, $7, $8, $9)
+            RETURNING {POST_COLUMNS}, $5::TEXT[] AS tags
+            "#
+        ))
         .bind(&post.title)
         .bind(&post.content)
         .bind(&post.author_id)
         .bind(post.created_at)
-        .fetch_one(&self.pool)
+        .bind(&post.tags)
+        .bind(post.status)
+        .bind(post.publish_at)
+        .bind(&post.content_html)
+        .bind(&slug)
+        .fetch_one(&mut *tx)
         .await?;
 
+        let post_id: DataId = record.get("id");
+        save_slug(&mut tx, &post_id, &slug).await?;
+        save_post_tags(&mut tx, &post_id, &post.tags).await?;
+        save_revision(&mut tx, &post_id, post).await?;
+
+        tx.commit().await?;
+
         Ok(make_post_by_row(&record))
     }
 
@@ -82,26 +276,114 @@ impl PostRepository for PostRepo {
     ///
     /// - `limit` — количество записей, должно быть больше 1
     /// - `offset` — отступ от первой записи в извлечённом списке
+    /// - `tag` — если передан, выгружаются только публикации с этим тегом
+    /// - `after` — если передан, выгрузка продолжается после позиции курсора
     ///
     /// ## Сортировка
     ///
-    /// В текущей реализации выгрузка производится по дате создания, от самой
-    /// молодой (поле `created_at` индексировано).
+    /// Выгрузка производится по времени публикации, от самой молодой, при
+    /// равенстве — по убыванию id (пара `(publish_at, id)` индексирована,
+    /// поэтому выгрузка по курсору не зависит от глубины страницы).
     ///
     /// ## Returns
     ///
-    /// Возвращает перечень публикаций, с учётом условий заказа, а также общее
-    /// количество публикаций в базе данных.
-    async fn list(&self, limit: i32, offset: i32) -> Result<(Vec<Post>, i64), SqlxError> {
-        let results = sqlx::query(
+    /// Возвращает перечень публикаций, с учётом условий заказа, а также курсор
+    /// следующей страницы, если она есть.
+    async fn list(
+        &self,
+        params: &ListPostsParams,
+    ) -> Result<(Vec<Post>, Option<PostCursor>), SqlxError> {
+        let (after_publish_at, after_id) = match &params.after {
+            Some(cursor) => (Some(cursor.publish_at), Some(&cursor.id)),
+            None => (None, None),
+        };
+
+        // Одна лишняя запись показывает, есть ли следующая страница.
+        let results = sqlx::query(&format!(
             r#"
-            SELECT id, title, content, author_id, created_at, updated_at
+            SELECT {POST_COLUMNS}, {POST_TAGS}
             FROM posts
-            ORDER BY created_at DESC
-            LIMIT $1
+            WHERE status = 'published' AND deleted_at IS NULL
+              AND ($3::VARCHAR IS NULL OR EXISTS (
+                      SELECT 1 FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+                      WHERE pt.post_id = posts.id AND t.name = $3
+                  ))
+              AND ($4::TIMESTAMPTZ IS NULL OR (publish_at, id) < ($4, $5))
+              AND ($6::BIGINT IS NULL OR author_id IN (
+                      SELECT followee_id FROM follows WHERE follower_id = $6
+                  ))
+              AND ($7::BIGINT IS NULL OR author_id = $7)
+            ORDER BY publish_at DESC, id DESC
+            LIMIT $1 + 1
             OFFSET $2
+            "#
+        ))
+        .bind(params.limit)
+        .bind(params.offset)
+        .bind(&params.tag)
+        .bind(after_publish_at)
+        .bind(after_id)
+        .bind(&params.followed_by)
+        .bind(&params.author_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut posts: Vec<Post> = results
+            .into_iter()
+            .map(|row| make_post_by_row(&row))
+            .collect();
+
+        let next_cursor = if posts.len() > params.limit as usize {
+            posts.truncate(params.limit as usize);
+            posts
+                .last()
+                .and_then(|post| Some(PostCursor::new(post.publish_at?, post.id.clone()?)))
+        } else {
+            None
+        };
+
+        Ok((posts, next_cursor))
+    }
+
+    async fn count(&self, params: &ListPostsParams) -> Result<i64, SqlxError> {
+        sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM posts
+            WHERE status = 'published' AND deleted_at IS NULL
+              AND ($1::VARCHAR IS NULL OR EXISTS (
+                  SELECT 1 FROM post_tags pt JOIN tags t ON t.id = pt.tag_id
+                  WHERE pt.post_id = posts.id AND t.name = $1
+              ))
+              AND ($2::BIGINT IS NULL OR author_id IN (
+                  SELECT followee_id FROM follows WHERE follower_id = $2
+              ))
+              AND ($3::BIGINT IS NULL OR author_id = $3)
             "#,
         )
+        .bind(&params.tag)
+        .bind(&params.followed_by)
+        .bind(&params.author_id)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn list_by_author(
+        &self,
+        author_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Post>, i64), SqlxError> {
+        let results = sqlx::query(&format!(
+            r#"
+            SELECT {POST_COLUMNS}, {POST_TAGS}
+            FROM posts
+            WHERE author_id = $1 AND status = 'published' AND deleted_at IS NULL
+            ORDER BY publish_at DESC, id DESC
+            LIMIT $2
+            OFFSET $3
+            "#
+        ))
+        .bind(author_id)
         .bind(limit)
         .bind(offset)
         .fetch_all(&self.pool)
@@ -112,35 +394,268 @@ impl PostRepository for PostRepo {
             .map(|row| make_post_by_row(&row))
             .collect();
 
-        let total_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM posts")
-            .fetch_one(&self.pool)
-            .await?;
+        let total_count: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM posts
+            WHERE author_id = $1 AND status = 'published' AND deleted_at IS NULL
+            "#,
+        )
+        .bind(author_id)
+        .fetch_one(&self.pool)
+        .await?;
 
         Ok((posts, total_count))
     }
 
-    async fn update(&self, post: &Post) -> Result<(), SqlxError> {
-        sqlx::query(
+    async fn list_unpublished(
+        &self,
+        author_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Post>, i64), SqlxError> {
+        let results = sqlx::query(&format!(
             r#"
-            UPDATE posts
-            SET title = $1, content = $2, updated_at = $3
-            WHERE id = $4
+            SELECT {POST_COLUMNS}, {POST_TAGS}
+            FROM posts
+            WHERE author_id = $1 AND status <> 'published' AND deleted_at IS NULL
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2
+            OFFSET $3
+            "#
+        ))
+        .bind(author_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let posts = results
+            .into_iter()
+            .map(|row| make_post_by_row(&row))
+            .collect();
+
+        let total_count: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM posts
+            WHERE author_id = $1 AND status <> 'published' AND deleted_at IS NULL
             "#,
         )
+        .bind(author_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok((posts, total_count))
+    }
+
+    async fn list_pending(&self, limit: i32, offset: i32) -> Result<(Vec<Post>, i64), SqlxError> {
+        let results = sqlx::query(&format!(
+            r#"
+            SELECT {POST_COLUMNS}, {POST_TAGS}
+            FROM posts
+            WHERE status = 'pending' AND deleted_at IS NULL
+            ORDER BY COALESCE(updated_at, created_at), id
+            LIMIT $1
+            OFFSET $2
+            "#
+        ))
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let posts = results
+            .into_iter()
+            .map(|row| make_post_by_row(&row))
+            .collect();
+
+        let total_count: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM posts WHERE status = 'pending' AND deleted_at IS NULL
+            "#,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok((posts, total_count))
+    }
+
+    async fn list_by_author_after(
+        &self,
+        author_id: &DataId,
+        after: Option<&DataId>,
+        limit: i32,
+    ) -> Result<Vec<Post>, SqlxError> {
+        let results = sqlx::query(&format!(
+            r#"
+            SELECT {POST_COLUMNS}, {POST_TAGS}
+            FROM posts
+            WHERE author_id = $1 AND ($2::BIGINT IS NULL OR id > $2)
+            ORDER BY id
+            LIMIT $3
+            "#
+        ))
+        .bind(author_id)
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|row| make_post_by_row(&row))
+            .collect())
+    }
+
+    async fn set_publication(
+        &self,
+        post_id: &DataId,
+        status: PostStatus,
+        publish_at: Option<DateTime<Utc>>,
+    ) -> Result<(), SqlxError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE posts SET status = $1, publish_at = $2
+            WHERE id = $3 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(status)
+        .bind(publish_at)
+        .bind(post_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(SqlxError::RowNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn publish_due(&self) -> Result<u64, SqlxError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE posts SET status = 'published'
+            WHERE status = 'scheduled' AND publish_at <= NOW() AND deleted_at IS NULL
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn update(&self, post: &Post) -> Result<Post, SqlxError> {
+        let post_id = post.id.clone().ok_or(SqlxError::RowNotFound)?;
+        let mut tx = self.pool.begin().await?;
+
+        let base = Slug::from_title(&post.title);
+        let slug = match &post.slug {
+            Some(slug) if slug.is_derived_from(&base) => slug.clone(),
+            _ => allocate_slug(&mut tx, &base, Some(&post_id)).await?,
+        };
+
+        let record = sqlx::query(&format!(
+            r#"
+            UPDATE posts
+            SET title = $1, content = $2, content_html = $3, updated_at = $4, slug = $6,
+                status = $8, publish_at = $9
+            WHERE id = $5 AND deleted_at IS NULL
+            RETURNING {POST_COLUMNS}, $7::TEXT[] AS tags
+            "#
+        ))
         .bind(&post.title)
         .bind(&post.content)
+        .bind(&post.content_html)
         .bind(post.updated_at)
-        .bind(&post.id)
+        .bind(&post_id)
+        .bind(&slug)
+        .bind(&post.tags)
+        .bind(post.status)
+        .bind(post.publish_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        save_slug(&mut tx, &post_id, &slug).await?;
+        save_post_tags(&mut tx, &post_id, &post.tags).await?;
+        save_revision(&mut tx, &post_id, post).await?;
+
+        tx.commit().await?;
+
+        Ok(make_post_by_row(&record))
+    }
+
+    async fn delete(&self, post_id: &DataId) -> Result<(), SqlxError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE posts SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(post_id)
         .execute(&self.pool)
         .await?;
 
+        if result.rows_affected() == 0 {
+            return Err(SqlxError::RowNotFound);
+        }
+
         Ok(())
     }
 
-    async fn delete(&self, post_id: &DataId) -> Result<(), SqlxError> {
+    async fn get_deleted(&self, post_id: &DataId) -> Result<Post, SqlxError> {
+        let record = sqlx::query(&format!(
+            r#"
+            SELECT {POST_COLUMNS}, {POST_TAGS}
+            FROM posts WHERE id = $1 AND deleted_at IS NOT NULL
+            "#
+        ))
+        .bind(post_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_post_by_row(&record))
+    }
+
+    async fn list_deleted(
+        &self,
+        author_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Post>, i64), SqlxError> {
+        let results = sqlx::query(&format!(
+            r#"
+            SELECT {POST_COLUMNS}, {POST_TAGS}
+            FROM posts
+            WHERE author_id = $1 AND deleted_at IS NOT NULL
+            ORDER BY deleted_at DESC, id DESC
+            LIMIT $2
+            OFFSET $3
+            "#
+        ))
+        .bind(author_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let posts = results
+            .into_iter()
+            .map(|row| make_post_by_row(&row))
+            .collect();
+
+        let total_count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM posts WHERE author_id = $1 AND deleted_at IS NOT NULL",
+        )
+        .bind(author_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok((posts, total_count))
+    }
+
+    async fn restore(&self, post_id: &DataId) -> Result<(), SqlxError> {
         let result = sqlx::query(
             r#"
-            DELETE FROM posts WHERE id = $1
+            UPDATE posts SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL
             "#,
         )
         .bind(post_id)
@@ -154,10 +669,48 @@ impl PostRepository for PostRepo {
         Ok(())
     }
 
+    async fn purge_deleted(&self, before: DateTime<Utc>) -> Result<u64, SqlxError> {
+        let result = sqlx::query("DELETE FROM posts WHERE deleted_at < $1")
+            .bind(before)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn fill_content_html(&self, limit: i32) -> Result<u64, SqlxError> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, content FROM posts WHERE content_html IS NULL
+            ORDER BY id
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut tx = self.pool.begin().await?;
+        for record in &records {
+            let post_id: DataId = record.get("id");
+            let content: String = record.get("content");
+            sqlx::query(
+                "UPDATE posts SET content_html = $1 WHERE id = $2 AND content_html IS NULL",
+            )
+            .bind(render_html(&content))
+            .bind(&post_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(records.len() as u64)
+    }
+
     async fn get_author_id(&self, post_id: &DataId) -> Result<DataId, SqlxError> {
         let record = sqlx::query(
             r#"
-            SELECT author_id FROM posts WHERE id = $1
+            SELECT author_id FROM posts WHERE id = $1 AND deleted_at IS NULL
             "#,
         )
         .bind(post_id)
@@ -167,6 +720,195 @@ impl PostRepository for PostRepo {
         let author_id = record.get("author_id");
         Ok(author_id)
     }
+
+    async fn list_tags(&self) -> Result<Vec<TagStat>, SqlxError> {
+        let results = sqlx::query(
+            r#"
+            SELECT t.name, COUNT(pt.post_id) AS posts_count
+            FROM tags t
+            JOIN post_tags pt ON pt.tag_id = t.id
+            JOIN posts p ON p.id = pt.post_id
+                AND p.status = 'published' AND p.deleted_at IS NULL
+            GROUP BY t.name
+            ORDER BY posts_count DESC, t.name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let tags = results
+            .into_iter()
+            .map(|row| TagStat {
+                name: row.get("name"),
+                posts_count: row.get("posts_count"),
+            })
+            .collect();
+
+        Ok(tags)
+    }
+
+    /// Полнотекстовый поиск по публикациям.
+    ///
+    /// Запрос разбирается `websearch_to_tsquery` в русской и английской
+    /// конфигурациях (поддерживаются кавычки, `or` и `-`). Совпадения
+    /// в заголовке весомее совпадений в содержании. Фрагмент `snippet`
+    /// строится в той конфигурации, в которой совпало содержание (русская
+    /// проверяется первой); совпадения в нём обрамлены `<mark>` и `</mark>`.
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<PostSearchHit>, i64), SqlxError> {
+        let results = sqlx::query(&format!(
+            r#"
+            SELECT {POST_COLUMNS}, {POST_TAGS},
+                   ts_rank(search_vector, q.ru || q.en) AS rank,
+                   CASE WHEN to_tsvector('russian', content) @@ q.ru
+                       THEN ts_headline(
+                           'russian', content, q.ru,
+                           'StartSel=<mark>, StopSel=</mark>, MaxWords=35, MinWords=15'
+                       )
+                       ELSE ts_headline(
+                           'english', content, q.en,
+                           'StartSel=<mark>, StopSel=</mark>, MaxWords=35, MinWords=15'
+                       )
+                   END AS snippet
+            FROM posts,
+                 (SELECT websearch_to_tsquery('russian', $1) AS ru,
+                         websearch_to_tsquery('english', $1) AS en) q
+            WHERE status = 'published' AND deleted_at IS NULL AND search_vector @@ (q.ru || q.en)
+            ORDER BY rank DESC, publish_at DESC
+            LIMIT $2
+            OFFSET $3
+            "#
+        ))
+        .bind(query)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let hits = results
+            .into_iter()
+            .map(|row| PostSearchHit {
+                post: make_post_by_row(&row),
+                rank: row.get("rank"),
+                snippet: row.get("snippet"),
+            })
+            .collect();
+
+        let total_count: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM posts
+            WHERE status = 'published' AND deleted_at IS NULL
+              AND search_vector @@ (websearch_to_tsquery('russian', $1)
+                                    || websearch_to_tsquery('english', $1))
+            "#,
+        )
+        .bind(query)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok((hits, total_count))
+    }
+}
+
+/// Заменить набор тегов публикации в рамках транзакции. Отсутствующие теги
+/// создаются.
+async fn save_post_tags(
+    tx: &mut Transaction<'_, Postgres>,
+    post_id: &DataId,
+    tags: &[Tag],
+) -> Result<(), SqlxError> {
+    sqlx::query("DELETE FROM post_tags WHERE post_id = $1")
+        .bind(post_id)
+        .execute(&mut **tx)
+        .await?;
+
+    if tags.is_empty() {
+        return Ok(());
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO tags (name)
+        SELECT UNNEST($1::VARCHAR[])
+        ON CONFLICT (name) DO NOTHING
+        "#,
+    )
+    .bind(tags)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO post_tags (post_id, tag_id)
+        SELECT $1, id FROM tags WHERE name = ANY($2)
+        "#,
+    )
+    .bind(post_id)
+    .bind(tags)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// Подобрать свободный адрес на основе `base`: сам `base` или первый
+/// свободный из `base-2`, `base-3`, ... Адрес, ранее принадлежавший той же
+/// публикации (`post_id`), считается свободным.
+///
+/// Подбор адресов с общей основой выполняется под advisory-блокировкой
+/// до конца транзакции, чтобы параллельные сохранения не получили один адрес.
+async fn allocate_slug(
+    tx: &mut Transaction<'_, Postgres>,
+    base: &Slug,
+    post_id: Option<&DataId>,
+) -> Result<Slug, SqlxError> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+        .bind(base)
+        .execute(&mut **tx)
+        .await?;
+
+    let mut candidate = base.clone();
+    let mut n = 1;
+    loop {
+        let owner: Option<DataId> =
+            sqlx::query_scalar("SELECT post_id FROM post_slugs WHERE slug = $1")
+                .bind(&candidate)
+                .fetch_optional(&mut **tx)
+                .await?;
+
+        match owner {
+            None => return Ok(candidate),
+            Some(owner) if Some(&owner) == post_id => return Ok(candidate),
+            Some(_) => {
+                n += 1;
+                candidate = base.with_suffix(n);
+            }
+        }
+    }
+}
+
+/// Закрепить адрес за публикацией. Ранее выданные адреса сохраняются.
+async fn save_slug(
+    tx: &mut Transaction<'_, Postgres>,
+    post_id: &DataId,
+    slug: &Slug,
+) -> Result<(), SqlxError> {
+    sqlx::query(
+        r#"
+        INSERT INTO post_slugs (slug, post_id) VALUES ($1, $2)
+        ON CONFLICT (slug) DO NOTHING
+        "#,
+    )
+    .bind(slug)
+    .bind(post_id)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
 }
 
 /// Создать [`Post`] на основе выгрузки строки из базы данных.
@@ -175,8 +917,18 @@ fn make_post_by_row(record: &PgRow) -> Post {
         record.get("id"),
         record.get("title"),
         record.get("content"),
+        record.get("content_html"),
         record.get("author_id"),
         record.get("created_at"),
         record.get("updated_at"),
     )
+    .with_author_username(record.get("author_username"))
+    .with_slug(record.get("slug"))
+    .with_tags(record.get("tags"))
+    .with_publication(record.get("status"), record.get("publish_at"))
+    .with_deleted_at(record.get("deleted_at"))
+    .with_reactions(ReactionCount::zip(
+        record.get("reaction_kinds"),
+        record.get("reaction_counts"),
+    ))
 }
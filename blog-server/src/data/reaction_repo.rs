@@ -0,0 +1,145 @@
+//! Репозиторий реакций на публикации.
+
+use crate::{
+    domain::{
+        reaction::{ReactionCount, ReactionKind},
+        types::DataId,
+    },
+    repo_pg_pool,
+};
+use sqlx::{Error as SqlxError, PgPool, Row};
+use tonic::async_trait;
+
+#[async_trait]
+pub(crate) trait ReactionRepository: Send + Sync {
+    /// Поставить реакцию на публикацию. Повторная реакция того же вида
+    /// ничего не меняет.
+    async fn add(
+        &self,
+        post_id: &DataId,
+        user_id: &DataId,
+        kind: ReactionKind,
+    ) -> Result<(), SqlxError>;
+
+    /// Снять реакцию с публикации. Отсутствие реакции ошибкой не считается.
+    async fn remove(
+        &self,
+        post_id: &DataId,
+        user_id: &DataId,
+        kind: ReactionKind,
+    ) -> Result<(), SqlxError>;
+
+    /// Количество реакций каждого вида на публикацию.
+    async fn counts(&self, post_id: &DataId) -> Result<Vec<ReactionCount>, SqlxError>;
+
+    /// Реакции пользователя на перечень публикаций.
+    ///
+    /// ## Returns
+    ///
+    /// Пары из id публикации и вида реакции.
+    async fn list_by_user(
+        &self,
+        post_ids: &[DataId],
+        user_id: &DataId,
+    ) -> Result<Vec<(DataId, ReactionKind)>, SqlxError>;
+}
+
+repo_pg_pool!(
+    #[derive(Clone)]
+    /// Структура взаимодействия с базой данной для работы с `post_reactions`.
+    pub(crate) struct ReactionRepo;
+);
+
+#[async_trait]
+impl ReactionRepository for ReactionRepo {
+    async fn add(
+        &self,
+        post_id: &DataId,
+        user_id: &DataId,
+        kind: ReactionKind,
+    ) -> Result<(), SqlxError> {
+        sqlx::query(
+            r#"
+            INSERT INTO post_reactions (post_id, user_id, kind)
+            VALUES ($1, $2, $3)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(post_id)
+        .bind(user_id)
+        .bind(kind)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn remove(
+        &self,
+        post_id: &DataId,
+        user_id: &DataId,
+        kind: ReactionKind,
+    ) -> Result<(), SqlxError> {
+        sqlx::query(
+            r#"
+            DELETE FROM post_reactions WHERE post_id = $1 AND user_id = $2 AND kind = $3
+            "#,
+        )
+        .bind(post_id)
+        .bind(user_id)
+        .bind(kind)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn counts(&self, post_id: &DataId) -> Result<Vec<ReactionCount>, SqlxError> {
+        let results = sqlx::query(
+            r#"
+            SELECT kind, COUNT(*) AS count
+            FROM post_reactions
+            WHERE post_id = $1
+            GROUP BY kind
+            ORDER BY kind
+            "#,
+        )
+        .bind(post_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|row| ReactionCount {
+                kind: row.get("kind"),
+                count: row.get("count"),
+            })
+            .collect())
+    }
+
+    async fn list_by_user(
+        &self,
+        post_ids: &[DataId],
+        user_id: &DataId,
+    ) -> Result<Vec<(DataId, ReactionKind)>, SqlxError> {
+        let post_ids: Vec<i64> = post_ids.iter().map(|id| id.0).collect();
+
+        let results = sqlx::query(
+            r#"
+            SELECT post_id, kind
+            FROM post_reactions
+            WHERE user_id = $1 AND post_id = ANY($2)
+            ORDER BY post_id, kind
+            "#,
+        )
+        .bind(user_id)
+        .bind(&post_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|row| (row.get("post_id"), row.get("kind")))
+            .collect())
+    }
+}
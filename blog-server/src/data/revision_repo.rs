@@ -0,0 +1,143 @@
+//! Репозиторий ревизий публикаций.
+
+use crate::{
+    domain::{post::Post, revision::PostRevision, types::DataId},
+    repo_pg_pool,
+};
+use sqlx::{postgres::PgRow, Error as SqlxError, PgPool, Postgres, Row, Transaction};
+use tonic::async_trait;
+
+#[async_trait]
+pub(crate) trait RevisionRepository: Send + Sync {
+    /// Получить ревизию публикации по её номеру.
+    async fn get(&self, post_id: &DataId, revision: i32) -> Result<PostRevision, SqlxError>;
+
+    /// Предоставить список ревизий публикации, от новых к старым.
+    ///
+    /// ## Args
+    ///
+    /// - `post_id` — id публикации
+    /// - `limit` — количество записей, должно быть больше 1
+    /// - `offset` — отступ от первой записи в извлечённом списке
+    ///
+    /// ## Returns
+    ///
+    /// Перечень ревизий, с учётом заказа, и общее число ревизий публикации.
+    async fn list_by_post(
+        &self,
+        post_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<PostRevision>, i64), SqlxError>;
+}
+
+repo_pg_pool!(
+    #[derive(Clone)]
+    /// Структура взаимодействия с базой данной для работы с `post_revisions`.
+    pub(crate) struct RevisionRepo;
+);
+
+#[async_trait]
+impl RevisionRepository for RevisionRepo {
+    async fn get(&self, post_id: &DataId, revision: i32) -> Result<PostRevision, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            SELECT post_id, revision, title, content, tags, created_at
+            FROM post_revisions
+            WHERE post_id = $1 AND revision = $2
+            "#,
+        )
+        .bind(post_id)
+        .bind(revision)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_revision_by_row(&record))
+    }
+
+    async fn list_by_post(
+        &self,
+        post_id: &DataId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<PostRevision>, i64), SqlxError> {
+        let results = sqlx::query(
+            r#"
+            SELECT post_id, revision, title, content, tags, created_at
+            FROM post_revisions
+            WHERE post_id = $1
+            ORDER BY revision DESC
+            LIMIT $2
+            OFFSET $3
+            "#,
+        )
+        .bind(post_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let revisions = results
+            .into_iter()
+            .map(|row| make_revision_by_row(&row))
+            .collect();
+
+        let total_count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM post_revisions WHERE post_id = $1")
+                .bind(post_id)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok((revisions, total_count))
+    }
+}
+
+/// Сохранить текущее состояние публикации следующей ревизией в рамках
+/// транзакции её создания или обновления.
+///
+/// Вызывается после записи в `posts`: строка публикации к этому моменту
+/// заблокирована транзакцией, поэтому номера ревизий не конфликтуют.
+/// Если заголовок, содержание и теги совпадают с последней ревизией
+/// (например, изменился только статус), новая ревизия не создаётся.
+pub(super) async fn save_revision(
+    tx: &mut Transaction<'_, Postgres>,
+    post_id: &DataId,
+    post: &Post,
+) -> Result<(), SqlxError> {
+    sqlx::query(
+        r#"
+        WITH last AS (
+            SELECT revision, title, content, tags FROM post_revisions
+            WHERE post_id = $1
+            ORDER BY revision DESC
+            LIMIT 1
+        )
+        INSERT INTO post_revisions (post_id, revision, title, content, tags, created_at)
+        SELECT $1, COALESCE((SELECT revision FROM last), 0) + 1, $2, $3, $4, $5
+        WHERE NOT EXISTS (
+            SELECT 1 FROM last WHERE title = $2 AND content = $3 AND tags = $4
+        )
+        "#,
+    )
+    .bind(post_id)
+    .bind(&post.title)
+    .bind(&post.content)
+    .bind(&post.tags)
+    .bind(post.updated_at.unwrap_or(post.created_at))
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// Создать [`PostRevision`] на основе выгрузки строки из базы данных.
+fn make_revision_by_row(record: &PgRow) -> PostRevision {
+    PostRevision::new(
+        record.get("post_id"),
+        record.get("revision"),
+        record.get("title"),
+        record.get("content"),
+        record.get("tags"),
+        record.get("created_at"),
+    )
+}
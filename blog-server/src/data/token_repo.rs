@@ -0,0 +1,247 @@
+//! Репозиторий токенов сессий.
+
+use crate::{
+    domain::{
+        token::{AccessTokenId, NewRefreshToken, RefreshToken},
+        types::DataId,
+    },
+    repo_pg_pool,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgRow, Error as SqlxError, PgPool, Row};
+use tonic::async_trait;
+
+#[async_trait]
+pub(crate) trait TokenRepository: Send + Sync {
+    /// Записать новый токен обновления.
+    async fn create(&self, token: &NewRefreshToken) -> Result<(), SqlxError>;
+
+    /// Получить токен обновления по хешу его секрета.
+    async fn get_by_hash(&self, token_hash: &str) -> Result<RefreshToken, SqlxError>;
+
+    /// Заменить токен обновления `old_id` новым токеном `token`.
+    ///
+    /// ## Returns
+    ///
+    /// `false`, если старый токен уже отозван (например, параллельным
+    /// запросом) — тогда новый токен не записывается.
+    async fn rotate(&self, old_id: &DataId, token: &NewRefreshToken) -> Result<bool, SqlxError>;
+
+    /// Отозвать все токены обновления сессии `family_id`.
+    ///
+    /// ## Returns
+    ///
+    /// Ещё не истёкшие токены доступа, выданные в этой сессии.
+    async fn revoke_family(&self, family_id: &str) -> Result<Vec<AccessTokenId>, SqlxError>;
+
+    /// Отозвать все токены обновления пользователя `user_id`.
+    ///
+    /// ## Returns
+    ///
+    /// Ещё не истёкшие токены доступа, выданные пользователю.
+    async fn revoke_user(&self, user_id: &DataId) -> Result<Vec<AccessTokenId>, SqlxError>;
+
+    /// Ещё не истёкшие токены доступа, выданные пользователю `user_id`.
+    /// В отличие от [`Self::revoke_user`], сессии не отзываются.
+    async fn list_user_access(&self, user_id: &DataId) -> Result<Vec<AccessTokenId>, SqlxError>;
+
+    /// Добавить токены доступа в список отозванных.
+    async fn revoke_access(&self, tokens: &[AccessTokenId]) -> Result<(), SqlxError>;
+
+    /// Список отозванных и ещё не истёкших токенов доступа. Истёкшие
+    /// записи при этом удаляются.
+    async fn list_revoked_access(&self) -> Result<Vec<AccessTokenId>, SqlxError>;
+}
+
+repo_pg_pool!(
+    #[derive(Clone)]
+    /// Структура взаимодействия с базой данной для работы с `refresh_tokens`
+    /// и `revoked_tokens`.
+    pub(crate) struct TokenRepo;
+);
+
+#[async_trait]
+impl TokenRepository for TokenRepo {
+    async fn create(&self, token: &NewRefreshToken) -> Result<(), SqlxError> {
+        insert_refresh_token(&self.pool, token).await
+    }
+
+    async fn get_by_hash(&self, token_hash: &str) -> Result<RefreshToken, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            SELECT t.id, t.user_id, u.username, u.role,
+                   u.disabled_at IS NOT NULL AS account_disabled, t.family_id,
+                   t.access_jti, t.access_expires_at, t.expires_at, t.revoked_at
+            FROM refresh_tokens t
+            JOIN users u ON u.id = t.user_id
+            WHERE t.token_hash = $1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_refresh_token_by_row(&record))
+    }
+
+    async fn rotate(&self, old_id: &DataId, token: &NewRefreshToken) -> Result<bool, SqlxError> {
+        let mut tx = self.pool.begin().await?;
+
+        let revoked = sqlx::query(
+            r#"
+            UPDATE refresh_tokens SET revoked_at = NOW()
+            WHERE id = $1 AND revoked_at IS NULL
+            "#,
+        )
+        .bind(old_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if revoked.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        insert_refresh_token(&mut *tx, token).await?;
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> Result<Vec<AccessTokenId>, SqlxError> {
+        let records = sqlx::query(
+            r#"
+            UPDATE refresh_tokens SET revoked_at = COALESCE(revoked_at, NOW())
+            WHERE family_id = $1
+            RETURNING access_jti, access_expires_at
+            "#,
+        )
+        .bind(family_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(unexpired_access_by_rows(&records))
+    }
+
+    async fn revoke_user(&self, user_id: &DataId) -> Result<Vec<AccessTokenId>, SqlxError> {
+        let records = sqlx::query(
+            r#"
+            UPDATE refresh_tokens SET revoked_at = COALESCE(revoked_at, NOW())
+            WHERE user_id = $1
+            RETURNING access_jti, access_expires_at
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(unexpired_access_by_rows(&records))
+    }
+
+    async fn list_user_access(&self, user_id: &DataId) -> Result<Vec<AccessTokenId>, SqlxError> {
+        let records = sqlx::query(
+            "SELECT access_jti, access_expires_at FROM refresh_tokens WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(unexpired_access_by_rows(&records))
+    }
+
+    async fn revoke_access(&self, tokens: &[AccessTokenId]) -> Result<(), SqlxError> {
+        let (jtis, expires): (Vec<&str>, Vec<DateTime<Utc>>) = tokens
+            .iter()
+            .map(|token| (token.jti.as_str(), token.expires_at))
+            .unzip();
+
+        sqlx::query(
+            r#"
+            INSERT INTO revoked_tokens (jti, expires_at)
+            SELECT * FROM UNNEST($1::VARCHAR[], $2::TIMESTAMPTZ[])
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(jtis)
+        .bind(expires)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_revoked_access(&self) -> Result<Vec<AccessTokenId>, SqlxError> {
+        sqlx::query("DELETE FROM revoked_tokens WHERE expires_at <= NOW()")
+            .execute(&self.pool)
+            .await?;
+
+        let records = sqlx::query("SELECT jti, expires_at FROM revoked_tokens")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(records
+            .iter()
+            .map(|record| AccessTokenId {
+                jti: record.get("jti"),
+                expires_at: record.get("expires_at"),
+            })
+            .collect())
+    }
+}
+
+/// Поддерживающая функция: записывает токен обновления в рамках
+/// переданного соединения или транзакции.
+async fn insert_refresh_token<'e, E>(executor: E, token: &NewRefreshToken) -> Result<(), SqlxError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query(
+        r#"
+        INSERT INTO refresh_tokens
+            (user_id, token_hash, family_id, access_jti, access_expires_at, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(&token.user_id)
+    .bind(&token.token_hash)
+    .bind(&token.family_id)
+    .bind(&token.access.jti)
+    .bind(token.access.expires_at)
+    .bind(token.expires_at)
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+/// Поддерживающая функция: ещё не истёкшие токены доступа из записей
+/// `refresh_tokens`.
+fn unexpired_access_by_rows(records: &[PgRow]) -> Vec<AccessTokenId> {
+    let now = Utc::now();
+    records
+        .iter()
+        .map(|record| AccessTokenId {
+            jti: record.get("access_jti"),
+            expires_at: record.get("access_expires_at"),
+        })
+        .filter(|token| token.expires_at > now)
+        .collect()
+}
+
+/// Поддерживающая функция: создаёт [`RefreshToken`] на основе записи из базы
+/// данных, обёрнутой в [`PgRow`].
+fn make_refresh_token_by_row(record: &PgRow) -> RefreshToken {
+    RefreshToken {
+        id: record.get("id"),
+        user_id: record.get("user_id"),
+        username: record.get("username"),
+        role: record.get("role"),
+        account_disabled: record.get("account_disabled"),
+        family_id: record.get("family_id"),
+        access: AccessTokenId {
+            jti: record.get("access_jti"),
+            expires_at: record.get("access_expires_at"),
+        },
+        expires_at: record.get("expires_at"),
+        revoked_at: record.get("revoked_at"),
+    }
+}
@@ -1,9 +1,15 @@
 //! Репозиторий пользователей.
 
 use crate::{
-    domain::{types::Username, user::User},
+    domain::{
+        account::DeletionMode,
+        role::Role,
+        types::{DataId, Email, StorageKey, Username},
+        user::{UpdateProfile, User, UserProfile},
+    },
     repo_pg_pool,
 };
+use chrono::{DateTime, Utc};
 use sqlx::{postgres::PgRow, Error as SqlxError, PgPool, Row};
 use tonic::async_trait;
 
@@ -11,8 +17,70 @@ use tonic::async_trait;
 pub(crate) trait UserRepository: Send + Sync {
     /// Создать пользователя.
     async fn create(&self, user: &User) -> Result<User, SqlxError>;
+    /// Предоставить экземпляр [`User`] по id.
+    async fn get_by_id(&self, user_id: &DataId) -> Result<User, SqlxError>;
     /// Предоставить экземпляр [`User`] по имени пользователя.
     async fn get_by_username(&self, username: &Username) -> Result<User, SqlxError>;
+    /// Предоставить экземпляр [`User`] по адресу электронной почты.
+    async fn get_by_email(&self, email: &Email) -> Result<User, SqlxError>;
+    /// Публичный профиль пользователя с количеством опубликованных записей.
+    async fn get_profile(&self, username: &Username) -> Result<UserProfile, SqlxError>;
+    /// Страница пользователей, от ранее зарегистрированных, и общее их
+    /// количество.
+    async fn list(&self, limit: i32, offset: i32) -> Result<(Vec<User>, i64), SqlxError>;
+    /// Назначить пользователю роль.
+    async fn set_role(&self, username: &Username, role: Role) -> Result<User, SqlxError>;
+    /// Заблокировать (`disabled = true`) или разблокировать учётную запись.
+    /// Повторная блокировка сохраняет исходное время блокировки.
+    async fn set_disabled(&self, username: &Username, disabled: bool) -> Result<User, SqlxError>;
+    /// Учесть неудачную попытку входа пользователя `user_id` в момент `now`.
+    /// Если неудач подряд набралось `max_failures`, вход блокируется до
+    /// `locked_until`, а счётчик сбрасывается.
+    async fn record_login_failure(
+        &self,
+        user_id: &DataId,
+        now: DateTime<Utc>,
+        max_failures: i32,
+        locked_until: DateTime<Utc>,
+    ) -> Result<User, SqlxError>;
+    /// Сбросить счётчик неудачных попыток входа и снять блокировку входа.
+    async fn reset_login_failures(&self, username: &Username) -> Result<User, SqlxError>;
+    /// Заменить хеш пароля пользователя `user_id`. Счётчик неудачных
+    /// попыток входа и блокировка входа при этом сбрасываются.
+    async fn set_password(
+        &self,
+        user_id: &DataId,
+        password_hash: &str,
+    ) -> Result<User, SqlxError>;
+    /// Отметить адрес `email` пользователя `user_id` подтверждённым
+    /// в момент `now`. Если адрес пользователя уже другой, возвращается
+    /// [`SqlxError::RowNotFound`]; время прежнего подтверждения сохраняется.
+    async fn mark_email_verified(
+        &self,
+        user_id: &DataId,
+        email: &Email,
+        now: DateTime<Utc>,
+    ) -> Result<User, SqlxError>;
+    /// Изменить профиль пользователя `user_id`: заданные в `update` поля
+    /// заменяются. Смена адреса электронной почты сбрасывает отметку о его
+    /// подтверждении.
+    async fn update_profile(
+        &self,
+        user_id: &DataId,
+        update: &UpdateProfile,
+    ) -> Result<User, SqlxError>;
+    /// Удалить учётную запись `user_id`. Публикации, комментарии и вложения
+    /// пользователя удаляются вместе с ней или, согласно `mode`, передаются
+    /// служебной учётной записи «удалённый пользователь».
+    ///
+    /// ## Returns
+    ///
+    /// Ключи файлов удалённых вложений, которые нужно убрать из хранилища.
+    async fn delete_account(
+        &self,
+        user_id: &DataId,
+        mode: DeletionMode,
+    ) -> Result<Vec<StorageKey>, SqlxError>;
 }
 
 repo_pg_pool!(
@@ -52,10 +120,167 @@ impl UserRepository for UserRepo {
         Ok(make_user_by_row(&record))
     }
 
+    async fn get_by_id(&self, user_id: &DataId) -> Result<User, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, username, email, password_hash, created_at, display_name, bio,
+                   avatar_url, role, disabled_at, failed_logins, last_failed_login_at,
+                   locked_until, email_verified_at
+            FROM users WHERE id = $1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_user_by_row(&record))
+    }
+
     async fn get_by_username(&self, username: &Username) -> Result<User, SqlxError> {
         let record = sqlx::query(
             r#"
-            SELECT id, username, email, password_hash, created_at FROM users WHERE username = $1
+            SELECT id, username, email, password_hash, created_at, display_name, bio,
+                   avatar_url, role, disabled_at, failed_logins, last_failed_login_at,
+                   locked_until, email_verified_at
+            FROM users WHERE username = $1
+            "#,
+        )
+        .bind(username)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_user_by_row(&record))
+    }
+
+    async fn get_by_email(&self, email: &Email) -> Result<User, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, username, email, password_hash, created_at, display_name, bio,
+                   avatar_url, role, disabled_at, failed_logins, last_failed_login_at,
+                   locked_until, email_verified_at
+            FROM users WHERE email = $1
+            "#,
+        )
+        .bind(email)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_user_by_row(&record))
+    }
+
+    async fn get_profile(&self, username: &Username) -> Result<UserProfile, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            SELECT username, display_name, bio, avatar_url, created_at,
+                   (SELECT COUNT(*) FROM posts
+                    WHERE author_id = users.id
+                      AND status = 'published' AND deleted_at IS NULL) AS posts_count
+            FROM users WHERE username = $1
+            "#,
+        )
+        .bind(username)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(UserProfile {
+            username: record.get("username"),
+            display_name: record.get("display_name"),
+            bio: record.get("bio"),
+            avatar_url: record.get("avatar_url"),
+            joined_at: record.get("created_at"),
+            posts_count: record.get("posts_count"),
+        })
+    }
+
+    async fn list(&self, limit: i32, offset: i32) -> Result<(Vec<User>, i64), SqlxError> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, username, email, password_hash, created_at, display_name, bio,
+                   avatar_url, role, disabled_at, failed_logins, last_failed_login_at,
+                   locked_until, email_verified_at
+            FROM users
+            ORDER BY id
+            LIMIT $1 OFFSET $2
+            "#,
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok((records.iter().map(make_user_by_row).collect(), total))
+    }
+
+    async fn set_role(&self, username: &Username, role: Role) -> Result<User, SqlxError> {
+        let record = sqlx::query("UPDATE users SET role = $2 WHERE username = $1 RETURNING *")
+            .bind(username)
+            .bind(role)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(make_user_by_row(&record))
+    }
+
+    async fn set_disabled(&self, username: &Username, disabled: bool) -> Result<User, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            UPDATE users
+            SET disabled_at = CASE WHEN $2 THEN COALESCE(disabled_at, NOW()) END
+            WHERE username = $1
+            RETURNING *
+            "#,
+        )
+        .bind(username)
+        .bind(disabled)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_user_by_row(&record))
+    }
+
+    async fn record_login_failure(
+        &self,
+        user_id: &DataId,
+        now: DateTime<Utc>,
+        max_failures: i32,
+        locked_until: DateTime<Utc>,
+    ) -> Result<User, SqlxError> {
+        // Счётчик увеличивается в самом запросе, чтобы параллельные попытки
+        // не затирали друг друга.
+        let record = sqlx::query(
+            r#"
+            UPDATE users
+            SET failed_logins = CASE WHEN failed_logins + 1 >= $3 THEN 0
+                                     ELSE failed_logins + 1 END,
+                last_failed_login_at = CASE WHEN failed_logins + 1 >= $3 THEN NULL
+                                            ELSE $2 END,
+                locked_until = CASE WHEN failed_logins + 1 >= $3 THEN $4
+                                    ELSE locked_until END
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(user_id)
+        .bind(now)
+        .bind(max_failures)
+        .bind(locked_until)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_user_by_row(&record))
+    }
+
+    async fn reset_login_failures(&self, username: &Username) -> Result<User, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            UPDATE users
+            SET failed_logins = 0, last_failed_login_at = NULL, locked_until = NULL
+            WHERE username = $1
+            RETURNING *
             "#,
         )
         .bind(username)
@@ -64,6 +289,144 @@ impl UserRepository for UserRepo {
 
         Ok(make_user_by_row(&record))
     }
+
+    async fn set_password(
+        &self,
+        user_id: &DataId,
+        password_hash: &str,
+    ) -> Result<User, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            UPDATE users
+            SET password_hash = $2,
+                failed_logins = 0, last_failed_login_at = NULL, locked_until = NULL
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(user_id)
+        .bind(password_hash)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_user_by_row(&record))
+    }
+
+    async fn mark_email_verified(
+        &self,
+        user_id: &DataId,
+        email: &Email,
+        now: DateTime<Utc>,
+    ) -> Result<User, SqlxError> {
+        let record = sqlx::query(
+            r#"
+            UPDATE users
+            SET email_verified_at = COALESCE(email_verified_at, $3)
+            WHERE id = $1 AND email = $2
+            RETURNING *
+            "#,
+        )
+        .bind(user_id)
+        .bind(email)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_user_by_row(&record))
+    }
+
+    async fn update_profile(
+        &self,
+        user_id: &DataId,
+        update: &UpdateProfile,
+    ) -> Result<User, SqlxError> {
+        // В правой части SET доступны прежние значения столбцов, поэтому
+        // адрес сравнивается с текущим.
+        let record = sqlx::query(
+            r#"
+            UPDATE users
+            SET display_name = COALESCE($2, display_name),
+                bio = COALESCE($3, bio),
+                avatar_url = COALESCE($4, avatar_url),
+                email = COALESCE($5, email),
+                email_verified_at = CASE WHEN $5 IS NULL OR $5 = email
+                                         THEN email_verified_at END
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(user_id)
+        .bind(
+            update
+                .display_name
+                .as_ref()
+                .map(|name| name.as_ref().trim()),
+        )
+        .bind(update.bio.as_ref().map(|bio| bio.as_ref().trim()))
+        .bind(update.avatar_url.as_ref())
+        .bind(update.email.as_ref())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(make_user_by_row(&record))
+    }
+
+    async fn delete_account(
+        &self,
+        user_id: &DataId,
+        mode: DeletionMode,
+    ) -> Result<Vec<StorageKey>, SqlxError> {
+        let mut tx = self.pool.begin().await?;
+
+        let removed_files = match mode {
+            DeletionMode::Anonymize => {
+                let heir_id: DataId =
+                    sqlx::query_scalar("SELECT id FROM users WHERE is_deleted_placeholder")
+                        .fetch_one(&mut *tx)
+                        .await?;
+                for query in [
+                    "UPDATE posts SET author_id = $2 WHERE author_id = $1",
+                    "UPDATE comments SET author_id = $2 WHERE author_id = $1",
+                    "UPDATE attachments SET owner_id = $2 WHERE owner_id = $1",
+                ] {
+                    sqlx::query(query)
+                        .bind(user_id)
+                        .bind(&heir_id)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+                Vec::new()
+            }
+            DeletionMode::Delete => {
+                // Комментарии других пользователей к удаляемым публикациям
+                // удаляются каскадно, вложения — вместе с учётной записью.
+                sqlx::query("DELETE FROM comments WHERE author_id = $1")
+                    .bind(user_id)
+                    .execute(&mut *tx)
+                    .await?;
+                sqlx::query("DELETE FROM posts WHERE author_id = $1")
+                    .bind(user_id)
+                    .execute(&mut *tx)
+                    .await?;
+                sqlx::query_scalar("SELECT storage_key FROM attachments WHERE owner_id = $1")
+                    .bind(user_id)
+                    .fetch_all(&mut *tx)
+                    .await?
+            }
+        };
+
+        let result = sqlx::query("DELETE FROM users WHERE id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(SqlxError::RowNotFound);
+        }
+
+        tx.commit().await?;
+
+        Ok(removed_files)
+    }
 }
 
 /// Поддерживающая функция: создаёт [`User`] на основе предоставленной записи
@@ -76,4 +439,16 @@ fn make_user_by_row(record: &PgRow) -> User {
         record.get("password_hash"),
         record.get("created_at"),
     )
+    .with_profile(
+        record.get("display_name"),
+        record.get("bio"),
+        record.get("avatar_url"),
+    )
+    .with_access(record.get("role"), record.get("disabled_at"))
+    .with_login_state(
+        record.get("failed_logins"),
+        record.get("last_failed_login_at"),
+        record.get("locked_until"),
+    )
+    .with_email_verified_at(record.get("email_verified_at"))
 }
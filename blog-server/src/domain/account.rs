@@ -0,0 +1,150 @@
+//! Удаление учётной записи и выгрузка персональных данных.
+//!
+//! При удалении пользователь выбирает, что станет с его публикациями и
+//! комментариями: они удаляются вместе с учётной записью или передаются
+//! служебной учётной записи «удалённый пользователь».
+
+use crate::{
+    domain::{
+        post::Post,
+        role::Role,
+        types::{DataId, Email, UserPassword, Username},
+        user::User,
+    },
+    errors::DomainError,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+use tokio_stream::wrappers::ReceiverStream;
+
+/// Что сделать с публикациями и комментариями удаляемой учётной записи.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum DeletionMode {
+    /// Удалить вместе с учётной записью.
+    Delete,
+    /// Сохранить, передав авторство служебной учётной записи.
+    Anonymize,
+}
+
+impl Display for DeletionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mode = match self {
+            DeletionMode::Delete => "delete",
+            DeletionMode::Anonymize => "anonymize",
+        };
+        f.write_str(mode)
+    }
+}
+
+impl FromStr for DeletionMode {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "delete" => Ok(DeletionMode::Delete),
+            "anonymize" => Ok(DeletionMode::Anonymize),
+            other => Err(DomainError::invalid_deletion_mode(format!(
+                "ожидается 'delete' или 'anonymize', получено '{other}'"
+            ))),
+        }
+    }
+}
+
+/// Удаление учётной записи её владельцем.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DeleteAccount {
+    /// Текущий пароль для подтверждения.
+    pub(crate) password: UserPassword,
+    /// Что сделать с публикациями и комментариями.
+    pub(crate) posts: DeletionMode,
+}
+
+/// Персональные данные учётной записи для выгрузки.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct AccountData {
+    /// Id пользователя.
+    pub(crate) id: Option<DataId>,
+    /// Имя пользователя.
+    pub(crate) username: Username,
+    /// Адрес электронной почты.
+    pub(crate) email: Email,
+    /// Отображаемое имя.
+    pub(crate) display_name: String,
+    /// Информация о себе.
+    pub(crate) bio: String,
+    /// Адрес аватара.
+    pub(crate) avatar_url: String,
+    /// Роль пользователя.
+    pub(crate) role: Role,
+    /// Время регистрации.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) created_at: DateTime<Utc>,
+    /// Время подтверждения адреса электронной почты.
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub(crate) email_verified_at: Option<DateTime<Utc>>,
+}
+
+impl From<User> for AccountData {
+    fn from(u: User) -> Self {
+        Self {
+            id: u.id,
+            username: u.username,
+            email: u.email,
+            display_name: u.display_name,
+            bio: u.bio,
+            avatar_url: u.avatar_url,
+            role: u.role,
+            created_at: u.created_at,
+            email_verified_at: u.email_verified_at,
+        }
+    }
+}
+
+/// Выгрузка персональных данных: профиль и все публикации пользователя,
+/// включая черновики и записи в корзине.
+pub(crate) struct AccountExport {
+    /// Данные учётной записи.
+    pub(crate) account: AccountData,
+    /// Поток публикаций пользователя, от старых к новым. Публикации
+    /// читаются из хранилища страницами по мере чтения потока; ошибка
+    /// чтения завершает поток.
+    pub(crate) posts: ReceiverStream<Result<Post, DomainError>>,
+    /// Время выгрузки.
+    pub(crate) exported_at: DateTime<Utc>,
+}
+
+/// Имя файла выгрузки персональных данных пользователя `username`.
+pub(crate) fn export_file_name(username: &Username) -> String {
+    format!("blog-export-{username}.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deletion_mode_from_str_and_display() {
+        for mode in [DeletionMode::Delete, DeletionMode::Anonymize] {
+            assert_eq!(mode.to_string().parse::<DeletionMode>().unwrap(), mode);
+        }
+        assert!(matches!(
+            "keep".parse::<DeletionMode>(),
+            Err(DomainError::InvalidDeletionMode(_))
+        ));
+    }
+
+    #[test]
+    fn delete_account_from_json() {
+        let cmd: DeleteAccount =
+            serde_json::from_str(r#"{"password": "My_secret_Password", "posts": "anonymize"}"#)
+                .unwrap();
+        assert_eq!(cmd.posts, DeletionMode::Anonymize);
+
+        let unknown = serde_json::from_str::<DeleteAccount>(
+            r#"{"password": "My_secret_Password", "posts": "keep"}"#,
+        );
+        assert!(unknown.is_err());
+    }
+}
@@ -0,0 +1,416 @@
+//! Модели для вложений публикаций: изображений и файлов.
+//!
+//! Тип файла определяется по содержимому (сигнатуре), а не по имени или
+//! заявленному клиентом `Content-Type`. У изображений дополнительно
+//! читаются и проверяются размеры в пикселях.
+
+use crate::{
+    domain::types::{DataId, StorageKey},
+    errors::DomainError,
+    settings::{ATTACHMENT_FILE_NAME_MAX_CHARS, IMAGE_MAX_SIDE_PX, UPLOADS_URL_PATH},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Поддерживаемый тип файла вложения.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MediaType {
+    /// Изображение PNG.
+    Png,
+    /// Изображение JPEG.
+    Jpeg,
+    /// Изображение GIF.
+    Gif,
+    /// Изображение WebP.
+    Webp,
+    /// Документ PDF.
+    Pdf,
+}
+
+impl MediaType {
+    /// Определить тип файла по сигнатуре в начале содержимого.
+    pub(crate) fn sniff(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(MediaType::Png)
+        } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(MediaType::Jpeg)
+        } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            Some(MediaType::Gif)
+        } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+            Some(MediaType::Webp)
+        } else if data.starts_with(b"%PDF-") {
+            Some(MediaType::Pdf)
+        } else {
+            None
+        }
+    }
+
+    /// Значение `Content-Type` для файлов этого типа.
+    pub(crate) fn content_type(self) -> &'static str {
+        match self {
+            MediaType::Png => "image/png",
+            MediaType::Jpeg => "image/jpeg",
+            MediaType::Gif => "image/gif",
+            MediaType::Webp => "image/webp",
+            MediaType::Pdf => "application/pdf",
+        }
+    }
+
+    /// Расширение файла для ключа в хранилище.
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            MediaType::Png => "png",
+            MediaType::Jpeg => "jpg",
+            MediaType::Gif => "gif",
+            MediaType::Webp => "webp",
+            MediaType::Pdf => "pdf",
+        }
+    }
+
+    /// Является ли файл изображением.
+    pub(crate) fn is_image(self) -> bool {
+        !matches!(self, MediaType::Pdf)
+    }
+
+    /// Прочитать размеры изображения (ширину и высоту в пикселях) из
+    /// заголовка файла. Для прочих типов и повреждённых заголовков
+    /// возвращается `None`.
+    pub(crate) fn image_size(self, data: &[u8]) -> Option<(u32, u32)> {
+        match self {
+            MediaType::Png => png_size(data),
+            MediaType::Jpeg => jpeg_size(data),
+            MediaType::Gif => gif_size(data),
+            MediaType::Webp => webp_size(data),
+            MediaType::Pdf => None,
+        }
+    }
+}
+
+/// Вложение, сохранённое в хранилище.
+#[derive(Clone, Debug)]
+pub(crate) struct Attachment {
+    /// Уникальный id вложения.
+    pub(crate) id: DataId,
+    /// Id пользователя, загрузившего файл.
+    pub(crate) owner_id: DataId,
+    /// Ключ файла в хранилище.
+    pub(crate) storage_key: StorageKey,
+    /// Исходное имя файла.
+    pub(crate) file_name: String,
+    /// Тип содержимого, определённый по сигнатуре файла.
+    pub(crate) content_type: String,
+    /// Размер файла в байтах.
+    pub(crate) size: i64,
+    /// Ширина изображения в пикселях (у прочих файлов нет).
+    pub(crate) width: Option<i32>,
+    /// Высота изображения в пикселях (у прочих файлов нет).
+    pub(crate) height: Option<i32>,
+    /// Время загрузки.
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    /// Постоянный адрес вложения относительно адреса сервера.
+    pub(crate) fn url(&self) -> String {
+        format!("{UPLOADS_URL_PATH}/{}", self.storage_key)
+    }
+}
+
+/// Проверенный файл для сохранения в хранилище.
+#[derive(Clone, Debug)]
+pub(crate) struct NewAttachment {
+    /// Id пользователя, загрузившего файл.
+    pub(crate) owner_id: DataId,
+    /// Ключ файла в хранилище.
+    pub(crate) storage_key: StorageKey,
+    /// Исходное имя файла (без пути).
+    pub(crate) file_name: String,
+    /// Тип файла.
+    pub(crate) media_type: MediaType,
+    /// Размер файла в байтах.
+    pub(crate) size: i64,
+    /// Ширина и высота изображения в пикселях.
+    pub(crate) dimensions: Option<(i32, i32)>,
+}
+
+impl NewAttachment {
+    /// Проверить загруженный файл: размер не больше `max_bytes`, тип
+    /// поддерживается, размеры изображения читаются и не превышают
+    /// [`IMAGE_MAX_SIDE_PX`].
+    pub(crate) fn from_upload(
+        owner_id: DataId,
+        file_name: &str,
+        data: &[u8],
+        max_bytes: usize,
+    ) -> Result<Self, DomainError> {
+        if data.is_empty() {
+            return Err(DomainError::invalid_attachment("файл пуст"));
+        }
+        if data.len() > max_bytes {
+            return Err(DomainError::attachment_too_large(format!(
+                "допустимый размер файла — не более {max_bytes} байт"
+            )));
+        }
+
+        let media_type = MediaType::sniff(data).ok_or_else(|| {
+            DomainError::invalid_attachment(
+                "неподдерживаемый тип файла, допустимы PNG, JPEG, GIF, WebP и PDF",
+            )
+        })?;
+
+        let dimensions = if media_type.is_image() {
+            let (width, height) = media_type.image_size(data).ok_or_else(|| {
+                DomainError::invalid_attachment("не удалось определить размеры изображения")
+            })?;
+            if width == 0 || height == 0 {
+                return Err(DomainError::invalid_attachment(
+                    "изображение не может быть пустым",
+                ));
+            }
+            if width > IMAGE_MAX_SIDE_PX || height > IMAGE_MAX_SIDE_PX {
+                return Err(DomainError::invalid_attachment(format!(
+                    "ширина и высота изображения — не более {IMAGE_MAX_SIDE_PX} пикселей"
+                )));
+            }
+            Some((width as i32, height as i32))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            owner_id,
+            storage_key: StorageKey::generate(media_type.extension()),
+            file_name: clean_file_name(file_name, media_type),
+            media_type,
+            size: data.len() as i64,
+            dimensions,
+        })
+    }
+}
+
+/// Dto-структура вложения для ответов сервера.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct AttachmentDto {
+    /// Уникальный id вложения.
+    pub(crate) id: DataId,
+    /// Id пользователя, загрузившего файл.
+    pub(crate) owner_id: DataId,
+    /// Постоянный адрес файла относительно адреса сервера.
+    pub(crate) url: String,
+    /// Исходное имя файла.
+    pub(crate) file_name: String,
+    /// Тип содержимого.
+    pub(crate) content_type: String,
+    /// Размер файла в байтах.
+    pub(crate) size: i64,
+    /// Ширина изображения в пикселях.
+    pub(crate) width: Option<i32>,
+    /// Высота изображения в пикселях.
+    pub(crate) height: Option<i32>,
+    /// Время загрузки.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl From<Attachment> for AttachmentDto {
+    fn from(a: Attachment) -> Self {
+        Self {
+            url: a.url(),
+            id: a.id,
+            owner_id: a.owner_id,
+            file_name: a.file_name,
+            content_type: a.content_type,
+            size: a.size,
+            width: a.width,
+            height: a.height,
+            created_at: a.created_at,
+        }
+    }
+}
+
+/// Имя файла без пути и управляющих символов, не длиннее
+/// [`ATTACHMENT_FILE_NAME_MAX_CHARS`]. Пустое имя заменяется на
+/// `file.<расширение>`.
+fn clean_file_name(file_name: &str, media_type: MediaType) -> String {
+    let base = file_name.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned: String = base
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(ATTACHMENT_FILE_NAME_MAX_CHARS)
+        .collect();
+    let cleaned = cleaned.trim();
+
+    if cleaned.is_empty() || cleaned == "." || cleaned == ".." {
+        format!("file.{}", media_type.extension())
+    } else {
+        cleaned.to_string()
+    }
+}
+
+/// Число из двух байт в порядке big-endian, начиная с `at`.
+fn be_u16(data: &[u8], at: usize) -> Option<u32> {
+    let bytes = data.get(at..at + 2)?;
+    Some(u32::from(u16::from_be_bytes([bytes[0], bytes[1]])))
+}
+
+/// Число из двух байт в порядке little-endian, начиная с `at`.
+fn le_u16(data: &[u8], at: usize) -> Option<u32> {
+    let bytes = data.get(at..at + 2)?;
+    Some(u32::from(u16::from_le_bytes([bytes[0], bytes[1]])))
+}
+
+/// Число из трёх байт в порядке little-endian, начиная с `at`.
+fn le_u24(data: &[u8], at: usize) -> Option<u32> {
+    let bytes = data.get(at..at + 3)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]))
+}
+
+/// Размеры PNG: первый блок после сигнатуры — `IHDR` с шириной и высотой.
+fn png_size(data: &[u8]) -> Option<(u32, u32)> {
+    if data.get(12..16)? != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(data.get(16..20)?.try_into().ok()?);
+    let height = u32::from_be_bytes(data.get(20..24)?.try_into().ok()?);
+
+    Some((width, height))
+}
+
+/// Размеры GIF из дескриптора логического экрана.
+fn gif_size(data: &[u8]) -> Option<(u32, u32)> {
+    Some((le_u16(data, 6)?, le_u16(data, 8)?))
+}
+
+/// Размеры JPEG из первого маркера начала кадра (`SOFn`).
+fn jpeg_size(data: &[u8]) -> Option<(u32, u32)> {
+    let mut pos = 2;
+
+    loop {
+        if *data.get(pos)? != 0xFF {
+            return None;
+        }
+        let marker = *data.get(pos + 1)?;
+        match marker {
+            // Заполнитель перед маркером.
+            0xFF => pos += 1,
+            // Маркеры без данных.
+            0x01 | 0xD0..=0xD7 => pos += 2,
+            // Начало кадра; DHT (C4), JPG (C8) и DAC (CC) к ним не относятся.
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                let height = be_u16(data, pos + 5)?;
+                let width = be_u16(data, pos + 7)?;
+                return Some((width, height));
+            }
+            // Начало сжатых данных или конец изображения до кадра.
+            0xD9 | 0xDA => return None,
+            _ => pos += 2 + be_u16(data, pos + 2)? as usize,
+        }
+    }
+}
+
+/// Размеры WebP из первого блока: `VP8 ` (с потерями), `VP8L` (без потерь)
+/// или `VP8X` (расширенный формат).
+fn webp_size(data: &[u8]) -> Option<(u32, u32)> {
+    match data.get(12..16)? {
+        b"VP8 " => {
+            if data.get(23..26)? != [0x9D, 0x01, 0x2A] {
+                return None;
+            }
+            Some((le_u16(data, 26)? & 0x3FFF, le_u16(data, 28)? & 0x3FFF))
+        }
+        b"VP8L" => {
+            if *data.get(20)? != 0x2F {
+                return None;
+            }
+            let bits = u32::from_le_bytes(data.get(21..25)?.try_into().ok()?);
+            Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
+        }
+        b"VP8X" => Some((le_u24(data, 24)? + 1, le_u24(data, 27)? + 1)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Минимальный заголовок PNG с указанными размерами.
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut data = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+        data.extend_from_slice(&width.to_be_bytes());
+        data.extend_from_slice(&height.to_be_bytes());
+        data.extend_from_slice(&[8, 6, 0, 0, 0]);
+        data
+    }
+
+    #[test]
+    fn sniffs_by_content() {
+        assert_eq!(MediaType::sniff(&png(1, 1)), Some(MediaType::Png));
+        assert_eq!(
+            MediaType::sniff(b"GIF89a\x02\x00\x03\x00"),
+            Some(MediaType::Gif)
+        );
+        assert_eq!(MediaType::sniff(b"%PDF-1.7\n"), Some(MediaType::Pdf));
+        assert_eq!(MediaType::sniff(b"<svg onload=alert(1)>"), None);
+        assert_eq!(MediaType::sniff(b""), None);
+    }
+
+    #[test]
+    fn reads_image_sizes() {
+        assert_eq!(MediaType::Png.image_size(&png(640, 480)), Some((640, 480)));
+        assert_eq!(
+            MediaType::Gif.image_size(b"GIF89a\x02\x01\x03\x00"),
+            Some((258, 3))
+        );
+
+        // APP0 (JFIF), затем SOF0 с высотой 0x0100 и шириной 0x0200.
+        let jpeg = [
+            0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x4A, 0x46, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x01,
+            0x00, 0x02, 0x00, 0x03,
+        ];
+        assert_eq!(MediaType::Jpeg.image_size(&jpeg), Some((512, 256)));
+        assert_eq!(MediaType::Jpeg.image_size(&jpeg[..12]), None);
+
+        let mut webp = b"RIFF\x00\x00\x00\x00WEBPVP8X\x0a\x00\x00\x00\x00\x00\x00\x00".to_vec();
+        webp.extend_from_slice(&[0x7F, 0x02, 0x00, 0xDF, 0x01, 0x00]);
+        assert_eq!(MediaType::Webp.image_size(&webp), Some((640, 480)));
+    }
+
+    #[test]
+    fn from_upload_checks_limits() {
+        let owner = DataId(1);
+
+        let ok = NewAttachment::from_upload(owner.clone(), "C:\\photos\\кот.png", &png(2, 3), 1024)
+            .unwrap();
+        assert_eq!(ok.media_type, MediaType::Png);
+        assert_eq!(ok.file_name, "кот.png");
+        assert_eq!(ok.dimensions, Some((2, 3)));
+        assert!(ok.storage_key.as_ref().ends_with(".png"));
+
+        let pdf = NewAttachment::from_upload(owner.clone(), "", b"%PDF-1.4", 1024).unwrap();
+        assert_eq!(pdf.file_name, "file.pdf");
+        assert_eq!(pdf.dimensions, None);
+
+        assert!(matches!(
+            NewAttachment::from_upload(owner.clone(), "a.png", &png(2, 3), 8),
+            Err(DomainError::AttachmentTooLarge(_))
+        ));
+        assert!(matches!(
+            NewAttachment::from_upload(
+                owner.clone(),
+                "a.png",
+                &png(IMAGE_MAX_SIDE_PX + 1, 1),
+                1024
+            ),
+            Err(DomainError::InvalidAttachment(_))
+        ));
+        assert!(matches!(
+            NewAttachment::from_upload(owner.clone(), "a.png", &png(0, 10), 1024),
+            Err(DomainError::InvalidAttachment(_))
+        ));
+        assert!(matches!(
+            NewAttachment::from_upload(owner, "a.txt", b"hello", 1024),
+            Err(DomainError::InvalidAttachment(_))
+        ));
+    }
+}
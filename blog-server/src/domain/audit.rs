@@ -0,0 +1,373 @@
+//! Журнал аудита: кто, когда и откуда выполнил значимое для безопасности
+//! или изменяющее данные действие.
+//!
+//! Записи журнала только добавляются. Кроме участника и действия в запись
+//! попадают сведения о запросе ([`RequestContext`]) и краткое состояние
+//! объекта до и после изменения.
+
+use crate::{
+    domain::{post::Post, types::DataId, user::User},
+    errors::DomainError,
+    settings::{AUDIT_REQUEST_ID_MAX_CHARS, AUDIT_USER_AGENT_MAX_CHARS},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{fmt::Display, net::IpAddr, str::FromStr};
+
+/// Действие, записываемое в журнал аудита.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AuditAction {
+    /// Регистрация пользователя.
+    UserRegistered,
+    /// Успешный вход.
+    LoginSucceeded,
+    /// Неудачная попытка входа.
+    LoginFailed,
+    /// Создание публикации.
+    PostCreated,
+    /// Изменение публикации.
+    PostUpdated,
+    /// Удаление публикации (перемещение в корзину).
+    PostDeleted,
+    /// Публикация записи автором, сразу или по расписанию.
+    PostPublished,
+    /// Скрытие публикации модератором.
+    PostHidden,
+    /// Возврат скрытой публикации читателям.
+    PostUnhidden,
+    /// Одобрение публикации из очереди модерации.
+    PostApproved,
+    /// Отклонение публикации из очереди модерации.
+    PostRejected,
+    /// Восстановление публикации из корзины.
+    PostRestored,
+    /// Восстановление публикации из ревизии.
+    PostRevisionRestored,
+}
+
+impl AuditAction {
+    /// Все действия журнала.
+    pub(crate) const ALL: [AuditAction; 13] = [
+        AuditAction::UserRegistered,
+        AuditAction::LoginSucceeded,
+        AuditAction::LoginFailed,
+        AuditAction::PostCreated,
+        AuditAction::PostUpdated,
+        AuditAction::PostDeleted,
+        AuditAction::PostPublished,
+        AuditAction::PostHidden,
+        AuditAction::PostUnhidden,
+        AuditAction::PostApproved,
+        AuditAction::PostRejected,
+        AuditAction::PostRestored,
+        AuditAction::PostRevisionRestored,
+    ];
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let action = match self {
+            AuditAction::UserRegistered => "user_registered",
+            AuditAction::LoginSucceeded => "login_succeeded",
+            AuditAction::LoginFailed => "login_failed",
+            AuditAction::PostCreated => "post_created",
+            AuditAction::PostUpdated => "post_updated",
+            AuditAction::PostDeleted => "post_deleted",
+            AuditAction::PostPublished => "post_published",
+            AuditAction::PostHidden => "post_hidden",
+            AuditAction::PostUnhidden => "post_unhidden",
+            AuditAction::PostApproved => "post_approved",
+            AuditAction::PostRejected => "post_rejected",
+            AuditAction::PostRestored => "post_restored",
+            AuditAction::PostRevisionRestored => "post_revision_restored",
+        };
+        f.write_str(action)
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|action| action.to_string() == s)
+            .ok_or_else(|| DomainError::invalid_audit_filter(format!("неизвестное действие '{s}'")))
+    }
+}
+
+/// Объект, над которым выполнено действие.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum AuditTarget {
+    /// Учётная запись пользователя.
+    User(DataId),
+    /// Публикация.
+    Post(DataId),
+}
+
+impl Display for AuditTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditTarget::User(id) => write!(f, "user:{id}"),
+            AuditTarget::Post(id) => write!(f, "post:{id}"),
+        }
+    }
+}
+
+/// Сведения о запросе, в рамках которого выполнено действие.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct RequestContext {
+    /// IP-адрес клиента.
+    pub(crate) ip: Option<IpAddr>,
+    /// Заголовок `User-Agent`.
+    pub(crate) user_agent: Option<String>,
+    /// Id запроса из заголовка `X-Request-Id`.
+    pub(crate) request_id: Option<String>,
+}
+
+impl RequestContext {
+    /// Создать сведения о запросе. Пустые значения отбрасываются, длинные
+    /// обрезаются до [`AUDIT_USER_AGENT_MAX_CHARS`] и
+    /// [`AUDIT_REQUEST_ID_MAX_CHARS`] символов.
+    pub(crate) fn new(
+        ip: Option<IpAddr>,
+        user_agent: Option<&str>,
+        request_id: Option<&str>,
+    ) -> Self {
+        Self {
+            ip,
+            user_agent: truncated(user_agent, AUDIT_USER_AGENT_MAX_CHARS),
+            request_id: truncated(request_id, AUDIT_REQUEST_ID_MAX_CHARS),
+        }
+    }
+}
+
+/// Непустое значение, обрезанное до `max_chars` символов.
+fn truncated(value: Option<&str>, max_chars: usize) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.chars().take(max_chars).collect())
+}
+
+/// Новая запись журнала аудита.
+#[derive(Clone, Debug)]
+pub(crate) struct NewAuditEvent {
+    /// Id участника; отсутствует, если участник не опознан.
+    pub(crate) actor_id: Option<DataId>,
+    /// Имя участника на момент действия.
+    pub(crate) actor: String,
+    /// Действие.
+    pub(crate) action: AuditAction,
+    /// Объект действия.
+    pub(crate) target: Option<AuditTarget>,
+    /// Сведения о запросе.
+    pub(crate) context: RequestContext,
+    /// Краткое состояние объекта до действия.
+    pub(crate) before: Option<Value>,
+    /// Краткое состояние объекта после действия.
+    pub(crate) after: Option<Value>,
+}
+
+impl NewAuditEvent {
+    /// Создать запись о действии `action` участника `actor`.
+    pub(crate) fn new(
+        action: AuditAction,
+        actor_id: Option<DataId>,
+        actor: impl Into<String>,
+        context: &RequestContext,
+    ) -> Self {
+        Self {
+            actor_id,
+            actor: actor.into(),
+            action,
+            target: None,
+            context: context.clone(),
+            before: None,
+            after: None,
+        }
+    }
+
+    /// Указать объект действия.
+    pub(crate) fn with_target(mut self, target: AuditTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Указать состояние объекта до и после действия.
+    pub(crate) fn with_changes(mut self, before: Option<Value>, after: Option<Value>) -> Self {
+        self.before = before;
+        self.after = after;
+        self
+    }
+}
+
+/// Краткое состояние учётной записи для журнала аудита (без пароля).
+pub(crate) fn user_summary(user: &User) -> Value {
+    json!({
+        "username": user.username,
+        "email": user.email,
+        "role": user.role,
+    })
+}
+
+/// Краткое состояние публикации для журнала аудита (без текста).
+pub(crate) fn post_summary(post: &Post) -> Value {
+    json!({
+        "title": post.title,
+        "slug": post.slug,
+        "status": post.status,
+        "tags": post.tags,
+        "deleted": post.deleted_at.is_some(),
+    })
+}
+
+/// Запись журнала аудита.
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct AuditEvent {
+    /// Id записи.
+    pub(crate) id: DataId,
+    /// Id участника.
+    pub(crate) actor_id: Option<DataId>,
+    /// Имя участника на момент действия.
+    pub(crate) actor: String,
+    /// Действие.
+    pub(crate) action: AuditAction,
+    /// Объект действия (`user:{id}`, `post:{id}`).
+    pub(crate) target: Option<String>,
+    /// IP-адрес клиента.
+    pub(crate) ip: Option<String>,
+    /// Заголовок `User-Agent`.
+    pub(crate) user_agent: Option<String>,
+    /// Id запроса.
+    pub(crate) request_id: Option<String>,
+    /// Краткое состояние объекта до действия.
+    pub(crate) before: Option<Value>,
+    /// Краткое состояние объекта после действия.
+    pub(crate) after: Option<Value>,
+    /// Время действия.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+/// Фильтры и пагинация выборки из журнала аудита.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AuditFilter {
+    /// Имя участника.
+    pub(crate) actor: Option<String>,
+    /// Действие.
+    pub(crate) action: Option<AuditAction>,
+    /// Объект действия (`user:{id}`, `post:{id}`).
+    pub(crate) target: Option<String>,
+    /// Начало периода (включительно).
+    pub(crate) since: Option<DateTime<Utc>>,
+    /// Конец периода (не включительно).
+    pub(crate) until: Option<DateTime<Utc>>,
+    /// Размер страницы.
+    pub(crate) limit: i32,
+    /// Сдвиг страницы.
+    pub(crate) offset: i32,
+}
+
+impl AuditFilter {
+    /// Проверить, что период задан корректно.
+    ///
+    /// ## Errors
+    ///
+    /// [`DomainError::InvalidAuditFilter`], если начало периода не раньше
+    /// его конца.
+    pub(crate) fn validate(&self) -> Result<(), DomainError> {
+        if let (Some(since), Some(until)) = (self.since, self.until)
+            && since >= until
+        {
+            return Err(DomainError::invalid_audit_filter(
+                "начало периода должно быть раньше его конца",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Dto-структура query-параметров выборки из журнала аудита.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QueryAuditEvents {
+    /// Имя участника.
+    pub(crate) actor: Option<String>,
+    /// Действие.
+    pub(crate) action: Option<AuditAction>,
+    /// Объект действия.
+    pub(crate) target: Option<String>,
+    /// Начало периода (unix-время, секунды).
+    pub(crate) since: Option<i64>,
+    /// Конец периода (unix-время, секунды).
+    pub(crate) until: Option<i64>,
+    /// Количество возвращаемых записей.
+    pub(crate) limit: Option<u32>,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: Option<u32>,
+}
+
+/// Страница журнала аудита, от новых записей к старым.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ListAuditEvents {
+    /// Записи журнала.
+    pub(crate) events: Vec<AuditEvent>,
+    /// Общее количество записей, подходящих под фильтры.
+    pub(crate) total: i64,
+    /// Размер страницы.
+    pub(crate) limit: i32,
+    /// Сдвиг страницы.
+    pub(crate) offset: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn audit_action_from_str_and_display() {
+        for action in AuditAction::ALL {
+            assert_eq!(action.to_string().parse::<AuditAction>().unwrap(), action);
+            assert_eq!(
+                serde_json::to_value(action).unwrap(),
+                Value::String(action.to_string())
+            );
+        }
+        assert!(matches!(
+            "post_liked".parse::<AuditAction>(),
+            Err(DomainError::InvalidAuditFilter(_))
+        ));
+    }
+
+    #[test]
+    fn request_context_drops_empty_and_truncates() {
+        let long_agent = "a".repeat(AUDIT_USER_AGENT_MAX_CHARS + 10);
+        let ctx = RequestContext::new(None, Some(&long_agent), Some("  "));
+
+        assert_eq!(
+            ctx.user_agent.map(|ua| ua.chars().count()),
+            Some(AUDIT_USER_AGENT_MAX_CHARS)
+        );
+        assert_eq!(ctx.request_id, None);
+    }
+
+    #[test]
+    fn audit_filter_period() {
+        let now = Utc::now();
+        let mut filter = AuditFilter {
+            since: Some(now),
+            until: Some(now + Duration::hours(1)),
+            ..Default::default()
+        };
+        assert!(filter.validate().is_ok());
+
+        filter.until = Some(now);
+        assert!(matches!(
+            filter.validate(),
+            Err(DomainError::InvalidAuditFilter(_))
+        ));
+    }
+}
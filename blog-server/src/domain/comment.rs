@@ -0,0 +1,103 @@
+//! Модели для комментариев к публикациям.
+
+use crate::domain::types::{CommentContent, DataId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Структура комментария к публикации (посту).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Comment {
+    /// Уникальный id комментария. Допускается `None` при создании экземпляра
+    /// перед сохранением в базу данных.
+    pub(crate) id: Option<DataId>,
+    /// Id публикации, к которой относится комментарий.
+    pub(crate) post_id: DataId,
+    /// Id автора комментария.
+    pub(crate) author_id: DataId,
+    /// Текст комментария.
+    pub(crate) content: CommentContent,
+    /// Время создания комментария.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl Comment {
+    /// Создание экземпляра [`Comment`] на основе предоставленных данных.
+    ///
+    /// Если `created_at` не передано, конструктор самостоятельно создаёт
+    /// временную метку на основе текущего времени UTC.
+    pub(crate) fn new(
+        comment_id: Option<DataId>,
+        post_id: DataId,
+        author_id: DataId,
+        content: CommentContent,
+        created_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        let created_at = created_at.unwrap_or_else(Utc::now);
+
+        Self {
+            id: comment_id,
+            post_id,
+            author_id,
+            content,
+            created_at,
+        }
+    }
+
+    /// Создать новый экземпляр [`Comment`] с помощью [`CreateComment`].
+    ///
+    /// Временная метка проставляется автоматически.
+    pub(crate) fn new_by_create(
+        comment: CreateComment,
+        post_id: DataId,
+        author_id: DataId,
+    ) -> Self {
+        Comment::new(None, post_id, author_id, comment.content, None)
+    }
+
+    /// Проверяет совпадение автора комментария с ID пользователя.
+    pub(crate) fn is_author(&self, user_id: &DataId) -> bool {
+        self.author_id.eq(user_id)
+    }
+}
+
+/// Перечень комментариев к публикации с пагинацией.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct ListComments {
+    /// Перечень комментариев.
+    pub(crate) comments: Vec<Comment>,
+    /// Общее количество комментариев к публикации.
+    pub(crate) total: i64,
+    /// Количество выгруженных записей.
+    pub(crate) limit: i32,
+    /// Сдвиг.
+    pub(crate) offset: i32,
+}
+
+impl ListComments {
+    /// Сформировать экземпляр [`ListComments`].
+    pub(crate) fn new(comments: Vec<Comment>, total: i64, limit: i32, offset: i32) -> Self {
+        Self {
+            comments,
+            total,
+            limit,
+            offset,
+        }
+    }
+}
+
+/// Dto-структура для создания комментария.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct CreateComment {
+    /// Текст комментария.
+    pub(crate) content: CommentContent,
+}
+
+/// Dto-структура query-параметров для извлечения перечня комментариев.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QueryComments {
+    /// Количество возвращаемых записей.
+    pub(crate) limit: Option<u32>,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: Option<u32>,
+}
@@ -0,0 +1,49 @@
+//! Модели для подписок на авторов.
+
+use crate::domain::types::Username;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Пользователь в перечне подписчиков или подписок.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct FollowUser {
+    /// Имя пользователя.
+    pub(crate) username: Username,
+    /// Время оформления подписки.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) followed_at: DateTime<Utc>,
+}
+
+/// Перечень подписчиков или подписок пользователя.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct ListFollows {
+    /// Пользователи, от недавно подписавшихся.
+    pub(crate) users: Vec<FollowUser>,
+    /// Общее количество пользователей в перечне.
+    pub(crate) total: i64,
+    /// Количество выгруженных записей.
+    pub(crate) limit: i32,
+    /// Сдвиг.
+    pub(crate) offset: i32,
+}
+
+impl ListFollows {
+    /// Сформировать экземпляр [`ListFollows`].
+    pub(crate) fn new(users: Vec<FollowUser>, total: i64, limit: i32, offset: i32) -> Self {
+        Self {
+            users,
+            total,
+            limit,
+            offset,
+        }
+    }
+}
+
+/// Dto-структура query-параметров для извлечения подписчиков и подписок.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QueryFollows {
+    /// Количество возвращаемых записей.
+    pub(crate) limit: Option<u32>,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: Option<u32>,
+}
@@ -0,0 +1,197 @@
+//! Защита от подбора пароля.
+//!
+//! Неудачные попытки входа учитываются по учётной записи и по IP-адресу.
+//! После каждой неудачи следующая попытка для учётной записи возможна лишь
+//! через экспоненциально растущую задержку, а после
+//! [`LoginPolicy::max_failures`] неудач подряд вход блокируется на время
+//! [`LoginPolicy::lockout`]. Блокировку снимает истечение этого времени или
+//! администратор.
+
+use crate::{
+    domain::{
+        types::{DataId, Username},
+        user::User,
+    },
+    errors::DomainError,
+    settings::{
+        LOGIN_BACKOFF_BASE_SECS, LOGIN_BACKOFF_MAX_SECS, LOGIN_IP_MAX_FAILURES, LOGIN_IP_WINDOW,
+    },
+};
+use chrono::{DateTime, Duration, Utc};
+use std::net::IpAddr;
+
+/// Правила блокировки входа.
+#[derive(Debug, Clone)]
+pub(crate) struct LoginPolicy {
+    /// Количество неудачных попыток подряд, после которого вход блокируется.
+    pub(crate) max_failures: i32,
+    /// Длительность блокировки входа.
+    pub(crate) lockout: Duration,
+}
+
+impl LoginPolicy {
+    /// Создать правила блокировки входа.
+    pub(crate) fn new(max_failures: u32, lockout: Duration) -> Self {
+        Self {
+            max_failures: i32::try_from(max_failures).unwrap_or(i32::MAX),
+            lockout,
+        }
+    }
+
+    /// Проверить, что пользователь может попытаться войти в момент `now`.
+    ///
+    /// ## Errors
+    ///
+    /// - [`DomainError::AccountLocked`], если вход заблокирован;
+    /// - [`DomainError::RateLimited`], если не истекла задержка после
+    ///   предыдущей неудачной попытки.
+    pub(crate) fn check(&self, user: &User, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if let Some(locked_until) = user.locked_until.filter(|until| *until > now) {
+            return Err(DomainError::AccountLocked {
+                retry_after: retry_secs(locked_until - now),
+            });
+        }
+
+        if let Some(last_failed) = user.last_failed_login_at {
+            let next_attempt = last_failed + backoff(user.failed_logins);
+            if next_attempt > now {
+                return Err(DomainError::RateLimited {
+                    retry_after: retry_secs(next_attempt - now),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Время, до которого блокируется вход, если неудачная попытка в момент
+    /// `now` окажется последней допустимой.
+    pub(crate) fn locked_until(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now + self.lockout
+    }
+
+    /// Проверить количество неудачных попыток входа с IP-адреса за
+    /// [`LOGIN_IP_WINDOW`]; `oldest` — время самой ранней из них.
+    ///
+    /// ## Errors
+    ///
+    /// [`DomainError::RateLimited`], если неудач слишком много.
+    pub(crate) fn check_ip(
+        &self,
+        failures: i64,
+        oldest: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if failures < LOGIN_IP_MAX_FAILURES {
+            return Ok(());
+        }
+
+        let retry_after = oldest.map_or(LOGIN_IP_WINDOW, |oldest| oldest + LOGIN_IP_WINDOW - now);
+        Err(DomainError::RateLimited {
+            retry_after: retry_secs(retry_after),
+        })
+    }
+}
+
+/// Задержка перед следующей попыткой входа после `failed_logins` неудач
+/// подряд: удваивается с каждой неудачей, но не больше
+/// [`LOGIN_BACKOFF_MAX_SECS`].
+pub(crate) fn backoff(failed_logins: i32) -> Duration {
+    if failed_logins <= 0 {
+        return Duration::zero();
+    }
+
+    let secs = 1_i64
+        .checked_shl((failed_logins - 1).unsigned_abs())
+        .and_then(|factor| LOGIN_BACKOFF_BASE_SECS.checked_mul(factor))
+        .filter(|secs| *secs > 0)
+        .map_or(LOGIN_BACKOFF_MAX_SECS, |secs| secs.min(LOGIN_BACKOFF_MAX_SECS));
+
+    Duration::seconds(secs)
+}
+
+/// Округлить время ожидания вверх до целых секунд (не меньше 1).
+fn retry_secs(wait: Duration) -> u64 {
+    let secs = (wait.num_milliseconds() + 999) / 1000;
+    u64::try_from(secs).unwrap_or_default().max(1)
+}
+
+/// Запись журнала попыток входа.
+#[derive(Debug, Clone)]
+pub(crate) struct NewLoginAttempt {
+    /// Id пользователя; отсутствует, если имя пользователя не найдено.
+    pub(crate) user_id: Option<DataId>,
+    /// Имя пользователя из запроса.
+    pub(crate) username: Username,
+    /// IP-адрес клиента, если известен.
+    pub(crate) ip: Option<IpAddr>,
+    /// Успешна ли попытка.
+    pub(crate) succeeded: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::Email;
+
+    fn policy() -> LoginPolicy {
+        LoginPolicy::new(5, Duration::minutes(15))
+    }
+
+    fn user(failed_logins: i32, last_failed: Option<DateTime<Utc>>) -> User {
+        User::new(
+            None,
+            Username::try_from("reader".to_string()).unwrap(),
+            Email::try_from("reader@example.com".to_string()).unwrap(),
+            "",
+            None,
+        )
+        .with_login_state(failed_logins, last_failed, None)
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        assert_eq!(backoff(0), Duration::zero());
+        assert_eq!(backoff(1), Duration::seconds(1));
+        assert_eq!(backoff(2), Duration::seconds(2));
+        assert_eq!(backoff(4), Duration::seconds(8));
+        assert_eq!(backoff(10), Duration::seconds(LOGIN_BACKOFF_MAX_SECS));
+        assert_eq!(backoff(100), Duration::seconds(LOGIN_BACKOFF_MAX_SECS));
+    }
+
+    #[test]
+    fn check_waits_for_backoff() {
+        let now = Utc::now();
+        let user = user(3, Some(now));
+
+        assert!(matches!(
+            policy().check(&user, now + Duration::seconds(1)),
+            Err(DomainError::RateLimited { retry_after: 3 })
+        ));
+        assert!(policy().check(&user, now + Duration::seconds(4)).is_ok());
+    }
+
+    #[test]
+    fn check_rejects_locked_account() {
+        let now = Utc::now();
+        let locked = user(0, None).with_login_state(0, None, Some(now + Duration::minutes(15)));
+
+        assert!(matches!(
+            policy().check(&locked, now),
+            Err(DomainError::AccountLocked { retry_after: 900 })
+        ));
+        assert!(policy().check(&locked, now + Duration::minutes(15)).is_ok());
+    }
+
+    #[test]
+    fn check_ip_limits_failures_in_window() {
+        let now = Utc::now();
+        let oldest = Some(now - Duration::minutes(10));
+
+        assert!(policy().check_ip(LOGIN_IP_MAX_FAILURES - 1, oldest, now).is_ok());
+        assert!(matches!(
+            policy().check_ip(LOGIN_IP_MAX_FAILURES, oldest, now),
+            Err(DomainError::RateLimited { retry_after: 300 })
+        ));
+    }
+}
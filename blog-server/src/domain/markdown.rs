@@ -0,0 +1,267 @@
+//! Преобразование содержания публикаций из CommonMark в безопасный HTML.
+//!
+//! HTML формируется по белому списку: выводятся только элементы разметки
+//! CommonMark, встроенный в текст HTML отбрасывается (содержимое `<script>`
+//! и `<style>` — целиком), ссылки с недопустимыми схемами превращаются
+//! в обычный текст, а допустимые получают `rel="nofollow"`.
+
+use crate::settings::MARKDOWN_URL_SCHEMES;
+use pulldown_cmark::{CodeBlockKind, Event, LinkType, Options, Parser, Tag, TagEnd};
+
+/// Отрисовать текст в формате CommonMark в безопасный HTML.
+pub(crate) fn render_html(markdown: &str) -> String {
+    let mut html = String::with_capacity(markdown.len() * 3 / 2);
+    // Открытые ссылки: `true`, если тег `<a>` был выведен.
+    let mut links: Vec<bool> = Vec::new();
+    // Вложенность изображений и признак вывода тега `<img>` для внешнего.
+    let mut image: Option<(usize, bool)> = None;
+    // Внутри встроенного `<script>` или `<style>`: текст пропускается.
+    let mut in_raw_block = false;
+
+    for event in Parser::new_ext(markdown, Options::empty()) {
+        // Содержимое изображения выводится как текст атрибута `alt`.
+        if let Some((depth, with_tag)) = image.as_mut() {
+            match event {
+                Event::Text(text) | Event::Code(text) => escape_into(&mut html, &text),
+                Event::Start(Tag::Image { .. }) => *depth += 1,
+                Event::End(TagEnd::Image) => {
+                    *depth -= 1;
+                    if *depth == 0 {
+                        if *with_tag {
+                            html.push_str("\" />");
+                        }
+                        image = None;
+                    }
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Paragraph => html.push_str("<p>"),
+                Tag::Heading { level, .. } => {
+                    html.push('<');
+                    html.push_str(&level.to_string());
+                    html.push('>');
+                }
+                Tag::BlockQuote(_) => html.push_str("<blockquote>\n"),
+                Tag::CodeBlock(kind) => {
+                    let lang = match &kind {
+                        CodeBlockKind::Fenced(info) => info.split_whitespace().next(),
+                        CodeBlockKind::Indented => None,
+                    };
+                    match lang {
+                        Some(lang) => {
+                            html.push_str("<pre><code class=\"language-");
+                            escape_into(&mut html, lang);
+                            html.push_str("\">");
+                        }
+                        None => html.push_str("<pre><code>"),
+                    }
+                }
+                Tag::List(Some(1)) => html.push_str("<ol>\n"),
+                Tag::List(Some(start)) => html.push_str(&format!("<ol start=\"{start}\">\n")),
+                Tag::List(None) => html.push_str("<ul>\n"),
+                Tag::Item => html.push_str("<li>"),
+                Tag::Emphasis => html.push_str("<em>"),
+                Tag::Strong => html.push_str("<strong>"),
+                Tag::Link {
+                    link_type,
+                    dest_url,
+                    title,
+                    ..
+                } => {
+                    let href = match link_type {
+                        LinkType::Email => format!("mailto:{dest_url}"),
+                        _ => dest_url.to_string(),
+                    };
+                    let allowed = is_allowed_url(&href);
+                    if allowed {
+                        html.push_str("<a href=\"");
+                        escape_into(&mut html, &href);
+                        html.push('"');
+                        if !title.is_empty() {
+                            html.push_str(" title=\"");
+                            escape_into(&mut html, &title);
+                            html.push('"');
+                        }
+                        html.push_str(" rel=\"nofollow\">");
+                    }
+                    links.push(allowed);
+                }
+                Tag::Image {
+                    dest_url, title, ..
+                } => {
+                    let allowed = is_allowed_url(&dest_url);
+                    if allowed {
+                        html.push_str("<img src=\"");
+                        escape_into(&mut html, &dest_url);
+                        html.push('"');
+                        if !title.is_empty() {
+                            html.push_str(" title=\"");
+                            escape_into(&mut html, &title);
+                            html.push('"');
+                        }
+                        html.push_str(" alt=\"");
+                    }
+                    image = Some((1, allowed));
+                }
+                _ => {}
+            },
+            Event::End(tag) => {
+                match tag {
+                    TagEnd::Heading(level) => {
+                        html.push_str("</");
+                        html.push_str(&level.to_string());
+                        html.push_str(">\n");
+                    }
+                    TagEnd::Link => {
+                        if links.pop() == Some(true) {
+                            html.push_str("</a>");
+                        }
+                    }
+                    _ => html.push_str(closing_tag(tag)),
+                }
+
+                // Незакрытый `<script>` не распространяется за пределы блока.
+                if !matches!(
+                    tag,
+                    TagEnd::Emphasis | TagEnd::Strong | TagEnd::Link | TagEnd::Image
+                ) {
+                    in_raw_block = false;
+                }
+            }
+            Event::Text(text) if !in_raw_block => escape_into(&mut html, &text),
+            Event::Code(code) if !in_raw_block => {
+                html.push_str("<code>");
+                escape_into(&mut html, &code);
+                html.push_str("</code>");
+            }
+            Event::InlineHtml(raw) => {
+                if in_raw_block {
+                    in_raw_block = !is_raw_text_tag(&raw, "</");
+                } else {
+                    in_raw_block = is_raw_text_tag(&raw, "<");
+                }
+            }
+            Event::SoftBreak if !in_raw_block => html.push('\n'),
+            Event::HardBreak if !in_raw_block => html.push_str("<br />\n"),
+            Event::Rule => html.push_str("<hr />\n"),
+            // Блочный HTML отбрасывается целиком, вместе с содержимым.
+            _ => {}
+        }
+    }
+
+    html
+}
+
+/// Закрывающий тег элемента разметки. Для элементов вне белого списка
+/// возвращается пустая строка.
+fn closing_tag(tag: TagEnd) -> &'static str {
+    match tag {
+        TagEnd::Paragraph => "</p>\n",
+        TagEnd::BlockQuote(_) => "</blockquote>\n",
+        TagEnd::CodeBlock => "</code></pre>\n",
+        TagEnd::List(true) => "</ol>\n",
+        TagEnd::List(false) => "</ul>\n",
+        TagEnd::Item => "</li>\n",
+        TagEnd::Emphasis => "</em>",
+        TagEnd::Strong => "</strong>",
+        _ => "",
+    }
+}
+
+/// Проверить, что встроенный HTML-тег открывает (`prefix` = `<`) или
+/// закрывает (`</`) элемент `<script>` либо `<style>`.
+fn is_raw_text_tag(raw: &str, prefix: &str) -> bool {
+    let Some(rest) = raw.strip_prefix(prefix) else {
+        return false;
+    };
+    let name: String = rest
+        .chars()
+        .take_while(char::is_ascii_alphabetic)
+        .collect::<String>()
+        .to_ascii_lowercase();
+
+    name == "script" || name == "style"
+}
+
+/// Допустима ли ссылка: без схемы (относительная или якорь) либо со схемой
+/// из [`MARKDOWN_URL_SCHEMES`].
+///
+/// Управляющие и пробельные символы при проверке отбрасываются, поскольку
+/// браузеры игнорируют их в схеме (`java\tscript:`).
+fn is_allowed_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_control() && !c.is_whitespace())
+        .collect();
+
+    match url.find([':', '/', '?', '#']) {
+        Some(pos) if url[pos..].starts_with(':') => MARKDOWN_URL_SCHEMES
+            .iter()
+            .any(|scheme| url[..pos].eq_ignore_ascii_case(scheme)),
+        _ => true,
+    }
+}
+
+/// Добавить текст с экранированием символов HTML (в том числе кавычек,
+/// чтобы результат годился и для значений атрибутов).
+fn escape_into(html: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            _ => html.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_html_commonmark() {
+        let html = render_html("# Заголовок\n\nТекст *с* **акцентом** и `кодом`.\n\n- один\n- два");
+
+        assert_eq!(
+            html,
+            "<h1>Заголовок</h1>\n\
+             <p>Текст <em>с</em> <strong>акцентом</strong> и <code>кодом</code>.</p>\n\
+             <ul>\n<li>один</li>\n<li>два</li>\n</ul>\n"
+        );
+    }
+
+    #[test]
+    fn render_html_strips_scripts_and_unsafe_links() {
+        let html = render_html(
+            "<script>alert(1)</script>\n\n\
+             Текст <style>p {}</style>после, [ссылка](https://example.com) \
+             и [вредная](javascript:alert(1)) ![x](javascript:1)",
+        );
+
+        assert!(!html.contains("script"));
+        assert!(!html.contains("style"));
+        assert!(!html.contains("alert"));
+        assert!(html.contains("<a href=\"https://example.com\" rel=\"nofollow\">ссылка</a>"));
+        assert!(html.contains(" и вредная x</p>"));
+    }
+
+    #[test]
+    fn is_allowed_url_schemes() {
+        assert!(is_allowed_url("https://example.com"));
+        assert!(is_allowed_url("/posts/1"));
+        assert!(is_allowed_url("#top"));
+        assert!(is_allowed_url("mailto:a@b.c"));
+        assert!(is_allowed_url("page?a=b:c"));
+        assert!(!is_allowed_url("JavaScript:alert(1)"));
+        assert!(!is_allowed_url(" java\nscript:alert(1)"));
+        assert!(!is_allowed_url("data:text/html,x"));
+    }
+}
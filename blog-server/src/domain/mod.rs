@@ -1,5 +1,21 @@
+pub(crate) mod account;
+pub(crate) mod attachment;
+pub(crate) mod audit;
+pub(crate) mod comment;
+pub(crate) mod follow;
+pub(crate) mod login;
+pub(crate) mod markdown;
+pub(crate) mod moderation;
+pub(crate) mod password;
 pub(crate) mod types;
 pub(crate) mod post;
+pub(crate) mod reaction;
+pub(crate) mod revision;
+pub(crate) mod role;
+mod slug;
+pub(crate) mod syndication;
+pub(crate) mod token;
 pub(crate) mod user;
+pub(crate) mod verification;
 mod validators;
 mod macros;
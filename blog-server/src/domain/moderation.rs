@@ -0,0 +1,427 @@
+//! Модерация текста публикаций и комментариев по правилам из базы данных.
+//!
+//! Правила компилируются в [`ModerationMatcher`] один раз и подменяются
+//! целиком при изменении списка, поэтому проверка текста не обращается
+//! к базе данных и не пересобирает регулярные выражения.
+
+use crate::{
+    domain::{
+        post::Post,
+        types::{CommentContent, DataId},
+    },
+    errors::DomainError,
+    settings::{MODERATION_PATTERN_MAX_CHARS, MODERATION_REGEX_SIZE_LIMIT},
+};
+use chrono::{DateTime, Utc};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    str::FromStr,
+    sync::{Arc, PoisonError, RwLock},
+};
+use tracing::warn;
+
+/// Вид шаблона правила модерации.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "moderation_rule_kind", rename_all = "lowercase")]
+pub(crate) enum RuleKind {
+    /// Отдельное слово, без учёта регистра.
+    Word,
+    /// Фраза из нескольких слов, разделённых любыми пробелами, без учёта
+    /// регистра.
+    Phrase,
+    /// Регулярное выражение.
+    Regex,
+}
+
+/// Действие при совпадении с правилом модерации.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "moderation_action", rename_all = "lowercase")]
+pub(crate) enum RuleAction {
+    /// Отклонить текст.
+    Reject,
+    /// Отправить публикацию на проверку модератору.
+    Hold,
+}
+
+impl Display for RuleKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            RuleKind::Word => "word",
+            RuleKind::Phrase => "phrase",
+            RuleKind::Regex => "regex",
+        };
+        f.write_str(kind)
+    }
+}
+
+impl FromStr for RuleKind {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "word" => Ok(RuleKind::Word),
+            "phrase" => Ok(RuleKind::Phrase),
+            "regex" => Ok(RuleKind::Regex),
+            other => Err(DomainError::invalid_rule(format!(
+                "неизвестный вид правила '{other}'"
+            ))),
+        }
+    }
+}
+
+impl Display for RuleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let action = match self {
+            RuleAction::Reject => "reject",
+            RuleAction::Hold => "hold",
+        };
+        f.write_str(action)
+    }
+}
+
+impl FromStr for RuleAction {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reject" => Ok(RuleAction::Reject),
+            "hold" => Ok(RuleAction::Hold),
+            other => Err(DomainError::invalid_rule(format!(
+                "неизвестное действие '{other}'"
+            ))),
+        }
+    }
+}
+
+/// Правило модерации.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct ModerationRule {
+    /// Уникальный id правила.
+    pub(crate) id: DataId,
+    /// Шаблон: слово, фраза или регулярное выражение.
+    pub(crate) pattern: String,
+    /// Вид шаблона.
+    pub(crate) kind: RuleKind,
+    /// Действие при совпадении.
+    pub(crate) action: RuleAction,
+    /// Время создания правила.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) created_at: DateTime<Utc>,
+    /// Время последнего изменения правила.
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub(crate) updated_at: Option<DateTime<Utc>>,
+}
+
+/// Новое правило модерации или новая редакция существующего.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct SaveModerationRule {
+    /// Шаблон: слово, фраза или регулярное выражение.
+    pub(crate) pattern: String,
+    /// Вид шаблона.
+    pub(crate) kind: RuleKind,
+    /// Действие при совпадении.
+    pub(crate) action: RuleAction,
+}
+
+impl SaveModerationRule {
+    /// Проверить шаблон правила и привести его к хранимому виду (без
+    /// пробелов по краям).
+    pub(crate) fn validated(self) -> Result<Self, DomainError> {
+        let pattern = self.pattern.trim().to_string();
+        if pattern.is_empty() || pattern.chars().count() > MODERATION_PATTERN_MAX_CHARS {
+            return Err(DomainError::invalid_rule(format!(
+                "допустимая длина шаблона от 1 до {} символов",
+                MODERATION_PATTERN_MAX_CHARS
+            )));
+        }
+
+        match self.kind {
+            RuleKind::Word if pattern.contains(char::is_whitespace) => {
+                return Err(DomainError::invalid_rule(
+                    "слово не должно содержать пробелов, используйте вид 'phrase'",
+                ));
+            }
+            RuleKind::Regex => {
+                compile(&pattern).map_err(DomainError::invalid_rule)?;
+            }
+            _ => {}
+        }
+
+        Ok(Self { pattern, ..self })
+    }
+}
+
+/// Перечень правил модерации.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ListModerationRules {
+    pub(crate) rules: Vec<ModerationRule>,
+}
+
+/// Dto-структура query-параметров для извлечения очереди модерации.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QueryModerationQueue {
+    /// Количество возвращаемых записей.
+    pub(crate) limit: Option<u32>,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: Option<u32>,
+}
+
+/// Результат проверки текста.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Verdict {
+    /// Совпадений нет.
+    Pass,
+    /// Найдено выражение, требующее проверки модератором.
+    Hold(String),
+    /// Найдено запрещённое выражение.
+    Reject(String),
+}
+
+/// Скомпилированные правила модерации.
+#[derive(Debug, Default)]
+pub(crate) struct ModerationMatcher {
+    /// Объединение правил с действием [`RuleAction::Reject`].
+    reject: Option<Regex>,
+    /// Объединение правил с действием [`RuleAction::Hold`].
+    hold: Option<Regex>,
+}
+
+impl ModerationMatcher {
+    /// Скомпилировать правила. Правило, которое не удалось скомпилировать,
+    /// пропускается с предупреждением в журнале.
+    pub(crate) fn new(rules: &[ModerationRule]) -> Self {
+        Self {
+            reject: join_rules(rules, RuleAction::Reject),
+            hold: join_rules(rules, RuleAction::Hold),
+        }
+    }
+
+    /// Проверить текст. Запрещающие правила важнее правил проверки.
+    pub(crate) fn check(&self, text: &str) -> Verdict {
+        if let Some(found) = self.reject.as_ref().and_then(|regex| regex.find(text)) {
+            return Verdict::Reject(found.as_str().to_string());
+        }
+        if let Some(found) = self.hold.as_ref().and_then(|regex| regex.find(text)) {
+            return Verdict::Hold(found.as_str().to_string());
+        }
+
+        Verdict::Pass
+    }
+}
+
+/// Общий для сервисов набор правил модерации, который можно заменить
+/// на лету.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ModerationFilter {
+    matcher: Arc<RwLock<Arc<ModerationMatcher>>>,
+}
+
+impl ModerationFilter {
+    /// Заменить действующие правила.
+    pub(crate) fn replace(&self, matcher: ModerationMatcher) {
+        let mut current = self.matcher.write().unwrap_or_else(PoisonError::into_inner);
+        *current = Arc::new(matcher);
+    }
+
+    /// Предоставить действующие правила.
+    fn matcher(&self) -> Arc<ModerationMatcher> {
+        let current = self.matcher.read().unwrap_or_else(PoisonError::into_inner);
+        Arc::clone(&current)
+    }
+
+    /// Проверить заголовок, содержание и теги публикации.
+    ///
+    /// Запрещённое выражение отклоняет публикацию. Выражение, требующее
+    /// проверки, отправляет опубликованную или отложенную запись в очередь
+    /// модерации (черновики читателям не видны и не проверяются до
+    /// публикации).
+    pub(crate) fn moderate_post(&self, post: &mut Post) -> Result<(), DomainError> {
+        let matcher = self.matcher();
+        let mut hold = false;
+
+        for text in [post.title.as_ref(), post.content.as_ref()] {
+            match matcher.check(text) {
+                Verdict::Reject(found) => {
+                    return Err(DomainError::invalid_post(format!(
+                        "найдено запрещённое выражение: '{found}'"
+                    )));
+                }
+                Verdict::Hold(_) => hold = true,
+                Verdict::Pass => {}
+            }
+        }
+        for tag in &post.tags {
+            match matcher.check(tag.as_ref()) {
+                Verdict::Reject(found) => {
+                    return Err(DomainError::invalid_tag(format!(
+                        "найдено запрещённое выражение: '{found}'"
+                    )));
+                }
+                Verdict::Hold(_) => hold = true,
+                Verdict::Pass => {}
+            }
+        }
+
+        if hold {
+            post.hold();
+        }
+
+        Ok(())
+    }
+
+    /// Проверить текст комментария. Очереди модерации для комментариев
+    /// нет, поэтому отклоняются совпадения с правилами обоих действий.
+    pub(crate) fn check_comment(&self, content: &CommentContent) -> Result<(), DomainError> {
+        match self.matcher().check(content.as_ref()) {
+            Verdict::Pass => Ok(()),
+            Verdict::Reject(found) => Err(DomainError::invalid_comment(format!(
+                "найдено запрещённое выражение: '{found}'"
+            ))),
+            Verdict::Hold(found) => Err(DomainError::invalid_comment(format!(
+                "выражение '{found}' недопустимо в комментариях"
+            ))),
+        }
+    }
+}
+
+/// Регулярное выражение правила.
+fn rule_regex(rule: &ModerationRule) -> String {
+    match rule.kind {
+        RuleKind::Word => format!(r"(?i:\b{}\b)", regex::escape(&rule.pattern)),
+        RuleKind::Phrase => {
+            let words = rule
+                .pattern
+                .split_whitespace()
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join(r"\s+");
+            format!(r"(?i:\b{words}\b)")
+        }
+        RuleKind::Regex => format!("(?:{})", rule.pattern),
+    }
+}
+
+/// Объединить правила с действием `action` в одно регулярное выражение.
+fn join_rules(rules: &[ModerationRule], action: RuleAction) -> Option<Regex> {
+    let patterns = rules
+        .iter()
+        .filter(|rule| rule.action == action)
+        .filter_map(|rule| {
+            let pattern = rule_regex(rule);
+            match compile(&pattern) {
+                Ok(_) => Some(pattern),
+                Err(err) => {
+                    warn!(rule_id = %rule.id, error = %err, "Правило модерации пропущено");
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    if patterns.is_empty() {
+        return None;
+    }
+
+    compile(&patterns.join("|"))
+        .inspect_err(|err| warn!(error = %err, "Не удалось объединить правила модерации"))
+        .ok()
+}
+
+/// Скомпилировать регулярное выражение с ограничением размера.
+fn compile(pattern: &str) -> Result<Regex, String> {
+    RegexBuilder::new(pattern)
+        .size_limit(MODERATION_REGEX_SIZE_LIMIT)
+        .build()
+        .map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, kind: RuleKind, action: RuleAction) -> ModerationRule {
+        ModerationRule {
+            id: 1.into(),
+            pattern: pattern.to_string(),
+            kind,
+            action,
+            created_at: Utc::now(),
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn matcher_words_and_phrases() {
+        let matcher = ModerationMatcher::new(&[
+            rule("дурак", RuleKind::Word, RuleAction::Reject),
+            rule("кому нужен ваш Rust", RuleKind::Phrase, RuleAction::Reject),
+        ]);
+
+        assert_eq!(
+            matcher.check("Автор, вы Дурак"),
+            Verdict::Reject("Дурак".to_string())
+        );
+        assert!(matches!(
+            matcher.check("кому  нужен\nваш rust?"),
+            Verdict::Reject(_)
+        ));
+        // Частичные совпадения не срабатывают.
+        assert_eq!(matcher.check("Это не дуракк"), Verdict::Pass);
+    }
+
+    #[test]
+    fn matcher_reject_wins_over_hold() {
+        let matcher = ModerationMatcher::new(&[
+            rule(r"https?://\S+", RuleKind::Regex, RuleAction::Hold),
+            rule("спам", RuleKind::Word, RuleAction::Reject),
+        ]);
+
+        assert_eq!(
+            matcher.check("ссылка http://example.com"),
+            Verdict::Hold("http://example.com".to_string())
+        );
+        assert!(matches!(
+            matcher.check("спам http://example.com"),
+            Verdict::Reject(_)
+        ));
+        assert_eq!(ModerationMatcher::default().check("спам"), Verdict::Pass);
+    }
+
+    #[test]
+    fn save_rule_validation() {
+        let save = |pattern: &str, kind| SaveModerationRule {
+            pattern: pattern.to_string(),
+            kind,
+            action: RuleAction::Reject,
+        };
+
+        assert_eq!(
+            save("  слово ", RuleKind::Word).validated().unwrap().pattern,
+            "слово"
+        );
+        assert!(save("два слова", RuleKind::Word).validated().is_err());
+        assert!(save("два слова", RuleKind::Phrase).validated().is_ok());
+        assert!(save("(незакрытая", RuleKind::Regex).validated().is_err());
+        assert!(save("   ", RuleKind::Phrase).validated().is_err());
+    }
+
+    #[test]
+    fn filter_replaces_rules() {
+        let filter = ModerationFilter::default();
+        let content: CommentContent = "Отличная статья, дурак".to_string().try_into().unwrap();
+        assert!(filter.check_comment(&content).is_ok());
+
+        filter.replace(ModerationMatcher::new(&[rule(
+            "дурак",
+            RuleKind::Word,
+            RuleAction::Hold,
+        )]));
+        assert!(matches!(
+            filter.check_comment(&content),
+            Err(DomainError::InvalidCommentContent(_))
+        ));
+    }
+}
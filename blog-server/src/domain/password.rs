@@ -0,0 +1,70 @@
+//! Смена и сброс пароля.
+//!
+//! Для сброса забытого пароля пользователь получает по электронной почте
+//! одноразовый токен с ограниченным сроком действия. В базе данных
+//! хранится только хеш токена.
+
+use crate::domain::types::{DataId, Email, UserPassword, Username};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Смена пароля авторизованным пользователем.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ChangePassword {
+    /// Текущий пароль.
+    pub(crate) old_password: UserPassword,
+    /// Новый пароль.
+    pub(crate) new_password: UserPassword,
+}
+
+/// Запрос на сброс забытого пароля.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ForgotPassword {
+    /// Адрес электронной почты учётной записи.
+    pub(crate) email: Email,
+}
+
+/// Установка нового пароля по токену сброса.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ResetPassword {
+    /// Токен сброса пароля из письма.
+    pub(crate) token: String,
+    /// Новый пароль.
+    pub(crate) new_password: UserPassword,
+}
+
+/// Новый токен сброса пароля для записи в базу данных.
+#[derive(Debug, Clone)]
+pub(crate) struct NewPasswordResetToken {
+    /// Id владельца учётной записи.
+    pub(crate) user_id: DataId,
+    /// Хеш секрета токена.
+    pub(crate) token_hash: String,
+    /// Время истечения токена.
+    pub(crate) expires_at: DateTime<Utc>,
+}
+
+/// Ссылка на страницу сброса пароля веб-клиента `site_url`.
+pub(crate) fn reset_link(site_url: &str, token: &str) -> String {
+    format!("{site_url}/reset-password?token={token}")
+}
+
+/// Текст письма со ссылкой на сброс пароля.
+pub(crate) fn reset_mail_body(
+    username: &Username,
+    link: &str,
+    token: &str,
+    expires_at: DateTime<Utc>,
+) -> String {
+    format!(
+        "Здравствуйте, {username}!\n\n\
+         Для вашей учётной записи запрошен сброс пароля. Чтобы задать новый\n\
+         пароль, перейдите по ссылке:\n\n\
+         {link}\n\n\
+         или укажите токен в клиенте блога:\n\n\
+         {token}\n\n\
+         Ссылка действует до {} (UTC) и может быть использована один раз.\n\
+         Если вы не запрашивали сброс пароля, проигнорируйте это письмо.\n",
+        expires_at.format("%d.%m.%Y %H:%M")
+    )
+}
@@ -1,8 +1,101 @@
 //! Модели для сообщений в блоге.
 
-use crate::domain::types::{DataId, PostContent, PostTitle};
+use crate::{
+    domain::{
+        markdown::render_html,
+        reaction::{ReactionCount, ReactionKind},
+        types::{DataId, PostContent, PostCursor, PostTitle, SearchQuery, Slug, Tag, Username},
+    },
+    errors::DomainError,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+
+/// Статус (этап жизненного цикла) публикации.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "post_status", rename_all = "lowercase")]
+pub(crate) enum PostStatus {
+    /// Черновик: виден только автору.
+    Draft,
+    /// Опубликована: видна всем.
+    #[default]
+    Published,
+    /// Отложенная: станет опубликованной в `publish_at`.
+    Scheduled,
+    /// Скрыта модератором: видна только автору, вернуть её может только
+    /// модератор.
+    Hidden,
+    /// Ожидает проверки модератором: видна только автору.
+    Pending,
+}
+
+impl PostStatus {
+    /// Определить статус и время публикации для новой записи.
+    ///
+    /// Если статус не указан, он выводится из `publish_at`: при наличии
+    /// времени публикация отложенная, иначе публикуется сразу. Время
+    /// допустимо только для отложенной публикации и должно быть в будущем.
+    pub(crate) fn resolve(
+        status: Option<PostStatus>,
+        publish_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<(PostStatus, Option<DateTime<Utc>>), DomainError> {
+        match (status, publish_at) {
+            (Some(PostStatus::Hidden), _) => Err(DomainError::invalid_publication(
+                "скрыть публикацию может только модератор",
+            )),
+            (Some(PostStatus::Pending), _) => Err(DomainError::invalid_publication(
+                "на проверку публикация отправляется автоматически",
+            )),
+            (None | Some(PostStatus::Published), None) => Ok((PostStatus::Published, Some(now))),
+            (Some(PostStatus::Draft), None) => Ok((PostStatus::Draft, None)),
+            (None | Some(PostStatus::Scheduled), Some(at)) if at > now => {
+                Ok((PostStatus::Scheduled, Some(at)))
+            }
+            (None | Some(PostStatus::Scheduled), Some(_)) => Err(
+                DomainError::invalid_publication("время публикации должно быть в будущем"),
+            ),
+            (Some(PostStatus::Scheduled), None) => Err(DomainError::invalid_publication(
+                "для отложенной публикации требуется publish_at",
+            )),
+            (Some(_), Some(_)) => Err(DomainError::invalid_publication(
+                "publish_at допустим только для отложенной публикации",
+            )),
+        }
+    }
+}
+
+impl Display for PostStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            PostStatus::Draft => "draft",
+            PostStatus::Published => "published",
+            PostStatus::Scheduled => "scheduled",
+            PostStatus::Hidden => "hidden",
+            PostStatus::Pending => "pending",
+        };
+        f.write_str(status)
+    }
+}
+
+impl FromStr for PostStatus {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "draft" => Ok(PostStatus::Draft),
+            "published" => Ok(PostStatus::Published),
+            "scheduled" => Ok(PostStatus::Scheduled),
+            "hidden" => Ok(PostStatus::Hidden),
+            "pending" => Ok(PostStatus::Pending),
+            other => Err(DomainError::invalid_publication(format!(
+                "неизвестный статус '{other}'"
+            ))),
+        }
+    }
+}
 
 /// Структура сообщения (поста) в блоге.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -12,23 +105,47 @@ pub(crate) struct Post {
     pub(crate) id: Option<DataId>,
     /// Заголовок сообщения.
     pub(crate) title: PostTitle,
-    /// Содержание сообщения.
+    /// Текущий человекочитаемый адрес. Назначается при сохранении в базу
+    /// данных.
+    pub(crate) slug: Option<Slug>,
+    /// Содержание сообщения в формате CommonMark.
     pub(crate) content: PostContent,
+    /// Содержание, отрисованное в безопасный HTML.
+    pub(crate) content_html: String,
     /// Id автора поста, на основе [`UserId`].
     pub(crate) author_id: DataId,
+    /// Имя автора. Заполняется при чтении из базы данных.
+    pub(crate) author_username: Option<Username>,
     /// Время создания поста.
     #[serde(with = "chrono::serde::ts_seconds")]
     pub(crate) created_at: DateTime<Utc>,
     /// Время, когда пост был обновлён.
     #[serde(with = "chrono::serde::ts_seconds_option")]
     pub(crate) updated_at: Option<DateTime<Utc>>,
+    /// Теги публикации (в нижнем регистре, без повторов).
+    pub(crate) tags: Vec<Tag>,
+    /// Статус публикации.
+    pub(crate) status: PostStatus,
+    /// Время публикации: фактическое для опубликованных записей,
+    /// запланированное для отложенных. У черновиков отсутствует.
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub(crate) publish_at: Option<DateTime<Utc>>,
+    /// Время перемещения в корзину. У действующих публикаций отсутствует.
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub(crate) deleted_at: Option<DateTime<Utc>>,
+    /// Количество реакций каждого вида.
+    pub(crate) reactions: Vec<ReactionCount>,
+    /// Реакции текущего пользователя. Заполняются только для
+    /// авторизованных запросов.
+    pub(crate) my_reactions: Vec<ReactionKind>,
 }
 
 impl Post {
     /// Создание экземпляра [`Post`] на основе предоставленных данных.
     ///
     /// Если `created_at` не передано, конструктор самостоятельно создаёт
-    /// временную метку на основе текущего времени UTC.
+    /// временную метку на основе текущего времени UTC. Аналогично, если
+    /// не передан сохранённый `content_html`, HTML отрисовывается из `content`.
     ///
     /// ## Важно
     ///
@@ -39,27 +156,199 @@ impl Post {
         post_id: Option<DataId>,
         title: PostTitle,
         content: PostContent,
+        content_html: Option<String>,
         author_id: DataId,
         created_at: Option<DateTime<Utc>>,
         updated_at: Option<DateTime<Utc>>,
     ) -> Self {
         let created_at = created_at.unwrap_or_else(Utc::now);
+        let content_html = content_html.unwrap_or_else(|| render_html(content.as_ref()));
 
         Self {
             id: post_id,
             title,
+            slug: None,
             content,
+            content_html,
             author_id,
+            author_username: None,
             created_at,
             updated_at,
+            tags: Vec::new(),
+            status: PostStatus::Published,
+            publish_at: Some(created_at),
+            deleted_at: None,
+            reactions: Vec::new(),
+            my_reactions: Vec::new(),
         }
     }
 
+    /// Установить теги публикации. Теги нормализуются: переводятся в нижний
+    /// регистр, повторы отбрасываются.
+    pub(crate) fn with_tags(mut self, tags: Vec<Tag>) -> Self {
+        self.tags = normalize_tags(tags);
+        self
+    }
+
+    /// Установить статус и время публикации.
+    pub(crate) fn with_publication(
+        mut self,
+        status: PostStatus,
+        publish_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.status = status;
+        self.publish_at = publish_at;
+        self
+    }
+
+    /// Установить имя автора публикации.
+    pub(crate) fn with_author_username(mut self, author_username: Option<Username>) -> Self {
+        self.author_username = author_username;
+        self
+    }
+
+    /// Установить текущий адрес публикации.
+    pub(crate) fn with_slug(mut self, slug: Option<Slug>) -> Self {
+        self.slug = slug;
+        self
+    }
+
+    /// Установить счётчики реакций на публикацию.
+    pub(crate) fn with_reactions(mut self, reactions: Vec<ReactionCount>) -> Self {
+        self.reactions = reactions;
+        self
+    }
+
+    /// Установить время перемещения публикации в корзину.
+    pub(crate) fn with_deleted_at(mut self, deleted_at: Option<DateTime<Utc>>) -> Self {
+        self.deleted_at = deleted_at;
+        self
+    }
+
     /// Создать новый экземпляр [`Post`] с помощью [`CreatePost`].
     ///
-    /// Временная метка проставляется автоматически.
-    pub(crate) fn new_by_create(post: CreatePost, author_id: DataId) -> Self {
-        Post::new(None, post.title, post.content, author_id, None, None)
+    /// Временная метка проставляется автоматически, статус определяется
+    /// через [`PostStatus::resolve`].
+    pub(crate) fn new_by_create(post: CreatePost, author_id: DataId) -> Result<Self, DomainError> {
+        let created_at = Utc::now();
+        let (status, publish_at) = PostStatus::resolve(post.status, post.publish_at, created_at)?;
+
+        Ok(
+            Post::new(
+                None,
+                post.title,
+                post.content,
+                None,
+                author_id,
+                Some(created_at),
+                None,
+            )
+            .with_tags(post.tags)
+            .with_publication(status, publish_at),
+        )
+    }
+
+    /// Опубликована ли запись.
+    pub(crate) fn is_published(&self) -> bool {
+        self.status == PostStatus::Published
+    }
+
+    /// Доступна ли публикация пользователю: опубликованные записи видны
+    /// всем, черновики, отложенные, скрытые и ожидающие проверки — только
+    /// автору.
+    pub(crate) fn is_visible_to(&self, viewer: Option<&DataId>) -> bool {
+        self.is_published() || viewer.is_some_and(|user_id| self.is_author(user_id))
+    }
+
+    /// Опубликовать запись сразу (`publish_at` не указан) или запланировать
+    /// публикацию на время в будущем.
+    ///
+    /// Повторная публикация уже опубликованной записи ничего не меняет.
+    /// Скрытую модератором или ожидающую проверки запись автор
+    /// опубликовать не может.
+    pub(crate) fn publish(
+        &mut self,
+        publish_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        match self.status {
+            PostStatus::Hidden => {
+                return Err(DomainError::invalid_publication(
+                    "публикация скрыта модератором",
+                ));
+            }
+            PostStatus::Pending => {
+                return Err(DomainError::invalid_publication(
+                    "публикация ожидает проверки модератором",
+                ));
+            }
+            _ => {}
+        }
+        if self.is_published() {
+            return match publish_at {
+                None => Ok(()),
+                Some(_) => Err(DomainError::invalid_publication(
+                    "публикация уже опубликована",
+                )),
+            };
+        }
+
+        (self.status, self.publish_at) = PostStatus::resolve(None, publish_at, now)?;
+
+        Ok(())
+    }
+
+    /// Скрыть публикацию по решению модератора. Черновик читателям и так
+    /// не виден, поэтому скрыть его нельзя.
+    pub(crate) fn hide(&mut self) -> Result<(), DomainError> {
+        match self.status {
+            PostStatus::Draft => Err(DomainError::invalid_publication(
+                "черновик не виден читателям",
+            )),
+            _ => {
+                self.status = PostStatus::Hidden;
+                Ok(())
+            }
+        }
+    }
+
+    /// Вернуть скрытую публикацию читателям. Запись становится
+    /// опубликованной; если её время публикации ещё не наступило, она
+    /// публикуется в `now`.
+    pub(crate) fn unhide(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != PostStatus::Hidden {
+            return Err(DomainError::invalid_publication("публикация не скрыта"));
+        }
+
+        self.status = PostStatus::Published;
+        self.publish_at = self.publish_at.filter(|at| *at <= now).or(Some(now));
+
+        Ok(())
+    }
+
+    /// Отправить опубликованную или отложенную запись на проверку
+    /// модератору. Запланированное время публикации сохраняется.
+    pub(crate) fn hold(&mut self) {
+        if matches!(self.status, PostStatus::Published | PostStatus::Scheduled) {
+            self.status = PostStatus::Pending;
+        }
+    }
+
+    /// Одобрить запись, ожидающую проверки: она публикуется в `now` или
+    /// остаётся отложенной, если её время публикации ещё не наступило.
+    pub(crate) fn approve(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != PostStatus::Pending {
+            return Err(DomainError::invalid_publication(
+                "публикация не ожидает проверки",
+            ));
+        }
+
+        (self.status, self.publish_at) = match self.publish_at {
+            Some(at) if at > now => (PostStatus::Scheduled, Some(at)),
+            _ => (PostStatus::Published, Some(now)),
+        };
+
+        Ok(())
     }
 
     /// Проверяет совпадение автора публикации с ID пользователя.
@@ -87,41 +376,92 @@ impl Post {
         }
 
         if let Some(content) = edit_post.content.clone() {
+            self.content_html = render_html(content.as_ref());
             self.content = content;
             updated = true;
         }
 
+        if let Some(tags) = edit_post.tags.clone() {
+            self.tags = normalize_tags(tags);
+            updated = true;
+        }
+
         if updated {
             self.updated_at = Some(update_at);
         }
     }
 }
 
+/// Привести теги к единому виду: нижний регистр, без повторов. Порядок
+/// первого вхождения сохраняется.
+fn normalize_tags(tags: Vec<Tag>) -> Vec<Tag> {
+    let mut normalized: Vec<Tag> = Vec::with_capacity(tags.len());
+    for tag in tags.iter().map(Tag::to_lowercase) {
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    normalized
+}
+
 /// Перечень публикаций с пагинацией, полученных по запросу из репозитория.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub(crate) struct ListPosts {
     /// Перечень публикаций.
     pub(crate) posts: Vec<Post>,
-    /// Общее количество публикаций в базе данных.
-    pub(crate) total: i64,
+    /// Общее количество публикаций в базе данных. Считается только для
+    /// первой страницы (без курсора), чтобы глубокие страницы не требовали
+    /// полного подсчёта.
+    pub(crate) total: Option<i64>,
     /// Количество выгруженных записей.
     pub(crate) limit: i32,
     /// Сдвиг.
     pub(crate) offset: i32,
+    /// Курсор для запроса следующей страницы. `None`, если страница
+    /// последняя.
+    pub(crate) next_cursor: Option<PostCursor>,
 }
 
 impl ListPosts {
     /// Сформировать экземпляр [`ListPosts`].
-    pub(crate) fn new(posts: Vec<Post>, total: i64, limit: i32, offset: i32) -> Self {
+    pub(crate) fn new(
+        posts: Vec<Post>,
+        total: Option<i64>,
+        limit: i32,
+        offset: i32,
+        next_cursor: Option<PostCursor>,
+    ) -> Self {
         Self {
             posts,
             total,
             limit,
             offset,
+            next_cursor,
         }
     }
 }
 
+/// Параметры выгрузки перечня публикаций.
+///
+/// Если передан `after`, выгрузка продолжается после позиции курсора
+/// (keyset-пагинация), а `offset` должен быть нулевым.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ListPostsParams {
+    /// Количество возвращаемых записей.
+    pub(crate) limit: i32,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: i32,
+    /// Фильтр по тегу.
+    pub(crate) tag: Option<Tag>,
+    /// Курсор, после которого продолжается выгрузка.
+    pub(crate) after: Option<PostCursor>,
+    /// Выгружать только публикации авторов, на которых подписан
+    /// пользователь с этим id (персональная лента).
+    pub(crate) followed_by: Option<DataId>,
+    /// Выгружать только публикации автора с этим id.
+    pub(crate) author_id: Option<DataId>,
+}
+
 /// Dto-структура для создания записи (поста).
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub(crate) struct CreatePost {
@@ -129,6 +469,45 @@ pub(crate) struct CreatePost {
     pub(crate) title: PostTitle,
     /// Содержимое поста.
     pub(crate) content: PostContent,
+    /// Теги поста (опционально).
+    #[serde(default)]
+    pub(crate) tags: Vec<Tag>,
+    /// Статус поста (опционально). По умолчанию пост публикуется сразу,
+    /// либо откладывается, если передан `publish_at`.
+    #[serde(default)]
+    pub(crate) status: Option<PostStatus>,
+    /// Время отложенной публикации (опционально).
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub(crate) publish_at: Option<DateTime<Utc>>,
+}
+
+/// Dto-структура для публикации черновика или отложенной записи.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct PublishPost {
+    /// Время отложенной публикации. Если не передано, запись публикуется
+    /// сразу.
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub(crate) publish_at: Option<DateTime<Utc>>,
+}
+
+/// Dto-структура query-параметров для извлечения черновиков и отложенных
+/// публикаций автора.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QueryDrafts {
+    /// Количество возвращаемых записей.
+    pub(crate) limit: Option<u32>,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: Option<u32>,
+}
+
+/// Dto-структура query-параметров для извлечения публикаций автора
+/// в корзине.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QueryTrash {
+    /// Количество возвращаемых записей.
+    pub(crate) limit: Option<u32>,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: Option<u32>,
 }
 
 /// Dto-структура для редактирования записи (поста).
@@ -138,6 +517,8 @@ pub(crate) struct EditPost {
     pub(crate) title: Option<PostTitle>,
     /// Содержимое поста.
     pub(crate) content: Option<PostContent>,
+    /// Новый набор тегов. Если передан, полностью заменяет прежний.
+    pub(crate) tags: Option<Vec<Tag>>,
 }
 
 /// Команда обновления поста.
@@ -162,6 +543,10 @@ pub(crate) struct QueryPosts {
     pub(crate) limit: Option<u32>,
     /// Количество записей, которые необходимо пропустить.
     pub(crate) offset: Option<u32>,
+    /// Фильтр по тегу (опционально).
+    pub(crate) tag: Option<Tag>,
+    /// Курсор следующей страницы из предыдущего ответа (опционально).
+    pub(crate) after: Option<PostCursor>,
 }
 
 impl Default for QueryPosts {
@@ -169,6 +554,159 @@ impl Default for QueryPosts {
         Self {
             limit: Some(10),
             offset: Some(0),
+            tag: None,
+            after: None,
         }
     }
 }
+
+/// Тег и количество публикаций с ним.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct TagStat {
+    /// Наименование тега.
+    pub(crate) name: Tag,
+    /// Количество публикаций с тегом.
+    pub(crate) posts_count: i64,
+}
+
+/// Перечень тегов, используемых в публикациях.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct ListTags {
+    /// Теги с количеством публикаций.
+    pub(crate) tags: Vec<TagStat>,
+}
+
+/// Результат полнотекстового поиска: публикация, её релевантность и
+/// фрагмент текста с подсвеченными совпадениями.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct PostSearchHit {
+    /// Найденная публикация.
+    pub(crate) post: Post,
+    /// Релевантность (чем больше, тем выше в выдаче).
+    pub(crate) rank: f32,
+    /// Фрагмент содержания; совпадения обрамлены `<mark>` и `</mark>`.
+    pub(crate) snippet: String,
+}
+
+/// Результаты поиска публикаций с пагинацией.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct SearchPosts {
+    /// Найденные публикации, от наиболее релевантных.
+    pub(crate) hits: Vec<PostSearchHit>,
+    /// Общее количество найденных публикаций.
+    pub(crate) total: i64,
+    /// Количество выгруженных записей.
+    pub(crate) limit: i32,
+    /// Сдвиг.
+    pub(crate) offset: i32,
+}
+
+/// Dto-структура query-параметров для поиска публикаций.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QuerySearch {
+    /// Поисковый запрос (поддерживается синтаксис `websearch_to_tsquery`).
+    pub(crate) q: SearchQuery,
+    /// Количество возвращаемых записей.
+    pub(crate) limit: Option<u32>,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn post_status_resolve() {
+        let now = Utc::now();
+        let later = now + Duration::hours(1);
+        let earlier = now - Duration::hours(1);
+
+        assert_eq!(
+            PostStatus::resolve(None, None, now).unwrap(),
+            (PostStatus::Published, Some(now))
+        );
+        assert_eq!(
+            PostStatus::resolve(Some(PostStatus::Draft), None, now).unwrap(),
+            (PostStatus::Draft, None)
+        );
+        assert_eq!(
+            PostStatus::resolve(None, Some(later), now).unwrap(),
+            (PostStatus::Scheduled, Some(later))
+        );
+        assert!(PostStatus::resolve(None, Some(earlier), now).is_err());
+        assert!(PostStatus::resolve(Some(PostStatus::Scheduled), None, now).is_err());
+        assert!(PostStatus::resolve(Some(PostStatus::Draft), Some(later), now).is_err());
+        assert!(PostStatus::resolve(Some(PostStatus::Hidden), None, now).is_err());
+        assert!(PostStatus::resolve(Some(PostStatus::Pending), None, now).is_err());
+    }
+
+    #[test]
+    fn post_hide_and_unhide() {
+        let now = Utc::now();
+        let author = DataId(1);
+        let mut post = Post::new(
+            Some(DataId(7)),
+            "Заголовок".to_string().try_into().unwrap(),
+            "Содержание публикации".to_string().try_into().unwrap(),
+            None,
+            author.clone(),
+            Some(now),
+            None,
+        )
+        .with_publication(PostStatus::Scheduled, Some(now + Duration::hours(1)));
+
+        post.hide().unwrap();
+        assert_eq!(post.status, PostStatus::Hidden);
+        assert!(!post.is_visible_to(None));
+        assert!(!post.is_visible_to(Some(&DataId(2))));
+        assert!(post.is_visible_to(Some(&author)));
+        assert!(post.publish(None, now).is_err());
+
+        post.unhide(now).unwrap();
+        assert_eq!(post.status, PostStatus::Published);
+        assert_eq!(post.publish_at, Some(now));
+        assert!(post.unhide(now).is_err());
+
+        let mut draft = post.with_publication(PostStatus::Draft, None);
+        assert!(draft.hide().is_err());
+    }
+
+    #[test]
+    fn post_hold_and_approve() {
+        let now = Utc::now();
+        let author = DataId(1);
+        let post = Post::new(
+            Some(DataId(7)),
+            "Заголовок".to_string().try_into().unwrap(),
+            "Содержание публикации".to_string().try_into().unwrap(),
+            None,
+            author.clone(),
+            Some(now - Duration::hours(1)),
+            None,
+        );
+
+        let mut held = post.clone();
+        held.hold();
+        assert_eq!(held.status, PostStatus::Pending);
+        assert!(!held.is_visible_to(None));
+        assert!(held.is_visible_to(Some(&author)));
+        assert!(held.publish(None, now).is_err());
+        held.approve(now).unwrap();
+        assert_eq!(held.status, PostStatus::Published);
+        assert_eq!(held.publish_at, Some(now));
+        assert!(held.approve(now).is_err());
+
+        let at = now + Duration::hours(1);
+        let mut scheduled = post.clone().with_publication(PostStatus::Scheduled, Some(at));
+        scheduled.hold();
+        scheduled.approve(now).unwrap();
+        assert_eq!(scheduled.status, PostStatus::Scheduled);
+        assert_eq!(scheduled.publish_at, Some(at));
+
+        let mut draft = post.with_publication(PostStatus::Draft, None);
+        draft.hold();
+        assert_eq!(draft.status, PostStatus::Draft);
+    }
+}
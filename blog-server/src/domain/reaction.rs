@@ -0,0 +1,121 @@
+//! Модели для реакций на публикации.
+
+use crate::{domain::types::DataId, errors::DomainError};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+
+/// Вид реакции на публикацию.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, sqlx::Type,
+)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "reaction_kind", rename_all = "lowercase")]
+pub(crate) enum ReactionKind {
+    /// Нравится.
+    Like,
+    /// Восхищение.
+    Love,
+    /// Смешно.
+    Laugh,
+    /// Удивление.
+    Wow,
+    /// Грусть.
+    Sad,
+}
+
+impl Display for ReactionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            ReactionKind::Like => "like",
+            ReactionKind::Love => "love",
+            ReactionKind::Laugh => "laugh",
+            ReactionKind::Wow => "wow",
+            ReactionKind::Sad => "sad",
+        };
+        f.write_str(kind)
+    }
+}
+
+impl FromStr for ReactionKind {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "like" => Ok(ReactionKind::Like),
+            "love" => Ok(ReactionKind::Love),
+            "laugh" => Ok(ReactionKind::Laugh),
+            "wow" => Ok(ReactionKind::Wow),
+            "sad" => Ok(ReactionKind::Sad),
+            other => Err(DomainError::invalid_reaction(format!(
+                "неизвестный вид реакции '{other}'"
+            ))),
+        }
+    }
+}
+
+/// Количество реакций одного вида на публикацию.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ReactionCount {
+    /// Вид реакции.
+    pub(crate) kind: ReactionKind,
+    /// Количество пользователей, поставивших реакцию.
+    pub(crate) count: i64,
+}
+
+impl ReactionCount {
+    /// Собрать счётчики из параллельных перечней видов и количеств,
+    /// полученных из базы данных.
+    pub(crate) fn zip(kinds: Vec<ReactionKind>, counts: Vec<i64>) -> Vec<Self> {
+        kinds
+            .into_iter()
+            .zip(counts)
+            .map(|(kind, count)| Self { kind, count })
+            .collect()
+    }
+}
+
+/// Сводка реакций на публикацию.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct PostReactions {
+    /// Id публикации.
+    pub(crate) post_id: DataId,
+    /// Количество реакций каждого вида. Виды без реакций не выводятся.
+    pub(crate) reactions: Vec<ReactionCount>,
+    /// Реакции, поставленные текущим пользователем.
+    pub(crate) my_reactions: Vec<ReactionKind>,
+}
+
+impl PostReactions {
+    /// Сформировать экземпляр [`PostReactions`].
+    pub(crate) fn new(
+        post_id: DataId,
+        reactions: Vec<ReactionCount>,
+        my_reactions: Vec<ReactionKind>,
+    ) -> Self {
+        Self {
+            post_id,
+            reactions,
+            my_reactions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reaction_kind_round_trip() {
+        for kind in [
+            ReactionKind::Like,
+            ReactionKind::Love,
+            ReactionKind::Laugh,
+            ReactionKind::Wow,
+            ReactionKind::Sad,
+        ] {
+            assert_eq!(kind.to_string().parse::<ReactionKind>().unwrap(), kind);
+        }
+        assert!("Like".parse::<ReactionKind>().is_err());
+        assert!("".parse::<ReactionKind>().is_err());
+    }
+}
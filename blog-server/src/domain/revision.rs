@@ -0,0 +1,275 @@
+//! Модели для истории изменений (ревизий) публикаций.
+
+use crate::{
+    domain::types::{DataId, PostContent, PostTitle, Tag},
+    settings::DIFF_MAX_CELLS,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Снимок публикации после очередного сохранения.
+///
+/// Ревизия 1 — исходная версия публикации, каждое последующее изменение
+/// (включая восстановление) получает следующий номер.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct PostRevision {
+    /// Id публикации.
+    pub(crate) post_id: DataId,
+    /// Порядковый номер ревизии в рамках публикации.
+    pub(crate) revision: i32,
+    /// Заголовок публикации.
+    pub(crate) title: PostTitle,
+    /// Содержание публикации.
+    pub(crate) content: PostContent,
+    /// Теги публикации.
+    pub(crate) tags: Vec<Tag>,
+    /// Время сохранения ревизии.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl PostRevision {
+    /// Создание экземпляра [`PostRevision`] на основе предоставленных данных.
+    pub(crate) fn new(
+        post_id: DataId,
+        revision: i32,
+        title: PostTitle,
+        content: PostContent,
+        tags: Vec<Tag>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            post_id,
+            revision,
+            title,
+            content,
+            tags,
+            created_at,
+        }
+    }
+}
+
+/// Перечень ревизий публикации с пагинацией.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct ListRevisions {
+    /// Перечень ревизий, от новых к старым.
+    pub(crate) revisions: Vec<PostRevision>,
+    /// Общее количество ревизий публикации.
+    pub(crate) total: i64,
+    /// Количество выгруженных записей.
+    pub(crate) limit: i32,
+    /// Сдвиг.
+    pub(crate) offset: i32,
+}
+
+impl ListRevisions {
+    /// Сформировать экземпляр [`ListRevisions`].
+    pub(crate) fn new(revisions: Vec<PostRevision>, total: i64, limit: i32, offset: i32) -> Self {
+        Self {
+            revisions,
+            total,
+            limit,
+            offset,
+        }
+    }
+}
+
+/// Операция построчного сравнения.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum DiffOp {
+    /// Строка не изменилась.
+    Equal,
+    /// Строка добавлена в новой версии.
+    Insert,
+    /// Строка удалена из прежней версии.
+    Delete,
+}
+
+impl Display for DiffOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self {
+            DiffOp::Equal => "equal",
+            DiffOp::Insert => "insert",
+            DiffOp::Delete => "delete",
+        };
+        write!(f, "{op}")
+    }
+}
+
+/// Строка результата сравнения.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DiffLine {
+    /// Операция над строкой.
+    pub(crate) op: DiffOp,
+    /// Текст строки.
+    pub(crate) text: String,
+}
+
+impl DiffLine {
+    fn new(op: DiffOp, text: &str) -> Self {
+        Self {
+            op,
+            text: text.to_string(),
+        }
+    }
+}
+
+/// Построчное сравнение двух ревизий публикации.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct RevisionDiff {
+    /// Id публикации.
+    pub(crate) post_id: DataId,
+    /// Номер исходной ревизии.
+    pub(crate) from: i32,
+    /// Номер сравниваемой ревизии.
+    pub(crate) to: i32,
+    /// Изменения заголовка.
+    pub(crate) title: Vec<DiffLine>,
+    /// Изменения содержания.
+    pub(crate) content: Vec<DiffLine>,
+}
+
+impl RevisionDiff {
+    /// Сравнить ревизии `from` и `to` одной публикации.
+    pub(crate) fn new(from: &PostRevision, to: &PostRevision) -> Self {
+        Self {
+            post_id: to.post_id.clone(),
+            from: from.revision,
+            to: to.revision,
+            title: line_diff(from.title.as_ref(), to.title.as_ref()),
+            content: line_diff(from.content.as_ref(), to.content.as_ref()),
+        }
+    }
+}
+
+/// Dto-структура query-параметров для извлечения перечня ревизий.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QueryRevisions {
+    /// Количество возвращаемых записей.
+    pub(crate) limit: Option<u32>,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: Option<u32>,
+}
+
+/// Dto-структура query-параметров для сравнения ревизий.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QueryDiff {
+    /// Номер исходной ревизии.
+    pub(crate) from: i32,
+    /// Номер сравниваемой ревизии.
+    pub(crate) to: i32,
+}
+
+/// Построчное сравнение текстов на основе наибольшей общей
+/// подпоследовательности строк.
+///
+/// Удалённые строки предшествуют добавленным на месте замены. Совпадающие
+/// начало и конец текстов в сравнение не попадают; если оставшийся фрагмент
+/// превышает [`DIFF_MAX_CELLS`], он выводится как замена целиком.
+pub(crate) fn line_diff(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut lines: Vec<DiffLine> = old[..prefix]
+        .iter()
+        .map(|l| DiffLine::new(DiffOp::Equal, l))
+        .collect();
+    lines.extend(middle_diff(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    ));
+    lines.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|l| DiffLine::new(DiffOp::Equal, l)),
+    );
+
+    lines
+}
+
+/// Сравнение фрагментов без общих начала и конца.
+fn middle_diff(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    let (n, m) = (old.len(), new.len());
+    if n.saturating_mul(m) > DIFF_MAX_CELLS {
+        return old
+            .iter()
+            .map(|l| DiffLine::new(DiffOp::Delete, l))
+            .chain(new.iter().map(|l| DiffLine::new(DiffOp::Insert, l)))
+            .collect();
+    }
+
+    // lcs[i][j] — длина общей подпоследовательности суффиксов old[i..] и new[j..].
+    let mut lcs = vec![vec![0_u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            lines.push(DiffLine::new(DiffOp::Equal, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(DiffLine::new(DiffOp::Delete, old[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::new(DiffOp::Insert, new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|l| DiffLine::new(DiffOp::Delete, l)));
+    lines.extend(new[j..].iter().map(|l| DiffLine::new(DiffOp::Insert, l)));
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ops(lines: &[DiffLine]) -> Vec<(DiffOp, &str)> {
+        lines.iter().map(|l| (l.op, l.text.as_str())).collect()
+    }
+
+    #[test]
+    fn line_diff_marks_changes() {
+        let diff = line_diff("один\nдва\nтри", "один\nдва с половиной\nтри\nчетыре");
+
+        assert_eq!(
+            ops(&diff),
+            vec![
+                (DiffOp::Equal, "один"),
+                (DiffOp::Delete, "два"),
+                (DiffOp::Insert, "два с половиной"),
+                (DiffOp::Equal, "три"),
+                (DiffOp::Insert, "четыре"),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_diff_edge_cases() {
+        assert!(line_diff("", "").is_empty());
+        assert!(line_diff("a\nb", "a\nb").iter().all(|l| l.op == DiffOp::Equal));
+        assert_eq!(ops(&line_diff("", "a")), vec![(DiffOp::Insert, "a")]);
+        assert_eq!(ops(&line_diff("a", "")), vec![(DiffOp::Delete, "a")]);
+    }
+}
@@ -0,0 +1,125 @@
+//! Роли пользователей и права, которые они дают.
+//!
+//! Авторские действия (редактирование своей публикации и т.п.) ролью
+//! не ограничиваются; роль расширяет доступ к чужим данным.
+
+use crate::errors::DomainError;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+
+/// Роль пользователя.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "user_role", rename_all = "lowercase")]
+pub(crate) enum Role {
+    /// Обычный пользователь: управляет только своими данными.
+    #[default]
+    User,
+    /// Модератор: может скрывать и удалять любые публикации и комментарии.
+    Moderator,
+    /// Администратор: права модератора и управление пользователями.
+    Admin,
+}
+
+/// Право на действие, выходящее за пределы собственных данных.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Permission {
+    /// Скрытие и удаление чужих публикаций и комментариев.
+    ModerateContent,
+    /// Просмотр списка пользователей, смена ролей и блокировка учётных
+    /// записей.
+    ManageUsers,
+    /// Просмотр журнала аудита.
+    ViewAuditLog,
+}
+
+impl Permission {
+    /// Все права.
+    pub(crate) const ALL: [Permission; 3] = [
+        Permission::ModerateContent,
+        Permission::ManageUsers,
+        Permission::ViewAuditLog,
+    ];
+}
+
+impl Role {
+    /// Даёт ли роль право `permission`.
+    pub(crate) fn can(self, permission: Permission) -> bool {
+        match permission {
+            Permission::ModerateContent => matches!(self, Role::Moderator | Role::Admin),
+            Permission::ManageUsers | Permission::ViewAuditLog => self == Role::Admin,
+        }
+    }
+
+    /// Даёт ли роль все права роли `other`.
+    pub(crate) fn includes(self, other: Role) -> bool {
+        Permission::ALL
+            .into_iter()
+            .all(|permission| self.can(permission) || !other.can(permission))
+    }
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let role = match self {
+            Role::User => "user",
+            Role::Moderator => "moderator",
+            Role::Admin => "admin",
+        };
+        f.write_str(role)
+    }
+}
+
+impl FromStr for Role {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user" => Ok(Role::User),
+            "moderator" => Ok(Role::Moderator),
+            "admin" => Ok(Role::Admin),
+            other => Err(DomainError::invalid_role(format!(
+                "неизвестная роль '{other}'"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permissions_by_role() {
+        assert!(!Role::User.can(Permission::ModerateContent));
+        assert!(!Role::User.can(Permission::ManageUsers));
+
+        assert!(Role::Moderator.can(Permission::ModerateContent));
+        assert!(!Role::Moderator.can(Permission::ManageUsers));
+        assert!(!Role::Moderator.can(Permission::ViewAuditLog));
+
+        assert!(Role::Admin.can(Permission::ModerateContent));
+        assert!(Role::Admin.can(Permission::ManageUsers));
+        assert!(Role::Admin.can(Permission::ViewAuditLog));
+    }
+
+    #[test]
+    fn role_includes_lower_roles() {
+        assert!(Role::Admin.includes(Role::Moderator));
+        assert!(Role::Moderator.includes(Role::User));
+        assert!(Role::User.includes(Role::User));
+        assert!(!Role::Moderator.includes(Role::Admin));
+        assert!(!Role::User.includes(Role::Moderator));
+    }
+
+    #[test]
+    fn role_round_trip() {
+        for role in [Role::User, Role::Moderator, Role::Admin] {
+            assert_eq!(role.to_string().parse::<Role>().unwrap(), role);
+        }
+        assert!(matches!(
+            "root".parse::<Role>(),
+            Err(DomainError::InvalidRole(_))
+        ));
+    }
+}
@@ -0,0 +1,147 @@
+//! Формирование человекочитаемых адресов (slug) публикаций.
+
+use crate::settings::SLUG_MAX_CHARS;
+
+/// Адрес, используемый, если в заголовке нет ни одной буквы или цифры.
+const SLUG_FALLBACK: &str = "post";
+
+/// Сформировать базовый адрес публикации по заголовку.
+///
+/// Кириллица транслитерируется в латиницу, прочие символы кроме латинских
+/// букв и цифр заменяются на `-`. Результат всегда проходит валидацию
+/// адреса.
+pub(super) fn slugify(title: &str) -> String {
+    let mut slug = String::with_capacity(title.len());
+
+    for c in title.chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if let Some(latin) = transliterate(c) {
+            slug.push_str(latin);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    fit_len(&slug, SLUG_MAX_CHARS)
+}
+
+/// Адрес с числовым суффиксом (`-2`, `-3`, ...) для разрешения совпадений.
+/// Базовая часть укорачивается, чтобы результат уместился в
+/// [`SLUG_MAX_CHARS`].
+pub(super) fn with_suffix(base: &str, n: u32) -> String {
+    let suffix = format!("-{n}");
+    let base = fit_len(base, SLUG_MAX_CHARS - suffix.len());
+
+    format!("{base}{suffix}")
+}
+
+/// Порождён ли адрес `slug` базовым `base`: совпадает с ним или получен
+/// через [`with_suffix`].
+pub(super) fn is_derived(slug: &str, base: &str) -> bool {
+    if slug == base {
+        return true;
+    }
+
+    let Some((head, n)) = slug.rsplit_once('-') else {
+        return false;
+    };
+    let is_number = !n.is_empty() && !n.starts_with('0') && n.bytes().all(|b| b.is_ascii_digit());
+
+    is_number && head == fit_len(base, SLUG_MAX_CHARS - n.len() - 1)
+}
+
+/// Обрезать адрес до `max` символов без висящих `-`. Пустой результат
+/// заменяется на [`SLUG_FALLBACK`].
+fn fit_len(slug: &str, max: usize) -> String {
+    let cut = slug.get(..max).unwrap_or(slug);
+    let cut = cut.trim_matches('-');
+
+    if cut.is_empty() {
+        SLUG_FALLBACK.to_string()
+    } else {
+        cut.to_string()
+    }
+}
+
+/// Транслитерация кириллицы (русский и украинский алфавиты) в латиницу.
+fn transliterate(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' => "g",
+        'д' => "d",
+        'е' => "e",
+        'ё' => "yo",
+        'ж' => "zh",
+        'з' => "z",
+        'и' => "i",
+        'й' => "y",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ъ' | 'ь' => "",
+        'ы' => "y",
+        'э' => "e",
+        'ю' => "yu",
+        'я' => "ya",
+        'і' => "i",
+        'ї' => "yi",
+        'є' => "ye",
+        'ґ' => "g",
+        _ => return None,
+    };
+
+    Some(latin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_transliterates_cyrillic() {
+        assert_eq!(
+            slugify("Щекотка пяток — вид спорта!"),
+            "shchekotka-pyatok-vid-sporta"
+        );
+        assert_eq!(slugify("Rust 2024: async & Ёлки"), "rust-2024-async-yolki");
+        assert_eq!(slugify("!!!"), SLUG_FALLBACK);
+    }
+
+    #[test]
+    fn slugify_fits_max_len() {
+        let slug = slugify(&"слово ".repeat(40));
+        assert!(slug.len() <= SLUG_MAX_CHARS);
+        assert!(!slug.ends_with('-'));
+
+        let suffixed = with_suffix(&slug, 12);
+        assert!(suffixed.len() <= SLUG_MAX_CHARS);
+        assert!(suffixed.ends_with("-12"));
+        assert!(is_derived(&suffixed, &slug));
+    }
+
+    #[test]
+    fn is_derived_suffix_only() {
+        let base = slugify("Привет мир");
+        assert!(is_derived(&slugify("привет, мир"), &base));
+        assert!(is_derived(&with_suffix(&base, 3), &base));
+        assert!(!is_derived("p-2", &base));
+        assert!(!is_derived(&slugify("Привет мир 03"), &slugify("Привет мир")));
+        assert!(!is_derived(&slugify("Пока мир"), &base));
+    }
+}
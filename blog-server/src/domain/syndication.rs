@@ -0,0 +1,377 @@
+//! Формирование лент публикаций в форматах RSS 2.0 и Atom.
+//!
+//! Ленты собираются вручную: все текстовые узлы и атрибуты экранируются,
+//! символы, недопустимые в XML 1.0, отбрасываются. Даты выводятся
+//! по RFC 822 (RSS) и RFC 3339 (Atom) в UTC.
+
+use crate::domain::post::Post;
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fmt::Write;
+
+/// Формат ленты публикаций.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FeedFormat {
+    /// RSS 2.0.
+    Rss,
+    /// Atom 1.0.
+    Atom,
+}
+
+impl FeedFormat {
+    /// Значение заголовка `Content-Type` для ленты.
+    pub(crate) fn content_type(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+        }
+    }
+
+    /// Расширение в адресе ленты (`/feed.rss`, `/feed.atom`).
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "rss",
+            FeedFormat::Atom => "atom",
+        }
+    }
+
+    /// Сформировать ленту из публикаций, упорядоченных от новых к старым.
+    pub(crate) fn render(self, meta: &FeedMeta, posts: &[Post]) -> String {
+        match self {
+            FeedFormat::Rss => render_rss(meta, posts),
+            FeedFormat::Atom => render_atom(meta, posts),
+        }
+    }
+}
+
+/// Описание ленты.
+#[derive(Clone, Debug)]
+pub(crate) struct FeedMeta {
+    /// Заголовок ленты.
+    pub(crate) title: String,
+    /// Описание ленты.
+    pub(crate) description: String,
+    /// Адрес сайта блога без завершающего `/`; от него строятся ссылки
+    /// на публикации.
+    pub(crate) site_url: String,
+    /// Адрес страницы, которой соответствует лента (сайт или профиль
+    /// автора).
+    pub(crate) link: String,
+    /// Адрес самой ленты.
+    pub(crate) self_url: String,
+}
+
+/// Время последнего изменения ленты: самое позднее время публикации или
+/// обновления среди записей. У пустой ленты отсутствует.
+pub(crate) fn last_modified(posts: &[Post]) -> Option<DateTime<Utc>> {
+    posts.iter().map(updated_at).max()
+}
+
+/// Тег сущности (ETag) для содержимого ленты: 64-битный FNV-1a в
+/// шестнадцатеричной записи.
+pub(crate) fn etag(body: &str) -> String {
+    let hash = body.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+/// Сформировать ленту RSS 2.0.
+fn render_rss(meta: &FeedMeta, posts: &[Post]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\n<channel>\n");
+
+    text_element(&mut xml, "title", &meta.title);
+    text_element(&mut xml, "link", &meta.link);
+    text_element(&mut xml, "description", &meta.description);
+    xml.push_str("<atom:link href=\"");
+    escape_into(&mut xml, &meta.self_url);
+    xml.push_str("\" rel=\"self\" type=\"application/rss+xml\"/>\n");
+    if let Some(updated) = last_modified(posts) {
+        text_element(&mut xml, "lastBuildDate", &rfc822(updated));
+    }
+
+    for post in posts {
+        xml.push_str("<item>\n");
+        text_element(&mut xml, "title", post.title.as_ref());
+        text_element(&mut xml, "link", &post_url(&meta.site_url, post));
+        xml.push_str("<guid isPermaLink=\"false\">");
+        escape_into(&mut xml, &post_guid(&meta.site_url, post));
+        xml.push_str("</guid>\n");
+        text_element(&mut xml, "pubDate", &rfc822(published_at(post)));
+        for tag in &post.tags {
+            text_element(&mut xml, "category", tag.as_ref());
+        }
+        text_element(&mut xml, "description", &post.content_html);
+        xml.push_str("</item>\n");
+    }
+
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+/// Сформировать ленту Atom 1.0.
+fn render_atom(meta: &FeedMeta, posts: &[Post]) -> String {
+    let updated = last_modified(posts).unwrap_or(DateTime::UNIX_EPOCH);
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+
+    text_element(&mut xml, "title", &meta.title);
+    text_element(&mut xml, "subtitle", &meta.description);
+    text_element(&mut xml, "id", &meta.self_url);
+    link_element(&mut xml, "alternate", "text/html", &meta.link);
+    link_element(&mut xml, "self", "application/atom+xml", &meta.self_url);
+    text_element(&mut xml, "updated", &rfc3339(updated));
+
+    for post in posts {
+        let author = post
+            .author_username
+            .as_ref()
+            .map_or("unknown", |username| username.as_ref());
+
+        xml.push_str("<entry>\n");
+        text_element(&mut xml, "title", post.title.as_ref());
+        text_element(&mut xml, "id", &post_guid(&meta.site_url, post));
+        link_element(
+            &mut xml,
+            "alternate",
+            "text/html",
+            &post_url(&meta.site_url, post),
+        );
+        text_element(&mut xml, "published", &rfc3339(published_at(post)));
+        text_element(&mut xml, "updated", &rfc3339(updated_at(post)));
+        xml.push_str("<author>\n");
+        text_element(&mut xml, "name", author);
+        xml.push_str("</author>\n");
+        for tag in &post.tags {
+            xml.push_str("<category term=\"");
+            escape_into(&mut xml, tag.as_ref());
+            xml.push_str("\"/>\n");
+        }
+        xml.push_str("<content type=\"html\">");
+        escape_into(&mut xml, &post.content_html);
+        xml.push_str("</content>\n</entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// Время публикации записи.
+fn published_at(post: &Post) -> DateTime<Utc> {
+    post.publish_at.unwrap_or(post.created_at)
+}
+
+/// Время последнего изменения записи: правка могла быть сделана и до
+/// публикации (у отложенных записей).
+fn updated_at(post: &Post) -> DateTime<Utc> {
+    let published = published_at(post);
+    post.updated_at
+        .map_or(published, |updated| updated.max(published))
+}
+
+/// Ссылка на страницу публикации: по адресу (slug), а при его отсутствии —
+/// по id.
+fn post_url(site_url: &str, post: &Post) -> String {
+    match &post.slug {
+        Some(slug) => format!("{site_url}/p/{slug}"),
+        None => post_guid(site_url, post),
+    }
+}
+
+/// Постоянный идентификатор публикации: адрес может меняться вместе
+/// с заголовком, id — нет.
+fn post_guid(site_url: &str, post: &Post) -> String {
+    let id = post.id.as_ref().map(|id| id.0).unwrap_or_default();
+    format!("{site_url}/post/{id}")
+}
+
+/// Дата по RFC 822 (с четырёхзначным годом, как требует RSS 2.0).
+fn rfc822(dt: DateTime<Utc>) -> String {
+    dt.format("%a, %d %b %Y %H:%M:%S +0000").to_string()
+}
+
+/// Дата по RFC 3339 с точностью до секунды.
+fn rfc3339(dt: DateTime<Utc>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Вывести элемент с текстовым содержимым.
+fn text_element(xml: &mut String, name: &str, text: &str) {
+    let _ = write!(xml, "<{name}>");
+    escape_into(xml, text);
+    let _ = writeln!(xml, "</{name}>");
+}
+
+/// Вывести ссылку Atom `<link rel=... type=... href=...>`.
+fn link_element(xml: &mut String, rel: &str, content_type: &str, href: &str) {
+    let _ = write!(xml, "<link rel=\"{rel}\" type=\"{content_type}\" href=\"");
+    escape_into(xml, href);
+    xml.push_str("\"/>\n");
+}
+
+/// Экранировать текст для XML, отбросив недопустимые в XML 1.0 символы.
+fn escape_into(xml: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => xml.push_str("&amp;"),
+            '<' => xml.push_str("&lt;"),
+            '>' => xml.push_str("&gt;"),
+            '"' => xml.push_str("&quot;"),
+            '\'' => xml.push_str("&apos;"),
+            '\t' | '\n' | '\r' => xml.push(c),
+            c if c < ' ' || c == '\u{FFFE}' || c == '\u{FFFF}' => {}
+            _ => xml.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::{DataId, PostContent, PostTitle, Slug, Tag};
+    use chrono::TimeZone;
+
+    fn meta() -> FeedMeta {
+        FeedMeta {
+            title: "Блог & <друзья>".to_string(),
+            description: "Свежие \"записи\"\u{1}".to_string(),
+            site_url: "https://blog.example".to_string(),
+            link: "https://blog.example".to_string(),
+            self_url: "https://api.example/feed.rss?a=1&b=2".to_string(),
+        }
+    }
+
+    fn post(id: i64, title: &str, content: &str, day: u32) -> Post {
+        let publish_at = Utc.with_ymd_and_hms(2026, 10, day, 9, 5, 7).unwrap();
+        let mut post = Post::new(
+            Some(DataId(id)),
+            PostTitle::try_from(title.to_string()).unwrap(),
+            PostContent::try_from(content.to_string()).unwrap(),
+            None,
+            DataId(1),
+            Some(publish_at),
+            None,
+        );
+        post.slug = Some(Slug::from_title(&post.title));
+        post.tags = vec![Tag::try_from("rust".to_string()).unwrap()];
+        post
+    }
+
+    /// Проверить, что документ — корректно сформированный XML: теги
+    /// сбалансированы, а `&` открывает только ссылки на сущности.
+    fn assert_well_formed(xml: &str) {
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+
+        let mut open: Vec<&str> = Vec::new();
+        let mut rest = xml;
+        while let Some(start) = rest.find('<') {
+            let text = &rest[..start];
+            assert_valid_text(text);
+
+            let end = rest[start..].find('>').expect("незакрытый тег") + start;
+            let tag = &rest[start + 1..end];
+            assert!(!tag.contains('<'), "вложенный '<' в теге: {tag}");
+
+            if tag.starts_with('?') {
+                assert!(tag.ends_with('?'));
+            } else if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(open.pop(), Some(name), "несбалансированный тег {name}");
+            } else if !tag.ends_with('/') {
+                open.push(tag.split_whitespace().next().unwrap());
+            } else {
+                assert_valid_text(tag);
+            }
+            rest = &rest[end + 1..];
+        }
+
+        assert_eq!(rest.trim(), "");
+        assert!(open.is_empty(), "незакрытые теги: {open:?}");
+    }
+
+    fn assert_valid_text(text: &str) {
+        for (i, _) in text.match_indices('&') {
+            let entity = &text[i..text[i..].find(';').map_or(text.len(), |e| i + e + 1)];
+            assert!(
+                ["&amp;", "&lt;", "&gt;", "&quot;", "&apos;"].contains(&entity),
+                "неэкранированный '&': {entity}"
+            );
+        }
+        assert!(!text
+            .chars()
+            .any(|c| c < ' ' && !matches!(c, '\t' | '\n' | '\r')));
+    }
+
+    #[test]
+    fn rss_is_valid_and_escaped() {
+        let posts = [
+            post(
+                2,
+                "Новое <и> важное",
+                "Текст с **акцентом** &amp; <b>html</b>",
+                17,
+            ),
+            post(1, "Старое", "Текст", 3),
+        ];
+        let xml = FeedFormat::Rss.render(&meta(), &posts);
+
+        assert_well_formed(&xml);
+        assert!(xml.contains("<title>Блог &amp; &lt;друзья&gt;</title>"));
+        assert!(xml.contains("<description>Свежие &quot;записи&quot;</description>"));
+        assert!(xml.contains("href=\"https://api.example/feed.rss?a=1&amp;b=2\""));
+        assert!(xml.contains("<title>Новое &lt;и&gt; важное</title>"));
+        assert!(xml.contains("&lt;strong&gt;акцентом&lt;/strong&gt; &amp;amp;"));
+        assert!(xml.contains("<link>https://blog.example/p/novoe-i-vazhnoe</link>"));
+        assert!(xml.contains("<guid isPermaLink=\"false\">https://blog.example/post/2</guid>"));
+        assert!(xml.contains("<pubDate>Sat, 17 Oct 2026 09:05:07 +0000</pubDate>"));
+        assert!(xml.contains("<pubDate>Sat, 03 Oct 2026 09:05:07 +0000</pubDate>"));
+        assert!(xml.contains("<lastBuildDate>Sat, 17 Oct 2026 09:05:07 +0000</lastBuildDate>"));
+        assert!(xml.contains("<category>rust</category>"));
+        assert_eq!(xml.matches("<item>").count(), 2);
+    }
+
+    #[test]
+    fn atom_is_valid_with_rfc3339_dates() {
+        let mut edited = post(2, "Правка", "Текст", 10);
+        edited.updated_at = Some(Utc.with_ymd_and_hms(2026, 10, 12, 18, 0, 0).unwrap());
+        let posts = [edited, post(1, "Старое", "Текст", 3)];
+        let xml = FeedFormat::Atom.render(&meta(), &posts);
+
+        assert_well_formed(&xml);
+        assert!(xml.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+        assert!(xml.contains("<updated>2026-10-12T18:00:00Z</updated>\n<entry>"));
+        assert!(xml.contains("<published>2026-10-10T09:05:07Z</published>"));
+        assert!(xml.contains("<id>https://blog.example/post/1</id>"));
+        assert!(xml.contains("<category term=\"rust\"/>"));
+        assert!(xml.contains("<name>unknown</name>"));
+        assert_eq!(xml.matches("<entry>").count(), 2);
+        assert_eq!(
+            last_modified(&posts),
+            Some(Utc.with_ymd_and_hms(2026, 10, 12, 18, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn empty_feeds_are_valid() {
+        let rss = FeedFormat::Rss.render(&meta(), &[]);
+        let atom = FeedFormat::Atom.render(&meta(), &[]);
+
+        assert_well_formed(&rss);
+        assert_well_formed(&atom);
+        assert!(!rss.contains("lastBuildDate"));
+        assert!(atom.contains("<updated>1970-01-01T00:00:00Z</updated>"));
+        assert_eq!(last_modified(&[]), None);
+    }
+
+    #[test]
+    fn etag_follows_content() {
+        let posts = [post(1, "Запись", "Текст", 3)];
+        let rss = FeedFormat::Rss.render(&meta(), &posts);
+        let atom = FeedFormat::Atom.render(&meta(), &posts);
+
+        assert_eq!(etag(&rss), etag(&rss.clone()));
+        assert_ne!(etag(&rss), etag(&atom));
+        assert_eq!(etag(&rss).len(), 16);
+    }
+}
@@ -0,0 +1,164 @@
+//! Токены сессий: токены обновления и отзыв токенов доступа.
+//!
+//! Токен обновления — непрозрачная случайная строка, в базе данных хранится
+//! только её хеш SHA-256. При каждом обновлении сессии токен заменяется
+//! новым (ротация); токены одной сессии объединены общим `family_id`, что
+//! позволяет отозвать сессию целиком при повторном использовании токена.
+
+use crate::domain::{
+    role::Role,
+    types::{DataId, Username},
+};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Длина случайных идентификаторов (`jti`, `family_id`) в байтах.
+const ID_BYTES: usize = 16;
+
+/// Длина секрета одноразового токена в байтах.
+const SECRET_BYTES: usize = 32;
+
+/// Сгенерировать случайный идентификатор из 32 шестнадцатеричных символов.
+pub(crate) fn random_id() -> String {
+    random_hex(ID_BYTES)
+}
+
+/// Сгенерировать секрет нового токена обновления или сброса пароля.
+pub(crate) fn generate_secret() -> String {
+    random_hex(SECRET_BYTES)
+}
+
+/// Хеш секрета токена для хранения в базе данных.
+pub(crate) fn hash_secret(secret: &str) -> String {
+    hex(&Sha256::digest(secret.as_bytes()))
+}
+
+/// Пара токенов, выдаваемая при входе и обновлении сессии.
+#[derive(Debug, Clone)]
+pub(crate) struct TokenPair {
+    /// Короткоживущий JWT-токен доступа.
+    pub(crate) access_token: String,
+    /// Токен обновления сессии.
+    pub(crate) refresh_token: String,
+}
+
+/// Сведения о выданном токене доступа, достаточные для его отзыва.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AccessTokenId {
+    /// Идентификатор токена (`jti`).
+    pub(crate) jti: String,
+    /// Время истечения токена: после него отзыв теряет смысл.
+    pub(crate) expires_at: DateTime<Utc>,
+}
+
+/// Новый токен обновления для записи в базу данных.
+#[derive(Debug, Clone)]
+pub(crate) struct NewRefreshToken {
+    /// Id владельца сессии.
+    pub(crate) user_id: DataId,
+    /// Хеш секрета токена.
+    pub(crate) token_hash: String,
+    /// Идентификатор сессии (цепочки ротаций).
+    pub(crate) family_id: String,
+    /// Токен доступа, выданный вместе с токеном обновления.
+    pub(crate) access: AccessTokenId,
+    /// Время истечения токена обновления.
+    pub(crate) expires_at: DateTime<Utc>,
+}
+
+/// Сохранённый токен обновления.
+#[derive(Debug, Clone)]
+pub(crate) struct RefreshToken {
+    /// Id записи.
+    pub(crate) id: DataId,
+    /// Id владельца сессии.
+    pub(crate) user_id: DataId,
+    /// Имя владельца сессии.
+    pub(crate) username: Username,
+    /// Текущая роль владельца сессии.
+    pub(crate) role: Role,
+    /// Заблокирована ли учётная запись владельца.
+    pub(crate) account_disabled: bool,
+    /// Идентификатор сессии (цепочки ротаций).
+    pub(crate) family_id: String,
+    /// Токен доступа, выданный вместе с токеном обновления.
+    pub(crate) access: AccessTokenId,
+    /// Время истечения токена обновления.
+    pub(crate) expires_at: DateTime<Utc>,
+    /// Время отзыва: токен уже заменён новым или сессия завершена.
+    pub(crate) revoked_at: Option<DateTime<Utc>>,
+}
+
+impl RefreshToken {
+    /// Истёк ли срок действия токена к моменту `now`.
+    pub(crate) fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// Запрос на обновление сессии или выход из неё.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RefreshTokenRequest {
+    /// Токен обновления сессии.
+    pub(crate) refresh_token: String,
+}
+
+/// Случайная строка из `len` байт в шестнадцатеричной записи.
+fn random_hex(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    OsRng.fill_bytes(&mut bytes);
+    hex(&bytes)
+}
+
+/// Шестнадцатеричная запись байтов.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn secrets_are_random_and_hashed() {
+        let first = generate_secret();
+        let second = generate_secret();
+        assert_eq!(first.len(), SECRET_BYTES * 2);
+        assert_ne!(first, second);
+        assert_eq!(random_id().len(), 32);
+
+        let hash = hash_secret(&first);
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, hash_secret(&first));
+        assert_ne!(hash, hash_secret(&second));
+        assert_eq!(
+            hash_secret("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn refresh_token_expiration() {
+        let now = Utc::now();
+        let token = RefreshToken {
+            id: DataId(1),
+            user_id: DataId(2),
+            username: "user".to_string().try_into().unwrap(),
+            role: Role::User,
+            account_disabled: false,
+            family_id: random_id(),
+            access: AccessTokenId {
+                jti: random_id(),
+                expires_at: now,
+            },
+            expires_at: now + Duration::days(1),
+            revoked_at: None,
+        };
+
+        assert!(!token.is_expired(now));
+        assert!(token.is_expired(now + Duration::days(1)));
+    }
+}
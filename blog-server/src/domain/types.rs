@@ -1,18 +1,19 @@
 //! Новые типы моделей для domain.
 
 use crate::{
-    domain::validators::*,
+    domain::{slug, validators::*},
     errors::DomainError,
     settings::{ArgonConfig, ARGON_ALGORITHM, ARGON_ALGORITHM_VERSION},
     validated_newtype,
 };
 use anyhow::{anyhow, Result as AnyhowResult};
+use chrono::{DateTime, Utc};
 use argon2::{
-    password_hash::{rand_core::OsRng, Error as PwdHashError, SaltString}, Argon2, Params, PasswordHash, PasswordHasher,
+    password_hash::{rand_core::{OsRng, RngCore}, Error as PwdHashError, SaltString}, Argon2, Params, PasswordHash, PasswordHasher,
     PasswordVerifier,
 };
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 /// Единый тип `id` для моделей. Поддерживает преобразование в `i64`.
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq, Eq)]
@@ -54,6 +55,13 @@ impl Username {
     pub(crate) fn to_lowercase(&self) -> Self {
         Self(self.0.to_lowercase())
     }
+
+    /// Служебное имя, заданное сервером (например,
+    /// [`DELETED_USER_USERNAME`](crate::settings::DELETED_USER_USERNAME)).
+    /// Проверка имени к нему не применяется.
+    pub(crate) fn reserved(name: &str) -> Self {
+        Self(name.to_string())
+    }
 }
 
 validated_newtype! {
@@ -71,6 +79,13 @@ impl Email {
     pub(crate) fn to_lowercase(&self) -> Self {
         Self(self.0.to_lowercase())
     }
+
+    /// Служебный адрес, заданный сервером (например,
+    /// [`DELETED_USER_EMAIL`](crate::settings::DELETED_USER_EMAIL)).
+    /// Проверка адреса к нему не применяется.
+    pub(crate) fn reserved(email: &str) -> Self {
+        Self(email.to_string())
+    }
 }
 
 validated_newtype! {
@@ -147,3 +162,215 @@ validated_newtype! {
     validate = validate_content;
     error = DomainError::invalid_post;
 }
+
+validated_newtype! {
+    /// Новый тип для текста комментария к публикации.
+    #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, sqlx::Type)]
+    #[serde(try_from = "String")]
+    #[sqlx(transparent)]
+    pub(crate) struct CommentContent;
+    validate = validate_comment;
+    error = DomainError::invalid_comment;
+}
+
+validated_newtype! {
+    /// Новый тип для тега публикации.
+    #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, sqlx::Type)]
+    #[serde(try_from = "String")]
+    #[sqlx(transparent)]
+    pub(crate) struct Tag;
+    validate = validate_tag;
+    error = DomainError::invalid_tag;
+}
+
+impl Tag {
+    /// Создаёт новый экземпляр [`Tag`], переводя значение в нижний регистр.
+    pub(crate) fn to_lowercase(&self) -> Self {
+        Self(self.0.to_lowercase())
+    }
+}
+
+validated_newtype! {
+    /// Новый тип для поискового запроса по публикациям.
+    #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, sqlx::Type)]
+    #[serde(try_from = "String")]
+    #[sqlx(transparent)]
+    pub(crate) struct SearchQuery;
+    validate = validate_search_query;
+    error = DomainError::invalid_search;
+}
+
+validated_newtype! {
+    /// Новый тип для человекочитаемого адреса (slug) публикации.
+    #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, sqlx::Type)]
+    #[serde(try_from = "String")]
+    #[sqlx(transparent)]
+    pub(crate) struct Slug;
+    validate = validate_slug;
+    error = DomainError::invalid_slug;
+}
+
+impl Slug {
+    /// Базовый адрес публикации, сформированный по заголовку
+    /// с транслитерацией кириллицы.
+    pub(crate) fn from_title(title: &PostTitle) -> Self {
+        Self(slug::slugify(title.as_ref()))
+    }
+
+    /// Адрес с числовым суффиксом `-n` для разрешения совпадений.
+    pub(crate) fn with_suffix(&self, n: u32) -> Self {
+        Self(slug::with_suffix(&self.0, n))
+    }
+
+    /// Совпадает ли адрес с базовым `base` с точностью до числового
+    /// суффикса.
+    pub(crate) fn is_derived_from(&self, base: &Slug) -> bool {
+        slug::is_derived(&self.0, &base.0)
+    }
+}
+
+validated_newtype! {
+    /// Новый тип для отображаемого имени пользователя.
+    #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, sqlx::Type)]
+    #[serde(try_from = "String")]
+    #[sqlx(transparent)]
+    pub(crate) struct DisplayName;
+    validate = validate_display_name;
+    error = DomainError::invalid_profile;
+}
+
+validated_newtype! {
+    /// Новый тип для информации о себе в профиле пользователя.
+    #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, sqlx::Type)]
+    #[serde(try_from = "String")]
+    #[sqlx(transparent)]
+    pub(crate) struct Bio;
+    validate = validate_bio;
+    error = DomainError::invalid_profile;
+}
+
+validated_newtype! {
+    /// Новый тип для адреса аватара пользователя.
+    #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, sqlx::Type)]
+    #[serde(try_from = "String")]
+    #[sqlx(transparent)]
+    pub(crate) struct AvatarUrl;
+    validate = validate_avatar_url;
+    error = DomainError::invalid_profile;
+}
+
+validated_newtype! {
+    /// Новый тип для ключа файла вложения в хранилище: 32 случайных
+    /// шестнадцатеричных символа и расширение (`<hex>.png`).
+    ///
+    /// Ключ не содержит разделителей пути и используется как имя файла
+    /// и часть адреса вложения.
+    #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, sqlx::Type)]
+    #[serde(try_from = "String")]
+    #[sqlx(transparent)]
+    pub(crate) struct StorageKey;
+    validate = validate_storage_key;
+    error = DomainError::invalid_attachment;
+}
+
+impl StorageKey {
+    /// Сгенерировать новый случайный ключ с расширением `extension`.
+    pub(crate) fn generate(extension: &str) -> Self {
+        let mut bytes = [0u8; 16];
+        OsRng.fill_bytes(&mut bytes);
+        let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+
+        Self(format!("{hex}.{extension}"))
+    }
+}
+
+/// Курсор постраничной выгрузки публикаций: позиция последней выданной
+/// записи в порядке `(publish_at, id)`.
+///
+/// Для клиентов курсор непрозрачен: сериализуется в строку из 32
+/// шестнадцатеричных символов и принимается обратно без изменений.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub(crate) struct PostCursor {
+    /// Время публикации последней выданной записи.
+    pub(crate) publish_at: DateTime<Utc>,
+    /// Id последней выданной публикации.
+    pub(crate) id: DataId,
+}
+
+impl PostCursor {
+    /// Длина строкового представления курсора.
+    const ENCODED_LEN: usize = 32;
+
+    /// Создать курсор по времени публикации и id записи.
+    pub(crate) fn new(publish_at: DateTime<Utc>, id: DataId) -> Self {
+        Self { publish_at, id }
+    }
+}
+
+impl Display for PostCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:016x}{:016x}",
+            self.publish_at.timestamp_micros() as u64,
+            self.id.0 as u64
+        )
+    }
+}
+
+impl FromStr for PostCursor {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DomainError::invalid_cursor("курсор повреждён или устарел");
+
+        if s.len() != Self::ENCODED_LEN || !s.is_ascii() {
+            return Err(invalid());
+        }
+
+        let (micros, id) = s.split_at(Self::ENCODED_LEN / 2);
+        let micros = u64::from_str_radix(micros, 16).map_err(|_| invalid())? as i64;
+        let id = u64::from_str_radix(id, 16).map_err(|_| invalid())? as i64;
+        let publish_at = DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?;
+
+        Ok(Self::new(publish_at, DataId(id)))
+    }
+}
+
+impl TryFrom<String> for PostCursor {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<PostCursor> for String {
+    fn from(cursor: PostCursor) -> Self {
+        cursor.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_cursor_roundtrip() {
+        let publish_at = DateTime::from_timestamp_micros(1_760_000_000_123_456).unwrap();
+        let cursor = PostCursor::new(publish_at, DataId(42));
+
+        let encoded = cursor.to_string();
+        assert_eq!(encoded.len(), 32);
+        assert_eq!(encoded.parse::<PostCursor>().unwrap(), cursor);
+    }
+
+    #[test]
+    fn post_cursor_rejects_garbage() {
+        assert!("".parse::<PostCursor>().is_err());
+        assert!("not-a-cursor".parse::<PostCursor>().is_err());
+        assert!("zz".repeat(16).parse::<PostCursor>().is_err());
+        assert!("ф".repeat(16).parse::<PostCursor>().is_err());
+    }
+}
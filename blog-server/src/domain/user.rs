@@ -1,7 +1,12 @@
 //! Доменные модели.
 
 use crate::{
-    domain::types::{DataId, Email, UserPassword, Username},
+    domain::{
+        role::{Permission, Role},
+        token::TokenPair,
+        types::{AvatarUrl, Bio, DataId, DisplayName, Email, UserPassword, Username},
+    },
+    errors::DomainError,
     infrastructure::jwt::Claims,
 };
 use chrono::{DateTime, Utc};
@@ -21,6 +26,24 @@ pub(crate) struct User {
     pub password_hash: String,
     /// Время создания пользователя.
     pub created_at: DateTime<Utc>,
+    /// Отображаемое имя. Пустое, если пользователь его не задал.
+    pub display_name: String,
+    /// Краткая информация о себе для публичного профиля.
+    pub bio: String,
+    /// Адрес аватара. Пустой, если аватар не задан.
+    pub avatar_url: String,
+    /// Роль пользователя.
+    pub role: Role,
+    /// Время блокировки учётной записи. У действующих записей отсутствует.
+    pub disabled_at: Option<DateTime<Utc>>,
+    /// Количество неудачных попыток входа подряд.
+    pub failed_logins: i32,
+    /// Время последней неудачной попытки входа.
+    pub last_failed_login_at: Option<DateTime<Utc>>,
+    /// Время, до которого вход заблокирован после неудачных попыток.
+    pub locked_until: Option<DateTime<Utc>>,
+    /// Время подтверждения адреса электронной почты.
+    pub email_verified_at: Option<DateTime<Utc>>,
 }
 
 impl User {
@@ -44,9 +67,70 @@ impl User {
             email,
             password_hash: pwd_hash.to_string(),
             created_at,
+            display_name: String::new(),
+            bio: String::new(),
+            avatar_url: String::new(),
+            role: Role::default(),
+            disabled_at: None,
+            failed_logins: 0,
+            last_failed_login_at: None,
+            locked_until: None,
+            email_verified_at: None,
         }
     }
 
+    /// Установить поля профиля: отображаемое имя, информацию о себе
+    /// и адрес аватара.
+    pub(crate) fn with_profile(
+        mut self,
+        display_name: String,
+        bio: String,
+        avatar_url: String,
+    ) -> Self {
+        self.display_name = display_name;
+        self.bio = bio;
+        self.avatar_url = avatar_url;
+        self
+    }
+
+    /// Установить роль и время блокировки учётной записи.
+    pub(crate) fn with_access(mut self, role: Role, disabled_at: Option<DateTime<Utc>>) -> Self {
+        self.role = role;
+        self.disabled_at = disabled_at;
+        self
+    }
+
+    /// Установить состояние защиты от подбора пароля: количество неудачных
+    /// попыток входа подряд, время последней из них и время окончания
+    /// блокировки входа.
+    pub(crate) fn with_login_state(
+        mut self,
+        failed_logins: i32,
+        last_failed_login_at: Option<DateTime<Utc>>,
+        locked_until: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.failed_logins = failed_logins;
+        self.last_failed_login_at = last_failed_login_at;
+        self.locked_until = locked_until;
+        self
+    }
+
+    /// Установить время подтверждения адреса электронной почты.
+    pub(crate) fn with_email_verified_at(mut self, verified_at: Option<DateTime<Utc>>) -> Self {
+        self.email_verified_at = verified_at;
+        self
+    }
+
+    /// Заблокирована ли учётная запись.
+    pub(crate) fn is_disabled(&self) -> bool {
+        self.disabled_at.is_some()
+    }
+
+    /// Подтверждён ли адрес электронной почты.
+    pub(crate) fn is_email_verified(&self) -> bool {
+        self.email_verified_at.is_some()
+    }
+
     /// Создание нового экземпляра [`User`] с помощью [`CreateUser`].
     ///
     /// Временная метка создания проставляется автоматически.
@@ -102,33 +186,171 @@ pub(crate) struct UserDto {
     pub(crate) username: Username,
     /// Адрес электронной почты.
     pub(crate) email: Email,
+    /// Роль пользователя.
+    pub(crate) role: Role,
+    /// Подтверждён ли адрес электронной почты.
+    pub(crate) email_verified: bool,
+    /// Отображаемое имя.
+    pub(crate) display_name: String,
+    /// Информация о себе.
+    pub(crate) bio: String,
+    /// Адрес аватара.
+    pub(crate) avatar_url: String,
 }
 
 impl From<User> for UserDto {
     fn from(u: User) -> Self {
         Self {
+            email_verified: u.email_verified_at.is_some(),
             id: u.id,
             username: u.username,
             email: u.email,
+            role: u.role,
+            display_name: u.display_name,
+            bio: u.bio,
+            avatar_url: u.avatar_url,
         }
     }
 }
 
+/// Изменение профиля текущего пользователя.
+///
+/// Незаданные поля не меняются, пустая строка очищает отображаемое имя,
+/// информацию о себе или аватар. Новый адрес электронной почты требует
+/// повторного подтверждения.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct UpdateProfile {
+    /// Отображаемое имя.
+    pub(crate) display_name: Option<DisplayName>,
+    /// Информация о себе.
+    pub(crate) bio: Option<Bio>,
+    /// Адрес аватара.
+    pub(crate) avatar_url: Option<AvatarUrl>,
+    /// Новый адрес электронной почты.
+    pub(crate) email: Option<Email>,
+}
+
+impl UpdateProfile {
+    /// Не задано ни одного изменения.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.display_name.is_none()
+            && self.bio.is_none()
+            && self.avatar_url.is_none()
+            && self.email.is_none()
+    }
+}
+
+/// Учётная запись пользователя для администратора.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct UserAccount {
+    /// Id пользователя.
+    pub(crate) id: Option<DataId>,
+    /// Имя пользователя.
+    pub(crate) username: Username,
+    /// Адрес электронной почты.
+    pub(crate) email: Email,
+    /// Роль пользователя.
+    pub(crate) role: Role,
+    /// Время регистрации.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) created_at: DateTime<Utc>,
+    /// Время блокировки учётной записи.
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub(crate) disabled_at: Option<DateTime<Utc>>,
+    /// Время, до которого вход заблокирован после неудачных попыток
+    /// (только действующая блокировка).
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub(crate) locked_until: Option<DateTime<Utc>>,
+}
+
+impl From<User> for UserAccount {
+    fn from(u: User) -> Self {
+        Self {
+            id: u.id,
+            username: u.username,
+            email: u.email,
+            role: u.role,
+            created_at: u.created_at,
+            disabled_at: u.disabled_at,
+            locked_until: u.locked_until.filter(|until| *until > Utc::now()),
+        }
+    }
+}
+
+/// Страница списка учётных записей.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ListUsers {
+    /// Учётные записи, от ранее зарегистрированных.
+    pub(crate) users: Vec<UserAccount>,
+    /// Общее количество пользователей.
+    pub(crate) total: i64,
+    /// Размер страницы.
+    pub(crate) limit: i32,
+    /// Сдвиг страницы.
+    pub(crate) offset: i32,
+}
+
+/// Dto-структура query-параметров для списка пользователей.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QueryUsers {
+    /// Количество возвращаемых записей.
+    pub(crate) limit: Option<u32>,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: Option<u32>,
+}
+
+/// Запрос на смену роли пользователя.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SetUserRole {
+    /// Новая роль.
+    pub(crate) role: Role,
+}
+
+/// Публичный профиль автора.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct UserProfile {
+    /// Имя пользователя.
+    pub(crate) username: Username,
+    /// Отображаемое имя.
+    pub(crate) display_name: String,
+    /// Информация о себе.
+    pub(crate) bio: String,
+    /// Адрес аватара.
+    pub(crate) avatar_url: String,
+    /// Время регистрации.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) joined_at: DateTime<Utc>,
+    /// Количество опубликованных записей.
+    pub(crate) posts_count: i64,
+}
+
+/// Dto-структура query-параметров для извлечения публикаций автора.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct QueryAuthorPosts {
+    /// Количество возвращаемых записей.
+    pub(crate) limit: Option<u32>,
+    /// Количество записей, которые необходимо пропустить.
+    pub(crate) offset: Option<u32>,
+}
+
 /// DTO-структура ответа пользователя при успешных событиях регистрации,
 /// авторизации.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct AuthResponse {
-    /// JWT-токен пользователя.
+    /// JWT-токен доступа пользователя.
     pub(crate) token: String,
+    /// Токен обновления сессии.
+    pub(crate) refresh_token: String,
     /// DTO-экземпляр пользователя.
     pub(crate) user: UserDto,
 }
 
 impl AuthResponse {
     /// Создать экземпляр ответа пользователя.
-    pub(crate) fn new(token: impl Into<String>, user: UserDto) -> Self {
+    pub(crate) fn new(tokens: TokenPair, user: UserDto) -> Self {
         Self {
-            token: token.into(),
+            token: tokens.access_token,
+            refresh_token: tokens.refresh_token,
             user,
         }
     }
@@ -143,6 +365,28 @@ pub(crate) struct AuthenticatedUser {
     pub(crate) id: DataId,
     /// Имя пользователя (`username`).
     pub(crate) username: Username,
+    /// Роль пользователя на момент выдачи токена.
+    pub(crate) role: Role,
+}
+
+impl AuthenticatedUser {
+    /// Есть ли у пользователя право `permission`.
+    pub(crate) fn can(&self, permission: Permission) -> bool {
+        self.role.can(permission)
+    }
+
+    /// Проверить наличие права `permission`.
+    ///
+    /// ## Errors
+    ///
+    /// [`DomainError::Forbidden`], если роль пользователя его не даёт.
+    pub(crate) fn require(&self, permission: Permission) -> Result<(), DomainError> {
+        if self.can(permission) {
+            Ok(())
+        } else {
+            Err(DomainError::Forbidden)
+        }
+    }
 }
 
 impl From<Claims> for AuthenticatedUser {
@@ -150,6 +394,7 @@ impl From<Claims> for AuthenticatedUser {
         Self {
             id: claims.user_id,
             username: claims.username,
+            role: claims.role,
         }
     }
 }
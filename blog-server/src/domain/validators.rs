@@ -1,10 +1,11 @@
 //! Валидаторы для различных ситуаций.
 
 use crate::settings::{
+    AVATAR_URL_MAX_CHARS, BIO_MAX_CHARS, COMMENT_MAX_CHARS, DISPLAY_NAME_MAX_CHARS,
     EMAIL_RANGE_LEN_CHARS, PASSWORD_MIN_CHARS, PASSWORD_VALID_SPECIAL_CHARS, POSTS_TITLE_MAX_CHARS,
+    SEARCH_QUERY_MAX_CHARS, SLUG_MAX_CHARS, TAG_RANGE_LEN_CHARS, UPLOADS_URL_PATH,
     USERNAME_RANGE_LEN_CHARS,
 };
-use regex::Regex;
 
 /// Является ли переданный `char` допустимым для пароля специальным символом.
 fn is_special_char(c: char) -> bool {
@@ -101,31 +102,59 @@ pub(super) fn validate_username(username: &str) -> Result<(), String> {
     Ok(())
 }
 
-const BLACKLIST_WORDS: [&str; 9] = [
-    "дурак",
-    "глупец",
-    "фронтенд",
-    "legacy",
-    "рефакторинг",
-    "техдолг",
-    "политик",
-    "кому нужен ваш Rust",
-    "я не тестировал, но должно работать",
-];
-
-/// Проверить предоставленный текст на наличие слов и фраз в списке запрещённых.
-fn check_in_blacklist(text: &str) -> Result<(), String> {
-    let pattern = BLACKLIST_WORDS
-        .iter()
-        .map(|w| format!(r"\b{}\b", regex::escape(w)))
-        .collect::<Vec<_>>()
-        .join("|");
-
-    let regx = Regex::new(&pattern).map_err(|err| err.to_string())?;
-    if let Some(match_black) = regx.find(text) {
+/// Валидатор отображаемого имени пользователя. Пустое имя допускается:
+/// вместо него показывается `username`.
+pub(super) fn validate_display_name(name: &str) -> Result<(), String> {
+    if name.trim().chars().count() > DISPLAY_NAME_MAX_CHARS {
         return Err(format!(
-            "найдено запрещённое выражение: '{}'",
-            match_black.as_str()
+            "допустимая длина отображаемого имени до {} символов",
+            DISPLAY_NAME_MAX_CHARS
+        ));
+    }
+
+    if name.chars().any(char::is_control) {
+        return Err("отображаемое имя не может содержать управляющие символы".to_string());
+    }
+
+    Ok(())
+}
+
+/// Валидатор информации о себе в профиле пользователя.
+pub(super) fn validate_bio(bio: &str) -> Result<(), String> {
+    if bio.trim().chars().count() > BIO_MAX_CHARS {
+        return Err(format!(
+            "допустимая длина информации о себе до {} символов",
+            BIO_MAX_CHARS
+        ));
+    }
+
+    Ok(())
+}
+
+/// Валидатор адреса аватара: ссылка `http(s)://` или адрес загруженного
+/// вложения (`/uploads/{key}`). Пустой адрес убирает аватар.
+pub(super) fn validate_avatar_url(url: &str) -> Result<(), String> {
+    if url.is_empty() {
+        return Ok(());
+    }
+
+    if url.chars().count() > AVATAR_URL_MAX_CHARS {
+        return Err(format!(
+            "допустимая длина адреса аватара до {} символов",
+            AVATAR_URL_MAX_CHARS
+        ));
+    }
+
+    let uploaded = url
+        .strip_prefix(UPLOADS_URL_PATH)
+        .is_some_and(|rest| rest.starts_with('/'));
+    let valid_scheme = url.starts_with("https://") || url.starts_with("http://") || uploaded;
+    let valid_chars = url
+        .chars()
+        .all(|c| c.is_ascii_graphic() && !matches!(c, '"' | '\'' | '<' | '>' | '\\'));
+    if !valid_scheme || !valid_chars {
+        return Err(format!(
+            "ожидается ссылка http(s):// или адрес вложения {UPLOADS_URL_PATH}/..."
         ));
     }
 
@@ -142,8 +171,6 @@ pub(super) fn validate_title(title: &str) -> Result<(), String> {
         ));
     }
 
-    check_in_blacklist(trimmed)?;
-
     Ok(())
 }
 
@@ -154,7 +181,101 @@ pub(super) fn validate_content(content: &str) -> Result<(), String> {
         return Err("публикация должна содержать текст".to_string());
     }
 
-    check_in_blacklist(trimmed)
+    Ok(())
+}
+
+/// Валидатор текста комментария к публикации.
+pub(super) fn validate_comment(comment: &str) -> Result<(), String> {
+    let trimmed = comment.trim();
+    if trimmed.is_empty() || trimmed.chars().count() > COMMENT_MAX_CHARS {
+        return Err(format!(
+            "допустимая длина комментария от 1 до {} символов",
+            COMMENT_MAX_CHARS
+        ));
+    }
+
+    Ok(())
+}
+
+/// Валидатор тега публикации.
+///
+/// Тег — одно слово: буквы (в том числе кириллица), цифры, `-` и `_`.
+pub(super) fn validate_tag(tag: &str) -> Result<(), String> {
+    if !TAG_RANGE_LEN_CHARS.contains(&tag.chars().count()) {
+        return Err(format!(
+            "допустимая длина тега от {} до {} символов",
+            TAG_RANGE_LEN_CHARS.start(),
+            TAG_RANGE_LEN_CHARS.end()
+        ));
+    }
+
+    if !tag
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("тег может содержать буквы, цифры, '-' и '_'".to_string());
+    }
+
+    Ok(())
+}
+
+/// Валидатор адреса (slug) публикации.
+///
+/// Адрес состоит из латинских букв в нижнем регистре и цифр, разделённых
+/// одиночными `-`.
+pub(super) fn validate_slug(slug: &str) -> Result<(), String> {
+    if slug.is_empty() || slug.len() > SLUG_MAX_CHARS {
+        return Err(format!(
+            "допустимая длина адреса от 1 до {} символов",
+            SLUG_MAX_CHARS
+        ));
+    }
+
+    let valid_chars = slug
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid_chars || slug.starts_with('-') || slug.ends_with('-') || slug.contains("--") {
+        return Err(
+            "адрес может содержать латинские буквы в нижнем регистре, цифры и одиночные '-'"
+                .to_string(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Валидатор ключа файла вложения: 32 шестнадцатеричных символа в нижнем
+/// регистре, точка и расширение из латинских букв и цифр.
+pub(super) fn validate_storage_key(key: &str) -> Result<(), String> {
+    let valid = key.split_once('.').is_some_and(|(name, ext)| {
+        name.len() == 32
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+            && (1..=5).contains(&ext.len())
+            && ext
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+    });
+
+    if !valid {
+        return Err("некорректный ключ файла".to_string());
+    }
+
+    Ok(())
+}
+
+/// Валидатор поискового запроса по публикациям.
+pub(super) fn validate_search_query(query: &str) -> Result<(), String> {
+    let trimmed = query.trim();
+    if trimmed.is_empty() || trimmed.chars().count() > SEARCH_QUERY_MAX_CHARS {
+        return Err(format!(
+            "допустимая длина поискового запроса от 1 до {} символов",
+            SEARCH_QUERY_MAX_CHARS
+        ));
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -299,22 +420,6 @@ mod tests {
         assert!(validate_title(title).is_ok());
     }
 
-    #[test]
-    fn validate_title_with_blacklist_word() {
-        for word in BLACKLIST_WORDS.iter() {
-            let title = format!("Заголовок с запрещённым словом: {}", word);
-            let err = validate_title(&title);
-            assert!(err.is_err());
-        }
-    }
-
-    #[test]
-    fn validate_title_with_partial_blacklist_match() {
-        // Проверяем, что частичные совпадения не срабатывают (из-за \b в regex)
-        let safe_title = "дуракк"; // не должно считаться совпадением с "дурак"
-        assert!(validate_title(safe_title).is_ok());
-    }
-
     #[test]
     fn validate_title_ok() {
         let title = "Корректный заголовок без запрещённых слов";
@@ -334,38 +439,103 @@ mod tests {
     }
 
     #[test]
-    fn validate_content_with_blacklist_word() {
-        for word in BLACKLIST_WORDS.iter() {
-            let content = format!("Текст публикации, содержащий запрещённое слово: {}", word);
-            let err = validate_content(&content).unwrap_err();
-            assert!(err.contains("найдено запрещённое выражение"));
-        }
+    fn validate_content_ok() {
+        let content = "Содержание публикации с нормальным текстом без запрещённых выражений";
+        assert!(validate_content(content).is_ok());
     }
 
     #[test]
-    fn validate_content_with_multiple_blacklist_words() {
-        let content = "Здесь есть дурак и глупец одновременно";
-        let err = validate_content(content).unwrap_err();
-        assert!(err.contains("найдено запрещённое выражение"));
+    fn validate_content_long_text() {
+        // Длинный текст без запрещённых слов должен проходить
+        let long_content = "a ".repeat(1000) + "нормальный текст";
+        assert!(validate_content(&long_content).is_ok());
     }
 
     #[test]
-    fn validate_content_partial_match_safe() {
-        // Частичные совпадения не должны срабатывать
-        let safe_content = "Это не дуракк, а умный человек";
-        assert!(validate_content(safe_content).is_ok());
+    fn validate_comment_empty() {
+        let err = validate_comment("  ").unwrap_err();
+        assert!(err.contains(&COMMENT_MAX_CHARS.to_string()));
     }
 
     #[test]
-    fn validate_content_ok() {
-        let content = "Содержание публикации с нормальным текстом без запрещённых выражений";
-        assert!(validate_content(content).is_ok());
+    fn validate_comment_too_long() {
+        let comment = "ы".repeat(COMMENT_MAX_CHARS + 1);
+        let err = validate_comment(&comment).unwrap_err();
+        assert!(err.contains(&COMMENT_MAX_CHARS.to_string()));
     }
 
     #[test]
-    fn validate_content_long_text() {
-        // Длинный текст без запрещённых слов должен проходить
-        let long_content = "a ".repeat(1000) + "нормальный текст";
-        assert!(validate_content(&long_content).is_ok());
+    fn validate_comment_exactly_max_length_multibyte() {
+        let comment = "ы".repeat(COMMENT_MAX_CHARS);
+        assert!(validate_comment(&comment).is_ok());
+    }
+
+    #[test]
+    fn validate_tag_bad_len() {
+        let max = *TAG_RANGE_LEN_CHARS.end();
+        assert!(validate_tag("").is_err());
+        let err = validate_tag(&"я".repeat(max + 1)).unwrap_err();
+        assert!(err.contains("допустимая длина тега"));
+    }
+
+    #[test]
+    fn validate_tag_bad_chars() {
+        let err = validate_tag("rust lang").unwrap_err();
+        assert_eq!(err, "тег может содержать буквы, цифры, '-' и '_'");
+    }
+
+    #[test]
+    fn validate_tag_ok() {
+        assert!(validate_tag("rust").is_ok());
+        assert!(validate_tag("веб-разработка").is_ok());
+    }
+
+    #[test]
+    fn validate_slug_rules() {
+        assert!(validate_slug("privet-mir-2").is_ok());
+        assert!(validate_slug("").is_err());
+        assert!(validate_slug("Privet").is_err());
+        assert!(validate_slug("-privet").is_err());
+        assert!(validate_slug("privet--mir").is_err());
+        assert!(validate_slug("привет").is_err());
+        assert!(validate_slug(&"a".repeat(SLUG_MAX_CHARS + 1)).is_err());
+    }
+
+    #[test]
+    fn validate_storage_key_rules() {
+        assert!(validate_storage_key("0123456789abcdef0123456789abcdef.png").is_ok());
+        assert!(validate_storage_key("0123456789abcdef0123456789abcdef").is_err());
+        assert!(validate_storage_key("0123456789ABCDEF0123456789abcdef.png").is_err());
+        assert!(validate_storage_key("../456789abcdef0123456789abcdef.png").is_err());
+        assert!(validate_storage_key("0123456789abcdef0123456789abcdef.p/g").is_err());
+        assert!(validate_storage_key("0123456789abcdef0123456789abcdef.").is_err());
+    }
+
+    #[test]
+    fn validate_profile_fields() {
+        assert!(validate_display_name("").is_ok());
+        assert!(validate_display_name("Иван Петров").is_ok());
+        assert!(validate_display_name("Иван\nПетров").is_err());
+        assert!(validate_display_name(&"я".repeat(DISPLAY_NAME_MAX_CHARS + 1)).is_err());
+        assert!(validate_bio(&"ы".repeat(BIO_MAX_CHARS)).is_ok());
+        assert!(validate_bio(&"ы".repeat(BIO_MAX_CHARS + 1)).is_err());
+    }
+
+    #[test]
+    fn validate_avatar_url_rules() {
+        assert!(validate_avatar_url("").is_ok());
+        assert!(validate_avatar_url("https://example.com/me.png").is_ok());
+        assert!(validate_avatar_url("/uploads/0123456789abcdef0123456789abcdef.png").is_ok());
+        assert!(validate_avatar_url("/uploadsx/me.png").is_err());
+        assert!(validate_avatar_url("javascript:alert(1)").is_err());
+        assert!(validate_avatar_url("https://example.com/a b.png").is_err());
+        assert!(validate_avatar_url("https://example.com/\"onerror=\"x").is_err());
+    }
+
+    #[test]
+    fn validate_search_query_bounds() {
+        assert!(validate_search_query("   ").is_err());
+        assert!(validate_search_query(&"щ".repeat(SEARCH_QUERY_MAX_CHARS + 1)).is_err());
+        assert!(validate_search_query("портал пицца").is_ok());
     }
 }
@@ -0,0 +1,109 @@
+//! Подтверждение адреса электронной почты.
+//!
+//! После регистрации пользователь получает письмо со ссылкой, содержащей
+//! подписанный токен подтверждения. Токен привязан к адресу, на который
+//! отправлен: после смены адреса он недействителен.
+
+use crate::{
+    domain::{types::Username, user::User},
+    errors::DomainError,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Подтверждение адреса по токену из письма.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct VerifyEmail {
+    /// Токен подтверждения из письма.
+    pub(crate) token: String,
+}
+
+/// Требования к подтверждению адреса электронной почты.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct EmailPolicy {
+    /// Создавать публикации могут только пользователи с подтверждённым
+    /// адресом.
+    pub(crate) required_to_post: bool,
+}
+
+impl EmailPolicy {
+    /// Создать требования к подтверждению адреса.
+    pub(crate) fn new(required_to_post: bool) -> Self {
+        Self { required_to_post }
+    }
+
+    /// Проверить, что пользователь `author` может создавать публикации.
+    ///
+    /// ## Errors
+    ///
+    /// [`DomainError::EmailNotVerified`], если подтверждение адреса
+    /// обязательно, а адрес автора не подтверждён.
+    pub(crate) fn check_can_post(&self, author: &User) -> Result<(), DomainError> {
+        if self.required_to_post && !author.is_email_verified() {
+            return Err(DomainError::EmailNotVerified);
+        }
+
+        Ok(())
+    }
+}
+
+/// Ссылка на страницу подтверждения адреса веб-клиента `site_url`.
+pub(crate) fn verification_link(site_url: &str, token: &str) -> String {
+    format!("{site_url}/verify-email?token={token}")
+}
+
+/// Текст письма со ссылкой на подтверждение адреса.
+pub(crate) fn verification_mail_body(
+    username: &Username,
+    link: &str,
+    token: &str,
+    expires_at: DateTime<Utc>,
+) -> String {
+    format!(
+        "Здравствуйте, {username}!\n\n\
+         Чтобы подтвердить адрес электронной почты вашей учётной записи,\n\
+         перейдите по ссылке:\n\n\
+         {link}\n\n\
+         или укажите токен в клиенте блога:\n\n\
+         {token}\n\n\
+         Ссылка действует до {} (UTC).\n\
+         Если вы не регистрировались в блоге, проигнорируйте это письмо.\n",
+        expires_at.format("%d.%m.%Y %H:%M")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::{Email, Username};
+
+    fn user(verified: bool) -> User {
+        let user = User::new(
+            None,
+            Username::try_from("author".to_string()).unwrap(),
+            Email::try_from("author@example.com".to_string()).unwrap(),
+            "hash",
+            None,
+        );
+        user.with_email_verified_at(verified.then(Utc::now))
+    }
+
+    #[test]
+    fn unverified_author_can_post_when_not_required() {
+        let policy = EmailPolicy::new(false);
+
+        assert!(policy.check_can_post(&user(false)).is_ok());
+        assert!(policy.check_can_post(&user(true)).is_ok());
+    }
+
+    #[test]
+    fn unverified_author_cannot_post_when_required() {
+        let policy = EmailPolicy::new(true);
+
+        assert!(matches!(
+            policy.check_can_post(&user(false)),
+            Err(DomainError::EmailNotVerified)
+        ));
+        assert!(policy.check_can_post(&user(true)).is_ok());
+    }
+}
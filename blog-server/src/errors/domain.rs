@@ -33,6 +33,45 @@ pub enum DomainError {
     #[error("Ошибка учётных данных: {0}")]
     InvalidCredentials(String),
 
+    /// Учётная запись заблокирована администратором.
+    #[error("Учётная запись заблокирована")]
+    AccountDisabled,
+
+    /// Вход временно заблокирован после неудачных попыток.
+    #[error("Вход временно заблокирован после неудачных попыток, повторите через {retry_after} с")]
+    AccountLocked {
+        /// Через сколько секунд блокировка будет снята.
+        retry_after: u64,
+    },
+
+    /// Действие доступно только с подтверждённым адресом электронной почты.
+    #[error("Подтвердите адрес электронной почты")]
+    EmailNotVerified,
+
+    /// Адрес электронной почты уже подтверждён.
+    #[error("Адрес электронной почты уже подтверждён")]
+    EmailAlreadyVerified,
+
+    /// Некорректная роль пользователя.
+    #[error("Некорректная роль: {0}")]
+    InvalidRole(String),
+
+    /// Некорректные данные профиля пользователя.
+    #[error("Некорректные данные профиля: {0}")]
+    InvalidProfile(String),
+
+    /// Некорректный способ удаления учётной записи.
+    #[error("Некорректный способ удаления учётной записи: {0}")]
+    InvalidDeletionMode(String),
+
+    /// Некорректный фильтр журнала аудита.
+    #[error("Некорректный фильтр журнала аудита: {0}")]
+    InvalidAuditFilter(String),
+
+    /// Токен сессии недействителен (неизвестен, истёк или отозван).
+    #[error("Недействительный токен: {0}")]
+    InvalidToken(String),
+
     /// Пост (публикация) не найден (в том числе для ситуаций, когда пытаются
     /// найти чужой пост).
     #[error("Публикация не найдена")]
@@ -43,6 +82,78 @@ pub enum DomainError {
     #[error("Публикация не соответствует Правилам: {0}")]
     InvalidPostContent(String),
 
+    /// Комментарий не найден (в том числе, если он относится к другой
+    /// публикации).
+    #[error("Комментарий не найден")]
+    CommentNotFound,
+
+    /// Ревизия публикации не найдена.
+    #[error("Ревизия публикации не найдена")]
+    RevisionNotFound,
+
+    /// Комментарий содержит некорректные данные.
+    #[error("Комментарий не соответствует Правилам: {0}")]
+    InvalidCommentContent(String),
+
+    /// Некорректный тег публикации.
+    #[error("Некорректный тег: {0}")]
+    InvalidTag(String),
+
+    /// Некорректный поисковый запрос.
+    #[error("Некорректный поисковый запрос: {0}")]
+    InvalidSearchQuery(String),
+
+    /// Некорректный курсор постраничной выгрузки.
+    #[error("Некорректный курсор: {0}")]
+    InvalidCursor(String),
+
+    /// Некорректный адрес (slug) публикации.
+    #[error("Некорректный адрес публикации: {0}")]
+    InvalidSlug(String),
+
+    /// Некорректный вид реакции на публикацию.
+    #[error("Некорректная реакция: {0}")]
+    InvalidReaction(String),
+
+    /// Некорректная подписка на автора.
+    #[error("Некорректная подписка: {0}")]
+    InvalidFollow(String),
+
+    /// Вложение не найдено.
+    #[error("Вложение не найдено")]
+    AttachmentNotFound,
+
+    /// Файл вложения не прошёл проверку (тип, размеры изображения и т.п.).
+    #[error("Некорректное вложение: {0}")]
+    InvalidAttachment(String),
+
+    /// Файл вложения превышает допустимый размер.
+    #[error("Слишком большое вложение: {0}")]
+    AttachmentTooLarge(String),
+
+    /// Некорректные параметры публикации (статус, время публикации).
+    #[error("Некорректные параметры публикации: {0}")]
+    InvalidPublication(String),
+
+    /// Правило модерации не найдено.
+    #[error("Правило модерации не найдено")]
+    ModerationRuleNotFound,
+
+    /// Некорректное правило модерации.
+    #[error("Некорректное правило модерации: {0}")]
+    InvalidModerationRule(String),
+
+    /// Правило модерации с таким шаблоном уже существует.
+    #[error("Правило модерации с таким шаблоном уже существует")]
+    ModerationRuleAlreadyExists,
+
+    /// Превышен лимит частоты запросов.
+    #[error("Слишком много запросов, повторите через {retry_after} с")]
+    RateLimited {
+        /// Через сколько секунд можно повторить запрос.
+        retry_after: u64,
+    },
+
     /// Доступ для пользователя к запрошенному разделу запрещён.
     #[error("Вы не можете изменять эти данные")]
     Forbidden,
@@ -67,12 +178,60 @@ impl DomainError {
         /// Конструктор для ошибки [`DomainError::InvalidCredentials`].
         fn invalid_credentials => InvalidCredentials;
         
+        /// Конструктор для ошибки [`DomainError::InvalidToken`].
+        fn invalid_token => InvalidToken;
+
+        /// Конструктор для ошибки [`DomainError::InvalidRole`].
+        fn invalid_role => InvalidRole;
+
+        /// Конструктор для ошибки [`DomainError::InvalidProfile`].
+        fn invalid_profile => InvalidProfile;
+
+        /// Конструктор для ошибки [`DomainError::InvalidDeletionMode`].
+        fn invalid_deletion_mode => InvalidDeletionMode;
+
+        /// Конструктор для ошибки [`DomainError::InvalidAuditFilter`].
+        fn invalid_audit_filter => InvalidAuditFilter;
+
         /// Конструктор для ошибки [`DomainError::ServerError`].
         fn server_err => ServerError;
         
         /// Конструктор для ошибки [`DomainError::InvalidPostContent`].
         fn invalid_post => InvalidPostContent;
+
+        /// Конструктор для ошибки [`DomainError::InvalidCommentContent`].
+        fn invalid_comment => InvalidCommentContent;
+
+        /// Конструктор для ошибки [`DomainError::InvalidTag`].
+        fn invalid_tag => InvalidTag;
+
+        /// Конструктор для ошибки [`DomainError::InvalidSearchQuery`].
+        fn invalid_search => InvalidSearchQuery;
+
+        /// Конструктор для ошибки [`DomainError::InvalidCursor`].
+        fn invalid_cursor => InvalidCursor;
+
+        /// Конструктор для ошибки [`DomainError::InvalidSlug`].
+        fn invalid_slug => InvalidSlug;
+
+        /// Конструктор для ошибки [`DomainError::InvalidReaction`].
+        fn invalid_reaction => InvalidReaction;
+
+        /// Конструктор для ошибки [`DomainError::InvalidFollow`].
+        fn invalid_follow => InvalidFollow;
+
+        /// Конструктор для ошибки [`DomainError::InvalidAttachment`].
+        fn invalid_attachment => InvalidAttachment;
+
+        /// Конструктор для ошибки [`DomainError::AttachmentTooLarge`].
+        fn attachment_too_large => AttachmentTooLarge;
+
+        /// Конструктор для ошибки [`DomainError::InvalidPublication`].
+        fn invalid_publication => InvalidPublication;
         
+        /// Конструктор для ошибки [`DomainError::InvalidModerationRule`].
+        fn invalid_rule => InvalidModerationRule;
+
         /// Конструктор для ошибки [`DomainError::ApiError`].
         fn api_error => ApiError;
 
@@ -8,23 +8,71 @@ impl From<DomainError> for Status {
         match err {
             DomainError::InvalidEmail(_)
             | DomainError::InvalidUsername(_)
+            | DomainError::InvalidRole(_)
+            | DomainError::InvalidProfile(_)
+            | DomainError::InvalidDeletionMode(_)
+            | DomainError::InvalidAuditFilter(_)
             | DomainError::InvalidPostContent(_)
+            | DomainError::InvalidCommentContent(_)
+            | DomainError::InvalidTag(_)
+            | DomainError::InvalidSearchQuery(_)
+            | DomainError::InvalidCursor(_)
+            | DomainError::InvalidSlug(_)
+            | DomainError::InvalidReaction(_)
+            | DomainError::InvalidFollow(_)
+            | DomainError::InvalidAttachment(_)
+            | DomainError::InvalidPublication(_)
+            | DomainError::InvalidModerationRule(_)
             | DomainError::ApiError(_) => Status::new(Code::InvalidArgument, err.to_string()),
 
-            DomainError::Forbidden => Status::new(Code::PermissionDenied, err.to_string()),
+            DomainError::Forbidden
+            | DomainError::AccountDisabled
+            | DomainError::EmailNotVerified => {
+                Status::new(Code::PermissionDenied, err.to_string())
+            }
 
-            DomainError::UserNotFound | DomainError::PostNotFound => {
+            DomainError::UserNotFound
+            | DomainError::PostNotFound
+            | DomainError::CommentNotFound
+            | DomainError::RevisionNotFound
+            | DomainError::AttachmentNotFound
+            | DomainError::ModerationRuleNotFound => {
                 Status::new(Code::NotFound, err.to_string())
             }
 
-            DomainError::UserAlreadyExists | DomainError::EmailAlreadyExists => {
+            DomainError::UserAlreadyExists
+            | DomainError::EmailAlreadyExists
+            | DomainError::EmailAlreadyVerified
+            | DomainError::ModerationRuleAlreadyExists => {
                 Status::new(Code::AlreadyExists, err.to_string())
             }
 
-            DomainError::InvalidCredentials(_) | DomainError::InvalidPassword(_) => {
+            DomainError::InvalidCredentials(_)
+            | DomainError::InvalidPassword(_)
+            | DomainError::InvalidToken(_) => {
                 Status::new(Code::Unauthenticated, err.to_string())
             }
 
+            DomainError::AttachmentTooLarge(_) => {
+                Status::new(Code::ResourceExhausted, err.to_string())
+            }
+
+            DomainError::RateLimited { retry_after } => {
+                let mut status = Status::new(Code::ResourceExhausted, err.to_string());
+                status
+                    .metadata_mut()
+                    .insert("retry-after", retry_after.into());
+                status
+            }
+
+            DomainError::AccountLocked { retry_after } => {
+                let mut status = Status::new(Code::FailedPrecondition, err.to_string());
+                status
+                    .metadata_mut()
+                    .insert("retry-after", retry_after.into());
+                status
+            }
+
             DomainError::ServerError(_) => Status::new(Code::Internal, err.to_string()),
         }
     }
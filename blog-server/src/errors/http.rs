@@ -1,25 +1,59 @@
 //! Ошибки HTTP-сервера.
 
 use crate::errors::DomainError;
-use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use actix_web::{
+    http::{header::RETRY_AFTER, StatusCode},
+    HttpResponse, ResponseError,
+};
 
 impl ResponseError for DomainError {
     fn error_response(&self) -> HttpResponse {
         let (status, details) = match self {
-            DomainError::Forbidden => (StatusCode::FORBIDDEN, self.to_string()),
-            DomainError::UserNotFound | DomainError::PostNotFound => {
-                (StatusCode::NOT_FOUND, self.to_string())
-            }
-            DomainError::UserAlreadyExists | DomainError::EmailAlreadyExists => {
-                (StatusCode::CONFLICT, self.to_string())
-            }
-            DomainError::InvalidCredentials(_) | DomainError::InvalidPassword(_) => {
+            DomainError::Forbidden
+            | DomainError::AccountDisabled
+            | DomainError::EmailNotVerified => (StatusCode::FORBIDDEN, self.to_string()),
+            DomainError::UserNotFound
+            | DomainError::PostNotFound
+            | DomainError::CommentNotFound
+            | DomainError::RevisionNotFound
+            | DomainError::AttachmentNotFound
+            | DomainError::ModerationRuleNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            DomainError::UserAlreadyExists
+            | DomainError::EmailAlreadyExists
+            | DomainError::EmailAlreadyVerified
+            | DomainError::ModerationRuleAlreadyExists => (StatusCode::CONFLICT, self.to_string()),
+            DomainError::InvalidCredentials(_)
+            | DomainError::InvalidPassword(_)
+            | DomainError::InvalidToken(_) => {
                 (StatusCode::UNAUTHORIZED, self.to_string())
             }
             DomainError::InvalidEmail(_)
             | DomainError::InvalidUsername(_)
+            | DomainError::InvalidRole(_)
+            | DomainError::InvalidProfile(_)
+            | DomainError::InvalidDeletionMode(_)
+            | DomainError::InvalidAuditFilter(_)
             | DomainError::InvalidPostContent(_)
+            | DomainError::InvalidCommentContent(_)
+            | DomainError::InvalidTag(_)
+            | DomainError::InvalidSearchQuery(_)
+            | DomainError::InvalidCursor(_)
+            | DomainError::InvalidSlug(_)
+            | DomainError::InvalidReaction(_)
+            | DomainError::InvalidFollow(_)
+            | DomainError::InvalidAttachment(_)
+            | DomainError::InvalidPublication(_)
+            | DomainError::InvalidModerationRule(_)
             | DomainError::ApiError(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            DomainError::AttachmentTooLarge(_) => {
+                (StatusCode::PAYLOAD_TOO_LARGE, self.to_string())
+            }
+            DomainError::RateLimited { retry_after } => {
+                return retry_later(StatusCode::TOO_MANY_REQUESTS, *retry_after, self);
+            }
+            DomainError::AccountLocked { retry_after } => {
+                return retry_later(StatusCode::LOCKED, *retry_after, self);
+            }
 
             DomainError::ServerError(_) => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
         };
@@ -30,3 +64,14 @@ impl ResponseError for DomainError {
         }))
     }
 }
+
+/// Ответ с заголовком `Retry-After`: запрос можно повторить через
+/// `retry_after` секунд.
+fn retry_later(status: StatusCode, retry_after: u64, err: &DomainError) -> HttpResponse {
+    HttpResponse::build(status)
+        .insert_header((RETRY_AFTER, retry_after.to_string()))
+        .json(serde_json::json!({
+            "error": status.as_u16(),
+            "details": err.to_string()
+        }))
+}
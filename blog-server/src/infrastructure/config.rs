@@ -1,13 +1,28 @@
 //! Конфигурация веб-сервера.
 
-use crate::infrastructure::jwt::JwtService;
-use crate::settings::{DB_MAX_CONN, DB_URL_TEMPLATE};
+use crate::domain::types::Username;
+use crate::infrastructure::{
+    jwt::JwtService,
+    mailer::{FileMailer, Mailer, SmtpMailer},
+    rate_limit::RateLimit,
+};
+use crate::settings::{
+    ARGS_HELP, DB_MAX_CONN, DB_URL_TEMPLATE, FEED_AUTHOR_TITLE_DEFAULT, FEED_DESCRIPTION_DEFAULT,
+    FEED_SITE_URL_DEFAULT, FEED_TITLE_DEFAULT, LOGIN_LOCKOUT_MINUTES_DEFAULT,
+    LOGIN_MAX_FAILURES_DEFAULT, MAIL_DIR_DEFAULT, MAIL_FROM_DEFAULT, RATE_LIMIT_AUTH_DEFAULT,
+    RATE_LIMIT_WRITE_DEFAULT, TRASH_RETENTION_DAYS_DEFAULT, UPLOADS_DIR_DEFAULT,
+    UPLOAD_MAX_BYTES_DEFAULT,
+};
 use anyhow::{anyhow, bail, Context, Result as AnyhowResult};
+use chrono::Duration;
+use lettre::message::Mailbox;
 use std::{
     env,
     fmt::Display,
     net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    path::PathBuf,
     str::FromStr,
+    sync::Arc,
 };
 
 trait Cfg {
@@ -24,8 +39,20 @@ pub(crate) struct BlogConfig {
     pub server: ServerCfg,
     /// Настройки безопасности.
     pub security: SecurityCfg,
-    /// Настройки для базы данных.
-    pub db: DBCfg,
+    /// Хранилище данных: PostgreSQL или память процесса.
+    pub data: DataCfg,
+    /// Настройки лент RSS и Atom.
+    pub feed: FeedCfg,
+    /// Настройки хранения вложений.
+    pub storage: StorageCfg,
+    /// Лимиты частоты запросов.
+    pub rate_limit: RateLimitCfg,
+    /// Блокировка входа после неудачных попыток.
+    pub login: LoginCfg,
+    /// Отправка писем.
+    pub mail: MailCfg,
+    /// Подтверждение адреса электронной почты.
+    pub verification: VerificationCfg,
 }
 
 impl BlogConfig {
@@ -33,12 +60,24 @@ impl BlogConfig {
     pub(crate) fn load() -> AnyhowResult<Self> {
         let server = ServerCfg::collect()?;
         let security = SecurityCfg::collect()?;
-        let db = DBCfg::collect()?;
+        let data = DataCfg::collect()?;
+        let feed = FeedCfg::collect()?;
+        let storage = StorageCfg::collect()?;
+        let rate_limit = RateLimitCfg::collect()?;
+        let login = LoginCfg::collect()?;
+        let mail = MailCfg::collect()?;
+        let verification = VerificationCfg::collect()?;
 
         Ok(Self {
             server,
             security,
-            db,
+            data,
+            feed,
+            storage,
+            rate_limit,
+            login,
+            mail,
+            verification,
         })
     }
 }
@@ -52,6 +91,8 @@ pub(crate) struct ServerCfg {
     pub port: u16,
     /// Порт сервера gRPC.
     pub port_grpc: u16,
+    /// Срок хранения публикаций в корзине до окончательного удаления.
+    pub trash_retention: Duration,
 }
 
 impl ServerCfg {
@@ -71,11 +112,18 @@ impl Cfg for ServerCfg {
         let host: Ipv4Addr = load_from_env("SERVER_HOST")?;
         let port: u16 = load_from_env("SERVER_PORT")?;
         let port_grpc: u16 = load_from_env("GRPC_PORT")?;
+        let retention_days: u32 =
+            load_from_env_or("TRASH_RETENTION_DAYS", TRASH_RETENTION_DAYS_DEFAULT)?;
+
+        if retention_days == 0 {
+            bail!("Срок хранения публикаций в корзине `TRASH_RETENTION_DAYS` должен быть больше 0");
+        }
 
         Ok(Self {
             host,
             port,
             port_grpc,
+            trash_retention: Duration::days(retention_days.into()),
         })
     }
 }
@@ -89,6 +137,8 @@ pub(crate) struct SecurityCfg {
     pub cors_max_age: usize,
     /// Инфраструктура для обработки JWT-токенов.
     pub jwt_service: JwtService,
+    /// Пользователи, назначаемые администраторами при запуске сервера.
+    pub admin_usernames: Vec<Username>,
 }
 
 impl Cfg for SecurityCfg {
@@ -100,14 +150,61 @@ impl Cfg for SecurityCfg {
         let jwt_secret: String = load_from_env("JWT_SECRET_KEY")?;
         let jwt_service = JwtService::from_secret(jwt_secret);
 
+        let admin_usernames = load_optional_csv_from_env("ADMIN_USERNAMES")?
+            .into_iter()
+            .map(Username::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!("Ошибка преобразования ADMIN_USERNAMES: {e}"))?;
+
         Ok(Self {
             cors_urls,
             cors_max_age,
             jwt_service,
+            admin_usernames,
         })
     }
 }
 
+/// Хранилище данных блога, выбираемое аргументом командной строки
+/// `--storage`.
+#[derive(Clone)]
+pub(crate) enum DataCfg {
+    /// База данных PostgreSQL (по умолчанию).
+    Postgres(DBCfg),
+    /// Память процесса: данные теряются при остановке сервера. Настройки
+    /// базы данных в этом режиме не требуются.
+    Memory,
+}
+
+impl Cfg for DataCfg {
+    fn collect() -> AnyhowResult<Self> {
+        match storage_mode_from_args(env::args().skip(1))?.as_str() {
+            "postgres" => Ok(Self::Postgres(DBCfg::collect()?)),
+            "memory" => Ok(Self::Memory),
+            mode => bail!("Неизвестный режим хранения `{mode}`\n{ARGS_HELP}"),
+        }
+    }
+}
+
+/// Режим хранения из аргументов командной строки: `--storage <mode>` или
+/// `--storage=<mode>`. Без аргумента выбирается `postgres`.
+fn storage_mode_from_args(args: impl IntoIterator<Item = String>) -> AnyhowResult<String> {
+    let mut mode = String::from("postgres");
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        mode = if arg == "--storage" {
+            args.next()
+                .with_context(|| format!("Не указан режим хранения `--storage`\n{ARGS_HELP}"))?
+        } else if let Some(value) = arg.strip_prefix("--storage=") {
+            value.to_string()
+        } else {
+            bail!("Неизвестный аргумент `{arg}`\n{ARGS_HELP}");
+        };
+    }
+
+    Ok(mode)
+}
+
 /// Настройки для базы данных.
 #[derive(Clone)]
 pub(crate) struct DBCfg {
@@ -151,6 +248,169 @@ impl Cfg for DBCfg {
     }
 }
 
+/// Настройки лент RSS и Atom.
+#[derive(Clone)]
+pub(crate) struct FeedCfg {
+    /// Адрес сайта блога (без завершающего `/`), на который ведут ссылки
+    /// из лент.
+    pub site_url: String,
+    /// Заголовок ленты блога.
+    pub title: String,
+    /// Описание ленты блога.
+    pub description: String,
+    /// Шаблон заголовка ленты автора: `{username}` заменяется именем автора.
+    pub author_title: String,
+}
+
+impl FeedCfg {
+    /// Заголовок ленты автора `username`.
+    pub(crate) fn author_title(&self, username: &str) -> String {
+        self.author_title.replace("{username}", username)
+    }
+}
+
+impl Cfg for FeedCfg {
+    fn collect() -> AnyhowResult<Self> {
+        let site_url: String =
+            load_from_env_or("FEED_SITE_URL", FEED_SITE_URL_DEFAULT.to_string())?;
+        let title = load_from_env_or("FEED_TITLE", FEED_TITLE_DEFAULT.to_string())?;
+        let description =
+            load_from_env_or("FEED_DESCRIPTION", FEED_DESCRIPTION_DEFAULT.to_string())?;
+        let author_title =
+            load_from_env_or("FEED_AUTHOR_TITLE", FEED_AUTHOR_TITLE_DEFAULT.to_string())?;
+
+        let site_url = site_url.trim_end_matches('/').to_string();
+        if !site_url.starts_with("http://") && !site_url.starts_with("https://") {
+            bail!("Адрес сайта `FEED_SITE_URL` должен начинаться с http:// или https://");
+        }
+
+        Ok(Self {
+            site_url,
+            title,
+            description,
+            author_title,
+        })
+    }
+}
+
+/// Настройки хранения вложений.
+#[derive(Clone)]
+pub(crate) struct StorageCfg {
+    /// Каталог, в котором сохраняются файлы вложений.
+    pub uploads_dir: PathBuf,
+    /// Максимальный размер вложения в байтах.
+    pub max_upload_bytes: usize,
+}
+
+impl Cfg for StorageCfg {
+    fn collect() -> AnyhowResult<Self> {
+        let uploads_dir: PathBuf = load_from_env_or("UPLOADS_DIR", UPLOADS_DIR_DEFAULT.into())?;
+        let max_upload_bytes: usize =
+            load_from_env_or("UPLOAD_MAX_BYTES", UPLOAD_MAX_BYTES_DEFAULT)?;
+
+        if max_upload_bytes == 0 {
+            bail!("Максимальный размер вложения `UPLOAD_MAX_BYTES` должен быть больше 0");
+        }
+
+        Ok(Self {
+            uploads_dir,
+            max_upload_bytes,
+        })
+    }
+}
+
+/// Лимиты частоты запросов по группам маршрутов.
+#[derive(Clone)]
+pub(crate) struct RateLimitCfg {
+    /// Вход, регистрация и обновление сессии (на IP-адрес).
+    pub auth: RateLimit,
+    /// Запросы, изменяющие данные (на IP-адрес и на пользователя).
+    pub write: RateLimit,
+}
+
+impl Cfg for RateLimitCfg {
+    fn collect() -> AnyhowResult<Self> {
+        let auth = load_from_env_or("RATE_LIMIT_AUTH", RATE_LIMIT_AUTH_DEFAULT.into())?;
+        let write = load_from_env_or("RATE_LIMIT_WRITE", RATE_LIMIT_WRITE_DEFAULT.into())?;
+
+        Ok(Self { auth, write })
+    }
+}
+
+/// Настройки блокировки входа после неудачных попыток.
+#[derive(Clone)]
+pub(crate) struct LoginCfg {
+    /// Количество неудачных попыток подряд до блокировки входа.
+    pub max_failures: u32,
+    /// Длительность блокировки входа.
+    pub lockout: Duration,
+}
+
+impl Cfg for LoginCfg {
+    fn collect() -> AnyhowResult<Self> {
+        let max_failures: u32 = load_from_env_or("LOGIN_MAX_FAILURES", LOGIN_MAX_FAILURES_DEFAULT)?;
+        let lockout_minutes: u32 =
+            load_from_env_or("LOGIN_LOCKOUT_MINUTES", LOGIN_LOCKOUT_MINUTES_DEFAULT)?;
+
+        if max_failures == 0 {
+            bail!("Количество попыток входа `LOGIN_MAX_FAILURES` должно быть больше 0");
+        }
+        if lockout_minutes == 0 {
+            bail!("Длительность блокировки входа `LOGIN_LOCKOUT_MINUTES` должна быть больше 0");
+        }
+
+        Ok(Self {
+            max_failures,
+            lockout: Duration::minutes(lockout_minutes.into()),
+        })
+    }
+}
+
+/// Настройки отправки писем.
+#[derive(Clone)]
+pub(crate) struct MailCfg {
+    /// Способ отправки писем: SMTP-сервер, если задан `MAIL_SMTP_URL`,
+    /// иначе файлы `.eml` в каталоге `MAIL_DIR`.
+    pub mailer: Arc<dyn Mailer>,
+}
+
+impl Cfg for MailCfg {
+    fn collect() -> AnyhowResult<Self> {
+        let from: Mailbox = load_from_env_or("MAIL_FROM", MAIL_FROM_DEFAULT.to_string())?
+            .parse()
+            .map_err(|e| anyhow!("Ошибка преобразования MAIL_FROM: {e}"))?;
+
+        let mailer: Arc<dyn Mailer> = match env::var("MAIL_SMTP_URL") {
+            Ok(url) => Arc::new(
+                SmtpMailer::from_url(&url, from)
+                    .map_err(|e| anyhow!("Ошибка преобразования MAIL_SMTP_URL: {e}"))?,
+            ),
+            Err(_) => {
+                let dir: PathBuf = load_from_env_or("MAIL_DIR", MAIL_DIR_DEFAULT.into())?;
+                Arc::new(FileMailer::new(dir, from))
+            }
+        };
+
+        Ok(Self { mailer })
+    }
+}
+
+/// Настройки подтверждения адреса электронной почты.
+#[derive(Clone)]
+pub(crate) struct VerificationCfg {
+    /// Создавать публикации могут только пользователи с подтверждённым
+    /// адресом.
+    pub required_to_post: bool,
+}
+
+impl Cfg for VerificationCfg {
+    fn collect() -> AnyhowResult<Self> {
+        let required_to_post = load_from_env_or("EMAIL_VERIFICATION_REQUIRED", false)?;
+
+        Ok(Self { required_to_post })
+    }
+}
+
 /// Загрузить указанный параметр из окружения.
 ///
 /// Дженерик преобразует значение из файла в требуемый тип, если возможно.
@@ -165,6 +425,22 @@ where
         .map_err(|e| anyhow!("Ошибка преобразования {name}: {e}"))
 }
 
+/// Загрузить необязательный параметр из окружения.
+///
+/// Если переменная не задана, возвращается значение `default`.
+fn load_from_env_or<T>(name: &str, default: T) -> AnyhowResult<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    match env::var(name) {
+        Ok(s) => s
+            .parse::<T>()
+            .map_err(|e| anyhow!("Ошибка преобразования {name}: {e}")),
+        Err(_) => Ok(default),
+    }
+}
+
 /// Загрузить список переменных из env.
 ///
 /// Например, для CORS:
@@ -188,3 +464,14 @@ fn load_csv_from_env(name: &str) -> AnyhowResult<Vec<String>> {
 
     Ok(values)
 }
+
+/// Загрузить необязательный список переменных из env.
+///
+/// Если переменная не задана, список пуст.
+fn load_optional_csv_from_env(name: &str) -> AnyhowResult<Vec<String>> {
+    if env::var(name).is_err() {
+        return Ok(Vec::new());
+    }
+
+    load_csv_from_env(name)
+}
@@ -1,15 +1,24 @@
 //! Работа с JWT токенами.
 
 use crate::{
-    domain::types::{DataId, Username},
-    settings::JWT_LIFETIME,
+    domain::{
+        role::Role,
+        token::{random_id, AccessTokenId},
+        types::{DataId, Email, Username},
+    },
+    settings::{EMAIL_VERIFICATION_AUDIENCE, EMAIL_VERIFICATION_TOKEN_LIFETIME, JWT_LIFETIME},
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use jsonwebtoken::{
-    decode, encode, errors::Error as JwtError, Algorithm, DecodingKey, EncodingKey, Header,
-    Validation,
+    decode, encode,
+    errors::{Error as JwtError, ErrorKind},
+    Algorithm, DecodingKey, EncodingKey, Header, Validation,
 };
 use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+};
 
 /// Используемый алгоритм шифрования токена.
 const JWT_ALGORITHM: Algorithm = Algorithm::HS256;
@@ -21,14 +30,18 @@ pub(crate) struct Claims {
     pub user_id: DataId,
     /// Username пользователя.
     pub username: Username,
+    /// Роль пользователя.
+    pub role: Role,
     /// Время истечения.
     pub exp: usize,
+    /// Уникальный идентификатор токена, по которому он может быть отозван.
+    pub jti: String,
 }
 
 impl Claims {
     /// Создать новый экземпляр [`Claims`]. Время истечения токена формируется
     /// автоматически.
-    pub(crate) fn new(user_id: &DataId, username: &Username) -> Self {
+    pub(crate) fn new(user_id: &DataId, username: &Username, role: Role) -> Self {
         let expiration = Utc::now() + JWT_LIFETIME;
         let user_id = user_id.clone();
         let username = username.clone();
@@ -36,11 +49,43 @@ impl Claims {
         Self {
             user_id,
             username,
+            role,
             exp: expiration.timestamp() as usize,
+            jti: random_id(),
+        }
+    }
+
+    /// Сведения о токене, необходимые для его отзыва.
+    pub(crate) fn token_id(&self) -> AccessTokenId {
+        AccessTokenId {
+            jti: self.jti.clone(),
+            expires_at: DateTime::from_timestamp(self.exp as i64, 0).unwrap_or_default(),
         }
     }
 }
 
+/// `Claims` токена подтверждения адреса электронной почты.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EmailClaims {
+    /// Id пользователя.
+    pub sub: DataId,
+    /// Подтверждаемый адрес.
+    pub email: Email,
+    /// Назначение токена, см. [`EMAIL_VERIFICATION_AUDIENCE`].
+    pub aud: String,
+    /// Время истечения.
+    pub exp: usize,
+}
+
+/// Выданный токен доступа.
+#[derive(Debug, Clone)]
+pub(crate) struct AccessToken {
+    /// Закодированный JWT-токен.
+    pub(crate) token: String,
+    /// Сведения о токене для его отзыва.
+    pub(crate) id: AccessTokenId,
+}
+
 /// Фабрика формирования и проверки токенов.
 #[derive(Clone)]
 pub(crate) struct JwtService {
@@ -48,6 +93,9 @@ pub(crate) struct JwtService {
     sign_key: EncodingKey,
     /// Ключ для декодирования JWT-токена.
     verify_key: DecodingKey,
+    /// Список отозванных токенов: `jti` и время истечения токена. Общий
+    /// для всех копий сервиса.
+    revoked: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
 }
 
 impl JwtService {
@@ -61,6 +109,7 @@ impl JwtService {
         Self {
             sign_key,
             verify_key,
+            revoked: Arc::default(),
         }
     }
 
@@ -69,20 +118,79 @@ impl JwtService {
         &self,
         user_id: &DataId,
         username: &Username,
-    ) -> Result<String, JwtError> {
-        let claim = Claims::new(user_id, username);
+        role: Role,
+    ) -> Result<AccessToken, JwtError> {
+        let claim = Claims::new(user_id, username, role);
         let header = Header::new(JWT_ALGORITHM);
 
-        encode(&header, &claim, &self.sign_key)
+        Ok(AccessToken {
+            token: encode(&header, &claim, &self.sign_key)?,
+            id: claim.token_id(),
+        })
     }
 
-    /// Проверка валидности токена.
+    /// Проверка валидности токена. Отозванный токен недействителен.
     pub(crate) fn verify_token(&self, token: &str) -> Result<Claims, JwtError> {
         let mut validation = Validation::new(JWT_ALGORITHM);
         validation.validate_exp = true;
 
-        let token_message = decode(token, &self.verify_key, &validation)?;
-        Ok(token_message.claims)
+        let claims = decode::<Claims>(token, &self.verify_key, &validation)?.claims;
+
+        let revoked = self.revoked.read().unwrap_or_else(PoisonError::into_inner);
+        if revoked.contains_key(&claims.jti) {
+            return Err(ErrorKind::InvalidToken.into());
+        }
+
+        Ok(claims)
+    }
+
+    /// Подписать токен подтверждения адреса `email` пользователя `user_id`.
+    ///
+    /// ## Returns
+    ///
+    /// Токен и время его истечения.
+    pub(crate) fn generate_email_token(
+        &self,
+        user_id: &DataId,
+        email: &Email,
+    ) -> Result<(String, DateTime<Utc>), JwtError> {
+        let expires_at = Utc::now() + EMAIL_VERIFICATION_TOKEN_LIFETIME;
+        let claims = EmailClaims {
+            sub: user_id.clone(),
+            email: email.clone(),
+            aud: EMAIL_VERIFICATION_AUDIENCE.to_string(),
+            exp: expires_at.timestamp() as usize,
+        };
+
+        let token = encode(&Header::new(JWT_ALGORITHM), &claims, &self.sign_key)?;
+        Ok((token, expires_at))
+    }
+
+    /// Проверка подписи и срока действия токена подтверждения адреса.
+    /// Токен доступа здесь недействителен, как и токен подтверждения —
+    /// в [`JwtService::verify_token`].
+    pub(crate) fn verify_email_token(&self, token: &str) -> Result<EmailClaims, JwtError> {
+        let mut validation = Validation::new(JWT_ALGORITHM);
+        validation.validate_exp = true;
+        validation.set_audience(&[EMAIL_VERIFICATION_AUDIENCE]);
+        validation.set_required_spec_claims(&["exp", "aud"]);
+
+        Ok(decode::<EmailClaims>(token, &self.verify_key, &validation)?.claims)
+    }
+
+    /// Отозвать токены до истечения срока их действия. Заодно из списка
+    /// убираются истёкшие токены: они отклоняются и без него.
+    pub(crate) fn revoke(&self, tokens: impl IntoIterator<Item = AccessTokenId>) {
+        let now = Utc::now();
+        let mut revoked = self.revoked.write().unwrap_or_else(PoisonError::into_inner);
+
+        revoked.retain(|_, expires_at| *expires_at > now);
+        revoked.extend(
+            tokens
+                .into_iter()
+                .filter(|token| token.expires_at > now)
+                .map(|token| (token.jti, token.expires_at)),
+        );
     }
 }
 
@@ -111,7 +219,7 @@ mod test {
         let jwt_serv = get_jwt_service();
         let (user_id, username) = get_user_data();
 
-        let result = jwt_serv.generate_token(&user_id, &username);
+        let result = jwt_serv.generate_token(&user_id, &username, Role::User);
         assert!(result.is_ok());
     }
 
@@ -120,12 +228,62 @@ mod test {
         let jwt_serv = get_jwt_service();
         let (user_id, username) = get_user_data();
 
-        let result = jwt_serv.generate_token(&user_id, &username).unwrap();
-        let token_verified = jwt_serv.verify_token(&result);
+        let result = jwt_serv
+            .generate_token(&user_id, &username, Role::Moderator)
+            .unwrap();
+        let token_verified = jwt_serv.verify_token(&result.token);
         assert!(token_verified.is_ok());
 
         let claims = token_verified.unwrap();
         assert_eq!(claims.user_id, user_id);
         assert_eq!(claims.username, username);
+        assert_eq!(claims.role, Role::Moderator);
+        assert_eq!(claims.jti, result.id.jti);
+    }
+
+    #[test]
+    fn test_revoked_token() {
+        let jwt_serv = get_jwt_service();
+        let (user_id, username) = get_user_data();
+
+        let revoked = jwt_serv
+            .generate_token(&user_id, &username, Role::User)
+            .unwrap();
+        let active = jwt_serv
+            .generate_token(&user_id, &username, Role::User)
+            .unwrap();
+        assert_ne!(revoked.id.jti, active.id.jti);
+
+        // Отзыв виден во всех копиях сервиса.
+        jwt_serv.clone().revoke([revoked.id]);
+        assert!(jwt_serv.verify_token(&revoked.token).is_err());
+        assert!(jwt_serv.verify_token(&active.token).is_ok());
+    }
+
+    #[test]
+    fn test_email_token() {
+        let jwt_serv = get_jwt_service();
+        let (user_id, _) = get_user_data();
+        let email: Email = String::from("jenny@example.com").try_into().unwrap();
+
+        let (token, _) = jwt_serv.generate_email_token(&user_id, &email).unwrap();
+        let claims = jwt_serv.verify_email_token(&token).unwrap();
+        assert_eq!(claims.sub, user_id);
+        assert_eq!(claims.email, email);
+    }
+
+    #[test]
+    fn test_email_and_access_tokens_are_not_interchangeable() {
+        let jwt_serv = get_jwt_service();
+        let (user_id, username) = get_user_data();
+        let email: Email = String::from("jenny@example.com").try_into().unwrap();
+
+        let (email_token, _) = jwt_serv.generate_email_token(&user_id, &email).unwrap();
+        let access = jwt_serv
+            .generate_token(&user_id, &username, Role::User)
+            .unwrap();
+
+        assert!(jwt_serv.verify_token(&email_token).is_err());
+        assert!(jwt_serv.verify_email_token(&access.token).is_err());
     }
 }
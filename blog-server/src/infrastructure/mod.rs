@@ -1,4 +1,7 @@
 pub(crate) mod database;
 pub(crate) mod jwt;
 pub(crate) mod logging;
-pub(crate) mod config;
\ No newline at end of file
+pub(crate) mod mailer;
+pub(crate) mod rate_limit;
+pub(crate) mod config;
+pub(crate) mod storage;
@@ -0,0 +1,299 @@
+//! Ограничение частоты запросов по алгоритму «ведро токенов».
+//!
+//! У каждого клиента (IP-адреса) и каждого авторизованного пользователя своё
+//! ведро в каждой группе маршрутов. Запрос забирает из ведра один токен,
+//! токены равномерно восполняются до ёмкости ведра. Состояние хранится
+//! в памяти процесса и общее для HTTP- и gRPC-серверов.
+
+use crate::{
+    errors::DomainError,
+    infrastructure::config::RateLimitCfg,
+    settings::RATE_LIMIT_SWEEP_SECS,
+};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    net::IpAddr,
+    str::FromStr,
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Группа маршрутов с общим лимитом запросов.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum RouteGroup {
+    /// Вход, регистрация и обновление сессии.
+    Auth,
+    /// Запросы, изменяющие данные.
+    Write,
+}
+
+/// Лимит запросов: не более `requests` запросов подряд, ведро полностью
+/// восполняется за `period`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct RateLimit {
+    /// Ёмкость ведра.
+    requests: u32,
+    /// Время полного восполнения ведра.
+    period: Duration,
+}
+
+impl RateLimit {
+    /// Создать лимит `requests` запросов за `period_secs` секунд.
+    pub(crate) const fn new(requests: u32, period_secs: u64) -> Self {
+        Self {
+            requests,
+            period: Duration::from_secs(period_secs),
+        }
+    }
+
+    /// Ёмкость ведра в токенах.
+    fn capacity(&self) -> f64 {
+        f64::from(self.requests)
+    }
+
+    /// Скорость восполнения ведра (токенов в секунду).
+    fn refill_rate(&self) -> f64 {
+        self.capacity() / self.period.as_secs_f64()
+    }
+}
+
+impl From<(u32, u64)> for RateLimit {
+    fn from((requests, period_secs): (u32, u64)) -> Self {
+        Self::new(requests, period_secs)
+    }
+}
+
+impl Display for RateLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.requests, self.period.as_secs())
+    }
+}
+
+impl FromStr for RateLimit {
+    type Err = String;
+
+    /// Разобрать лимит вида `10/60`: запросов за период в секундах.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (requests, period) = s
+            .split_once('/')
+            .ok_or_else(|| format!("ожидается формат <запросов>/<секунд>, получено '{s}'"))?;
+        let requests: u32 = requests
+            .trim()
+            .parse()
+            .map_err(|_| format!("некорректное количество запросов '{requests}'"))?;
+        let period: u64 = period
+            .trim()
+            .parse()
+            .map_err(|_| format!("некорректный период '{period}'"))?;
+
+        if requests == 0 || period == 0 {
+            return Err("количество запросов и период должны быть больше 0".to_string());
+        }
+
+        Ok(Self::new(requests, period))
+    }
+}
+
+/// Владелец ведра: клиент с IP-адресом или авторизованный пользователь.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum RateKey {
+    /// IP-адрес клиента.
+    Ip(IpAddr),
+    /// Id пользователя.
+    User(i64),
+}
+
+/// Ведро токенов.
+#[derive(Debug)]
+struct Bucket {
+    /// Доступные токены.
+    tokens: f64,
+    /// Время последнего восполнения.
+    updated: Instant,
+}
+
+impl Bucket {
+    /// Новое полное ведро.
+    fn full(limit: &RateLimit, now: Instant) -> Self {
+        Self {
+            tokens: limit.capacity(),
+            updated: now,
+        }
+    }
+
+    /// Восполнить токены за время, прошедшее с прошлого восполнения.
+    fn refill(&mut self, limit: &RateLimit, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * limit.refill_rate()).min(limit.capacity());
+        self.updated = now;
+    }
+
+    /// Время ожидания следующего токена (`None`, если токен есть).
+    fn wait(&self, limit: &RateLimit) -> Option<Duration> {
+        (self.tokens < 1.0)
+            .then(|| Duration::from_secs_f64((1.0 - self.tokens) / limit.refill_rate()))
+    }
+}
+
+/// Вёдра всех клиентов.
+#[derive(Debug)]
+struct Buckets {
+    /// Вёдра по группе маршрутов и владельцу.
+    map: HashMap<(RouteGroup, RateKey), Bucket>,
+    /// Время последнего удаления неактивных вёдер.
+    swept: Instant,
+}
+
+/// Ограничитель частоты запросов.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    /// Лимит запросов аутентификации.
+    auth: RateLimit,
+    /// Лимит изменяющих запросов.
+    write: RateLimit,
+    /// Вёдра клиентов.
+    buckets: Mutex<Buckets>,
+}
+
+impl RateLimiter {
+    /// Создать ограничитель с лимитами из конфигурации.
+    pub(crate) fn new(cfg: &RateLimitCfg) -> Self {
+        Self {
+            auth: cfg.auth,
+            write: cfg.write,
+            buckets: Mutex::new(Buckets {
+                map: HashMap::new(),
+                swept: Instant::now(),
+            }),
+        }
+    }
+
+    /// Учесть запрос группы `group` от владельцев `keys` (IP-адреса и, если
+    /// известен, пользователя).
+    ///
+    /// Запрос проходит, только если токен есть в каждом ведре; иначе токены
+    /// не расходуются.
+    ///
+    /// ## Errors
+    ///
+    /// [`DomainError::RateLimited`] со временем, через которое запрос можно
+    /// повторить.
+    pub(crate) fn check(&self, group: RouteGroup, keys: &[RateKey]) -> Result<(), DomainError> {
+        self.check_at(group, keys, Instant::now())
+    }
+
+    fn check_at(
+        &self,
+        group: RouteGroup,
+        keys: &[RateKey],
+        now: Instant,
+    ) -> Result<(), DomainError> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if now.saturating_duration_since(buckets.swept) >= Duration::from_secs(RATE_LIMIT_SWEEP_SECS)
+        {
+            self.sweep(&mut buckets, now);
+        }
+
+        let limit = self.limit(group);
+        let mut wait: Option<Duration> = None;
+        for key in keys {
+            let bucket = buckets
+                .map
+                .entry((group, key.clone()))
+                .or_insert_with(|| Bucket::full(&limit, now));
+            bucket.refill(&limit, now);
+            wait = wait.max(bucket.wait(&limit));
+        }
+
+        if let Some(wait) = wait {
+            return Err(DomainError::RateLimited {
+                retry_after: wait.as_secs_f64().ceil().max(1.0) as u64,
+            });
+        }
+
+        for key in keys {
+            if let Some(bucket) = buckets.map.get_mut(&(group, key.clone())) {
+                bucket.tokens -= 1.0;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Лимит группы маршрутов.
+    fn limit(&self, group: RouteGroup) -> RateLimit {
+        match group {
+            RouteGroup::Auth => self.auth,
+            RouteGroup::Write => self.write,
+        }
+    }
+
+    /// Удалить полные вёдра: их владельцы давно не обращались к серверу,
+    /// и новое ведро ничем не будет отличаться от удалённого.
+    fn sweep(&self, buckets: &mut Buckets, now: Instant) {
+        buckets.map.retain(|(group, _), bucket| {
+            let limit = self.limit(*group);
+            bucket.refill(&limit, now);
+            bucket.tokens < limit.capacity()
+        });
+        buckets.swept = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn limiter() -> RateLimiter {
+        RateLimiter::new(&RateLimitCfg {
+            auth: RateLimit::new(2, 60),
+            write: RateLimit::new(10, 10),
+        })
+    }
+
+    #[test]
+    fn rate_limit_from_str() {
+        assert_eq!("10/60".parse::<RateLimit>(), Ok(RateLimit::new(10, 60)));
+        assert!("10".parse::<RateLimit>().is_err());
+        assert!("0/60".parse::<RateLimit>().is_err());
+        assert!("10/0".parse::<RateLimit>().is_err());
+    }
+
+    #[test]
+    fn bucket_empties_and_refills() {
+        let limiter = limiter();
+        let ip = [RateKey::Ip(Ipv4Addr::LOCALHOST.into())];
+        let now = Instant::now();
+
+        assert!(limiter.check_at(RouteGroup::Auth, &ip, now).is_ok());
+        assert!(limiter.check_at(RouteGroup::Auth, &ip, now).is_ok());
+        assert!(matches!(
+            limiter.check_at(RouteGroup::Auth, &ip, now),
+            Err(DomainError::RateLimited { retry_after: 30 })
+        ));
+        // Другая группа маршрутов считается отдельно.
+        assert!(limiter.check_at(RouteGroup::Write, &ip, now).is_ok());
+
+        let later = now + Duration::from_secs(30);
+        assert!(limiter.check_at(RouteGroup::Auth, &ip, later).is_ok());
+        assert!(limiter.check_at(RouteGroup::Auth, &ip, later).is_err());
+    }
+
+    #[test]
+    fn user_limited_across_addresses() {
+        let limiter = limiter();
+        let now = Instant::now();
+        let from = |ip: [u8; 4]| [RateKey::Ip(ip.into()), RateKey::User(7)];
+
+        assert!(limiter.check_at(RouteGroup::Auth, &from([10, 0, 0, 1]), now).is_ok());
+        assert!(limiter.check_at(RouteGroup::Auth, &from([10, 0, 0, 2]), now).is_ok());
+        // Ведро пользователя пусто, ведро нового адреса не расходуется.
+        assert!(limiter.check_at(RouteGroup::Auth, &from([10, 0, 0, 3]), now).is_err());
+        let ip = [RateKey::Ip([10, 0, 0, 3].into())];
+        assert!(limiter.check_at(RouteGroup::Auth, &ip, now).is_ok());
+        assert!(limiter.check_at(RouteGroup::Auth, &ip, now).is_ok());
+    }
+}
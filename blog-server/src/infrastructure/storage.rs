@@ -0,0 +1,110 @@
+//! Хранилище файлов вложений.
+
+use crate::domain::types::StorageKey;
+use std::{
+    collections::HashMap,
+    io,
+    path::PathBuf,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+use tokio::fs;
+use tonic::async_trait;
+
+/// Хранилище содержимого файлов вложений по ключу.
+///
+/// Сведения о вложениях (владелец, тип, размеры) хранятся в базе данных,
+/// хранилище отвечает только за байты файлов.
+#[async_trait]
+pub(crate) trait FileStorage: Send + Sync {
+    /// Сохранить файл. Существующий файл с тем же ключом перезаписывается.
+    async fn save(&self, key: &StorageKey, data: &[u8]) -> io::Result<()>;
+
+    /// Прочитать файл целиком.
+    async fn load(&self, key: &StorageKey) -> io::Result<Vec<u8>>;
+
+    /// Удалить файл. Отсутствие файла ошибкой не считается.
+    async fn delete(&self, key: &StorageKey) -> io::Result<()>;
+}
+
+/// Хранилище в каталоге локальной файловой системы: ключ используется
+/// как имя файла.
+#[derive(Clone, Debug)]
+pub(crate) struct LocalStorage {
+    /// Корневой каталог хранилища.
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    /// Создать хранилище в каталоге `root`. Каталог создаётся при первом
+    /// сохранении файла.
+    pub(crate) fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Путь к файлу с ключом `key`. Ключ проверен при создании и не
+    /// содержит разделителей пути.
+    fn path(&self, key: &StorageKey) -> PathBuf {
+        self.root.join(key.as_ref())
+    }
+}
+
+#[async_trait]
+impl FileStorage for LocalStorage {
+    /// Файл сначала записывается во временный, затем переименовывается,
+    /// чтобы читатели не видели его частично записанным.
+    async fn save(&self, key: &StorageKey, data: &[u8]) -> io::Result<()> {
+        fs::create_dir_all(&self.root).await?;
+
+        let path = self.path(key);
+        let tmp_path = path.with_extension("part");
+        fs::write(&tmp_path, data).await?;
+        fs::rename(&tmp_path, &path).await
+    }
+
+    async fn load(&self, key: &StorageKey) -> io::Result<Vec<u8>> {
+        fs::read(self.path(key)).await
+    }
+
+    async fn delete(&self, key: &StorageKey) -> io::Result<()> {
+        match fs::remove_file(self.path(key)).await {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+}
+
+/// Хранилище в памяти процесса для режима без базы данных: файлы теряются
+/// при остановке сервера.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryStorage {
+    /// Содержимое файлов по ключу.
+    files: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl MemoryStorage {
+    /// Захватить файлы. Каждая операция меняет их целиком, поэтому после
+    /// паники другого потока данные остаются согласованными.
+    fn files(&self) -> MutexGuard<'_, HashMap<String, Vec<u8>>> {
+        self.files.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl FileStorage for MemoryStorage {
+    async fn save(&self, key: &StorageKey, data: &[u8]) -> io::Result<()> {
+        self.files().insert(key.to_string(), data.to_vec());
+        Ok(())
+    }
+
+    async fn load(&self, key: &StorageKey) -> io::Result<Vec<u8>> {
+        self.files()
+            .get(key.as_ref())
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "файл не найден"))
+    }
+
+    async fn delete(&self, key: &StorageKey) -> io::Result<()> {
+        self.files().remove(key.as_ref());
+        Ok(())
+    }
+}
@@ -4,6 +4,10 @@
 //! публикациями и др. Доступные транспорты: HTTP и gRPC. Сервер обеспечивает
 //! централизованный доступ к репозиториям данных (PostgreSQL).
 //!
+//! С аргументом `--storage memory` данные хранятся в памяти процесса и
+//! теряются при остановке сервера: режим для демонстраций и тестов, не
+//! требующий PostgreSQL.
+//!
 //! ## Технологии
 //!
 //! - HTTP‑сервисы реализованы на `actix_web`.
@@ -26,8 +30,17 @@
 
 use crate::{
     application::AppServices,
-    infrastructure::{config::BlogConfig, database::get_pool_postgres, logging::init_logging},
-    server::{run_blog_grpc, run_blog_server},
+    data::Repositories,
+    infrastructure::{
+        config::{BlogConfig, DataCfg},
+        database::get_pool_postgres,
+        logging::init_logging,
+        storage::{FileStorage, LocalStorage, MemoryStorage},
+    },
+    server::{
+        run_blog_grpc, run_blog_server, run_login_attempts_purge, run_post_scheduler,
+        run_trash_purge,
+    },
     settings::{ENV_HELP, SLEEP_BEFORE_SHUTDOWN_MS},
 };
 use actix_web::rt::{spawn, time};
@@ -35,7 +48,7 @@ use anyhow::{Context, Result as AnyhowResult};
 use dotenvy::dotenv;
 use std::{sync::Arc, time::Duration};
 use tokio::sync::broadcast;
-use tracing::info;
+use tracing::{info, warn};
 
 mod application;
 mod data;
@@ -55,10 +68,40 @@ async fn main() -> AnyhowResult<()> {
 
     let cfg = Arc::new(BlogConfig::load()?);
 
-    // Соединение с БД и осуществление миграций.
-    let pool = get_pool_postgres(&cfg.db, true).await?;
-    info!("Успешное подключение к базе данных");
-    let app_services = AppServices::new(&pool);
+    let (repos, storage): (Repositories, Arc<dyn FileStorage>) = match &cfg.data {
+        DataCfg::Postgres(db) => {
+            // Соединение с БД и осуществление миграций.
+            let pool = get_pool_postgres(db, true).await?;
+            info!("Успешное подключение к базе данных");
+            let storage = LocalStorage::new(cfg.storage.uploads_dir.clone());
+            (Repositories::postgres(&pool), Arc::new(storage))
+        }
+        DataCfg::Memory => {
+            warn!("Данные хранятся в памяти и будут потеряны при остановке сервера");
+            (Repositories::memory(), Arc::new(MemoryStorage::default()))
+        }
+    };
+    let app_services = AppServices::new(&repos, storage, &cfg);
+    app_services
+        .token_service
+        .load_revoked()
+        .await
+        .context("Ошибка загрузки списка отозванных токенов")?;
+    app_services
+        .blog_service
+        .fill_content_html()
+        .await
+        .context("Ошибка заполнения HTML публикаций")?;
+    app_services
+        .admin_service
+        .grant_admins(&cfg.security.admin_usernames)
+        .await
+        .context("Ошибка назначения администраторов")?;
+    app_services
+        .moderation_service
+        .reload()
+        .await
+        .context("Ошибка загрузки правил модерации")?;
 
     // Запуск серверов.
     let tx = broadcast::channel::<bool>(1).0;
@@ -69,6 +112,19 @@ async fn main() -> AnyhowResult<()> {
         tx.subscribe(),
     ));
     
+    let scheduler = spawn(run_post_scheduler(app_services.clone(), tx.subscribe()));
+
+    let trash_purge = spawn(run_trash_purge(
+        app_services.clone(),
+        cfg.server.trash_retention,
+        tx.subscribe(),
+    ));
+
+    let login_attempts_purge = spawn(run_login_attempts_purge(
+        app_services.clone(),
+        tx.subscribe(),
+    ));
+
     let grpc = spawn(run_blog_grpc(cfg, app_services, tx.subscribe()));
 
     tokio::signal::ctrl_c()
@@ -86,6 +142,18 @@ async fn main() -> AnyhowResult<()> {
     grpc.await
         .with_context(|| "ошибка в асинхронном цикле сервера gRPC")?
         .with_context(|| "выход из цикла событий сервера gRPC")?;
+    scheduler
+        .await
+        .with_context(|| "ошибка в асинхронном цикле планировщика публикаций")?
+        .with_context(|| "выход из цикла планировщика публикаций")?;
+    trash_purge
+        .await
+        .with_context(|| "ошибка в асинхронном цикле очистки корзины")?
+        .with_context(|| "выход из цикла очистки корзины")?;
+    login_attempts_purge
+        .await
+        .with_context(|| "ошибка в асинхронном цикле очистки журнала попыток входа")?
+        .with_context(|| "выход из цикла очистки журнала попыток входа")?;
 
     info!("Все серверы остановлены");
     Ok(())
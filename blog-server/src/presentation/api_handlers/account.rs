@@ -0,0 +1,200 @@
+//! `Endpoints` учётной записи текущего пользователя: профиль, выгрузка
+//! персональных данных и удаление учётной записи.
+
+use crate::{
+    application::{account_service::AccountService, profile_service::ProfileService},
+    domain::{
+        account::{export_file_name, AccountExport, DeleteAccount},
+        user::{AuthenticatedUser, UpdateProfile, UserDto},
+    },
+    errors::DomainError,
+};
+use actix_web::{
+    delete, get,
+    http::header::{ContentDisposition, ContentType, DispositionParam, DispositionType},
+    patch,
+    web::{self, Bytes, Json, ReqData},
+    HttpResponse, Responder, Result as ActixResult,
+};
+use serde::Serialize;
+use std::sync::Arc;
+use tokio_stream::{Stream, StreamExt};
+use tracing::error;
+
+/// Данные текущего пользователя.
+///
+/// `/api/me`
+#[get("")]
+async fn get_me(
+    user: ReqData<AuthenticatedUser>,
+    profile_service: web::Data<Arc<ProfileService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let account = profile_service.me(&user).await?;
+
+    Ok(HttpResponse::Ok().json(UserDto::from(account)))
+}
+
+/// Изменение профиля текущего пользователя. Новый адрес электронной почты
+/// требует повторного подтверждения.
+///
+/// `/api/me`
+#[patch("")]
+async fn update_my_profile(
+    user: ReqData<AuthenticatedUser>,
+    body: Json<UpdateProfile>,
+    profile_service: web::Data<Arc<ProfileService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+
+    let updated = profile_service
+        .update(&user, body.into_inner())
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                username = %user.username,
+                "Ошибка изменения профиля"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(UserDto::from(updated)))
+}
+
+/// Выгрузка персональных данных: JSON-файл с данными учётной записи и всеми
+/// публикациями пользователя.
+///
+/// Файл передаётся частями (chunked) по мере чтения публикаций из
+/// хранилища. Ошибка посреди выгрузки обрывает соединение, и клиент
+/// получает незавершённый JSON.
+///
+/// `/api/me/export`
+#[get("/export")]
+async fn export_my_data(
+    user: ReqData<AuthenticatedUser>,
+    account_service: web::Data<Arc<AccountService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+
+    let export = account_service.export(&user).await.inspect_err(|err| {
+        error!(
+            error = %err,
+            username = %user.username,
+            "Ошибка выгрузки персональных данных"
+        )
+    })?;
+    let file_name = export_file_name(&export.account.username);
+
+    Ok(HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(file_name)],
+        })
+        .streaming(export_json(export)?))
+}
+
+/// Части JSON-документа выгрузки
+/// `{"account": …, "exported_at": …, "posts": [ … ]}`: по одной на каждую
+/// публикацию, между заголовком и закрывающими скобками.
+fn export_json(
+    export: AccountExport,
+) -> Result<impl Stream<Item = Result<Bytes, DomainError>>, DomainError> {
+    let head = format!(
+        r#"{{"account":{},"exported_at":{},"posts":["#,
+        to_json(&export.account)?,
+        export.exported_at.timestamp()
+    );
+
+    let mut first = true;
+    let posts = export.posts.map(move |post| {
+        let separator = if std::mem::take(&mut first) { "" } else { "," };
+        Ok(Bytes::from(format!("{separator}{}", to_json(&post?)?)))
+    });
+
+    Ok(tokio_stream::once(Ok(Bytes::from(head)))
+        .chain(posts)
+        .chain(tokio_stream::once(Ok(Bytes::from_static(b"]}")))))
+}
+
+/// Сериализовать часть выгрузки в JSON.
+fn to_json<T: Serialize>(value: &T) -> Result<String, DomainError> {
+    serde_json::to_string(value).map_err(|err| DomainError::server_err(err.to_string()))
+}
+
+/// Удаление учётной записи после подтверждения паролем. Публикации и
+/// комментарии удаляются или обезличиваются по выбору пользователя.
+///
+/// `/api/me`
+#[delete("")]
+async fn delete_my_account(
+    user: ReqData<AuthenticatedUser>,
+    body: Json<DeleteAccount>,
+    account_service: web::Data<Arc<AccountService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+
+    account_service
+        .delete_account(&user, &body)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                username = %user.username,
+                "Ошибка удаления учётной записи"
+            )
+        })?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub(super) fn configure_account_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/me")
+            .service(get_me)
+            .service(update_my_profile)
+            .service(export_my_data)
+            .service(delete_my_account),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::fixtures, Repositories},
+        domain::{post::PostStatus, role::Role},
+    };
+    use chrono::Utc;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    #[actix_web::test]
+    async fn export_is_streamed_as_single_json_document() {
+        let repos = Repositories::memory();
+        let user = fixtures::user(&repos, "alice", Role::default()).await;
+        let account = repos.users.get_by_id(&user.id).await.unwrap();
+        let (tx, rx) = mpsc::channel(2);
+        for _ in 0..2 {
+            let post = fixtures::post(&repos, &user.id, PostStatus::Draft).await;
+            tx.send(Ok(post)).await.unwrap();
+        }
+        drop(tx);
+        let export = AccountExport {
+            account: account.into(),
+            posts: ReceiverStream::new(rx),
+            exported_at: Utc::now(),
+        };
+
+        let chunks: Vec<Bytes> = export_json(export)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(chunks.len(), 4);
+
+        let body: serde_json::Value = serde_json::from_slice(&chunks.concat()).unwrap();
+        assert_eq!(body["account"]["username"], "alice");
+        assert_eq!(body["posts"].as_array().map(Vec::len), Some(2));
+        assert!(body["exported_at"].is_i64());
+    }
+}
@@ -0,0 +1,198 @@
+//! `Endpoints` администрирования учётных записей и журнала аудита (требуют
+//! роли администратора).
+
+use crate::{
+    application::{admin_service::AdminService, audit_service::AuditService},
+    domain::{
+        audit::{AuditFilter, QueryAuditEvents},
+        types::Username,
+        user::{AuthenticatedUser, QueryUsers, SetUserRole},
+    },
+    errors::DomainError,
+    presentation::{
+        api_handlers::tools::valid_query_posts_params, tools::audit_time_from_timestamp,
+    },
+};
+use actix_web::{
+    HttpResponse, Responder, Result as ActixResult, get, post, put,
+    web::{self, Json, ReqData},
+};
+use std::sync::Arc;
+use tracing::error;
+
+/// Список учётных записей. Извлекает query-параметры limit и offset
+/// (по умолчанию limit=10, offset=0).
+///
+/// `/api/admin/users`
+#[get("")]
+async fn list_users(
+    user: ReqData<AuthenticatedUser>,
+    query: web::Query<QueryUsers>,
+    admin_service: web::Data<Arc<AdminService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or_default();
+    let (limit, offset) = valid_query_posts_params(limit, offset)?;
+
+    let users = admin_service.list_users(&user, limit, offset).await?;
+
+    Ok(HttpResponse::Ok().json(users))
+}
+
+/// Смена роли пользователя.
+///
+/// `/api/admin/users/{username}/role`
+#[put("/{username}/role")]
+async fn set_user_role(
+    user: ReqData<AuthenticatedUser>,
+    username: web::Path<Username>,
+    body: Json<SetUserRole>,
+    admin_service: web::Data<Arc<AdminService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let username = username.into_inner();
+
+    let account = admin_service
+        .set_role(&user, &username, body.role)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                admin = %user.username,
+                username = %username,
+                "Ошибка смены роли пользователя"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(account))
+}
+
+/// Блокировка учётной записи: пользователь не может войти, его сессии
+/// отзываются.
+///
+/// `/api/admin/users/{username}/disable`
+#[post("/{username}/disable")]
+async fn disable_user(
+    user: ReqData<AuthenticatedUser>,
+    username: web::Path<Username>,
+    admin_service: web::Data<Arc<AdminService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    set_user_disabled(
+        user.into_inner(),
+        username.into_inner(),
+        true,
+        &admin_service,
+    )
+    .await
+}
+
+/// Разблокировка учётной записи.
+///
+/// `/api/admin/users/{username}/enable`
+#[post("/{username}/enable")]
+async fn enable_user(
+    user: ReqData<AuthenticatedUser>,
+    username: web::Path<Username>,
+    admin_service: web::Data<Arc<AdminService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    set_user_disabled(
+        user.into_inner(),
+        username.into_inner(),
+        false,
+        &admin_service,
+    )
+    .await
+}
+
+/// Снятие блокировки входа, наступившей после неудачных попыток.
+///
+/// `/api/admin/users/{username}/unlock`
+#[post("/{username}/unlock")]
+async fn unlock_user(
+    user: ReqData<AuthenticatedUser>,
+    username: web::Path<Username>,
+    admin_service: web::Data<Arc<AdminService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let username = username.into_inner();
+
+    let account = admin_service
+        .unlock(&user, &username)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                admin = %user.username,
+                username = %username,
+                "Ошибка снятия блокировки входа"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(account))
+}
+
+/// Записи журнала аудита, от новых к старым. Извлекает query-параметры
+/// фильтров `actor`, `action`, `target`, `since`, `until` и пагинации
+/// `limit`, `offset` (по умолчанию limit=10, offset=0).
+///
+/// `/api/admin/audit`
+#[get("")]
+async fn list_audit_events(
+    user: ReqData<AuthenticatedUser>,
+    query: web::Query<QueryAuditEvents>,
+    audit_service: web::Data<Arc<AuditService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let query = query.into_inner();
+    let (limit, offset) =
+        valid_query_posts_params(query.limit.unwrap_or(10), query.offset.unwrap_or_default())?;
+
+    let filter = AuditFilter {
+        actor: query.actor,
+        action: query.action,
+        target: query.target,
+        since: query.since.map(audit_time_from_timestamp).transpose()?,
+        until: query.until.map(audit_time_from_timestamp).transpose()?,
+        limit,
+        offset,
+    };
+    let events = audit_service.list(&user, filter).await?;
+
+    Ok(HttpResponse::Ok().json(events))
+}
+
+/// Поддерживающая функция: блокировка или разблокировка учётной записи.
+async fn set_user_disabled(
+    user: AuthenticatedUser,
+    username: Username,
+    disabled: bool,
+    admin_service: &AdminService,
+) -> ActixResult<HttpResponse, DomainError> {
+    let account = admin_service
+        .set_disabled(&user, &username, disabled)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                admin = %user.username,
+                username = %username,
+                disabled,
+                "Ошибка изменения статуса учётной записи"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(account))
+}
+
+pub(super) fn configure_admin_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/admin/users")
+            .service(list_users)
+            .service(set_user_role)
+            .service(disable_user)
+            .service(enable_user)
+            .service(unlock_user),
+    )
+    .service(web::scope("/admin/audit").service(list_audit_events));
+}
@@ -0,0 +1,218 @@
+//! Роутеры лент публикаций в форматах RSS 2.0 и Atom.
+//!
+//! Ленты доступны вне `/api`, по привычным для читалок адресам, и
+//! поддерживают условные запросы (`If-None-Match`, `If-Modified-Since`).
+
+use crate::{
+    application::{blog_service::BlogService, user_service::UserService},
+    domain::{
+        post::ListPostsParams,
+        syndication::{self, FeedFormat, FeedMeta},
+        types::{DataId, Username},
+    },
+    errors::DomainError,
+    infrastructure::config::BlogConfig,
+    settings::FEED_ITEMS_LIMIT,
+};
+use actix_web::{
+    get,
+    http::header::{
+        EntityTag, HttpDate, IfModifiedSince, IfNoneMatch, LastModified, ETAG, LAST_MODIFIED,
+    },
+    web, HttpMessage, HttpRequest, HttpResponse, Responder, Result as ActixResult,
+};
+use chrono::{DateTime, Utc};
+use std::{sync::Arc, time::SystemTime};
+
+/// Лента публикаций блога в формате RSS 2.0.
+///
+/// `/feed.rss`
+#[get("/feed.rss")]
+async fn blog_rss(
+    req: HttpRequest,
+    blog_service: web::Data<Arc<BlogService>>,
+    config: web::Data<Arc<BlogConfig>>,
+) -> ActixResult<impl Responder, DomainError> {
+    blog_feed(FeedFormat::Rss, &req, &blog_service, &config).await
+}
+
+/// Лента публикаций блога в формате Atom.
+///
+/// `/feed.atom`
+#[get("/feed.atom")]
+async fn blog_atom(
+    req: HttpRequest,
+    blog_service: web::Data<Arc<BlogService>>,
+    config: web::Data<Arc<BlogConfig>>,
+) -> ActixResult<impl Responder, DomainError> {
+    blog_feed(FeedFormat::Atom, &req, &blog_service, &config).await
+}
+
+/// Лента публикаций автора в формате RSS 2.0.
+///
+/// `/users/{username}/feed.rss`
+#[get("/users/{username}/feed.rss")]
+async fn author_rss(
+    req: HttpRequest,
+    username: web::Path<Username>,
+    blog_service: web::Data<Arc<BlogService>>,
+    user_service: web::Data<Arc<UserService>>,
+    config: web::Data<Arc<BlogConfig>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let username = username.into_inner();
+    author_feed(
+        FeedFormat::Rss,
+        &req,
+        &username,
+        &blog_service,
+        &user_service,
+        &config,
+    )
+    .await
+}
+
+/// Лента публикаций автора в формате Atom.
+///
+/// `/users/{username}/feed.atom`
+#[get("/users/{username}/feed.atom")]
+async fn author_atom(
+    req: HttpRequest,
+    username: web::Path<Username>,
+    blog_service: web::Data<Arc<BlogService>>,
+    user_service: web::Data<Arc<UserService>>,
+    config: web::Data<Arc<BlogConfig>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let username = username.into_inner();
+    author_feed(
+        FeedFormat::Atom,
+        &req,
+        &username,
+        &blog_service,
+        &user_service,
+        &config,
+    )
+    .await
+}
+
+/// Сформировать общую ленту блога.
+async fn blog_feed(
+    format: FeedFormat,
+    req: &HttpRequest,
+    blog_service: &BlogService,
+    config: &BlogConfig,
+) -> Result<HttpResponse, DomainError> {
+    let meta = FeedMeta {
+        title: config.feed.title.clone(),
+        description: config.feed.description.clone(),
+        site_url: config.feed.site_url.clone(),
+        link: config.feed.site_url.clone(),
+        self_url: format!("{}/feed.{}", config.feed.site_url, format.extension()),
+    };
+
+    render_feed(format, req, meta, blog_service, None).await
+}
+
+/// Сформировать ленту автора. Для неизвестного пользователя возвращается
+/// `404`.
+async fn author_feed(
+    format: FeedFormat,
+    req: &HttpRequest,
+    username: &Username,
+    blog_service: &BlogService,
+    user_service: &UserService,
+    config: &BlogConfig,
+) -> Result<HttpResponse, DomainError> {
+    let author_id = user_service.get_author_id(username).await?;
+    let meta = FeedMeta {
+        title: config.feed.author_title(username.as_ref()),
+        description: config.feed.description.clone(),
+        site_url: config.feed.site_url.clone(),
+        link: format!("{}/user/{username}", config.feed.site_url),
+        self_url: format!(
+            "{}/users/{}/feed.{}",
+            config.feed.site_url,
+            username.to_lowercase(),
+            format.extension()
+        ),
+    };
+
+    render_feed(format, req, meta, blog_service, Some(author_id)).await
+}
+
+/// Выгрузить последние публикации и сформировать ответ с лентой.
+///
+/// Если лента не изменилась с версии, которая уже есть у клиента,
+/// возвращается `304 Not Modified` без тела.
+async fn render_feed(
+    format: FeedFormat,
+    req: &HttpRequest,
+    meta: FeedMeta,
+    blog_service: &BlogService,
+    author_id: Option<DataId>,
+) -> Result<HttpResponse, DomainError> {
+    let params = ListPostsParams {
+        limit: FEED_ITEMS_LIMIT,
+        offset: 0,
+        tag: None,
+        after: None,
+        followed_by: None,
+        author_id,
+    };
+    let posts = blog_service.list_posts(params).await?.posts;
+
+    let body = format.render(&meta, &posts);
+    let etag = EntityTag::new_strong(syndication::etag(&body));
+    let last_modified = syndication::last_modified(&posts).map(http_date);
+
+    let not_modified = is_not_modified(req, &etag, last_modified);
+    let mut response = if not_modified {
+        HttpResponse::NotModified()
+    } else {
+        HttpResponse::Ok()
+    };
+    response.insert_header((ETAG, etag));
+    if let Some(last_modified) = last_modified {
+        response.insert_header((LAST_MODIFIED, LastModified(last_modified)));
+    }
+
+    if not_modified {
+        return Ok(response.finish());
+    }
+
+    Ok(response.content_type(format.content_type()).body(body))
+}
+
+/// Есть ли у клиента актуальная версия ленты.
+///
+/// Как и требует RFC 9110, `If-Modified-Since` учитывается, только если
+/// в запросе нет `If-None-Match`.
+fn is_not_modified(req: &HttpRequest, etag: &EntityTag, last_modified: Option<HttpDate>) -> bool {
+    if let Some(if_none_match) = req.get_header::<IfNoneMatch>() {
+        return match if_none_match {
+            IfNoneMatch::Any => true,
+            IfNoneMatch::Items(tags) => tags.iter().any(|tag| tag.weak_eq(etag)),
+        };
+    }
+
+    match (req.get_header::<IfModifiedSince>(), last_modified) {
+        (Some(IfModifiedSince(since)), Some(modified)) => {
+            SystemTime::from(modified) <= SystemTime::from(since)
+        }
+        _ => false,
+    }
+}
+
+/// Дата для HTTP-заголовков. Доли секунды отбрасываются: заголовки их
+/// не передают, и иначе `If-Modified-Since` никогда бы не совпал.
+fn http_date(dt: DateTime<Utc>) -> HttpDate {
+    let secs = DateTime::from_timestamp(dt.timestamp(), 0).unwrap_or(dt);
+    HttpDate::from(SystemTime::from(secs))
+}
+
+/// Роутеры лент публикаций.
+pub(super) fn configure_feed_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(blog_rss)
+        .service(blog_atom)
+        .service(author_rss)
+        .service(author_atom);
+}
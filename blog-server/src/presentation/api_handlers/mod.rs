@@ -1,12 +1,20 @@
 //! Роутеры для внешнего взаимодействия HTTP-сервера.
 
 use crate::presentation::middleware;
-use actix_web::web;
+use actix_web::{middleware::from_fn, web};
 use actix_web_httpauth::middleware::HttpAuthentication;
 
+mod account;
+mod admin;
+mod feeds;
+mod moderation;
+mod multipart;
+mod password;
 pub(crate) mod protected;
 pub(crate) mod public;
 mod tools;
+mod uploads;
+mod verification;
 
 const API_ENDPOINT: &str = "/api";
 
@@ -17,14 +25,26 @@ const API_ENDPOINT: &str = "/api";
 pub(crate) fn configure_api_routers(cfg: &mut web::ServiceConfig) {
     let jwt_auth = HttpAuthentication::bearer(middleware::jwt_validator);
 
-    cfg.service(
-        web::scope(API_ENDPOINT)
-            .service(web::scope("/auth").configure(public::configure_auth_routes))
-            .configure(public::configure_list_routes)
-            .service(
-                web::scope("")
-                    .wrap(jwt_auth)
-                    .configure(protected::configure_posts_routes),
-            ),
-    );
+    cfg.configure(feeds::configure_feed_routes)
+        .configure(uploads::configure_upload_routes)
+        .service(
+            web::scope(API_ENDPOINT)
+                .service(
+                    web::scope("/auth")
+                        .wrap(from_fn(middleware::rate_limit_auth))
+                        .configure(public::configure_auth_routes)
+                        .configure(password::configure_password_routes)
+                        .configure(verification::configure_verification_routes),
+                )
+                .configure(public::configure_list_routes)
+                .service(
+                    web::scope("")
+                        .wrap(from_fn(middleware::rate_limit_write))
+                        .wrap(jwt_auth)
+                        .configure(protected::configure_posts_routes)
+                        .configure(account::configure_account_routes)
+                        .configure(admin::configure_admin_routes)
+                        .configure(moderation::configure_moderation_routes),
+                ),
+        );
 }
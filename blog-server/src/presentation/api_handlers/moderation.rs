@@ -0,0 +1,203 @@
+//! `Endpoints` модерации: правила проверки текста и очередь публикаций
+//! (требуют роли модератора).
+
+use crate::{
+    application::{blog_service::BlogService, moderation_service::ModerationService},
+    domain::{
+        moderation::{QueryModerationQueue, SaveModerationRule},
+        types::DataId,
+        user::AuthenticatedUser,
+    },
+    errors::DomainError,
+    presentation::api_handlers::tools::{request_context, valid_query_posts_params},
+};
+use actix_web::{
+    HttpRequest, HttpResponse, Responder, Result as ActixResult, delete, get, post, put,
+    web::{self, Json, ReqData},
+};
+use std::sync::Arc;
+use tracing::error;
+
+/// Список правил модерации.
+///
+/// `/api/moderation/rules`
+#[get("/rules")]
+async fn list_rules(
+    user: ReqData<AuthenticatedUser>,
+    moderation_service: web::Data<Arc<ModerationService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let rules = moderation_service.list_rules(&user.into_inner()).await?;
+
+    Ok(HttpResponse::Ok().json(rules))
+}
+
+/// Добавление правила модерации.
+///
+/// `/api/moderation/rules`
+#[post("/rules")]
+async fn create_rule(
+    user: ReqData<AuthenticatedUser>,
+    body: Json<SaveModerationRule>,
+    moderation_service: web::Data<Arc<ModerationService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+
+    let rule = moderation_service
+        .create_rule(&user, body.into_inner())
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                user_id = %user.id,
+                "Ошибка добавления правила модерации"
+            )
+        })?;
+
+    Ok(HttpResponse::Created().json(rule))
+}
+
+/// Изменение правила модерации.
+///
+/// `/api/moderation/rules/{id}`
+#[put("/rules/{id}")]
+async fn update_rule(
+    user: ReqData<AuthenticatedUser>,
+    rule_id: web::Path<DataId>,
+    body: Json<SaveModerationRule>,
+    moderation_service: web::Data<Arc<ModerationService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let rule_id = rule_id.into_inner();
+
+    let rule = moderation_service
+        .update_rule(&user, &rule_id, body.into_inner())
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                rule_id = %rule_id,
+                user_id = %user.id,
+                "Ошибка изменения правила модерации"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(rule))
+}
+
+/// Удаление правила модерации.
+///
+/// `/api/moderation/rules/{id}`
+#[delete("/rules/{id}")]
+async fn delete_rule(
+    user: ReqData<AuthenticatedUser>,
+    rule_id: web::Path<DataId>,
+    moderation_service: web::Data<Arc<ModerationService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let rule_id = rule_id.into_inner();
+
+    moderation_service
+        .delete_rule(&user, &rule_id)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                rule_id = %rule_id,
+                user_id = %user.id,
+                "Ошибка удаления правила модерации"
+            )
+        })?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Очередь модерации: публикации, ожидающие проверки, от давно ожидающих.
+/// Извлекает query-параметры limit и offset (по умолчанию limit=10, offset=0).
+///
+/// `/api/moderation/queue`
+#[get("/queue")]
+async fn list_queue(
+    user: ReqData<AuthenticatedUser>,
+    query: web::Query<QueryModerationQueue>,
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or_default();
+    let (limit, offset) = valid_query_posts_params(limit, offset)?;
+
+    let posts = blog_service
+        .list_moderation_queue(&user, limit, offset)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(posts))
+}
+
+/// Одобрение публикации из очереди модерации.
+///
+/// `/api/moderation/posts/{id}/approve`
+#[post("/posts/{id}/approve")]
+async fn approve_post(
+    req: HttpRequest,
+    user: ReqData<AuthenticatedUser>,
+    post_id: web::Path<DataId>,
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let post_id = post_id.into_inner();
+
+    let post = blog_service
+        .approve_post(&post_id, &user, &request_context(&req))
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                user_id = %user.id,
+                "Ошибка одобрения публикации"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(post))
+}
+
+/// Отклонение публикации из очереди модерации: запись скрывается.
+///
+/// `/api/moderation/posts/{id}/reject`
+#[post("/posts/{id}/reject")]
+async fn reject_post(
+    req: HttpRequest,
+    user: ReqData<AuthenticatedUser>,
+    post_id: web::Path<DataId>,
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let post_id = post_id.into_inner();
+
+    let post = blog_service
+        .reject_post(&post_id, &user, &request_context(&req))
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                user_id = %user.id,
+                "Ошибка отклонения публикации"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(post))
+}
+
+pub(super) fn configure_moderation_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/moderation")
+            .service(list_rules)
+            .service(create_rule)
+            .service(update_rule)
+            .service(delete_rule)
+            .service(list_queue)
+            .service(approve_post)
+            .service(reject_post),
+    );
+}
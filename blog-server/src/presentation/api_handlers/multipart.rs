@@ -0,0 +1,154 @@
+//! Разбор тела запроса в формате `multipart/form-data` (RFC 7578).
+//!
+//! Поддерживается ровно то, что нужно для загрузки вложений: поиск файла
+//! в поле с заданным именем в теле, целиком прочитанном в память.
+
+use crate::errors::DomainError;
+
+/// Файл из поля формы.
+#[derive(Debug)]
+pub(super) struct FilePart {
+    /// Имя файла, переданное клиентом.
+    pub(super) file_name: String,
+    /// Содержимое файла.
+    pub(super) data: Vec<u8>,
+}
+
+/// Извлечь файл из поля `field` тела `body` с заголовком
+/// `Content-Type: content_type`.
+pub(super) fn extract_file(
+    content_type: &str,
+    body: &[u8],
+    field: &str,
+) -> Result<FilePart, DomainError> {
+    let boundary = boundary(content_type).ok_or_else(|| {
+        DomainError::api_error("ожидается тело `multipart/form-data` с параметром boundary")
+    })?;
+    let delimiter = format!("--{boundary}");
+    let malformed = || DomainError::api_error("некорректное тело `multipart/form-data`");
+
+    // Части разделены строками `--boundary`; после последней — `--boundary--`.
+    let mut pos = find(body, delimiter.as_bytes(), 0).ok_or_else(malformed)? + delimiter.len();
+    loop {
+        if body[pos..].starts_with(b"--") {
+            break;
+        }
+        if !body[pos..].starts_with(b"\r\n") {
+            return Err(malformed());
+        }
+        pos += 2;
+
+        let headers_end = find(body, b"\r\n\r\n", pos).ok_or_else(malformed)?;
+        let headers = std::str::from_utf8(&body[pos..headers_end]).map_err(|_| malformed())?;
+        let data_start = headers_end + 4;
+
+        let closing = format!("\r\n{delimiter}");
+        let data_end = find(body, closing.as_bytes(), data_start).ok_or_else(malformed)?;
+
+        if let Some((name, file_name)) = disposition(headers)
+            && name == field
+        {
+            return Ok(FilePart {
+                file_name: file_name.ok_or_else(|| {
+                    DomainError::invalid_attachment(format!("поле `{field}` должно содержать файл"))
+                })?,
+                data: body[data_start..data_end].to_vec(),
+            });
+        }
+
+        pos = data_end + closing.len();
+    }
+
+    Err(DomainError::invalid_attachment(format!(
+        "в запросе нет файла в поле `{field}`"
+    )))
+}
+
+/// Значение параметра `boundary` заголовка `multipart/form-data`.
+fn boundary(content_type: &str) -> Option<&str> {
+    let mut params = content_type.split(';');
+    let mime = params.next()?.trim();
+    if !mime.eq_ignore_ascii_case("multipart/form-data") {
+        return None;
+    }
+
+    params
+        .filter_map(|param| param.trim().split_once('='))
+        .find(|(key, _)| key.eq_ignore_ascii_case("boundary"))
+        .map(|(_, value)| value.trim_matches('"'))
+        .filter(|value| !value.is_empty())
+}
+
+/// Имя поля и (если есть) имя файла из заголовка `Content-Disposition`
+/// части формы.
+fn disposition(headers: &str) -> Option<(String, Option<String>)> {
+    let value = headers.split("\r\n").find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.trim()
+            .eq_ignore_ascii_case("content-disposition")
+            .then_some(value)
+    })?;
+
+    let mut name = None;
+    let mut file_name = None;
+    for param in value.split(';').skip(1) {
+        let Some((key, value)) = param.trim().split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"').to_string();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "name" => name = Some(value),
+            "filename" => file_name = Some(value),
+            _ => {}
+        }
+    }
+
+    Some((name?, file_name))
+}
+
+/// Позиция первого вхождения `needle` в `haystack`, начиная с `from`.
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|i| i + from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT_TYPE: &str = "multipart/form-data; boundary=\"XyZ\"";
+
+    #[test]
+    fn extracts_file_field() {
+        let body = b"--XyZ\r\n\
+            Content-Disposition: form-data; name=\"title\"\r\n\r\n\
+            text\r\n\
+            --XyZ\r\n\
+            Content-Disposition: form-data; name=\"file\"; filename=\"a b.png\"\r\n\
+            Content-Type: image/png\r\n\r\n\
+            \x89PNG\r\n--X\r\n\
+            --XyZ--\r\n";
+
+        let part = extract_file(CONTENT_TYPE, body, "file").unwrap();
+        assert_eq!(part.file_name, "a b.png");
+        assert_eq!(part.data, b"\x89PNG\r\n--X");
+    }
+
+    #[test]
+    fn rejects_missing_or_malformed_parts() {
+        let body = b"--XyZ\r\nContent-Disposition: form-data; name=\"file\"\r\n\r\nx\r\n--XyZ--";
+        assert!(matches!(
+            extract_file(CONTENT_TYPE, body, "file"),
+            Err(DomainError::InvalidAttachment(_))
+        ));
+        assert!(matches!(
+            extract_file(CONTENT_TYPE, body, "other"),
+            Err(DomainError::InvalidAttachment(_))
+        ));
+        assert!(extract_file("application/json", body, "file").is_err());
+        assert!(extract_file(CONTENT_TYPE, b"--XyZ\r\nbroken", "file").is_err());
+    }
+}
@@ -0,0 +1,89 @@
+//! `Endpoints` смены и сброса пароля.
+
+use crate::{
+    application::{password_service::PasswordService, token_service::TokenService},
+    domain::{
+        password::{ChangePassword, ForgotPassword, ResetPassword},
+        user::{AuthResponse, AuthenticatedUser, UserDto},
+    },
+    errors::DomainError,
+    presentation::{middleware, tools::issue_tokens},
+};
+use actix_web::{
+    post,
+    web::{self, Json, ReqData},
+    HttpResponse, Responder, Result as ActixResult,
+};
+use actix_web_httpauth::middleware::HttpAuthentication;
+use std::sync::Arc;
+use tracing::error;
+
+/// Смена пароля авторизованным пользователем. Все сессии пользователя
+/// завершаются, ответ содержит токены новой сессии.
+///
+/// `/api/auth/password`
+async fn change_password(
+    user: ReqData<AuthenticatedUser>,
+    body: Json<ChangePassword>,
+    password_service: web::Data<Arc<PasswordService>>,
+    token_service: web::Data<Arc<TokenService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+
+    let account = password_service
+        .change_password(&user, &body)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                username = %user.username,
+                "Ошибка смены пароля"
+            )
+        })?;
+
+    let user_dto: UserDto = account.into();
+    let tokens = issue_tokens(&user_dto, &token_service).await?;
+
+    Ok(HttpResponse::Ok().json(AuthResponse::new(tokens, user_dto)))
+}
+
+/// Запрос на сброс забытого пароля: на адрес учётной записи отправляется
+/// письмо с токеном. Ответ не зависит от того, зарегистрирован ли адрес.
+///
+/// `/api/auth/password/forgot`
+#[post("/password/forgot")]
+async fn forgot_password(
+    body: Json<ForgotPassword>,
+    password_service: web::Data<Arc<PasswordService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    password_service
+        .request_reset(&body.email)
+        .await
+        .inspect_err(|err| error!(error = %err, "Ошибка запроса сброса пароля"))?;
+
+    Ok(HttpResponse::Accepted().finish())
+}
+
+/// Установка нового пароля по токену сброса из письма.
+///
+/// `/api/auth/password/reset`
+#[post("/password/reset")]
+async fn reset_password(
+    body: Json<ResetPassword>,
+    password_service: web::Data<Arc<PasswordService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    password_service
+        .reset_password(&body.token, &body.new_password)
+        .await
+        .inspect_err(|err| error!(error = %err, "Ошибка сброса пароля"))?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub(super) fn configure_password_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(forgot_password).service(reset_password).service(
+        web::resource("/password")
+            .wrap(HttpAuthentication::bearer(middleware::jwt_validator))
+            .route(web::post().to(change_password)),
+    );
+}
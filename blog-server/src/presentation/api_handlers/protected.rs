@@ -1,18 +1,33 @@
 //! Защищённые `endpoints` HTTP-сервера.
 
 use crate::{
-    application::blog_service::BlogService,
-    data::post_repo::PostRepo,
+    application::{
+        attachment_service::AttachmentService, blog_service::BlogService,
+        comment_service::CommentService, follow_service::FollowService,
+        reaction_service::ReactionService, revision_service::RevisionService,
+    },
     domain::{
-        post::{CreatePost, EditPost, EditPostCommand},
-        types::DataId,
+        attachment::AttachmentDto,
+        comment::CreateComment,
+        post::{
+            CreatePost, EditPost, EditPostCommand, ListPostsParams, PublishPost, QueryDrafts,
+            QueryPosts, QueryTrash,
+        },
+        reaction::ReactionKind,
+        revision::{QueryDiff, QueryRevisions},
+        types::{DataId, Username},
         user::AuthenticatedUser,
     },
     errors::DomainError,
+    presentation::api_handlers::{
+        multipart::extract_file,
+        tools::{request_context, valid_query_posts_params},
+    },
+    settings::MULTIPART_OVERHEAD_BYTES,
 };
 use actix_web::{
-    delete, post, put, web, web::{Json, ReqData}, HttpResponse, Responder,
-    Result as ActixResult,
+    delete, get, http::header::CONTENT_TYPE, post, put, web, web::{Json, ReqData}, HttpRequest,
+    HttpResponse, Responder, Result as ActixResult,
 };
 use std::sync::Arc;
 use tracing::error;
@@ -22,15 +37,16 @@ use tracing::error;
 /// `/api/posts`
 #[post("")]
 async fn create_post(
+    req: HttpRequest,
     user: ReqData<AuthenticatedUser>,
     body: Json<CreatePost>,
-    blog_service: web::Data<Arc<BlogService<PostRepo>>>,
+    blog_service: web::Data<Arc<BlogService>>,
 ) -> ActixResult<impl Responder, DomainError> {
     let author = user.into_inner();
     let post = body.into_inner();
 
     let post = blog_service
-        .create_post(&post, &author.id)
+        .create_post(&post, &author, &request_context(&req))
         .await
         .inspect_err(|err| {
             error!(
@@ -49,17 +65,18 @@ async fn create_post(
 /// `/api/posts/{id}`
 #[put("/{id}")]
 async fn update_post(
+    req: HttpRequest,
     user: ReqData<AuthenticatedUser>,
     body: Json<EditPost>,
     post_id: web::Path<DataId>,
-    blog_service: web::Data<Arc<BlogService<PostRepo>>>,
+    blog_service: web::Data<Arc<BlogService>>,
 ) -> ActixResult<impl Responder, DomainError> {
     let user = user.into_inner();
 
     let edit_command = EditPostCommand::new(post_id.into_inner(), body.into_inner());
 
     let post = blog_service
-        .update_post(&edit_command, &user.id)
+        .update_post(&edit_command, &user, &request_context(&req))
         .await
         .inspect_err(|err| {
             error!(
@@ -73,20 +90,22 @@ async fn update_post(
     Ok(HttpResponse::Ok().json(post))
 }
 
-/// Удаление публикации (поста) (требует аутентификации).
+/// Удаление публикации (поста) (требует аутентификации, доступно автору
+/// и модераторам).
 ///
 /// `/api/posts/{id}`
 #[delete("/{id}")]
 async fn delete_post(
+    req: HttpRequest,
     user: ReqData<AuthenticatedUser>,
     post_id: web::Path<DataId>,
-    blog_service: web::Data<Arc<BlogService<PostRepo>>>,
+    blog_service: web::Data<Arc<BlogService>>,
 ) -> ActixResult<impl Responder, DomainError> {
     let user = user.into_inner();
     let post_id = post_id.into_inner();
 
     blog_service
-        .delete_post(&post_id, &user.id)
+        .delete_post(&post_id, &user, &request_context(&req))
         .await
         .inspect_err(|err| {
             error!(
@@ -100,12 +119,594 @@ async fn delete_post(
     Ok(HttpResponse::NoContent().finish())
 }
 
+/// Публикация черновика или отложенной записи (требует аутентификации).
+///
+/// Без тела запроса (или без `publish_at`) запись публикуется сразу, иначе
+/// публикация откладывается до указанного времени.
+///
+/// `/api/posts/{id}/publish`
+#[post("/{id}/publish")]
+async fn publish_post(
+    req: HttpRequest,
+    user: ReqData<AuthenticatedUser>,
+    body: Option<Json<PublishPost>>,
+    post_id: web::Path<DataId>,
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let post_id = post_id.into_inner();
+    let publish = body.map(Json::into_inner).unwrap_or_default();
+
+    let post = blog_service
+        .publish_post(&post_id, &user, publish.publish_at, &request_context(&req))
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                user_id = %user.id,
+                "Ошибка публикации записи"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(post))
+}
+
+/// Черновики и отложенные публикации пользователя (требует аутентификации).
+/// Извлекает query-параметры limit и offset (по умолчанию limit=10, offset=0).
+///
+/// `/api/drafts`
+#[get("")]
+async fn list_drafts(
+    user: ReqData<AuthenticatedUser>,
+    query: web::Query<QueryDrafts>,
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or_default();
+    let (limit, offset) = valid_query_posts_params(limit, offset)?;
+
+    let drafts = blog_service.list_drafts(&user.id, limit, offset).await?;
+
+    Ok(HttpResponse::Ok().json(drafts))
+}
+
+/// Персональная лента: публикации авторов, на которых подписан пользователь
+/// (требует аутентификации). Параметры те же, что у `/api/posts`: limit,
+/// offset (по умолчанию limit=10, offset=0), tag и курсор after.
+///
+/// `/api/feed`
+#[get("")]
+async fn get_feed(
+    user: ReqData<AuthenticatedUser>,
+    query: web::Query<QueryPosts>,
+    blog_service: web::Data<Arc<BlogService>>,
+    reaction_service: web::Data<Arc<ReactionService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or_default();
+    let (limit_i32, offset_i32) = valid_query_posts_params(limit, offset)?;
+
+    let query = query.into_inner();
+    let params = ListPostsParams {
+        limit: limit_i32,
+        offset: offset_i32,
+        tag: query.tag,
+        after: query.after,
+        followed_by: None,
+        author_id: None,
+    };
+
+    let mut posts = blog_service
+        .feed(&user.id, params)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                user_id = %user.id,
+                "Ошибка получения персональной ленты"
+            )
+        })?;
+    reaction_service
+        .apply_viewer(&mut posts.posts, Some(&user.id))
+        .await?;
+
+    Ok(HttpResponse::Ok().json(posts))
+}
+
+/// Публикации пользователя в корзине, от недавно удалённых (требует
+/// аутентификации). Извлекает query-параметры limit и offset (по умолчанию
+/// limit=10, offset=0).
+///
+/// `/api/trash`
+#[get("")]
+async fn list_trash(
+    user: ReqData<AuthenticatedUser>,
+    query: web::Query<QueryTrash>,
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or_default();
+    let (limit, offset) = valid_query_posts_params(limit, offset)?;
+
+    let trash = blog_service.list_trash(&user.id, limit, offset).await?;
+
+    Ok(HttpResponse::Ok().json(trash))
+}
+
+/// Восстановление публикации из корзины (требует аутентификации, доступно
+/// автору).
+///
+/// `/api/posts/{id}/restore`
+#[post("/{id}/restore")]
+async fn restore_post(
+    req: HttpRequest,
+    user: ReqData<AuthenticatedUser>,
+    post_id: web::Path<DataId>,
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let post_id = post_id.into_inner();
+
+    let post = blog_service
+        .restore_post(&post_id, &user, &request_context(&req))
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                user_id = %user.id,
+                "Ошибка восстановления публикации из корзины"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(post))
+}
+
+/// Скрытие публикации от читателей (требует роли модератора).
+///
+/// `/api/posts/{id}/hide`
+#[post("/{id}/hide")]
+async fn hide_post(
+    req: HttpRequest,
+    user: ReqData<AuthenticatedUser>,
+    post_id: web::Path<DataId>,
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let post_id = post_id.into_inner();
+
+    let post = blog_service
+        .hide_post(&post_id, &user, &request_context(&req))
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                user_id = %user.id,
+                "Ошибка скрытия публикации"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(post))
+}
+
+/// Возврат скрытой публикации читателям (требует роли модератора).
+///
+/// `/api/posts/{id}/unhide`
+#[post("/{id}/unhide")]
+async fn unhide_post(
+    req: HttpRequest,
+    user: ReqData<AuthenticatedUser>,
+    post_id: web::Path<DataId>,
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let post_id = post_id.into_inner();
+
+    let post = blog_service
+        .unhide_post(&post_id, &user, &request_context(&req))
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                user_id = %user.id,
+                "Ошибка возврата скрытой публикации"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(post))
+}
+
+/// История изменений публикации, от новых ревизий к старым (требует
+/// аутентификации, доступна автору). Извлекает query-параметры limit и offset
+/// (по умолчанию limit=10, offset=0).
+///
+/// `/api/posts/{id}/revisions`
+#[get("/{id}/revisions")]
+async fn list_revisions(
+    user: ReqData<AuthenticatedUser>,
+    post_id: web::Path<DataId>,
+    query: web::Query<QueryRevisions>,
+    revision_service: web::Data<Arc<RevisionService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let post_id = post_id.into_inner();
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or_default();
+    let (limit, offset) = valid_query_posts_params(limit, offset)?;
+
+    let revisions = revision_service
+        .list_revisions(&post_id, &user.id, limit, offset)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(revisions))
+}
+
+/// Отдельная ревизия публикации (требует аутентификации, доступна автору).
+///
+/// `/api/posts/{id}/revisions/{revision}`
+#[get("/{id}/revisions/{revision}")]
+async fn get_revision(
+    user: ReqData<AuthenticatedUser>,
+    path: web::Path<(DataId, i32)>,
+    revision_service: web::Data<Arc<RevisionService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let (post_id, revision) = path.into_inner();
+
+    let revision = revision_service
+        .get_revision(&post_id, revision, &user.id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(revision))
+}
+
+/// Построчное сравнение двух ревизий публикации (требует аутентификации,
+/// доступно автору). Номера ревизий передаются query-параметрами `from`
+/// и `to`.
+///
+/// `/api/posts/{id}/diff`
+#[get("/{id}/diff")]
+async fn diff_revisions(
+    user: ReqData<AuthenticatedUser>,
+    post_id: web::Path<DataId>,
+    query: web::Query<QueryDiff>,
+    revision_service: web::Data<Arc<RevisionService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let post_id = post_id.into_inner();
+
+    let diff = revision_service
+        .diff_revisions(&post_id, query.from, query.to, &user.id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(diff))
+}
+
+/// Восстановление публикации из ревизии (требует аутентификации, доступно
+/// автору). Восстановленная версия сохраняется новой ревизией.
+///
+/// `/api/posts/{id}/revisions/{revision}/restore`
+#[post("/{id}/revisions/{revision}/restore")]
+async fn restore_revision(
+    req: HttpRequest,
+    user: ReqData<AuthenticatedUser>,
+    path: web::Path<(DataId, i32)>,
+    revision_service: web::Data<Arc<RevisionService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let (post_id, revision) = path.into_inner();
+
+    let post = revision_service
+        .restore_revision(&post_id, revision, &user, &request_context(&req))
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                revision,
+                user_id = %user.id,
+                "Ошибка восстановления публикации из ревизии"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(post))
+}
+
+/// Добавление комментария к публикации (требует аутентификации).
+///
+/// `/api/posts/{id}/comments`
+#[post("/{id}/comments")]
+async fn create_comment(
+    user: ReqData<AuthenticatedUser>,
+    body: Json<CreateComment>,
+    post_id: web::Path<DataId>,
+    comment_service: web::Data<Arc<CommentService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let author = user.into_inner();
+    let post_id = post_id.into_inner();
+    let comment = body.into_inner();
+
+    let comment = comment_service
+        .add_comment(&post_id, &comment, &author.id)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                author_id = %author.id,
+                "Ошибка добавления комментария"
+            )
+        })?;
+
+    Ok(HttpResponse::Created().json(comment))
+}
+
+/// Удаление комментария (требует аутентификации).
+///
+/// Доступно автору комментария, автору публикации и модераторам.
+///
+/// `/api/posts/{id}/comments/{comment_id}`
+#[delete("/{id}/comments/{comment_id}")]
+async fn delete_comment(
+    user: ReqData<AuthenticatedUser>,
+    path: web::Path<(DataId, DataId)>,
+    comment_service: web::Data<Arc<CommentService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let (post_id, comment_id) = path.into_inner();
+
+    comment_service
+        .delete_comment(&post_id, &comment_id, &user)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                comment_id = %comment_id,
+                user_id = %user.id,
+                "Ошибка при попытке удаления комментария"
+            )
+        })?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Реакция на публикацию (требует аутентификации).
+///
+/// Повторная реакция того же вида ничего не меняет. Возвращает сводку
+/// реакций на публикацию.
+///
+/// `/api/posts/{id}/reactions/{kind}`
+#[put("/{id}/reactions/{kind}")]
+async fn react_post(
+    user: ReqData<AuthenticatedUser>,
+    path: web::Path<(DataId, String)>,
+    reaction_service: web::Data<Arc<ReactionService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let (post_id, kind) = path.into_inner();
+    let kind: ReactionKind = kind.parse()?;
+
+    let reactions = reaction_service
+        .react(&post_id, &user.id, kind)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                user_id = %user.id,
+                kind = %kind,
+                "Ошибка реакции на публикацию"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(reactions))
+}
+
+/// Снятие реакции с публикации (требует аутентификации).
+///
+/// Возвращает сводку реакций на публикацию.
+///
+/// `/api/posts/{id}/reactions/{kind}`
+#[delete("/{id}/reactions/{kind}")]
+async fn unreact_post(
+    user: ReqData<AuthenticatedUser>,
+    path: web::Path<(DataId, String)>,
+    reaction_service: web::Data<Arc<ReactionService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let (post_id, kind) = path.into_inner();
+    let kind: ReactionKind = kind.parse()?;
+
+    let reactions = reaction_service
+        .unreact(&post_id, &user.id, kind)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                user_id = %user.id,
+                kind = %kind,
+                "Ошибка снятия реакции с публикации"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(reactions))
+}
+
+/// Подписка на автора (требует аутентификации).
+///
+/// `/api/users/{username}/follow`
+#[put("/{username}/follow")]
+async fn follow_user(
+    user: ReqData<AuthenticatedUser>,
+    username: web::Path<Username>,
+    follow_service: web::Data<Arc<FollowService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let username = username.into_inner();
+
+    follow_service
+        .follow(&user.id, &username)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                user_id = %user.id,
+                followee = %username,
+                "Ошибка подписки на автора"
+            )
+        })?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Отписка от автора (требует аутентификации).
+///
+/// `/api/users/{username}/follow`
+#[delete("/{username}/follow")]
+async fn unfollow_user(
+    user: ReqData<AuthenticatedUser>,
+    username: web::Path<Username>,
+    follow_service: web::Data<Arc<FollowService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let username = username.into_inner();
+
+    follow_service
+        .unfollow(&user.id, &username)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                user_id = %user.id,
+                followee = %username,
+                "Ошибка отписки от автора"
+            )
+        })?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Загрузка вложения — изображения или файла (требует аутентификации).
+/// Тело запроса в формате `multipart/form-data`, файл передаётся в поле
+/// `file`.
+///
+/// `/api/uploads`
+#[post("")]
+async fn upload_attachment(
+    user: ReqData<AuthenticatedUser>,
+    req: HttpRequest,
+    payload: web::Payload,
+    attachment_service: web::Data<Arc<AttachmentService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+    let max_bytes = attachment_service.max_bytes();
+
+    let body = payload
+        .to_bytes_limited(max_bytes + MULTIPART_OVERHEAD_BYTES)
+        .await
+        .map_err(|_| {
+            DomainError::attachment_too_large(format!(
+                "допустимый размер файла — не более {max_bytes} байт"
+            ))
+        })?
+        .map_err(|err| DomainError::api_error(format!("ошибка чтения тела запроса: {err}")))?;
+
+    let content_type = req
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    let file = extract_file(content_type, &body, "file")?;
+
+    let attachment = attachment_service
+        .upload(&user.id, &file.file_name, &file.data)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                user_id = %user.id,
+                file_name = %file.file_name,
+                "Ошибка загрузки вложения"
+            )
+        })?;
+
+    Ok(HttpResponse::Created().json(AttachmentDto::from(attachment)))
+}
+
 /// Конфигурация роутеров.
 pub(super) fn configure_posts_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/posts")
             .service(create_post)
             .service(update_post)
-            .service(delete_post),
+            .service(delete_post)
+            .service(publish_post)
+            .service(restore_post)
+            .service(hide_post)
+            .service(unhide_post)
+            .service(list_revisions)
+            .service(get_revision)
+            .service(diff_revisions)
+            .service(restore_revision)
+            .service(create_comment)
+            .service(delete_comment)
+            .service(react_post)
+            .service(unreact_post),
+    )
+    .service(web::scope("/drafts").service(list_drafts))
+    .service(web::scope("/trash").service(list_trash))
+    .service(web::scope("/feed").service(get_feed))
+    .service(web::scope("/uploads").service(upload_attachment))
+    .service(
+        web::scope("/users")
+            .service(follow_user)
+            .service(unfollow_user),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::{memory::fixtures, Repositories},
+        domain::{post::PostStatus, role::Role},
+    };
+    use actix_web::{dev::Service, test, App, HttpMessage};
+
+    #[actix_web::test]
+    async fn feed_without_limit_returns_first_ten_posts() {
+        let repos = Repositories::memory();
+        let reader = fixtures::user(&repos, "alice", Role::default()).await;
+        let author = fixtures::user(&repos, "bob", Role::default()).await;
+        for _ in 0..12 {
+            fixtures::post(&repos, &author.id, PostStatus::Published).await;
+        }
+        repos.follows.follow(&reader.id, &author.id).await.unwrap();
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(Arc::new(fixtures::blog_service(&repos))))
+                .app_data(web::Data::new(Arc::new(fixtures::reaction_service(&repos))))
+                .wrap_fn(move |req, srv| {
+                    req.extensions_mut().insert(reader.clone());
+                    srv.call(req)
+                })
+                .service(web::scope("/api/feed").service(get_feed)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/api/feed").to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(body["limit"], 10);
+        assert_eq!(body["total"], 12);
+        assert_eq!(body["posts"].as_array().map(Vec::len), Some(10));
+    }
+}
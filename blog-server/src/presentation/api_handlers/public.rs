@@ -1,21 +1,32 @@
 //! Публичные роутеры HTTP-сервера.
 
 use crate::{
-    application::{auth_service::AuthService, blog_service::BlogService},
-    data::{post_repo::PostRepo, user_repo::UserRepo},
+    application::{
+        auth_service::AuthService, blog_service::BlogService, comment_service::CommentService,
+        follow_service::FollowService, reaction_service::ReactionService,
+        token_service::TokenService, user_service::UserService,
+        verification_service::VerificationService,
+    },
     domain::{
-        post::QueryPosts,
-        types::DataId,
-        user::{AuthResponse, CreateUser, LoginUser, UserDto},
+        comment::QueryComments,
+        follow::QueryFollows,
+        post::{ListPostsParams, QueryPosts, QuerySearch},
+        token::RefreshTokenRequest,
+        types::{DataId, Slug, Username},
+        user::{AuthResponse, CreateUser, LoginUser, QueryAuthorPosts, UserDto},
     },
     errors::DomainError,
     infrastructure::config::BlogConfig,
     presentation::{
-        api_handlers::tools::valid_query_posts_params,
-        tools::{get_jwt_token, verified_user_password},
+        api_handlers::tools::{optional_user_id, request_context, valid_query_posts_params},
+        tools::issue_tokens,
     },
+    settings::COMMENTS_LIMIT_DEFAULT,
+};
+use actix_web::{
+    get, http::header, post, web, HttpRequest, HttpResponse, Responder, Result as ActixResult,
 };
-use actix_web::{get, post, web, HttpResponse, Responder, Result as ActixResult};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
 use serde_json::json;
 use std::sync::Arc;
 use tracing::error;
@@ -33,14 +44,17 @@ async fn health() -> impl Responder {
 /// `/api/auth/register`
 #[post("/register")]
 async fn register(
-    auth_service: web::Data<Arc<AuthService<UserRepo>>>,
+    req: HttpRequest,
+    auth_service: web::Data<Arc<AuthService>>,
+    token_service: web::Data<Arc<TokenService>>,
+    verification_service: web::Data<Arc<VerificationService>>,
     body: web::Json<CreateUser>,
-    server_cfg: web::Data<Arc<BlogConfig>>,
 ) -> ActixResult<impl Responder, DomainError> {
     let create_user = body.into_inner();
+    let ctx = request_context(&req);
 
     let user = auth_service
-        .create_user(&create_user)
+        .create_user(&create_user, &ctx)
         .await
         .inspect_err(|err| {
             error!(
@@ -50,9 +64,19 @@ async fn register(
             "Ошибка регистрации {}", create_user.username)
         })?;
 
+    // Ошибка отправки письма не отменяет регистрацию: письмо можно запросить
+    // повторно.
+    if let Err(err) = verification_service.send_verification(&user).await {
+        error!(
+            error = %err,
+            username = %user.username,
+            "Не удалось отправить письмо для подтверждения адреса"
+        );
+    }
+
     let user_dto: UserDto = user.into();
-    let token = get_jwt_token(&user_dto, &server_cfg.security.jwt_service)?;
-    let auth_response = AuthResponse::new(token, user_dto);
+    let tokens = issue_tokens(&user_dto, &token_service).await?;
+    let auth_response = AuthResponse::new(tokens, user_dto);
 
     Ok(HttpResponse::Created().json(auth_response))
 }
@@ -62,74 +86,351 @@ async fn register(
 /// `/api/auth/login`
 #[post("/login")]
 async fn login(
-    auth_service: web::Data<Arc<AuthService<UserRepo>>>,
+    req: HttpRequest,
+    auth_service: web::Data<Arc<AuthService>>,
+    token_service: web::Data<Arc<TokenService>>,
     body: web::Json<LoginUser>,
-    server_cfg: web::Data<Arc<BlogConfig>>,
 ) -> ActixResult<impl Responder, DomainError> {
     let login_user = body.into_inner();
+    let ctx = request_context(&req);
     let user = auth_service
-        .get_user(&login_user.username)
+        .login(&login_user, &ctx)
         .await
         .inspect_err(|err| {
             error!(
                 error = %err,
                 username = %login_user.username,
-                "Пользователь не найден: {}", login_user.username)
+                "Ошибка авторизации {}", login_user.username)
         })?;
 
-    verified_user_password(&login_user, &user.password_hash)?;
-
     let user_dto: UserDto = user.into();
-    let token = get_jwt_token(&user_dto, &server_cfg.security.jwt_service)?;
-    let auth_response = AuthResponse::new(token, user_dto);
+    let tokens = issue_tokens(&user_dto, &token_service).await?;
+    let auth_response = AuthResponse::new(tokens, user_dto);
+
+    Ok(HttpResponse::Ok().json(auth_response))
+}
+
+/// Обновление сессии: новая пара токенов взамен токена обновления.
+///
+/// `/api/auth/refresh`
+#[post("/refresh")]
+async fn refresh(
+    auth_service: web::Data<Arc<AuthService>>,
+    token_service: web::Data<Arc<TokenService>>,
+    body: web::Json<RefreshTokenRequest>,
+) -> ActixResult<impl Responder, DomainError> {
+    let (username, tokens) = token_service.refresh(&body.refresh_token).await?;
+    let user = auth_service.get_user(&username).await?;
+
+    let auth_response = AuthResponse::new(tokens, user.into());
 
     Ok(HttpResponse::Ok().json(auth_response))
 }
 
+/// Завершение сессии: токен обновления и выданные по нему токены доступа
+/// отзываются.
+///
+/// `/api/auth/logout`
+#[post("/logout")]
+async fn logout(
+    token_service: web::Data<Arc<TokenService>>,
+    body: web::Json<RefreshTokenRequest>,
+) -> ActixResult<impl Responder, DomainError> {
+    token_service.logout(&body.refresh_token).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
 /// Список постов (публичный, с пагинацией).
-/// Извлекает query-параметры limit и offset (по умолчанию limit=10, offset=0).
+/// Извлекает query-параметры limit и offset (по умолчанию limit=10, offset=0),
+/// а также опциональный фильтр tag и курсор after из `next_cursor`
+/// предыдущего ответа.
+///
+/// Для запроса с Bearer-токеном публикации дополняются реакциями
+/// пользователя.
 ///
 /// `api/posts`
 #[get("/posts")]
 async fn get_posts(
     query: web::Query<QueryPosts>,
-    blog_service: web::Data<Arc<BlogService<PostRepo>>>,
+    credentials: Option<BearerAuth>,
+    blog_service: web::Data<Arc<BlogService>>,
+    reaction_service: web::Data<Arc<ReactionService>>,
+    config: web::Data<Arc<BlogConfig>>,
 ) -> ActixResult<impl Responder, DomainError> {
-    let limit = query.limit.unwrap_or_default();
+    let viewer = optional_user_id(credentials, &config);
+    let limit = query.limit.unwrap_or(10);
     let offset = query.offset.unwrap_or_default();
     let (limit_i32, offset_i32) = valid_query_posts_params(limit, offset)?;
 
-    let posts = blog_service.list_posts(limit_i32, offset_i32).await?;
+    let query = query.into_inner();
+    let params = ListPostsParams {
+        limit: limit_i32,
+        offset: offset_i32,
+        tag: query.tag,
+        after: query.after,
+        followed_by: None,
+        author_id: None,
+    };
+
+    let mut posts = blog_service.list_posts(params).await?;
+    reaction_service
+        .apply_viewer(&mut posts.posts, viewer.as_ref())
+        .await?;
 
     Ok(HttpResponse::Ok().json(posts))
 }
 
+/// Полнотекстовый поиск публикаций (публичный, с пагинацией).
+/// Извлекает query-параметры q, limit и offset (по умолчанию limit=10,
+/// offset=0).
+///
+/// `api/posts/search`
+#[get("/posts/search")]
+async fn search_posts(
+    query: web::Query<QuerySearch>,
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or_default();
+    let (limit_i32, offset_i32) = valid_query_posts_params(limit, offset)?;
+
+    let results = blog_service
+        .search_posts(&query.q, limit_i32, offset_i32)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(results))
+}
+
+/// Перечень тегов с количеством публикаций.
+///
+/// `api/tags`
+#[get("/tags")]
+async fn get_tags(
+    blog_service: web::Data<Arc<BlogService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let tags = blog_service.list_tags().await?;
+
+    Ok(HttpResponse::Ok().json(tags))
+}
+
 /// Возвращает публикацию по id, при наличии.
 ///
+/// Черновики и отложенные публикации доступны только автору: для этого
+/// запрос должен содержать его Bearer-токен. Для авторизованного запроса
+/// публикация дополняется реакциями пользователя.
+///
 /// `api/posts/{id}`
 #[get("/posts/{id}")]
 async fn get_one_post(
     post_id: web::Path<DataId>,
-    blog_service: web::Data<Arc<BlogService<PostRepo>>>,
+    credentials: Option<BearerAuth>,
+    blog_service: web::Data<Arc<BlogService>>,
+    reaction_service: web::Data<Arc<ReactionService>>,
+    config: web::Data<Arc<BlogConfig>>,
 ) -> ActixResult<impl Responder, DomainError> {
     let post_id = post_id.into_inner();
-    let post = blog_service.get_post(&post_id).await.inspect_err(|err| {
-        error!(
-            error = %err,
-            post_id = %post_id,
-            "Неудачная попытка чтения публикации"
-        )
-    })?;
+    let viewer = optional_user_id(credentials, &config);
+
+    let mut post = blog_service
+        .get_visible_post(&post_id, viewer.as_ref())
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                "Неудачная попытка чтения публикации"
+            )
+        })?;
+    reaction_service
+        .apply_viewer(std::slice::from_mut(&mut post), viewer.as_ref())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(post))
+}
+
+/// Возвращает публикацию по её человекочитаемому адресу.
+///
+/// Если запрошен прежний адрес публикации, ответ — перенаправление
+/// `301 Moved Permanently` на текущий. Видимость — как у `api/posts/{id}`.
+///
+/// `api/posts/by-slug/{slug}`
+#[get("/posts/by-slug/{slug}")]
+async fn get_post_by_slug(
+    slug: web::Path<Slug>,
+    credentials: Option<BearerAuth>,
+    blog_service: web::Data<Arc<BlogService>>,
+    reaction_service: web::Data<Arc<ReactionService>>,
+    config: web::Data<Arc<BlogConfig>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let slug = slug.into_inner();
+    let viewer = optional_user_id(credentials, &config);
+
+    let mut post = blog_service
+        .get_visible_post_by_slug(&slug, viewer.as_ref())
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                slug = %slug,
+                "Неудачная попытка чтения публикации по адресу"
+            )
+        })?;
+
+    if let Some(current) = post.slug.as_ref().filter(|current| **current != slug) {
+        return Ok(HttpResponse::MovedPermanently()
+            .insert_header((header::LOCATION, format!("/api/posts/by-slug/{current}")))
+            .finish());
+    }
+    reaction_service
+        .apply_viewer(std::slice::from_mut(&mut post), viewer.as_ref())
+        .await?;
 
     Ok(HttpResponse::Ok().json(post))
 }
 
+/// Список комментариев к публикации (публичный, с пагинацией).
+/// Извлекает query-параметры limit и offset (по умолчанию limit=50, offset=0).
+///
+/// `api/posts/{id}/comments`
+#[get("/posts/{id}/comments")]
+async fn get_comments(
+    post_id: web::Path<DataId>,
+    query: web::Query<QueryComments>,
+    credentials: Option<BearerAuth>,
+    comment_service: web::Data<Arc<CommentService>>,
+    config: web::Data<Arc<BlogConfig>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let post_id = post_id.into_inner();
+    let viewer = optional_user_id(credentials, &config);
+    let limit = query.limit.unwrap_or(COMMENTS_LIMIT_DEFAULT);
+    let offset = query.offset.unwrap_or_default();
+    let (limit_i32, offset_i32) = valid_query_posts_params(limit, offset)?;
+
+    let comments = comment_service
+        .list_comments(&post_id, viewer.as_ref(), limit_i32, offset_i32)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                post_id = %post_id,
+                "Неудачная попытка чтения комментариев"
+            )
+        })?;
+
+    Ok(HttpResponse::Ok().json(comments))
+}
+
+/// Публичный профиль автора.
+///
+/// `api/users/{username}`
+#[get("/users/{username}")]
+async fn get_user_profile(
+    username: web::Path<Username>,
+    user_service: web::Data<Arc<UserService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let username = username.into_inner();
+    let profile = user_service.get_profile(&username).await?;
+
+    Ok(HttpResponse::Ok().json(profile))
+}
+
+/// Опубликованные записи автора (публичный, с пагинацией).
+/// Извлекает query-параметры limit и offset (по умолчанию limit=10, offset=0).
+///
+/// `api/users/{username}/posts`
+#[get("/users/{username}/posts")]
+async fn get_user_posts(
+    username: web::Path<Username>,
+    query: web::Query<QueryAuthorPosts>,
+    credentials: Option<BearerAuth>,
+    user_service: web::Data<Arc<UserService>>,
+    reaction_service: web::Data<Arc<ReactionService>>,
+    config: web::Data<Arc<BlogConfig>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let username = username.into_inner();
+    let viewer = optional_user_id(credentials, &config);
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or_default();
+    let (limit_i32, offset_i32) = valid_query_posts_params(limit, offset)?;
+
+    let mut posts = user_service
+        .list_author_posts(&username, limit_i32, offset_i32)
+        .await?;
+    reaction_service
+        .apply_viewer(&mut posts.posts, viewer.as_ref())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(posts))
+}
+
+/// Подписчики пользователя, от недавно подписавшихся (публичный,
+/// с пагинацией). Извлекает query-параметры limit и offset (по умолчанию
+/// limit=10, offset=0).
+///
+/// `api/users/{username}/followers`
+#[get("/users/{username}/followers")]
+async fn get_followers(
+    username: web::Path<Username>,
+    query: web::Query<QueryFollows>,
+    follow_service: web::Data<Arc<FollowService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let username = username.into_inner();
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or_default();
+    let (limit_i32, offset_i32) = valid_query_posts_params(limit, offset)?;
+
+    let followers = follow_service
+        .list_followers(&username, limit_i32, offset_i32)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(followers))
+}
+
+/// Авторы, на которых подписан пользователь (публичный, с пагинацией).
+/// Извлекает query-параметры limit и offset (по умолчанию limit=10, offset=0).
+///
+/// `api/users/{username}/following`
+#[get("/users/{username}/following")]
+async fn get_following(
+    username: web::Path<Username>,
+    query: web::Query<QueryFollows>,
+    follow_service: web::Data<Arc<FollowService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let username = username.into_inner();
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or_default();
+    let (limit_i32, offset_i32) = valid_query_posts_params(limit, offset)?;
+
+    let following = follow_service
+        .list_following(&username, limit_i32, offset_i32)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(following))
+}
+
 /// Публичные роутеры, кроме регистрации и авторизации.
 pub(super) fn configure_list_routes(cfg: &mut web::ServiceConfig) {
-    cfg.service(get_posts).service(get_one_post).service(health);
+    // `search_posts` регистрируется раньше `get_one_post`, иначе путь
+    // `/posts/search` будет перехвачен как `/posts/{id}`. По той же причине
+    // `get_post_by_slug` предшествует `get_comments`.
+    cfg.service(get_posts)
+        .service(search_posts)
+        .service(get_one_post)
+        .service(get_post_by_slug)
+        .service(get_comments)
+        .service(get_user_profile)
+        .service(get_user_posts)
+        .service(get_followers)
+        .service(get_following)
+        .service(get_tags)
+        .service(health);
 }
 
 /// Роутеры для регистрации и авторизации пользователей.
 pub(super) fn configure_auth_routes(cfg: &mut web::ServiceConfig) {
-    cfg.service(register).service(login);
+    cfg.service(register)
+        .service(login)
+        .service(refresh)
+        .service(logout);
 }
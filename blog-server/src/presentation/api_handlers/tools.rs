@@ -1,10 +1,14 @@
 //! Поддерживающие утилиты и инструменты для роутеров.
 
 use crate::{
+    domain::{audit::RequestContext, types::DataId, user::AuthenticatedUser},
     errors::DomainError,
+    infrastructure::config::BlogConfig,
     presentation::tools::validate_list_params,
     settings::POSTS_OFFSET_MAX,
 };
+use actix_web::{http::header::USER_AGENT, HttpRequest};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
 
 /// Быстрая проверка валидности значений `limit` и `offset` в query-параметрах
 /// для выгрузки списка публикаций и преобразование значений в ожидаемые.
@@ -31,3 +35,34 @@ pub(super) fn valid_query_posts_params(limit: u32, offset: u32) -> Result<(i32,
 
     Ok((limit_i32, offset_i32))
 }
+
+/// Id пользователя из необязательного Bearer-токена публичного роутера.
+///
+/// Отсутствующий или недействительный токен не является ошибкой: запрос
+/// обрабатывается как анонимный.
+pub(super) fn optional_user_id(credentials: Option<BearerAuth>, cfg: &BlogConfig) -> Option<DataId> {
+    let claim = cfg
+        .security
+        .jwt_service
+        .verify_token(credentials?.token())
+        .ok()?;
+
+    let user: AuthenticatedUser = claim.into();
+    Some(user.id)
+}
+
+/// Сведения о запросе для журнала аудита: IP-адрес клиента, заголовки
+/// `User-Agent` и `X-Request-Id`.
+pub(super) fn request_context(req: &HttpRequest) -> RequestContext {
+    let header = |name| {
+        req.headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    };
+
+    RequestContext::new(
+        req.peer_addr().map(|addr| addr.ip()),
+        header(USER_AGENT.as_str()),
+        header("x-request-id"),
+    )
+}
@@ -0,0 +1,72 @@
+//! Раздача файлов вложений.
+//!
+//! Вложения доступны без префикса `/api` по постоянному адресу
+//! `/uploads/{key}`, который возвращается при загрузке файла.
+
+use crate::{
+    application::attachment_service::AttachmentService, domain::types::StorageKey,
+    errors::DomainError,
+};
+use actix_web::{
+    get,
+    http::header::{
+        CacheControl, CacheDirective, Charset, ContentDisposition, DispositionParam,
+        DispositionType, ExtendedValue, X_CONTENT_TYPE_OPTIONS,
+    },
+    web, HttpResponse, Responder, Result as ActixResult,
+};
+use std::sync::Arc;
+
+/// Срок кеширования вложений клиентами (секунды). Файл по ключу
+/// никогда не меняется.
+const UPLOADS_MAX_AGE_SECS: u32 = 365 * 24 * 3600;
+
+/// Файл вложения.
+///
+/// Изображения отдаются для показа в браузере, прочие файлы — для
+/// скачивания под исходным именем.
+///
+/// `/uploads/{key}`
+#[get("/uploads/{key}")]
+async fn get_upload(
+    key: web::Path<StorageKey>,
+    attachment_service: web::Data<Arc<AttachmentService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let (attachment, data) = attachment_service.open(&key).await?;
+
+    let disposition = if attachment.content_type.starts_with("image/") {
+        DispositionType::Inline
+    } else {
+        DispositionType::Attachment
+    };
+    let file_name = if attachment.file_name.is_ascii() {
+        DispositionParam::Filename(attachment.file_name)
+    } else {
+        DispositionParam::FilenameExt(ExtendedValue {
+            charset: Charset::Ext("UTF-8".to_string()),
+            language_tag: None,
+            value: attachment.file_name.into_bytes(),
+        })
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type(attachment.content_type)
+        .insert_header(ContentDisposition {
+            disposition,
+            parameters: vec![file_name],
+        })
+        .insert_header(CacheControl(vec![
+            CacheDirective::Public,
+            CacheDirective::MaxAge(UPLOADS_MAX_AGE_SECS),
+            CacheDirective::Extension("immutable".to_string(), None),
+        ]))
+        // Тип содержимого задан загрузившим пользователем: браузер не должен
+        // угадывать его по самому файлу.
+        .insert_header((X_CONTENT_TYPE_OPTIONS, "nosniff"))
+        .body(data))
+}
+
+/// Роутеры раздачи вложений.
+pub(super) fn configure_upload_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_upload);
+}
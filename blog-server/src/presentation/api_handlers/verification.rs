@@ -0,0 +1,69 @@
+//! `Endpoints` подтверждения адреса электронной почты.
+
+use crate::{
+    application::verification_service::VerificationService,
+    domain::{
+        user::{AuthenticatedUser, UserDto},
+        verification::VerifyEmail,
+    },
+    errors::DomainError,
+    presentation::middleware,
+};
+use actix_web::{
+    post,
+    web::{self, Json, ReqData},
+    HttpResponse, Responder, Result as ActixResult,
+};
+use actix_web_httpauth::middleware::HttpAuthentication;
+use std::sync::Arc;
+use tracing::error;
+
+/// Подтверждение адреса по токену из письма.
+///
+/// `/api/auth/email/verify`
+#[post("/email/verify")]
+async fn verify_email(
+    body: Json<VerifyEmail>,
+    verification_service: web::Data<Arc<VerificationService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = verification_service
+        .verify(&body.token)
+        .await
+        .inspect_err(|err| error!(error = %err, "Ошибка подтверждения адреса"))?;
+
+    let user_dto: UserDto = user.into();
+
+    Ok(HttpResponse::Ok().json(user_dto))
+}
+
+/// Повторная отправка письма для подтверждения адреса авторизованному
+/// пользователю.
+///
+/// `/api/auth/email/resend`
+async fn resend_verification(
+    user: ReqData<AuthenticatedUser>,
+    verification_service: web::Data<Arc<VerificationService>>,
+) -> ActixResult<impl Responder, DomainError> {
+    let user = user.into_inner();
+
+    verification_service
+        .resend(&user)
+        .await
+        .inspect_err(|err| {
+            error!(
+                error = %err,
+                username = %user.username,
+                "Ошибка повторной отправки письма подтверждения"
+            )
+        })?;
+
+    Ok(HttpResponse::Accepted().finish())
+}
+
+pub(super) fn configure_verification_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(verify_email).service(
+        web::resource("/email/resend")
+            .wrap(HttpAuthentication::bearer(middleware::jwt_validator))
+            .route(web::post().to(resend_verification)),
+    );
+}
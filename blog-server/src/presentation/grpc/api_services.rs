@@ -1,34 +1,99 @@
 //! Инфраструктура сервера для обработки gRPC.
 
 use crate::{
-    application::{auth_service::AuthService, blog_service::BlogService, AppServices},
-    data::{post_repo::PostRepo, user_repo::UserRepo},
+    application::{
+        account_service::AccountService, admin_service::AdminService,
+        attachment_service::AttachmentService, audit_service::AuditService,
+        auth_service::AuthService, blog_service::BlogService, comment_service::CommentService,
+        follow_service::FollowService, moderation_service::ModerationService,
+        password_service::PasswordService, profile_service::ProfileService,
+        reaction_service::ReactionService, revision_service::RevisionService,
+        token_service::TokenService, user_service::UserService,
+        verification_service::VerificationService, AppServices,
+    },
     domain::{
-        post::{CreatePost, EditPostCommand},
-        types::DataId,
-        user::{AuthResponse as UserAuthResponse, CreateUser, LoginUser, UserDto},
+        account::DeleteAccount,
+        audit::AuditFilter,
+        comment::CreateComment,
+        moderation::SaveModerationRule,
+        password::{ChangePassword, ResetPassword},
+        post::{CreatePost, EditPostCommand, ListPostsParams},
+        reaction::ReactionKind,
+        role::Role,
+        types::{DataId, Email, PostCursor, SearchQuery, Slug, Tag, Username},
+        user::{AuthResponse as UserAuthResponse, CreateUser, LoginUser, UpdateProfile, UserDto},
     },
+    errors::DomainError,
     infrastructure::jwt::JwtService,
     presentation::{
-        grpc::tools::get_auth_user,
-        tools::{get_jwt_token, validate_list_params, verified_user_password},
+        grpc::tools::{get_auth_user, request_context},
+        tools::{datetime_from_timestamp, issue_tokens, validate_list_params},
     },
+    settings::EXPORT_POSTS_PAGE_SIZE,
 };
 use proto_crate::proto_blog::{
-    blog_service_server::BlogService as TraitBlogService, AuthResponse, CreatePostRequest, DeletePostRequest, DeletePostResponse,
-    GetPostRequest, ListPostsRequest, ListPostsResponse, LoginRequest, Post as ProtoPost,
-    PostResponse, RegisterRequest, UpdatePostRequest,
+    blog_service_server::BlogService as TraitBlogService, AddCommentRequest, AttachmentResponse,
+    AuthResponse, ChangePasswordRequest, Comment as ProtoComment, CommentResponse,
+    CreateModerationRuleRequest, CreatePostRequest, DeleteCommentRequest, DeleteCommentResponse,
+    DeleteModerationRuleRequest, DeleteModerationRuleResponse, DeleteMyAccountRequest,
+    DeleteMyAccountResponse, DeletePostRequest, DeletePostResponse, DiffRevisionsRequest,
+    DiffRevisionsResponse, ExportMyDataRequest, ExportMyDataResponse, FollowRequest,
+    FollowResponse, GetMeRequest, GetPostBySlugRequest, GetPostRequest, GetRevisionRequest,
+    GetUserProfileRequest, HidePostRequest, ListAuditEventsRequest, ListAuditEventsResponse,
+    ListCommentsRequest, ListCommentsResponse, ListDraftsRequest, ListFollowsRequest,
+    ListFollowsResponse, ListModerationQueueRequest, ListModerationRulesRequest,
+    ListModerationRulesResponse, ListPostsRequest, ListPostsResponse, ListRevisionsRequest,
+    ListRevisionsResponse, ListTagsRequest, ListTagsResponse, ListTrashRequest,
+    ListUserPostsRequest, ListUsersRequest, ListUsersResponse, LoginRequest, LogoutRequest,
+    LogoutResponse, MeResponse, ModerationRuleResponse, PasswordResetResponse, Post as ProtoPost,
+    PostResponse, PublishPostRequest, ReactRequest, ReactionsResponse, RefreshRequest,
+    RegisterRequest, RequestPasswordResetRequest, ResendVerificationRequest,
+    ResendVerificationResponse, ResetPasswordRequest, RestorePostRequest, RestoreRevisionRequest,
+    RevisionResponse, SearchPostsRequest, SearchPostsResponse, SetUserDisabledRequest,
+    SetUserRoleRequest, UnlockUserRequest, UpdateModerationRuleRequest, UpdatePostRequest,
+    UpdateProfileRequest, UploadAttachmentRequest, UserAccountResponse, UserProfileResponse,
+    VerifyEmailRequest, VerifyEmailResponse,
 };
 use std::sync::Arc;
-use tonic::{Request, Response, Status};
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, StreamExt};
+use tonic::{Request, Response, Status, Streaming};
 use tracing::error;
 
 /// gRPC-сервис блога, использующий методы взаимодействия с базой данных.
 pub(crate) struct BlogGrpcService {
     /// Серверный сервис аутентификации.
-    auth_service: Arc<AuthService<UserRepo>>,
+    auth_service: Arc<AuthService>,
+    /// Серверный сервис сессий пользователей.
+    token_service: Arc<TokenService>,
+    /// Серверный сервис смены и сброса пароля.
+    password_service: Arc<PasswordService>,
+    /// Серверный сервис подтверждения адреса электронной почты.
+    verification_service: Arc<VerificationService>,
+    /// Серверный сервис профиля текущего пользователя.
+    profile_service: Arc<ProfileService>,
     /// Серверный сервис обработки данных блога.
-    post_service: Arc<BlogService<PostRepo>>,
+    post_service: Arc<BlogService>,
+    /// Серверный сервис обработки комментариев.
+    comment_service: Arc<CommentService>,
+    /// Серверный сервис истории изменений публикаций.
+    revision_service: Arc<RevisionService>,
+    /// Серверный сервис публичных профилей авторов.
+    user_service: Arc<UserService>,
+    /// Серверный сервис реакций на публикации.
+    reaction_service: Arc<ReactionService>,
+    /// Серверный сервис подписок на авторов.
+    follow_service: Arc<FollowService>,
+    /// Серверный сервис вложений публикаций.
+    attachment_service: Arc<AttachmentService>,
+    /// Серверный сервис удаления учётной записи и выгрузки данных.
+    account_service: Arc<AccountService>,
+    /// Серверный сервис администрирования учётных записей.
+    admin_service: Arc<AdminService>,
+    /// Серверный сервис журнала аудита.
+    audit_service: Arc<AuditService>,
+    /// Серверный сервис правил модерации.
+    moderation_service: Arc<ModerationService>,
     /// Сервис обработки JWT-токенов приложения.
     jwt_service: Arc<JwtService>,
 }
@@ -39,7 +104,21 @@ impl BlogGrpcService {
     pub(crate) fn new(app_services: AppServices, jwt_service: Arc<JwtService>) -> Self {
         Self {
             auth_service: Arc::clone(&app_services.auth_service),
+            token_service: Arc::clone(&app_services.token_service),
+            password_service: Arc::clone(&app_services.password_service),
+            verification_service: Arc::clone(&app_services.verification_service),
+            profile_service: Arc::clone(&app_services.profile_service),
             post_service: Arc::clone(&app_services.blog_service),
+            comment_service: Arc::clone(&app_services.comment_service),
+            revision_service: Arc::clone(&app_services.revision_service),
+            user_service: Arc::clone(&app_services.user_service),
+            reaction_service: Arc::clone(&app_services.reaction_service),
+            follow_service: Arc::clone(&app_services.follow_service),
+            attachment_service: Arc::clone(&app_services.attachment_service),
+            account_service: Arc::clone(&app_services.account_service),
+            admin_service: Arc::clone(&app_services.admin_service),
+            audit_service: Arc::clone(&app_services.audit_service),
+            moderation_service: Arc::clone(&app_services.moderation_service),
             jwt_service,
         }
     }
@@ -51,11 +130,12 @@ impl TraitBlogService for BlogGrpcService {
         &self,
         request: Request<RegisterRequest>,
     ) -> Result<Response<AuthResponse>, Status> {
+        let ctx = request_context(&request);
         let create_user = CreateUser::try_from(request.into_inner())?;
 
         let user = self
             .auth_service
-            .create_user(&create_user)
+            .create_user(&create_user, &ctx)
             .await
             .inspect_err(|err| {
                 error!(
@@ -65,9 +145,19 @@ impl TraitBlogService for BlogGrpcService {
             "Ошибка регистрации")
             })?;
 
+        // Ошибка отправки письма не отменяет регистрацию: письмо можно
+        // запросить повторно.
+        if let Err(err) = self.verification_service.send_verification(&user).await {
+            error!(
+                error = %err,
+                username = %user.username,
+                "Не удалось отправить письмо для подтверждения адреса"
+            );
+        }
+
         let user_dto: UserDto = user.into();
-        let token = get_jwt_token(&user_dto, &self.jwt_service)?;
-        let auth_response: AuthResponse = UserAuthResponse::new(token, user_dto).try_into()?;
+        let tokens = issue_tokens(&user_dto, &self.token_service).await?;
+        let auth_response: AuthResponse = UserAuthResponse::new(tokens, user_dto).try_into()?;
 
         Ok(Response::new(auth_response))
     }
@@ -76,11 +166,12 @@ impl TraitBlogService for BlogGrpcService {
         &self,
         request: Request<LoginRequest>,
     ) -> Result<Response<AuthResponse>, Status> {
+        let ctx = request_context(&request);
         let login_user = LoginUser::try_from(request.into_inner())?;
 
         let user = self
             .auth_service
-            .get_user(&login_user.username)
+            .login(&login_user, &ctx)
             .await
             .inspect_err(|err| {
                 error!(
@@ -90,25 +181,274 @@ impl TraitBlogService for BlogGrpcService {
                 )
             })?;
 
-        verified_user_password(&login_user, &user.password_hash)?;
+        let user_dto: UserDto = user.into();
+        let tokens = issue_tokens(&user_dto, &self.token_service).await?;
+        let auth_response: AuthResponse = UserAuthResponse::new(tokens, user_dto).try_into()?;
+
+        Ok(Response::new(auth_response))
+    }
+
+    async fn refresh(
+        &self,
+        request: Request<RefreshRequest>,
+    ) -> Result<Response<AuthResponse>, Status> {
+        let refresh_token = request.into_inner().refresh_token;
+
+        let (username, tokens) = self.token_service.refresh(&refresh_token).await?;
+        let user = self.auth_service.get_user(&username).await?;
+        let auth_response: AuthResponse = UserAuthResponse::new(tokens, user.into()).try_into()?;
+
+        Ok(Response::new(auth_response))
+    }
+
+    async fn logout(
+        &self,
+        request: Request<LogoutRequest>,
+    ) -> Result<Response<LogoutResponse>, Status> {
+        let refresh_token = request.into_inner().refresh_token;
+        self.token_service.logout(&refresh_token).await?;
+
+        Ok(Response::new(LogoutResponse { success: true }))
+    }
+
+    async fn change_password(
+        &self,
+        request: Request<ChangePasswordRequest>,
+    ) -> Result<Response<AuthResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let change_password = ChangePassword::try_from(request.into_inner())?;
+
+        let user = self
+            .password_service
+            .change_password(&auth_user, &change_password)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    username = %auth_user.username,
+                    "Ошибка смены пароля"
+                )
+            })?;
 
         let user_dto: UserDto = user.into();
-        let token = get_jwt_token(&user_dto, &self.jwt_service)?;
-        let auth_response: AuthResponse = UserAuthResponse::new(token, user_dto).try_into()?;
+        let tokens = issue_tokens(&user_dto, &self.token_service).await?;
+        let auth_response: AuthResponse = UserAuthResponse::new(tokens, user_dto).try_into()?;
 
         Ok(Response::new(auth_response))
     }
 
+    async fn request_password_reset(
+        &self,
+        request: Request<RequestPasswordResetRequest>,
+    ) -> Result<Response<PasswordResetResponse>, Status> {
+        let email = Email::try_from(request.into_inner().email)?;
+
+        self.password_service
+            .request_reset(&email)
+            .await
+            .inspect_err(|err| error!(error = %err, "Ошибка запроса сброса пароля"))?;
+
+        Ok(Response::new(PasswordResetResponse { success: true }))
+    }
+
+    async fn reset_password(
+        &self,
+        request: Request<ResetPasswordRequest>,
+    ) -> Result<Response<PasswordResetResponse>, Status> {
+        let reset_password = ResetPassword::try_from(request.into_inner())?;
+
+        self.password_service
+            .reset_password(&reset_password.token, &reset_password.new_password)
+            .await
+            .inspect_err(|err| error!(error = %err, "Ошибка сброса пароля"))?;
+
+        Ok(Response::new(PasswordResetResponse { success: true }))
+    }
+
+    async fn verify_email(
+        &self,
+        request: Request<VerifyEmailRequest>,
+    ) -> Result<Response<VerifyEmailResponse>, Status> {
+        let token = request.into_inner().token;
+
+        let user = self
+            .verification_service
+            .verify(&token)
+            .await
+            .inspect_err(|err| error!(error = %err, "Ошибка подтверждения адреса"))?;
+
+        let user_dto: UserDto = user.into();
+
+        Ok(Response::new(VerifyEmailResponse {
+            user: Some(user_dto.try_into()?),
+        }))
+    }
+
+    async fn resend_verification(
+        &self,
+        request: Request<ResendVerificationRequest>,
+    ) -> Result<Response<ResendVerificationResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+
+        self.verification_service
+            .resend(&auth_user)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    username = %auth_user.username,
+                    "Ошибка повторной отправки письма подтверждения"
+                )
+            })?;
+
+        Ok(Response::new(ResendVerificationResponse { success: true }))
+    }
+
+    type ExportMyDataStream = ReceiverStream<Result<ExportMyDataResponse, Status>>;
+
+    async fn export_my_data(
+        &self,
+        request: Request<ExportMyDataRequest>,
+    ) -> Result<Response<Self::ExportMyDataStream>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+
+        let export = self
+            .account_service
+            .export(&auth_user)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    username = %auth_user.username,
+                    "Ошибка выгрузки персональных данных"
+                )
+            })?;
+        let account = ExportMyDataResponse::try_from(export.account)?;
+
+        // Публикации пересылаются клиенту по мере чтения из хранилища;
+        // задача завершается на первой ошибке или при отключении клиента.
+        let (tx, rx) = mpsc::channel(EXPORT_POSTS_PAGE_SIZE as usize);
+        let mut posts = export.posts;
+        tokio::spawn(async move {
+            if tx.send(Ok(account)).await.is_err() {
+                return;
+            }
+            while let Some(post) = posts.next().await {
+                let item = post
+                    .map_err(Status::from)
+                    .and_then(ExportMyDataResponse::try_from);
+                let failed = item.is_err();
+                if tx.send(item).await.is_err() || failed {
+                    return;
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn delete_my_account(
+        &self,
+        request: Request<DeleteMyAccountRequest>,
+    ) -> Result<Response<DeleteMyAccountResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let cmd = DeleteAccount::try_from(request.into_inner())?;
+
+        self.account_service
+            .delete_account(&auth_user, &cmd)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    username = %auth_user.username,
+                    "Ошибка удаления учётной записи"
+                )
+            })?;
+
+        Ok(Response::new(DeleteMyAccountResponse { success: true }))
+    }
+
+    async fn get_me(&self, request: Request<GetMeRequest>) -> Result<Response<MeResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+
+        let user = self.profile_service.me(&auth_user).await?;
+        let user_dto: UserDto = user.into();
+
+        Ok(Response::new(MeResponse {
+            user: Some(user_dto.try_into()?),
+        }))
+    }
+
+    async fn update_profile(
+        &self,
+        request: Request<UpdateProfileRequest>,
+    ) -> Result<Response<MeResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let update = UpdateProfile::try_from(request.into_inner())?;
+
+        let user = self
+            .profile_service
+            .update(&auth_user, update)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    username = %auth_user.username,
+                    "Ошибка изменения профиля"
+                )
+            })?;
+        let user_dto: UserDto = user.into();
+
+        Ok(Response::new(MeResponse {
+            user: Some(user_dto.try_into()?),
+        }))
+    }
+
+    async fn get_user_profile(
+        &self,
+        request: Request<GetUserProfileRequest>,
+    ) -> Result<Response<UserProfileResponse>, Status> {
+        let username = Username::try_from(request.into_inner().username)?;
+        let profile = self.user_service.get_profile(&username).await?;
+
+        Ok(Response::new(UserProfileResponse {
+            profile: Some(profile.into()),
+        }))
+    }
+
+    async fn list_user_posts(
+        &self,
+        request: Request<ListUserPostsRequest>,
+    ) -> Result<Response<ListPostsResponse>, Status> {
+        let viewer = get_auth_user(request.metadata(), self.jwt_service.clone())
+            .ok()
+            .map(|user| user.id);
+        let list_posts = request.into_inner();
+        validate_list_params(list_posts.limit, list_posts.offset)?;
+        let username = Username::try_from(list_posts.username)?;
+
+        let mut posts = self
+            .user_service
+            .list_author_posts(&username, list_posts.limit, list_posts.offset)
+            .await?;
+        self.reaction_service
+            .apply_viewer(&mut posts.posts, viewer.as_ref())
+            .await?;
+
+        Ok(Response::new(posts.try_into()?))
+    }
+
     async fn create_post(
         &self,
         request: Request<CreatePostRequest>,
     ) -> Result<Response<PostResponse>, Status> {
         let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let ctx = request_context(&request);
         let create_post = CreatePost::try_from(request.into_inner())?;
 
         let post = self
             .post_service
-            .create_post(&create_post, &auth_user.id)
+            .create_post(&create_post, &auth_user, &ctx)
             .await
             .inspect_err(|err| {
                 error!(
@@ -129,10 +469,15 @@ impl TraitBlogService for BlogGrpcService {
         &self,
         request: Request<GetPostRequest>,
     ) -> Result<Response<PostResponse>, Status> {
+        // Токен необязателен: он нужен автору для просмотра черновиков
+        // и пользователю — для отметки его реакций.
+        let viewer = get_auth_user(request.metadata(), self.jwt_service.clone())
+            .ok()
+            .map(|user| user.id);
         let post_id: DataId = request.into_inner().id.into();
-        let post = self
+        let mut post = self
             .post_service
-            .get_post(&post_id)
+            .get_visible_post(&post_id, viewer.as_ref())
             .await
             .inspect_err(|err| {
                 error!(
@@ -141,6 +486,39 @@ impl TraitBlogService for BlogGrpcService {
                     "Публикация не найдена"
                 )
             })?;
+        self.reaction_service
+            .apply_viewer(std::slice::from_mut(&mut post), viewer.as_ref())
+            .await?;
+
+        let post_grpc: ProtoPost = post.try_into()?;
+
+        Ok(Response::new(PostResponse {
+            post: Some(post_grpc),
+        }))
+    }
+
+    async fn get_post_by_slug(
+        &self,
+        request: Request<GetPostBySlugRequest>,
+    ) -> Result<Response<PostResponse>, Status> {
+        let viewer = get_auth_user(request.metadata(), self.jwt_service.clone())
+            .ok()
+            .map(|user| user.id);
+        let slug = Slug::try_from(request.into_inner().slug)?;
+        let mut post = self
+            .post_service
+            .get_visible_post_by_slug(&slug, viewer.as_ref())
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    slug = %slug,
+                    "Публикация не найдена"
+                )
+            })?;
+        self.reaction_service
+            .apply_viewer(std::slice::from_mut(&mut post), viewer.as_ref())
+            .await?;
 
         let post_grpc: ProtoPost = post.try_into()?;
 
@@ -154,11 +532,12 @@ impl TraitBlogService for BlogGrpcService {
         request: Request<UpdatePostRequest>,
     ) -> Result<Response<PostResponse>, Status> {
         let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let ctx = request_context(&request);
         let edit_command = EditPostCommand::try_from(request.into_inner())?;
 
         let post = self
             .post_service
-            .update_post(&edit_command, &auth_user.id)
+            .update_post(&edit_command, &auth_user, &ctx)
             .await
             .inspect_err(|err| {
                 error!(
@@ -181,17 +560,17 @@ impl TraitBlogService for BlogGrpcService {
         request: Request<DeletePostRequest>,
     ) -> Result<Response<DeletePostResponse>, Status> {
         let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
-        let user_id: DataId = auth_user.id;
+        let ctx = request_context(&request);
         let post_id: DataId = request.into_inner().id.into();
 
         self.post_service
-            .delete_post(&post_id, &user_id)
+            .delete_post(&post_id, &auth_user, &ctx)
             .await
             .inspect_err(|err| {
                 error!(
                     error = %err,
                     post_id=%post_id,
-                    user_id=%user_id,
+                    user_id=%auth_user.id,
                     "Ошибка удаления публикации"
                 )
             })?;
@@ -203,16 +582,833 @@ impl TraitBlogService for BlogGrpcService {
         &self,
         request: Request<ListPostsRequest>,
     ) -> Result<Response<ListPostsResponse>, Status> {
+        let viewer = get_auth_user(request.metadata(), self.jwt_service.clone())
+            .ok()
+            .map(|user| user.id);
         let list_posts = request.into_inner();
         validate_list_params(list_posts.limit, list_posts.offset)?;
+        let params = ListPostsParams {
+            limit: list_posts.limit,
+            offset: list_posts.offset,
+            tag: list_posts.tag.map(Tag::try_from).transpose()?,
+            after: list_posts.after.map(PostCursor::try_from).transpose()?,
+            followed_by: None,
+            author_id: None,
+        };
 
-        let posts = self
-            .post_service
-            .list_posts(list_posts.limit, list_posts.offset)
+        let mut posts = self.post_service.list_posts(params).await?;
+        self.reaction_service
+            .apply_viewer(&mut posts.posts, viewer.as_ref())
             .await?;
 
         let grpc_posts: ListPostsResponse = posts.try_into()?;
 
         Ok(Response::new(grpc_posts))
     }
+
+    async fn search_posts(
+        &self,
+        request: Request<SearchPostsRequest>,
+    ) -> Result<Response<SearchPostsResponse>, Status> {
+        let search = request.into_inner();
+        validate_list_params(search.limit, search.offset)?;
+        let query = SearchQuery::try_from(search.query)?;
+
+        let results = self
+            .post_service
+            .search_posts(&query, search.limit, search.offset)
+            .await?;
+
+        Ok(Response::new(results.try_into()?))
+    }
+
+    async fn publish_post(
+        &self,
+        request: Request<PublishPostRequest>,
+    ) -> Result<Response<PostResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let ctx = request_context(&request);
+        let publish = request.into_inner();
+        let post_id: DataId = publish.id.into();
+        let publish_at = publish.publish_at.map(datetime_from_timestamp).transpose()?;
+
+        let post = self
+            .post_service
+            .publish_post(&post_id, &auth_user, publish_at, &ctx)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id = %post_id,
+                    user_id = %auth_user.id,
+                    "Ошибка публикации записи"
+                )
+            })?;
+
+        let post_grpc: ProtoPost = post.try_into()?;
+
+        Ok(Response::new(PostResponse {
+            post: Some(post_grpc),
+        }))
+    }
+
+    async fn list_drafts(
+        &self,
+        request: Request<ListDraftsRequest>,
+    ) -> Result<Response<ListPostsResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let list_drafts = request.into_inner();
+        validate_list_params(list_drafts.limit, list_drafts.offset)?;
+
+        let drafts = self
+            .post_service
+            .list_drafts(&auth_user.id, list_drafts.limit, list_drafts.offset)
+            .await?;
+
+        Ok(Response::new(drafts.try_into()?))
+    }
+
+    async fn list_trash(
+        &self,
+        request: Request<ListTrashRequest>,
+    ) -> Result<Response<ListPostsResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let list_trash = request.into_inner();
+        validate_list_params(list_trash.limit, list_trash.offset)?;
+
+        let trash = self
+            .post_service
+            .list_trash(&auth_user.id, list_trash.limit, list_trash.offset)
+            .await?;
+
+        Ok(Response::new(trash.try_into()?))
+    }
+
+    async fn restore_post(
+        &self,
+        request: Request<RestorePostRequest>,
+    ) -> Result<Response<PostResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let ctx = request_context(&request);
+        let post_id: DataId = request.into_inner().id.into();
+
+        let post = self
+            .post_service
+            .restore_post(&post_id, &auth_user, &ctx)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id = %post_id,
+                    user_id = %auth_user.id,
+                    "Ошибка восстановления публикации из корзины"
+                )
+            })?;
+
+        let post_grpc: ProtoPost = post.try_into()?;
+
+        Ok(Response::new(PostResponse {
+            post: Some(post_grpc),
+        }))
+    }
+
+    async fn hide_post(
+        &self,
+        request: Request<HidePostRequest>,
+    ) -> Result<Response<PostResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let ctx = request_context(&request);
+        let post_id: DataId = request.into_inner().id.into();
+
+        let post = self
+            .post_service
+            .hide_post(&post_id, &auth_user, &ctx)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id = %post_id,
+                    user_id = %auth_user.id,
+                    "Ошибка скрытия публикации"
+                )
+            })?;
+
+        let post_grpc: ProtoPost = post.try_into()?;
+
+        Ok(Response::new(PostResponse {
+            post: Some(post_grpc),
+        }))
+    }
+
+    async fn unhide_post(
+        &self,
+        request: Request<HidePostRequest>,
+    ) -> Result<Response<PostResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let ctx = request_context(&request);
+        let post_id: DataId = request.into_inner().id.into();
+
+        let post = self
+            .post_service
+            .unhide_post(&post_id, &auth_user, &ctx)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id = %post_id,
+                    user_id = %auth_user.id,
+                    "Ошибка возврата скрытой публикации"
+                )
+            })?;
+
+        let post_grpc: ProtoPost = post.try_into()?;
+
+        Ok(Response::new(PostResponse {
+            post: Some(post_grpc),
+        }))
+    }
+
+    async fn list_tags(
+        &self,
+        _request: Request<ListTagsRequest>,
+    ) -> Result<Response<ListTagsResponse>, Status> {
+        let tags = self.post_service.list_tags().await?;
+
+        Ok(Response::new(tags.into()))
+    }
+
+    async fn add_comment(
+        &self,
+        request: Request<AddCommentRequest>,
+    ) -> Result<Response<CommentResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let add_comment = request.into_inner();
+        let post_id: DataId = add_comment.post_id.into();
+        let create_comment = CreateComment::try_from(add_comment)?;
+
+        let comment = self
+            .comment_service
+            .add_comment(&post_id, &create_comment, &auth_user.id)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id = %post_id,
+                    author_id = %auth_user.id,
+                    "Ошибка добавления комментария"
+                )
+            })?;
+
+        let comment_grpc: ProtoComment = comment.try_into()?;
+
+        Ok(Response::new(CommentResponse {
+            comment: Some(comment_grpc),
+        }))
+    }
+
+    async fn list_comments(
+        &self,
+        request: Request<ListCommentsRequest>,
+    ) -> Result<Response<ListCommentsResponse>, Status> {
+        let viewer = get_auth_user(request.metadata(), self.jwt_service.clone())
+            .ok()
+            .map(|user| user.id);
+        let list_comments = request.into_inner();
+        validate_list_params(list_comments.limit, list_comments.offset)?;
+        let post_id: DataId = list_comments.post_id.into();
+
+        let comments = self
+            .comment_service
+            .list_comments(
+                &post_id,
+                viewer.as_ref(),
+                list_comments.limit,
+                list_comments.offset,
+            )
+            .await?;
+
+        let grpc_comments: ListCommentsResponse = comments.try_into()?;
+
+        Ok(Response::new(grpc_comments))
+    }
+
+    async fn delete_comment(
+        &self,
+        request: Request<DeleteCommentRequest>,
+    ) -> Result<Response<DeleteCommentResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let delete_comment = request.into_inner();
+        let post_id: DataId = delete_comment.post_id.into();
+        let comment_id: DataId = delete_comment.id.into();
+
+        self.comment_service
+            .delete_comment(&post_id, &comment_id, &auth_user)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id=%post_id,
+                    comment_id=%comment_id,
+                    user_id=%auth_user.id,
+                    "Ошибка удаления комментария"
+                )
+            })?;
+
+        Ok(Response::new(DeleteCommentResponse { success: true }))
+    }
+
+    async fn list_revisions(
+        &self,
+        request: Request<ListRevisionsRequest>,
+    ) -> Result<Response<ListRevisionsResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let list_revisions = request.into_inner();
+        validate_list_params(list_revisions.limit, list_revisions.offset)?;
+        let post_id: DataId = list_revisions.post_id.into();
+
+        let revisions = self
+            .revision_service
+            .list_revisions(
+                &post_id,
+                &auth_user.id,
+                list_revisions.limit,
+                list_revisions.offset,
+            )
+            .await?;
+
+        Ok(Response::new(revisions.into()))
+    }
+
+    async fn get_revision(
+        &self,
+        request: Request<GetRevisionRequest>,
+    ) -> Result<Response<RevisionResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let get_revision = request.into_inner();
+        let post_id: DataId = get_revision.post_id.into();
+
+        let revision = self
+            .revision_service
+            .get_revision(&post_id, get_revision.revision, &auth_user.id)
+            .await?;
+
+        Ok(Response::new(RevisionResponse {
+            revision: Some(revision.into()),
+        }))
+    }
+
+    async fn diff_revisions(
+        &self,
+        request: Request<DiffRevisionsRequest>,
+    ) -> Result<Response<DiffRevisionsResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let diff_revisions = request.into_inner();
+        let post_id: DataId = diff_revisions.post_id.into();
+
+        let diff = self
+            .revision_service
+            .diff_revisions(
+                &post_id,
+                diff_revisions.from,
+                diff_revisions.to,
+                &auth_user.id,
+            )
+            .await?;
+
+        Ok(Response::new(diff.into()))
+    }
+
+    async fn restore_revision(
+        &self,
+        request: Request<RestoreRevisionRequest>,
+    ) -> Result<Response<PostResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let ctx = request_context(&request);
+        let restore = request.into_inner();
+        let post_id: DataId = restore.post_id.into();
+
+        let post = self
+            .revision_service
+            .restore_revision(&post_id, restore.revision, &auth_user, &ctx)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id = %post_id,
+                    revision = restore.revision,
+                    user_id = %auth_user.id,
+                    "Ошибка восстановления публикации из ревизии"
+                )
+            })?;
+
+        let post_grpc: ProtoPost = post.try_into()?;
+
+        Ok(Response::new(PostResponse {
+            post: Some(post_grpc),
+        }))
+    }
+
+    async fn react(
+        &self,
+        request: Request<ReactRequest>,
+    ) -> Result<Response<ReactionsResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let react = request.into_inner();
+        let post_id: DataId = react.post_id.into();
+        let kind: ReactionKind = react.kind.parse()?;
+
+        let reactions = self
+            .reaction_service
+            .react(&post_id, &auth_user.id, kind)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id = %post_id,
+                    user_id = %auth_user.id,
+                    kind = %kind,
+                    "Ошибка реакции на публикацию"
+                )
+            })?;
+
+        Ok(Response::new(reactions.into()))
+    }
+
+    async fn unreact(
+        &self,
+        request: Request<ReactRequest>,
+    ) -> Result<Response<ReactionsResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let react = request.into_inner();
+        let post_id: DataId = react.post_id.into();
+        let kind: ReactionKind = react.kind.parse()?;
+
+        let reactions = self
+            .reaction_service
+            .unreact(&post_id, &auth_user.id, kind)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id = %post_id,
+                    user_id = %auth_user.id,
+                    kind = %kind,
+                    "Ошибка снятия реакции с публикации"
+                )
+            })?;
+
+        Ok(Response::new(reactions.into()))
+    }
+
+    async fn follow(
+        &self,
+        request: Request<FollowRequest>,
+    ) -> Result<Response<FollowResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let username = Username::try_from(request.into_inner().username)?;
+
+        self.follow_service
+            .follow(&auth_user.id, &username)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    user_id = %auth_user.id,
+                    followee = %username,
+                    "Ошибка подписки на автора"
+                )
+            })?;
+
+        Ok(Response::new(FollowResponse { success: true }))
+    }
+
+    async fn unfollow(
+        &self,
+        request: Request<FollowRequest>,
+    ) -> Result<Response<FollowResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let username = Username::try_from(request.into_inner().username)?;
+
+        self.follow_service
+            .unfollow(&auth_user.id, &username)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    user_id = %auth_user.id,
+                    followee = %username,
+                    "Ошибка отписки от автора"
+                )
+            })?;
+
+        Ok(Response::new(FollowResponse { success: true }))
+    }
+
+    async fn list_followers(
+        &self,
+        request: Request<ListFollowsRequest>,
+    ) -> Result<Response<ListFollowsResponse>, Status> {
+        let list = request.into_inner();
+        validate_list_params(list.limit, list.offset)?;
+        let username = Username::try_from(list.username)?;
+
+        let followers = self
+            .follow_service
+            .list_followers(&username, list.limit, list.offset)
+            .await?;
+
+        Ok(Response::new(followers.into()))
+    }
+
+    async fn list_following(
+        &self,
+        request: Request<ListFollowsRequest>,
+    ) -> Result<Response<ListFollowsResponse>, Status> {
+        let list = request.into_inner();
+        validate_list_params(list.limit, list.offset)?;
+        let username = Username::try_from(list.username)?;
+
+        let following = self
+            .follow_service
+            .list_following(&username, list.limit, list.offset)
+            .await?;
+
+        Ok(Response::new(following.into()))
+    }
+
+    async fn feed(
+        &self,
+        request: Request<ListPostsRequest>,
+    ) -> Result<Response<ListPostsResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let list_posts = request.into_inner();
+        validate_list_params(list_posts.limit, list_posts.offset)?;
+        let params = ListPostsParams {
+            limit: list_posts.limit,
+            offset: list_posts.offset,
+            tag: list_posts.tag.map(Tag::try_from).transpose()?,
+            after: list_posts.after.map(PostCursor::try_from).transpose()?,
+            followed_by: None,
+            author_id: None,
+        };
+
+        let mut posts = self
+            .post_service
+            .feed(&auth_user.id, params)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    user_id = %auth_user.id,
+                    "Ошибка получения персональной ленты"
+                )
+            })?;
+        self.reaction_service
+            .apply_viewer(&mut posts.posts, Some(&auth_user.id))
+            .await?;
+
+        Ok(Response::new(posts.try_into()?))
+    }
+
+    async fn upload_attachment(
+        &self,
+        request: Request<Streaming<UploadAttachmentRequest>>,
+    ) -> Result<Response<AttachmentResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let max_bytes = self.attachment_service.max_bytes();
+
+        let mut stream = request.into_inner();
+        let mut file_name = None;
+        let mut data = Vec::new();
+        while let Some(part) = stream.message().await? {
+            file_name.get_or_insert(part.file_name);
+            if data.len() + part.chunk.len() > max_bytes {
+                return Err(DomainError::attachment_too_large(format!(
+                    "допустимый размер файла — не более {max_bytes} байт"
+                ))
+                .into());
+            }
+            data.extend_from_slice(&part.chunk);
+        }
+        let file_name = file_name.unwrap_or_default();
+
+        let attachment = self
+            .attachment_service
+            .upload(&auth_user.id, &file_name, &data)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    user_id = %auth_user.id,
+                    file_name = %file_name,
+                    "Ошибка загрузки вложения"
+                )
+            })?;
+
+        Ok(Response::new(attachment.into()))
+    }
+
+    async fn list_users(
+        &self,
+        request: Request<ListUsersRequest>,
+    ) -> Result<Response<ListUsersResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let list = request.into_inner();
+        validate_list_params(list.limit, list.offset)?;
+
+        let users = self
+            .admin_service
+            .list_users(&auth_user, list.limit, list.offset)
+            .await?;
+
+        Ok(Response::new(users.try_into()?))
+    }
+
+    async fn set_user_role(
+        &self,
+        request: Request<SetUserRoleRequest>,
+    ) -> Result<Response<UserAccountResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let set_role = request.into_inner();
+        let username = Username::try_from(set_role.username)?;
+        let role: Role = set_role.role.parse()?;
+
+        let account = self
+            .admin_service
+            .set_role(&auth_user, &username, role)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    admin = %auth_user.username,
+                    username = %username,
+                    "Ошибка смены роли пользователя"
+                )
+            })?;
+
+        Ok(Response::new(UserAccountResponse {
+            account: Some(account.try_into()?),
+        }))
+    }
+
+    async fn set_user_disabled(
+        &self,
+        request: Request<SetUserDisabledRequest>,
+    ) -> Result<Response<UserAccountResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let set_disabled = request.into_inner();
+        let username = Username::try_from(set_disabled.username)?;
+
+        let account = self
+            .admin_service
+            .set_disabled(&auth_user, &username, set_disabled.disabled)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    admin = %auth_user.username,
+                    username = %username,
+                    disabled = set_disabled.disabled,
+                    "Ошибка изменения статуса учётной записи"
+                )
+            })?;
+
+        Ok(Response::new(UserAccountResponse {
+            account: Some(account.try_into()?),
+        }))
+    }
+
+    async fn unlock_user(
+        &self,
+        request: Request<UnlockUserRequest>,
+    ) -> Result<Response<UserAccountResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let username = Username::try_from(request.into_inner().username)?;
+
+        let account = self
+            .admin_service
+            .unlock(&auth_user, &username)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    admin = %auth_user.username,
+                    username = %username,
+                    "Ошибка снятия блокировки входа"
+                )
+            })?;
+
+        Ok(Response::new(UserAccountResponse {
+            account: Some(account.try_into()?),
+        }))
+    }
+
+    async fn list_audit_events(
+        &self,
+        request: Request<ListAuditEventsRequest>,
+    ) -> Result<Response<ListAuditEventsResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let filter = AuditFilter::try_from(request.into_inner())?;
+        validate_list_params(filter.limit, filter.offset)?;
+
+        let events = self.audit_service.list(&auth_user, filter).await?;
+
+        Ok(Response::new(events.into()))
+    }
+
+    async fn list_moderation_rules(
+        &self,
+        request: Request<ListModerationRulesRequest>,
+    ) -> Result<Response<ListModerationRulesResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+
+        let rules = self.moderation_service.list_rules(&auth_user).await?;
+
+        Ok(Response::new(rules.into()))
+    }
+
+    async fn create_moderation_rule(
+        &self,
+        request: Request<CreateModerationRuleRequest>,
+    ) -> Result<Response<ModerationRuleResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let rule = SaveModerationRule::try_from(request.into_inner())?;
+
+        let rule = self
+            .moderation_service
+            .create_rule(&auth_user, rule)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    user_id = %auth_user.id,
+                    "Ошибка добавления правила модерации"
+                )
+            })?;
+
+        Ok(Response::new(ModerationRuleResponse {
+            rule: Some(rule.into()),
+        }))
+    }
+
+    async fn update_moderation_rule(
+        &self,
+        request: Request<UpdateModerationRuleRequest>,
+    ) -> Result<Response<ModerationRuleResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let update = request.into_inner();
+        let rule_id: DataId = update.id.into();
+        let rule = SaveModerationRule::try_from(update)?;
+
+        let rule = self
+            .moderation_service
+            .update_rule(&auth_user, &rule_id, rule)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    rule_id = %rule_id,
+                    user_id = %auth_user.id,
+                    "Ошибка изменения правила модерации"
+                )
+            })?;
+
+        Ok(Response::new(ModerationRuleResponse {
+            rule: Some(rule.into()),
+        }))
+    }
+
+    async fn delete_moderation_rule(
+        &self,
+        request: Request<DeleteModerationRuleRequest>,
+    ) -> Result<Response<DeleteModerationRuleResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let rule_id: DataId = request.into_inner().id.into();
+
+        self.moderation_service
+            .delete_rule(&auth_user, &rule_id)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    rule_id = %rule_id,
+                    user_id = %auth_user.id,
+                    "Ошибка удаления правила модерации"
+                )
+            })?;
+
+        Ok(Response::new(DeleteModerationRuleResponse { success: true }))
+    }
+
+    async fn list_moderation_queue(
+        &self,
+        request: Request<ListModerationQueueRequest>,
+    ) -> Result<Response<ListPostsResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let list = request.into_inner();
+        validate_list_params(list.limit, list.offset)?;
+
+        let posts = self
+            .post_service
+            .list_moderation_queue(&auth_user, list.limit, list.offset)
+            .await?;
+
+        Ok(Response::new(posts.try_into()?))
+    }
+
+    async fn approve_post(
+        &self,
+        request: Request<HidePostRequest>,
+    ) -> Result<Response<PostResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let ctx = request_context(&request);
+        let post_id: DataId = request.into_inner().id.into();
+
+        let post = self
+            .post_service
+            .approve_post(&post_id, &auth_user, &ctx)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id = %post_id,
+                    user_id = %auth_user.id,
+                    "Ошибка одобрения публикации"
+                )
+            })?;
+
+        let post_grpc: ProtoPost = post.try_into()?;
+
+        Ok(Response::new(PostResponse {
+            post: Some(post_grpc),
+        }))
+    }
+
+    async fn reject_post(
+        &self,
+        request: Request<HidePostRequest>,
+    ) -> Result<Response<PostResponse>, Status> {
+        let auth_user = get_auth_user(request.metadata(), self.jwt_service.clone())?;
+        let ctx = request_context(&request);
+        let post_id: DataId = request.into_inner().id.into();
+
+        let post = self
+            .post_service
+            .reject_post(&post_id, &auth_user, &ctx)
+            .await
+            .inspect_err(|err| {
+                error!(
+                    error = %err,
+                    post_id = %post_id,
+                    user_id = %auth_user.id,
+                    "Ошибка отклонения публикации"
+                )
+            })?;
+
+        let post_grpc: ProtoPost = post.try_into()?;
+
+        Ok(Response::new(PostResponse {
+            post: Some(post_grpc),
+        }))
+    }
 }
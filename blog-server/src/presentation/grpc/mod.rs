@@ -1,4 +1,5 @@
 //! Инфраструктура gRPC-сервисов.
 
 pub(crate) mod api_services;
+pub(crate) mod rate_limit;
 mod tools;
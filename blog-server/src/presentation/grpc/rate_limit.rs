@@ -0,0 +1,161 @@
+//! Слой `tower` для ограничения частоты запросов gRPC-сервера.
+
+use crate::{
+    domain::user::AuthenticatedUser,
+    infrastructure::{
+        jwt::JwtService,
+        rate_limit::{RateKey, RateLimiter, RouteGroup},
+    },
+};
+use std::{
+    future::{ready, Future},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tonic::{
+    body::Body,
+    codegen::http::{header::AUTHORIZATION, Request, Response},
+    transport::server::TcpConnectInfo,
+    Status,
+};
+use tower::{Layer, Service};
+use tracing::warn;
+
+/// Методы аутентификации: ограничиваются по IP-адресу.
+const AUTH_METHODS: [&str; 9] = [
+    "Register",
+    "Login",
+    "Refresh",
+    "Logout",
+    "ChangePassword",
+    "RequestPasswordReset",
+    "ResetPassword",
+    "VerifyEmail",
+    "ResendVerification",
+];
+
+/// Префиксы методов, которые только читают данные и не ограничиваются.
+const READ_METHOD_PREFIXES: [&str; 5] = ["Get", "List", "Search", "Diff", "Feed"];
+
+/// Слой, подключающий [`GrpcRateLimit`] к сервисам gRPC-сервера.
+#[derive(Clone)]
+pub(crate) struct GrpcRateLimitLayer {
+    /// Ограничитель частоты запросов.
+    limiter: Arc<RateLimiter>,
+    /// Сервис JWT-токенов: по токену запроса определяется пользователь.
+    jwt_service: Arc<JwtService>,
+}
+
+impl GrpcRateLimitLayer {
+    /// Создать слой с ограничителем `limiter`.
+    pub(crate) fn new(limiter: Arc<RateLimiter>, jwt_service: Arc<JwtService>) -> Self {
+        Self {
+            limiter,
+            jwt_service,
+        }
+    }
+}
+
+impl<S> Layer<S> for GrpcRateLimitLayer {
+    type Service = GrpcRateLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GrpcRateLimit {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// Сервис, отвечающий `RESOURCE_EXHAUSTED` на запросы сверх лимита.
+///
+/// Запросы аутентификации учитываются по IP-адресу клиента, изменяющие
+/// запросы — по IP-адресу и пользователю из токена, если он валиден.
+#[derive(Clone)]
+pub(crate) struct GrpcRateLimit<S> {
+    /// Обёрнутый сервис.
+    inner: S,
+    /// Настройки слоя.
+    layer: GrpcRateLimitLayer,
+}
+
+impl<S> GrpcRateLimit<S> {
+    /// Учесть запрос; при превышении лимита вернуть ответ с ошибкой.
+    fn check<B>(&self, req: &Request<B>) -> Result<(), Status> {
+        let method = req.uri().path().rsplit('/').next().unwrap_or_default();
+        let group = if AUTH_METHODS.contains(&method) {
+            RouteGroup::Auth
+        } else if READ_METHOD_PREFIXES
+            .iter()
+            .any(|prefix| method.starts_with(prefix))
+        {
+            return Ok(());
+        } else {
+            RouteGroup::Write
+        };
+
+        let mut keys = Vec::with_capacity(2);
+        let peer = req
+            .extensions()
+            .get::<TcpConnectInfo>()
+            .and_then(TcpConnectInfo::remote_addr);
+        if let Some(addr) = peer {
+            keys.push(RateKey::Ip(addr.ip()));
+        }
+        if group == RouteGroup::Write
+            && let Some(user) = self.user(req)
+        {
+            keys.push(RateKey::User(user.id.0));
+        }
+
+        self.layer.limiter.check(group, &keys).map_err(|err| {
+            warn!(
+                error = %err,
+                method = %method,
+                peer = ?peer,
+                "Превышен лимит частоты запросов"
+            );
+            err.into()
+        })
+    }
+
+    /// Пользователь из Bearer-токена запроса. Недействительный токен
+    /// не учитывается: такой запрос отклонит сам метод.
+    fn user<B>(&self, req: &Request<B>) -> Option<AuthenticatedUser> {
+        let token = req
+            .headers()
+            .get(AUTHORIZATION)?
+            .to_str()
+            .ok()?
+            .strip_prefix("Bearer ")?;
+
+        self.layer
+            .jwt_service
+            .verify_token(token)
+            .ok()
+            .map(Into::into)
+    }
+}
+
+impl<S, B> Service<Request<B>> for GrpcRateLimit<S>
+where
+    S: Service<Request<B>, Response = Response<Body>>,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        match self.check(&req) {
+            Ok(()) => Box::pin(self.inner.call(req)),
+            Err(status) => Box::pin(ready(Ok(status.into_http()))),
+        }
+    }
+}
@@ -1,10 +1,13 @@
 //! Вспомогательные методы для модуля gRPC.
 
-use crate::{domain::user::AuthenticatedUser, infrastructure::jwt::JwtService};
+use crate::{
+    domain::{audit::RequestContext, user::AuthenticatedUser},
+    infrastructure::jwt::JwtService,
+};
 use std::sync::Arc;
 use tonic::{
     metadata::{Ascii, MetadataMap, MetadataValue},
-    Status,
+    Request, Status,
 };
 use tracing::error;
 
@@ -28,6 +31,23 @@ pub(super) fn get_auth_user(
     Ok(auth_user)
 }
 
+/// Сведения о запросе для журнала аудита: IP-адрес клиента, метаданные
+/// `user-agent` и `x-request-id`.
+pub(super) fn request_context<T>(request: &Request<T>) -> RequestContext {
+    let metadata = |key| {
+        request
+            .metadata()
+            .get(key)
+            .and_then(|value| value.to_str().ok())
+    };
+
+    RequestContext::new(
+        request.remote_addr().map(|addr| addr.ip()),
+        metadata("user-agent"),
+        metadata("x-request-id"),
+    )
+}
+
 /// Предоставить нормализованный токен из метаданных запроса.
 fn get_token(token: Option<&MetadataValue<Ascii>>) -> Result<String, Status> {
     let raw = token
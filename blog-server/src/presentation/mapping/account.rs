@@ -0,0 +1,70 @@
+//! Конвертеры типов учётной записи текущего пользователя.
+
+use crate::{
+    domain::{
+        account::{AccountData, DeleteAccount},
+        post::Post,
+    },
+    errors::DomainError,
+};
+use proto_crate::proto_blog::{
+    export_my_data_response::Item, AccountData as ProtoAccountData, DeleteMyAccountRequest,
+    ExportMyDataResponse,
+};
+use tonic::Status;
+
+impl TryFrom<DeleteMyAccountRequest> for DeleteAccount {
+    type Error = DomainError;
+
+    fn try_from(r: DeleteMyAccountRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            password: r.password.try_into()?,
+            posts: r.posts.parse()?,
+        })
+    }
+}
+
+impl TryFrom<AccountData> for ProtoAccountData {
+    type Error = Status;
+
+    fn try_from(account: AccountData) -> Result<Self, Self::Error> {
+        let id: i64 = account
+            .id
+            .ok_or_else(|| Status::internal("Отсутствует ID пользователя"))?
+            .into();
+
+        Ok(Self {
+            id,
+            username: account.username.to_string(),
+            email: account.email.to_string(),
+            bio: account.bio,
+            display_name: account.display_name,
+            avatar_url: account.avatar_url,
+            role: account.role.to_string(),
+            created_at: account.created_at.timestamp(),
+            email_verified_at: account.email_verified_at.map(|at| at.timestamp()),
+        })
+    }
+}
+
+/// Первое сообщение потока выгрузки — данные учётной записи.
+impl TryFrom<AccountData> for ExportMyDataResponse {
+    type Error = Status;
+
+    fn try_from(account: AccountData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            item: Some(Item::Account(account.try_into()?)),
+        })
+    }
+}
+
+/// Сообщение потока выгрузки с одной публикацией.
+impl TryFrom<Post> for ExportMyDataResponse {
+    type Error = Status;
+
+    fn try_from(post: Post) -> Result<Self, Self::Error> {
+        Ok(Self {
+            item: Some(Item::Post(post.try_into()?)),
+        })
+    }
+}
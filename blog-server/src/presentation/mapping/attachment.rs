@@ -0,0 +1,28 @@
+//! Конвертеры для вложений HTTP - gRPC.
+
+use crate::domain::attachment::Attachment;
+use proto_crate::proto_blog::{Attachment as ProtoAttachment, AttachmentResponse};
+
+impl From<Attachment> for ProtoAttachment {
+    fn from(a: Attachment) -> Self {
+        Self {
+            url: a.url(),
+            id: a.id.into(),
+            owner_id: a.owner_id.into(),
+            file_name: a.file_name,
+            content_type: a.content_type,
+            size: a.size,
+            width: a.width,
+            height: a.height,
+            created_at: a.created_at.timestamp(),
+        }
+    }
+}
+
+impl From<Attachment> for AttachmentResponse {
+    fn from(a: Attachment) -> Self {
+        Self {
+            attachment: Some(a.into()),
+        }
+    }
+}
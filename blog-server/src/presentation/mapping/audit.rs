@@ -0,0 +1,55 @@
+//! Конвертеры для журнала аудита HTTP - gRPC.
+
+use crate::{
+    domain::audit::{AuditEvent, AuditFilter, ListAuditEvents},
+    errors::DomainError,
+    presentation::tools::audit_time_from_timestamp,
+};
+use proto_crate::proto_blog::{
+    AuditEvent as ProtoAuditEvent, ListAuditEventsRequest, ListAuditEventsResponse,
+};
+
+impl TryFrom<ListAuditEventsRequest> for AuditFilter {
+    type Error = DomainError;
+
+    fn try_from(r: ListAuditEventsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            actor: r.actor,
+            action: r.action.map(|action| action.parse()).transpose()?,
+            target: r.target,
+            since: r.since.map(audit_time_from_timestamp).transpose()?,
+            until: r.until.map(audit_time_from_timestamp).transpose()?,
+            limit: r.limit,
+            offset: r.offset,
+        })
+    }
+}
+
+impl From<AuditEvent> for ProtoAuditEvent {
+    fn from(event: AuditEvent) -> Self {
+        Self {
+            id: event.id.into(),
+            actor_id: event.actor_id.map(Into::into),
+            actor: event.actor,
+            action: event.action.to_string(),
+            target: event.target,
+            ip: event.ip,
+            user_agent: event.user_agent,
+            request_id: event.request_id,
+            before: event.before.map(|before| before.to_string()),
+            after: event.after.map(|after| after.to_string()),
+            created_at: event.created_at.timestamp(),
+        }
+    }
+}
+
+impl From<ListAuditEvents> for ListAuditEventsResponse {
+    fn from(list: ListAuditEvents) -> Self {
+        Self {
+            events: list.events.into_iter().map(Into::into).collect(),
+            total: list.total,
+            limit: list.limit,
+            offset: list.offset,
+        }
+    }
+}
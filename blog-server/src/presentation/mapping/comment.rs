@@ -0,0 +1,59 @@
+//! Конвертеры для комментариев HTTP - gRPC.
+
+use crate::domain::comment::{Comment, CreateComment, ListComments};
+use proto_crate::proto_blog::{AddCommentRequest, Comment as ProtoComment, ListCommentsResponse};
+use tonic::Status;
+use tracing::error;
+
+impl TryFrom<AddCommentRequest> for CreateComment {
+    type Error = Status;
+
+    fn try_from(acr: AddCommentRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            content: acr.content.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Comment> for ProtoComment {
+    type Error = Status;
+
+    fn try_from(c: Comment) -> Result<Self, Self::Error> {
+        let id: i64 =
+            c.id.ok_or_else(|| {
+                error!(
+                post_id=%c.post_id,
+                author_id=%c.author_id,
+                "Ошибка преобразования Comment/ProtoComment: отсутствует ID");
+                Status::internal("Отсутствует ID комментария")
+            })?
+            .into();
+
+        Ok(Self {
+            id,
+            post_id: c.post_id.into(),
+            author_id: c.author_id.into(),
+            content: c.content.to_string(),
+            created_at: c.created_at.timestamp(),
+        })
+    }
+}
+
+impl TryFrom<ListComments> for ListCommentsResponse {
+    type Error = Status;
+
+    fn try_from(comments: ListComments) -> Result<Self, Self::Error> {
+        let grpc_comments: Vec<ProtoComment> = comments
+            .comments
+            .into_iter()
+            .map(|c| c.try_into())
+            .collect::<Result<_, _>>()?;
+
+        Ok(ListCommentsResponse {
+            comments: grpc_comments,
+            total: comments.total,
+            limit: comments.limit,
+            offset: comments.offset,
+        })
+    }
+}
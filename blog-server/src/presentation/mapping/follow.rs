@@ -0,0 +1,24 @@
+//! Конвертеры для подписок на авторов HTTP - gRPC.
+
+use crate::domain::follow::{FollowUser, ListFollows};
+use proto_crate::proto_blog::{FollowUser as ProtoFollowUser, ListFollowsResponse};
+
+impl From<FollowUser> for ProtoFollowUser {
+    fn from(u: FollowUser) -> Self {
+        Self {
+            username: u.username.to_string(),
+            followed_at: u.followed_at.timestamp(),
+        }
+    }
+}
+
+impl From<ListFollows> for ListFollowsResponse {
+    fn from(list: ListFollows) -> Self {
+        Self {
+            users: list.users.into_iter().map(Into::into).collect(),
+            total: list.total,
+            limit: list.limit,
+            offset: list.offset,
+        }
+    }
+}
@@ -1,4 +1,12 @@
 //! Адаптеры слоёв представления.
 
+mod account;
+mod attachment;
+mod audit;
+mod comment;
+mod follow;
+mod moderation;
 mod post;
+mod reaction;
+mod revision;
 mod user;
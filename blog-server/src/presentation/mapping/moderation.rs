@@ -0,0 +1,55 @@
+//! Конвертеры для правил модерации HTTP - gRPC.
+
+use crate::{
+    domain::moderation::{ListModerationRules, ModerationRule, SaveModerationRule},
+    errors::DomainError,
+};
+use proto_crate::proto_blog::{
+    CreateModerationRuleRequest, ListModerationRulesResponse,
+    ModerationRule as ProtoModerationRule, UpdateModerationRuleRequest,
+};
+
+impl TryFrom<CreateModerationRuleRequest> for SaveModerationRule {
+    type Error = DomainError;
+
+    fn try_from(r: CreateModerationRuleRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pattern: r.pattern,
+            kind: r.kind.parse()?,
+            action: r.action.parse()?,
+        })
+    }
+}
+
+impl TryFrom<UpdateModerationRuleRequest> for SaveModerationRule {
+    type Error = DomainError;
+
+    fn try_from(r: UpdateModerationRuleRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pattern: r.pattern,
+            kind: r.kind.parse()?,
+            action: r.action.parse()?,
+        })
+    }
+}
+
+impl From<ModerationRule> for ProtoModerationRule {
+    fn from(rule: ModerationRule) -> Self {
+        Self {
+            id: rule.id.into(),
+            pattern: rule.pattern,
+            kind: rule.kind.to_string(),
+            action: rule.action.to_string(),
+            created_at: rule.created_at.timestamp(),
+            updated_at: rule.updated_at.map(|at| at.timestamp()),
+        }
+    }
+}
+
+impl From<ListModerationRules> for ListModerationRulesResponse {
+    fn from(list: ListModerationRules) -> Self {
+        Self {
+            rules: list.rules.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -1,11 +1,15 @@
 //! Конвертеры для постов (публикаций) HTTP - gRPC.
 
-use crate::domain::{
-    post::{CreatePost, EditPost, EditPostCommand, ListPosts, Post},
-    types::{DataId, PostContent, PostTitle},
+use crate::{
+    domain::{
+        post::{CreatePost, EditPost, EditPostCommand, ListPosts, ListTags, Post, SearchPosts},
+        types::{DataId, PostContent, PostTitle, Tag},
+    },
+    presentation::tools::datetime_from_timestamp,
 };
 use proto_crate::proto_blog::{
-    CreatePostRequest, ListPostsResponse, Post as ProtoPost, UpdatePostRequest,
+    CreatePostRequest, ListPostsResponse, ListTagsResponse, Post as ProtoPost, PostSearchHit,
+    SearchPostsResponse, TagStat as ProtoTagStat, UpdatePostRequest,
 };
 use tonic::Status;
 use tracing::error;
@@ -17,6 +21,9 @@ impl TryFrom<CreatePostRequest> for CreatePost {
         Ok(Self {
             title: cpr.title.try_into()?,
             content: cpr.content.try_into()?,
+            tags: tags_from_strings(cpr.tags)?,
+            status: cpr.status.as_deref().map(str::parse).transpose()?,
+            publish_at: cpr.publish_at.map(datetime_from_timestamp).transpose()?,
         })
     }
 }
@@ -41,9 +48,21 @@ impl TryFrom<Post> for ProtoPost {
             id,
             title: p.title.to_string(),
             content: p.content.to_string(),
+            content_html: p.content_html,
+            slug: p.slug.map(|slug| slug.to_string()).unwrap_or_default(),
+            author_username: p
+                .author_username
+                .map(|username| username.to_string())
+                .unwrap_or_default(),
             author_id: p.author_id.into(),
             created_at,
             updated_at,
+            tags: p.tags.iter().map(Tag::to_string).collect(),
+            status: p.status.to_string(),
+            publish_at: p.publish_at.map(|dt| dt.timestamp()),
+            deleted_at: p.deleted_at.map(|dt| dt.timestamp()),
+            reactions: p.reactions.into_iter().map(Into::into).collect(),
+            my_reactions: p.my_reactions.iter().map(ToString::to_string).collect(),
         })
     }
 }
@@ -56,6 +75,7 @@ impl TryFrom<UpdatePostRequest> for EditPostCommand {
             edit_post: EditPost {
                 title: u.title.map(PostTitle::try_from).transpose()?,
                 content: u.content.map(PostContent::try_from).transpose()?,
+                tags: u.tags.map(|t| tags_from_strings(t.tags)).transpose()?,
             },
         })
     }
@@ -75,6 +95,54 @@ impl TryFrom<ListPosts> for ListPostsResponse {
             total: posts.total,
             limit: posts.limit,
             offset: posts.offset,
+            next_cursor: posts.next_cursor.map(String::from),
         })
     }
 }
+
+impl TryFrom<SearchPosts> for SearchPostsResponse {
+    type Error = Status;
+    fn try_from(search: SearchPosts) -> Result<Self, Self::Error> {
+        let hits: Vec<PostSearchHit> = search
+            .hits
+            .into_iter()
+            .map(|hit| {
+                Ok(PostSearchHit {
+                    post: Some(hit.post.try_into()?),
+                    rank: hit.rank,
+                    snippet: hit.snippet,
+                })
+            })
+            .collect::<Result<_, Status>>()?;
+
+        Ok(Self {
+            hits,
+            total: search.total,
+            limit: search.limit,
+            offset: search.offset,
+        })
+    }
+}
+
+impl From<ListTags> for ListTagsResponse {
+    fn from(list: ListTags) -> Self {
+        Self {
+            tags: list
+                .tags
+                .into_iter()
+                .map(|t| ProtoTagStat {
+                    name: t.name.to_string(),
+                    posts_count: t.posts_count,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Преобразовать перечень строк в теги с валидацией.
+fn tags_from_strings(tags: Vec<String>) -> Result<Vec<Tag>, Status> {
+    Ok(tags
+        .into_iter()
+        .map(Tag::try_from)
+        .collect::<Result<_, _>>()?)
+}
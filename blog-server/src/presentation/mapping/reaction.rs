@@ -0,0 +1,23 @@
+//! Конвертеры для реакций на публикации HTTP - gRPC.
+
+use crate::domain::reaction::{PostReactions, ReactionCount};
+use proto_crate::proto_blog::{ReactionCount as ProtoReactionCount, ReactionsResponse};
+
+impl From<ReactionCount> for ProtoReactionCount {
+    fn from(r: ReactionCount) -> Self {
+        Self {
+            kind: r.kind.to_string(),
+            count: r.count,
+        }
+    }
+}
+
+impl From<PostReactions> for ReactionsResponse {
+    fn from(r: PostReactions) -> Self {
+        Self {
+            post_id: r.post_id.into(),
+            reactions: r.reactions.into_iter().map(Into::into).collect(),
+            my_reactions: r.my_reactions.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Конвертеры для ревизий публикаций HTTP - gRPC.
+
+use crate::domain::{
+    revision::{DiffLine, ListRevisions, PostRevision, RevisionDiff},
+    types::Tag,
+};
+use proto_crate::proto_blog::{
+    DiffLine as ProtoDiffLine, DiffRevisionsResponse, ListRevisionsResponse,
+    PostRevision as ProtoPostRevision,
+};
+
+impl From<PostRevision> for ProtoPostRevision {
+    fn from(r: PostRevision) -> Self {
+        Self {
+            post_id: r.post_id.into(),
+            revision: r.revision,
+            title: r.title.to_string(),
+            content: r.content.to_string(),
+            tags: r.tags.iter().map(Tag::to_string).collect(),
+            created_at: r.created_at.timestamp(),
+        }
+    }
+}
+
+impl From<ListRevisions> for ListRevisionsResponse {
+    fn from(list: ListRevisions) -> Self {
+        Self {
+            revisions: list.revisions.into_iter().map(Into::into).collect(),
+            total: list.total,
+            limit: list.limit,
+            offset: list.offset,
+        }
+    }
+}
+
+impl From<DiffLine> for ProtoDiffLine {
+    fn from(line: DiffLine) -> Self {
+        Self {
+            op: line.op.to_string(),
+            text: line.text,
+        }
+    }
+}
+
+impl From<RevisionDiff> for DiffRevisionsResponse {
+    fn from(diff: RevisionDiff) -> Self {
+        Self {
+            post_id: diff.post_id.into(),
+            from: diff.from,
+            to: diff.to,
+            title: diff.title.into_iter().map(Into::into).collect(),
+            content: diff.content.into_iter().map(Into::into).collect(),
+        }
+    }
+}
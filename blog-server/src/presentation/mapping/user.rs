@@ -1,11 +1,19 @@
 //! Конвертеры типов из HTTP сервера и gRPC.
 
 use crate::{
-    domain::user::{AuthResponse, CreateUser, LoginUser, UserDto},
+    domain::{
+        password::{ChangePassword, ResetPassword},
+        user::{
+            AuthResponse, CreateUser, ListUsers, LoginUser, UpdateProfile, UserAccount, UserDto,
+            UserProfile,
+        },
+    },
     errors::DomainError,
 };
 use proto_crate::proto_blog::{
-    AuthResponse as ProtoAuthResponse, LoginRequest, RegisterRequest, User as ProtoUser,
+    AuthResponse as ProtoAuthResponse, ChangePasswordRequest, ListUsersResponse, LoginRequest,
+    RegisterRequest, ResetPasswordRequest, UpdateProfileRequest, User as ProtoUser,
+    UserAccount as ProtoUserAccount, UserProfile as ProtoUserProfile,
 };
 use tonic::Status;
 use tracing::error;
@@ -33,6 +41,41 @@ impl TryFrom<LoginRequest> for LoginUser {
     }
 }
 
+impl TryFrom<UpdateProfileRequest> for UpdateProfile {
+    type Error = DomainError;
+
+    fn try_from(r: UpdateProfileRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            display_name: r.display_name.map(TryInto::try_into).transpose()?,
+            bio: r.bio.map(TryInto::try_into).transpose()?,
+            avatar_url: r.avatar_url.map(TryInto::try_into).transpose()?,
+            email: r.email.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<ChangePasswordRequest> for ChangePassword {
+    type Error = DomainError;
+
+    fn try_from(r: ChangePasswordRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            old_password: r.old_password.try_into()?,
+            new_password: r.new_password.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<ResetPasswordRequest> for ResetPassword {
+    type Error = DomainError;
+
+    fn try_from(r: ResetPasswordRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            token: r.token,
+            new_password: r.new_password.try_into()?,
+        })
+    }
+}
+
 impl TryFrom<UserDto> for ProtoUser {
     type Error = Status;
 
@@ -52,6 +95,11 @@ impl TryFrom<UserDto> for ProtoUser {
             id,
             username: user.username.to_string(),
             email: user.email.to_string(),
+            role: user.role.to_string(),
+            email_verified: user.email_verified,
+            display_name: user.display_name,
+            bio: user.bio,
+            avatar_url: user.avatar_url,
         })
     }
 }
@@ -63,7 +111,59 @@ impl TryFrom<AuthResponse> for ProtoAuthResponse {
 
         Ok(Self {
             token: ar.token,
+            refresh_token: ar.refresh_token,
             user: Some(proto_user),
         })
     }
 }
+
+impl From<UserProfile> for ProtoUserProfile {
+    fn from(profile: UserProfile) -> Self {
+        Self {
+            username: profile.username.to_string(),
+            bio: profile.bio,
+            display_name: profile.display_name,
+            avatar_url: profile.avatar_url,
+            joined_at: profile.joined_at.timestamp(),
+            posts_count: profile.posts_count,
+        }
+    }
+}
+
+impl TryFrom<UserAccount> for ProtoUserAccount {
+    type Error = Status;
+
+    fn try_from(account: UserAccount) -> Result<Self, Self::Error> {
+        let id: i64 = account
+            .id
+            .ok_or_else(|| Status::internal("Отсутствует ID пользователя"))?
+            .into();
+
+        Ok(Self {
+            id,
+            username: account.username.to_string(),
+            email: account.email.to_string(),
+            role: account.role.to_string(),
+            created_at: account.created_at.timestamp(),
+            disabled_at: account.disabled_at.map(|at| at.timestamp()),
+            locked_until: account.locked_until.map(|at| at.timestamp()),
+        })
+    }
+}
+
+impl TryFrom<ListUsers> for ListUsersResponse {
+    type Error = Status;
+
+    fn try_from(list: ListUsers) -> Result<Self, Self::Error> {
+        Ok(Self {
+            users: list
+                .users
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            total: list.total,
+            limit: list.limit,
+            offset: list.offset,
+        })
+    }
+}
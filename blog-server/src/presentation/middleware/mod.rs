@@ -1,10 +1,12 @@
 //! Промежуточное программное обеспечение.
 
 mod jwt;
+mod rate_limit;
 
 use crate::settings::APP_HEADERS;
 use actix_web::middleware::DefaultHeaders;
 pub(crate) use jwt::jwt_validator;
+pub(crate) use rate_limit::{rate_limit_auth, rate_limit_write};
 
 /// Стандартный генератор заголовка запроса (`headers`) для приложения.
 pub(crate) fn default_headers() -> DefaultHeaders {
@@ -0,0 +1,65 @@
+//! Middleware ограничения частоты запросов HTTP-сервера.
+
+use crate::{
+    domain::user::AuthenticatedUser,
+    errors::DomainError,
+    infrastructure::rate_limit::{RateKey, RateLimiter, RouteGroup},
+};
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::Method,
+    middleware::Next,
+    web, Error as ActixError, HttpMessage,
+};
+use std::sync::Arc;
+use tracing::{error, warn};
+
+/// Ограничить частоту запросов аутентификации с одного IP-адреса.
+pub(crate) async fn rate_limit_auth(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, ActixError> {
+    check_rate(&req, RouteGroup::Auth)?;
+    next.call(req).await
+}
+
+/// Ограничить частоту изменяющих запросов с одного IP-адреса и от одного
+/// пользователя. Запросы на чтение (`GET`, `HEAD`) не ограничиваются.
+///
+/// Пользователь определяется по данным JWT-токена, поэтому middleware
+/// должно выполняться после [`super::jwt_validator`].
+pub(crate) async fn rate_limit_write(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, ActixError> {
+    if !matches!(*req.method(), Method::GET | Method::HEAD) {
+        check_rate(&req, RouteGroup::Write)?;
+    }
+    next.call(req).await
+}
+
+/// Учесть запрос в вёдрах IP-адреса клиента и авторизованного пользователя.
+fn check_rate(req: &ServiceRequest, group: RouteGroup) -> Result<(), DomainError> {
+    let Some(limiter) = req.app_data::<web::Data<Arc<RateLimiter>>>() else {
+        error!("Ошибка доступа к ограничителю частоты запросов");
+        return Err(DomainError::server_err("ошибка конфигурации сервера"));
+    };
+
+    let mut keys = Vec::with_capacity(2);
+    if let Some(addr) = req.peer_addr() {
+        keys.push(RateKey::Ip(addr.ip()));
+    }
+    if let Some(user) = req.extensions().get::<AuthenticatedUser>() {
+        keys.push(RateKey::User(user.id.0));
+    }
+
+    limiter.check(group, &keys).inspect_err(|err| {
+        warn!(
+            error = %err,
+            path = %req.path(),
+            peer = ?req.peer_addr(),
+            "Превышен лимит частоты запросов"
+        )
+    })
+}
@@ -1,37 +1,26 @@
 //! Общие утилиты для presentation.
 
 use crate::{
-    domain::user::{LoginUser, UserDto},
+    application::token_service::TokenService,
+    domain::{token::TokenPair, user::UserDto},
     errors::DomainError,
-    infrastructure::jwt::JwtService,
-    settings::{POSTS_LIMIT_RANGE, POSTS_OFFSET_MAX}
+    settings::{POSTS_LIMIT_RANGE, POSTS_OFFSET_MAX},
 };
+use chrono::{DateTime, Utc};
 
-/// Поддерживающая функция. Получает JWT-токен для пользователя.
-pub(crate) fn get_jwt_token(user: &UserDto, jwt_service: &JwtService) -> Result<String, DomainError> {
+/// Поддерживающая функция. Начинает новую сессию пользователя: выдаёт
+/// токен доступа и токен обновления.
+pub(crate) async fn issue_tokens(
+    user: &UserDto,
+    token_service: &TokenService,
+) -> Result<TokenPair, DomainError> {
     let user_id = user
         .id
         .as_ref()
         .ok_or_else(|| DomainError::server_err("Пользователь не имеет ID"))?;
-    Ok(jwt_service.generate_token(user_id, &user.username)?)
-}
-
-/// Поддерживающая функция. Проверка корректности предоставленного пароля
-/// авторизуемого пользователя.
-pub(crate) fn verified_user_password(
-    login_user: &LoginUser,
-    password_hash: &str,
-) -> Result<(), DomainError> {
-    let verified_hash = login_user
-        .password
-        .verify_hash(password_hash)
-        .map_err(|err| DomainError::invalid_credentials(format!("ошибка хеширования: {err}")))?;
-
-    if !verified_hash {
-        return Err(DomainError::invalid_password(""));
-    }
-
-    Ok(())
+    token_service
+        .issue(user_id, &user.username, user.role)
+        .await
 }
 
 /// Проверить валидность значений, применяемых для выгрузки списка публикаций.
@@ -54,3 +43,16 @@ pub(crate) fn validate_list_params(limit: i32, offset: i32) -> Result<(), Domain
     
     Ok(())
 }
+
+/// Преобразовать unix-время (секунды) во временную метку UTC.
+pub(crate) fn datetime_from_timestamp(ts: i64) -> Result<DateTime<Utc>, DomainError> {
+    DateTime::from_timestamp(ts, 0)
+        .ok_or_else(|| DomainError::invalid_publication(format!("некорректное время: {ts}")))
+}
+
+/// Преобразовать границу периода выборки из журнала аудита (unix-время,
+/// секунды) во временную метку UTC.
+pub(crate) fn audit_time_from_timestamp(ts: i64) -> Result<DateTime<Utc>, DomainError> {
+    DateTime::from_timestamp(ts, 0)
+        .ok_or_else(|| DomainError::invalid_audit_filter(format!("некорректное время: {ts}")))
+}
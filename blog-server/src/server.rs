@@ -2,14 +2,21 @@
 
 use crate::{
     application::AppServices,
-    infrastructure::config::BlogConfig,
-    presentation::{api_handlers, grpc::api_services::BlogGrpcService, middleware},
+    infrastructure::config::{BlogConfig, SecurityCfg},
+    presentation::{
+        api_handlers,
+        grpc::{api_services::BlogGrpcService, rate_limit::GrpcRateLimitLayer},
+        middleware,
+    },
+    settings::{
+        LOGIN_ATTEMPTS_PURGE_CHECK_SECS, SCHEDULED_POSTS_CHECK_SECS, TRASH_PURGE_CHECK_SECS,
+    },
 };
 use actix_cors::Cors;
-use actix_web::{middleware::Logger, web, App, HttpResponse, HttpServer};
+use actix_web::{middleware::Logger, rt::time, web, App, HttpResponse, HttpServer};
 use anyhow::{Context, Result as AnyhowResult};
 use proto_crate::proto_blog::blog_service_server::BlogServiceServer;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use tokio::sync::broadcast::Receiver;
 use tonic::transport::Server;
 use tracing::info;
@@ -28,20 +35,7 @@ pub(crate) async fn run_blog_server(
     let cfg_clone = Arc::clone(&cfg);
 
     let server = HttpServer::new(move || {
-        let mut cors = Cors::default()
-            .allowed_methods(vec!["GET", "POST", "OPTIONS"])
-            .allow_any_header()
-            // .supports_credentials()
-            .max_age(cfg_clone.security.cors_max_age);
-
-        if cfg_clone.security.cors_urls.iter().any(|c| c == "*") {
-            cors = cors.allow_any_origin()
-        } else {
-            for origin in &cfg_clone.security.cors_urls {
-                cors = cors.allowed_origin(origin)
-            }
-        }
-
+        let cors = cors_policy(&cfg_clone.security);
         let default_headers = middleware::default_headers();
 
         App::new()
@@ -50,7 +44,24 @@ pub(crate) async fn run_blog_server(
             .wrap(cors)
             .configure(api_handlers::configure_api_routers)
             .app_data(web::Data::new(Arc::clone(&app_services.auth_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.token_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.password_service)))
+            .app_data(web::Data::new(Arc::clone(
+                &app_services.verification_service,
+            )))
+            .app_data(web::Data::new(Arc::clone(&app_services.profile_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.account_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.admin_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.audit_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.moderation_service)))
             .app_data(web::Data::new(Arc::clone(&app_services.blog_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.comment_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.revision_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.user_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.reaction_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.follow_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.attachment_service)))
+            .app_data(web::Data::new(Arc::clone(&app_services.rate_limiter)))
             .app_data(web::Data::new(Arc::clone(&cfg_clone)))
             .default_service(web::to(|| async { HttpResponse::NotFound().finish() }))
     })
@@ -72,6 +83,25 @@ pub(crate) async fn run_blog_server(
     Ok(())
 }
 
+/// Политика CORS для веб-клиента: разрешённые origin из конфигурации
+/// и все методы, которые используют маршруты API.
+fn cors_policy(security: &SecurityCfg) -> Cors {
+    let mut cors = Cors::default()
+        .allowed_methods(vec!["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"])
+        .allow_any_header()
+        // .supports_credentials()
+        .max_age(security.cors_max_age);
+
+    if security.cors_urls.iter().any(|c| c == "*") {
+        cors = cors.allow_any_origin()
+    } else {
+        for origin in &security.cors_urls {
+            cors = cors.allowed_origin(origin)
+        }
+    }
+    cors
+}
+
 /// Сервер `tonik`, обслуживающий механизмы `gRPC`.
 pub(crate) async fn run_blog_grpc(
     cfg: Arc<BlogConfig>,
@@ -81,10 +111,15 @@ pub(crate) async fn run_blog_grpc(
     info!("Запуск gPRC...");
 
     let jwt_service = Arc::new(cfg.security.jwt_service.clone());
+    let rate_limit = GrpcRateLimitLayer::new(
+        Arc::clone(&app_services.rate_limiter),
+        Arc::clone(&jwt_service),
+    );
 
     let service = BlogGrpcService::new(app_services, jwt_service);
 
     Server::builder()
+        .layer(rate_limit)
         .add_service(BlogServiceServer::new(service))
         .serve_with_shutdown(cfg.server.grpc_addr(), async move {
             let _ = shutdown.recv().await;
@@ -97,3 +132,135 @@ pub(crate) async fn run_blog_grpc(
 
     Ok(())
 }
+
+/// Фоновая задача: периодически публикует отложенные записи, время
+/// публикации которых наступило.
+///
+/// Ошибки отдельной проверки не останавливают задачу: они журналируются
+/// сервисом, а попытка повторяется на следующем шаге.
+pub(crate) async fn run_post_scheduler(
+    app_services: AppServices,
+    mut shutdown: Receiver<bool>,
+) -> AnyhowResult<()> {
+    info!("Запуск планировщика отложенных публикаций...");
+
+    let mut interval = time::interval(Duration::from_secs(SCHEDULED_POSTS_CHECK_SECS));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                let _ = app_services.blog_service.publish_scheduled_posts().await;
+            }
+            _ = shutdown.recv() => {
+                info!("Планировщик отложенных публикаций получил команду shutdown");
+                break;
+            }
+        }
+    }
+
+    info!("Планировщик отложенных публикаций остановлен");
+
+    Ok(())
+}
+
+/// Запуск фоновой очистки корзины.
+///
+/// Публикации, пролежавшие в корзине дольше `retention`, удаляются
+/// окончательно вместе с комментариями и ревизиями.
+pub(crate) async fn run_trash_purge(
+    app_services: AppServices,
+    retention: chrono::Duration,
+    mut shutdown: Receiver<bool>,
+) -> AnyhowResult<()> {
+    info!("Запуск очистки корзины публикаций...");
+
+    let mut interval = time::interval(Duration::from_secs(TRASH_PURGE_CHECK_SECS));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                let _ = app_services.blog_service.purge_trash(retention).await;
+            }
+            _ = shutdown.recv() => {
+                info!("Очистка корзины публикаций получила команду shutdown");
+                break;
+            }
+        }
+    }
+
+    info!("Очистка корзины публикаций остановлена");
+
+    Ok(())
+}
+
+/// Фоновая задача: периодически удаляет из журнала попыток входа записи
+/// старше срока хранения.
+///
+/// Ошибки отдельной очистки не останавливают задачу: они журналируются
+/// сервисом, а попытка повторяется на следующем шаге.
+pub(crate) async fn run_login_attempts_purge(
+    app_services: AppServices,
+    mut shutdown: Receiver<bool>,
+) -> AnyhowResult<()> {
+    info!("Запуск очистки журнала попыток входа...");
+
+    let mut interval = time::interval(Duration::from_secs(LOGIN_ATTEMPTS_PURGE_CHECK_SECS));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                let _ = app_services.auth_service.purge_login_attempts().await;
+            }
+            _ = shutdown.recv() => {
+                info!("Очистка журнала попыток входа получила команду shutdown");
+                break;
+            }
+        }
+    }
+
+    info!("Очистка журнала попыток входа остановлена");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::jwt::JwtService;
+    use actix_web::{
+        http::{header, Method, StatusCode},
+        test,
+    };
+
+    #[actix_web::test]
+    async fn cors_preflight_allows_patch() {
+        let security = SecurityCfg {
+            cors_urls: vec!["http://localhost:3000".to_string()],
+            cors_max_age: 600,
+            jwt_service: JwtService::from_secret("secret"),
+            admin_usernames: Vec::new(),
+        };
+        let app = test::init_service(
+            App::new()
+                .wrap(cors_policy(&security))
+                .route("/api/me", web::patch().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let req = test::TestRequest::default()
+            .method(Method::OPTIONS)
+            .uri("/api/me")
+            .insert_header((header::ORIGIN, "http://localhost:3000"))
+            .insert_header((header::ACCESS_CONTROL_REQUEST_METHOD, "PATCH"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let allowed = resp
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_METHODS)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        assert!(allowed.contains("PATCH"));
+    }
+}
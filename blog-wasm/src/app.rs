@@ -3,18 +3,41 @@
 
 use crate::{
     components::{footer::Footer, nav::Nav},
+    domain::errors::BlogWasmError,
     routes::{switch, Route},
+    services::clients::BlogClient,
     state::blog_state::BlogAppState,
 };
 
+use std::time::Duration;
+use tracing::warn;
+use yew::platform::{spawn_local, time::sleep};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+/// Интервал продления сессии: меньше срока действия токена доступа.
+const SESSION_RENEW_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
 /// Главная страница и первая точка входа во фронт.
 #[component(Main)]
 pub(crate) fn main_app() -> Html {
     let blog_state = use_state(BlogAppState::default);
 
+    // Токен доступа живёт недолго: сессия продлевается при открытии
+    // приложения и далее периодически, пока открыта вкладка.
+    {
+        let blog_state = blog_state.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                loop {
+                    renew_session(&blog_state).await;
+                    sleep(SESSION_RENEW_INTERVAL).await;
+                }
+            });
+            || ()
+        });
+    }
+
     html! {
         <ContextProvider<BlogAppState> context={(*blog_state).clone()}>
             <ContextProvider<UseStateHandle<BlogAppState>> context={blog_state}>
@@ -33,3 +56,26 @@ pub(crate) fn main_app() -> Html {
         </ContextProvider<BlogAppState>>
     }
 }
+
+/// Продлить сессию авторизованного пользователя. Токен обновления читается
+/// из `localStorage`, так как его могла заменить другая вкладка.
+async fn renew_session(blog_state: &UseStateHandle<BlogAppState>) {
+    let state = BlogAppState::default();
+    let Some(refresh_token) = state.get_refresh_token() else {
+        return;
+    };
+    let Ok(client) = BlogClient::new() else {
+        return;
+    };
+
+    match client.refresh(&refresh_token).await {
+        Ok(r) => blog_state.set(state.save_user_data(
+            r.user.id,
+            r.user.username,
+            &r.token.into(),
+            &r.refresh_token.into(),
+        )),
+        Err(BlogWasmError::Forbidden) => blog_state.set(state.clear_user_data()),
+        Err(err) => warn!(error=%err, "Не удалось продлить сессию"),
+    }
+}
@@ -0,0 +1,252 @@
+//! Комментарии к публикации.
+
+use commons::string_from_datetime;
+use web_sys::HtmlTextAreaElement;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{
+    services::{clients::BlogClient, models::CreateComment},
+    state::{blog_state::BlogAppState, models::PostsStatus},
+};
+
+/// Количество комментариев, загружаемых для публикации.
+const COMMENTS_LIMIT: i32 = 50;
+
+/// Свойства блока комментариев.
+#[derive(Properties, Clone, PartialEq)]
+pub(crate) struct CommentsProps {
+    /// Id публикации.
+    pub(crate) post_id: u64,
+    /// Id автора публикации (может удалять любые комментарии к ней).
+    pub(crate) post_author_id: u64,
+}
+
+/// Блок комментариев: список, форма добавления и удаление.
+#[function_component(Comments)]
+pub(crate) fn comments(props: &CommentsProps) -> Html {
+    let app_state =
+        use_context::<UseStateHandle<BlogAppState>>().expect("Отсутствует BlogAppState");
+
+    let post_id = props.post_id;
+    let post_author_id = props.post_author_id;
+
+    let comments = use_state(Vec::new);
+    let total = use_state(|| 0_i64);
+    let status = use_state(|| PostsStatus::Loading);
+    let submit_status = use_state(|| PostsStatus::Idle);
+    let content = use_state(String::new);
+    // Счётчик для повторной загрузки списка после изменений.
+    let reload = use_state(|| 0_u32);
+
+    {
+        let comments = comments.clone();
+        let total = total.clone();
+        let status = status.clone();
+
+        use_effect_with((post_id, *reload), move |(post_id, _)| {
+            status.set(PostsStatus::Loading);
+
+            let comments = comments.clone();
+            let total = total.clone();
+            let status = status.clone();
+            let post_id = *post_id;
+
+            spawn_local(async move {
+                let client = match BlogClient::new() {
+                    Ok(c) => c,
+                    Err(e) => {
+                        status.set(PostsStatus::Error(e.to_string()));
+                        return;
+                    }
+                };
+
+                match client
+                    .load_comments(post_id.into(), COMMENTS_LIMIT, 0)
+                    .await
+                {
+                    Ok(resp) => {
+                        comments.set(resp.comments);
+                        total.set(resp.total);
+                        status.set(PostsStatus::Idle);
+                    }
+                    Err(e) => status.set(PostsStatus::Error(e.to_string())),
+                }
+            });
+
+            || ()
+        });
+    }
+
+    let current_user_id = app_state.get_user_blog_id();
+    let token = app_state.get_token();
+    let is_auth = app_state.is_authenticated();
+
+    let on_content_input = {
+        let content = content.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            content.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let token = token.clone();
+        let content = content.clone();
+        let submit_status = submit_status.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let content_value = content.trim().to_string();
+            if content_value.is_empty() {
+                submit_status.set(PostsStatus::Error(
+                    "Комментарий не должен быть пустым".to_string(),
+                ));
+                return;
+            }
+
+            submit_status.set(PostsStatus::Loading);
+
+            let token = token.clone();
+            let content = content.clone();
+            let submit_status = submit_status.clone();
+            let reload = reload.clone();
+
+            spawn_local(async move {
+                let client = match BlogClient::new() {
+                    Ok(c) => c,
+                    Err(e) => {
+                        submit_status.set(PostsStatus::Error(e.to_string()));
+                        return;
+                    }
+                };
+
+                let create_comment = CreateComment::new(&content_value);
+                match client
+                    .add_comment(post_id.into(), &create_comment, token)
+                    .await
+                {
+                    Ok(_) => {
+                        content.set(String::new());
+                        submit_status.set(PostsStatus::Idle);
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => submit_status.set(PostsStatus::Error(e.to_string())),
+                }
+            });
+        })
+    };
+
+    let on_delete = {
+        let token = token.clone();
+        let status = status.clone();
+        let reload = reload.clone();
+
+        Callback::from(move |comment_id: u64| {
+            let token = token.clone();
+            let status = status.clone();
+            let reload = reload.clone();
+
+            spawn_local(async move {
+                let client = match BlogClient::new() {
+                    Ok(c) => c,
+                    Err(e) => {
+                        status.set(PostsStatus::Error(e.to_string()));
+                        return;
+                    }
+                };
+
+                match client
+                    .delete_comment(post_id.into(), comment_id, token)
+                    .await
+                {
+                    Ok(_) => reload.set(*reload + 1),
+                    Err(e) => status.set(PostsStatus::Error(e.to_string())),
+                }
+            });
+        })
+    };
+
+    let is_submitting = matches!(&*submit_status, PostsStatus::Loading);
+
+    html! {
+        <section class="comments" style="margin-top: 2rem;">
+            <h2>{ format!("Комментарии ({})", *total) }</h2>
+
+            {
+                match &*status {
+                    PostsStatus::Loading => html! {
+                        <div class="status-message loading">{ "Загружаем комментарии..." }</div>
+                    },
+                    PostsStatus::Error(msg) => html! {
+                        <div class="status-message error">{ msg.clone() }</div>
+                    },
+                    PostsStatus::Idle => html! {},
+                }
+            }
+
+            {
+                for comments.iter().map(|c| {
+                    let created_at = string_from_datetime(c.created_at, true);
+                    let can_delete = is_auth
+                        && matches!(
+                            current_user_id,
+                            Some(uid) if uid == c.author_id || uid == post_author_id
+                        );
+                    let on_delete = {
+                        let on_delete = on_delete.clone();
+                        let comment_id = c.id;
+                        Callback::from(move |_| on_delete.emit(comment_id))
+                    };
+
+                    html! {
+                        <div class="card" key={c.id}>
+                            <p class="post-meta">
+                                { format!("ID автора: {}", c.author_id) }
+                                if let Some(dt) = created_at {
+                                    { format!(", {}", dt) }
+                                }
+                            </p>
+                            <p>{ c.content.clone() }</p>
+
+                            if can_delete {
+                                <button class="btn btn-danger" onclick={on_delete}>
+                                    { "Удалить" }
+                                </button>
+                            }
+                        </div>
+                    }
+                })
+            }
+
+            if is_auth {
+                {
+                    match &*submit_status {
+                        PostsStatus::Error(msg) => html! {
+                            <div class="status-message error">{ msg.clone() }</div>
+                        },
+                        _ => html! {},
+                    }
+                }
+
+                <form class="form" onsubmit={on_submit}>
+                    <div class="input-group">
+                        <label for="comment">{ "Новый комментарий" }</label>
+                        <textarea
+                            id="comment"
+                            value={(*content).clone()}
+                            oninput={on_content_input}
+                            placeholder="Введите текст комментария"
+                            disabled={is_submitting}
+                        />
+                    </div>
+
+                    <button class="btn" type="submit" disabled={is_submitting}>
+                        { if is_submitting { "Отправляем..." } else { "Отправить" } }
+                    </button>
+                </form>
+            }
+        </section>
+    }
+}
@@ -1,4 +1,7 @@
 //! Структурные компоненты и элементы страниц.
 
+pub(crate) mod comments;
 pub(crate) mod footer;
 pub(crate) mod nav;
+pub(crate) mod reactions;
+pub(crate) mod uploads;
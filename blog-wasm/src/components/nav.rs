@@ -1,6 +1,7 @@
 //! Навигационные панели.
 
 use crate::{state::blog_state::BlogAppState, routes::Route};
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -12,6 +13,29 @@ pub(crate) fn nav_top() -> Html {
     let is_auth = state.is_authenticated();
     let username = state.get_username().cloned().unwrap_or("Noname".to_string());
 
+    let navigator = use_navigator().expect("Навигатор недоступен");
+    let search_query = use_state(String::new);
+
+    let on_search_input = {
+        let search_query = search_query.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            search_query.set(input.value());
+        })
+    };
+
+    let on_search = {
+        let search_query = search_query.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let query = search_query.trim().to_string();
+            if !query.is_empty() {
+                navigator.push(&Route::Search { query });
+            }
+        })
+    };
+
     html! {
         <header class="navbar">
             <div class="container">
@@ -21,6 +45,15 @@ pub(crate) fn nav_top() -> Html {
                     </Link<Route>>
                 </div>
 
+                <form class="nav-search" onsubmit={on_search}>
+                    <input
+                        type="search"
+                        value={(*search_query).clone()}
+                        oninput={on_search_input}
+                        placeholder="Поиск публикаций"
+                    />
+                </form>
+
                 <nav class="nav-links">
                     // Основные ссылки, доступные всем
                     <Link<Route> classes={classes!("nav-link")} to={Route::Home}>
@@ -44,6 +77,14 @@ pub(crate) fn nav_top() -> Html {
                             {"Создать запись"}
                         </Link<Route>>
 
+                        <Link<Route> classes={classes!("nav-link")} to={Route::Settings}>
+                            {"Профиль"}
+                        </Link<Route>>
+
+                        <Link<Route> classes={classes!("nav-link")} to={Route::ChangePassword}>
+                            {"Пароль"}
+                        </Link<Route>>
+
                         <Link<Route> classes={classes!("nav-link")} to={Route::Logout}>
                             {format!("Выйти, {}", username)}
                         </Link<Route>>
@@ -0,0 +1,124 @@
+//! Панель реакций на публикацию.
+
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{
+    services::{clients::BlogClient, models::ReactionCount},
+    state::blog_state::BlogAppState,
+};
+
+/// Доступные виды реакций и их значки, в порядке вывода.
+const REACTION_KINDS: [(&str, &str); 5] = [
+    ("like", "👍"),
+    ("love", "❤️"),
+    ("laugh", "😂"),
+    ("wow", "😮"),
+    ("sad", "😢"),
+];
+
+/// Свойства панели реакций.
+#[derive(Properties, Clone, PartialEq)]
+pub(crate) struct ReactionsProps {
+    /// Id публикации.
+    pub(crate) post_id: u64,
+    /// Количество реакций каждого вида.
+    pub(crate) reactions: Vec<ReactionCount>,
+    /// Реакции текущего пользователя.
+    pub(crate) my_reactions: Vec<String>,
+}
+
+/// Панель реакций: счётчики по видам и переключение своей реакции.
+///
+/// Анонимному пользователю показываются только счётчики.
+#[function_component(Reactions)]
+pub(crate) fn reactions(props: &ReactionsProps) -> Html {
+    let app_state =
+        use_context::<UseStateHandle<BlogAppState>>().expect("Отсутствует BlogAppState");
+
+    let post_id = props.post_id;
+    let reactions = use_state(|| props.reactions.clone());
+    let my_reactions = use_state(|| props.my_reactions.clone());
+    let error = use_state(|| None::<String>);
+    let is_sending = use_state(|| false);
+
+    let token = app_state.get_token();
+    let is_auth = app_state.is_authenticated();
+
+    let on_toggle = {
+        let reactions = reactions.clone();
+        let my_reactions = my_reactions.clone();
+        let error = error.clone();
+        let is_sending = is_sending.clone();
+
+        Callback::from(move |kind: &'static str| {
+            let active = !my_reactions.iter().any(|k| k == kind);
+            is_sending.set(true);
+            error.set(None);
+
+            let token = token.clone();
+            let reactions = reactions.clone();
+            let my_reactions = my_reactions.clone();
+            let error = error.clone();
+            let is_sending = is_sending.clone();
+
+            spawn_local(async move {
+                let client = match BlogClient::new() {
+                    Ok(c) => c,
+                    Err(e) => {
+                        is_sending.set(false);
+                        error.set(Some(e.to_string()));
+                        return;
+                    }
+                };
+
+                match client
+                    .set_reaction(post_id.into(), kind, active, token)
+                    .await
+                {
+                    Ok(resp) => {
+                        reactions.set(resp.reactions);
+                        my_reactions.set(resp.my_reactions);
+                    }
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                is_sending.set(false);
+            });
+        })
+    };
+
+    html! {
+        <>
+            <div class="reaction-bar">
+                {
+                    for REACTION_KINDS.iter().map(|&(kind, icon)| {
+                        let count = reactions
+                            .iter()
+                            .find(|r| r.kind == kind)
+                            .map(|r| r.count)
+                            .unwrap_or_default();
+                        let is_mine = my_reactions.iter().any(|k| k == kind);
+                        let onclick = {
+                            let on_toggle = on_toggle.clone();
+                            Callback::from(move |_| on_toggle.emit(kind))
+                        };
+
+                        html! {
+                            <button
+                                class={classes!("tag", is_mine.then_some("active"))}
+                                title={kind}
+                                disabled={!is_auth || *is_sending}
+                                {onclick}
+                            >
+                                { format!("{icon} {count}") }
+                            </button>
+                        }
+                    })
+                }
+            </div>
+
+            if let Some(err) = &*error {
+                <div class="status-message error">{ err.clone() }</div>
+            }
+        </>
+    }
+}
@@ -0,0 +1,88 @@
+//! Кнопка загрузки вложений в редакторе публикаций.
+
+use web_sys::HtmlInputElement;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{services::clients::BlogClient, state::blog_state::BlogAppState};
+
+/// Свойства кнопки загрузки вложений.
+#[derive(Properties, Clone, PartialEq)]
+pub(crate) struct AttachmentUploadProps {
+    /// Вызывается с разметкой Markdown загруженного файла.
+    pub(crate) on_uploaded: Callback<String>,
+    /// Кнопка недоступна, пока форма занята.
+    #[prop_or_default]
+    pub(crate) disabled: bool,
+}
+
+/// Выбор файла и загрузка его на сервер.
+///
+/// После успешной загрузки разметка вложения передаётся в `on_uploaded`,
+/// откуда её вставляет в текст редактор.
+#[function_component(AttachmentUpload)]
+pub(crate) fn attachment_upload(props: &AttachmentUploadProps) -> Html {
+    let app_state =
+        use_context::<UseStateHandle<BlogAppState>>().expect("Отсутствует BlogAppState");
+
+    let error = use_state(|| None::<String>);
+    let is_uploading = use_state(|| false);
+
+    let on_change = {
+        let on_uploaded = props.on_uploaded.clone();
+        let error = error.clone();
+        let is_uploading = is_uploading.clone();
+        let token = app_state.get_token();
+
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let Some(file) = input.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            // Сбрасываем выбор, чтобы тот же файл можно было выбрать снова.
+            input.set_value("");
+
+            is_uploading.set(true);
+            error.set(None);
+
+            let on_uploaded = on_uploaded.clone();
+            let error = error.clone();
+            let is_uploading = is_uploading.clone();
+            let token = token.clone();
+
+            spawn_local(async move {
+                let client = match BlogClient::new() {
+                    Ok(c) => c,
+                    Err(e) => {
+                        is_uploading.set(false);
+                        error.set(Some(e.to_string()));
+                        return;
+                    }
+                };
+
+                match client.upload(&file, token).await {
+                    Ok(attachment) => on_uploaded.emit(attachment.markdown()),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                is_uploading.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="input-group">
+            <label for="attachment">
+                { if *is_uploading { "Загружаем файл..." } else { "Вложение" } }
+            </label>
+            <input
+                id="attachment"
+                type="file"
+                onchange={on_change}
+                disabled={props.disabled || *is_uploading}
+            />
+
+            if let Some(err) = &*error {
+                <div class="status-message error">{ err.clone() }</div>
+            }
+        </div>
+    }
+}
@@ -49,6 +49,13 @@ impl TryFrom<PostId> for i64 {
     }
 }
 
+/// Человекочитаемый адрес публикации (slug).
+#[derive(Properties, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PostSlug {
+    /// Текущий или прежний адрес публикации.
+    pub(crate) slug: String,
+}
+
 /// Тип для хранения токена.
 #[derive(Clone, PartialEq, Debug)]
 pub(crate) struct JwtToken(String);
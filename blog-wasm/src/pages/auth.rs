@@ -89,6 +89,7 @@ pub(crate) fn sign_up() -> Html {
                             r.user.id,
                             r.user.username,
                             &r.token.into(),
+                            &r.refresh_token.into(),
                         ));
                         auth_status.set(AuthStatus::Signin);
                         navigator.push(&Route::Home);
@@ -248,6 +249,7 @@ pub(crate) fn login() -> Html {
                             r.user.id,
                             r.user.username,
                             &r.token.into(),
+                            &r.refresh_token.into(),
                         ));
                         auth_status.set(AuthStatus::Signin);
                         navigator.push(&Route::Home);
@@ -317,6 +319,8 @@ pub(crate) fn login() -> Html {
                 {"Войти"}
             </button>
             { status_view }
+
+            <Link<Route> to={Route::ForgotPassword}>{"Забыли пароль?"}</Link<Route>>
         </form>
     }
 }
@@ -338,10 +342,25 @@ pub(crate) fn logout() -> Html {
         let logout_completed = logout_completed.clone();
 
         use_effect_with((), move |_| {
+            let refresh_token = (*blog_state).get_refresh_token();
             blog_state.set((*blog_state).clear_user_data());
             logout_completed.set(true);
             navigator.push(&Route::Home);
 
+            // Сервер отзывает токены сессии; локальные данные уже удалены,
+            // поэтому ошибка запроса только записывается в журнал.
+            if let Some(refresh_token) = refresh_token {
+                spawn_local(async move {
+                    let result = match BlogClient::new() {
+                        Ok(client) => client.logout(&refresh_token).await,
+                        Err(err) => Err(err),
+                    };
+                    if let Err(err) = result {
+                        error!(error=%err, "Не удалось завершить сессию на сервере");
+                    }
+                });
+            }
+
             || ()
         });
     }
@@ -4,53 +4,50 @@ use yew::platform::spawn_local;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-use crate::{routes::Route, services::clients::BlogClient, state::models::PostsStatus};
+use crate::{
+    routes::Route,
+    services::clients::BlogClient,
+    state::{blog_state::BlogAppState, models::PostsStatus},
+};
 
 use commons::string_from_datetime;
 
 /// Главная страница проекта.
 #[function_component(Home)]
 pub(crate) fn home() -> Html {
+    let app_state =
+        use_context::<UseStateHandle<BlogAppState>>().expect("Отсутствует BlogAppState");
+
     let posts = use_state(Vec::new);
-    let total = use_state(|| 0_i32);
     let limit = use_state(|| 10_i32);
-    let offset = use_state(|| 0_i32);
+    // Курсор текущей страницы (`None` — первая страница), курсоры пройденных
+    // страниц для возврата назад и курсор следующей страницы из ответа.
+    let after = use_state(|| None::<String>);
+    let history = use_state(Vec::<Option<String>>::new);
+    let next_cursor = use_state(|| None::<String>);
     let status = use_state(|| PostsStatus::Idle);
+    let tag = use_state(|| None::<String>);
+    let tags = use_state(Vec::new);
+    // Вкладка «Моя лента»: публикации авторов из подписок пользователя.
+    // После выхода из аккаунта показывается общий список.
+    let is_feed = use_state(|| false);
+    let show_feed = *is_feed && app_state.is_authenticated();
 
-    // Подгрузка постов при изменении offset.
+    // Подгрузка перечня тегов (однократно).
     {
-        let posts = posts.clone();
-        let total = total.clone();
-        let limit_state = limit.clone();
-        let status = status.clone();
-
-        use_effect_with(*offset, move |offset_dep| {
-            status.set(PostsStatus::Loading);
-
-            let posts = posts.clone();
-            let total = total.clone();
-            let limit_state = limit_state.clone();
-            let status = status.clone();
-            let offset = *offset_dep;
-            let limit = *limit_state;
+        let tags = tags.clone();
 
+        use_effect_with((), move |_| {
             spawn_local(async move {
                 let client = match BlogClient::new() {
                     Ok(c) => c,
-                    Err(e) => {
-                        status.set(PostsStatus::Error(e.to_string()));
-                        return;
-                    }
+                    Err(_) => return,
                 };
 
-                match client.load_posts(limit, offset).await {
-                    Ok(resp) => {
-                        posts.set(resp.posts);
-                        total.set(resp.total);
-                        limit_state.set(resp.limit);
-                        status.set(PostsStatus::Idle);
-                    }
-                    Err(e) => status.set(PostsStatus::Error(e.to_string())),
+                // Перечень тегов вспомогательный: при ошибке фильтр просто
+                // не отображается.
+                if let Ok(resp) = client.load_tags().await {
+                    tags.set(resp.tags);
                 }
             });
 
@@ -58,35 +55,175 @@ pub(crate) fn home() -> Html {
         });
     }
 
+    // Подгрузка постов при изменении курсора, фильтра по тегу или вкладки.
+    {
+        let posts = posts.clone();
+        let next_cursor = next_cursor.clone();
+        let limit_state = limit.clone();
+        let status = status.clone();
+        let token = app_state.get_token();
+
+        use_effect_with(
+            ((*after).clone(), (*tag).clone(), show_feed),
+            move |(after_dep, tag_dep, feed_dep)| {
+                status.set(PostsStatus::Loading);
+
+                let posts = posts.clone();
+                let next_cursor = next_cursor.clone();
+                let limit_state = limit_state.clone();
+                let status = status.clone();
+                let after = after_dep.clone();
+                let limit = *limit_state;
+                let tag = tag_dep.clone();
+                let is_feed = *feed_dep;
+
+                spawn_local(async move {
+                    let client = match BlogClient::new() {
+                        Ok(c) => c,
+                        Err(e) => {
+                            status.set(PostsStatus::Error(e.to_string()));
+                            return;
+                        }
+                    };
+
+                    let resp = if is_feed {
+                        client
+                            .load_feed(limit, tag.as_deref(), after.as_deref(), token)
+                            .await
+                    } else {
+                        client
+                            .load_posts(limit, tag.as_deref(), after.as_deref())
+                            .await
+                    };
+
+                    match resp {
+                        Ok(resp) => {
+                            posts.set(resp.posts);
+                            next_cursor.set(resp.next_cursor);
+                            limit_state.set(resp.limit);
+                            status.set(PostsStatus::Idle);
+                        }
+                        Err(e) => status.set(PostsStatus::Error(e.to_string())),
+                    }
+                });
+
+                || ()
+            },
+        );
+    }
+
     let on_prev = {
-        let offset = offset.clone();
-        let limit = limit.clone();
+        let after = after.clone();
+        let history = history.clone();
         Callback::from(move |_| {
-            let next = (*offset - *limit).max(0);
-            offset.set(next);
+            let mut pages = (*history).clone();
+            if let Some(prev) = pages.pop() {
+                history.set(pages);
+                after.set(prev);
+            }
         })
     };
 
     let on_next = {
-        let offset = offset.clone();
-        let limit = limit.clone();
-        let total = total.clone();
+        let after = after.clone();
+        let history = history.clone();
+        let next_cursor = next_cursor.clone();
         Callback::from(move |_| {
-            let next = *offset + *limit;
-            if next < *total {
-                offset.set(next);
+            if let Some(next) = (*next_cursor).clone() {
+                let mut pages = (*history).clone();
+                pages.push((*after).clone());
+                history.set(pages);
+                after.set(Some(next));
             }
         })
     };
 
-    let can_prev = *offset > 0;
-    let can_next = (*offset + *limit) < *total;
+    // Выбор тега для фильтрации; `None` сбрасывает фильтр.
+    let on_tag = {
+        let tag = tag.clone();
+        let after = after.clone();
+        let history = history.clone();
+        Callback::from(move |selected: Option<String>| {
+            history.set(Vec::new());
+            after.set(None);
+            tag.set(selected);
+        })
+    };
+
+    // Переключение между общим списком и персональной лентой.
+    let on_feed = {
+        let is_feed = is_feed.clone();
+        let after = after.clone();
+        let history = history.clone();
+        Callback::from(move |feed: bool| {
+            history.set(Vec::new());
+            after.set(None);
+            is_feed.set(feed);
+        })
+    };
+
+    let can_prev = !history.is_empty();
+    let can_next = next_cursor.is_some();
 
     html! {
         <section class="container">
             <div class="card">
                 <h1>{ "Публикации" }</h1>
 
+                if app_state.is_authenticated() {
+                    <div class="feed-tabs">
+                        <button
+                            class={classes!("btn", show_feed.then_some("btn-secondary"))}
+                            onclick={
+                                let on_feed = on_feed.clone();
+                                Callback::from(move |_| on_feed.emit(false))
+                            }
+                        >
+                            { "Все публикации" }
+                        </button>
+                        <button
+                            class={classes!("btn", (!show_feed).then_some("btn-secondary"))}
+                            onclick={
+                                let on_feed = on_feed.clone();
+                                Callback::from(move |_| on_feed.emit(true))
+                            }
+                        >
+                            { "Моя лента" }
+                        </button>
+                    </div>
+                }
+
+                if !tags.is_empty() {
+                    <div class="tag-list">
+                        <button
+                            class={classes!("tag", tag.is_none().then_some("active"))}
+                            onclick={
+                                let on_tag = on_tag.clone();
+                                Callback::from(move |_| on_tag.emit(None))
+                            }
+                        >
+                            { "Все" }
+                        </button>
+                        { for tags.iter().map(|t| {
+                            let is_active = tag.as_deref() == Some(t.name.as_str());
+                            let on_click = {
+                                let on_tag = on_tag.clone();
+                                let name = t.name.clone();
+                                Callback::from(move |_| on_tag.emit(Some(name.clone())))
+                            };
+
+                            html! {
+                                <button
+                                    class={classes!("tag", is_active.then_some("active"))}
+                                    onclick={on_click}
+                                >
+                                    { format!("#{} ({})", t.name, t.posts_count) }
+                                </button>
+                            }
+                        }) }
+                    </div>
+                }
+
                 {
                     match &*status {
                         PostsStatus::Loading => html! {
@@ -102,7 +239,11 @@ pub(crate) fn home() -> Html {
                 <div class="post-list">
                     {
                         if posts.is_empty() && matches!(&*status, PostsStatus::Idle) {
-                            html! { <p>{ "Пока нет публикаций." }</p> }
+                            if show_feed {
+                                html! { <p>{ "В ленте пока пусто: подпишитесь на авторов." }</p> }
+                            } else {
+                                html! { <p>{ "Пока нет публикаций." }</p> }
+                            }
                         } else {
                             html! {
                                 <>
@@ -117,15 +258,31 @@ pub(crate) fn home() -> Html {
                                                         { format!("{dt}") }
                                                    </p>
                                                     }
-                                                <p class="post-meta">
-                                                    { format!("ID автора: {}", post.author_id) }
-                                                </p>
-                                                <p>{ post.content.clone() }</p>
+                                                { post.author_view() }
+                                                { post.content_view() }
+
+                                                if !post.tags.is_empty() {
+                                                    <div class="tag-list">
+                                                        { for post.tags.iter().map(|t| {
+                                                            let on_click = {
+                                                                let on_tag = on_tag.clone();
+                                                                let name = t.clone();
+                                                                Callback::from(move |_| on_tag.emit(Some(name.clone())))
+                                                            };
+
+                                                            html! {
+                                                                <button class="tag" onclick={on_click}>
+                                                                    { format!("#{t}") }
+                                                                </button>
+                                                            }
+                                                        }) }
+                                                    </div>
+                                                }
 
                                                 <div style="margin-top: 0.8rem;">
                                                     <Link<Route>
                                                         classes={classes!("btn-secondary", "btn")}
-                                                        to={Route::Post { id: post.id }}
+                                                        to={post.route()}
                                                     >
                                                         { "Открыть" }
                                                     </Link<Route>>
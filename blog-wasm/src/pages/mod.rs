@@ -3,4 +3,9 @@
 pub(crate) mod auth;
 pub(crate) mod errors;
 pub(crate) mod home;
+pub(crate) mod password;
 pub(crate) mod posts;
+pub(crate) mod search;
+pub(crate) mod settings;
+pub(crate) mod users;
+pub(crate) mod verification;
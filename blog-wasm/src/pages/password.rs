@@ -0,0 +1,269 @@
+//! Страницы смены и сброса пароля.
+
+use crate::{
+    routes::Route,
+    services::{
+        clients::BlogClient,
+        models::{
+            ChangePassword as ChangePasswordDto, ForgotPassword as ForgotPasswordDto,
+            ResetPassword as ResetPasswordDto,
+        },
+    },
+    state::{blog_state::BlogAppState, models::PasswordStatus},
+};
+use serde::Deserialize;
+use tracing::error;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// Параметры ссылки из письма для сброса пароля.
+#[derive(Deserialize, Default)]
+struct ResetQuery {
+    /// Токен сброса пароля.
+    #[serde(default)]
+    token: String,
+}
+
+/// Обработчик ввода в текстовое поле формы.
+fn on_input(value: &UseStateHandle<String>) -> Callback<InputEvent> {
+    let value = value.clone();
+    Callback::from(move |e: InputEvent| {
+        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+        value.set(input.value());
+    })
+}
+
+/// Сообщение о состоянии формы.
+fn status_view(status: &PasswordStatus, done_msg: &str) -> Html {
+    match status {
+        PasswordStatus::Done => html! {
+            <div class="status-message success">
+                <span>{ done_msg.to_string() }</span>
+            </div>
+        },
+        PasswordStatus::Error(msg) => html! {
+            <div class="status-message error">
+                <span>{ msg }</span>
+            </div>
+        },
+        PasswordStatus::Sending => html! {
+            <div class="status-message loading">
+                <span>{"Отправка..."}</span>
+            </div>
+        },
+        PasswordStatus::Idle => html! {},
+    }
+}
+
+/// Смена пароля авторизованным пользователем.
+#[function_component(ChangePassword)]
+pub(crate) fn change_password() -> Html {
+    let Some(blog_state) = use_context::<UseStateHandle<BlogAppState>>() else {
+        error!("Недоступно состояние приложения в ChangePassword.");
+        return html! { <Redirect<Route> to={Route::Home} /> };
+    };
+
+    let status = use_state(|| PasswordStatus::Idle);
+    let old_password = use_state(String::new);
+    let new_password = use_state(String::new);
+
+    let on_submit = {
+        let blog_state = blog_state.clone();
+        let old_password = old_password.clone();
+        let new_password = new_password.clone();
+        let status = status.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let blog_state = blog_state.clone();
+            let cmd = ChangePasswordDto::new(&old_password, &new_password);
+            let token = blog_state.get_token();
+            let status = status.clone();
+            status.set(PasswordStatus::Sending);
+
+            spawn_local(async move {
+                let client = match BlogClient::new() {
+                    Ok(c) => c,
+                    Err(err) => {
+                        status.set(PasswordStatus::Error(err.to_string()));
+                        return;
+                    }
+                };
+
+                // Прежние токены сервер отозвал: сохраняются токены новой
+                // сессии из ответа.
+                match client.change_password(&cmd, token).await {
+                    Ok(r) => {
+                        blog_state.set((*blog_state).save_user_data(
+                            r.user.id,
+                            r.user.username,
+                            &r.token.into(),
+                            &r.refresh_token.into(),
+                        ));
+                        status.set(PasswordStatus::Done);
+                    }
+                    Err(err) => status.set(PasswordStatus::Error(err.to_string())),
+                }
+            })
+        })
+    };
+
+    if !blog_state.is_authenticated() {
+        return html! { <Redirect<Route> to={Route::Login} /> };
+    }
+
+    html! {
+        <form class="form" onsubmit={on_submit}>
+            <div class="input-group">
+                <label>{"Текущий пароль"}</label>
+                <input
+                    type="password"
+                    value={(*old_password).clone()}
+                    oninput={on_input(&old_password)}
+                    required={true}
+                />
+            </div>
+
+            <div class="input-group">
+                <label>{"Новый пароль"}</label>
+                <input
+                    type="password"
+                    value={(*new_password).clone()}
+                    oninput={on_input(&new_password)}
+                    required={true}
+                />
+            </div>
+
+            <button class="btn" type="submit" disabled={matches!(&*status, PasswordStatus::Sending)}>
+                {"Сменить пароль"}
+            </button>
+            { status_view(&status, "Пароль изменён, прочие сессии завершены") }
+        </form>
+    }
+}
+
+/// Запрос письма для сброса забытого пароля.
+#[function_component(ForgotPassword)]
+pub(crate) fn forgot_password() -> Html {
+    let status = use_state(|| PasswordStatus::Idle);
+    let email = use_state(String::new);
+
+    let on_submit = {
+        let email = email.clone();
+        let status = status.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let cmd = ForgotPasswordDto::new(&email);
+            let status = status.clone();
+            status.set(PasswordStatus::Sending);
+
+            spawn_local(async move {
+                let result = match BlogClient::new() {
+                    Ok(client) => client.forgot_password(&cmd).await,
+                    Err(err) => Err(err),
+                };
+                match result {
+                    Ok(()) => status.set(PasswordStatus::Done),
+                    Err(err) => status.set(PasswordStatus::Error(err.to_string())),
+                }
+            })
+        })
+    };
+
+    html! {
+        <form class="form" onsubmit={on_submit}>
+            <div class="input-group">
+                <label>{"Электропочта"}</label>
+                <input
+                    type="email"
+                    value={(*email).clone()}
+                    oninput={on_input(&email)}
+                    placeholder="your_name@yandex.ru"
+                    required={true}
+                />
+            </div>
+
+            <button class="btn" type="submit" disabled={matches!(&*status, PasswordStatus::Sending)}>
+                {"Отправить письмо"}
+            </button>
+            {
+                status_view(
+                    &status,
+                    "Если адрес зарегистрирован, на него отправлено письмо со ссылкой для сброса пароля",
+                )
+            }
+        </form>
+    }
+}
+
+/// Новый пароль по ссылке из письма.
+#[function_component(ResetPassword)]
+pub(crate) fn reset_password() -> Html {
+    let token = use_location()
+        .and_then(|location| location.query::<ResetQuery>().ok())
+        .unwrap_or_default()
+        .token;
+
+    let status = use_state(|| PasswordStatus::Idle);
+    let new_password = use_state(String::new);
+
+    let on_submit = {
+        let new_password = new_password.clone();
+        let status = status.clone();
+        let token = token.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let cmd = ResetPasswordDto::new(&token, &new_password);
+            let status = status.clone();
+            status.set(PasswordStatus::Sending);
+
+            spawn_local(async move {
+                let result = match BlogClient::new() {
+                    Ok(client) => client.reset_password(&cmd).await,
+                    Err(err) => Err(err),
+                };
+                match result {
+                    Ok(()) => status.set(PasswordStatus::Done),
+                    Err(err) => status.set(PasswordStatus::Error(err.to_string())),
+                }
+            })
+        })
+    };
+
+    if token.is_empty() {
+        return html! {
+            <div class="status-message error">
+                <span>{"Ссылка для сброса пароля не содержит токен"}</span>
+            </div>
+        };
+    }
+
+    html! {
+        <form class="form" onsubmit={on_submit}>
+            <div class="input-group">
+                <label>{"Новый пароль"}</label>
+                <input
+                    type="password"
+                    value={(*new_password).clone()}
+                    oninput={on_input(&new_password)}
+                    required={true}
+                />
+            </div>
+
+            <button class="btn" type="submit" disabled={matches!(&*status, PasswordStatus::Sending | PasswordStatus::Done)}>
+                {"Сохранить пароль"}
+            </button>
+            { status_view(&status, "Пароль изменён, выполните вход с новым паролем") }
+
+            if matches!(&*status, PasswordStatus::Done) {
+                <Link<Route> to={Route::Login}>{"Авторизация"}</Link<Route>>
+            }
+        </form>
+    }
+}
@@ -1,15 +1,20 @@
 //! Страницы взаимодействия с публикациями.
 
-use commons::string_from_datetime;
+use commons::{string_from_datetime, timestamp_from_string};
 use web_sys::{HtmlInputElement, HtmlTextAreaElement};
 use yew::{platform::spawn_local, prelude::*};
 use yew_router::prelude::*;
 
-use crate::services::models::EditPost;
+use crate::services::models::{EditPost, PublishPost};
 use crate::{
-    domain::types::PostId,
+    components::{comments::Comments, reactions::Reactions, uploads::AttachmentUpload},
+    domain::types::{PostId, PostSlug},
     routes::Route,
-    services::{clients::BlogClient, models::CreatePost as CreatePostResp},
+    services::{
+        clients::BlogClient,
+        models::CreatePost as CreatePostResp,
+        tools::{append_paragraph, parse_tags},
+    },
     state::{blog_state::BlogAppState, models::PostsStatus},
 };
 
@@ -25,11 +30,13 @@ pub(crate) fn one_post(post: &PostId) -> Html {
     let status = use_state(|| PostsStatus::Loading);
 
     let is_deleting = use_state(|| false);
-    let delete_error = use_state(|| None::<String>);
+    let action_error = use_state(|| None::<String>);
+    let is_publishing = use_state(|| false);
 
     {
         let post_data = post_data.clone();
         let status = status.clone();
+        let token = app_state.get_token();
 
         use_effect_with(post_id, move |post_id| {
             status.set(PostsStatus::Loading);
@@ -47,7 +54,7 @@ pub(crate) fn one_post(post: &PostId) -> Html {
                     }
                 };
 
-                match client.get_post(post_id.into()).await {
+                match client.get_post(post_id.into(), token).await {
                     Ok(p) => {
                         post_data.set(Some(p));
                         status.set(PostsStatus::Idle);
@@ -74,23 +81,23 @@ pub(crate) fn one_post(post: &PostId) -> Html {
         let token = token.clone();
         let navigator = navigator.clone();
         let is_deleting = is_deleting.clone();
-        let delete_error = delete_error.clone();
+        let action_error = action_error.clone();
 
         Callback::from(move |_| {
             is_deleting.set(true);
-            delete_error.set(None);
+            action_error.set(None);
 
             let token = token.clone();
             let navigator = navigator.clone();
             let is_deleting = is_deleting.clone();
-            let delete_error = delete_error.clone();
+            let action_error = action_error.clone();
 
             spawn_local(async move {
                 let client = match BlogClient::new() {
                     Ok(c) => c,
                     Err(e) => {
                         is_deleting.set(false);
-                        delete_error.set(Some(e.to_string()));
+                        action_error.set(Some(e.to_string()));
                         return;
                     }
                 };
@@ -99,13 +106,50 @@ pub(crate) fn one_post(post: &PostId) -> Html {
                     Ok(_) => navigator.push(&Route::Home),
                     Err(e) => {
                         is_deleting.set(false);
-                        delete_error.set(Some(e.to_string()));
+                        action_error.set(Some(e.to_string()));
                     }
                 }
             });
         })
     };
 
+    let on_publish = {
+        let token = token.clone();
+        let post_data = post_data.clone();
+        let is_publishing = is_publishing.clone();
+        let action_error = action_error.clone();
+
+        Callback::from(move |_| {
+            is_publishing.set(true);
+            action_error.set(None);
+
+            let token = token.clone();
+            let post_data = post_data.clone();
+            let is_publishing = is_publishing.clone();
+            let action_error = action_error.clone();
+
+            spawn_local(async move {
+                let client = match BlogClient::new() {
+                    Ok(c) => c,
+                    Err(e) => {
+                        is_publishing.set(false);
+                        action_error.set(Some(e.to_string()));
+                        return;
+                    }
+                };
+
+                match client
+                    .publish_post(post_id.into(), &PublishPost::default(), token)
+                    .await
+                {
+                    Ok(p) => post_data.set(Some(p)),
+                    Err(e) => action_error.set(Some(e.to_string())),
+                }
+                is_publishing.set(false);
+            });
+        })
+    };
+
     html! {
         <div class="container">
             <article class="card">
@@ -120,6 +164,18 @@ pub(crate) fn one_post(post: &PostId) -> Html {
                         PostsStatus::Idle => {
                             if let Some(p) = &*post_data {
                                 let created_at = string_from_datetime(p.created_at, true);
+                                let publication = match p.status.as_str() {
+                                    "draft" => Some("Черновик".to_string()),
+                                    "hidden" => Some("Скрыта модератором".to_string()),
+                                    "pending" => Some("На проверке у модератора".to_string()),
+                                    "scheduled" => Some(format!(
+                                        "Запланирована на {}",
+                                        p.publish_at
+                                            .and_then(|dt| string_from_datetime(dt, true))
+                                            .unwrap_or_default()
+                                    )),
+                                    _ => None,
+                                };
 
                                 html! {
                                     <>
@@ -129,10 +185,28 @@ pub(crate) fn one_post(post: &PostId) -> Html {
                                             <p class="post-meta">{ dt }</p>
                                         }
 
-                                        <p class="post-meta">{ format!("ID автора: {}", p.author_id) }</p>
-                                        <p>{ p.content.clone() }</p>
+                                        if let Some(label) = publication {
+                                            <p class="post-meta"><span class="tag">{ label }</span></p>
+                                        }
+
+                                        { p.author_view() }
+                                        { p.content_view() }
+
+                                        if !p.tags.is_empty() {
+                                            <div class="tag-list">
+                                                { for p.tags.iter().map(|t| html! {
+                                                    <span class="tag">{ format!("#{t}") }</span>
+                                                }) }
+                                            </div>
+                                        }
+
+                                        <Reactions
+                                            post_id={p.id}
+                                            reactions={p.reactions.clone()}
+                                            my_reactions={p.my_reactions.clone()}
+                                        />
 
-                                        if let Some(err) = &*delete_error {
+                                        if let Some(err) = &*action_error {
                                             <div class="status-message error" style="margin-top: 1rem;">
                                                 { err.clone() }
                                             </div>
@@ -151,6 +225,16 @@ pub(crate) fn one_post(post: &PostId) -> Html {
                                                     { "Редактировать" }
                                                 </Link<Route>>
 
+                                                if !p.is_published() {
+                                                    <button
+                                                        class="btn"
+                                                        onclick={on_publish}
+                                                        disabled={*is_publishing}
+                                                    >
+                                                        { if *is_publishing { "Публикуем..." } else { "Опубликовать сейчас" } }
+                                                    </button>
+                                                }
+
                                                 <button
                                                     class="btn btn-danger"
                                                     onclick={on_delete}
@@ -160,6 +244,8 @@ pub(crate) fn one_post(post: &PostId) -> Html {
                                                 </button>
                                             }
                                         </div>
+
+                                        <Comments post_id={p.id} post_author_id={p.author_id} />
                                     </>
                                 }
                             } else {
@@ -175,6 +261,69 @@ pub(crate) fn one_post(post: &PostId) -> Html {
     }
 }
 
+/// Страница публикации по её человекочитаемому адресу.
+///
+/// Адрес разрешается в id публикации; если открыт прежний адрес, в строке
+/// браузера он заменяется текущим.
+#[function_component(PostBySlug)]
+pub(crate) fn post_by_slug(props: &PostSlug) -> Html {
+    let app_state =
+        use_context::<UseStateHandle<BlogAppState>>().expect("Отсутствует BlogAppState");
+    let navigator = use_navigator().expect("Навигатор недоступен");
+
+    let post_id = use_state(|| None::<u64>);
+    let status = use_state(|| PostsStatus::Loading);
+
+    {
+        let post_id = post_id.clone();
+        let status = status.clone();
+        let token = app_state.get_token();
+
+        use_effect_with(props.slug.clone(), move |slug| {
+            status.set(PostsStatus::Loading);
+
+            let slug = slug.clone();
+
+            spawn_local(async move {
+                let client = match BlogClient::new() {
+                    Ok(c) => c,
+                    Err(e) => {
+                        status.set(PostsStatus::Error(e.to_string()));
+                        return;
+                    }
+                };
+
+                match client.get_post_by_slug(&slug, token).await {
+                    Ok(p) => {
+                        if !p.slug.is_empty() && p.slug != slug {
+                            navigator.replace(&Route::PostBySlug { slug: p.slug });
+                        }
+                        post_id.set(Some(p.id));
+                        status.set(PostsStatus::Idle);
+                    }
+                    Err(e) => status.set(PostsStatus::Error(e.to_string())),
+                }
+            });
+
+            || ()
+        });
+    }
+
+    match (&*status, *post_id) {
+        (PostsStatus::Idle, Some(id)) => html! { <Post {id} /> },
+        (PostsStatus::Error(msg), _) => html! {
+            <div class="container">
+                <div class="status-message error">{ msg.clone() }</div>
+            </div>
+        },
+        _ => html! {
+            <div class="container">
+                <div class="status-message loading">{ "Загружаем публикацию..." }</div>
+            </div>
+        },
+    }
+}
+
 /// Создание публикации.
 #[function_component(CreatePost)]
 pub(crate) fn create_post() -> Html {
@@ -184,6 +333,9 @@ pub(crate) fn create_post() -> Html {
 
     let title = use_state(String::new);
     let content = use_state(String::new);
+    let tags = use_state(String::new);
+    let draft = use_state(|| false);
+    let publish_at = use_state(String::new);
     let status = use_state(|| PostsStatus::Idle);
 
     // Если не авторизован — не даём работать с формой.
@@ -214,11 +366,45 @@ pub(crate) fn create_post() -> Html {
         })
     };
 
+    let on_attachment_uploaded = {
+        let content = content.clone();
+        Callback::from(move |markup: String| {
+            content.set(append_paragraph(&content, &markup));
+        })
+    };
+
+    let on_tags_input = {
+        let tags = tags.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            tags.set(input.value());
+        })
+    };
+
+    let on_draft_change = {
+        let draft = draft.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            draft.set(input.checked());
+        })
+    };
+
+    let on_publish_at_input = {
+        let publish_at = publish_at.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            publish_at.set(input.value());
+        })
+    };
+
     let on_submit = {
         let app_state = app_state.clone();
         let navigator = navigator.clone();
         let title = title.clone();
         let content = content.clone();
+        let tags = tags.clone();
+        let draft = draft.clone();
+        let publish_at = publish_at.clone();
         let status = status.clone();
 
         Callback::from(move |e: SubmitEvent| {
@@ -226,6 +412,8 @@ pub(crate) fn create_post() -> Html {
 
             let title_value = title.trim().to_string();
             let content_value = content.trim().to_string();
+            let tags_value = parse_tags(&tags);
+            let draft_value = *draft;
 
             if title_value.is_empty() || content_value.is_empty() {
                 status.set(PostsStatus::Error(
@@ -234,6 +422,20 @@ pub(crate) fn create_post() -> Html {
                 return;
             }
 
+            let publish_at_value = if draft_value || publish_at.trim().is_empty() {
+                None
+            } else {
+                match timestamp_from_string(publish_at.trim()) {
+                    Some(ts) => Some(ts),
+                    None => {
+                        status.set(PostsStatus::Error(
+                            "Некорректное время публикации".to_string(),
+                        ));
+                        return;
+                    }
+                }
+            };
+
             let token = app_state.get_token().clone();
             status.set(PostsStatus::Loading);
 
@@ -249,7 +451,13 @@ pub(crate) fn create_post() -> Html {
                     }
                 };
 
-                let create_post = CreatePostResp::new(&title_value, &content_value);
+                let create_post = CreatePostResp::new(
+                    &title_value,
+                    &content_value,
+                    tags_value,
+                    draft_value,
+                    publish_at_value,
+                );
 
                 match client.create_post(&create_post, token).await {
                     Ok(created_post) => {
@@ -307,8 +515,56 @@ pub(crate) fn create_post() -> Html {
                         />
                     </div>
 
+                    <AttachmentUpload on_uploaded={on_attachment_uploaded} disabled={is_loading} />
+
+                    <div class="input-group">
+                        <label for="tags">{ "Теги" }</label>
+                        <input
+                            id="tags"
+                            type="text"
+                            value={(*tags).clone()}
+                            oninput={on_tags_input}
+                            placeholder="Через запятую: rust, web"
+                            disabled={is_loading}
+                        />
+                    </div>
+
+                    <div class="input-group">
+                        <label for="draft">
+                            <input
+                                id="draft"
+                                type="checkbox"
+                                checked={*draft}
+                                onchange={on_draft_change}
+                                disabled={is_loading}
+                            />
+                            { " Сохранить как черновик" }
+                        </label>
+                    </div>
+
+                    if !*draft {
+                        <div class="input-group">
+                            <label for="publish_at">{ "Отложенная публикация" }</label>
+                            <input
+                                id="publish_at"
+                                type="datetime-local"
+                                value={(*publish_at).clone()}
+                                oninput={on_publish_at_input}
+                                disabled={is_loading}
+                            />
+                        </div>
+                    }
+
                     <button class="btn" type="submit" disabled={is_loading}>
-                        { if is_loading { "Публикуем..." } else { "Опубликовать" } }
+                        {
+                            if is_loading {
+                                "Сохраняем..."
+                            } else if *draft {
+                                "Сохранить черновик"
+                            } else {
+                                "Опубликовать"
+                            }
+                        }
                     </button>
                 </form>
             </article>
@@ -331,6 +587,7 @@ pub(crate) fn update_post(post: &PostId) -> Html {
 
     let title = use_state(String::new);
     let content = use_state(String::new);
+    let tags = use_state(String::new);
 
     let is_auth = app_state.is_authenticated();
     {
@@ -348,7 +605,9 @@ pub(crate) fn update_post(post: &PostId) -> Html {
         let post_data = post_data.clone();
         let title = title.clone();
         let content = content.clone();
+        let tags = tags.clone();
         let load_status = load_status.clone();
+        let token = app_state.get_token();
 
         use_effect_with(post_id, move |post_id| {
             load_status.set(PostsStatus::Loading);
@@ -356,6 +615,7 @@ pub(crate) fn update_post(post: &PostId) -> Html {
             let post_data = post_data.clone();
             let title = title.clone();
             let content = content.clone();
+            let tags = tags.clone();
             let load_status = load_status.clone();
             let post_id = *post_id;
 
@@ -368,10 +628,11 @@ pub(crate) fn update_post(post: &PostId) -> Html {
                     }
                 };
 
-                match client.get_post(post_id.into()).await {
+                match client.get_post(post_id.into(), token).await {
                     Ok(p) => {
                         title.set(p.title.clone());
                         content.set(p.content.clone());
+                        tags.set(p.tags.join(", "));
                         post_data.set(Some(p));
                         load_status.set(PostsStatus::Idle);
                     }
@@ -405,11 +666,27 @@ pub(crate) fn update_post(post: &PostId) -> Html {
         })
     };
 
+    let on_attachment_uploaded = {
+        let content = content.clone();
+        Callback::from(move |markup: String| {
+            content.set(append_paragraph(&content, &markup));
+        })
+    };
+
+    let on_tags_input = {
+        let tags = tags.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            tags.set(input.value());
+        })
+    };
+
     let on_submit = {
         let app_state = app_state.clone();
         let navigator = navigator.clone();
         let title = title.clone();
         let content = content.clone();
+        let tags = tags.clone();
         let submit_status = submit_status.clone();
 
         Callback::from(move |e: SubmitEvent| {
@@ -417,6 +694,7 @@ pub(crate) fn update_post(post: &PostId) -> Html {
 
             let title_value = title.trim().to_string();
             let content_value = content.trim().to_string();
+            let tags_value = parse_tags(&tags);
 
             if title_value.is_empty() || content_value.is_empty() {
                 submit_status.set(PostsStatus::Error(
@@ -440,7 +718,7 @@ pub(crate) fn update_post(post: &PostId) -> Html {
                     }
                 };
 
-                let update_post = EditPost::new(&title_value, &content_value);
+                let update_post = EditPost::new(&title_value, &content_value, tags_value);
                 match client
                     .update_post(post_id.into(), &update_post, token)
                     .await
@@ -514,6 +792,23 @@ pub(crate) fn update_post(post: &PostId) -> Html {
                                 />
                             </div>
 
+                            <AttachmentUpload
+                                on_uploaded={on_attachment_uploaded}
+                                disabled={is_submitting}
+                            />
+
+                            <div class="input-group">
+                                <label for="tags">{ "Теги" }</label>
+                                <input
+                                    id="tags"
+                                    type="text"
+                                    value={(*tags).clone()}
+                                    oninput={on_tags_input}
+                                    placeholder="Через запятую: rust, web"
+                                    disabled={is_submitting}
+                                />
+                            </div>
+
                             <button class="btn" type="submit" disabled={is_submitting}>
                                 { if is_submitting { "Сохраняем..." } else { "Сохранить" } }
                             </button>
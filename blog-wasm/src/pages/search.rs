@@ -0,0 +1,185 @@
+//! Страница результатов поиска публикаций.
+
+use commons::string_from_datetime;
+use yew::{platform::spawn_local, prelude::*};
+use yew_router::prelude::*;
+
+use crate::{routes::Route, services::clients::BlogClient, state::models::PostsStatus};
+
+/// Открывающий маркер совпадения во фрагменте, сформированном сервером.
+const MARK_START: &str = "<mark>";
+
+/// Закрывающий маркер совпадения во фрагменте, сформированном сервером.
+const MARK_END: &str = "</mark>";
+
+/// Свойства страницы поиска.
+#[derive(Properties, Clone, PartialEq)]
+pub(crate) struct SearchProps {
+    /// Поисковый запрос.
+    pub(crate) query: String,
+}
+
+/// Страница результатов поиска.
+#[function_component(Search)]
+pub(crate) fn search(props: &SearchProps) -> Html {
+    let hits = use_state(Vec::new);
+    let total = use_state(|| 0_i64);
+    let limit = use_state(|| 10_i32);
+    let offset = use_state(|| 0_i32);
+    let status = use_state(|| PostsStatus::Idle);
+
+    // Новый запрос — поиск с первой страницы.
+    {
+        let offset = offset.clone();
+        use_effect_with(props.query.clone(), move |_| {
+            offset.set(0);
+            || ()
+        });
+    }
+
+    {
+        let hits = hits.clone();
+        let total = total.clone();
+        let limit_state = limit.clone();
+        let status = status.clone();
+
+        use_effect_with(
+            (props.query.clone(), *offset),
+            move |(query_dep, offset_dep)| {
+                status.set(PostsStatus::Loading);
+
+                let hits = hits.clone();
+                let total = total.clone();
+                let limit_state = limit_state.clone();
+                let status = status.clone();
+                let query = query_dep.clone();
+                let offset = *offset_dep;
+                let limit = *limit_state;
+
+                spawn_local(async move {
+                    let client = match BlogClient::new() {
+                        Ok(c) => c,
+                        Err(e) => {
+                            status.set(PostsStatus::Error(e.to_string()));
+                            return;
+                        }
+                    };
+
+                    match client.search_posts(&query, limit, offset).await {
+                        Ok(resp) => {
+                            hits.set(resp.hits);
+                            total.set(resp.total);
+                            limit_state.set(resp.limit);
+                            status.set(PostsStatus::Idle);
+                        }
+                        Err(e) => status.set(PostsStatus::Error(e.to_string())),
+                    }
+                });
+
+                || ()
+            },
+        );
+    }
+
+    let on_prev = {
+        let offset = offset.clone();
+        let limit = limit.clone();
+        Callback::from(move |_| {
+            let next = (*offset - *limit).max(0);
+            offset.set(next);
+        })
+    };
+
+    let on_next = {
+        let offset = offset.clone();
+        let limit = limit.clone();
+        let total = total.clone();
+        Callback::from(move |_| {
+            let next = *offset + *limit;
+            if i64::from(next) < *total {
+                offset.set(next);
+            }
+        })
+    };
+
+    let can_prev = *offset > 0;
+    let can_next = i64::from(*offset + *limit) < *total;
+
+    html! {
+        <section class="container">
+            <div class="card">
+                <h1>{ format!("Поиск: {}", props.query) }</h1>
+
+                {
+                    match &*status {
+                        PostsStatus::Loading => html! {
+                            <div class="status-message loading">{ "Ищем публикации..." }</div>
+                        },
+                        PostsStatus::Error(msg) => html! {
+                            <div class="status-message error">{ msg.clone() }</div>
+                        },
+                        PostsStatus::Idle => html! {
+                            <p class="post-meta">{ format!("Найдено публикаций: {}", *total) }</p>
+                        },
+                    }
+                }
+
+                <div class="post-list">
+                    { for hits.iter().map(|hit| {
+                        let created_at = string_from_datetime(hit.post.created_at, true);
+
+                        html! {
+                            <article class="card post-card">
+                                <h2>{ hit.post.title.clone() }</h2>
+                                if let Some(dt) = created_at {
+                                    <p class="post-meta">{ dt }</p>
+                                }
+                                { hit.post.author_view() }
+                                <p>{ highlight_snippet(&hit.snippet) }</p>
+
+                                <div style="margin-top: 0.8rem;">
+                                    <Link<Route>
+                                        classes={classes!("btn-secondary", "btn")}
+                                        to={hit.post.route()}
+                                    >
+                                        { "Открыть" }
+                                    </Link<Route>>
+                                </div>
+                            </article>
+                        }
+                    }) }
+                </div>
+
+                <div class="pagination">
+                    <button class="btn btn-secondary" onclick={on_prev} disabled={!can_prev}>
+                        { "Назад" }
+                    </button>
+                    <button class="btn btn-secondary" onclick={on_next} disabled={!can_next}>
+                        { "Вперёд" }
+                    </button>
+                </div>
+            </div>
+        </section>
+    }
+}
+
+/// Отрисовать фрагмент с подсветкой совпадений.
+///
+/// Текст не интерпретируется как HTML: маркеры `<mark>` разбираются вручную,
+/// остальное выводится как обычный текст.
+fn highlight_snippet(snippet: &str) -> Html {
+    let mut parts = Vec::new();
+    let mut rest = snippet;
+
+    while let Some(start) = rest.find(MARK_START) {
+        parts.push(html! { { rest[..start].to_string() } });
+        rest = &rest[start + MARK_START.len()..];
+
+        let end = rest.find(MARK_END).unwrap_or(rest.len());
+        parts.push(html! { <mark>{ rest[..end].to_string() }</mark> });
+        rest = rest.get(end + MARK_END.len()..).unwrap_or_default();
+    }
+    parts.push(html! { { rest.to_string() } });
+
+    html! { <>{ for parts }</> }
+}
@@ -0,0 +1,224 @@
+//! Страница настроек профиля авторизованного пользователя.
+
+use crate::{
+    routes::Route,
+    services::{
+        clients::BlogClient,
+        models::{UpdateProfile as UpdateProfileDto, User},
+    },
+    state::{blog_state::BlogAppState, models::VerificationStatus},
+};
+use tracing::error;
+use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+use yew::platform::spawn_local;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// Обработчик ввода в текстовое поле формы.
+fn on_input(value: &UseStateHandle<String>) -> Callback<InputEvent> {
+    let value = value.clone();
+    Callback::from(move |e: InputEvent| {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        value.set(input.value());
+    })
+}
+
+/// Сообщение о состоянии запроса.
+fn status_view(status: &VerificationStatus) -> Html {
+    match status {
+        VerificationStatus::Done(msg) => html! {
+            <div class="status-message success">
+                <span>{ msg }</span>
+            </div>
+        },
+        VerificationStatus::Error(msg) => html! {
+            <div class="status-message error">
+                <span>{ msg }</span>
+            </div>
+        },
+        VerificationStatus::Sending => html! {
+            <div class="status-message loading">
+                <span>{"Отправка..."}</span>
+            </div>
+        },
+        VerificationStatus::Idle => html! {},
+    }
+}
+
+/// Редактирование профиля: отображаемое имя, информация о себе, аватар и
+/// адрес электронной почты.
+///
+/// Новый адрес нужно подтвердить по ссылке из письма.
+#[function_component(Settings)]
+pub(crate) fn settings() -> Html {
+    let Some(blog_state) = use_context::<UseStateHandle<BlogAppState>>() else {
+        error!("Недоступно состояние приложения в Settings.");
+        return html! { <Redirect<Route> to={Route::Home} /> };
+    };
+
+    let status = use_state(|| VerificationStatus::Idle);
+    let current = use_state(|| None::<User>);
+    let display_name = use_state(String::new);
+    let bio = use_state(String::new);
+    let avatar_url = use_state(String::new);
+    let email = use_state(String::new);
+
+    // Заполнение формы текущими данными профиля.
+    let set_form = {
+        let current = current.clone();
+        let display_name = display_name.clone();
+        let bio = bio.clone();
+        let avatar_url = avatar_url.clone();
+        let email = email.clone();
+
+        move |user: User| {
+            display_name.set(user.display_name.clone());
+            bio.set(user.bio.clone());
+            avatar_url.set(user.avatar_url.clone());
+            email.set(user.email.clone());
+            current.set(Some(user));
+        }
+    };
+
+    {
+        let token = blog_state.get_token();
+        let status = status.clone();
+        let set_form = set_form.clone();
+
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let result = match BlogClient::new() {
+                    Ok(client) => client.get_me(token).await,
+                    Err(err) => Err(err),
+                };
+                match result {
+                    Ok(user) => set_form(user),
+                    Err(err) => status.set(VerificationStatus::Error(err.to_string())),
+                }
+            });
+
+            || ()
+        });
+    }
+
+    let on_bio_input = {
+        let bio = bio.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            bio.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let blog_state = blog_state.clone();
+        let current = current.clone();
+        let display_name = display_name.clone();
+        let bio = bio.clone();
+        let avatar_url = avatar_url.clone();
+        let email = email.clone();
+        let status = status.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let Some(user) = &*current else {
+                return;
+            };
+            let cmd = UpdateProfileDto::new(user, &display_name, &bio, &avatar_url, &email);
+            if cmd.is_empty() {
+                status.set(VerificationStatus::Done("Изменений нет".to_string()));
+                return;
+            }
+
+            let token = blog_state.get_token();
+            let status = status.clone();
+            let set_form = set_form.clone();
+            status.set(VerificationStatus::Sending);
+
+            spawn_local(async move {
+                let result = match BlogClient::new() {
+                    Ok(client) => client.update_profile(&cmd, token).await,
+                    Err(err) => Err(err),
+                };
+                match result {
+                    Ok(user) => {
+                        let msg = if cmd.email.is_some() && !user.email_verified {
+                            "Профиль сохранён. На новый адрес отправлено письмо для его подтверждения"
+                        } else {
+                            "Профиль сохранён"
+                        };
+                        set_form(user);
+                        status.set(VerificationStatus::Done(msg.to_string()));
+                    }
+                    Err(err) => status.set(VerificationStatus::Error(err.to_string())),
+                }
+            })
+        })
+    };
+
+    if !blog_state.is_authenticated() {
+        return html! { <Redirect<Route> to={Route::Login} /> };
+    }
+
+    let is_sending = matches!(&*status, VerificationStatus::Sending);
+
+    html! {
+        <form class="form" onsubmit={on_submit}>
+            if !avatar_url.is_empty() {
+                <img class="avatar" src={(*avatar_url).clone()} alt="Аватар" />
+            }
+
+            <div class="input-group">
+                <label for="display_name">{"Отображаемое имя"}</label>
+                <input
+                    id="display_name"
+                    type="text"
+                    value={(*display_name).clone()}
+                    oninput={on_input(&display_name)}
+                />
+            </div>
+
+            <div class="input-group">
+                <label for="bio">{"О себе"}</label>
+                <textarea
+                    id="bio"
+                    value={(*bio).clone()}
+                    oninput={on_bio_input}
+                />
+            </div>
+
+            <div class="input-group">
+                <label for="avatar_url">{"Адрес аватара"}</label>
+                <input
+                    id="avatar_url"
+                    type="text"
+                    value={(*avatar_url).clone()}
+                    oninput={on_input(&avatar_url)}
+                    placeholder="https://..."
+                />
+            </div>
+
+            <div class="input-group">
+                <label for="email">{"Электронная почта"}</label>
+                <input
+                    id="email"
+                    type="email"
+                    value={(*email).clone()}
+                    oninput={on_input(&email)}
+                    required={true}
+                />
+                if current.as_ref().is_some_and(|u| !u.email_verified) {
+                    <Link<Route> to={Route::VerifyEmail}>
+                        {"Адрес не подтверждён"}
+                    </Link<Route>>
+                }
+            </div>
+
+            <button class="btn" type="submit" disabled={is_sending || current.is_none()}>
+                {"Сохранить"}
+            </button>
+
+            { status_view(&status) }
+        </form>
+    }
+}
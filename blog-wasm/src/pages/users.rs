@@ -0,0 +1,205 @@
+//! Страница публичного профиля автора.
+
+use commons::string_from_datetime;
+use yew::{platform::spawn_local, prelude::*};
+use yew_router::prelude::*;
+
+use crate::{
+    routes::Route,
+    services::{clients::BlogClient, models::UserProfile},
+    state::models::PostsStatus,
+};
+
+/// Свойства страницы профиля.
+#[derive(Properties, Clone, PartialEq)]
+pub(crate) struct UserProps {
+    /// Имя автора.
+    pub(crate) username: String,
+}
+
+/// Профиль автора и его опубликованные записи.
+#[function_component(UserPage)]
+pub(crate) fn user_page(props: &UserProps) -> Html {
+    let profile = use_state(|| None::<UserProfile>);
+    let posts = use_state(Vec::new);
+    let total = use_state(|| 0_i64);
+    let limit = use_state(|| 10_i32);
+    let offset = use_state(|| 0_i32);
+    let status = use_state(|| PostsStatus::Idle);
+
+    // Другой автор — профиль и публикации с первой страницы.
+    {
+        let profile = profile.clone();
+        let offset = offset.clone();
+        let status = status.clone();
+
+        use_effect_with(props.username.clone(), move |username| {
+            offset.set(0);
+            profile.set(None);
+
+            let username = username.clone();
+
+            spawn_local(async move {
+                let client = match BlogClient::new() {
+                    Ok(c) => c,
+                    Err(e) => {
+                        status.set(PostsStatus::Error(e.to_string()));
+                        return;
+                    }
+                };
+
+                match client.get_user_profile(&username).await {
+                    Ok(p) => profile.set(Some(p)),
+                    Err(e) => status.set(PostsStatus::Error(e.to_string())),
+                }
+            });
+
+            || ()
+        });
+    }
+
+    {
+        let posts = posts.clone();
+        let total = total.clone();
+        let limit_state = limit.clone();
+        let status = status.clone();
+
+        use_effect_with(
+            (props.username.clone(), *offset),
+            move |(username_dep, offset_dep)| {
+                status.set(PostsStatus::Loading);
+
+                let posts = posts.clone();
+                let total = total.clone();
+                let limit_state = limit_state.clone();
+                let status = status.clone();
+                let username = username_dep.clone();
+                let offset = *offset_dep;
+                let limit = *limit_state;
+
+                spawn_local(async move {
+                    let client = match BlogClient::new() {
+                        Ok(c) => c,
+                        Err(e) => {
+                            status.set(PostsStatus::Error(e.to_string()));
+                            return;
+                        }
+                    };
+
+                    match client.list_user_posts(&username, limit, offset).await {
+                        Ok(resp) => {
+                            posts.set(resp.posts);
+                            total.set(resp.total.unwrap_or_default());
+                            limit_state.set(resp.limit);
+                            status.set(PostsStatus::Idle);
+                        }
+                        Err(e) => status.set(PostsStatus::Error(e.to_string())),
+                    }
+                });
+
+                || ()
+            },
+        );
+    }
+
+    let on_prev = {
+        let offset = offset.clone();
+        let limit = limit.clone();
+        Callback::from(move |_| {
+            let next = (*offset - *limit).max(0);
+            offset.set(next);
+        })
+    };
+
+    let on_next = {
+        let offset = offset.clone();
+        let limit = limit.clone();
+        let total = total.clone();
+        Callback::from(move |_| {
+            let next = *offset + *limit;
+            if i64::from(next) < *total {
+                offset.set(next);
+            }
+        })
+    };
+
+    let can_prev = *offset > 0;
+    let can_next = i64::from(*offset + *limit) < *total;
+
+    html! {
+        <section class="container">
+            <div class="card">
+                if let Some(p) = profile.as_ref().filter(|p| !p.avatar_url.is_empty()) {
+                    <img class="avatar" src={p.avatar_url.clone()} alt={props.username.clone()} />
+                }
+                <h1>{ format!("@{}", props.username) }</h1>
+
+                if let Some(p) = &*profile {
+                    if !p.display_name.is_empty() {
+                        <p><strong>{ p.display_name.clone() }</strong></p>
+                    }
+                    <p class="post-meta">
+                        {
+                            format!(
+                                "С нами с {}, публикаций: {}",
+                                string_from_datetime(p.joined_at, true).unwrap_or_default(),
+                                p.posts_count
+                            )
+                        }
+                    </p>
+                    if !p.bio.is_empty() {
+                        <p>{ p.bio.clone() }</p>
+                    }
+                }
+
+                {
+                    match &*status {
+                        PostsStatus::Loading => html! {
+                            <div class="status-message loading">{ "Загружаем публикации..." }</div>
+                        },
+                        PostsStatus::Error(msg) => html! {
+                            <div class="status-message error">{ msg.clone() }</div>
+                        },
+                        PostsStatus::Idle if posts.is_empty() => html! {
+                            <p>{ "Пока нет публикаций." }</p>
+                        },
+                        PostsStatus::Idle => html! {},
+                    }
+                }
+
+                <div class="post-list">
+                    { for posts.iter().map(|post| {
+                        let created_at = string_from_datetime(post.created_at, true);
+
+                        html! {
+                            <article class="card post-card">
+                                <h2>{ post.title.clone() }</h2>
+                                if let Some(dt) = created_at {
+                                    <p class="post-meta">{ dt }</p>
+                                }
+
+                                <div style="margin-top: 0.8rem;">
+                                    <Link<Route>
+                                        classes={classes!("btn-secondary", "btn")}
+                                        to={post.route()}
+                                    >
+                                        { "Открыть" }
+                                    </Link<Route>>
+                                </div>
+                            </article>
+                        }
+                    }) }
+                </div>
+
+                <div class="pagination">
+                    <button class="btn btn-secondary" onclick={on_prev} disabled={!can_prev}>
+                        { "Назад" }
+                    </button>
+                    <button class="btn btn-secondary" onclick={on_next} disabled={!can_next}>
+                        { "Вперёд" }
+                    </button>
+                </div>
+            </div>
+        </section>
+    }
+}
@@ -0,0 +1,138 @@
+//! Страница подтверждения адреса электронной почты.
+
+use crate::{
+    routes::Route,
+    services::{clients::BlogClient, models::VerifyEmail as VerifyEmailDto},
+    state::{blog_state::BlogAppState, models::VerificationStatus},
+};
+use serde::Deserialize;
+use tracing::error;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// Параметры ссылки из письма для подтверждения адреса.
+#[derive(Deserialize, Default)]
+struct VerifyQuery {
+    /// Токен подтверждения адреса.
+    #[serde(default)]
+    token: String,
+}
+
+/// Сообщение о состоянии запроса.
+fn status_view(status: &VerificationStatus) -> Html {
+    match status {
+        VerificationStatus::Done(msg) => html! {
+            <div class="status-message success">
+                <span>{ msg }</span>
+            </div>
+        },
+        VerificationStatus::Error(msg) => html! {
+            <div class="status-message error">
+                <span>{ msg }</span>
+            </div>
+        },
+        VerificationStatus::Sending => html! {
+            <div class="status-message loading">
+                <span>{"Отправка..."}</span>
+            </div>
+        },
+        VerificationStatus::Idle => html! {},
+    }
+}
+
+/// Подтверждение адреса по ссылке из письма.
+///
+/// Адрес подтверждается сразу при открытии страницы. Авторизованный
+/// пользователь может запросить письмо повторно, если ссылка устарела.
+#[function_component(VerifyEmail)]
+pub(crate) fn verify_email() -> Html {
+    let Some(blog_state) = use_context::<UseStateHandle<BlogAppState>>() else {
+        error!("Недоступно состояние приложения в VerifyEmail.");
+        return html! { <Redirect<Route> to={Route::Home} /> };
+    };
+
+    let token = use_location()
+        .and_then(|location| location.query::<VerifyQuery>().ok())
+        .unwrap_or_default()
+        .token;
+
+    let status = use_state(|| VerificationStatus::Idle);
+    let resend_status = use_state(|| VerificationStatus::Idle);
+
+    {
+        let status = status.clone();
+        let token = token.clone();
+
+        use_effect_with(token, move |token| {
+            if !token.is_empty() {
+                let cmd = VerifyEmailDto::new(token);
+                status.set(VerificationStatus::Sending);
+
+                spawn_local(async move {
+                    let result = match BlogClient::new() {
+                        Ok(client) => client.verify_email(&cmd).await,
+                        Err(err) => Err(err),
+                    };
+                    match result {
+                        Ok(user) => status.set(VerificationStatus::Done(format!(
+                            "Адрес {} подтверждён",
+                            user.email
+                        ))),
+                        Err(err) => status.set(VerificationStatus::Error(err.to_string())),
+                    }
+                });
+            }
+
+            || ()
+        });
+    }
+
+    let on_resend = {
+        let blog_state = blog_state.clone();
+        let resend_status = resend_status.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let token = blog_state.get_token();
+            let resend_status = resend_status.clone();
+            resend_status.set(VerificationStatus::Sending);
+
+            spawn_local(async move {
+                let result = match BlogClient::new() {
+                    Ok(client) => client.resend_verification(token).await,
+                    Err(err) => Err(err),
+                };
+                match result {
+                    Ok(()) => resend_status.set(VerificationStatus::Done(
+                        "Письмо для подтверждения адреса отправлено повторно".to_string(),
+                    )),
+                    Err(err) => resend_status.set(VerificationStatus::Error(err.to_string())),
+                }
+            })
+        })
+    };
+
+    let verified = matches!(&*status, VerificationStatus::Done(_));
+
+    html! {
+        <div class="form">
+            if token.is_empty() {
+                <div class="status-message error">
+                    <span>{"Ссылка для подтверждения адреса не содержит токен"}</span>
+                </div>
+            }
+            { status_view(&status) }
+
+            if !verified && blog_state.is_authenticated() {
+                <button
+                    class="btn"
+                    onclick={on_resend}
+                    disabled={matches!(&*resend_status, VerificationStatus::Sending | VerificationStatus::Done(_))}
+                >
+                    {"Отправить письмо ещё раз"}
+                </button>
+                { status_view(&resend_status) }
+            }
+        </div>
+    }
+}
@@ -4,7 +4,12 @@ use crate::pages::{
     auth::{Login, Logout, SignUp},
     errors::PageNotFound,
     home::Home,
-    posts::{CreatePost, Post, UpdatePost},
+    password::{ChangePassword, ForgotPassword, ResetPassword},
+    posts::{CreatePost, Post, PostBySlug, UpdatePost},
+    search::Search,
+    settings::Settings,
+    users::UserPage,
+    verification::VerifyEmail,
 };
 use yew::{html, Html};
 use yew_router::Routable;
@@ -28,10 +33,34 @@ pub(crate) enum Route {
     #[at("/logout")]
     Logout,
 
+    /// Смена пароля авторизованным пользователем.
+    #[at("/password")]
+    ChangePassword,
+
+    /// Запрос письма для сброса забытого пароля.
+    #[at("/forgot-password")]
+    ForgotPassword,
+
+    /// Новый пароль по ссылке из письма (`/reset-password?token=...`).
+    #[at("/reset-password")]
+    ResetPassword,
+
+    /// Настройки профиля авторизованного пользователя.
+    #[at("/settings")]
+    Settings,
+
+    /// Подтверждение адреса по ссылке из письма (`/verify-email?token=...`).
+    #[at("/verify-email")]
+    VerifyEmail,
+
     /// Страница с одной публикацией.
     #[at("/post/:id")]
     Post { id: u64 },
 
+    /// Страница публикации по её человекочитаемому адресу.
+    #[at("/p/:slug")]
+    PostBySlug { slug: String },
+
     /// Создание публикации.
     #[at("/post_create")]
     Create,
@@ -40,6 +69,14 @@ pub(crate) enum Route {
     #[at("/post_edit/:id")]
     Update { id: u64 },
 
+    /// Публичный профиль автора.
+    #[at("/user/:username")]
+    User { username: String },
+
+    /// Результаты поиска публикаций.
+    #[at("/search/:query")]
+    Search { query: String },
+
     /// 404. Страница не найдена.
     #[not_found]
     #[at("/404")]
@@ -65,10 +102,34 @@ pub(crate) fn switch(route: Route) -> Html {
             html! { <Logout /> }
         }
 
+        Route::ChangePassword => {
+            html! { <ChangePassword /> }
+        }
+
+        Route::ForgotPassword => {
+            html! { <ForgotPassword /> }
+        }
+
+        Route::ResetPassword => {
+            html! { <ResetPassword /> }
+        }
+
+        Route::Settings => {
+            html! { <Settings /> }
+        }
+
+        Route::VerifyEmail => {
+            html! { <VerifyEmail /> }
+        }
+
         Route::Post { id } => {
             html! { <Post {id} /> }
         }
 
+        Route::PostBySlug { slug } => {
+            html! { <PostBySlug {slug} /> }
+        }
+
         Route::Create => {
             html! { <CreatePost /> }
         }
@@ -77,6 +138,14 @@ pub(crate) fn switch(route: Route) -> Html {
             html! { <UpdatePost {id} /> }
         }
 
+        Route::User { username } => {
+            html! { <UserPage {username} /> }
+        }
+
+        Route::Search { query } => {
+            html! { <Search {query} /> }
+        }
+
         Route::NotFound => {
             html! { <PageNotFound /> }
         }
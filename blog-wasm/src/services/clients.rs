@@ -7,7 +7,11 @@ use crate::{
     },
     services::{
         models::{
-            AuthResponse, CreatePost, EditPost, ListPostsResponse, LoginUser, Post, RegisterUser,
+            ApiErrorResponse, Attachment, AuthResponse, ChangePassword, Comment, CreateComment,
+            CreatePost, EditPost, ForgotPassword, ListCommentsResponse, ListPostsResponse,
+            ListTagsResponse, LoginUser, Post, PublishPost, ReactionsResponse, RefreshSession,
+            RegisterUser, ResetPassword, SearchPostsResponse, UpdateProfile, User, UserProfile,
+            VerifyEmail,
         },
         tools::get_base_api_url,
     },
@@ -15,6 +19,7 @@ use crate::{
 use gloo_net::http::Request;
 use tracing::error;
 use url::Url;
+use web_sys::{File, FormData};
 
 /// Универсальный адаптер для взаимодействия с API.
 ///
@@ -73,37 +78,366 @@ impl BlogClient {
         Ok(auth)
     }
 
+    /// Продлить сессию: получить новую пару токенов взамен токена
+    /// обновления.
+    ///
+    /// Отказ сервера (токен отозван или истёк) возвращается как
+    /// [`BlogWasmError::Forbidden`]: сессию нужно начинать заново.
+    pub(crate) async fn refresh(
+        &self,
+        refresh_token: &JwtToken,
+    ) -> Result<AuthResponse, BlogWasmError> {
+        let url_api = self.make_url(&["api", "auth", "refresh"])?;
+        let resp = Request::post(url_api.as_str())
+            .json(&RefreshSession::new(refresh_token))?
+            .send()
+            .await?;
+
+        if !resp.ok() {
+            return Err(BlogWasmError::Forbidden);
+        }
+
+        let auth: AuthResponse = resp.json().await?;
+
+        Ok(auth)
+    }
+
+    /// Завершить сессию: сервер отзывает её токены.
+    pub(crate) async fn logout(&self, refresh_token: &JwtToken) -> Result<(), BlogWasmError> {
+        let url_api = self.make_url(&["api", "auth", "logout"])?;
+        Request::post(url_api.as_str())
+            .json(&RefreshSession::new(refresh_token))?
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Сменить пароль: сервер завершает все сессии пользователя и
+    /// возвращает токены новой.
+    pub(crate) async fn change_password(
+        &self,
+        change_password: &ChangePassword,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<AuthResponse, BlogWasmError> {
+        let token = jwt_token.ok_or(BlogWasmError::Forbidden)?;
+        let url_api = self.make_url(&["api", "auth", "password"])?;
+
+        let resp = Request::post(url_api.as_str())
+            .header("Authorization", &token.bearer())
+            .json(change_password)?
+            .send()
+            .await?;
+
+        if !resp.ok() {
+            let err: ApiErrorResponse = resp.json().await?;
+            return Err(BlogWasmError::user_data_err(err.details));
+        }
+
+        let auth: AuthResponse = resp.json().await?;
+
+        Ok(auth)
+    }
+
+    /// Запросить письмо для сброса забытого пароля.
+    pub(crate) async fn forgot_password(
+        &self,
+        forgot_password: &ForgotPassword,
+    ) -> Result<(), BlogWasmError> {
+        let url_api = self.make_url(&["api", "auth", "password", "forgot"])?;
+
+        let resp = Request::post(url_api.as_str())
+            .json(forgot_password)?
+            .send()
+            .await?;
+
+        if !resp.ok() {
+            let err: ApiErrorResponse = resp.json().await?;
+            return Err(BlogWasmError::user_data_err(err.details));
+        }
+
+        Ok(())
+    }
+
+    /// Установить новый пароль по токену из письма.
+    pub(crate) async fn reset_password(
+        &self,
+        reset_password: &ResetPassword,
+    ) -> Result<(), BlogWasmError> {
+        let url_api = self.make_url(&["api", "auth", "password", "reset"])?;
+
+        let resp = Request::post(url_api.as_str())
+            .json(reset_password)?
+            .send()
+            .await?;
+
+        if !resp.ok() {
+            let err: ApiErrorResponse = resp.json().await?;
+            return Err(BlogWasmError::user_data_err(err.details));
+        }
+
+        Ok(())
+    }
+
+    /// Подтвердить адрес электронной почты по токену из письма.
+    pub(crate) async fn verify_email(
+        &self,
+        verify_email: &VerifyEmail,
+    ) -> Result<User, BlogWasmError> {
+        let url_api = self.make_url(&["api", "auth", "email", "verify"])?;
+
+        let resp = Request::post(url_api.as_str())
+            .json(verify_email)?
+            .send()
+            .await?;
+
+        if !resp.ok() {
+            let err: ApiErrorResponse = resp.json().await?;
+            return Err(BlogWasmError::user_data_err(err.details));
+        }
+
+        let user: User = resp.json().await?;
+
+        Ok(user)
+    }
+
+    /// Повторно отправить письмо для подтверждения адреса.
+    pub(crate) async fn resend_verification(
+        &self,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<(), BlogWasmError> {
+        let token = jwt_token.ok_or(BlogWasmError::Forbidden)?;
+        let url_api = self.make_url(&["api", "auth", "email", "resend"])?;
+
+        let resp = Request::post(url_api.as_str())
+            .header("Authorization", &token.bearer())
+            .send()
+            .await?;
+
+        if !resp.ok() {
+            let err: ApiErrorResponse = resp.json().await?;
+            return Err(BlogWasmError::user_data_err(err.details));
+        }
+
+        Ok(())
+    }
+
+    /// Получить профиль авторизованного пользователя.
+    pub(crate) async fn get_me(&self, jwt_token: Option<JwtToken>) -> Result<User, BlogWasmError> {
+        let token = jwt_token.ok_or(BlogWasmError::Forbidden)?;
+        let url_api = self.make_url(&["api", "me"])?;
+
+        let resp = Request::get(url_api.as_str())
+            .header("Authorization", &token.bearer())
+            .send()
+            .await?;
+
+        if !resp.ok() {
+            let err: ApiErrorResponse = resp.json().await?;
+            return Err(BlogWasmError::user_data_err(err.details));
+        }
+
+        let user: User = resp.json().await?;
+
+        Ok(user)
+    }
+
+    /// Изменить профиль авторизованного пользователя. После смены адреса
+    /// электронной почты его нужно подтвердить заново.
+    pub(crate) async fn update_profile(
+        &self,
+        update_profile: &UpdateProfile,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<User, BlogWasmError> {
+        let token = jwt_token.ok_or(BlogWasmError::Forbidden)?;
+        let url_api = self.make_url(&["api", "me"])?;
+
+        let resp = Request::patch(url_api.as_str())
+            .header("Authorization", &token.bearer())
+            .json(update_profile)?
+            .send()
+            .await?;
+
+        if !resp.ok() {
+            let err: ApiErrorResponse = resp.json().await?;
+            return Err(BlogWasmError::user_data_err(err.details));
+        }
+
+        let user: User = resp.json().await?;
+
+        Ok(user)
+    }
+
     /// Загрузить список публикаций через API.
     ///
     /// ## Args
     ///
     /// - `limit` — количество новостей для выгрузки
-    /// - `offset` — сдвиг для пагинации
+    /// - `tag` — фильтр по тегу (опционально)
+    /// - `after` — курсор следующей страницы из предыдущего ответа
+    ///   (опционально), `None` для первой страницы
     pub(crate) async fn load_posts(
         &self,
         limit: i32,
-        offset: i32,
+        tag: Option<&str>,
+        after: Option<&str>,
     ) -> Result<ListPostsResponse, BlogWasmError> {
         let url_api = self.make_url(&["api", "posts"])?;
 
+        let mut request =
+            Request::get(url_api.as_str()).query([("limit", &limit.to_string())]);
+        if let Some(tag) = tag {
+            request = request.query([("tag", tag)]);
+        }
+        if let Some(after) = after {
+            request = request.query([("after", after)]);
+        }
+
+        let resp = request.send().await?;
+
+        let posts: ListPostsResponse = resp.json().await?;
+
+        Ok(posts)
+    }
+
+    /// Загрузить персональную ленту через API: публикации авторов,
+    /// на которых подписан пользователь. Параметры те же, что
+    /// у [`BlogClient::load_posts`].
+    pub(crate) async fn load_feed(
+        &self,
+        limit: i32,
+        tag: Option<&str>,
+        after: Option<&str>,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<ListPostsResponse, BlogWasmError> {
+        let token = jwt_token.ok_or(BlogWasmError::Forbidden)?;
+        let url_api = self.make_url(&["api", "feed"])?;
+
+        let mut request = Request::get(url_api.as_str())
+            .header("Authorization", &token.bearer())
+            .query([("limit", &limit.to_string())]);
+        if let Some(tag) = tag {
+            request = request.query([("tag", tag)]);
+        }
+        if let Some(after) = after {
+            request = request.query([("after", after)]);
+        }
+
+        let resp = request.send().await?;
+
+        let posts: ListPostsResponse = resp.json().await?;
+
+        Ok(posts)
+    }
+
+    /// Полнотекстовый поиск публикаций через API.
+    ///
+    /// ## Args
+    ///
+    /// - `query` — поисковый запрос
+    /// - `limit` — количество публикаций для выгрузки
+    /// - `offset` — сдвиг для пагинации
+    pub(crate) async fn search_posts(
+        &self,
+        query: &str,
+        limit: i32,
+        offset: i32,
+    ) -> Result<SearchPostsResponse, BlogWasmError> {
+        let url_api = self.make_url(&["api", "posts", "search"])?;
+        let (limit, offset) = (limit.to_string(), offset.to_string());
+
         let resp = Request::get(url_api.as_str())
             .query([
-                ("limit", &limit.to_string()),
-                ("offset", &offset.to_string()),
+                ("q", query),
+                ("limit", limit.as_str()),
+                ("offset", offset.as_str()),
             ])
             .send()
             .await?;
 
-        let posts: ListPostsResponse = resp.json().await?;
+        let results: SearchPostsResponse = resp.json().await?;
+
+        Ok(results)
+    }
+
+    /// Загрузить перечень тегов с количеством публикаций.
+    pub(crate) async fn load_tags(&self) -> Result<ListTagsResponse, BlogWasmError> {
+        let url_api = self.make_url(&["api", "tags"])?;
+
+        let resp = Request::get(url_api.as_str()).send().await?;
+
+        let tags: ListTagsResponse = resp.json().await?;
+        Ok(tags)
+    }
+
+    /// Загрузить публичный профиль автора.
+    pub(crate) async fn get_user_profile(
+        &self,
+        username: &str,
+    ) -> Result<UserProfile, BlogWasmError> {
+        let url_api = self.make_url(&["api", "users", username])?;
+
+        let resp = Request::get(url_api.as_str()).send().await?;
+
+        let profile: UserProfile = resp.json().await?;
+        Ok(profile)
+    }
+
+    /// Загрузить опубликованные записи автора, от новых к старым.
+    pub(crate) async fn list_user_posts(
+        &self,
+        username: &str,
+        limit: i32,
+        offset: i32,
+    ) -> Result<ListPostsResponse, BlogWasmError> {
+        let url_api = self.make_url(&["api", "users", username, "posts"])?;
+        let (limit, offset) = (limit.to_string(), offset.to_string());
 
+        let resp = Request::get(url_api.as_str())
+            .query([("limit", limit.as_str()), ("offset", offset.as_str())])
+            .send()
+            .await?;
+
+        let posts: ListPostsResponse = resp.json().await?;
         Ok(posts)
     }
 
     /// Получить отдельный пост по его id.
-    pub(crate) async fn get_post(&self, post_id: PostId) -> Result<Post, BlogWasmError> {
+    ///
+    /// Токен необязателен: с ним автору доступны его черновики и
+    /// отложенные публикации.
+    pub(crate) async fn get_post(
+        &self,
+        post_id: PostId,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<Post, BlogWasmError> {
         let url_api = self.make_url(&["api", "posts", post_id.to_string().as_str()])?;
 
-        let resp = Request::get(url_api.as_str()).send().await?;
+        let mut request = Request::get(url_api.as_str());
+        if let Some(token) = jwt_token {
+            request = request.header("Authorization", &token.bearer());
+        }
+        let resp = request.send().await?;
+
+        let post: Post = resp.json().await?;
+        Ok(post)
+    }
+
+    /// Получить публикацию по её текущему или прежнему адресу (slug).
+    ///
+    /// Перенаправление с прежнего адреса на текущий выполняет браузер.
+    pub(crate) async fn get_post_by_slug(
+        &self,
+        slug: &str,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<Post, BlogWasmError> {
+        let url_api = self.make_url(&["api", "posts", "by-slug", slug])?;
+
+        let mut request = Request::get(url_api.as_str());
+        if let Some(token) = jwt_token {
+            request = request.header("Authorization", &token.bearer());
+        }
+        let resp = request.send().await?;
 
         let post: Post = resp.json().await?;
         Ok(post)
@@ -128,6 +462,27 @@ impl BlogClient {
         Ok(post)
     }
 
+    /// Опубликовать черновик или перенести время публикации через API.
+    pub(crate) async fn publish_post(
+        &self,
+        post_id: PostId,
+        publish_post: &PublishPost,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<Post, BlogWasmError> {
+        let token = jwt_token.ok_or(BlogWasmError::Forbidden)?;
+        let post_id = post_id.to_string();
+        let url_api = self.make_url(&["api", "posts", post_id.as_str(), "publish"])?;
+
+        let resp = Request::post(url_api.as_str())
+            .header("Authorization", &token.bearer())
+            .json(publish_post)?
+            .send()
+            .await?;
+
+        let post: Post = resp.json().await?;
+        Ok(post)
+    }
+
     /// Обновить публикацию через API.
     pub(crate) async fn update_post(
         &self,
@@ -167,6 +522,138 @@ impl BlogClient {
         Ok(())
     }
 
+    /// Загрузить комментарии к публикации через API.
+    ///
+    /// ## Args
+    ///
+    /// - `post_id` — id публикации
+    /// - `limit` — количество комментариев для выгрузки
+    /// - `offset` — сдвиг для пагинации
+    pub(crate) async fn load_comments(
+        &self,
+        post_id: PostId,
+        limit: i32,
+        offset: i32,
+    ) -> Result<ListCommentsResponse, BlogWasmError> {
+        let post_id = post_id.to_string();
+        let url_api = self.make_url(&["api", "posts", &post_id, "comments"])?;
+
+        let resp = Request::get(url_api.as_str())
+            .query([
+                ("limit", &limit.to_string()),
+                ("offset", &offset.to_string()),
+            ])
+            .send()
+            .await?;
+
+        let comments: ListCommentsResponse = resp.json().await?;
+
+        Ok(comments)
+    }
+
+    /// Добавить комментарий к публикации через API.
+    pub(crate) async fn add_comment(
+        &self,
+        post_id: PostId,
+        create_comment: &CreateComment,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<Comment, BlogWasmError> {
+        let token = jwt_token.ok_or(BlogWasmError::Forbidden)?;
+
+        let post_id = post_id.to_string();
+        let url_api = self.make_url(&["api", "posts", &post_id, "comments"])?;
+
+        let resp = Request::post(url_api.as_str())
+            .header("Authorization", &token.bearer())
+            .json(create_comment)?
+            .send()
+            .await?;
+
+        let comment: Comment = resp.json().await?;
+        Ok(comment)
+    }
+
+    /// Удалить комментарий через API.
+    pub(crate) async fn delete_comment(
+        &self,
+        post_id: PostId,
+        comment_id: u64,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<(), BlogWasmError> {
+        let token = jwt_token.ok_or(BlogWasmError::Forbidden)?;
+
+        let post_id = post_id.to_string();
+        let comment_id = comment_id.to_string();
+        let url = self.make_url(&["api", "posts", &post_id, "comments", &comment_id])?;
+
+        Request::delete(url.as_str())
+            .header("Authorization", &token.bearer())
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Поставить (`active` = `true`) или снять реакцию на публикацию
+    /// через API.
+    pub(crate) async fn set_reaction(
+        &self,
+        post_id: PostId,
+        kind: &str,
+        active: bool,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<ReactionsResponse, BlogWasmError> {
+        let token = jwt_token.ok_or(BlogWasmError::Forbidden)?;
+
+        let post_id = post_id.to_string();
+        let url = self.make_url(&["api", "posts", &post_id, "reactions", kind])?;
+
+        let request = if active {
+            Request::put(url.as_str())
+        } else {
+            Request::delete(url.as_str())
+        };
+        let resp = request
+            .header("Authorization", &token.bearer())
+            .send()
+            .await?;
+
+        let reactions: ReactionsResponse = resp.json().await?;
+        Ok(reactions)
+    }
+
+    /// Загрузить файл вложения через API.
+    ///
+    /// Адрес загруженного файла в ответе дополняется до полного, чтобы его
+    /// можно было сразу вставить в текст публикации.
+    pub(crate) async fn upload(
+        &self,
+        file: &File,
+        jwt_token: Option<JwtToken>,
+    ) -> Result<Attachment, BlogWasmError> {
+        let token = jwt_token.ok_or(BlogWasmError::Forbidden)?;
+        let url = self.make_url(&["api", "uploads"])?;
+
+        let form = FormData::new().map_err(|_| BlogWasmError::InternalFatalError)?;
+        form.append_with_blob_and_filename("file", file, &file.name())
+            .map_err(|_| BlogWasmError::InternalFatalError)?;
+
+        let resp = Request::post(url.as_str())
+            .header("Authorization", &token.bearer())
+            .body(form)?
+            .send()
+            .await?;
+
+        if !resp.ok() {
+            let err: ApiErrorResponse = resp.json().await?;
+            return Err(BlogWasmError::user_data_err(err.details));
+        }
+
+        let mut attachment: Attachment = resp.json().await?;
+        attachment.url = self.make_url(&[attachment.url.trim_start_matches('/')])?.into();
+        Ok(attachment)
+    }
+
     /// Локальный метод обеспечивающий сборку ссылки для доступа к API.
     /// При ошибках генерирует ошибку [`BlogWasmError::InternalFatalError`],
     /// т.к. это поведение противоречит стабильной работе.
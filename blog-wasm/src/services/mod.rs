@@ -1,4 +1,4 @@
 //! Сециализированные сервисы для внешнего взаимодействия.
 pub(crate) mod models;
-mod tools;
+pub(crate) mod tools;
 pub(crate) mod clients;
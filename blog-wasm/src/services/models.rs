@@ -1,7 +1,10 @@
 //! Модели для организации запросов и получения информации.
 
+use crate::{domain::types::JwtToken, routes::Route};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use yew::{html, AttrValue, Html};
+use yew_router::prelude::Link;
 
 /// Экземпляр пользователя.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -12,6 +15,18 @@ pub(crate) struct User {
     pub(crate) username: String,
     /// Адрес электронной почты.
     pub(crate) email: String,
+    /// Адрес электронной почты подтверждён.
+    #[serde(default)]
+    pub(crate) email_verified: bool,
+    /// Отображаемое имя.
+    #[serde(default)]
+    pub(crate) display_name: String,
+    /// Информация о себе.
+    #[serde(default)]
+    pub(crate) bio: String,
+    /// Адрес аватара.
+    #[serde(default)]
+    pub(crate) avatar_url: String,
 }
 
 /// Экземпляр ответа по авторизованному пользователю.
@@ -19,10 +34,28 @@ pub(crate) struct User {
 pub(crate) struct AuthResponse {
     /// JWT-токен пользователя.
     pub(crate) token: String,
+    /// Токен обновления сессии.
+    pub(crate) refresh_token: String,
     /// DTO-экземпляр пользователя.
     pub(crate) user: User,
 }
 
+/// DTO, продление сессии или выход из неё.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub(crate) struct RefreshSession {
+    /// Токен обновления сессии.
+    pub(crate) refresh_token: String,
+}
+
+impl RefreshSession {
+    /// Создать DTO с токеном обновления.
+    pub(crate) fn new(refresh_token: &JwtToken) -> Self {
+        Self {
+            refresh_token: refresh_token.to_string(),
+        }
+    }
+}
+
 /// DTO, регистрация пользователя.
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub(crate) struct RegisterUser {
@@ -64,6 +97,122 @@ impl LoginUser {
     }
 }
 
+/// DTO, смена пароля авторизованным пользователем.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub(crate) struct ChangePassword {
+    /// Текущий пароль.
+    pub(crate) old_password: String,
+    /// Новый пароль.
+    pub(crate) new_password: String,
+}
+
+impl ChangePassword {
+    /// Создать DTO для смены пароля.
+    pub(crate) fn new(old_password: &str, new_password: &str) -> Self {
+        Self {
+            old_password: old_password.to_string(),
+            new_password: new_password.to_string(),
+        }
+    }
+}
+
+/// DTO, изменение профиля авторизованного пользователя. Незаданные поля
+/// не меняются.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub(crate) struct UpdateProfile {
+    /// Отображаемое имя.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) display_name: Option<String>,
+    /// Информация о себе.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) bio: Option<String>,
+    /// Адрес аватара.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) avatar_url: Option<String>,
+    /// Новый адрес электронной почты.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) email: Option<String>,
+}
+
+impl UpdateProfile {
+    /// Создать DTO изменения профиля по значениям формы и текущему профилю:
+    /// в запрос попадают только изменённые поля.
+    pub(crate) fn new(
+        current: &User,
+        display_name: &str,
+        bio: &str,
+        avatar_url: &str,
+        email: &str,
+    ) -> Self {
+        let changed = |old: &str, new: &str| (old != new).then(|| new.to_string());
+        Self {
+            display_name: changed(&current.display_name, display_name),
+            bio: changed(&current.bio, bio),
+            avatar_url: changed(&current.avatar_url, avatar_url),
+            email: changed(&current.email, email),
+        }
+    }
+
+    /// В запросе нет ни одного изменения.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.display_name.is_none()
+            && self.bio.is_none()
+            && self.avatar_url.is_none()
+            && self.email.is_none()
+    }
+}
+
+/// DTO, запрос на сброс забытого пароля.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub(crate) struct ForgotPassword {
+    /// Адрес электронной почты учётной записи.
+    pub(crate) email: String,
+}
+
+impl ForgotPassword {
+    /// Создать DTO запроса на сброс пароля.
+    pub(crate) fn new(email: &str) -> Self {
+        Self {
+            email: email.to_string(),
+        }
+    }
+}
+
+/// DTO, установка нового пароля по токену из письма.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub(crate) struct ResetPassword {
+    /// Токен сброса пароля.
+    pub(crate) token: String,
+    /// Новый пароль.
+    pub(crate) new_password: String,
+}
+
+impl ResetPassword {
+    /// Создать DTO для установки нового пароля.
+    pub(crate) fn new(token: &str, new_password: &str) -> Self {
+        Self {
+            token: token.to_string(),
+            new_password: new_password.to_string(),
+        }
+    }
+}
+
+/// DTO, подтверждение адреса электронной почты по токену из письма.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub(crate) struct VerifyEmail {
+    /// Токен подтверждения адреса.
+    pub(crate) token: String,
+}
+
+impl VerifyEmail {
+    /// Создать DTO для подтверждения адреса.
+    pub(crate) fn new(token: &str) -> Self {
+        Self {
+            token: token.to_string(),
+        }
+    }
+}
+
 /// Структура сообщения (поста) в блоге.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub(crate) struct Post {
@@ -72,16 +221,170 @@ pub(crate) struct Post {
     pub(crate) id: u64,
     /// Заголовок сообщения.
     pub(crate) title: String,
-    /// Содержание сообщения.
+    /// Текущий человекочитаемый адрес публикации.
+    #[serde(default)]
+    pub(crate) slug: String,
+    /// Содержание сообщения в формате CommonMark.
     pub(crate) content: String,
+    /// Содержание, отрисованное сервером в безопасный HTML.
+    #[serde(default)]
+    pub(crate) content_html: String,
     /// Id автора поста, на основе [`UserId`].
     pub(crate) author_id: u64,
+    /// Имя автора поста.
+    #[serde(default)]
+    pub(crate) author_username: String,
     /// Время создания поста.
     #[serde(with = "chrono::serde::ts_seconds")]
     pub(crate) created_at: DateTime<Utc>,
     /// Время, когда пост был обновлён.
     #[serde(with = "chrono::serde::ts_seconds_option")]
     pub(crate) updated_at: Option<DateTime<Utc>>,
+    /// Теги публикации.
+    #[serde(default)]
+    pub(crate) tags: Vec<String>,
+    /// Статус публикации: `draft`, `published`, `scheduled`, `pending`
+    /// или `hidden`.
+    #[serde(default)]
+    pub(crate) status: String,
+    /// Время публикации (фактическое или запланированное).
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub(crate) publish_at: Option<DateTime<Utc>>,
+    /// Количество реакций каждого вида.
+    #[serde(default)]
+    pub(crate) reactions: Vec<ReactionCount>,
+    /// Реакции текущего пользователя.
+    #[serde(default)]
+    pub(crate) my_reactions: Vec<String>,
+}
+
+impl Post {
+    /// Опубликована ли запись.
+    pub(crate) fn is_published(&self) -> bool {
+        self.status.is_empty() || self.status == "published"
+    }
+
+    /// Ссылка на страницу публикации: по адресу, если он известен,
+    /// иначе по id.
+    pub(crate) fn route(&self) -> Route {
+        if self.slug.is_empty() {
+            Route::Post { id: self.id }
+        } else {
+            Route::PostBySlug {
+                slug: self.slug.clone(),
+            }
+        }
+    }
+
+    /// Подпись с автором: ссылка на его профиль, либо id, если имя
+    /// не передано.
+    pub(crate) fn author_view(&self) -> Html {
+        if self.author_username.is_empty() {
+            return html! {
+                <p class="post-meta">{ format!("ID автора: {}", self.author_id) }</p>
+            };
+        }
+
+        let username = self.author_username.clone();
+        html! {
+            <p class="post-meta">
+                { "Автор: " }
+                <Link<Route> to={Route::User { username: username.clone() }}>
+                    { format!("@{username}") }
+                </Link<Route>>
+            </p>
+        }
+    }
+
+    /// Содержание для отображения: HTML, подготовленный и очищенный
+    /// сервером, либо исходный текст, если HTML не передан.
+    pub(crate) fn content_view(&self) -> Html {
+        if self.content_html.is_empty() {
+            return html! { <p>{ self.content.clone() }</p> };
+        }
+
+        html! {
+            <div class="post-content">
+                { Html::from_html_unchecked(AttrValue::from(self.content_html.clone())) }
+            </div>
+        }
+    }
+}
+
+/// Количество реакций одного вида на публикацию.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ReactionCount {
+    /// Вид реакции: `like`, `love`, `laugh`, `wow` или `sad`.
+    pub(crate) kind: String,
+    /// Количество пользователей, поставивших реакцию.
+    pub(crate) count: i64,
+}
+
+/// Сводка реакций на публикацию после реакции пользователя.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub(crate) struct ReactionsResponse {
+    /// Id публикации.
+    pub(crate) post_id: u64,
+    /// Количество реакций каждого вида.
+    pub(crate) reactions: Vec<ReactionCount>,
+    /// Реакции текущего пользователя.
+    pub(crate) my_reactions: Vec<String>,
+}
+
+/// Загруженное вложение.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub(crate) struct Attachment {
+    /// Id вложения.
+    pub(crate) id: u64,
+    /// Адрес файла.
+    pub(crate) url: String,
+    /// Исходное имя файла.
+    pub(crate) file_name: String,
+    /// MIME-тип содержимого.
+    pub(crate) content_type: String,
+    /// Размер файла в байтах.
+    pub(crate) size: i64,
+}
+
+impl Attachment {
+    /// Разметка Markdown для вставки вложения в текст публикации:
+    /// изображение для картинок, ссылка для остальных файлов.
+    pub(crate) fn markdown(&self) -> String {
+        let name = self.file_name.replace(['[', ']'], "");
+        if self.content_type.starts_with("image/") {
+            format!("![{name}]({})", self.url)
+        } else {
+            format!("[{name}]({})", self.url)
+        }
+    }
+}
+
+/// Тело ответа API с ошибкой.
+#[derive(Deserialize, Clone, Debug)]
+pub(crate) struct ApiErrorResponse {
+    /// Описание ошибки.
+    pub(crate) details: String,
+}
+
+/// Публичный профиль автора.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub(crate) struct UserProfile {
+    /// Имя пользователя.
+    pub(crate) username: String,
+    /// Отображаемое имя.
+    #[serde(default)]
+    pub(crate) display_name: String,
+    /// Информация о себе.
+    #[serde(default)]
+    pub(crate) bio: String,
+    /// Адрес аватара.
+    #[serde(default)]
+    pub(crate) avatar_url: String,
+    /// Время регистрации.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) joined_at: DateTime<Utc>,
+    /// Количество опубликованных записей.
+    pub(crate) posts_count: i64,
 }
 
 /// Успешный ответ со списком публикаций в блоге.
@@ -89,12 +392,14 @@ pub(crate) struct Post {
 pub(crate) struct ListPostsResponse {
     /// Перечень публикаций.
     pub(crate) posts: Vec<Post>,
-    /// Всего публикаций.
-    pub(crate) total: i32,
+    /// Всего публикаций (только для первой страницы, без курсора).
+    pub(crate) total: Option<i64>,
     /// Заказанное количество публикаций.
     pub(crate) limit: i32,
     /// Сдвиг по публикациям.
     pub(crate) offset: i32,
+    /// Курсор следующей страницы; `None` на последней странице.
+    pub(crate) next_cursor: Option<String>,
 }
 
 /// Dto-структура для создания записи (поста).
@@ -104,17 +409,42 @@ pub(crate) struct CreatePost {
     pub(crate) title: String,
     /// Содержимое поста.
     pub(crate) content: String,
+    /// Теги поста.
+    pub(crate) tags: Vec<String>,
+    /// Статус поста (`draft`, `published`, `scheduled`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) status: Option<String>,
+    /// Время отложенной публикации (unix timestamp).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) publish_at: Option<i64>,
 }
 
 impl CreatePost {
-    pub(crate) fn new(title: &str, content: &str) -> Self {
+    pub(crate) fn new(
+        title: &str,
+        content: &str,
+        tags: Vec<String>,
+        draft: bool,
+        publish_at: Option<i64>,
+    ) -> Self {
         Self {
             title: title.to_string(),
             content: content.to_string(),
+            tags,
+            status: draft.then(|| "draft".to_string()),
+            publish_at,
         }
     }
 }
 
+/// Dto-структура для публикации черновика или отложенной записи.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct PublishPost {
+    /// Время отложенной публикации (unix timestamp). `None` — сразу.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) publish_at: Option<i64>,
+}
+
 /// Dto-структура для редактирования записи (поста).
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub(crate) struct EditPost {
@@ -122,13 +452,100 @@ pub(crate) struct EditPost {
     pub(crate) title: Option<String>,
     /// Содержимое поста.
     pub(crate) content: Option<String>,
+    /// Новый набор тегов поста.
+    pub(crate) tags: Option<Vec<String>>,
 }
 
 impl EditPost {
-    pub(crate) fn new(title: &str, content: &str) -> Self {
+    pub(crate) fn new(title: &str, content: &str, tags: Vec<String>) -> Self {
         Self {
             title: Some(title.to_string()),
             content: Some(content.to_string()),
+            tags: Some(tags),
+        }
+    }
+}
+
+/// Тег и количество публикаций с ним.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub(crate) struct TagStat {
+    /// Наименование тега.
+    pub(crate) name: String,
+    /// Количество публикаций с тегом.
+    pub(crate) posts_count: i64,
+}
+
+/// Успешный ответ с перечнем тегов.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub(crate) struct ListTagsResponse {
+    /// Теги, от самых популярных.
+    pub(crate) tags: Vec<TagStat>,
+}
+
+/// Комментарий к публикации.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct Comment {
+    /// Уникальный id комментария.
+    pub(crate) id: u64,
+    /// Id публикации, к которой относится комментарий.
+    pub(crate) post_id: u64,
+    /// Id автора комментария.
+    pub(crate) author_id: u64,
+    /// Текст комментария.
+    pub(crate) content: String,
+    /// Время создания комментария.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+/// Успешный ответ со списком комментариев к публикации.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub(crate) struct ListCommentsResponse {
+    /// Перечень комментариев.
+    pub(crate) comments: Vec<Comment>,
+    /// Всего комментариев у публикации.
+    pub(crate) total: i64,
+    /// Заказанное количество комментариев.
+    pub(crate) limit: i32,
+    /// Сдвиг по комментариям.
+    pub(crate) offset: i32,
+}
+
+/// Dto-структура для добавления комментария.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct CreateComment {
+    /// Текст комментария.
+    pub(crate) content: String,
+}
+
+impl CreateComment {
+    pub(crate) fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
         }
     }
 }
+
+/// Результат поиска публикаций.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub(crate) struct PostSearchHit {
+    /// Найденная публикация.
+    pub(crate) post: Post,
+    /// Релевантность.
+    pub(crate) rank: f32,
+    /// Фрагмент текста; совпадения обрамлены `<mark>` и `</mark>`.
+    pub(crate) snippet: String,
+}
+
+/// Успешный ответ на поиск публикаций.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub(crate) struct SearchPostsResponse {
+    /// Найденные публикации, от наиболее релевантных.
+    pub(crate) hits: Vec<PostSearchHit>,
+    /// Всего найдено.
+    pub(crate) total: i64,
+    /// Заказанное количество публикаций.
+    pub(crate) limit: i32,
+    /// Сдвиг по публикациям.
+    pub(crate) offset: i32,
+}
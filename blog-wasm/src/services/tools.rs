@@ -15,6 +15,29 @@ pub(super) fn get_base_api_url() -> Option<String> {
         .and_then(|e1| e1.get_attribute("content"))
 }
 
+/// Разобрать строку с тегами, введёнными через запятую или пробел.
+///
+/// Пустые значения отбрасываются, проверку допустимости выполняет сервер.
+pub(crate) fn parse_tags(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .map(|t| t.trim_start_matches('#'))
+        .filter(|t| !t.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Дописать абзац `paragraph` в конец текста `text`, отделив его пустой
+/// строкой.
+pub(crate) fn append_paragraph(text: &str, paragraph: &str) -> String {
+    let text = text.trim_end();
+    if text.is_empty() {
+        paragraph.to_string()
+    } else {
+        format!("{text}\n\n{paragraph}")
+    }
+}
+
 /// Предоставить доступ к объекту DOM для извлечения данных.
 fn get_dom() -> Option<Document> {
     window().and_then(|d| d.document())
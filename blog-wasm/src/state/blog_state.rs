@@ -12,6 +12,9 @@ const USER_BLOG_ID_FIELD: &str = "blog_user_id";
 /// Имя поля для хранения в local storage JWT-токена.
 const TOKEN_FIELD: &str = "blog_token";
 
+/// Имя поля для хранения в local storage токена обновления сессии.
+const REFRESH_TOKEN_FIELD: &str = "blog_refresh_token";
+
 /// Имя поля для хранения в local storage username.
 const USERNAME_FIELD: &str = "blog_username";
 
@@ -24,6 +27,8 @@ pub(crate) struct BlogAppState {
     username: Option<String>,
     /// Токен доступа к API.
     token: Option<JwtToken>,
+    /// Токен обновления сессии.
+    refresh_token: Option<JwtToken>,
 }
 
 impl Default for BlogAppState {
@@ -31,11 +36,13 @@ impl Default for BlogAppState {
         let user_blog_id: Option<u64> = Self::load_user_id();
         let username = Self::load_username();
         let token = Self::load_token();
+        let refresh_token = Self::load_refresh_token();
 
         Self {
             user_blog_id,
             username,
             token,
+            refresh_token,
         }
     }
 }
@@ -46,12 +53,13 @@ impl BlogAppState {
         self.token.is_some()
     }
 
-    /// Сохранить предоставленный токен в `Local Storage` и в структуре.
+    /// Сохранить предоставленные токены в `Local Storage` и в структуре.
     pub(crate) fn save_user_data(
         &self,
         user_blog_id: u64,
         username: String,
         token: &JwtToken,
+        refresh_token: &JwtToken,
     ) -> Self {
         /// Внутренняя функция сохранения в local storage.
         fn save_to_storage(cell: &str, elem: &str, storage: &Storage) {
@@ -69,12 +77,14 @@ impl BlogAppState {
 
         s.username = Some(username.clone());
         s.token = Some(token.clone());
+        s.refresh_token = Some(refresh_token.clone());
         s.user_blog_id = Some(user_blog_id);
 
         if let Some(storage) = Self::get_storage() {
             for (cell, elem) in [
                 (USERNAME_FIELD, &username),
                 (TOKEN_FIELD, &token.to_string()),
+                (REFRESH_TOKEN_FIELD, &refresh_token.to_string()),
                 (USER_BLOG_ID_FIELD, &user_blog_id.to_string()),
             ] {
                 save_to_storage(cell, elem, &storage)
@@ -93,6 +103,11 @@ impl BlogAppState {
         self.token.clone()
     }
 
+    /// Получить сохранённый токен обновления.
+    pub(crate) fn get_refresh_token(&self) -> Option<JwtToken> {
+        self.refresh_token.clone()
+    }
+
     /// Получить сохранённый username.
     pub(crate) fn get_username(&self) -> Option<&String> {
         self.username.as_ref()
@@ -103,10 +118,12 @@ impl BlogAppState {
         let mut s = self.clone();
         s.username = None;
         s.token = None;
+        s.refresh_token = None;
         s.user_blog_id = None;
 
         if let Some(storage) = Self::get_storage() {
             let _ = storage.remove_item(TOKEN_FIELD);
+            let _ = storage.remove_item(REFRESH_TOKEN_FIELD);
             let _ = storage.remove_item(USERNAME_FIELD);
             let _ = storage.remove_item(USER_BLOG_ID_FIELD);
         }
@@ -119,6 +136,11 @@ impl BlogAppState {
         Self::load_field(TOKEN_FIELD).map(JwtToken::new)
     }
 
+    /// Загрузить токен обновления из `localStorage`.
+    fn load_refresh_token() -> Option<JwtToken> {
+        Self::load_field(REFRESH_TOKEN_FIELD).map(JwtToken::new)
+    }
+
     /// Загрузить имя пользователя из `localStorage`.
     fn load_username() -> Option<String> {
         Self::load_field(USERNAME_FIELD)
@@ -24,6 +24,32 @@ impl AuthStatus {
     }
 }
 
+/// Состояние формы смены или сброса пароля.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum PasswordStatus {
+    /// Форма ещё не отправлена.
+    Idle,
+    /// Запрос отправлен.
+    Sending,
+    /// Запрос выполнен.
+    Done,
+    /// Ошибка запроса.
+    Error(String),
+}
+
+/// Состояние подтверждения адреса электронной почты.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum VerificationStatus {
+    /// Запрос ещё не отправлен.
+    Idle,
+    /// Запрос отправлен.
+    Sending,
+    /// Запрос выполнен, сообщение для пользователя.
+    Done(String),
+    /// Ошибка запроса.
+    Error(String),
+}
+
 /// Состояние отображения публикаций.
 #[derive(Clone, PartialEq)]
 pub(crate) enum PostsStatus {
@@ -1,6 +1,6 @@
 //! Операции со временем и датами.
 
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
 
 /// Преобразовать часовую отметку UTC в текстовое представление даты.
 ///
@@ -33,3 +33,31 @@ pub fn string_from_datetime(dt: DateTime<Utc>, local_time: bool) -> Option<Strin
 
     Some(datetime_local.format("%H:%M, %d.%m.%Y").to_string())
 }
+
+/// Преобразовать текстовое представление даты и времени в количество секунд
+/// с начала эпохи UNIX.
+///
+/// Поддерживаются формат RFC 3339 (`2026-10-20T09:00:00+03:00`), а также
+/// `2026-10-20 09:00` и `2026-10-20T09:00` — в этом случае время считается
+/// локальным.
+///
+/// ## Returns
+///
+/// Временная метка UTC в секундах, либо `None`, если строку не удалось
+/// разобрать.
+pub fn timestamp_from_string(value: &str) -> Option<i64> {
+    let value = value.trim();
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.timestamp());
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M")
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M"))
+        .ok()?;
+
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|dt| dt.timestamp())
+}
@@ -5,4 +5,4 @@
 
 mod datetime;
 
-pub use datetime::{string_from_timestamp, string_from_datetime};
+pub use datetime::{string_from_timestamp, string_from_datetime, timestamp_from_string};